@@ -0,0 +1,137 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the cache, used when `--cache-location` is not provided or points to a directory.
+pub const DEFAULT_CACHE_FILE: &str = ".oxfmtcache";
+
+/// On-disk cache of the content hashes of formatted files.
+///
+/// Files whose contents hash to the value stored for them are known to be formatted, and are
+/// skipped on subsequent runs, as long as the formatter and its options are the same. Only
+/// content hashes are compared, so operations which touch files without changing them (e.g.
+/// `git checkout` or restoring CI caches) do not invalidate the cache.
+#[derive(Debug)]
+pub struct FormatCache {
+    path: PathBuf,
+    key: String,
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    key: String,
+    files: BTreeMap<String, String>,
+}
+
+impl FormatCache {
+    /// Load the cache from `location`, which is either a file or a directory.
+    ///
+    /// `options` is a fingerprint of the format options. If it differs from the one the cache
+    /// was created with, or the formatter has changed, the cache is discarded.
+    pub fn load(location: &Path, options: &str) -> Self {
+        let path = if location.is_dir() {
+            location.join(DEFAULT_CACHE_FILE)
+        } else {
+            location.to_path_buf()
+        };
+        let key = cache_key(options);
+
+        let files = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache| cache.key == key)
+            .map(|cache| cache.files)
+            .unwrap_or_default();
+
+        Self { path, key, files }
+    }
+
+    /// Returns `true` if the file at `path` was formatted when its contents hashed to `hash`.
+    pub fn is_fresh(&self, path: &Path, hash: &str) -> bool {
+        self.files.get(&*path.to_string_lossy()).is_some_and(|cached| cached == hash)
+    }
+
+    /// Record that the file at `path` is formatted while its contents hash to `hash`, or forget
+    /// the file when `hash` is `None`.
+    pub fn update(&mut self, path: &Path, hash: Option<String>) {
+        let key = path.to_string_lossy().into_owned();
+        match hash {
+            Some(hash) => self.files.insert(key, hash),
+            None => self.files.remove(&key),
+        };
+    }
+
+    /// Write the cache to disk, dropping entries for files which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be written.
+    pub fn save(mut self) -> io::Result<()> {
+        self.files.retain(|path, _| Path::new(path).is_file());
+        let cache = CacheFile { key: self.key, files: self.files };
+        fs::write(&self.path, serde_json::to_string(&cache).map_err(io::Error::other)?)
+    }
+}
+
+/// Hash of the contents of a file, as stored in the cache.
+pub fn content_hash(content: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Combine the options fingerprint with the identity of the formatter itself, so that the cache
+/// is invalidated when oxfmt is upgraded.
+fn cache_key(options: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    option_env!("OXC_VERSION").hash(&mut hasher);
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    // Development builds share a version number, so also take the binary itself into account
+    if let Some(metadata) = std::env::current_exe().ok().and_then(|exe| fs::metadata(exe).ok()) {
+        metadata.len().hash(&mut hasher);
+        metadata.modified().ok().hash(&mut hasher);
+    }
+    options.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{DEFAULT_CACHE_FILE, FormatCache, content_hash};
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.js");
+        fs::write(&file, "a;\n").unwrap();
+        let hash = content_hash("a;\n");
+
+        let mut cache = FormatCache::load(dir.path(), "options");
+        assert!(!cache.is_fresh(&file, &hash));
+        cache.update(&file, Some(hash.clone()));
+        cache.update(&dir.path().join("deleted.js"), Some(hash.clone()));
+        cache.save().unwrap();
+        assert!(dir.path().join(DEFAULT_CACHE_FILE).is_file());
+
+        let cache = FormatCache::load(dir.path(), "options");
+        assert!(cache.is_fresh(&file, &hash));
+        assert!(!cache.is_fresh(&file, &content_hash("b;\n")));
+        assert!(!cache.is_fresh(&dir.path().join("deleted.js"), &hash));
+
+        // Changed options discard the cache
+        let mut cache = FormatCache::load(dir.path(), "other options");
+        assert!(!cache.is_fresh(&file, &hash));
+
+        cache.update(&file, None);
+        assert!(!cache.is_fresh(&file, &hash));
+    }
+}
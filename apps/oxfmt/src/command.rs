@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+const VERSION: &str = match option_env!("OXC_VERSION") {
+    Some(v) => v,
+    None => "dev",
+};
+
+/// Format JavaScript and TypeScript files
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub struct FormatCommand {
+    /// Check that files are formatted without writing them,
+    /// and exit with an error when any file is not formatted
+    #[bpaf(switch)]
+    pub check: bool,
+
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    #[bpaf(argument("INT"), hide_usage)]
+    pub threads: Option<usize>,
+
+    /// Files or directories to format, defaults to the current working directory
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only format files that have changed since the last run.
+    /// Hashes of the contents of formatted files are remembered in a cache file,
+    /// and files with the same contents are skipped on later runs.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file or directory.
+    /// Defaults to `.oxfmtcache` in the current working directory.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{FormatCommand, format_command};
+
+    fn parse(args: &[&str]) -> FormatCommand {
+        format_command().run_inner(args).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let command = parse(&[]);
+        assert!(!command.check);
+        assert!(!command.cache_options.cache);
+        assert_eq!(command.cache_options.cache_location, None);
+        assert_eq!(command.threads, None);
+        assert!(command.paths.is_empty());
+    }
+
+    #[test]
+    fn options() {
+        let command = parse(&[
+            "--check",
+            "--cache",
+            "--cache-location",
+            "node_modules/.cache/oxfmt",
+            "--threads",
+            "4",
+            "src",
+            "index.ts",
+        ]);
+        assert!(command.check);
+        assert!(command.cache_options.cache);
+        assert_eq!(
+            command.cache_options.cache_location,
+            Some(PathBuf::from("node_modules/.cache/oxfmt"))
+        );
+        assert_eq!(command.threads, Some(4));
+        assert_eq!(command.paths, [PathBuf::from("src"), PathBuf::from("index.ts")]);
+    }
+}
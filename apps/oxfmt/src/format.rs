@@ -0,0 +1,323 @@
+use std::{
+    fmt, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
+
+use crate::{
+    cache::{FormatCache, content_hash},
+    command::FormatCommand,
+    walk::Walk,
+};
+
+/// Formats the files of a [`FormatCommand`] on a pool of threads.
+pub struct FormatRunner {
+    options: FormatCommand,
+    cwd: PathBuf,
+}
+
+/// Outcome of formatting a single file.
+enum FileResult {
+    /// Skipped, because the cache records the file as formatted.
+    Cached,
+    /// Already formatted. Holds the content hash of the file.
+    Unchanged(String),
+    /// Written with the formatted code. Holds the content hash of the formatted code.
+    Formatted(String),
+    /// Not formatted, and not written because of `--check`.
+    Unformatted,
+    /// Could not be read, parsed or written.
+    Error(String),
+}
+
+/// Counts of files by outcome.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FormatSummary {
+    pub check: bool,
+    /// Number of files which were walked.
+    pub files: usize,
+    /// Files which were skipped because they are unchanged since the last run.
+    pub cached: usize,
+    /// Files which were written, or which are not formatted with `--check`.
+    pub changed: Vec<PathBuf>,
+    /// Files which could not be formatted.
+    pub errors: usize,
+    pub threads: usize,
+    pub duration: Duration,
+}
+
+impl FormatRunner {
+    pub fn new(options: FormatCommand, cwd: PathBuf) -> Self {
+        Self { options, cwd }
+    }
+
+    /// Format the files, and print unformatted files and errors followed by a summary.
+    ///
+    /// # Panics
+    ///
+    /// * When writing to `stdout` fails
+    pub fn run(self, stdout: &mut dyn Write) -> ExitCode {
+        let summary = self.format(stdout);
+        write!(stdout, "{summary}").unwrap();
+        stdout.flush().unwrap();
+        let failed = summary.errors > 0 || (summary.check && !summary.changed.is_empty());
+        if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+    }
+
+    /// Format the files, printing unformatted files and errors as they are found.
+    ///
+    /// # Panics
+    ///
+    /// * When writing to `stdout` fails
+    pub fn format(self, stdout: &mut dyn Write) -> FormatSummary {
+        let start = Instant::now();
+        let FormatCommand { check, cache_options, threads, paths } = self.options;
+
+        let pool = match rayon::ThreadPoolBuilder::new().num_threads(threads.unwrap_or(0)).build() {
+            Ok(pool) => pool,
+            Err(err) => {
+                writeln!(stdout, "Failed to start threads: {err}").unwrap();
+                return FormatSummary { check, errors: 1, ..FormatSummary::default() };
+            }
+        };
+        let threads = pool.current_num_threads();
+
+        let paths = if paths.is_empty() {
+            vec![self.cwd.clone()]
+        } else {
+            paths.into_iter().map(|path| self.cwd.join(path)).collect()
+        };
+        let paths = Walk::new(&paths, threads).paths();
+
+        let format_options = FormatOptions::default();
+        let mut cache = cache_options.cache.then(|| {
+            let location = cache_options.cache_location.as_deref().unwrap_or(&self.cwd);
+            FormatCache::load(&self.cwd.join(location), &format!("{format_options:?}"))
+        });
+
+        let results = pool.install(|| {
+            paths
+                .par_iter()
+                .map(|path| format_file(path, &format_options, check, cache.as_ref()))
+                .collect::<Vec<_>>()
+        });
+
+        let mut summary =
+            FormatSummary { check, files: paths.len(), threads, ..Default::default() };
+        for (path, result) in paths.iter().zip(results) {
+            let display_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let hash = match result {
+                FileResult::Cached => {
+                    summary.cached += 1;
+                    continue;
+                }
+                FileResult::Unchanged(hash) => Some(hash),
+                FileResult::Formatted(hash) => {
+                    writeln!(stdout, "{}", display_path.display()).unwrap();
+                    summary.changed.push(display_path.to_path_buf());
+                    Some(hash)
+                }
+                FileResult::Unformatted => {
+                    writeln!(stdout, "{}", display_path.display()).unwrap();
+                    summary.changed.push(display_path.to_path_buf());
+                    None
+                }
+                FileResult::Error(error) => {
+                    writeln!(stdout, "Failed to format {}: {error}", display_path.display())
+                        .unwrap();
+                    summary.errors += 1;
+                    None
+                }
+            };
+            if let Some(cache) = &mut cache {
+                cache.update(path, hash);
+            }
+        }
+
+        if let Some(cache) = cache {
+            if let Err(err) = cache.save() {
+                writeln!(stdout, "Failed to write the cache: {err}").unwrap();
+            }
+        }
+
+        summary.duration = start.elapsed();
+        summary
+    }
+}
+
+fn format_file(
+    path: &Path,
+    options: &FormatOptions,
+    check: bool,
+    cache: Option<&FormatCache>,
+) -> FileResult {
+    let source_text = match fs::read_to_string(path) {
+        Ok(source_text) => source_text,
+        Err(err) => return FileResult::Error(err.to_string()),
+    };
+    let hash = content_hash(&source_text);
+    if cache.is_some_and(|cache| cache.is_fresh(path, &hash)) {
+        return FileResult::Cached;
+    }
+
+    let source_type = match SourceType::from_path(path) {
+        Ok(source_type) => source_type,
+        Err(err) => return FileResult::Error(err.to_string()),
+    };
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type)
+        .with_options(ParseOptions { preserve_parens: false, ..ParseOptions::default() })
+        .parse();
+    if let Some(error) = ret.errors.first() {
+        return FileResult::Error(error.to_string());
+    }
+
+    let code = Formatter::new(&allocator, options.clone()).build(&ret.program);
+    if code == source_text {
+        FileResult::Unchanged(hash)
+    } else if check {
+        FileResult::Unformatted
+    } else {
+        match fs::write(path, &code) {
+            Ok(()) => FileResult::Formatted(content_hash(&code)),
+            Err(err) => FileResult::Error(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FormatSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        let changed = self.changed.len();
+        if self.check {
+            write!(f, "Checked {} file{}", self.files, plural(self.files))?;
+        } else {
+            write!(f, "Formatted {changed} of {} file{}", self.files, plural(self.files))?;
+        }
+        write!(
+            f,
+            " in {}ms using {} thread{}",
+            self.duration.as_millis(),
+            self.threads,
+            plural(self.threads)
+        )?;
+        if self.cached > 0 {
+            write!(f, " ({} cached)", self.cached)?;
+        }
+        writeln!(f, ".")?;
+        if self.check && changed > 0 {
+            let verb = if changed == 1 { "is" } else { "are" };
+            writeln!(f, "{changed} file{} {verb} not formatted.", plural(changed))?;
+        }
+        if self.errors > 0 {
+            writeln!(f, "{} file{} could not be formatted.", self.errors, plural(self.errors))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf, time::Duration};
+
+    use super::{FormatRunner, FormatSummary};
+    use crate::format_command;
+
+    const UNFORMATTED: &str = "const a  =  1\nlet b = {x:1}";
+    const FORMATTED: &str = "const a = 1;\nlet b = { x: 1 };\n";
+
+    fn format(cwd: &std::path::Path, args: &[&str]) -> (FormatSummary, String) {
+        let command = format_command().run_inner(args).unwrap();
+        let mut output = vec![];
+        let summary = FormatRunner::new(command, cwd.to_path_buf()).format(&mut output);
+        (summary, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn check_and_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/a.js"), UNFORMATTED).unwrap();
+        fs::write(root.join("src/b.ts"), FORMATTED).unwrap();
+        fs::write(root.join("src/c.js"), "let = ;").unwrap();
+
+        let (summary, output) = format(root, &["--check", "--threads", "2"]);
+        assert_eq!(summary.files, 3);
+        assert_eq!(summary.changed, [PathBuf::from("src/a.js")]);
+        assert_eq!(summary.errors, 1);
+        assert_eq!(summary.threads, 2);
+        assert!(output.starts_with("src/a.js\nFailed to format src/c.js: "), "{output}");
+        assert_eq!(fs::read_to_string(root.join("src/a.js")).unwrap(), UNFORMATTED);
+
+        let (summary, output) = format(root, &["src/a.js", "src/b.ts"]);
+        assert_eq!(summary.changed, [PathBuf::from("src/a.js")]);
+        assert_eq!(summary.errors, 0);
+        assert_eq!(output, "src/a.js\n");
+        assert_eq!(fs::read_to_string(root.join("src/a.js")).unwrap(), FORMATTED);
+
+        let (summary, _) = format(root, &["--check", "src/a.js"]);
+        assert!(summary.changed.is_empty());
+    }
+
+    #[test]
+    fn cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("a.js"), UNFORMATTED).unwrap();
+        fs::write(root.join("b.js"), FORMATTED).unwrap();
+
+        let (summary, _) = format(root, &["--check", "--cache"]);
+        assert_eq!(summary.cached, 0);
+        assert_eq!(summary.changed, [PathBuf::from("a.js")]);
+        assert!(root.join(".oxfmtcache").is_file());
+
+        // Only the formatted file is cached
+        let (summary, _) = format(root, &["--cache"]);
+        assert_eq!(summary.cached, 1);
+        assert_eq!(summary.changed, [PathBuf::from("a.js")]);
+
+        // The written file is cached
+        let (summary, _) = format(root, &["--check", "--cache"]);
+        assert_eq!(summary.cached, 2);
+        assert!(summary.changed.is_empty());
+
+        // Changed files are formatted again
+        fs::write(root.join("b.js"), UNFORMATTED).unwrap();
+        let (summary, _) = format(root, &["--check", "--cache"]);
+        assert_eq!(summary.cached, 1);
+        assert_eq!(summary.changed, [PathBuf::from("b.js")]);
+    }
+
+    #[test]
+    fn summary() {
+        let summary = FormatSummary {
+            check: true,
+            files: 12,
+            cached: 10,
+            changed: vec![PathBuf::from("a.js"), PathBuf::from("b.js")],
+            errors: 1,
+            threads: 1,
+            duration: Duration::from_millis(31),
+        };
+        assert_eq!(
+            summary.to_string(),
+            "Checked 12 files in 31ms using 1 thread (10 cached).\n2 files are not formatted.\n1 file could not be formatted.\n"
+        );
+
+        let summary = FormatSummary { check: false, errors: 0, ..summary };
+        assert_eq!(
+            summary.to_string(),
+            "Formatted 2 of 12 files in 31ms using 1 thread (10 cached).\n"
+        );
+    }
+}
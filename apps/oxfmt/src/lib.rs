@@ -0,0 +1,11 @@
+//! Command line interface of the Oxc formatter.
+
+mod cache;
+mod command;
+mod format;
+mod walk;
+
+pub use crate::{
+    command::{CacheOptions, FormatCommand, format_command},
+    format::{FormatRunner, FormatSummary},
+};
@@ -0,0 +1,9 @@
+use std::{env, io, process::ExitCode};
+
+use oxfmt::{FormatRunner, format_command};
+
+fn main() -> ExitCode {
+    let command = format_command().run();
+    let cwd = env::current_dir().expect("Failed to get current working directory");
+    FormatRunner::new(command, cwd).run(&mut io::stdout().lock())
+}
@@ -0,0 +1,119 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc,
+};
+
+use oxc_span::VALID_EXTENSIONS;
+
+/// Walks the files to format in parallel, respecting `.gitignore` files and skipping hidden
+/// files and directories.
+pub struct Walk {
+    inner: ignore::WalkParallel,
+}
+
+struct WalkBuilder {
+    sender: mpsc::Sender<Vec<PathBuf>>,
+}
+
+impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
+    fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
+        Box::new(WalkCollector { paths: vec![], sender: self.sender.clone() })
+    }
+}
+
+struct WalkCollector {
+    paths: Vec<PathBuf>,
+    sender: mpsc::Sender<Vec<PathBuf>>,
+}
+
+impl Drop for WalkCollector {
+    fn drop(&mut self) {
+        let paths = std::mem::take(&mut self.paths);
+        self.sender.send(paths).unwrap();
+    }
+}
+
+impl ignore::ParallelVisitor for WalkCollector {
+    fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
+        match entry {
+            Ok(entry) => {
+                let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+                if !is_dir && is_formattable(entry.path()) {
+                    self.paths.push(entry.into_path());
+                }
+                ignore::WalkState::Continue
+            }
+            Err(_err) => ignore::WalkState::Skip,
+        }
+    }
+}
+
+impl Walk {
+    /// Walk `paths` with `threads` threads, or a number of threads chosen from the number of
+    /// CPUs when `threads` is 0.
+    ///
+    /// # Panics
+    ///
+    /// * When `paths` is empty
+    pub fn new(paths: &[PathBuf], threads: usize) -> Self {
+        let (first, rest) = paths.split_first().expect("At least one path must be provided");
+        let mut inner = ignore::WalkBuilder::new(first);
+        for path in rest {
+            inner.add(path);
+        }
+        let inner = inner.ignore(false).git_global(false).threads(threads).build_parallel();
+        Self { inner }
+    }
+
+    /// The paths of the files to format, sorted so that they do not depend on the order of the
+    /// parallel traversal.
+    pub fn paths(self) -> Vec<PathBuf> {
+        let (sender, receiver) = mpsc::channel::<Vec<PathBuf>>();
+        let mut builder = WalkBuilder { sender };
+        self.inner.visit(&mut builder);
+        drop(builder);
+        let mut paths = receiver.into_iter().flatten().collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths
+    }
+}
+
+fn is_formattable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| VALID_EXTENSIONS.contains(&extension))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::Walk;
+
+    #[test]
+    fn walk() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir_all(root.join(".hidden")).unwrap();
+        fs::create_dir_all(root.join("dist")).unwrap();
+        for file in
+            ["a.js", "src/b.ts", "src/nested/c.tsx", "src/d.css", ".hidden/e.js", "dist/f.js"]
+        {
+            fs::write(root.join(file), "").unwrap();
+        }
+        // `.gitignore` files are only respected inside of git repositories
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "dist\n").unwrap();
+
+        let paths = Walk::new(&[root.to_path_buf()], 2).paths();
+        let paths = paths
+            .iter()
+            .map(|path| path.strip_prefix(root).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [PathBuf::from("a.js"), PathBuf::from("src/b.ts"), PathBuf::from("src/nested/c.tsx")]
+        );
+    }
+}
@@ -0,0 +1,2 @@
+let s = '';
+debugger;
@@ -0,0 +1,30 @@
+import js from "@eslint/js";
+import react from "eslint-plugin-react";
+import foo from "eslint-plugin-foo";
+import globals from "globals";
+import tseslint from "typescript-eslint";
+
+const ignores = { ignores: ["dist/**", "coverage/**"] };
+
+export default tseslint.config(
+  ignores,
+  js.configs.recommended,
+  ...tseslint.configs.recommended,
+  {
+    plugins: { react, foo },
+    languageOptions: {
+      globals: { ...globals.browser, ...globals.node, process: "readonly" },
+    },
+    rules: {
+      eqeqeq: "error",
+      "react/jsx-key": "error",
+      "@typescript-eslint/no-explicit-any": "warn",
+      "no-restricted-syntax": ["error", "WithStatement"],
+      "no-magic-numbers": ["error", { ignore: [getIgnored()] }],
+    },
+  },
+  {
+    files: ["**/*.test.ts"],
+    rules: { "vitest/no-focused-tests": "error" },
+  },
+);
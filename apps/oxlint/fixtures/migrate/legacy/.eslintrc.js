@@ -0,0 +1,32 @@
+const restrictedSyntax = ["error", "WithStatement"];
+
+module.exports = {
+  root: true,
+  // Shared configs can't be migrated
+  extends: ["eslint:recommended", "plugin:react/recommended"],
+  plugins: ["react", "@typescript-eslint", "foo"],
+  parser: "@typescript-eslint/parser",
+  env: { browser: true },
+  globals: { process: "readonly" },
+  settings: {
+    react: { version: "18.0" },
+    "import/resolver": { typescript: true },
+  },
+  ignorePatterns: ["dist/**"],
+  rules: {
+    eqeqeq: ["error", "always"],
+    "foo/bar": "error",
+    "foo/baz": "off",
+    "no-console": "warn",
+    "no-restricted-syntax": restrictedSyntax,
+    "react/jsx-key": 2,
+    "@typescript-eslint/no-explicit-any": "off",
+  },
+  overrides: [
+    {
+      files: ["**/*.test.ts"],
+      env: { node: true },
+      rules: { "vitest/no-focused-tests": "error" },
+    },
+  ],
+};
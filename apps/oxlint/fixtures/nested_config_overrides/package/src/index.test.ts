@@ -0,0 +1,5 @@
+debugger;
+
+if (process.env.CI) {
+  test("ci", () => {});
+}
@@ -0,0 +1,6 @@
+import { format } from './utils/format.js';
+import * as math from './utils/math.js';
+
+export function main() {
+  return format(math.sum(1, 2));
+}
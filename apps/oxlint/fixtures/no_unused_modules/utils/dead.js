@@ -0,0 +1 @@
+console.log('not imported by any module');
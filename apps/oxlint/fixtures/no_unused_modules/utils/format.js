@@ -0,0 +1,7 @@
+export function format(value) {
+  return String(value);
+}
+
+export function unusedFormat(value) {
+  return JSON.stringify(value);
+}
@@ -0,0 +1 @@
+try {} catch {}
@@ -0,0 +1,4 @@
+debugger;
+const a = 1.0 + foo(
+  2.50,
+);
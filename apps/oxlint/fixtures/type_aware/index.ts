@@ -0,0 +1,5 @@
+declare function save(): Promise<void>;
+
+save();
+await save();
+void save();
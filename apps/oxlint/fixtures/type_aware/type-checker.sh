@@ -0,0 +1,6 @@
+#!/bin/sh
+# A type checker for tests, which says that every expression is a promise.
+while read -r request; do
+  id=$(echo "$request" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  echo "{\"id\":$id,\"type\":{\"text\":\"Promise<void>\",\"flags\":[\"promiseLike\"]}}"
+done
@@ -0,0 +1,4 @@
+function foo() {
+  debugger;
+  return "🐛"; debugger;
+}
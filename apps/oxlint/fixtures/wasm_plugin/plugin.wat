@@ -0,0 +1,129 @@
+;; A plugin with a `no-debugger` rule, which implements the ABI of WASM plugins by hand.
+;; Compiled to `plugin.wasm` with `wat2wasm plugin.wat`.
+(module
+  (memory (export "memory") 1)
+
+  ;; Start of the buffer of the input and output
+  (global $heap i32 (i32.const 1024))
+  ;; End of the last number read by `$read_number`
+  (global $pos (mut i32) (i32.const 0))
+
+  (data (i32.const 0) "{\"name\":\"wasm-plugin\",\"rules\":[\"no-debugger\"]}")
+  (data (i32.const 64) "\"type\":\"DebuggerStatement\",\"start\":")
+  (data (i32.const 128) "{\"ruleIndex\":0,\"message\":\"Unexpected Debugger Statement\",\"loc\":{\"start\":")
+  (data (i32.const 224) ",\"end\":")
+  (data (i32.const 240) "},\"fixes\":[{\"start\":")
+  (data (i32.const 272) ",\"text\":\"\"}]}")
+
+  (func (export "oxlint_abi_version") (result i32)
+    (i32.const 1))
+
+  (func (export "oxlint_plugin") (result i64)
+    (call $string (i32.const 0) (i32.const 46)))
+
+  ;; The output is written after the input, so reserve enough memory for both
+  (func (export "oxlint_alloc") (param $len i32) (result i32)
+    (local $needed i32)
+    (local.set $needed
+      (i32.add (global.get $heap) (i32.add (i32.mul (local.get $len) (i32.const 6)) (i32.const 1024))))
+    (if (i32.gt_u (local.get $needed) (i32.mul (memory.size) (i32.const 65536)))
+      (then
+        (drop (memory.grow
+          (i32.sub
+            (i32.div_u (i32.add (local.get $needed) (i32.const 65535)) (i32.const 65536))
+            (memory.size))))))
+    (global.get $heap))
+
+  ;; Report every `DebuggerStatement` in the JSON of the AST, with a fix which removes it
+  (func (export "oxlint_lint") (param $ptr i32) (param $len i32) (result i64)
+    (local $i i32)
+    (local $end i32)
+    (local $out i32)
+    (local $out_start i32)
+    (local $start i32)
+    (local $stmt_end i32)
+    (local.set $i (local.get $ptr))
+    (local.set $end (i32.sub (i32.add (local.get $ptr) (local.get $len)) (i32.const 35)))
+    (local.set $out_start (i32.add (local.get $ptr) (local.get $len)))
+    (local.set $out (local.get $out_start))
+    (i32.store8 (local.get $out) (i32.const 91)) ;; `[`
+    (local.set $out (i32.add (local.get $out) (i32.const 1)))
+    (block $done
+      (loop $scan
+        (br_if $done (i32.gt_s (local.get $i) (local.get $end)))
+        (if (call $matches (local.get $i) (i32.const 64) (i32.const 35))
+          (then
+            (local.set $start (call $read_number (i32.add (local.get $i) (i32.const 35))))
+            (local.set $stmt_end (call $read_number (i32.add (global.get $pos) (i32.const 7))))
+            (local.set $i (global.get $pos))
+            (if (i32.ne (local.get $out) (i32.add (local.get $out_start) (i32.const 1)))
+              (then
+                (i32.store8 (local.get $out) (i32.const 44)) ;; `,`
+                (local.set $out (i32.add (local.get $out) (i32.const 1)))))
+            (local.set $out (call $copy (local.get $out) (i32.const 128) (i32.const 72)))
+            (local.set $out (call $write_number (local.get $out) (local.get $start)))
+            (local.set $out (call $copy (local.get $out) (i32.const 224) (i32.const 7)))
+            (local.set $out (call $write_number (local.get $out) (local.get $stmt_end)))
+            (local.set $out (call $copy (local.get $out) (i32.const 240) (i32.const 20)))
+            (local.set $out (call $write_number (local.get $out) (local.get $start)))
+            (local.set $out (call $copy (local.get $out) (i32.const 224) (i32.const 7)))
+            (local.set $out (call $write_number (local.get $out) (local.get $stmt_end)))
+            (local.set $out (call $copy (local.get $out) (i32.const 272) (i32.const 13))))
+          (else
+            (local.set $i (i32.add (local.get $i) (i32.const 1)))))
+        (br $scan)))
+    (i32.store8 (local.get $out) (i32.const 93)) ;; `]`
+    (local.set $out (i32.add (local.get $out) (i32.const 1)))
+    (call $string (local.get $out_start) (i32.sub (local.get $out) (local.get $out_start))))
+
+  ;; Pack a pointer and a length into the return value of a function returning a string
+  (func $string (param $ptr i32) (param $len i32) (result i64)
+    (i64.or
+      (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+      (i64.extend_i32_u (local.get $len))))
+
+  (func $matches (param $a i32) (param $b i32) (param $len i32) (result i32)
+    (block $no
+      (loop $next
+        (if (i32.eqz (local.get $len)) (then (return (i32.const 1))))
+        (br_if $no (i32.ne (i32.load8_u (local.get $a)) (i32.load8_u (local.get $b))))
+        (local.set $a (i32.add (local.get $a) (i32.const 1)))
+        (local.set $b (i32.add (local.get $b) (i32.const 1)))
+        (local.set $len (i32.sub (local.get $len) (i32.const 1)))
+        (br $next)))
+    (i32.const 0))
+
+  ;; Copy `len` bytes from `src` to `dst`, and return the end of the copy
+  (func $copy (param $dst i32) (param $src i32) (param $len i32) (result i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $len)))
+        (i32.store8 (local.get $dst) (i32.load8_u (local.get $src)))
+        (local.set $dst (i32.add (local.get $dst) (i32.const 1)))
+        (local.set $src (i32.add (local.get $src) (i32.const 1)))
+        (local.set $len (i32.sub (local.get $len) (i32.const 1)))
+        (br $next)))
+    (local.get $dst))
+
+  ;; Read the decimal number at `ptr`, and set `$pos` to its end
+  (func $read_number (param $ptr i32) (result i32)
+    (local $n i32)
+    (local $digit i32)
+    (block $done
+      (loop $next
+        (local.set $digit (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)))
+        (br_if $done (i32.gt_u (local.get $digit) (i32.const 9)))
+        (local.set $n (i32.add (i32.mul (local.get $n) (i32.const 10)) (local.get $digit)))
+        (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+        (br $next)))
+    (global.set $pos (local.get $ptr))
+    (local.get $n))
+
+  ;; Write `n` in decimal at `dst`, and return the end of it
+  (func $write_number (param $dst i32) (param $n i32) (result i32)
+    (if (i32.ge_u (local.get $n) (i32.const 10))
+      (then
+        (local.set $dst (call $write_number (local.get $dst) (i32.div_u (local.get $n) (i32.const 10))))))
+    (i32.store8 (local.get $dst) (i32.add (i32.const 48) (i32.rem_u (local.get $n) (i32.const 10))))
+    (i32.add (local.get $dst) (i32.const 1)))
+)
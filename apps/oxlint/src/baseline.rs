@@ -0,0 +1,368 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+    thread::{self, JoinHandle},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{
+    DiagnosticSender, DiagnosticTuple, Error,
+    reporter::{self, ProjectRoot},
+};
+
+use crate::{
+    cli::{BaselineOptions, CliRunResult},
+    output_formatter::OutputFormat,
+};
+
+/// Default path of the baseline file, used by `--generate-baseline` if `--baseline` is not provided.
+pub const DEFAULT_BASELINE_FILE: &str = "oxlint-baseline.json";
+
+/// Version of the baseline format. Baselines of other versions must be generated again.
+///
+/// 1. Paths and fingerprints were relative to the working directory, and fingerprints didn't
+///    include the path.
+/// 2. Paths and fingerprints are relative to the project root.
+const BASELINE_VERSION: u32 = 2;
+
+/// Violations which existed when the baseline was generated.
+///
+/// Violations are identified by file, rule and a fingerprint of the path, message and offending code.
+/// The fingerprint does not include the position of the violation, so violations don't need to be
+/// added to the baseline again when unrelated code is added above them.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    /// See [`BASELINE_VERSION`]. Missing in baselines of version 1.
+    #[serde(default = "legacy_version")]
+    version: u32,
+    /// Violations grouped by path of the file, relative to the project root.
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self { version: BASELINE_VERSION, files: BTreeMap::default() }
+    }
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub rule: String,
+    pub fingerprint: String,
+    /// Number of identical violations in the file.
+    pub count: usize,
+}
+
+impl Baseline {
+    /// # Errors
+    ///
+    /// Returns an error if the baseline file cannot be read or is invalid.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read baseline file {}: {err}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&content)
+            .map_err(|err| format!("Failed to parse baseline file {}: {err}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "Baseline file {} was generated by another version of oxlint, run with `--generate-baseline` to update it",
+                path.display()
+            ));
+        }
+        Ok(baseline)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the baseline file cannot be written.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, content + "\n")
+    }
+
+    /// Total number of violations in the baseline.
+    pub fn len(&self) -> usize {
+        self.files.values().flatten().map(|entry| entry.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over all entries, with the path of the file they are in.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &BaselineEntry)> {
+        self.files
+            .iter()
+            .flat_map(|(path, entries)| entries.iter().map(move |entry| (path.as_str(), entry)))
+    }
+
+    /// Only keep entries for files for which `f` returns `true`.
+    pub fn retain_files(&mut self, mut f: impl FnMut(&str) -> bool) {
+        self.files.retain(|path, _| f(path));
+    }
+
+    fn add(&mut self, path: &str, rule: String, fingerprint: String) {
+        let entries = self.files.entry(path.to_string()).or_default();
+        if let Some(entry) =
+            entries.iter_mut().find(|entry| entry.rule == rule && entry.fingerprint == fingerprint)
+        {
+            entry.count += 1;
+        } else {
+            entries.push(BaselineEntry { rule, fingerprint, count: 1 });
+            entries.sort_unstable();
+        }
+    }
+
+    /// Remove one matching violation. Returns `false` if there is none.
+    fn take(&mut self, path: &str, rule: &str, fingerprint: &str) -> bool {
+        let Some(entries) = self.files.get_mut(path) else { return false };
+        let Some(index) =
+            entries.iter().position(|entry| entry.rule == rule && entry.fingerprint == fingerprint)
+        else {
+            return false;
+        };
+        entries[index].count -= 1;
+        if entries[index].count == 0 {
+            entries.remove(index);
+            if entries.is_empty() {
+                self.files.remove(path);
+            }
+        }
+        true
+    }
+}
+
+/// What to do with violations of lint rules.
+pub enum BaselineMode {
+    /// Record all violations, and don't report them.
+    Generate,
+    /// Don't report violations which are in the baseline.
+    Apply(Baseline),
+}
+
+/// Result of [`filter_diagnostics`].
+pub struct BaselineResult {
+    /// All violations which were found.
+    pub found: Baseline,
+    /// Violations from the baseline which were not found.
+    pub unmatched: Baseline,
+}
+
+/// The baseline file of `--baseline` or `--generate-baseline`, relative to `cwd`, and what to do
+/// with the violations found. Returns [`None`] if neither option is passed.
+///
+/// # Errors
+///
+/// Returns an error if the baseline file can't be read.
+pub fn load(
+    options: &BaselineOptions,
+    cwd: &Path,
+) -> Result<Option<(PathBuf, BaselineMode)>, (CliRunResult, String)> {
+    if !options.generate_baseline && options.baseline.is_none() {
+        return Ok(None);
+    }
+    let path = cwd.join(options.baseline.as_deref().unwrap_or(Path::new(DEFAULT_BASELINE_FILE)));
+    if options.generate_baseline {
+        return Ok(Some((path, BaselineMode::Generate)));
+    }
+    match Baseline::from_file(&path) {
+        Ok(baseline) => Ok(Some((path, BaselineMode::Apply(baseline)))),
+        Err(err) => Err((CliRunResult::InvalidOptionBaseline, format!("{err}\n"))),
+    }
+}
+
+/// Write the violations found by `--generate-baseline` to `path`, or describe the violations of
+/// the baseline which no longer occur in the `linted` files.
+pub fn report(
+    path: &Path,
+    result: BaselineResult,
+    generate: bool,
+    format: &OutputFormat,
+    linted: &[Arc<OsStr>],
+    project: &ProjectRoot,
+) -> Option<String> {
+    let BaselineResult { found, mut unmatched } = result;
+    if generate {
+        return Some(match found.write(path) {
+            Ok(()) => {
+                format!("Baseline with {} violations written to {}\n", found.len(), path.display())
+            }
+            Err(err) => format!("Failed to write baseline file {}: {err}\n", path.display()),
+        });
+    }
+    if *format != OutputFormat::Default {
+        return None;
+    }
+    // Entries of files which were not linted this time can't be checked
+    let linted = linted
+        .iter()
+        .map(|path| relative_path(Path::new(path), &project.root))
+        .collect::<FxHashSet<_>>();
+    unmatched.retain_files(|path| linted.contains(path) || !project.root.join(path).exists());
+    if unmatched.is_empty() {
+        return None;
+    }
+    let mut message = format!(
+        "{} baseline violations no longer occur, run with `--generate-baseline` to update the baseline:\n",
+        unmatched.len()
+    );
+    for (path, entry) in unmatched.entries() {
+        let _ = writeln!(message, "  {path}: {}", entry.rule);
+    }
+    Some(message)
+}
+
+/// Forward diagnostics to `tx_error`, except for violations suppressed by the baseline.
+///
+/// Diagnostics which are not violations of a lint rule (e.g. parse errors) are always reported.
+///
+/// Returns the sender to pass to the linter, and a handle which resolves to the result once
+/// the returned sender has been dropped.
+pub fn filter_diagnostics(
+    tx_error: DiagnosticSender,
+    mode: BaselineMode,
+    project: ProjectRoot,
+) -> (DiagnosticSender, JoinHandle<BaselineResult>) {
+    let (sender, receiver) = mpsc::channel::<DiagnosticTuple>();
+    let handle = thread::spawn(move || {
+        let mut found = Baseline::default();
+        let (generate, mut unmatched) = match mode {
+            BaselineMode::Generate => (true, Baseline::default()),
+            BaselineMode::Apply(baseline) => (false, baseline),
+        };
+
+        for (path, diagnostics) in receiver {
+            let relative_path = relative_path(&path, &project.root);
+            let diagnostics = diagnostics
+                .into_iter()
+                .filter(|error| {
+                    let Some((rule, fingerprint)) = fingerprint(error, &project) else {
+                        return true;
+                    };
+                    found.add(&relative_path, rule.clone(), fingerprint.clone());
+                    !(generate || unmatched.take(&relative_path, &rule, &fingerprint))
+                })
+                .collect::<Vec<_>>();
+            if !diagnostics.is_empty() {
+                // Receiver is only dropped once all diagnostics have been reported
+                let _ = tx_error.send((path, diagnostics));
+            }
+        }
+
+        BaselineResult { found, unmatched }
+    });
+    (sender, handle)
+}
+
+/// Path of `path` relative to `dir`, with `/` separators, so baselines can be shared across platforms.
+pub fn relative_path(path: &Path, dir: &Path) -> String {
+    let path = path.strip_prefix(dir).unwrap_or(path);
+    path.to_string_lossy().cow_replace('\\', "/").into_owned()
+}
+
+/// Returns rule name and fingerprint of a violation, or `None` if diagnostic is not from a rule.
+fn fingerprint(error: &Error, project: &ProjectRoot) -> Option<(String, String)> {
+    let rule = error.code()?.to_string();
+    Some((rule, reporter::fingerprint(error, project)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_diagnostics::{DiagnosticService, NamedSource, OxcDiagnostic, reporter::*};
+    use oxc_span::Span;
+
+    use super::{Baseline, BaselineMode, filter_diagnostics};
+
+    #[derive(Default)]
+    struct CountingReporter;
+
+    impl DiagnosticReporter for CountingReporter {
+        fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+            None
+        }
+
+        fn render_error(&mut self, _: oxc_diagnostics::Error) -> Option<String> {
+            None
+        }
+    }
+
+    fn debugger(source_text: &str, offset: u32) -> oxc_diagnostics::Error {
+        OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(Span::new(offset, offset + 8))
+            .with_source_code(NamedSource::new("test.js", source_text.to_string()))
+    }
+
+    fn run(
+        mode: BaselineMode,
+        diagnostics: Vec<oxc_diagnostics::Error>,
+    ) -> (usize, super::BaselineResult) {
+        let (mut service, tx_error) = DiagnosticService::new(Box::new(CountingReporter));
+        let project = ProjectRoot::new(PathBuf::from("/project"), PathBuf::from("/project"));
+        let (sender, handle) = filter_diagnostics(tx_error, mode, project);
+        sender.send((PathBuf::from("/project/src/test.js"), diagnostics)).unwrap();
+        drop(sender);
+        let result = service.run(&mut Vec::new());
+        (result.warnings_count(), handle.join().unwrap())
+    }
+
+    #[test]
+    fn generate_and_apply() {
+        let source_text = "debugger;\nfoo();\ndebugger;";
+        let (reported, result) =
+            run(BaselineMode::Generate, vec![debugger(source_text, 0), debugger(source_text, 17)]);
+        assert_eq!(reported, 0);
+        assert_eq!(result.found.len(), 2);
+        let entries = result.found.entries().collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, "src/test.js");
+        assert_eq!(entries[0].1.rule, "eslint(no-debugger)");
+        assert_eq!(entries[0].1.count, 2);
+
+        // Moving existing violations does not report them, but new ones are
+        let source_text = "foo();\ndebugger;\ndebugger;\ndebugger;";
+        let diagnostics =
+            vec![debugger(source_text, 7), debugger(source_text, 17), debugger(source_text, 27)];
+        let (reported, result) = run(BaselineMode::Apply(result.found), diagnostics);
+        assert_eq!(reported, 1);
+        assert!(result.unmatched.is_empty());
+    }
+
+    #[test]
+    fn unmatched() {
+        let source_text = "debugger;";
+        let (_, generated) = run(BaselineMode::Generate, vec![debugger(source_text, 0)]);
+        let (reported, result) = run(BaselineMode::Apply(generated.found), vec![]);
+        assert_eq!(reported, 0);
+        assert_eq!(result.unmatched.len(), 1);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let source_text = "debugger;";
+        let (_, generated) = run(BaselineMode::Generate, vec![debugger(source_text, 0)]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        generated.found.write(&path).unwrap();
+        let baseline = Baseline::from_file(&path).unwrap();
+        assert_eq!(baseline.len(), 1);
+        assert!(Baseline::from_file(Path::new("does-not-exist.json")).is_err());
+
+        // Baselines of version 1 have no version
+        std::fs::write(&path, r#"{ "files": {} }"#).unwrap();
+        let err = Baseline::from_file(&path).unwrap_err();
+        assert!(err.contains("--generate-baseline"));
+    }
+}
@@ -0,0 +1,570 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, mpsc},
+    thread::{self, JoinHandle},
+    time::UNIX_EPOCH,
+};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticTuple};
+use oxc_linter::rules::RULES;
+
+use crate::{baseline::relative_path, cli::CacheOptions};
+
+/// File name of the cache, used when `--cache-location` is not provided or points to a directory.
+pub const DEFAULT_CACHE_FILE: &str = ".oxlintcache";
+
+/// How to detect whether a file has changed since it was last linted.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum CacheStrategy {
+    /// Compare file size and modification time.
+    #[default]
+    Metadata,
+    /// Compare a hash of the file's contents.
+    /// Slower than `Metadata`, but survives operations which touch files without changing them
+    /// (e.g. `git checkout` or restoring CI caches).
+    Content,
+}
+
+impl FromStr for CacheStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "metadata" => Ok(Self::Metadata),
+            "content" => Ok(Self::Content),
+            _ => Err(format!("'{s}' is not a known cache strategy")),
+        }
+    }
+}
+
+/// Storage of the serialized [`LintCache`].
+///
+/// The key identifies the linter and its configuration, so backends shared by several projects or
+/// branches, e.g. the remote cache of a CI, keep a separate cache for each configuration.
+pub trait CacheBackend: Send + Sync {
+    /// Read the cache stored under `key`, `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache exists but cannot be read.
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>>;
+
+    /// Store the cache under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache cannot be written.
+    fn put(&self, key: &str, content: &[u8]) -> io::Result<()>;
+}
+
+/// Stores the cache in a file on disk. This is the default backend.
+///
+/// Only one cache is stored, the one of the last run. It is discarded when the key changes.
+#[derive(Debug)]
+pub struct LocalCacheBackend {
+    path: PathBuf,
+}
+
+impl LocalCacheBackend {
+    /// `location` is either a file or a directory, in which the cache is stored in
+    /// [`DEFAULT_CACHE_FILE`].
+    pub fn new(location: &Path) -> Self {
+        let path = if location.is_dir() {
+            location.join(DEFAULT_CACHE_FILE)
+        } else {
+            location.to_path_buf()
+        };
+        Self { path }
+    }
+}
+
+impl CacheBackend for LocalCacheBackend {
+    fn get(&self, _key: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(&self.path) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, _key: &str, content: &[u8]) -> io::Result<()> {
+        fs::write(&self.path, content)
+    }
+}
+
+/// Stores the cache on an HTTP server, to share it between the machines of a CI.
+///
+/// The cache is read with `GET <url>/<key>` and written with `PUT <url>/<key>`. A `404` response
+/// means there is no cache yet. If the `OXLINT_CACHE_TOKEN` environment variable is set, it is sent
+/// as a bearer token. Proxies are read from the usual `HTTPS_PROXY` and `HTTP_PROXY` variables.
+///
+/// Machines usually check out files at different times, so the remote cache is always used with
+/// [`CacheStrategy::Content`].
+#[cfg(feature = "remote_cache")]
+pub struct HttpCacheBackend {
+    url: String,
+    token: Option<String>,
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "remote_cache")]
+impl HttpCacheBackend {
+    pub fn new(url: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(std::time::Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        let token = std::env::var("OXLINT_CACHE_TOKEN").ok().filter(|token| !token.is_empty());
+        Self { url: url.trim_end_matches('/').to_string(), token, agent }
+    }
+
+    /// Whether `location` of the cache is the URL of a remote cache.
+    pub fn is_url(location: &str) -> bool {
+        location.starts_with("http://") || location.starts_with("https://")
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{key}", self.url)
+    }
+
+    fn authorization(&self) -> Option<String> {
+        self.token.as_ref().map(|token| format!("Bearer {token}"))
+    }
+}
+
+#[cfg(feature = "remote_cache")]
+impl CacheBackend for HttpCacheBackend {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        let mut request = self.agent.get(self.url(key));
+        if let Some(authorization) = self.authorization() {
+            request = request.header("Authorization", authorization);
+        }
+        let mut response = request.call().map_err(io::Error::other)?;
+        match response.status().as_u16() {
+            404 => Ok(None),
+            200..=299 => response.body_mut().read_to_vec().map(Some).map_err(io::Error::other),
+            status => Err(io::Error::other(format!("GET {} returned {status}", self.url(key)))),
+        }
+    }
+
+    fn put(&self, key: &str, content: &[u8]) -> io::Result<()> {
+        let mut request = self.agent.put(self.url(key));
+        if let Some(authorization) = self.authorization() {
+            request = request.header("Authorization", authorization);
+        }
+        let response = request.send(content).map_err(io::Error::other)?;
+        match response.status().as_u16() {
+            200..=299 => Ok(()),
+            status => Err(io::Error::other(format!("PUT {} returned {status}", self.url(key)))),
+        }
+    }
+}
+
+/// Cache of files which produced no diagnostics.
+///
+/// Only files which were linted without any diagnostics are stored. Those files are skipped on
+/// subsequent runs, as long as they have not changed, and the linter and its configuration are
+/// the same. Files which produced diagnostics are always linted again, so their diagnostics are
+/// reported on every run.
+///
+/// Files are stored with paths relative to the working directory, so the cache can be shared by
+/// machines which check out the project in different directories.
+pub struct LintCache {
+    backend: Box<dyn CacheBackend>,
+    cwd: PathBuf,
+    strategy: CacheStrategy,
+    key: String,
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    key: String,
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheEntry {
+    size: u64,
+    /// Modification time in nanoseconds since the Unix epoch.
+    mtime: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+}
+
+impl LintCache {
+    /// Load the cache of `--cache`, from `--cache-location` relative to `cwd`, see [`LintCache::load`].
+    pub fn open(options: &CacheOptions, cwd: &Path, config: &str) -> Self {
+        let strategy = options.cache_strategy;
+        let (backend, strategy): (Box<dyn CacheBackend>, _) = match &options.cache_location {
+            // Modification times differ between machines, so only contents can be compared
+            #[cfg(feature = "remote_cache")]
+            Some(location) if location.to_str().is_some_and(HttpCacheBackend::is_url) => (
+                Box::new(HttpCacheBackend::new(&location.to_string_lossy())),
+                CacheStrategy::Content,
+            ),
+            Some(location) => (Box::new(LocalCacheBackend::new(&cwd.join(location))), strategy),
+            None => (Box::new(LocalCacheBackend::new(cwd)), strategy),
+        };
+        Self::load(backend, cwd, strategy, config)
+    }
+
+    /// Load the cache from `backend`. A cache which can't be read is treated as empty.
+    ///
+    /// `config` is a fingerprint of everything that affects lint results, other than the contents
+    /// of the files being linted. Paths in it are made relative to `cwd`, so machines which check
+    /// out the project in different directories share the cache. If it differs from the one the
+    /// cache was created with, or the version of the linter has changed, the cache is discarded.
+    pub fn load(
+        backend: Box<dyn CacheBackend>,
+        cwd: &Path,
+        strategy: CacheStrategy,
+        config: &str,
+    ) -> Self {
+        let key = cache_key(config, cwd);
+
+        let files = backend
+            .get(&key)
+            .ok()
+            .flatten()
+            .and_then(|content| serde_json::from_slice::<CacheFile>(&content).ok())
+            .filter(|cache| cache.key == key)
+            .map(|cache| cache.files)
+            .unwrap_or_default();
+
+        Self { backend, cwd: cwd.to_path_buf(), strategy, key, files }
+    }
+
+    /// Returns `true` if the file at `path` is unchanged since it was last linted without
+    /// any diagnostics.
+    pub fn is_fresh(&self, path: &Path) -> bool {
+        let Some(entry) = self.files.get(&relative_path(path, &self.cwd)) else {
+            return false;
+        };
+        let Some(current) = CacheEntry::new(path, self.strategy) else {
+            return false;
+        };
+        match self.strategy {
+            CacheStrategy::Metadata => entry.size == current.size && entry.mtime == current.mtime,
+            CacheStrategy::Content => {
+                entry.size == current.size && entry.hash.is_some() && entry.hash == current.hash
+            }
+        }
+    }
+
+    /// The files of `paths` which changed since they were last linted without any diagnostics.
+    pub fn stale_paths(&self, paths: Vec<Arc<OsStr>>) -> Vec<Arc<OsStr>> {
+        paths.into_par_iter().filter(|path| !self.is_fresh(Path::new(path))).collect()
+    }
+
+    /// Record the result of linting `linted` files.
+    ///
+    /// Files in `files_with_diagnostics` are removed from the cache, all others are stored.
+    pub fn update<'a>(
+        &mut self,
+        linted: impl IntoIterator<Item = &'a Path>,
+        files_with_diagnostics: &FxHashSet<PathBuf>,
+    ) {
+        for path in linted {
+            let key = relative_path(path, &self.cwd);
+            let entry = if files_with_diagnostics.contains(path) {
+                None
+            } else {
+                CacheEntry::new(path, self.strategy)
+            };
+            match entry {
+                Some(entry) => self.files.insert(key, entry),
+                None => self.files.remove(&key),
+            };
+        }
+    }
+
+    /// Record the result of linting `linted` files, with the files with diagnostics `tracker`
+    /// resolves to, see [`track_diagnostics`], and write the cache to the backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache cannot be written.
+    pub fn finish(
+        mut self,
+        linted: &[Arc<OsStr>],
+        tracker: JoinHandle<FxHashSet<PathBuf>>,
+    ) -> io::Result<()> {
+        let files_with_diagnostics = tracker.join().unwrap();
+        self.update(linted.iter().map(Path::new), &files_with_diagnostics);
+        self.save()
+    }
+
+    /// Write the cache to the backend, dropping entries for files which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache cannot be written.
+    pub fn save(mut self) -> io::Result<()> {
+        let cwd = &self.cwd;
+        self.files.retain(|path, _| cwd.join(path).is_file());
+        let cache = CacheFile { key: self.key, files: self.files };
+        let content = serde_json::to_vec(&cache).map_err(io::Error::other)?;
+        self.backend.put(&cache.key, &content)
+    }
+}
+
+impl CacheEntry {
+    fn new(path: &Path, strategy: CacheStrategy) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX));
+        let hash = match strategy {
+            CacheStrategy::Metadata => None,
+            CacheStrategy::Content => Some(hash_to_string(&fs::read(path).ok()?)),
+        };
+        Some(Self { size: metadata.len(), mtime, hash })
+    }
+}
+
+/// Combine the configuration fingerprint with the version of the linter, so that the cache is
+/// invalidated when oxlint is upgraded, or rule implementations otherwise change.
+///
+/// Keys are shared between machines by remote caches, so they must only depend on the version
+/// and the configuration, and be hashed the same way on every platform, which rules out
+/// [`std::hash::DefaultHasher`].
+fn cache_key(config: &str, cwd: &Path) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(option_env!("OXC_VERSION").unwrap_or_default());
+    hasher.update([0]);
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    for rule in RULES.iter() {
+        hasher.update([0]);
+        hasher.update(rule.plugin_name());
+        hasher.update([0]);
+        hasher.update(rule.name());
+    }
+    // Development builds share a version number, so also take the binary itself into account.
+    // Released builds have an `OXC_VERSION`, so their keys are the same on all machines.
+    if option_env!("OXC_VERSION").is_none() {
+        if let Some(metadata) = std::env::current_exe().ok().and_then(|exe| fs::metadata(exe).ok())
+        {
+            hasher.update(metadata.len().to_le_bytes());
+            if let Ok(mtime) = metadata.modified() {
+                hasher.update(format!("{mtime:?}"));
+            }
+        }
+    }
+    hasher.update([0]);
+    hasher.update(normalize_paths(config, cwd));
+    format!("{:x}", hasher.finalize())
+}
+
+/// Replace the working directory in `config` with `.`, so it does not depend on where the
+/// project is checked out. Paths appear in `config` as written by both `Display` and `Debug`.
+fn normalize_paths(config: &str, cwd: &Path) -> String {
+    let display = cwd.to_string_lossy();
+    let debug = format!("{cwd:?}");
+    let debug = debug.trim_matches('"');
+    let config = if debug == display { config.to_string() } else { config.replace(debug, ".") };
+    config.replace(&*display, ".")
+}
+
+fn hash_to_string(content: &[u8]) -> String {
+    format!("{:x}", Sha1::digest(content))
+}
+
+/// Forward diagnostics to `tx_error`, recording which files reported any.
+///
+/// Returns the sender to pass to the linter, and a handle which resolves to the paths of files
+/// with diagnostics once the returned sender has been dropped.
+pub fn track_diagnostics(
+    tx_error: DiagnosticSender,
+) -> (DiagnosticSender, JoinHandle<FxHashSet<PathBuf>>) {
+    let (sender, receiver) = mpsc::channel::<DiagnosticTuple>();
+    let handle = thread::spawn(move || {
+        let mut files = FxHashSet::default();
+        for (path, diagnostics) in receiver {
+            files.insert(path.clone());
+            // Receiver is only dropped once all diagnostics have been reported
+            let _ = tx_error.send((path, diagnostics));
+        }
+        files
+    });
+    (sender, handle)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use rustc_hash::FxHashSet;
+
+    use super::{CacheStrategy, DEFAULT_CACHE_FILE, LintCache, LocalCacheBackend, cache_key};
+
+    fn load(location: &Path, cwd: &Path, strategy: CacheStrategy, config: &str) -> LintCache {
+        LintCache::load(Box::new(LocalCacheBackend::new(location)), cwd, strategy, config)
+    }
+
+    #[test]
+    fn cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let clean = dir.path().join("clean.js");
+        let dirty = dir.path().join("dirty.js");
+        fs::write(&clean, "let a = 1;").unwrap();
+        fs::write(&dirty, "debugger;").unwrap();
+
+        for strategy in [CacheStrategy::Metadata, CacheStrategy::Content] {
+            let mut cache = load(dir.path(), dir.path(), strategy, "config");
+            assert!(!cache.is_fresh(&clean));
+            assert!(!cache.is_fresh(&dirty));
+
+            let files_with_diagnostics = FxHashSet::from_iter([dirty.clone()]);
+            cache.update([clean.as_path(), dirty.as_path()], &files_with_diagnostics);
+            cache.save().unwrap();
+            assert!(dir.path().join(DEFAULT_CACHE_FILE).is_file());
+
+            let cache = load(dir.path(), dir.path(), strategy, "config");
+            assert!(cache.is_fresh(&clean));
+            assert!(!cache.is_fresh(&dirty));
+
+            // Different configuration discards the cache
+            let cache = load(dir.path(), dir.path(), strategy, "other config");
+            assert!(!cache.is_fresh(&clean));
+
+            fs::remove_file(dir.path().join(DEFAULT_CACHE_FILE)).unwrap();
+        }
+    }
+
+    #[test]
+    fn content_strategy_detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("test.js");
+        let cache_file = dir.path().join("cache.json");
+        fs::write(&file, "let a = 1;").unwrap();
+
+        let mut cache = load(&cache_file, dir.path(), CacheStrategy::Content, "config");
+        cache.update([file.as_path()], &FxHashSet::default());
+        cache.save().unwrap();
+
+        fs::write(&file, "let b = 1;").unwrap();
+        let cache = load(&cache_file, dir.path(), CacheStrategy::Content, "config");
+        assert!(!cache.is_fresh(&file));
+    }
+
+    #[test]
+    fn shared_between_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_file = dir.path().join("cache.json");
+        let checkouts = [dir.path().join("a"), dir.path().join("b")];
+        for checkout in &checkouts {
+            fs::create_dir(checkout).unwrap();
+            fs::write(checkout.join("test.js"), "let a = 1;").unwrap();
+        }
+
+        let mut cache = load(&cache_file, &checkouts[0], CacheStrategy::Content, "config");
+        cache.update([checkouts[0].join("test.js").as_path()], &FxHashSet::default());
+        cache.save().unwrap();
+
+        let cache = load(&cache_file, &checkouts[1], CacheStrategy::Content, "config");
+        assert!(cache.is_fresh(&checkouts[1].join("test.js")));
+    }
+
+    #[test]
+    fn key_does_not_depend_on_checkout_directory() {
+        let config = |cwd: &Path| format!("{:?}", cwd.join(".oxlintrc.json"));
+        let (a, b) = (Path::new("/home/a/project"), Path::new("/home/b/project"));
+        assert_eq!(cache_key(&config(a), a), cache_key(&config(b), b));
+        assert_ne!(cache_key(&config(a), a), cache_key(&config(a), b));
+    }
+
+    /// Serve `GET` and `PUT` requests from memory until `requests` requests have been handled.
+    #[cfg(feature = "remote_cache")]
+    fn serve(requests: usize) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::{
+            io::{BufRead, BufReader, Read, Write},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/cache", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut store = rustc_hash::FxHashMap::<String, Vec<u8>>::default();
+            let mut log = vec![];
+            for stream in listener.incoming().take(requests) {
+                let mut stream = BufReader::new(stream.unwrap());
+                let mut request_line = String::new();
+                stream.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    stream.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                stream.read_exact(&mut body).unwrap();
+
+                let mut parts = request_line.split_whitespace();
+                let (method, path) = (parts.next().unwrap(), parts.next().unwrap().to_string());
+                log.push(format!("{method} {path}"));
+                let (status, body) = match method {
+                    "PUT" => {
+                        store.insert(path, body);
+                        ("200 OK", vec![])
+                    }
+                    _ => store
+                        .get(&path)
+                        .map_or(("404 Not Found", vec![]), |body| ("200 OK", body.clone())),
+                };
+                let stream = stream.get_mut();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                )
+                .unwrap();
+                stream.write_all(&body).unwrap();
+            }
+            log
+        });
+        (url, handle)
+    }
+
+    #[test]
+    #[cfg(feature = "remote_cache")]
+    fn http_backend() {
+        use super::{CacheBackend, HttpCacheBackend};
+
+        assert!(HttpCacheBackend::is_url("https://cache.example.com/oxlint"));
+        assert!(!HttpCacheBackend::is_url("node_modules/.cache/oxlint"));
+
+        let (url, server) = serve(3);
+        let backend = HttpCacheBackend::new(&format!("{url}/"));
+        assert_eq!(backend.get("key").unwrap(), None);
+        backend.put("key", b"content").unwrap();
+        assert_eq!(backend.get("key").unwrap().as_deref(), Some(&b"content"[..]));
+        assert_eq!(server.join().unwrap(), ["GET /cache/key", "PUT /cache/key", "GET /cache/key"]);
+    }
+
+    #[test]
+    fn strategy_from_str() {
+        assert_eq!("metadata".parse::<CacheStrategy>(), Ok(CacheStrategy::Metadata));
+        assert_eq!("content".parse::<CacheStrategy>(), Ok(CacheStrategy::Content));
+        assert!("mtime".parse::<CacheStrategy>().is_err());
+    }
+}
@@ -26,6 +26,23 @@ pub struct IgnoreOptions {
 
     #[bpaf(switch, hide_usage, help(NO_IGNORE_HELP))]
     pub no_ignore: bool,
+
+    /// Do not respect .gitignore files, only the file specified with `--ignore-path`
+    #[bpaf(switch, hide_usage)]
+    pub no_gitignore: bool,
+
+    /// Do not follow symbolic links when searching for files
+    #[bpaf(switch, hide_usage)]
+    pub no_follow_symlinks: bool,
+
+    /// Maximum depth of directories to descend into when searching for files,
+    /// where 0 only lints the given paths
+    #[bpaf(argument("DEPTH"), hide_usage)]
+    pub max_depth: Option<usize>,
+
+    /// Also lint the JavaScript and TypeScript code blocks of Markdown (.md) and MDX (.mdx) files
+    #[bpaf(switch, hide_usage)]
+    pub markdown: bool,
 }
 
 #[cfg(test)]
@@ -45,6 +62,10 @@ mod ignore_options {
         assert_eq!(options.ignore_path, OsString::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
+        assert!(!options.no_gitignore);
+        assert!(!options.no_follow_symlinks);
+        assert_eq!(options.max_depth, None);
+        assert!(!options.markdown);
     }
 
     #[test]
@@ -70,4 +91,15 @@ mod ignore_options {
         let options = get_ignore_options("--ignore-pattern ./test --ignore-pattern bar.js foo.js");
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
+
+    #[test]
+    fn walk_options() {
+        let options = get_ignore_options(
+            "--no-gitignore --no-follow-symlinks --max-depth 2 --markdown foo.js",
+        );
+        assert!(options.no_gitignore);
+        assert!(options.no_follow_symlinks);
+        assert_eq!(options.max_depth, Some(2));
+        assert!(options.markdown);
+    }
 }
@@ -1,9 +1,14 @@
-use std::path::PathBuf;
+use std::{ffi::OsString, path::PathBuf};
 
-use bpaf::Bpaf;
-use oxc_linter::{AllowWarnDeny, BuiltinLintPlugins, FixKind, LintPlugins};
+use bpaf::{Bpaf, OptionParser, Parser};
+use oxc_linter::{
+    AllowWarnDeny, BuiltinLintPlugins, FixKind, LintPlugins, OxlintRules, SuggestionChoices,
+};
 
-use crate::output_formatter::OutputFormat;
+use crate::{
+    cache::CacheStrategy,
+    output_formatter::{GroupBy, OutputFormat},
+};
 
 use super::{
     MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
@@ -12,7 +17,7 @@ use super::{
 };
 
 #[derive(Debug, Clone, Bpaf)]
-#[bpaf(options, version(VERSION))]
+#[bpaf(generate(lint_options))]
 pub struct LintCommand {
     #[bpaf(external)]
     pub basic_options: BasicOptions,
@@ -35,7 +40,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
-    /// list all the rules that are currently registered
+    /// list all the rules that are currently registered.
+    /// With `--format=json`, also print the fix capability, the default severity and a JSON
+    /// Schema of the options of each rule.
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
@@ -49,6 +56,15 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub workspace_options: WorkspaceOptions,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -61,10 +77,18 @@ impl LintCommand {
 
     fn set_rayon_threads(threads: Option<usize>) {
         if let Some(threads) = threads {
-            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
+            // The global pool can only be built once, so runs of the linter after the first
+            // one in a process use the pool of the first run
+            let _ = rayon::ThreadPoolBuilder::new().num_threads(threads).build_global();
         }
     }
 }
+
+/// Parser of the options of linting, without the subcommands of [`super::oxlint_command`].
+pub fn lint_command() -> OptionParser<LintCommand> {
+    lint_options().to_options().version(VERSION)
+}
+
 /// Basic Configuration
 #[derive(Debug, Clone, Bpaf)]
 pub struct BasicOptions {
@@ -76,11 +100,35 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
+    /// Enable or configure a rule, e.g. `--rule no-debugger=off` or
+    /// `--rule 'eqeqeq=["error","smart"]'`.
+    /// OPTIONS is a severity or an array of a severity and the options of the rule, like in the
+    /// `rules` of a configuration file, and takes precedence over them.
+    /// Can be repeated for different rules.
+    #[bpaf(
+        argument::<String>("NAME=OPTIONS"),
+        many,
+        parse(|values: Vec<String>| OxlintRules::parse(values)),
+        hide_usage
+    )]
+    pub rule: OxlintRules,
+
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
 
-    /// Initialize oxlint configuration with default values
+    /// Enable rules which need type information, like `typescript/no-floating-promises`.
+    /// Types are queried from the process started with `--type-checker`.
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
+
+    /// Command of the process which provides type information with `--type-aware`, e.g. a wrapper
+    /// around `tsserver` or `tsgo`. It is passed the `--tsconfig` option if provided.
+    #[bpaf(argument("COMMAND"), fallback(OsString::from("oxlint-type-checker")), hide_usage)]
+    pub type_checker: OsString,
+
+    /// Initialize oxlint configuration with plugins for the frameworks detected in `package.json`.
+    /// Asks which rule categories to enable when run in a terminal.
     #[bpaf(switch, hide_usage)]
     pub init: bool,
 }
@@ -144,6 +192,23 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions.
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Apply the suggestion CHOICE of RULE to its violations, whether or not suggestions are
+    /// fixed otherwise, e.g. `--apply-suggestions react/forward-ref-uses-ref:add-ref-parameter`.
+    /// CHOICE is the name of the suggestion or its index, starting at 0.
+    /// Can be repeated for different rules.
+    #[bpaf(
+        argument::<String>("RULE:CHOICE"),
+        many,
+        parse(|values: Vec<String>| SuggestionChoices::parse(values)),
+        hide_usage
+    )]
+    pub apply_suggestions: SuggestionChoices,
+
+    /// Replace the old names of renamed rules in the configuration files with their current
+    /// names, e.g. `unicorn/no-reduce` with `unicorn/no-array-reduce`.
+    #[bpaf(switch, hide_usage)]
+    pub fix_config: bool,
 }
 
 impl FixOptions {
@@ -169,7 +234,10 @@ impl FixOptions {
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix
+            || self.fix_suggestions
+            || self.fix_dangerously
+            || !self.apply_suggestions.is_empty()
     }
 }
 
@@ -188,15 +256,41 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Fail instead of printing a warning when a deprecated rule is enabled
+    #[bpaf(switch, hide_usage)]
+    pub no_deprecated: bool,
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Group diagnostics in the `default` output format. Possible values:
+    /// `file` (print each diagnostic), `rule` (print the number of diagnostics of each rule,
+    /// and the files they occur in)
+    #[bpaf(argument("GROUP"), fallback(GroupBy::File), hide_usage)]
+    pub group_by: GroupBy,
+
+    /// Show the lines changed by the fix of each fixable diagnostic, before and after fixing,
+    /// in the `default` output format. Without `--fix`, previews what `--fix` would change.
+    #[bpaf(switch, hide_usage)]
+    pub show_fixes: bool,
+
+    /// Print the time spent and the heap allocations made in the 10 slowest rules.
+    /// Rules run one after another on each file to be measured. Allocations are only
+    /// counted by builds with the `allocator` feature, e.g. the released binaries.
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Write the time spent and the heap allocations made in each rule to a JSON file,
+    /// e.g. to track performance regressions
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub timing_json: Option<PathBuf>,
 }
 
 /// Enable Plugins
@@ -275,6 +369,10 @@ pub struct EnablePlugins {
     /// Enable the vue plugin and detect vue usage problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub vue_plugin: OverrideToggle,
+
+    /// Enable the security plugin and detect potential security vulnerabilities
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub security_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -353,6 +451,7 @@ impl EnablePlugins {
         self.node_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::NODE, yes));
         self.regex_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::REGEX, yes));
         self.vue_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::VUE, yes));
+        self.security_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::SECURITY, yes));
 
         // Without this, jest plugins adapted to vitest will not be enabled.
         if self.vitest_plugin.is_enabled() && self.jest_plugin.is_not_set() {
@@ -391,6 +490,60 @@ pub struct InlineConfigOptions {
     pub report_unused_directives: ReportUnusedDirectives,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files that have changed since the last run.
+    /// Files without any diagnostics are remembered in a cache file, and skipped
+    /// on later runs until they, the configuration or oxlint itself change.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file or directory.
+    /// Defaults to `.oxlintcache` in the current working directory.
+    /// An `http://` or `https://` URL stores the cache on a server with `GET` and `PUT`
+    /// requests, to share it between machines, e.g. in CI. The `OXLINT_CACHE_TOKEN`
+    /// environment variable is sent as a bearer token. Always uses `--cache-strategy content`.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+
+    /// Strategy used to detect changed files. Possible values:
+    /// `metadata` (file size and modification time), `content` (hash of the file contents)
+    #[bpaf(argument("STRATEGY"), fallback(CacheStrategy::Metadata), hide_usage)]
+    pub cache_strategy: CacheStrategy,
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Path to a baseline file. Violations recorded in the baseline are not reported,
+    /// so only new violations fail the run.
+    #[bpaf(argument("FILE"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all current violations in the baseline file, instead of reporting them.
+    /// Writes to `oxlint-baseline.json` unless `--baseline` is provided.
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
+
+    /// Path to a file with the number of violations of each rule. The run only fails if the
+    /// number of violations of a rule increases. When it decreases, the file is updated.
+    /// The file is created if it does not exist. Lint the same files on every run.
+    #[bpaf(argument("FILE"), hide_usage)]
+    pub ratchet: Option<PathBuf>,
+}
+
+/// Monorepos
+#[derive(Debug, Clone, Bpaf)]
+pub struct WorkspaceOptions {
+    /// Only fail for problems in the given workspace package, problems in other packages are
+    /// still reported. Can be passed multiple times, e.g. to enforce rules package by package.
+    /// Packages are read from the `workspaces` of `package.json` or from `pnpm-workspace.yaml`
+    /// in the current working directory.
+    #[bpaf(argument("NAME"), many, hide_usage)]
+    pub fail_on_package: Vec<String>,
+}
+
 #[cfg(test)]
 mod plugins {
     use rustc_hash::FxHashSet;
@@ -466,15 +619,21 @@ mod warning_options {
         let options = get_warning_options("--max-warnings 10 .");
         assert_eq!(options.max_warnings, Some(10));
     }
+
+    #[test]
+    fn no_deprecated() {
+        assert!(!get_warning_options(".").no_deprecated);
+        assert!(get_warning_options("--no-deprecated .").no_deprecated);
+    }
 }
 
 #[cfg(test)]
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, OxlintRules, SuggestionChoices};
 
-    use super::{LintCommand, OutputFormat, lint_command};
+    use super::{GroupBy, LintCommand, OutputFormat, lint_command};
 
     fn get_lint_options(arg: &str) -> LintCommand {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -488,6 +647,32 @@ mod lint_options {
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
         assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert_eq!(options.output_options.group_by, GroupBy::File);
+    }
+
+    #[test]
+    fn group_by() {
+        let options = get_lint_options("--group-by rule .");
+        assert_eq!(options.output_options.group_by, GroupBy::Rule);
+        assert!(lint_command().run_inner(&["--group-by", "plugin", "."]).is_err());
+    }
+
+    #[test]
+    fn show_fixes() {
+        let options = get_lint_options(".");
+        assert!(!options.output_options.show_fixes);
+        let options = get_lint_options("--show-fixes .");
+        assert!(options.output_options.show_fixes);
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_lint_options(".");
+        assert!(!options.output_options.timing);
+        assert!(options.output_options.timing_json.is_none());
+        let options = get_lint_options("--timing --timing-json timing.json .");
+        assert!(options.output_options.timing);
+        assert_eq!(options.output_options.timing_json, Some(PathBuf::from("timing.json")));
     }
 
     #[test]
@@ -529,6 +714,51 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_config() {
+        let options = get_lint_options("--fix-config test.js");
+        assert!(options.fix_options.fix_config);
+        assert!(!options.fix_options.is_enabled());
+    }
+
+    #[test]
+    fn apply_suggestions() {
+        let options = get_lint_options("test.js");
+        assert!(options.fix_options.apply_suggestions.is_empty());
+        assert!(!options.fix_options.is_enabled());
+
+        let options = get_lint_options(
+            "--apply-suggestions react/forward-ref-uses-ref:add-ref-parameter --apply-suggestions no-foo:0 test.js",
+        );
+        assert_eq!(
+            options.fix_options.apply_suggestions,
+            SuggestionChoices::parse(["react/forward-ref-uses-ref:add-ref-parameter", "no-foo:0"])
+                .unwrap()
+        );
+        assert!(options.fix_options.is_enabled());
+        assert!(options.fix_options.fix_kind().is_none());
+
+        let result = lint_command().run_inner(&["--apply-suggestions", "no-foo", "test.js"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rule() {
+        let options = get_lint_options("test.js");
+        assert!(options.basic_options.rule.is_empty());
+
+        let options = lint_command()
+            .run_inner(&["--rule", "eqeqeq=error", "--rule", "no-console=[1]"])
+            .unwrap();
+        assert_eq!(
+            options.basic_options.rule,
+            OxlintRules::parse(["eqeqeq=error", "no-console=[1]"]).unwrap()
+        );
+
+        let result = lint_command().run_inner(&["--rule", "eqeqeq", "test.js"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -622,3 +852,70 @@ mod inline_config_options {
         );
     }
 }
+
+#[cfg(test)]
+mod cache_options {
+    use std::path::PathBuf;
+
+    use super::{CacheOptions, CacheStrategy, lint_command};
+
+    fn get_cache_options(arg: &str) -> CacheOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().cache_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_cache_options(".");
+        assert!(!options.cache);
+        assert_eq!(options.cache_location, None);
+        assert_eq!(options.cache_strategy, CacheStrategy::Metadata);
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_cache_options(
+            "--cache --cache-location node_modules/.cache/oxlint --cache-strategy content .",
+        );
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from("node_modules/.cache/oxlint")));
+        assert_eq!(options.cache_strategy, CacheStrategy::Content);
+    }
+}
+
+#[cfg(test)]
+mod baseline_options {
+    use std::path::PathBuf;
+
+    use super::{BaselineOptions, lint_command};
+
+    fn get_baseline_options(arg: &str) -> BaselineOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().baseline_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_baseline_options(".");
+        assert_eq!(options.baseline, None);
+        assert!(!options.generate_baseline);
+        assert_eq!(options.ratchet, None);
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_baseline_options("--baseline baseline.json .");
+        assert_eq!(options.baseline, Some(PathBuf::from("baseline.json")));
+        assert!(!options.generate_baseline);
+
+        let options = get_baseline_options("--generate-baseline --baseline baseline.json .");
+        assert_eq!(options.baseline, Some(PathBuf::from("baseline.json")));
+        assert!(options.generate_baseline);
+    }
+
+    #[test]
+    fn ratchet() {
+        let options = get_baseline_options("--ratchet ratchet.json .");
+        assert_eq!(options.ratchet, Some(PathBuf::from("ratchet.json")));
+    }
+}
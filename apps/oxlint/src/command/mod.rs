@@ -3,11 +3,14 @@ mod lint;
 
 use std::path::PathBuf;
 
-use bpaf::Bpaf;
+use bpaf::{Bpaf, OptionParser, Parser, construct};
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    lint::{
+        BaselineOptions, BasicOptions, CacheOptions, FixOptions, LintCommand, OutputOptions,
+        ReportUnusedDirectives, WarningOptions, lint_command,
+    },
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -15,6 +18,63 @@ const VERSION: &str = match option_env!("OXC_VERSION") {
     None => "dev",
 };
 
+/// A subcommand, or linting, which is the default.
+#[derive(Debug, Clone)]
+pub enum OxlintCommand {
+    Subcommand(Subcommand),
+    Lint(Box<LintCommand>),
+}
+
+#[derive(Debug, Clone, Bpaf)]
+pub enum Subcommand {
+    /// Run the language server, so editors only need the `oxlint` executable
+    #[cfg(feature = "language_server")]
+    #[bpaf(command("lsp"))]
+    Lsp {
+        /// Communicate over stdin and stdout, which is the only supported transport.
+        /// Accepted because language clients pass it by default
+        #[bpaf(switch)]
+        stdio: bool,
+    },
+
+    /// Convert an ESLint configuration file to `.oxlintrc.json`
+    ///
+    /// Convert the ESLint configuration file CONFIG to `.oxlintrc.json` in the current directory,
+    /// and report the rules and options which have no oxlint equivalent. Without CONFIG, the
+    /// configuration file is searched for in the current directory.
+    ///
+    /// Both flat configs (`eslint.config.js`) and legacy configs (`.eslintrc.json`, `.eslintrc.js`
+    /// or `.eslintrc.yaml`) are supported. JavaScript configs are not run, only their literal
+    /// values are migrated, so shared configs such as `js.configs.recommended` are reported as
+    /// not migrated.
+    #[bpaf(command("migrate"))]
+    Migrate {
+        /// ESLint configuration file
+        #[bpaf(positional("CONFIG"))]
+        config: Option<PathBuf>,
+    },
+
+    /// Print the documentation of a rule
+    ///
+    /// Print the documentation of RULE, e.g. `eqeqeq` or `react/jsx-key`: what it checks, examples
+    /// of incorrect and correct code, its options, and whether its violations can be fixed.
+    #[cfg(feature = "ruledocs")]
+    #[bpaf(command("explain"))]
+    Explain {
+        /// Name of the rule, optionally prefixed with its plugin
+        #[bpaf(positional("RULE"))]
+        rule: String,
+    },
+}
+
+/// Parser of the command line of oxlint: a subcommand, or the options and paths of linting.
+/// A path to lint named like a subcommand can be given as e.g. `./migrate` or after `--`.
+pub fn oxlint_command() -> OptionParser<OxlintCommand> {
+    let subcommand = subcommand().map(OxlintCommand::Subcommand);
+    let lint = lint::lint_options().map(|command| OxlintCommand::Lint(Box::new(command)));
+    construct!([subcommand, lint]).to_options().version(VERSION)
+}
+
 /// Miscellaneous
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
@@ -26,10 +86,81 @@ pub struct MiscOptions {
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
 
+    /// Process only one file at a time while oxlint uses more than MB megabytes of memory,
+    /// to avoid running out of memory on small machines. Only supported on Linux
+    #[bpaf(
+        argument::<u64>("MB"),
+        guard(|megabytes| megabytes.checked_mul(MEGABYTE).is_some(), "MB is too large"),
+        optional,
+        hide_usage
+    )]
+    pub max_memory: Option<u64>,
+
+    /// Process only one file larger than KB kilobytes at a time, such as generated files,
+    /// while smaller files are processed in parallel
+    #[bpaf(
+        argument::<u64>("KB"),
+        guard(|kilobytes| kilobytes.checked_mul(KILOBYTE).is_some(), "KB is too large"),
+        optional,
+        hide_usage
+    )]
+    pub large_file_size: Option<u64>,
+
+    /// Stop running a rule on a file after MS milliseconds and report that it was skipped,
+    /// instead of hanging on pathological files such as large generated files
+    #[bpaf(argument("MS"), hide_usage)]
+    pub rule_timeout: Option<u64>,
+
     /// This option outputs the configuration to be used.
     /// When present, no linting is performed and only config-related options are valid.
+    /// When a file path is given, the configuration which applies to that file is printed,
+    /// with its nested configuration file, `extends`, overrides and categories resolved.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Explain how the configuration of FILE is resolved instead of linting: which configuration
+    /// files are found and which applies to it, which overrides match it, whether it is ignored,
+    /// and what decided the severity of each rule. Printed as text, or as JSON with `--format json`
+    #[bpaf(argument("FILE"), hide_usage)]
+    pub explain_config_resolution: Option<PathBuf>,
+
+    /// Watch files for changes, and lint them again when they change.
+    /// All files are linted again when a configuration file changes.
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
+
+    /// Keep running and lint on request of other processes, so they don't pay the startup cost
+    /// of oxlint. Requests are JSON-RPC messages on a socket in `$XDG_RUNTIME_DIR` or the
+    /// temporary directory, which only the user can connect to. Configuration files are only read
+    /// again when they change. Only supported on Unix
+    #[bpaf(switch, hide_usage)]
+    pub daemon: bool,
+
+    /// Send the lint request to the daemon of the working directory if one is running,
+    /// see `--daemon`
+    #[bpaf(switch, hide_usage)]
+    pub use_daemon: bool,
+
+    /// Lint the source text read from stdin, as if it was the file at PATH.
+    /// The path is used to find configuration files and to detect the source type,
+    /// the file does not need to exist.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
+const KILOBYTE: u64 = 1024;
+const MEGABYTE: u64 = 1024 * KILOBYTE;
+
+impl MiscOptions {
+    /// `--max-memory` in bytes.
+    pub fn max_memory_bytes(&self) -> Option<u64> {
+        self.max_memory.and_then(|megabytes| megabytes.checked_mul(MEGABYTE))
+    }
+
+    /// `--large-file-size` in bytes.
+    pub fn large_file_size_bytes(&self) -> Option<u64> {
+        self.large_file_size.and_then(|kilobytes| kilobytes.checked_mul(KILOBYTE))
+    }
 }
 
 #[expect(clippy::ptr_arg)]
@@ -43,8 +174,54 @@ fn validate_paths(paths: &Vec<PathBuf>) -> bool {
 
 const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
 
+#[cfg(test)]
+mod subcommand {
+    use std::path::PathBuf;
+
+    use super::{OxlintCommand, Subcommand, oxlint_command};
+
+    fn parse(args: &[&str]) -> OxlintCommand {
+        oxlint_command().run_inner(args).unwrap()
+    }
+
+    #[test]
+    fn migrate() {
+        let OxlintCommand::Subcommand(Subcommand::Migrate { config }) = parse(&["migrate"]) else {
+            panic!("expected `migrate`");
+        };
+        assert!(config.is_none());
+        let OxlintCommand::Subcommand(Subcommand::Migrate { config }) =
+            parse(&["migrate", ".eslintrc.json"])
+        else {
+            panic!("expected `migrate`");
+        };
+        assert_eq!(config, Some(PathBuf::from(".eslintrc.json")));
+    }
+
+    #[test]
+    fn paths_named_like_subcommands() {
+        for args in [&["./migrate"][..], &["--", "migrate"]] {
+            let OxlintCommand::Lint(command) = parse(args) else {
+                panic!("expected linting for {args:?}");
+            };
+            assert_eq!(command.paths.len(), 1);
+        }
+    }
+
+    #[test]
+    fn lint() {
+        let OxlintCommand::Lint(command) = parse(&["--fix", "src"]) else {
+            panic!("expected linting");
+        };
+        assert!(command.fix_options.fix);
+        assert_eq!(command.paths, vec![PathBuf::from("src")]);
+    }
+}
+
 #[cfg(test)]
 mod misc_options {
+    use std::path::PathBuf;
+
     use super::{MiscOptions, lint::lint_command};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
@@ -63,4 +240,62 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn memory_limits() {
+        let options = get_misc_options(".");
+        assert!(options.max_memory.is_none());
+        assert!(options.large_file_size.is_none());
+        let options = get_misc_options("--max-memory 1536 --large-file-size 2048 .");
+        assert_eq!(options.max_memory, Some(1536));
+        assert_eq!(options.large_file_size, Some(2048));
+        assert_eq!(options.max_memory_bytes(), Some(1536 * 1024 * 1024));
+        assert_eq!(options.large_file_size_bytes(), Some(2048 * 1024));
+
+        // Sizes which overflow in bytes are rejected
+        let max = u64::MAX.to_string();
+        assert!(lint_command().run_inner(&["--max-memory", max.as_str(), "."]).is_err());
+        assert!(lint_command().run_inner(&["--large-file-size", max.as_str(), "."]).is_err());
+    }
+
+    #[test]
+    fn rule_timeout() {
+        let options = get_misc_options(".");
+        assert!(options.rule_timeout.is_none());
+        let options = get_misc_options("--rule-timeout 500 .");
+        assert_eq!(options.rule_timeout, Some(500));
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_misc_options(".");
+        assert!(!options.watch);
+        let options = get_misc_options("--watch .");
+        assert!(options.watch);
+    }
+
+    #[test]
+    fn daemon() {
+        let options = get_misc_options(".");
+        assert!(!options.daemon);
+        assert!(!options.use_daemon);
+        assert!(get_misc_options("--daemon").daemon);
+        assert!(get_misc_options("--use-daemon .").use_daemon);
+    }
+
+    #[test]
+    fn explain_config_resolution() {
+        let options = get_misc_options(".");
+        assert!(options.explain_config_resolution.is_none());
+        let options = get_misc_options("--explain-config-resolution src/foo.ts");
+        assert_eq!(options.explain_config_resolution, Some(PathBuf::from("src/foo.ts")));
+    }
+
+    #[test]
+    fn stdin_filename() {
+        let options = get_misc_options(".");
+        assert!(options.stdin_filename.is_none());
+        let options = get_misc_options("--stdin-filename src/foo.ts");
+        assert_eq!(options.stdin_filename, Some(PathBuf::from("src/foo.ts")));
+    }
 }
@@ -0,0 +1,186 @@
+//! Configurations resolved by earlier runs of a long-running oxlint, i.e. `--daemon` and
+//! `--watch`, so configuration files are only read and built again when they change.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{Config, FileMetadata, FileSystem, Oxlintrc};
+use rustc_hash::FxHashMap;
+
+use crate::watch::FileState;
+
+/// Configurations of the working directory and of the directories of linted files.
+///
+/// The cache is cleared when the options which affect configurations, e.g. `--rule`, differ from
+/// the options it was filled with, or when any file which was read or looked for while resolving
+/// the configurations changed, including the files configurations extend.
+#[derive(Default)]
+pub struct ConfigCache {
+    options: String,
+    files: FxHashMap<PathBuf, Option<FileState>>,
+    /// Configuration of the working directory, or of `--config`.
+    pub base: Option<Config>,
+    /// Configuration file of each directory which was searched for one, and its configuration.
+    pub directories: FxHashMap<PathBuf, Option<(Oxlintrc, Config)>>,
+}
+
+impl ConfigCache {
+    /// Lock `cache` for a run with `options`, see [`ConfigCache::validate`].
+    pub fn lock(cache: &Mutex<Self>, options: String) -> MutexGuard<'_, Self> {
+        let mut cache = cache.lock().unwrap();
+        cache.validate(options);
+        cache
+    }
+
+    /// Clear the cache if `options` differ from the options it was filled with,
+    /// or if a configuration file changed.
+    pub fn validate(&mut self, options: String) {
+        let changed = self.files.iter().any(|(path, state)| FileState::new(path) != *state);
+        if changed || self.options != options {
+            *self = Self { options, ..Self::default() };
+        }
+    }
+
+    /// Watch the files which were read or looked for while resolving the configurations.
+    pub fn record(&mut self, paths: &[PathBuf]) {
+        for path in paths {
+            // Keep the state of files recorded earlier, so a change since then is detected
+            self.files.entry(path.clone()).or_insert_with_key(|path| FileState::new(path));
+        }
+    }
+
+    /// Files which were read or looked for while resolving the cached configurations.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+}
+
+/// Files which were read or looked for while resolving the configurations of a run,
+/// in `file_system`, or by earlier runs if the configurations are cached.
+pub fn config_paths(
+    file_system: RecordingFileSystem,
+    cache: Option<MutexGuard<ConfigCache>>,
+) -> Vec<PathBuf> {
+    let paths = file_system.into_paths();
+    let Some(mut cache) = cache else { return paths };
+    cache.record(&paths);
+    // Cached configurations were resolved by earlier runs, from files not read again
+    cache.paths().map(Path::to_path_buf).collect()
+}
+
+/// File system which records the paths of the files read or looked for in `inner`.
+pub struct RecordingFileSystem<'a> {
+    inner: &'a dyn FileSystem,
+    paths: Mutex<Vec<PathBuf>>,
+}
+
+impl<'a> RecordingFileSystem<'a> {
+    pub fn new(inner: &'a dyn FileSystem) -> Self {
+        Self { inner, paths: Mutex::default() }
+    }
+
+    fn record(&self, path: &Path) {
+        self.paths.lock().unwrap().push(path.to_path_buf());
+    }
+
+    /// Paths of the files which were read or looked for.
+    pub fn into_paths(self) -> Vec<PathBuf> {
+        self.paths.into_inner().unwrap()
+    }
+}
+
+impl FileSystem for RecordingFileSystem<'_> {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.record(path);
+        self.inner.read_to_string(path)
+    }
+
+    fn read_to_arena_str<'a>(&self, path: &Path, allocator: &'a Allocator) -> io::Result<&'a str> {
+        self.record(path);
+        self.inner.read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> io::Result<()> {
+        self.inner.write_file(path, content)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.record(path);
+        self.inner.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.record(path);
+        self.inner.symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.inner.read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.inner.read_dir(path)
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        self.inner.file_size(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.record(path);
+        self.inner.is_file(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.inner.is_dir(path)
+    }
+
+    // Configurations extended from URLs are not watched
+    fn fetch(&self, url: &str) -> io::Result<String> {
+        self.inner.fetch(url)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use oxc_linter::{FileSystem, OsFileSystem};
+
+    use super::{ConfigCache, RecordingFileSystem};
+
+    fn fill(cache: &mut ConfigCache, path: &Path) {
+        let file_system = RecordingFileSystem::new(&OsFileSystem);
+        let _ = file_system.read_to_string(path);
+        cache.record(&file_system.into_paths());
+        cache.base = Some(oxc_linter::ConfigStoreBuilder::default().build());
+    }
+
+    #[test]
+    fn invalidation() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join(".oxlintrc.json");
+        let mut cache = ConfigCache::default();
+
+        // A configuration file which doesn't exist is watched too
+        fill(&mut cache, &config);
+        cache.validate(String::new());
+        assert!(cache.base.is_some());
+        fs::write(&config, "{}").unwrap();
+        cache.validate(String::new());
+        assert!(cache.base.is_none());
+
+        fill(&mut cache, &config);
+        cache.validate("--rule no-debugger=off".to_string());
+        assert!(cache.base.is_none());
+
+        fill(&mut cache, &config);
+        fs::write(&config, r#"{ "rules": {} }"#).unwrap();
+        cache.validate("--rule no-debugger=off".to_string());
+        assert!(cache.base.is_none());
+    }
+}
@@ -0,0 +1,362 @@
+//! `oxlint --daemon`: lint on request of other processes, e.g. git hooks and build tools, which
+//! then don't pay the startup cost of oxlint on every invocation.
+//!
+//! The daemon listens on the Unix socket [`socket_path`] of its working directory, in a directory
+//! which only the user can access. Requests and responses are JSON-RPC 2.0 messages, one per line:
+//!
+//! ```text
+//! --> {"jsonrpc":"2.0","id":1,"method":"lint","params":{"args":["--format=unix","src"]}}
+//! <-- {"jsonrpc":"2.0","id":1,"result":{"success":false,"output":"src/a.js:1:1: ..."}}
+//! ```
+//!
+//! `args` are the command line arguments of oxlint, relative to the working directory of the
+//! daemon, and `stdin` the source text for `--stdin-filename`. The `shutdown` method stops the
+//! daemon. `oxlint --use-daemon` sends its arguments to the daemon of its working directory.
+
+use std::{
+    env, fs,
+    io::{self, BufRead, BufReader, Write},
+    os::unix::{
+        fs::{DirBuilderExt, MetadataExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+};
+
+use bpaf::ParseFailure;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha1::{Digest, Sha1};
+
+use crate::{
+    cli::{CliRunResult, LintCommand, lint_command},
+    lint::print_and_flush_stdout,
+};
+
+/// Lint on request of other processes until the daemon of `cwd` is shut down, see [`Daemon`].
+///
+/// The options of the daemon are ignored, each request has its own. `lint` lints with the options
+/// and the source text for `--stdin-filename` of a request, and writes the output to the buffer.
+pub fn run(
+    cwd: &Path,
+    stdout: &mut dyn Write,
+    mut lint: impl FnMut(LintCommand, Option<String>, &mut dyn Write) -> CliRunResult,
+) -> CliRunResult {
+    let daemon = match Daemon::bind(cwd) {
+        Ok(daemon) => daemon,
+        Err(err) => {
+            print_and_flush_stdout(stdout, &format!("Failed to start the daemon: {err}\n"));
+            return CliRunResult::InvalidOptionDaemon;
+        }
+    };
+    print_and_flush_stdout(stdout, &format!("Daemon listening on {}\n", daemon.socket().display()));
+
+    daemon.serve(|params| {
+        let options =
+            lint_command().run_inner(params.args.as_slice()).map_err(|err| match err {
+                ParseFailure::Stdout(doc, _) | ParseFailure::Stderr(doc) => doc.to_string(),
+                ParseFailure::Completion(message) => message,
+            })?;
+        if options.misc_options.watch || options.misc_options.daemon {
+            return Err("`--watch` and `--daemon` can't be used in requests to the daemon".into());
+        }
+        let mut output = Vec::new();
+        let result = lint(options, params.stdin, &mut output);
+        Ok(LintResponse {
+            success: result.is_success(),
+            output: String::from_utf8_lossy(&output).into_owned(),
+        })
+    });
+    CliRunResult::None
+}
+
+/// Path of the socket of the daemon of `cwd`.
+///
+/// Sockets are created in the directory `oxlint-<uid>` of `$XDG_RUNTIME_DIR`, or else of the
+/// temporary directory, instead of in projects where they would show up in `git status` and
+/// could be shared with other users. They are named after a hash of `cwd`, which keeps the path
+/// below the length limit of socket paths.
+pub fn socket_path(cwd: &Path) -> PathBuf {
+    let runtime_dir = env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .unwrap_or_else(env::temp_dir);
+    let hash = Sha1::digest(cwd.as_os_str().as_encoded_bytes());
+    let hash = hash[..8].iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+    runtime_dir.join(format!("oxlint-{}", current_uid())).join(format!("daemon-{hash}.sock"))
+}
+
+fn current_uid() -> u32 {
+    // SAFETY: `getuid` has no preconditions and always succeeds.
+    unsafe { libc::getuid() }
+}
+
+/// Create the directory of the sockets with mode 0700, before any socket is bound in it, so that
+/// other users can never connect to them.
+///
+/// The temporary directory is shared with other users, who could have created the directory
+/// first, so an existing directory is only used if it's [private](check_owner).
+fn create_socket_dir(dir: &Path) -> io::Result<()> {
+    match fs::DirBuilder::new().mode(0o700).create(dir) {
+        Err(err) if err.kind() != io::ErrorKind::AlreadyExists => return Err(err),
+        _ => {}
+    }
+    check_owner(dir, /* private */ true)
+}
+
+/// Check that `path` is not a symbolic link and belongs to the current user, and if `private`,
+/// that other users can't access it.
+fn check_owner(path: &Path, private: bool) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    if metadata.file_type().is_symlink()
+        || metadata.uid() != current_uid()
+        || (private && metadata.mode() & 0o077 != 0)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is not private to the current user", path.display()),
+        ));
+    }
+    Ok(())
+}
+
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LintParams {
+    pub args: Vec<String>,
+    /// Source text for `--stdin-filename`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintResponse {
+    /// Whether oxlint would exit with a success status.
+    pub success: bool,
+    /// What oxlint would print to stdout.
+    pub output: String,
+}
+
+pub struct Daemon {
+    listener: UnixListener,
+    socket: PathBuf,
+}
+
+impl Daemon {
+    /// Listen on the socket of `cwd`, which only the user can connect to. A socket left behind by
+    /// a daemon which is not running anymore is replaced.
+    pub fn bind(cwd: &Path) -> io::Result<Self> {
+        let socket = socket_path(cwd);
+        if let Some(dir) = socket.parent() {
+            create_socket_dir(dir)?;
+        }
+        if socket.exists() {
+            if UnixStream::connect(&socket).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("A daemon is already listening on {}", socket.display()),
+                ));
+            }
+            fs::remove_file(&socket)?;
+        }
+        let listener = UnixListener::bind(&socket)?;
+        let daemon = Self { listener, socket };
+        fs::set_permissions(&daemon.socket, fs::Permissions::from_mode(0o600))?;
+        Ok(daemon)
+    }
+
+    pub fn socket(&self) -> &Path {
+        &self.socket
+    }
+
+    /// Answer requests until a `shutdown` request. Connections are handled one after another,
+    /// linting itself runs in parallel.
+    ///
+    /// `lint` returns the response to a `lint` request, or a message when its arguments are
+    /// invalid.
+    pub fn serve(&self, mut lint: impl FnMut(LintParams) -> Result<LintResponse, String>) {
+        for stream in self.listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            // A client which disconnects early doesn't stop the daemon
+            if let Ok(true) = Self::handle_connection(&stream, &mut lint) {
+                return;
+            }
+        }
+    }
+
+    /// Returns `true` after a `shutdown` request.
+    fn handle_connection(
+        stream: &UnixStream,
+        lint: &mut impl FnMut(LintParams) -> Result<LintResponse, String>,
+    ) -> io::Result<bool> {
+        let mut writer = stream;
+        for line in BufReader::new(stream).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (response, shutdown) = match serde_json::from_str::<Request>(&line) {
+                Ok(request) => match request.method.as_str() {
+                    "lint" => {
+                        let result = serde_json::from_value::<LintParams>(request.params)
+                            .map_err(|err| err.to_string())
+                            .and_then(&mut *lint);
+                        let response = match result {
+                            Ok(response) => {
+                                json!({ "jsonrpc": "2.0", "id": request.id, "result": response })
+                            }
+                            Err(message) => error(request.id, INVALID_PARAMS, &message),
+                        };
+                        (response, false)
+                    }
+                    "shutdown" => {
+                        (json!({ "jsonrpc": "2.0", "id": request.id, "result": null }), true)
+                    }
+                    method => (
+                        error(request.id, METHOD_NOT_FOUND, &format!("Unknown method `{method}`")),
+                        false,
+                    ),
+                },
+                Err(err) => (error(Value::Null, INVALID_REQUEST, &err.to_string()), false),
+            };
+            writeln!(writer, "{response}")?;
+            writer.flush()?;
+            if shutdown {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.socket);
+    }
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Connection to the daemon of a working directory.
+pub struct DaemonClient {
+    stream: UnixStream,
+}
+
+impl DaemonClient {
+    /// Returns [`None`] when no daemon of `cwd` is listening, or when the socket or its directory
+    /// don't belong to the current user, who would then send sources to someone else's daemon.
+    pub fn connect(cwd: &Path) -> Option<Self> {
+        let socket = socket_path(cwd);
+        check_owner(socket.parent()?, /* private */ true).ok()?;
+        check_owner(&socket, /* private */ false).ok()?;
+        UnixStream::connect(socket).ok().map(|stream| Self { stream })
+    }
+
+    /// Send a `lint` request.
+    pub fn lint(&self, params: &LintParams) -> io::Result<LintResponse> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": "lint", "params": params });
+        let mut writer = &self.stream;
+        writeln!(writer, "{request}")?;
+        writer.flush()?;
+
+        let mut line = String::new();
+        BufReader::new(&self.stream).read_line(&mut line)?;
+        let mut response = serde_json::from_str::<Value>(&line)?;
+        if let Some(result) = response.get_mut("result") {
+            return Ok(serde_json::from_value(result.take())?);
+        }
+        let message = response
+            .pointer("/error/message")
+            .and_then(Value::as_str)
+            .unwrap_or("invalid response of the daemon");
+        Err(io::Error::other(message.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        io::Write,
+        os::unix::{fs::PermissionsExt, net::UnixStream},
+        thread,
+    };
+
+    use super::{Daemon, DaemonClient, LintParams, LintResponse, create_socket_dir, socket_path};
+
+    #[test]
+    fn lint_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path().to_path_buf();
+        assert!(DaemonClient::connect(&cwd).is_none());
+
+        let daemon = Daemon::bind(&cwd).unwrap();
+        assert!(Daemon::bind(&cwd).is_err());
+        let socket = socket_path(&cwd);
+        assert!(!socket.starts_with(&cwd));
+        assert_eq!(fs::metadata(&socket).unwrap().permissions().mode() & 0o777, 0o600);
+        let socket_dir = socket.parent().unwrap();
+        assert_eq!(fs::metadata(socket_dir).unwrap().permissions().mode() & 0o777, 0o700);
+        let server = thread::spawn(move || {
+            daemon.serve(|params| {
+                if params.args.is_empty() {
+                    return Err("no arguments".to_string());
+                }
+                Ok(LintResponse { success: params.stdin.is_none(), output: params.args.join(" ") })
+            });
+        });
+
+        let params = LintParams {
+            args: vec!["--stdin-filename".into(), "a.js".into()],
+            stdin: Some("debugger;".into()),
+        };
+        let client = DaemonClient::connect(&cwd).unwrap();
+        assert_eq!(
+            client.lint(&params).unwrap(),
+            LintResponse { success: false, output: "--stdin-filename a.js".into() }
+        );
+        let err = client.lint(&LintParams::default()).unwrap_err();
+        assert_eq!(err.to_string(), "no arguments");
+        drop(client);
+
+        let mut stream = UnixStream::connect(&socket).unwrap();
+        stream.write_all(b"{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"shutdown\"}\n").unwrap();
+        server.join().unwrap();
+        // The socket is removed with the daemon
+        assert!(!socket.exists());
+    }
+
+    #[test]
+    fn socket_dir_is_private() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket_dir = dir.path().join("oxlint");
+        create_socket_dir(&socket_dir).unwrap();
+        assert_eq!(fs::metadata(&socket_dir).unwrap().permissions().mode() & 0o777, 0o700);
+        // An existing private directory is reused
+        create_socket_dir(&socket_dir).unwrap();
+
+        // A directory which other users can access is rejected
+        fs::set_permissions(&socket_dir, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(create_socket_dir(&socket_dir).is_err());
+
+        // So is a symbolic link
+        let link = dir.path().join("link");
+        std::os::unix::fs::symlink(&socket_dir, &link).unwrap();
+        fs::set_permissions(&socket_dir, fs::Permissions::from_mode(0o700)).unwrap();
+        assert!(create_socket_dir(&link).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Warnings about deprecated rules, and rules configured by the names they were renamed from.
+
+use std::fmt::Write as _;
+
+use oxc_linter::{Config, Oxlintrc};
+
+use crate::cli::{CliRunResult, WarningOptions};
+
+/// The deprecated rules enabled in `configs`, described with the rules replacing them.
+pub fn deprecated_rules<'a>(configs: impl Iterator<Item = &'a Config>) -> Vec<String> {
+    let mut rules = configs
+        .flat_map(|config| config.rules().iter())
+        .filter(|(_, severity)| severity.is_warn_deny())
+        .filter_map(|(rule, _)| {
+            let deprecated = rule.deprecated()?;
+            Some(format!("`{}/{}` is {deprecated}", rule.plugin_name(), rule.name()))
+        })
+        .collect::<Vec<_>>();
+    rules.sort_unstable();
+    rules.dedup();
+    rules
+}
+
+/// The rules which are configured by the names they were renamed from in `configs`,
+/// described with their current names.
+pub fn renamed_rules(configs: &[Oxlintrc]) -> Vec<String> {
+    let mut rules = configs
+        .iter()
+        .flat_map(Oxlintrc::renamed_rules)
+        .map(|rule| format!("`{}` is renamed to `{}`", rule.old_name, rule.new_name))
+        .collect::<Vec<_>>();
+    rules.sort_unstable();
+    rules.dedup();
+    rules
+}
+
+/// The warnings about the deprecated rules enabled in `configs` and the `renamed_rules`,
+/// or [`None`] if there are none or they are silenced.
+///
+/// # Errors
+///
+/// With `--no-deprecated`, returns the warnings as errors.
+pub fn report<'a>(
+    configs: impl Iterator<Item = &'a Config>,
+    renamed_rules: &[String],
+    warning_options: &WarningOptions,
+    silent: bool,
+) -> Result<Option<String>, (CliRunResult, String)> {
+    let mut rules = deprecated_rules(configs);
+    rules.extend_from_slice(renamed_rules);
+    let no_deprecated = warning_options.no_deprecated;
+    if rules.is_empty() || (silent && !no_deprecated) {
+        return Ok(None);
+    }
+
+    let severity = if no_deprecated { "Error" } else { "Warning" };
+    let mut message = String::new();
+    for rule in &rules {
+        writeln!(message, "{severity}: The rule {rule}.").unwrap();
+    }
+    if !renamed_rules.is_empty() {
+        message.push_str(
+            "Run with `--fix-config` to update the names of renamed rules in the configuration files.\n",
+        );
+    }
+    if no_deprecated {
+        Err((CliRunResult::InvalidOptionConfig, message))
+    } else {
+        Ok(Some(message))
+    }
+}
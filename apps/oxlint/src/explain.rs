@@ -0,0 +1,230 @@
+//! `oxlint --explain-config-resolution <FILE>`: print how the configuration of a file is resolved,
+//! for debugging the configuration of projects with nested configuration files and overrides.
+
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use ignore::{Match, overrides::Override};
+use oxc_linter::{
+    AllowWarnDeny, ConfigResolution, ConfigStore, FileSystem, LINTABLE_EXTENSIONS, RuleSource,
+};
+use serde::Serialize;
+
+use crate::{
+    cli::{CliRunResult, IgnoreOptions, MiscOptions},
+    output_formatter::OutputFormat,
+    walk::read_ignore_file,
+};
+
+/// The file of `--explain-config-resolution`, relative to `cwd`. Its configuration is explained
+/// instead of linting `paths`.
+///
+/// # Errors
+///
+/// Returns an error if paths or `--stdin-filename` are passed too.
+pub fn explain_path(
+    cwd: &Path,
+    misc_options: &MiscOptions,
+    paths: &[PathBuf],
+) -> Result<Option<PathBuf>, (CliRunResult, String)> {
+    let Some(path) = &misc_options.explain_config_resolution else { return Ok(None) };
+    if !paths.is_empty() || misc_options.stdin_filename.is_some() {
+        return Err((
+            CliRunResult::InvalidOptionConfig,
+            "`--explain-config-resolution` can't be used with paths or `--stdin-filename`.\n"
+                .to_string(),
+        ));
+    }
+    Ok(Some(cwd.join(path)))
+}
+
+/// Whether a file is linted, and if not, why.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IgnoreDecision {
+    /// The file is linted.
+    Linted,
+    /// The file is linted, ignore patterns and files are disabled with `--no-ignore`.
+    IgnoreDisabled,
+    /// Ignored by `--ignore-pattern`.
+    IgnorePattern { pattern: String },
+    /// Ignored by `ignorePatterns` of the configuration file.
+    ConfigIgnorePattern { pattern: String },
+    /// Ignored by the ignore file, `--ignore-path`.
+    IgnoreFile { path: PathBuf },
+    /// Not linted because oxlint doesn't lint files with its extension.
+    NotLintable,
+}
+
+impl IgnoreDecision {
+    /// Decide whether the file at `path` is linted, like when it is passed to oxlint.
+    /// `overrides` are the ignore patterns of the command line and the configuration file.
+    pub fn new(
+        path: &Path,
+        overrides: &Override,
+        ignore_options: &IgnoreOptions,
+        file_system: &dyn FileSystem,
+    ) -> Self {
+        if !is_lintable(path) {
+            return Self::NotLintable;
+        }
+        if let Match::Ignore(glob) = overrides.matched(path, false) {
+            // Patterns are added with a `!` prefix, which means "ignore" for overrides
+            let pattern = glob.original().strip_prefix('!').unwrap_or(glob.original()).to_string();
+            return if ignore_options.ignore_pattern.contains(&pattern) {
+                Self::IgnorePattern { pattern }
+            } else {
+                Self::ConfigIgnorePattern { pattern }
+            };
+        }
+        let ignore_path = Path::new(&ignore_options.ignore_path);
+        if read_ignore_file(file_system, ignore_path).matched(path, false).is_ignore() {
+            return Self::IgnoreFile { path: ignore_path.to_path_buf() };
+        }
+        Self::Linted
+    }
+
+    /// Decide whether the file at `path` is linted with `--no-ignore`.
+    pub fn no_ignore(path: &Path) -> Self {
+        if is_lintable(path) { Self::IgnoreDisabled } else { Self::NotLintable }
+    }
+}
+
+fn is_lintable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| LINTABLE_EXTENSIONS.contains(&extension))
+}
+
+/// Explanation of the configuration of a file.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Explanation {
+    pub file: PathBuf,
+    pub ignore: IgnoreDecision,
+    #[serde(flatten)]
+    pub config: ConfigResolution,
+}
+
+impl Explanation {
+    /// Explain how the configuration of `file` is resolved by `config_store`.
+    pub fn new(file: PathBuf, ignore: IgnoreDecision, config_store: &ConfigStore) -> Self {
+        let config = config_store.explain_resolution(&file);
+        Self { file, ignore, config }
+    }
+
+    /// Render the explanation as JSON for `--format=json`, and as text otherwise.
+    pub fn render(&self, format: &OutputFormat, cwd: &Path) -> String {
+        if *format == OutputFormat::Json { self.to_json() } else { self.to_text(cwd) }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        json
+    }
+
+    /// Render the explanation as indented text, with paths relative to `cwd`.
+    pub fn to_text(&self, cwd: &Path) -> String {
+        let relative = |path: &Path| path.strip_prefix(cwd).unwrap_or(path).display().to_string();
+        let config = &self.config;
+        let mut out = String::new();
+
+        // Writing to a `String` can't fail
+        let _ = writeln!(out, "Configuration of {}", relative(&self.file));
+
+        out.push_str("\nConfiguration files:\n");
+        match &config.root_config {
+            Some(path) => {
+                let _ = writeln!(out, "  root: {}", relative(path));
+            }
+            None => out.push_str("  root: none, using the default configuration\n"),
+        }
+        for path in &config.nested_configs {
+            let _ = writeln!(out, "  nested: {}", relative(path));
+        }
+        let applied = config.config.as_deref().map_or_else(|| "default".to_string(), relative);
+        if config.is_nested {
+            let _ = writeln!(out, "  applies: {applied} (nearest nested configuration)");
+        } else if config.nested_configs.is_empty() {
+            let _ = writeln!(out, "  applies: {applied} (root configuration)");
+        } else {
+            let _ = writeln!(
+                out,
+                "  applies: {applied} (root configuration, no nested configuration in a parent directory)"
+            );
+        }
+
+        out.push_str("\nIgnore:\n");
+        let _ = match &self.ignore {
+            IgnoreDecision::Linted => writeln!(out, "  linted"),
+            IgnoreDecision::IgnoreDisabled => {
+                writeln!(out, "  linted, ignoring is disabled with `--no-ignore`")
+            }
+            IgnoreDecision::IgnorePattern { pattern } => {
+                writeln!(out, "  ignored by `--ignore-pattern {pattern}`")
+            }
+            IgnoreDecision::ConfigIgnorePattern { pattern } => {
+                writeln!(out, "  ignored by `{pattern}` in `ignorePatterns` of the configuration")
+            }
+            IgnoreDecision::IgnoreFile { path } => {
+                writeln!(out, "  ignored by {}", path.display())
+            }
+            IgnoreDecision::NotLintable => {
+                writeln!(out, "  not linted, files with this extension are not supported")
+            }
+        };
+
+        if config.overrides.is_empty() {
+            out.push_str("\nOverrides: none\n");
+        } else {
+            let _ =
+                writeln!(out, "\nOverrides, matched against `{}`:", config.override_path.display());
+            for override_config in &config.overrides {
+                let _ = writeln!(
+                    out,
+                    "  #{} {:?}: {}",
+                    override_config.index,
+                    override_config.files,
+                    if override_config.matched { "matched" } else { "not matched" }
+                );
+            }
+        }
+
+        let _ = writeln!(out, "\nPlugins: {}", config.plugins.join(", "));
+
+        out.push_str("\nCategories:\n");
+        if config.categories.is_empty() {
+            out.push_str("  none\n");
+        }
+        for (category, severity) in &config.categories {
+            let _ = writeln!(out, "  {category}: {}", severity_name(*severity));
+        }
+
+        let enabled = config.rules.iter().filter(|rule| rule.severity.is_warn_deny()).count();
+        let _ = writeln!(out, "\nRules ({enabled} enabled):");
+        for rule in &config.rules {
+            let source = match rule.source {
+                RuleSource::Override { index } => format!("override #{index}"),
+                RuleSource::Coverage => "coverage policy".to_string(),
+                RuleSource::Rules => "rules".to_string(),
+                RuleSource::Category { category } => format!("category {category}"),
+                RuleSource::Filter => "`-A`/`-W`/`-D` or default".to_string(),
+            };
+            let _ = writeln!(out, "  {}: {} ({source})", rule.name, severity_name(rule.severity));
+        }
+
+        out
+    }
+}
+
+/// Name of `severity` as in configuration files.
+fn severity_name(severity: AllowWarnDeny) -> &'static str {
+    match severity {
+        AllowWarnDeny::Allow => "off",
+        AllowWarnDeny::Warn => "warn",
+        AllowWarnDeny::Deny => "error",
+    }
+}
@@ -0,0 +1,43 @@
+//! `oxlint --fix-config`: update the names of renamed rules in configuration files.
+
+use std::io::Write;
+
+use oxc_linter::{FileSystem, Oxlintrc};
+
+use crate::{cli::CliRunResult, lint::print_and_flush_stdout};
+
+/// Replace the old names of renamed rules in the configuration files `configs`, in `file_system`.
+/// Each updated file is reported on `stdout` unless `silent`.
+///
+/// # Errors
+///
+/// Returns an error if a configuration file can't be written.
+pub fn fix_renamed_rules(
+    stdout: &mut dyn Write,
+    configs: &[Oxlintrc],
+    file_system: &dyn FileSystem,
+    silent: bool,
+) -> Result<(), (CliRunResult, String)> {
+    for config in configs {
+        let Some(fixed) = file_system
+            .read_to_string(&config.path)
+            .ok()
+            .and_then(|source_text| config.fix_renamed_rules(&source_text))
+        else {
+            continue;
+        };
+        file_system.write_file(&config.path, &fixed).map_err(|err| {
+            (
+                CliRunResult::InvalidOptionConfig,
+                format!("Failed to update configuration file {}: {err}\n", config.path.display()),
+            )
+        })?;
+        if !silent {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Updated renamed rules in {}\n", config.path.display()),
+            );
+        }
+    }
+    Ok(())
+}
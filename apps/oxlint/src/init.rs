@@ -0,0 +1,331 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+use serde_json::{Map, Value};
+
+use oxc_linter::{AllowWarnDeny, BuiltinLintPlugins, Oxlintrc, RuleCategory};
+
+use crate::{cli::CliRunResult, lint::LintRunner};
+
+/// Frameworks and tools used by a project, detected from its `package.json` for `--init`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ProjectInfo {
+    /// Display names of the detected frameworks, e.g. `React` or `TypeScript`.
+    pub frameworks: Vec<&'static str>,
+    /// Plugins for the detected frameworks.
+    pub plugins: BuiltinLintPlugins,
+    /// Environments the code runs in, e.g. `browser` for React or Vue.
+    pub envs: Vec<&'static str>,
+}
+
+impl ProjectInfo {
+    /// Detect the frameworks of the project in `cwd` from its `package.json` and `tsconfig.json`.
+    pub fn detect(cwd: &Path) -> Self {
+        let package_json = fs::read_to_string(cwd.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok());
+        let has_tsconfig = cwd.join("tsconfig.json").is_file();
+        Self::from_package_json(package_json.as_ref(), has_tsconfig)
+    }
+
+    fn from_package_json(package_json: Option<&Value>, has_tsconfig: bool) -> Self {
+        let has_dependency = |name: &str| {
+            package_json.is_some_and(|package_json| {
+                ["dependencies", "devDependencies", "peerDependencies"].iter().any(|key| {
+                    package_json.get(key).and_then(|dependencies| dependencies.get(name)).is_some()
+                })
+            })
+        };
+
+        let mut info = Self { plugins: BuiltinLintPlugins::empty(), ..Self::default() };
+        let mut add = |framework, plugins, env: Option<&'static str>| {
+            info.frameworks.push(framework);
+            info.plugins |= plugins;
+            if let Some(env) = env {
+                if !info.envs.contains(&env) {
+                    info.envs.push(env);
+                }
+            }
+        };
+
+        if has_dependency("react") || has_dependency("next") {
+            add("React", BuiltinLintPlugins::REACT | BuiltinLintPlugins::JSX_A11Y, Some("browser"));
+        }
+        if has_dependency("next") {
+            add("Next.js", BuiltinLintPlugins::NEXTJS, None);
+        }
+        if has_dependency("vue") || has_dependency("nuxt") {
+            add("Vue", BuiltinLintPlugins::VUE, Some("browser"));
+        }
+        if has_dependency("typescript") || has_tsconfig {
+            add("TypeScript", BuiltinLintPlugins::TYPESCRIPT, None);
+        }
+        let is_node = has_dependency("@types/node")
+            || package_json.is_some_and(|package_json| {
+                package_json.get("engines").and_then(|engines| engines.get("node")).is_some()
+                    || package_json.get("bin").is_some()
+            });
+        if is_node {
+            add("Node.js", BuiltinLintPlugins::NODE, Some("node"));
+        }
+        if has_dependency("jest") {
+            add("Jest", BuiltinLintPlugins::JEST, None);
+        }
+        if has_dependency("vitest") {
+            // Without the jest plugin, jest rules adapted to vitest are not enabled.
+            add("Vitest", BuiltinLintPlugins::VITEST | BuiltinLintPlugins::JEST, None);
+        }
+
+        info
+    }
+}
+
+/// Answers to the questions asked by `--init`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitAnswers {
+    /// Enable the plugins and environments of the detected frameworks.
+    pub framework_plugins: bool,
+    /// Warn about code that is most likely wrong or useless (`suspicious` category).
+    pub suspicious: bool,
+    /// Warn about code that could be written to run faster (`perf` category).
+    pub perf: bool,
+}
+
+impl Default for InitAnswers {
+    /// Answers used when `--init` is not run in a terminal.
+    fn default() -> Self {
+        Self { framework_plugins: true, suspicious: true, perf: false }
+    }
+}
+
+impl InitAnswers {
+    /// Ask the questions for `project` on `output`, and read the answers from `input`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` can't be read or `output` can't be written.
+    pub fn ask(
+        project: &ProjectInfo,
+        input: &mut dyn BufRead,
+        output: &mut dyn Write,
+    ) -> io::Result<Self> {
+        let defaults = Self::default();
+
+        let framework_plugins = if project.frameworks.is_empty() {
+            writeln!(output, "No frameworks detected in package.json.")?;
+            false
+        } else {
+            writeln!(output, "Detected {}.", project.frameworks.join(", "))?;
+            let plugins = project
+                .plugins
+                .iter()
+                .map(<&'static str>::from)
+                .filter(|name| !name.is_empty())
+                .collect::<Vec<_>>();
+            confirm(
+                &format!("Enable the {} plugins?", plugins.join(", ")),
+                defaults.framework_plugins,
+                input,
+                output,
+            )?
+        };
+        let suspicious = confirm(
+            "Warn about code that is most likely wrong or useless (`suspicious` rules)?",
+            defaults.suspicious,
+            input,
+            output,
+        )?;
+        let perf = confirm(
+            "Warn about code that could be written to run faster (`perf` rules)?",
+            defaults.perf,
+            input,
+            output,
+        )?;
+
+        Ok(Self { framework_plugins, suspicious, perf })
+    }
+
+    /// Add the plugins, environments and categories selected by the answers to `oxlintrc`.
+    pub fn apply(self, project: &ProjectInfo, oxlintrc: &mut Oxlintrc) {
+        if self.framework_plugins {
+            let mut plugins = oxlintrc.plugins.take().unwrap_or_default();
+            plugins.builtin |= project.plugins;
+            oxlintrc.plugins = Some(plugins);
+
+            oxlintrc.env = oxlintrc
+                .env
+                .iter()
+                .chain(project.envs.iter().copied())
+                .map(ToString::to_string)
+                .collect();
+        }
+        if self.suspicious {
+            oxlintrc.categories.insert(RuleCategory::Suspicious, AllowWarnDeny::Warn);
+        }
+        if self.perf {
+            oxlintrc.categories.insert(RuleCategory::Perf, AllowWarnDeny::Warn);
+        }
+    }
+}
+
+/// Configure `oxlintrc` for the project in `cwd` for `--init`. The questions are asked on `output`
+/// if stdin is a terminal, otherwise the default answers are used.
+///
+/// # Errors
+///
+/// Returns an error if the questions can't be asked or answered.
+pub fn configure(
+    cwd: &Path,
+    oxlintrc: &mut Oxlintrc,
+    output: &mut dyn Write,
+) -> Result<(), (CliRunResult, String)> {
+    let project = ProjectInfo::detect(cwd);
+    let answers = if io::stdin().is_terminal() {
+        InitAnswers::ask(&project, &mut io::stdin().lock(), output).map_err(|err| {
+            (
+                CliRunResult::ConfigFileInitFailed,
+                format!("Failed to create configuration file: {err}\n"),
+            )
+        })?
+    } else {
+        InitAnswers::default()
+    };
+    answers.apply(&project, oxlintrc);
+    Ok(())
+}
+
+/// Write the configuration file `config_file` created by `--init` to `cwd`, with the `$schema` of
+/// the installed oxlint if there is one. Returns the message to print and the result of the run.
+pub fn write_config(cwd: &Path, config_file: String) -> (&'static str, CliRunResult) {
+    let schema_relative_path = "node_modules/oxlint/configuration_schema.json";
+    let configuration = if cwd.join(schema_relative_path).is_file() {
+        let mut config_json: Value = serde_json::from_str(&config_file).unwrap();
+        if let Value::Object(obj) = &mut config_json {
+            let mut json_object = Map::new();
+            json_object.insert("$schema".to_string(), format!("./{schema_relative_path}").into());
+            json_object.extend(obj.clone());
+            *obj = json_object;
+        }
+        serde_json::to_string_pretty(&config_json).unwrap()
+    } else {
+        config_file
+    };
+
+    if fs::write(cwd.join(LintRunner::DEFAULT_OXLINTRC), configuration).is_ok() {
+        ("Configuration file created\n", CliRunResult::ConfigFileInitSucceeded)
+    } else {
+        ("Failed to create configuration file\n", CliRunResult::ConfigFileInitFailed)
+    }
+}
+
+/// Ask a yes/no `question` until it is answered. An empty answer or end of input selects `default`.
+fn confirm(
+    question: &str,
+    default: bool,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> io::Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        write!(output, "{question} {hint} ")?;
+        output.flush()?;
+
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            writeln!(output)?;
+            return Ok(default);
+        }
+        let answer = answer.trim();
+        let is_any_of = |options: [&str; 2]| options.iter().any(|o| answer.eq_ignore_ascii_case(o));
+        if answer.is_empty() {
+            return Ok(default);
+        } else if is_any_of(["y", "yes"]) {
+            return Ok(true);
+        } else if is_any_of(["n", "no"]) {
+            return Ok(false);
+        }
+        writeln!(output, "Please answer `y` or `n`.")?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use serde_json::json;
+
+    use oxc_linter::{AllowWarnDeny, BuiltinLintPlugins, Oxlintrc, RuleCategory};
+
+    use super::{InitAnswers, ProjectInfo};
+
+    #[test]
+    fn detect_frameworks() {
+        let package_json = json!({
+            "dependencies": { "react": "^19.0.0", "next": "^15.0.0" },
+            "devDependencies": { "typescript": "^5.0.0", "vitest": "^3.0.0" },
+        });
+        let info = ProjectInfo::from_package_json(Some(&package_json), false);
+        assert_eq!(info.frameworks, ["React", "Next.js", "TypeScript", "Vitest"]);
+        assert_eq!(
+            info.plugins,
+            BuiltinLintPlugins::REACT
+                | BuiltinLintPlugins::JSX_A11Y
+                | BuiltinLintPlugins::NEXTJS
+                | BuiltinLintPlugins::TYPESCRIPT
+                | BuiltinLintPlugins::VITEST
+                | BuiltinLintPlugins::JEST
+        );
+        assert_eq!(info.envs, ["browser"]);
+
+        let package_json = json!({ "engines": { "node": ">=20" } });
+        let info = ProjectInfo::from_package_json(Some(&package_json), true);
+        assert_eq!(info.frameworks, ["TypeScript", "Node.js"]);
+        assert_eq!(info.envs, ["node"]);
+
+        let info = ProjectInfo::from_package_json(None, false);
+        assert!(info.frameworks.is_empty());
+        assert!(info.plugins.is_empty());
+    }
+
+    #[test]
+    fn ask() {
+        let info =
+            ProjectInfo::from_package_json(Some(&json!({ "dependencies": { "vue": "3" } })), false);
+
+        let mut output = Vec::new();
+        let answers =
+            InitAnswers::ask(&info, &mut Cursor::new("\nmaybe\nn\nyes\n"), &mut output).unwrap();
+        assert_eq!(answers, InitAnswers { framework_plugins: true, suspicious: false, perf: true });
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Detected Vue.\n\
+             Enable the vue plugins? [Y/n] \
+             Warn about code that is most likely wrong or useless (`suspicious` rules)? [Y/n] \
+             Please answer `y` or `n`.\n\
+             Warn about code that is most likely wrong or useless (`suspicious` rules)? [Y/n] \
+             Warn about code that could be written to run faster (`perf` rules)? [y/N] "
+        );
+
+        // End of input selects the defaults
+        let answers = InitAnswers::ask(&info, &mut Cursor::new(""), &mut Vec::new()).unwrap();
+        assert_eq!(answers, InitAnswers::default());
+    }
+
+    #[test]
+    fn apply() {
+        let info =
+            ProjectInfo::from_package_json(Some(&json!({ "dependencies": { "vue": "3" } })), false);
+        let mut oxlintrc = Oxlintrc::default();
+        InitAnswers { framework_plugins: true, suspicious: true, perf: false }
+            .apply(&info, &mut oxlintrc);
+
+        assert!(oxlintrc.plugins.unwrap().builtin.contains(BuiltinLintPlugins::VUE));
+        assert!(oxlintrc.env.contains("browser"));
+        assert!(oxlintrc.env.contains("builtin"));
+        assert_eq!(oxlintrc.categories.get(&RuleCategory::Suspicious), Some(&AllowWarnDeny::Warn));
+        assert_eq!(oxlintrc.categories.get(&RuleCategory::Perf), None);
+    }
+}
@@ -1,34 +1,48 @@
 use std::{ffi::OsStr, io::BufWriter};
 
 pub use oxc_linter::{
-    ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, LintFileResult,
-    PluginLoadResult,
+    CountingAllocator, ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb,
+    LintFileResult, PluginLoadResult,
 };
 
+pub use crate::output_formatter::{Reporter, ReporterFactory, register_reporter};
+
+mod baseline;
+mod cache;
 mod command;
+mod config_cache;
+#[cfg(unix)]
+mod daemon;
+mod deprecated_rules;
+mod explain;
+mod fix_config;
+mod init;
 mod lint;
+mod migrate;
 mod output_formatter;
+mod print_config;
+mod ratchet;
 mod result;
+#[cfg(feature = "ruledocs")]
+mod rule_docs;
+mod stdin;
 mod tester;
+mod timing;
+mod type_aware;
 mod walk;
+mod watch;
+mod workspace;
 
 pub mod cli {
     pub use crate::{command::*, lint::LintRunner, result::CliRunResult};
 }
 
-use cli::{CliRunResult, LintRunner};
+use cli::{CliRunResult, LintRunner, OxlintCommand, Subcommand};
 
 #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
 mod raw_fs;
 
-#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
-#[global_allocator]
-static GLOBAL: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
-
 pub fn lint(external_linter: Option<ExternalLinter>) -> CliRunResult {
-    init_tracing();
-    init_miette();
-
     let mut args = std::env::args_os().peekable();
 
     let args = match args.peek() {
@@ -37,11 +51,9 @@ pub fn lint(external_linter: Option<ExternalLinter>) -> CliRunResult {
     };
     let args = args.collect::<Vec<_>>();
 
-    // SAFELY skip first two args (node + script.js)
-    // let cli_args = std::env::args_os().skip(2);
-    let cmd = crate::cli::lint_command();
-    let command = match cmd.run_inner(&*args) {
-        Ok(cmd) => cmd,
+    let command = match crate::cli::oxlint_command().run_inner(&*args) {
+        Ok(OxlintCommand::Lint(command)) => *command,
+        Ok(OxlintCommand::Subcommand(subcommand)) => return run_subcommand(subcommand),
         Err(e) => {
             e.print_message(100);
             return if e.exit_code() == 0 {
@@ -52,17 +64,86 @@ pub fn lint(external_linter: Option<ExternalLinter>) -> CliRunResult {
         }
     };
 
-    command.handle_threads();
+    init_tracing();
+    init_miette();
+
     // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
     // See `https://github.com/rust-lang/rust/issues/60673`.
     let mut stdout = BufWriter::new(std::io::stdout());
 
+    #[cfg(unix)]
+    if command.misc_options.use_daemon && !command.misc_options.daemon {
+        if let Some(result) = lint_with_daemon(&args, &command, &mut stdout) {
+            return result;
+        }
+    }
+
+    command.handle_threads();
+
     LintRunner::new(command, external_linter).run(&mut stdout)
 }
 
+/// Send the arguments to the daemon of the working directory, see `--daemon`.
+/// Returns [`None`] when no daemon is running.
+#[cfg(unix)]
+fn lint_with_daemon(
+    args: &[std::ffi::OsString],
+    command: &cli::LintCommand,
+    stdout: &mut dyn std::io::Write,
+) -> Option<CliRunResult> {
+    use std::io::Read;
+
+    let client = daemon::DaemonClient::connect(&std::env::current_dir().ok()?)?;
+    let stdin = command.misc_options.stdin_filename.as_ref().map(|_| {
+        let mut source_text = String::new();
+        std::io::stdin().read_to_string(&mut source_text).map(|_| source_text)
+    });
+    let args = args.iter().map(|arg| arg.to_string_lossy().into_owned()).collect();
+    let response =
+        stdin.transpose().and_then(|stdin| client.lint(&daemon::LintParams { args, stdin }));
+    Some(match response {
+        Ok(response) => {
+            stdout.write_all(response.output.as_bytes()).and_then(|()| stdout.flush()).ok()?;
+            // Only the exit status of the result of the daemon is known
+            if response.success {
+                CliRunResult::LintSucceeded
+            } else {
+                CliRunResult::LintFoundErrors
+            }
+        }
+        Err(err) => {
+            writeln!(stdout, "Failed to lint with the daemon: {err}").ok()?;
+            CliRunResult::InvalidOptionDaemon
+        }
+    })
+}
+
+fn run_subcommand(subcommand: Subcommand) -> CliRunResult {
+    let mut stdout = BufWriter::new(std::io::stdout());
+    match subcommand {
+        #[cfg(feature = "language_server")]
+        Subcommand::Lsp { .. } => run_language_server(),
+        Subcommand::Migrate { config } => migrate::run_migrate(config.as_deref(), &mut stdout),
+        #[cfg(feature = "ruledocs")]
+        Subcommand::Explain { rule } => rule_docs::run_explain(&rule, &mut stdout),
+    }
+}
+
+/// `oxlint lsp`: run the language server over stdin and stdout.
+#[cfg(feature = "language_server")]
+fn run_language_server() -> CliRunResult {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start the language server runtime");
+    runtime.block_on(oxc_language_server::run_stdio());
+    CliRunResult::None
+}
+
 // Initialize the data which relies on `is_atty` system calls so they don't block subsequent threads.
+// The hook is already set if the linter runs more than once in a process, e.g. with the napi binding.
 fn init_miette() {
-    miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build()))).unwrap();
+    let _ = miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build())));
 }
 
 /// To debug `oxc_resolver`:
@@ -72,7 +153,8 @@ fn init_tracing() {
 
     // Usage without the `regex` feature.
     // <https://github.com/tokio-rs/tracing/issues/1436#issuecomment-918528013>
-    tracing_subscriber::registry()
+    // Already initialized if the linter runs more than once in a process.
+    let _ = tracing_subscriber::registry()
         .with(std::env::var("OXC_LOG").map_or_else(
             |_| Targets::new(),
             |env_var| {
@@ -81,5 +163,5 @@ fn init_tracing() {
             },
         ))
         .with(tracing_subscriber::fmt::layer())
-        .init();
+        .try_init();
 }
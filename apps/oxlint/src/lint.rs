@@ -1,35 +1,58 @@
 use std::{
     env,
     ffi::OsStr,
-    fs,
     io::{ErrorKind, Write},
+    iter,
     path::{Path, PathBuf, absolute},
-    sync::Arc,
-    time::Instant,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use cow_utils::CowUtils;
-use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
+use ignore::overrides::OverrideBuilder;
 use oxc_allocator::AllocatorPool;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{
+    DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic,
+    reporter::ProjectRoot,
+};
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
+    FileSystem, InvalidFilterKind, LintFilter, LintOptions, LintService, LintServiceOptions,
+    Linter, OsFileSystem, OxlintRules, Oxlintrc, RuleTimings,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
-use serde_json::Value;
 
+#[cfg(unix)]
+use crate::daemon;
 use crate::{
+    baseline::{self, filter_diagnostics},
+    cache::{LintCache, track_diagnostics},
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormatter},
-    walk::Walk,
+    config_cache::{ConfigCache, RecordingFileSystem, config_paths},
+    deprecated_rules,
+    explain::{self, Explanation, IgnoreDecision},
+    fix_config, init,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    print_config,
+    ratchet::{self, count_by_rule},
+    stdin::stdin_file_system,
+    timing, type_aware,
+    walk::{Walk, read_ignore_file},
+    watch::{self, LintedPaths},
+    workspace::{self, Workspace, count_by_package},
 };
 
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct LintRunner {
     options: LintCommand,
     cwd: PathBuf,
     external_linter: Option<ExternalLinter>,
+    /// File system provided by an embedder, `None` for the file system of the operating system.
+    file_system: Option<Arc<dyn FileSystem>>,
+    /// Source text for `--stdin-filename`, `None` to read it from stdin.
+    stdin: Option<String>,
+    /// Configurations resolved by earlier runs, shared by the runs of `--daemon` and `--watch`.
+    config_cache: Option<Arc<Mutex<ConfigCache>>>,
 }
 
 impl LintRunner {
@@ -38,18 +61,55 @@ impl LintRunner {
             options,
             cwd: env::current_dir().expect("Failed to get current working directory"),
             external_linter,
+            file_system: None,
+            stdin: None,
+            config_cache: None,
         }
     }
 
     pub(crate) fn run(self, stdout: &mut dyn Write) -> CliRunResult {
-        let format_str = self.options.output_options.format;
-        let output_formatter = OutputFormatter::new(format_str);
+        if self.options.misc_options.daemon && !self.options.list_rules {
+            return self.daemon(stdout);
+        }
+        // Source text from stdin can't change, `lint` reports that `--watch` can't be used
+        if self.options.misc_options.watch
+            && !self.options.list_rules
+            && self.options.misc_options.stdin_filename.is_none()
+        {
+            self.watch(stdout)
+        } else {
+            self.lint(stdout, &mut LintedPaths::default())
+        }
+    }
+
+    /// Lint files once. Paths of all files which were found, and of the configuration files, are
+    /// written to `linted`.
+    fn lint(self, stdout: &mut dyn Write, linted: &mut LintedPaths) -> CliRunResult {
+        match self.try_lint(stdout, linted) {
+            Ok(result) => result,
+            Err((result, message)) => {
+                print_and_flush_stdout(stdout, &message);
+                result
+            }
+        }
+    }
+
+    /// See [`Self::lint`]. Invalid options and configurations are returned with the message to
+    /// print.
+    fn try_lint(
+        self,
+        stdout: &mut dyn Write,
+        linted: &mut LintedPaths,
+    ) -> Result<CliRunResult, (CliRunResult, String)> {
+        let format_str = self.options.output_options.format.clone();
+        let mut output_formatter =
+            OutputFormatter::new(format_str.clone(), self.options.output_options.group_by);
 
         if self.options.list_rules {
             if let Some(output) = output_formatter.all_rules() {
                 print_and_flush_stdout(stdout, &output);
             }
-            return CliRunResult::None;
+            return Ok(CliRunResult::None);
         }
 
         let LintCommand {
@@ -60,25 +120,47 @@ impl LintRunner {
             ignore_options,
             fix_options,
             enable_plugins,
+            output_options,
             misc_options,
             disable_nested_config,
             inline_config_options,
+            cache_options,
+            baseline_options,
+            workspace_options,
             ..
         } = self.options;
 
         let external_linter = self.external_linter.as_ref();
+        let file_system: Arc<dyn FileSystem> =
+            self.file_system.clone().unwrap_or_else(|| Arc::new(OsFileSystem));
+
+        let stdin_path = misc_options.stdin_filename.as_ref().map(|path| self.cwd.join(path));
+        let (file_system, mut paths) = match &stdin_path {
+            Some(path) => {
+                let file_system = stdin_file_system(
+                    file_system,
+                    path.clone(),
+                    self.stdin,
+                    &paths,
+                    &misc_options,
+                    &fix_options,
+                )?;
+                (file_system, vec![path.clone()])
+            }
+            None => (file_system, paths),
+        };
 
-        let mut paths = paths;
         let provided_path_count = paths.len();
         let now = Instant::now();
 
-        let filters = match Self::get_filters(filter) {
-            Ok(filters) => filters,
-            Err((result, message)) => {
-                print_and_flush_stdout(stdout, &message);
-                return result;
-            }
-        };
+        // `--explain-config-resolution` resolves the configuration of a single file without linting it
+        let explain_path = explain::explain_path(&self.cwd, &misc_options, &paths)?;
+        if let Some(path) = &explain_path {
+            paths.push(path.clone());
+        }
+        let mut ignore_decision = explain_path.as_deref().map(IgnoreDecision::no_ignore);
+
+        let filters = Self::get_filters(filter)?;
 
         let handler = if cfg!(any(test, feature = "force_test_reporter")) {
             GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
@@ -86,23 +168,42 @@ impl LintRunner {
             GraphicalReportHandler::new()
         };
 
-        let config_search_result =
-            Self::find_oxlint_config(&self.cwd, basic_options.config.as_ref());
-
-        let mut oxlintrc = match config_search_result {
-            Ok(config) => config,
-            Err(err) => {
-                print_and_flush_stdout(
-                    stdout,
-                    &format!(
-                        "Failed to parse configuration file.\n{}\n",
-                        render_report(&handler, &err)
+        // Configurations are only cached for files of the operating system and without JS plugins,
+        // because configurations refer to the plugins loaded by the run which resolved them.
+        let mut config_cache = self
+            .config_cache
+            .as_deref()
+            .filter(|_| self.file_system.is_none() && external_linter.is_none())
+            .map(|cache| {
+                ConfigCache::lock(
+                    cache,
+                    format!(
+                        "{:?}{:?}{filters:?}{enable_plugins:?}{disable_nested_config}",
+                        basic_options.config, basic_options.rule
                     ),
-                );
-
-                return CliRunResult::InvalidOptionConfig;
-            }
-        };
+                )
+            });
+        let config_file_system = RecordingFileSystem::new(&*file_system);
+
+        let mut oxlintrc =
+            Self::find_oxlint_config(&self.cwd, basic_options.config.as_ref(), &config_file_system)
+                .map_err(|err| config_error(&handler, &err))?;
+
+        // Fingerprints of diagnostics and the baseline use paths relative to the directory of the
+        // configuration file, so they don't depend on the directory oxlint is run in.
+        let project_root = oxlintrc
+            .path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or_else(|| self.cwd.clone(), |dir| self.cwd.join(dir));
+        let project = ProjectRoot::new(self.cwd.clone(), project_root);
+        output_formatter.set_project_root(project.clone());
+
+        // Configuration files using old names of renamed rules, to warn about or fix them
+        let mut renamed_configs = vec![];
+        if oxlintrc.renamed_rules().next().is_some() {
+            renamed_configs.push(oxlintrc.clone());
+        }
 
         let mut override_builder = None;
 
@@ -129,13 +230,20 @@ impl LintRunner {
 
             let builder = builder.build().unwrap();
 
+            if let Some(path) = &explain_path {
+                ignore_decision =
+                    Some(IgnoreDecision::new(path, &builder, &ignore_options, &*file_system));
+            }
+
             // The ignore crate whitelists explicit paths, but priority
             // should be given to the ignore file. Many users lint
             // automatically and pass a list of changed files explicitly.
             // To accommodate this, unless `--no-ignore` is passed,
             // pre-filter the paths.
-            if !paths.is_empty() {
-                let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
+            // The configuration of an ignored file can still be explained.
+            if !paths.is_empty() && explain_path.is_none() {
+                let ignore =
+                    read_ignore_file(&*file_system, Path::new(&ignore_options.ignore_path));
 
                 paths.retain_mut(|p| {
                     // Try to prepend cwd to all paths
@@ -145,7 +253,7 @@ impl LintRunner {
 
                     std::mem::swap(p, &mut path);
 
-                    if path.is_dir() {
+                    if file_system.is_dir(&path) {
                         true
                     } else {
                         !(builder.matched(p, false).is_ignore()
@@ -170,16 +278,34 @@ impl LintRunner {
                     print_and_flush_stdout(stdout, &end);
                 }
 
-                return CliRunResult::LintNoFilesFound;
+                return Ok(CliRunResult::LintNoFilesFound);
             }
 
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
+        // The file read from stdin may not exist in the file system of the operating system
+        let walker = if self.file_system.is_some() || stdin_path.is_some() {
+            Walk::new_with_file_system(
+                &paths,
+                &ignore_options,
+                override_builder,
+                Arc::clone(&file_system),
+            )
+        } else {
+            Walk::new(&paths, &ignore_options, override_builder)
+        };
         let paths = walker.paths();
+        linted.files.clone_from(&paths);
         let number_of_files = paths.len();
 
+        let cwd = self.cwd.clone();
+        let baseline = baseline::load(&baseline_options, &cwd)?;
+        let baseline_path = baseline.as_ref().map(|(path, _)| path.clone());
+        let ratchet = ratchet::load(baseline_options.ratchet.as_deref(), &cwd)?;
+        let (workspace, fail_on_package_indices) =
+            workspace::load(&cwd, &format_str, &workspace_options.fail_on_package)?;
+
         let mut external_plugin_store = ExternalPluginStore::default();
 
         let search_for_nested_configs = !disable_nested_config &&
@@ -188,95 +314,133 @@ impl LintRunner {
             basic_options.config.is_none();
 
         let nested_configs = if search_for_nested_configs {
-            match Self::get_nested_configs(
-                stdout,
+            Self::get_nested_configs(
                 &handler,
                 &filters,
+                &basic_options.rule,
                 &paths,
+                &config_file_system,
+                config_cache.as_deref_mut(),
                 external_linter,
                 &mut external_plugin_store,
-            ) {
-                Ok(v) => v,
-                Err(v) => return v,
-            }
+                &mut renamed_configs,
+            )?
         } else {
             FxHashMap::default()
         };
 
+        if basic_options.init {
+            init::configure(&self.cwd, &mut oxlintrc, stdout)?;
+        }
+
         {
             let mut plugins = oxlintrc.plugins.unwrap_or_default();
             enable_plugins.apply_overrides(&mut plugins);
             oxlintrc.plugins = Some(plugins);
         }
 
+        // `--rule` takes precedence over `rules` in the config file
+        oxlintrc.rules.extend(basic_options.rule.clone());
+
+        // `--max-warnings` takes precedence over `maxWarnings` in the config file
+        let max_warnings = warning_options.max_warnings.or(oxlintrc.max_warnings);
+
         let oxlintrc_for_print = if misc_options.print_config || basic_options.init {
             Some(oxlintrc.clone())
         } else {
             None
         };
-        let config_builder = match ConfigStoreBuilder::from_oxlintrc(
-            false,
-            oxlintrc,
-            external_linter,
-            &mut external_plugin_store,
-        ) {
-            Ok(builder) => builder,
-            Err(e) => {
-                print_and_flush_stdout(
-                    stdout,
-                    &format!(
-                        "Failed to parse configuration file.\n{}\n",
-                        render_report(&handler, &OxcDiagnostic::error(e.to_string()))
-                    ),
-                );
-                return CliRunResult::InvalidOptionConfig;
+        // The configuration is resolved again to print or explain it
+        let cached_base = config_cache
+            .as_ref()
+            .and_then(|cache| cache.base.clone())
+            .filter(|_| oxlintrc_for_print.is_none() && explain_path.is_none());
+        let lint_config = if let Some(config) = cached_base {
+            config
+        } else {
+            let config_builder = ConfigStoreBuilder::from_oxlintrc_with_file_system(
+                false,
+                oxlintrc,
+                &config_file_system,
+                external_linter,
+                &mut external_plugin_store,
+            )
+            .map_err(|err| config_error(&handler, &OxcDiagnostic::error(err.to_string())))?
+            .with_filters(&filters);
+
+            if let (Some(path), Some(ignore)) = (explain_path, ignore_decision) {
+                let config_store =
+                    ConfigStore::new(config_builder.build(), nested_configs, external_plugin_store);
+                let explanation = Explanation::new(path, ignore, &config_store);
+                print_and_flush_stdout(stdout, &explanation.render(&format_str, &self.cwd));
+
+                return Ok(CliRunResult::PrintConfigResult);
             }
-        }
-        .with_filters(&filters);
 
-        if let Some(basic_config_file) = oxlintrc_for_print {
-            let config_file = config_builder.resolve_final_config_file(basic_config_file);
-            if misc_options.print_config {
+            // With a file path, print the configuration which applies to that file instead
+            if misc_options.print_config && provided_path_count > 0 {
+                let config_store =
+                    ConfigStore::new(config_builder.build(), nested_configs, external_plugin_store);
+                let config_file = print_config::config_of_file(&paths, &config_store)?;
                 print_and_flush_stdout(stdout, &config_file);
-                print_and_flush_stdout(stdout, "\n");
-
-                return CliRunResult::PrintConfigResult;
-            } else if basic_options.init {
-                let schema_relative_path = "node_modules/oxlint/configuration_schema.json";
-                let configuration = if self.cwd.join(schema_relative_path).is_file() {
-                    let mut config_json: Value = serde_json::from_str(&config_file).unwrap();
-                    if let Value::Object(ref mut obj) = config_json {
-                        let mut json_object = serde_json::Map::new();
-                        json_object.insert(
-                            "$schema".to_string(),
-                            format!("./{schema_relative_path}").into(),
-                        );
-                        json_object.extend(obj.clone());
-                        *obj = json_object;
-                    }
-                    serde_json::to_string_pretty(&config_json).unwrap()
-                } else {
-                    config_file
-                };
 
-                if fs::write(Self::DEFAULT_OXLINTRC, configuration).is_ok() {
-                    print_and_flush_stdout(stdout, "Configuration file created\n");
-                    return CliRunResult::ConfigFileInitSucceeded;
+                return Ok(CliRunResult::PrintConfigResult);
+            }
+
+            if let Some(basic_config_file) = oxlintrc_for_print {
+                let config_file = config_builder.resolve_final_config_file(basic_config_file);
+                if misc_options.print_config {
+                    print_and_flush_stdout(stdout, &config_file);
+                    print_and_flush_stdout(stdout, "\n");
+
+                    return Ok(CliRunResult::PrintConfigResult);
                 }
 
-                // failed case
-                print_and_flush_stdout(stdout, "Failed to create configuration file\n");
-                return CliRunResult::ConfigFileInitFailed;
+                // `--init`
+                let (message, result) = init::write_config(&self.cwd, config_file);
+                print_and_flush_stdout(stdout, message);
+                return Ok(result);
             }
-        }
+
+            let config = config_builder.build();
+            if let Some(cache) = &mut config_cache {
+                cache.base = Some(config.clone());
+            }
+            config
+        };
+        linted.configs = config_paths(config_file_system, config_cache);
 
         // TODO(refactor): pull this into a shared function, so that the language server can use
         // the same functionality.
-        let use_cross_module = config_builder.plugins().has_import()
+        let use_cross_module = lint_config.plugins().has_import()
             || nested_configs.values().any(|config| config.plugins().has_import());
-        let mut options = LintServiceOptions::new(self.cwd).with_cross_module(use_cross_module);
-
-        let lint_config = config_builder.build();
+        let mut options = LintServiceOptions::new(self.cwd)
+            .with_cross_module(use_cross_module)
+            // All files to lint are known, so rules which need the importers of every module can run.
+            .with_module_graph(true)
+            .with_file_system(Arc::clone(&file_system))
+            .with_max_memory(misc_options.max_memory_bytes())
+            .with_large_file_size(misc_options.large_file_size_bytes());
+
+        let renamed_rules = if fix_options.fix_config {
+            fix_config::fix_renamed_rules(
+                stdout,
+                &renamed_configs,
+                &*file_system,
+                misc_options.silent,
+            )?;
+            vec![]
+        } else {
+            deprecated_rules::renamed_rules(&renamed_configs)
+        };
+        if let Some(message) = deprecated_rules::report(
+            iter::once(&lint_config).chain(nested_configs.values()),
+            &renamed_rules,
+            &warning_options,
+            misc_options.silent,
+        )? {
+            print_and_flush_stdout(stdout, &message);
+        }
 
         let report_unused_directives = match inline_config_options.report_unused_directives {
             ReportUnusedDirectives::WithoutSeverity(true) => Some(AllowWarnDeny::Warn),
@@ -284,40 +448,114 @@ impl LintRunner {
             _ => None,
         };
 
-        let linter = Linter::new(
+        // Results of cross-module rules and JS plugins depend on more than the file being linted,
+        // so they can't be cached. Neither can source text from stdin.
+        let cache = (cache_options.cache
+            && stdin_path.is_none()
+            && !use_cross_module
+            && !basic_options.type_aware
+            && external_plugin_store.is_empty())
+        .then(|| {
+            let mut nested_configs = nested_configs.iter().collect::<Vec<_>>();
+            nested_configs.sort_unstable_by_key(|(path, _)| *path);
+            let config = format!(
+                "{lint_config:?}{nested_configs:?}{:?}{:?}{report_unused_directives:?}{:?}",
+                fix_options.fix_kind(),
+                fix_options.apply_suggestions,
+                basic_options.tsconfig,
+            );
+            LintCache::open(&cache_options, options.cwd(), &config)
+        });
+
+        let mut linter = Linter::new(
             LintOptions::default(),
             ConfigStore::new(lint_config, nested_configs, external_plugin_store),
             self.external_linter,
         )
         .with_fix(fix_options.fix_kind())
-        .with_report_unused_directives(report_unused_directives);
+        .with_suggestion_choices(fix_options.apply_suggestions)
+        .with_report_unused_directives(report_unused_directives)
+        .with_rule_timeout(misc_options.rule_timeout.map(Duration::from_millis));
+
+        linter = linter
+            .with_fix_preview(output_options.show_fixes && format_str == OutputFormat::Default);
+        let timings = (output_options.timing || output_options.timing_json.is_some())
+            .then(|| Arc::new(RuleTimings::new()));
+        if let Some(timings) = &timings {
+            linter = linter.with_timing(Arc::clone(timings));
+        }
 
-        let tsconfig = basic_options.tsconfig;
-        if let Some(path) = tsconfig.as_ref() {
-            if path.is_file() {
-                options = options.with_tsconfig(path);
-            } else {
+        if let Some(path) = &basic_options.tsconfig {
+            if !file_system.is_file(path) {
                 let path = if path.is_relative() { options.cwd().join(path) } else { path.clone() };
-
-                print_and_flush_stdout(
-                    stdout,
-                    &format!(
+                return Err((
+                    CliRunResult::InvalidOptionTsConfig,
+                    format!(
                         "The tsconfig file {:?} does not exist, Please provide a valid tsconfig file.\n",
                         path.to_string_lossy().cow_replace('\\', "/")
                     ),
-                );
-
-                return CliRunResult::InvalidOptionTsConfig;
+                ));
             }
+            options = options.with_tsconfig(path);
         }
 
-        let (mut diagnostic_service, tx_error) =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+        if basic_options.type_aware {
+            let type_checker = type_aware::spawn_type_checker(&basic_options, options.cwd())?;
+            linter = linter.with_type_checker(Arc::new(type_checker));
+        }
+
+        let (mut diagnostic_service, tx_error) = Self::get_diagnostic_service(
+            &output_formatter,
+            &warning_options,
+            &misc_options,
+            max_warnings,
+        );
 
         let number_of_rules = linter.number_of_rules();
 
+        // Violations in the baseline are filtered out before they are counted.
+        let (tx_error, workspace_summary) = match &workspace {
+            Some(workspace) => {
+                let (tx_error, summary) = count_by_package(tx_error, Arc::clone(workspace));
+                (tx_error, Some(summary))
+            }
+            None => (tx_error, None),
+        };
+
+        // Violations in the baseline don't count towards the ratchet either.
+        let (tx_error, ratchet_counter) = match &ratchet {
+            Some(_) => {
+                let (tx_error, counter) = count_by_rule(tx_error);
+                (tx_error, Some(counter))
+            }
+            None => (tx_error, None),
+        };
+
+        // Diagnostics pass the cache tracker before violations in the baseline are filtered out,
+        // so files with suppressed violations are never cached.
+        let (tx_error, baseline_filter) = match baseline {
+            Some((_, mode)) => {
+                let (tx_error, filter) = filter_diagnostics(tx_error, mode, project.clone());
+                (tx_error, Some(filter))
+            }
+            None => (tx_error, None),
+        };
+
+        // Skip files which are unchanged since they were last linted without any diagnostics
+        let (paths, tx_error, cache_tracker) = match &cache {
+            Some(cache) => {
+                let paths = cache.stale_paths(paths);
+                let (tx_error, tracker) = track_diagnostics(tx_error);
+                (paths, tx_error, Some(tracker))
+            }
+            None => (paths, tx_error, None),
+        };
+        let cached_paths = if cache.is_some() { paths.clone() } else { vec![] };
+
         let allocator_pool = AllocatorPool::new(rayon::current_num_threads());
 
+        #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
+        let read_from_stdin = stdin_path.is_some();
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn(move || {
             let mut lint_service = LintService::new(linter, allocator_pool, options);
@@ -325,8 +563,9 @@ impl LintRunner {
 
             // Use `RawTransferFileSystem` if `oxlint2` feature is enabled.
             // This reads the source text into start of allocator, instead of the end.
+            // Source text from stdin is not read from disk.
             #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
-            {
+            if !read_from_stdin {
                 use crate::raw_fs::RawTransferFileSystem;
                 lint_service.with_file_system(Box::new(RawTransferFileSystem));
             }
@@ -334,7 +573,54 @@ impl LintRunner {
             lint_service.run(&tx_error);
         });
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+        let mut diagnostic_result = diagnostic_service.run(stdout);
+
+        if let (Some(cache), Some(tracker)) = (cache, cache_tracker) {
+            if let Err(err) = cache.finish(&cached_paths, tracker) {
+                print_and_flush_stdout(stdout, &format!("Failed to write lint cache: {err}\n"));
+            }
+        }
+
+        if let (Some(path), Some(filter)) = (baseline_path, baseline_filter) {
+            if let Some(message) = baseline::report(
+                &path,
+                filter.join().unwrap(),
+                baseline_options.generate_baseline,
+                &format_str,
+                &linted.files,
+                &project,
+            ) {
+                print_and_flush_stdout(stdout, &message);
+            }
+        }
+
+        let mut ratchet_result = None;
+        if let (Some((path, recorded)), Some(counter)) = (ratchet, ratchet_counter) {
+            let (message, result) = ratchet::finish(&path, recorded, counter.join().unwrap());
+            if let Some(message) = message.filter(|_| format_str == OutputFormat::Default) {
+                print_and_flush_stdout(stdout, &message);
+            }
+            ratchet_result = Some(result);
+        }
+
+        if let Some(timings) = timings {
+            print_and_flush_stdout(stdout, &timing::report(&timings, &output_options, &cwd));
+        }
+
+        if let (Some(workspace), Some(summary)) = (workspace, workspace_summary) {
+            let (breakdown, result) = workspace::finish(
+                &workspace,
+                &summary.join().unwrap(),
+                &format_str,
+                &fail_on_package_indices,
+                diagnostic_result,
+                max_warnings,
+            );
+            if let Some(breakdown) = breakdown {
+                print_and_flush_stdout(stdout, &breakdown);
+            }
+            diagnostic_result = result;
+        }
 
         if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
             number_of_files,
@@ -345,7 +631,9 @@ impl LintRunner {
             print_and_flush_stdout(stdout, &end);
         }
 
-        if diagnostic_result.errors_count() > 0 {
+        Ok(if let Some(result) = ratchet_result {
+            result
+        } else if diagnostic_result.errors_count() > 0 {
             CliRunResult::LintFoundErrors
         } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
             CliRunResult::LintNoWarningsAllowed
@@ -353,12 +641,44 @@ impl LintRunner {
             CliRunResult::LintMaxWarningsExceeded
         } else {
             CliRunResult::LintSucceeded
-        }
+        })
     }
 }
 
 impl LintRunner {
-    const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    pub const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+
+    /// Lint all files, then watch them for changes, see [`watch::run`].
+    fn watch(mut self, stdout: &mut dyn Write) -> CliRunResult {
+        self.config_cache = Some(Arc::default());
+        watch::run(stdout, &self.options.ignore_options, |paths, stdout, linted| {
+            let mut runner = self.clone();
+            if let Some(paths) = paths {
+                runner.options.paths = paths;
+            }
+            runner.lint(stdout, linted);
+        })
+    }
+
+    /// Lint on request of other processes until the daemon is shut down, see [`daemon::run`].
+    /// Configurations are resolved once and cached across requests, until a configuration file
+    /// changes.
+    #[cfg(unix)]
+    fn daemon(mut self, stdout: &mut dyn Write) -> CliRunResult {
+        self.config_cache = Some(Arc::default());
+        daemon::run(&self.cwd, stdout, |options, stdin, output| {
+            let mut runner = self.clone();
+            runner.options = options;
+            runner.stdin = stdin;
+            runner.lint(output, &mut LintedPaths::default())
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn daemon(self, stdout: &mut dyn Write) -> CliRunResult {
+        print_and_flush_stdout(stdout, "`--daemon` is only supported on Unix.\n");
+        CliRunResult::InvalidOptionDaemon
+    }
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -366,17 +686,35 @@ impl LintRunner {
         self
     }
 
+    /// Find, read and fix files and configuration files in `file_system` instead of the file
+    /// system of the operating system. The cache, baseline, `--init` and `--watch` still use the file
+    /// system of the operating system, and `.gitignore` files are not respected.
+    #[must_use]
+    pub fn with_file_system(mut self, file_system: Arc<dyn FileSystem>) -> Self {
+        self.file_system = Some(file_system);
+        self
+    }
+
+    /// Source text for `--stdin-filename`, instead of reading it from stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
         misc_options: &MiscOptions,
+        max_warnings: Option<usize>,
     ) -> (DiagnosticService, DiagnosticSender) {
         let (service, sender) = DiagnosticService::new(reporter.get_diagnostic_reporter());
         (
             service
                 .with_quiet(warning_options.quiet)
                 .with_silent(misc_options.silent)
-                .with_max_warnings(warning_options.max_warnings),
+                .with_max_warnings(max_warnings)
+                .with_sorted(true),
             sender,
         )
     }
@@ -422,77 +760,89 @@ impl LintRunner {
     }
 
     fn get_nested_configs(
-        stdout: &mut dyn Write,
         handler: &GraphicalReportHandler,
         filters: &Vec<LintFilter>,
+        rules: &OxlintRules,
         paths: &Vec<Arc<OsStr>>,
+        file_system: &dyn FileSystem,
+        mut cache: Option<&mut ConfigCache>,
         external_linter: Option<&ExternalLinter>,
         external_plugin_store: &mut ExternalPluginStore,
-    ) -> Result<FxHashMap<PathBuf, Config>, CliRunResult> {
+        renamed_configs: &mut Vec<Oxlintrc>,
+    ) -> Result<FxHashMap<PathBuf, Config>, (CliRunResult, String)> {
         // TODO(perf): benchmark whether or not it is worth it to store the configurations on a
         // per-file or per-directory basis, to avoid calling `.parent()` on every path.
         let mut nested_oxlintrc = FxHashMap::<&Path, Oxlintrc>::default();
         let mut nested_configs = FxHashMap::<PathBuf, Config>::default();
-        // get all of the unique directories among the paths to use for search for
-        // oxlint config files in those directories and their ancestors
-        // e.g. `/some/file.js` will check `/some` and `/`
+        // search the unique directories among the paths and their ancestors for oxlint config
+        // files, e.g. `/some/file.js` will check `/some` and `/`
         //      `/some/other/file.js` will check `/some/other`, `/some`, and `/`
+        // The search stops at the root of the project: a directory with a config file with
+        // `root: true`, or the root of a workspace (`pnpm-workspace.yaml` or `workspaces` in
+        // `package.json`), so config files outside of the project are not loaded.
         let mut directories = FxHashSet::default();
         for path in paths {
             let path = Path::new(path);
             // Start from the file's parent directory and walk up the tree
             let mut current = path.parent();
             while let Some(dir) = current {
-                // NOTE: Initial benchmarking showed that it was faster to iterate over the directories twice
-                // rather than constructing the configs in one iteration. It's worth re-benchmarking that though.
                 let inserted = directories.insert(dir);
                 if !inserted {
                     break;
                 }
-                current = dir.parent();
-            }
-        }
-        for directory in directories {
-            #[expect(clippy::match_same_arms)]
-            match Self::find_oxlint_config_in_directory(directory) {
-                Ok(Some(v)) => {
-                    nested_oxlintrc.insert(directory, v);
+                // TODO(camc314): report invalid config files
+                let oxlintrc = match cache.as_ref().and_then(|cache| cache.directories.get(dir)) {
+                    Some(cached) => cached.as_ref().map(|(oxlintrc, _)| oxlintrc.clone()),
+                    None => Self::find_oxlint_config_in_directory(dir, file_system).ok().flatten(),
+                };
+                let is_root = oxlintrc.as_ref().is_some_and(|oxlintrc| oxlintrc.root)
+                    || Workspace::is_root(dir);
+                if let Some(oxlintrc) = oxlintrc {
+                    nested_oxlintrc.insert(dir, oxlintrc);
                 }
-                Ok(None) => {}
-                Err(_) => {
-                    // TODO(camc314): report this error
+                if is_root {
+                    break;
                 }
+                current = dir.parent();
             }
         }
 
         // iterate over each config and build the ConfigStore
-        for (dir, oxlintrc) in nested_oxlintrc {
-            // TODO(refactor): clean up all of the error handling in this function
-            let builder = match ConfigStoreBuilder::from_oxlintrc(
+        for (dir, mut oxlintrc) in nested_oxlintrc {
+            if oxlintrc.renamed_rules().next().is_some() {
+                renamed_configs.push(oxlintrc.clone());
+            }
+            if let Some(Some((_, config))) =
+                cache.as_ref().and_then(|cache| cache.directories.get(dir))
+            {
+                nested_configs.insert(dir.to_path_buf(), config.clone());
+                continue;
+            }
+            let cached_oxlintrc = cache.is_some().then(|| oxlintrc.clone());
+            oxlintrc.rules.extend(rules.clone());
+            let builder = ConfigStoreBuilder::from_oxlintrc_with_file_system(
                 false,
                 oxlintrc,
+                file_system,
                 external_linter,
                 external_plugin_store,
-            ) {
-                Ok(builder) => builder,
-                Err(e) => {
-                    print_and_flush_stdout(
-                        stdout,
-                        &format!(
-                            "Failed to parse configuration file.\n{}\n",
-                            render_report(handler, &OxcDiagnostic::error(e.to_string()))
-                        ),
-                    );
-
-                    return Err(CliRunResult::InvalidOptionConfig);
-                }
-            }
+            )
+            .map_err(|err| config_error(handler, &OxcDiagnostic::error(err.to_string())))?
             .with_filters(filters);
 
             let config = builder.build();
+            if let (Some(cache), Some(oxlintrc)) = (cache.as_deref_mut(), cached_oxlintrc) {
+                cache.directories.insert(dir.to_path_buf(), Some((oxlintrc, config.clone())));
+            }
             nested_configs.insert(dir.to_path_buf(), config);
         }
 
+        if let Some(cache) = cache {
+            for dir in directories {
+                cache.directories.entry(dir.to_path_buf()).or_insert(None);
+            }
+        }
+
         Ok(nested_configs)
     }
 
@@ -500,12 +850,16 @@ impl LintRunner {
     // when config is provided, but not found, an String with the formatted error is returned, else the oxlintrc config file is returned
     // when no config is provided, it will search for the default file names in the current working directory
     // when no file is found, the default configuration is returned
-    fn find_oxlint_config(cwd: &Path, config: Option<&PathBuf>) -> Result<Oxlintrc, OxcDiagnostic> {
+    fn find_oxlint_config(
+        cwd: &Path,
+        config: Option<&PathBuf>,
+        file_system: &dyn FileSystem,
+    ) -> Result<Oxlintrc, OxcDiagnostic> {
         let path: &Path = config.map_or(Self::DEFAULT_OXLINTRC.as_ref(), PathBuf::as_ref);
         let full_path = cwd.join(path);
 
-        if config.is_some() || full_path.exists() {
-            return Oxlintrc::from_file(&full_path);
+        if config.is_some() || file_system.metadata(&full_path).is_ok() {
+            return Oxlintrc::from_file_with_file_system(&full_path, file_system);
         }
         Ok(Oxlintrc::default())
     }
@@ -513,10 +867,13 @@ impl LintRunner {
     /// Looks in a directory for an oxlint config file, returns the oxlint config if it exists
     /// and returns `Err` if none exists or the file is invalid. Does not apply the default
     /// config file.
-    fn find_oxlint_config_in_directory(dir: &Path) -> Result<Option<Oxlintrc>, OxcDiagnostic> {
+    fn find_oxlint_config_in_directory(
+        dir: &Path,
+        file_system: &dyn FileSystem,
+    ) -> Result<Option<Oxlintrc>, OxcDiagnostic> {
         let possible_config_path = dir.join(Self::DEFAULT_OXLINTRC);
-        if possible_config_path.is_file() {
-            Oxlintrc::from_file(&possible_config_path).map(Some)
+        if file_system.is_file(&possible_config_path) {
+            Oxlintrc::from_file_with_file_system(&possible_config_path, file_system).map(Some)
         } else {
             Ok(None)
         }
@@ -547,7 +904,7 @@ impl LintRunner {
     }
 }
 
-fn print_and_flush_stdout(stdout: &mut dyn Write, message: &str) {
+pub(crate) fn print_and_flush_stdout(stdout: &mut dyn Write, message: &str) {
     stdout.write_all(message.as_bytes()).or_else(check_for_writer_error).unwrap();
     stdout.flush().unwrap();
 }
@@ -567,12 +924,32 @@ fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -
     err
 }
 
+/// Error for an invalid configuration file.
+fn config_error(
+    handler: &GraphicalReportHandler,
+    diagnostic: &OxcDiagnostic,
+) -> (CliRunResult, String) {
+    (
+        CliRunResult::InvalidOptionConfig,
+        format!("Failed to parse configuration file.\n{}\n", render_report(handler, diagnostic)),
+    )
+}
+
 #[cfg(test)]
 mod test {
-    use std::{fs, path::PathBuf};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use oxc_linter::{MemoryFileSystem, OsFileSystem};
 
     use super::LintRunner;
-    use crate::tester::Tester;
+    use crate::{
+        cli::{CliRunResult, lint_command},
+        tester::Tester,
+    };
 
     // lints the full directory of fixtures,
     // so do not snapshot it, test only
@@ -797,6 +1174,12 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_non_utf8_files() {
+        let args = &["fixtures/encoding"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn lint_vue_file() {
         let args = &["fixtures/vue/debugger.vue"];
@@ -891,6 +1274,18 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_print_config_for_file() {
+        let tester = Tester::new().with_cwd("fixtures/print_config/file".into());
+        tester.test_and_snapshot_multiple(&[
+            &["--print-config", "a.test.ts"],
+            &["--print-config", "b.ts"],
+            &["--print-config", "nested/c.js"],
+            &["--print-config", "nested/c.js", "--disable-nested-config"],
+            &["--print-config", "."],
+        ]);
+    }
+
     #[test]
     fn test_init_config() {
         assert!(!fs::exists(LintRunner::DEFAULT_OXLINTRC).unwrap());
@@ -903,6 +1298,276 @@ mod test {
         fs::remove_file(LintRunner::DEFAULT_OXLINTRC).unwrap();
     }
 
+    #[test]
+    fn test_file_system() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#)
+                .with_file("/project/.eslintignore", "src/ignored")
+                .with_file("/project/src/debugger.js", "debugger;\n")
+                .with_file("/project/src/ignored/debugger.js", "debugger;\n")
+                .with_file("/project/.hidden/debugger.js", "debugger;\n")
+                .with_file(
+                    "/project/src/nested/.oxlintrc.json",
+                    r#"{ "rules": { "eqeqeq": "error" } }"#,
+                )
+                .with_file("/project/src/nested/eqeqeq.js", "a == b;\n"),
+        );
+        let (result, output) =
+            Tester::new().with_file_system(&file_system).lint(&["--format=unix", "--fix"]);
+
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        // `debugger` is fixed, the ignored and hidden files are skipped, and the nested config is
+        // used for `src/nested`.
+        assert_eq!(
+            output,
+            "src/nested/eqeqeq.js:1:3: Expected === and instead saw == [Error/eslint(eqeqeq)]\n\n1 problem\n"
+        );
+        assert_eq!(file_system.file(Path::new("/project/src/debugger.js")).unwrap(), "\n");
+    }
+
+    #[test]
+    fn test_fix_multiple_passes() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file(
+                    "/project/.oxlintrc.json",
+                    r#"{ "plugins": ["unicorn"], "rules": { "unicorn/no-useless-spread": "error" } }"#,
+                )
+                .with_file("/project/spread.js", "a = [...[...[...[1, 2]]]];\n"),
+        );
+        let (result, output) = Tester::new()
+            .with_file_system(&file_system)
+            .lint(&["--format=unix", "--fix-dangerously"]);
+
+        // The nested spreads conflict, so one is fixed in each pass, and nothing is reported
+        assert!(matches!(result, CliRunResult::LintSucceeded), "{output}");
+        assert_eq!(file_system.file(Path::new("/project/spread.js")).unwrap(), "a = [1, 2];\n");
+    }
+
+    #[test]
+    fn test_markdown() {
+        let markdown = "# Example\n\n```js\ndebugger;\n```\n\n```sh\nnpm install\n```\n";
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#)
+                .with_file("/project/README.md", markdown),
+        );
+        let tester = Tester::new().with_file_system(&file_system);
+
+        // Markdown files are only linted with `--markdown`
+        let (result, _) = tester.lint(&["--format=unix"]);
+        assert!(matches!(result, CliRunResult::LintSucceeded));
+
+        // The diagnostic points at the line of the code block in the Markdown file
+        let (result, output) = tester.lint(&["--format=unix", "--markdown"]);
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert!(output.contains("README.md:4:1: `debugger` statement is not allowed"), "{output}");
+
+        // Fixes are applied to the code block
+        let (result, _) = tester.lint(&["--format=unix", "--markdown", "--fix-dangerously"]);
+        assert!(matches!(result, CliRunResult::LintSucceeded));
+        let fixed = file_system.file(Path::new("/project/README.md")).unwrap();
+        assert!(!fixed.contains("debugger"), "{fixed}");
+        assert!(fixed.starts_with("# Example\n\n```js\n"), "{fixed}");
+        assert!(fixed.ends_with("```\n\n```sh\nnpm install\n```\n"), "{fixed}");
+    }
+
+    #[test]
+    fn test_rule_timeout() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#)
+                .with_file("/project/debugger.js", "debugger;\n")
+                // Rules run on files with more than 200,000 nodes with the rules in the inner loop
+                .with_file("/project/large.js", "debugger;\n".repeat(200_001)),
+        );
+        let (_, output) = Tester::new().with_file_system(&file_system).lint(&[
+            "--format=unix",
+            "--rule-timeout",
+            "0",
+        ]);
+
+        // The rule is stopped before it visits the `debugger` statements
+        for file in ["debugger.js", "large.js"] {
+            assert!(
+                output.contains(&format!(
+                    "{file}:1:1: Rule `eslint/no-debugger` was skipped on this file because it \
+                     ran longer than 0ms"
+                )),
+                "{output}"
+            );
+        }
+        assert!(!output.contains("`debugger` statement"), "{output}");
+    }
+
+    #[test]
+    fn test_memory_limits() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#)
+                .with_file("/project/a.js", "debugger;\n")
+                .with_file("/project/b.js", format!("{}debugger;\n", "// generated\n".repeat(1024)))
+                .with_file("/project/c.js", "debugger;\n"),
+        );
+        // Every file is processed alone, as the memory limit is always exceeded, and `b.js` is
+        // larger than the large file size.
+        let (result, output) = Tester::new().with_file_system(&file_system).lint(&[
+            "--format=unix",
+            "--max-memory",
+            "0",
+            "--large-file-size",
+            "1",
+        ]);
+
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert_eq!(
+            output,
+            "a.js:1:1: `debugger` statement is not allowed [Error/eslint(no-debugger)]\n\
+             b.js:1025:1: `debugger` statement is not allowed [Error/eslint(no-debugger)]\n\
+             c.js:1:1: `debugger` statement is not allowed [Error/eslint(no-debugger)]\n\n3 problems\n"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_rules() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file(
+                    "/project/.oxlintrc.json",
+                    r#"{ "rules": { "typescript/no-var-requires": "error", "typescript/ban-types": "warn" } }"#,
+                )
+                .with_file("/project/test.js", "export const a = 1;\n"),
+        );
+        let tester = Tester::new().with_file_system(&file_system);
+
+        let (result, output) = tester.lint(&["--format=unix"]);
+        assert!(matches!(result, CliRunResult::LintSucceeded), "{output}");
+        assert_eq!(
+            output,
+            "Warning: The rule `typescript/ban-types` is deprecated, use `typescript/no-empty-object-type`, `typescript/no-unsafe-function-type` or `typescript/no-wrapper-object-types` instead.\n\
+             Warning: The rule `typescript/no-var-requires` is deprecated, use `typescript/no-require-imports` instead.\n"
+        );
+
+        let (result, output) = tester.lint(&["--format=unix", "--no-deprecated"]);
+        assert!(matches!(result, CliRunResult::InvalidOptionConfig), "{output}");
+        assert!(
+            output.starts_with("Error: The rule `typescript/ban-types` is deprecated"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_renamed_rules() {
+        let config = "{\n  // old names\n  \"rules\": { \"unicorn/no-reduce\": \"error\", \"no-new-symbol\": \"off\" }\n}\n";
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", config)
+                .with_file("/project/test.js", "[1, 2].reduce((a, b) => a + b);\n"),
+        );
+        let tester = Tester::new().with_file_system(&file_system);
+
+        // The rules are configured by their current names
+        let (result, output) = tester.lint(&["--format=unix"]);
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert!(
+            output.starts_with(
+                "Warning: The rule `no-new-symbol` is renamed to `no-new-native-nonconstructor`.\n\
+                 Warning: The rule `unicorn/no-reduce` is renamed to `unicorn/no-array-reduce`.\n\
+                 Run with `--fix-config` to update the names of renamed rules in the configuration files.\n"
+            ),
+            "{output}"
+        );
+        assert!(output.contains("[Error/unicorn(no-array-reduce)]"), "{output}");
+
+        let (result, output) = tester.lint(&["--format=unix", "--no-deprecated"]);
+        assert!(matches!(result, CliRunResult::InvalidOptionConfig), "{output}");
+
+        let (result, output) = tester.lint(&["--format=unix", "--fix-config"]);
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert!(
+            output.starts_with("Updated renamed rules in /project/.oxlintrc.json\n"),
+            "{output}"
+        );
+        assert_eq!(
+            file_system.file(Path::new("/project/.oxlintrc.json")).unwrap(),
+            "{\n  // old names\n  \"rules\": { \"unicorn/no-array-reduce\": \"error\", \"no-new-native-nonconstructor\": \"off\" }\n}\n"
+        );
+
+        let (_, output) = tester.lint(&["--format=unix"]);
+        assert!(!output.contains("Warning: The rule"), "{output}");
+    }
+
+    #[test]
+    fn test_apply_suggestions() {
+        let lint = |choice: &str| {
+            let file_system = Arc::new(
+                MemoryFileSystem::default()
+                    .with_file(
+                        "/project/.oxlintrc.json",
+                        r#"{ "plugins": ["react"], "rules": { "no-debugger": "error", "react/forward-ref-uses-ref": "error" } }"#,
+                    )
+                    .with_file("/project/src/test.js", "debugger;\nforwardRef((props) => null);\n"),
+            );
+            Tester::new().with_file_system(&file_system).lint(&["--apply-suggestions", choice]);
+            file_system.file(Path::new("/project/src/test.js")).unwrap()
+        };
+
+        // Only the chosen suggestion is applied, not the fix of `no-debugger`.
+        assert_eq!(
+            lint("react/forward-ref-uses-ref:add-ref-parameter"),
+            "debugger;\nforwardRef((props, ref) => null);\n"
+        );
+        assert_eq!(lint("forward-ref-uses-ref:0"), "debugger;\n(props) => null;\n");
+        assert_eq!(
+            lint("forward-ref-uses-ref:unknown"),
+            "debugger;\nforwardRef((props) => null);\n"
+        );
+    }
+
+    #[test]
+    fn test_cli_rules() {
+        let file_system = Arc::new(
+            MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "rules": { "no-debugger": "error" } }"#)
+                .with_file("/project/test.js", "debugger;\na == b;\na == null;\n"),
+        );
+        let (result, output) = Tester::new().with_file_system(&file_system).lint(&[
+            "--format=unix",
+            "--rule",
+            "no-debugger=off",
+            "--rule",
+            r#"eqeqeq=["error", "smart"]"#,
+        ]);
+
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        // `--rule` takes precedence over the config file, and passes the options to the rule.
+        assert_eq!(
+            output,
+            "test.js:2:3: Expected === and instead saw == [Error/eslint(eqeqeq)]\n\n1 problem\n"
+        );
+    }
+
+    #[test]
+    fn test_init_config_detects_frameworks() {
+        let cwd = PathBuf::from("fixtures/init_frameworks");
+        let config_path = cwd.join(LintRunner::DEFAULT_OXLINTRC);
+        assert!(!fs::exists(&config_path).unwrap());
+
+        Tester::new().with_cwd(cwd).test(&["--init"]);
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        fs::remove_file(&config_path).unwrap();
+
+        let plugins = config["plugins"].as_array().unwrap();
+        for plugin in ["react", "jsx-a11y", "typescript"] {
+            assert!(plugins.contains(&plugin.into()), "{plugin} is not enabled: {plugins:?}");
+        }
+        assert_eq!(config["categories"]["suspicious"], "warn");
+        assert_eq!(config["env"]["browser"], true);
+    }
+
     #[test]
     fn test_overrides() {
         let args_1 = &["-c", "fixtures/overrides/.oxlintrc.json", "fixtures/overrides/test.js"];
@@ -1001,6 +1666,17 @@ mod test {
             .test_and_snapshot_multiple(&[args_1, args_2]);
     }
 
+    #[test]
+    fn test_max_warnings() {
+        let args_1 = &["-c", ".oxlintrc.json", "test.js"];
+        // `--max-warnings` takes precedence over `maxWarnings` in the config file
+        let args_2 = &["-c", ".oxlintrc.json", "--max-warnings", "3", "test.js"];
+        let args_3 = &["--max-warnings", "2", "test.js"];
+        Tester::new()
+            .with_cwd("fixtures/max_warnings".into())
+            .test_and_snapshot_multiple(&[args_1, args_2, args_3]);
+    }
+
     #[test]
     fn test_two_rules_with_same_rule_name_from_different_plugins() {
         // Issue: <https://github.com/oxc-project/oxc/issues/8485>
@@ -1049,6 +1725,14 @@ mod test {
         Tester::new().with_cwd("fixtures/nested_config".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_nested_config_overrides() {
+        // The `files` of the overrides of `package/.oxlintrc.json` are resolved from `package`,
+        // so `root.test.ts` is not affected by them.
+        let args = &["-A", "all"];
+        Tester::new().with_cwd("fixtures/nested_config_overrides".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_nested_config_explicit_config_precedence() {
         // `--config` takes absolute precedence over nested configs, and will be used for
@@ -1110,17 +1794,18 @@ mod test {
 
         // Test case 1: Invalid path that should fail
         let invalid_config = PathBuf::from("child/../../fixtures/linter/eslintrc.json");
-        let result = LintRunner::find_oxlint_config(&cwd, Some(&invalid_config));
+        let result = LintRunner::find_oxlint_config(&cwd, Some(&invalid_config), &OsFileSystem);
         assert!(result.is_err(), "Expected config lookup to fail with invalid path");
 
         // Test case 2: Valid path that should pass
         let valid_config = PathBuf::from("fixtures/linter/eslintrc.json");
-        let result = LintRunner::find_oxlint_config(&cwd, Some(&valid_config));
+        let result = LintRunner::find_oxlint_config(&cwd, Some(&valid_config), &OsFileSystem);
         assert!(result.is_ok(), "Expected config lookup to succeed with valid path");
 
         // Test case 3: Valid path using parent directory (..) syntax that should pass
         let valid_parent_config = PathBuf::from("fixtures/linter/../linter/eslintrc.json");
-        let result = LintRunner::find_oxlint_config(&cwd, Some(&valid_parent_config));
+        let result =
+            LintRunner::find_oxlint_config(&cwd, Some(&valid_parent_config), &OsFileSystem);
         assert!(result.is_ok(), "Expected config lookup to succeed with parent directory syntax");
 
         // Verify the resolved path is correct
@@ -1156,6 +1841,48 @@ mod test {
         Tester::new().with_cwd("fixtures/import-cycle".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_no_unused_modules() {
+        // `math.js` is imported as a namespace, so none of its exports are reported.
+        let args = &["--import-plugin", "-A", "all", "-D", "import/no-unused-modules"];
+        Tester::new().with_cwd("fixtures/no_unused_modules".into()).test_and_snapshot(args);
+
+        // The exports of the entry point are not reported, `utils/dead.js` is not imported.
+        let args = &["-c", "entry-points.json"];
+        Tester::new().with_cwd("fixtures/no_unused_modules".into()).test_and_snapshot(args);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_type_aware() {
+        // The type checker of the fixture says that every expression is a promise.
+        let args = &[
+            "--type-aware",
+            "--type-checker",
+            "./type-checker.sh",
+            "-A",
+            "all",
+            "-D",
+            "typescript/no-floating-promises",
+        ];
+        Tester::new().with_cwd("fixtures/type_aware".into()).test_and_snapshot(args);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_type_aware_without_type_checker() {
+        let args = &["--type-aware", "--type-checker", "./missing-type-checker"];
+        Tester::new().with_cwd("fixtures/type_aware".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_coverage() {
+        // `no-debugger` is turned off in `covered.js` and escalated in `uncovered.js`, while
+        // `partial.js` and `untracked.js`, which is not in the report, keep the configured severity.
+        let args = &["-c", ".oxlintrc.json"];
+        Tester::new().with_cwd("fixtures/coverage".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_rule_config_being_enabled_correctly() {
         let args = &["-c", ".oxlintrc.json"];
@@ -1179,4 +1906,179 @@ mod test {
         let args = &["-c", ".oxlintrc.json"];
         Tester::new().with_cwd("fixtures/issue_11644".into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_location = dir.path().join("oxlintcache.json");
+        let args =
+            &["--cache", "--cache-location", cache_location.to_str().unwrap(), "fixtures/cache"];
+
+        for _ in 0..2 {
+            Tester::new().test(args);
+
+            let cache = fs::read_to_string(&cache_location).unwrap();
+            let cache: serde_json::Value = serde_json::from_str(&cache).unwrap();
+            let files = cache["files"].as_object().unwrap();
+            // Only files without diagnostics are cached
+            assert_eq!(files.len(), 1);
+            assert!(files.keys().all(|path| path.ends_with("clean.js")));
+        }
+    }
+
+    #[test]
+    fn test_config_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join(".oxlintrc.json");
+        fs::write(dir.path().join("debugger.js"), "debugger;\n").unwrap();
+        fs::write(&config, r#"{ "rules": { "no-debugger": "error" } }"#).unwrap();
+
+        let options = lint_command().run_inner(&["--format=unix"]).unwrap();
+        let mut runner = LintRunner::new(options, None).with_cwd(dir.path().to_path_buf());
+        runner.config_cache = Some(Arc::default());
+        let run = || runner.clone().lint(&mut Vec::new(), &mut LintedPaths::default());
+
+        assert!(matches!(run(), CliRunResult::LintFoundErrors));
+        let cache = runner.config_cache.as_ref().unwrap();
+        assert!(cache.lock().unwrap().base.is_some());
+        assert!(matches!(run(), CliRunResult::LintFoundErrors));
+
+        // A changed configuration file is read again
+        fs::write(&config, r#"{ "rules": { "no-debugger": "off" } }"#).unwrap();
+        assert!(matches!(run(), CliRunResult::LintSucceeded));
+    }
+
+    #[test]
+    fn test_workspace_packages() {
+        let args = &["-D", "no-debugger"];
+        Tester::new().with_cwd("fixtures/workspaces".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_show_fixes() {
+        let args = &["--show-fixes", "-W", "unicorn/no-zero-fractions", "-A", "no-unused-vars"];
+        Tester::new().with_cwd("fixtures/show_fixes".into()).test_and_snapshot(args);
+        // Fixes are previewed in the default output format only
+        let args = &["--show-fixes", "--format", "unix"];
+        Tester::new().with_cwd("fixtures/show_fixes".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    #[cfg(feature = "wasm_plugins")]
+    fn test_wasm_plugin() {
+        let args = &["--show-fixes"];
+        Tester::new().with_cwd("fixtures/wasm_plugin".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_config_root() {
+        // The config file in the parent directory is invalid, but not loaded because of `root: true`
+        Tester::new().with_cwd("fixtures/config_root/project".into()).test_and_snapshot(&[]);
+    }
+
+    #[test]
+    fn test_config_root_workspace() {
+        // The search for config files stops at the root of the workspace, and packages with their
+        // own config file are linted with it.
+        Tester::new().with_cwd("fixtures/config_root/monorepo".into()).test_and_snapshot(&[]);
+        Tester::new()
+            .with_cwd("fixtures/config_root/monorepo/packages/a".into())
+            .test_and_snapshot(&[]);
+    }
+
+    #[test]
+    fn test_fail_on_package() {
+        let args = &["-D", "no-debugger", "--fail-on-package", "@acme/utils"];
+        Tester::new().with_cwd("fixtures/workspaces".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_fail_on_unknown_package() {
+        let args = &["--fail-on-package", "@acme/unknown"];
+        Tester::new().with_cwd("fixtures/workspaces".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let args = &["--baseline", "baseline.json", "debugger.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_generate_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        let args =
+            &["--generate-baseline", "--baseline", baseline.to_str().unwrap(), "debugger.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test(args);
+
+        let baseline = fs::read_to_string(&baseline).unwrap();
+        let baseline: serde_json::Value = serde_json::from_str(&baseline).unwrap();
+        let entries = baseline["files"]["debugger.js"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["rule"], "eslint(no-debugger)");
+        assert_eq!(entries[0]["count"], 2);
+    }
+
+    #[test]
+    fn test_ratchet_increased() {
+        let args = &["--ratchet", "ratchet.json", "debugger.js"];
+        Tester::new().with_cwd("fixtures/ratchet".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_ratchet_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let ratchet = dir.path().join("ratchet.json");
+        let args = &["--ratchet", ratchet.to_str().unwrap(), "debugger.js"];
+
+        // Created if it does not exist
+        Tester::new().with_cwd("fixtures/ratchet".into()).test(args);
+        let read = || -> serde_json::Value {
+            serde_json::from_str(&fs::read_to_string(&ratchet).unwrap()).unwrap()
+        };
+        assert_eq!(read()["rules"]["eslint(no-debugger)"], 2);
+
+        // Updated when the count decreases
+        fs::write(&ratchet, r#"{ "rules": { "eslint(no-debugger)": 3 } }"#).unwrap();
+        Tester::new().with_cwd("fixtures/ratchet".into()).test(args);
+        assert_eq!(read()["rules"]["eslint(no-debugger)"], 2);
+    }
+
+    fn lint_stdin(args: &[&str], source_text: &str) -> (CliRunResult, String) {
+        let mut args = args.to_vec();
+        args.insert(0, "--format=unix");
+        Tester::new().with_cwd("fixtures/stdin".into()).with_stdin(source_text).lint(&args)
+    }
+
+    #[test]
+    fn test_stdin_filename() {
+        let source_text = "debugger;\nconst a: number = 1;\na == 2;\n";
+
+        // The file does not exist, but its path decides the source type and the config
+        let (result, output) = lint_stdin(&["--stdin-filename", "unsaved.ts"], source_text);
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert_eq!(
+            output,
+            "unsaved.ts:1:1: `debugger` statement is not allowed [Error/eslint(no-debugger)]\n\n1 problem\n"
+        );
+        let (result, output) = lint_stdin(&["--stdin-filename", "nested/unsaved.ts"], source_text);
+        assert!(matches!(result, CliRunResult::LintFoundErrors), "{output}");
+        assert_eq!(
+            output,
+            "nested/unsaved.ts:3:3: Expected === and instead saw == [Error/eslint(eqeqeq)]\n\n1 problem\n"
+        );
+
+        // Ignore patterns apply to the path
+        let (result, _) = lint_stdin(
+            &["--stdin-filename", "unsaved.ts", "--ignore-pattern", "unsaved.ts"],
+            source_text,
+        );
+        assert!(matches!(result, CliRunResult::LintNoFilesFound));
+
+        let (result, _) = lint_stdin(&["--stdin-filename", "unsaved.ts", "--fix"], source_text);
+        assert!(matches!(result, CliRunResult::InvalidOptionStdinFilename));
+        let (result, _) = lint_stdin(&["--stdin-filename", "unsaved.ts", "."], source_text);
+        assert!(matches!(result, CliRunResult::InvalidOptionStdinFilename));
+    }
 }
@@ -1,5 +1,11 @@
 use oxlint::{cli::CliRunResult, lint};
 
+// Allocations are only counted with `--timing`.
+#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+#[global_allocator]
+static GLOBAL: oxlint::CountingAllocator<mimalloc_safe::MiMalloc> =
+    oxlint::CountingAllocator::new(mimalloc_safe::MiMalloc);
+
 fn main() -> CliRunResult {
     lint(None)
 }
@@ -0,0 +1,373 @@
+//! Configuration files in JavaScript, i.e. `eslint.config.js` and `.eslintrc.js`.
+//!
+//! The files are not run. The value exported by `export default` or `module.exports` is evaluated
+//! statically: literals, arrays, objects and references to top-level variables are supported, and
+//! everything else is reported as not migrated.
+
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, ArrayExpression, ArrayExpressionElement, Declaration, Expression, ObjectExpression,
+    ObjectProperty, ObjectPropertyKind, Program, PropertyKind, Statement, UnaryOperator,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use super::{ConfigObject, EslintConfig, strings};
+
+/// Parse a JavaScript configuration file. `eslint.config.*` files, and files which export an
+/// array, are flat configs, other files are legacy configs.
+pub fn parse(path: &Path, source_text: &str, file_name: &str) -> Result<EslintConfig, String> {
+    let source_type = SourceType::from_path(path).map_err(|err| err.to_string())?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if let Some(error) = ret.errors.first() {
+        return Err(error.to_string());
+    }
+
+    let mut evaluator =
+        Evaluator { source_text, program: &ret.program, config: EslintConfig::default() };
+    let expr = exported_expression(&ret.program)
+        .ok_or("the configuration is not exported with `export default` or `module.exports`")?;
+    let expr = evaluator.resolve(expr);
+    if file_name.starts_with("eslint.config.") || !matches!(expr, Expression::ObjectExpression(_)) {
+        evaluator.config_array(expr);
+        return Ok(evaluator.config);
+    }
+
+    let value = evaluator.evaluate(expr, "module.exports").unwrap_or_default();
+    let mut config = EslintConfig::from_legacy(value)?;
+    config.skipped.extend(evaluator.config.skipped);
+    Ok(config)
+}
+
+/// The expression of `export default` or `module.exports =`.
+fn exported_expression<'a, 'p>(program: &'p Program<'a>) -> Option<&'p Expression<'a>> {
+    program.body.iter().find_map(|statement| match statement {
+        Statement::ExportDefaultDeclaration(decl) => decl.declaration.as_expression(),
+        Statement::ExpressionStatement(statement) => match &statement.expression {
+            Expression::AssignmentExpression(assign)
+                if assign.left.as_member_expression().is_some_and(|member| {
+                    member.is_specific_member_access("module", "exports")
+                }) =>
+            {
+                Some(&assign.right)
+            }
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+struct Evaluator<'a, 'p> {
+    source_text: &'a str,
+    program: &'p Program<'a>,
+    config: EslintConfig,
+}
+
+impl<'a, 'p> Evaluator<'a, 'p> {
+    /// Resolve references to variables declared at the top level, e.g. `config` in
+    /// `const config = {}; export default [config];`.
+    fn resolve(&self, mut expr: &'p Expression<'a>) -> &'p Expression<'a> {
+        // Limit the depth to not loop forever on `const a = b, b = a;`
+        for _ in 0..8 {
+            let Expression::Identifier(ident) = expr.get_inner_expression() else { break };
+            let init = self.program.body.iter().find_map(|statement| {
+                let decl = match statement {
+                    Statement::VariableDeclaration(decl) => decl,
+                    Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                        Some(Declaration::VariableDeclaration(decl)) => decl,
+                        _ => return None,
+                    },
+                    _ => return None,
+                };
+                decl.declarations
+                    .iter()
+                    .find(|decl| decl.id.get_identifier_name() == Some(ident.name))
+                    .and_then(|decl| decl.init.as_ref())
+            });
+            let Some(init) = init else { break };
+            expr = init;
+        }
+        expr.get_inner_expression()
+    }
+
+    /// Collect the configuration objects of a flat config.
+    fn config_array(&mut self, expr: &'p Expression<'a>) {
+        match self.resolve(expr) {
+            Expression::ArrayExpression(array) => {
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            self.config_array(&spread.argument);
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        _ => self.config_array(element.to_expression()),
+                    }
+                }
+            }
+            // `defineConfig(...)` of `eslint/config`, or `tseslint.config(...)`
+            Expression::CallExpression(call)
+                if call.callee.is_specific_id("defineConfig")
+                    || call
+                        .callee
+                        .as_member_expression()
+                        .is_some_and(|member| member.static_property_name() == Some("config")) =>
+            {
+                for argument in &call.arguments {
+                    match argument {
+                        Argument::SpreadElement(spread) => self.config_array(&spread.argument),
+                        _ => self.config_array(argument.to_expression()),
+                    }
+                }
+            }
+            Expression::ObjectExpression(object) => self.config_object(object),
+            // e.g. `js.configs.recommended`
+            expr => self.skip(None, expr.span()),
+        }
+    }
+
+    fn config_object(&mut self, object: &'p ObjectExpression<'a>) {
+        let mut config = ConfigObject::default();
+        let mut ignores = vec![];
+        let mut only_ignores = true;
+        for property in &object.properties {
+            let Some((key, property)) = self.property(property) else { continue };
+            only_ignores &= matches!(key.as_str(), "ignores" | "name");
+            let value = &property.value;
+            match key.as_str() {
+                "files" => {
+                    config.files = self.evaluate(value, &key).map(strings).unwrap_or_default()
+                }
+                "ignores" => ignores = self.evaluate(value, &key).map(strings).unwrap_or_default(),
+                "plugins" => match self.resolve(value) {
+                    Expression::ObjectExpression(plugins) => {
+                        for property in &plugins.properties {
+                            if let Some((name, _)) = self.property(property) {
+                                config.plugins.push(name);
+                            }
+                        }
+                    }
+                    value => self.skip(Some(&key), value.span()),
+                },
+                "languageOptions" => self.language_options(value, &mut config),
+                "rules" => config.rules = self.evaluate_object(value, &key),
+                "settings" => config.settings = self.evaluate_object(value, &key),
+                "name" => {}
+                _ => self.skip(Some(&key), value.span()),
+            }
+        }
+
+        if only_ignores {
+            // An object with only `ignores` ignores files globally
+            self.config.ignore_patterns.extend(ignores);
+        } else {
+            if !ignores.is_empty() {
+                self.config.skipped.push("`ignores` of configurations with other keys".to_string());
+            }
+            self.config.objects.push(config);
+        }
+    }
+
+    fn language_options(&mut self, value: &'p Expression<'a>, config: &mut ConfigObject) {
+        let Expression::ObjectExpression(object) = self.resolve(value) else {
+            self.skip(Some("languageOptions"), value.span());
+            return;
+        };
+        for property in &object.properties {
+            let Some((key, property)) = self.property(property) else { continue };
+            match key.as_str() {
+                "globals" => self.globals(&property.value, config),
+                // Only needed by ESLint to parse files, which oxlint does without configuration
+                "ecmaVersion" | "sourceType" | "parser" | "parserOptions" => {}
+                _ => self.skip(Some(&key), property.value.span()),
+            }
+        }
+    }
+
+    /// Globals, where the globals of an environment of the `globals` package, e.g.
+    /// `...globals.browser`, are migrated to that environment.
+    fn globals(&mut self, value: &'p Expression<'a>, config: &mut ConfigObject) {
+        let value = self.resolve(value);
+        if let Some(env) = globals_env(value) {
+            config.env.insert(env.to_string(), Value::Bool(true));
+            return;
+        }
+        let Expression::ObjectExpression(object) = value else {
+            self.skip(Some("globals"), value.span());
+            return;
+        };
+        for property in &object.properties {
+            match property {
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    match globals_env(self.resolve(&spread.argument)) {
+                        Some(env) => {
+                            config.env.insert(env.to_string(), Value::Bool(true));
+                        }
+                        None => self.skip(None, spread.span),
+                    }
+                }
+                ObjectPropertyKind::ObjectProperty(_) => {
+                    let Some((key, property)) = self.property(property) else { continue };
+                    if let Some(value) = self.evaluate(&property.value, &key) {
+                        config.globals.insert(key, value);
+                    }
+                }
+            }
+        }
+    }
+
+    fn evaluate_object(&mut self, expr: &'p Expression<'a>, key: &str) -> Map<String, Value> {
+        match self.evaluate(expr, key) {
+            Some(Value::Object(properties)) => properties,
+            Some(_) => {
+                self.skip(Some(key), expr.span());
+                Map::new()
+            }
+            None => Map::new(),
+        }
+    }
+
+    /// Evaluate the value of the property `key`.
+    ///
+    /// Properties of objects which can't be evaluated are skipped, so one unsupported rule does
+    /// not prevent the migration of the other rules.
+    fn evaluate(&mut self, expr: &'p Expression<'a>, key: &str) -> Option<Value> {
+        match self.resolve(expr) {
+            Expression::ObjectExpression(object) => {
+                let mut properties = Map::new();
+                for property in &object.properties {
+                    let Some((key, property)) = self.property(property) else { continue };
+                    if let Some(value) = self.evaluate(&property.value, &key) {
+                        properties.insert(key, value);
+                    }
+                }
+                Some(Value::Object(properties))
+            }
+            // e.g. `overrides` of a legacy config
+            Expression::ArrayExpression(array)
+                if array.elements.iter().all(|element| {
+                    matches!(element, ArrayExpressionElement::ObjectExpression(_))
+                }) =>
+            {
+                let values = array
+                    .elements
+                    .iter()
+                    .filter_map(|element| self.evaluate(element.to_expression(), key));
+                Some(Value::Array(values.collect()))
+            }
+            resolved => {
+                let value = self.literal(resolved);
+                if value.is_none() {
+                    self.skip(Some(key), expr.span());
+                }
+                value
+            }
+        }
+    }
+
+    /// Evaluate an expression of literals, e.g. `["error", { "allow": ["warn"] }]`.
+    fn literal(&self, expr: &'p Expression<'a>) -> Option<Value> {
+        let value = match self.resolve(expr) {
+            Expression::StringLiteral(lit) => Value::from(lit.value.as_str()),
+            Expression::NumericLiteral(lit) => number(lit.value)?,
+            Expression::BooleanLiteral(lit) => Value::Bool(lit.value),
+            Expression::NullLiteral(_) => Value::Null,
+            Expression::TemplateLiteral(lit) => Value::from(lit.single_quasi()?.as_str()),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation =>
+            {
+                match &unary.argument {
+                    Expression::NumericLiteral(lit) => number(-lit.value)?,
+                    _ => return None,
+                }
+            }
+            Expression::ArrayExpression(array) => self.literal_array(array)?,
+            Expression::ObjectExpression(object) => {
+                let mut properties = Map::new();
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        return None;
+                    };
+                    let key = property_key(property)?;
+                    properties.insert(key, self.literal(&property.value)?);
+                }
+                Value::Object(properties)
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn literal_array(&self, array: &'p ArrayExpression<'a>) -> Option<Value> {
+        let values = array
+            .elements
+            .iter()
+            .map(|element| match element {
+                ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+                    None
+                }
+                _ => self.literal(element.to_expression()),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Value::Array(values))
+    }
+
+    /// The key of a property with a static key, e.g. `a` of `{ a: 1 }` or `"a"` of `{ "a": 1 }`.
+    fn property(
+        &mut self,
+        property: &'p ObjectPropertyKind<'a>,
+    ) -> Option<(String, &'p ObjectProperty<'a>)> {
+        match property {
+            ObjectPropertyKind::ObjectProperty(property) => match property_key(property) {
+                Some(key) => Some((key, property)),
+                None => {
+                    self.skip(None, property.span);
+                    None
+                }
+            },
+            ObjectPropertyKind::SpreadProperty(spread) => {
+                self.skip(None, spread.span);
+                None
+            }
+        }
+    }
+
+    /// Report the code at `span`, e.g. the value of the property `key`, as not migrated.
+    fn skip(&mut self, key: Option<&str>, span: Span) {
+        let source_text = span.source_text(self.source_text);
+        let code = match source_text.split_once('\n') {
+            Some((first_line, _)) => format!("{} ...", first_line.trim_end()),
+            None => source_text.to_string(),
+        };
+        self.config.skipped.push(match key {
+            Some(key) => format!("`{key}`: `{code}`"),
+            None => format!("`{code}`"),
+        });
+    }
+}
+
+fn property_key(property: &ObjectProperty<'_>) -> Option<String> {
+    if property.computed || property.method || property.kind != PropertyKind::Init {
+        return None;
+    }
+    property.key.static_name().map(String::from)
+}
+
+/// The environment of globals of the `globals` package, e.g. `browser` for `globals.browser`.
+fn globals_env<'a>(expr: &Expression<'a>) -> Option<&'a str> {
+    let member = expr.as_member_expression()?;
+    if member.object().is_specific_id("globals") { member.static_property_name() } else { None }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn number(value: f64) -> Option<Value> {
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        Some(Value::from(value as i64))
+    } else {
+        serde_json::Number::from_f64(value).map(Value::Number)
+    }
+}
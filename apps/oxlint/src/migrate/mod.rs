@@ -0,0 +1,473 @@
+//! `oxlint migrate [CONFIG]`: convert an ESLint configuration file to `.oxlintrc.json`, and report
+//! the rules and options which have no oxlint equivalent.
+
+mod javascript;
+mod yaml;
+
+use std::{ffi::OsStr, fmt::Write as _, fs, io::Write, path::Path};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use oxc_linter::{BuiltinLintPlugins, OxlintRules};
+
+use crate::{cli::CliRunResult, lint::LintRunner};
+
+/// ESLint configuration files, in the order ESLint looks for them.
+const CONFIG_FILES: [&str; 9] = [
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.json",
+    ".eslintrc",
+];
+
+/// Settings of plugins which are implemented by oxlint.
+const SUPPORTED_SETTINGS: [&str; 4] = ["jsx-a11y", "next", "react", "jsdoc"];
+
+/// Migrate the ESLint configuration file `config`, or the one found in the current directory,
+/// to `.oxlintrc.json`, and print a report to `stdout`.
+pub fn run_migrate(config: Option<&Path>, stdout: &mut dyn Write) -> CliRunResult {
+    let cwd = std::env::current_dir().unwrap();
+    let (output, result) = match config {
+        Some(config) => migrate_file(&cwd, &cwd.join(config)),
+        None => match CONFIG_FILES.iter().map(|file| cwd.join(file)).find(|path| path.is_file()) {
+            Some(path) => migrate_file(&cwd, &path),
+            None => (
+                "No ESLint configuration file found in the current directory.\n".to_string(),
+                CliRunResult::InvalidOptionConfig,
+            ),
+        },
+    };
+    stdout.write_all(output.as_bytes()).and_then(|()| stdout.flush()).unwrap();
+    result
+}
+
+fn migrate_file(cwd: &Path, path: &Path) -> (String, CliRunResult) {
+    let display_path = path.strip_prefix(cwd).unwrap_or(path).display();
+    let migration = match EslintConfig::from_file(path) {
+        Ok(config) => Migration::new(config),
+        Err(err) => {
+            return (
+                format!("Failed to migrate {display_path}: {err}\n"),
+                CliRunResult::ConfigFileInitFailed,
+            );
+        }
+    };
+
+    let schema_relative_path = "node_modules/oxlint/configuration_schema.json";
+    let config = if cwd.join(schema_relative_path).is_file() {
+        let mut config = Map::new();
+        config.insert("$schema".to_string(), format!("./{schema_relative_path}").into());
+        config.extend(migration.config.clone());
+        config
+    } else {
+        migration.config.clone()
+    };
+    let configuration = serde_json::to_string_pretty(&config).unwrap();
+    if fs::write(cwd.join(LintRunner::DEFAULT_OXLINTRC), configuration).is_err() {
+        return (
+            "Failed to create configuration file\n".to_string(),
+            CliRunResult::ConfigFileInitFailed,
+        );
+    }
+
+    (migration.report(&display_path.to_string()), CliRunResult::ConfigFileInitSucceeded)
+}
+
+/// The parts of an ESLint configuration which can be migrated.
+#[derive(Debug, Default)]
+struct EslintConfig {
+    /// Configuration objects of a flat config, or the root and overrides of a legacy config.
+    objects: Vec<ConfigObject>,
+    /// Glob patterns of files which are not linted.
+    ignore_patterns: Vec<String>,
+    /// Parts of the configuration which can't be migrated, e.g. `extends`.
+    skipped: Vec<String>,
+}
+
+/// A configuration object of a flat config, or the root or an override of a legacy config.
+#[derive(Debug, Default)]
+struct ConfigObject {
+    /// Glob patterns of the files which the object applies to, all files if empty.
+    files: Vec<String>,
+    plugins: Vec<String>,
+    env: Map<String, Value>,
+    globals: Map<String, Value>,
+    rules: Map<String, Value>,
+    settings: Map<String, Value>,
+}
+
+impl EslintConfig {
+    fn from_file(path: &Path) -> Result<Self, String> {
+        let source_text = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let file_name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+        match path.extension().and_then(OsStr::to_str) {
+            Some("js" | "mjs" | "cjs") => javascript::parse(path, &source_text, file_name),
+            Some("yaml" | "yml") => Self::from_legacy(yaml::parse(&source_text)?),
+            Some("json") => Self::from_legacy(parse_jsonc(source_text)?),
+            // `.eslintrc` is either JSON or YAML
+            _ => Self::from_legacy(
+                parse_jsonc(source_text.clone()).or_else(|_| yaml::parse(&source_text))?,
+            ),
+        }
+    }
+
+    /// Collect the parts of a legacy config, i.e. an `.eslintrc` file.
+    fn from_legacy(value: Value) -> Result<Self, String> {
+        let Value::Object(root) = value else {
+            return Err("the configuration is not an object".to_string());
+        };
+        let mut config = Self::default();
+        let mut root_object = ConfigObject::default();
+        for (key, value) in root {
+            match key.as_str() {
+                "ignorePatterns" => config.ignore_patterns.extend(strings(value)),
+                "overrides" => {
+                    let Value::Array(overrides) = value else { continue };
+                    for value in overrides {
+                        let Value::Object(properties) = value else { continue };
+                        let mut object = ConfigObject::default();
+                        for (key, value) in properties {
+                            match key.as_str() {
+                                "files" => object.files = strings(value),
+                                _ => config.legacy_property(&mut object, &key, value, "overrides."),
+                            }
+                        }
+                        config.objects.push(object);
+                    }
+                }
+                _ => config.legacy_property(&mut root_object, &key, value, ""),
+            }
+        }
+        config.objects.insert(0, root_object);
+        Ok(config)
+    }
+
+    fn legacy_property(&mut self, object: &mut ConfigObject, key: &str, value: Value, path: &str) {
+        match key {
+            "plugins" => object.plugins.extend(strings(value)),
+            "env" => object.env.extend(properties(value)),
+            "globals" => object.globals.extend(properties(value)),
+            "rules" => object.rules.extend(properties(value)),
+            "settings" => object.settings.extend(properties(value)),
+            "extends" => {
+                for name in strings(value) {
+                    self.skipped.push(format!("`{path}extends`: {name}"));
+                }
+            }
+            // Only needed by ESLint to parse files, which oxlint does without configuration
+            "root" | "parser" | "parserOptions" | "$schema" => {}
+            _ => self.skipped.push(format!("`{path}{key}`")),
+        }
+    }
+}
+
+/// An ESLint configuration converted to an oxlint configuration.
+#[derive(Debug, Default)]
+struct Migration {
+    /// Content of `.oxlintrc.json`.
+    config: Map<String, Value>,
+    /// Number of migrated rules.
+    rules: usize,
+    /// Enabled rules which oxlint doesn't implement.
+    unsupported_rules: Vec<String>,
+    /// Plugins which oxlint doesn't implement.
+    unsupported_plugins: Vec<String>,
+    /// Parts of the ESLint configuration which were not migrated.
+    skipped: Vec<String>,
+}
+
+impl Migration {
+    fn new(eslint: EslintConfig) -> Self {
+        let mut migration = Self { skipped: eslint.skipped, ..Self::default() };
+
+        // Configuration objects without `files` apply to all files, so they are merged into the
+        // root of `.oxlintrc.json`, and the others become `overrides`.
+        let mut root = ConfigObject::default();
+        let mut overrides = vec![];
+        for object in eslint.objects {
+            if object.files.is_empty() {
+                root.plugins.extend(object.plugins);
+                root.env.extend(object.env);
+                root.globals.extend(object.globals);
+                root.rules.extend(object.rules);
+                root.settings.extend(object.settings);
+            } else {
+                overrides.push(object);
+            }
+        }
+
+        let mut config = Map::new();
+        let (plugins, rules) = migration.migrate_rules(&root);
+        config.insert("plugins".to_string(), plugin_names(plugins));
+        // ESLint only reports the configured rules
+        config.insert("categories".to_string(), serde_json::json!({ "correctness": "off" }));
+        insert_non_empty(&mut config, "env", root.env);
+        insert_non_empty(&mut config, "globals", root.globals);
+        let settings = root
+            .settings
+            .into_iter()
+            .filter(|(key, _)| SUPPORTED_SETTINGS.contains(&key.as_str()))
+            .collect::<Map<_, _>>();
+        insert_non_empty(&mut config, "settings", settings);
+        insert_non_empty(&mut config, "rules", rules);
+        if !eslint.ignore_patterns.is_empty() {
+            config.insert("ignorePatterns".to_string(), eslint.ignore_patterns.into());
+        }
+
+        let overrides = overrides
+            .into_iter()
+            .map(|object| {
+                let (override_plugins, rules) = migration.migrate_rules(&object);
+                if !object.settings.is_empty() {
+                    migration.skipped.push("`settings` of configurations with `files`".to_string());
+                }
+                let mut config = Map::new();
+                config.insert("files".to_string(), object.files.into());
+                // Plugins of the root are enabled for overrides as well
+                let override_plugins = override_plugins.difference(plugins);
+                if !override_plugins.is_empty() {
+                    config.insert("plugins".to_string(), plugin_names(override_plugins));
+                }
+                insert_non_empty(&mut config, "env", object.env);
+                insert_non_empty(&mut config, "globals", object.globals);
+                insert_non_empty(&mut config, "rules", rules);
+                Value::Object(config)
+            })
+            .collect::<Vec<_>>();
+        if !overrides.is_empty() {
+            config.insert("overrides".to_string(), overrides.into());
+        }
+
+        migration.skipped.dedup();
+        migration.config = config;
+        migration
+    }
+
+    /// The rules of `object` which oxlint implements, and the plugins needed for them.
+    fn migrate_rules(&mut self, object: &ConfigObject) -> (BuiltinLintPlugins, Map<String, Value>) {
+        let mut plugins = BuiltinLintPlugins::empty();
+        for name in &object.plugins {
+            match builtin_plugin(name) {
+                Some(plugin) => plugins |= plugin,
+                None if !self.unsupported_plugins.contains(name) => {
+                    self.unsupported_plugins.push(name.clone());
+                }
+                None => {}
+            }
+        }
+
+        let mut rules = Map::new();
+        for (name, value) in &object.rules {
+            let rule = Value::Object(Map::from_iter([(name.clone(), value.clone())]));
+            let Some(rule) =
+                OxlintRules::deserialize(&rule).ok().and_then(|rules| rules.iter().next().cloned())
+            else {
+                self.skipped.push(format!("`{name}`: invalid rule configuration"));
+                continue;
+            };
+            if rule.is_builtin() {
+                // e.g. rules of `tseslint.configs.recommended`, which enables the plugin
+                plugins |= BuiltinLintPlugins::from(rule.plugin_name.as_str());
+                rules.insert(name.clone(), value.clone());
+                self.rules += 1;
+            } else if !rule.severity.is_allow() && !self.unsupported_rules.contains(name) {
+                self.unsupported_rules.push(name.clone());
+            }
+        }
+        (plugins, rules)
+    }
+
+    fn report(&self, path: &str) -> String {
+        let mut out = format!(
+            "Migrated {} rules from {path} to {}\n",
+            self.rules,
+            LintRunner::DEFAULT_OXLINTRC
+        );
+        // Writing to a `String` can't fail
+        for (heading, items) in [
+            ("Rules without an oxlint equivalent", &self.unsupported_rules),
+            ("Plugins without an oxlint equivalent", &self.unsupported_plugins),
+            ("Not migrated", &self.skipped),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            let _ = writeln!(out, "\n{heading}:");
+            for item in items {
+                let _ = writeln!(out, "  - {item}");
+            }
+        }
+        out
+    }
+}
+
+/// The oxlint plugin of the ESLint plugin `name`, e.g. `react` for `eslint-plugin-react`.
+fn builtin_plugin(name: &str) -> Option<BuiltinLintPlugins> {
+    let name = match name.split_once('/') {
+        // `@scope/eslint-plugin` or `@scope/eslint-plugin-name`
+        Some((scope, rest)) if rest == "eslint-plugin" => scope,
+        Some((scope, "next")) if scope == "@next" => "nextjs",
+        _ => name.trim_start_matches("eslint-plugin-"),
+    };
+    let plugin = BuiltinLintPlugins::from(name);
+    (!plugin.is_empty()).then_some(plugin)
+}
+
+fn plugin_names(plugins: BuiltinLintPlugins) -> Value {
+    plugins.iter().map(<&'static str>::from).collect::<Vec<_>>().into()
+}
+
+fn insert_non_empty(config: &mut Map<String, Value>, key: &str, value: Map<String, Value>) {
+    if !value.is_empty() {
+        config.insert(key.to_string(), Value::Object(value));
+    }
+}
+
+fn parse_jsonc(mut source_text: String) -> Result<Value, String> {
+    json_strip_comments::strip(&mut source_text).map_err(|err| err.to_string())?;
+    serde_json::from_str(&source_text).map_err(|err| err.to_string())
+}
+
+/// A string, or the strings of an array, e.g. `files`.
+fn strings(value: Value) -> Vec<String> {
+    match value {
+        Value::String(string) => vec![string],
+        Value::Array(values) => values
+            .into_iter()
+            .filter_map(|value| match value {
+                Value::String(string) => Some(string),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn properties(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(properties) => properties,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::{Value, json};
+
+    use super::{EslintConfig, Migration};
+
+    fn migrate(path: &str) -> Migration {
+        Migration::new(EslintConfig::from_file(Path::new(path)).unwrap())
+    }
+
+    #[test]
+    fn legacy_json() {
+        let migration = migrate("fixtures/migrate/legacy/.eslintrc.json");
+        assert_eq!(
+            Value::Object(migration.config),
+            json!({
+                "plugins": ["react", "typescript"],
+                "categories": { "correctness": "off" },
+                "env": { "browser": true },
+                "globals": { "process": "readonly" },
+                "settings": { "react": { "version": "18.0" } },
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "no-console": "warn",
+                    "react/jsx-key": 2,
+                    "@typescript-eslint/no-explicit-any": "off"
+                },
+                "ignorePatterns": ["dist/**"],
+                "overrides": [{
+                    "files": ["**/*.test.ts"],
+                    "plugins": ["vitest"],
+                    "env": { "node": true },
+                    "rules": { "vitest/no-focused-tests": "error" }
+                }]
+            })
+        );
+        assert_eq!(migration.rules, 5);
+        assert_eq!(migration.unsupported_rules, ["foo/bar", "no-restricted-syntax"]);
+        assert_eq!(migration.unsupported_plugins, ["foo"]);
+        assert_eq!(
+            migration.skipped,
+            ["`extends`: eslint:recommended", "`extends`: plugin:react/recommended"]
+        );
+    }
+
+    #[test]
+    fn legacy_yaml_and_javascript() {
+        let json = migrate("fixtures/migrate/legacy/.eslintrc.json");
+        for path in
+            ["fixtures/migrate/legacy/.eslintrc.yaml", "fixtures/migrate/legacy/.eslintrc.js"]
+        {
+            let migration = migrate(path);
+            assert_eq!(migration.config, json.config, "{path}");
+            assert_eq!(migration.unsupported_rules, json.unsupported_rules, "{path}");
+        }
+    }
+
+    #[test]
+    fn flat() {
+        let migration = migrate("fixtures/migrate/flat/eslint.config.mjs");
+        assert_eq!(
+            Value::Object(migration.config),
+            json!({
+                "plugins": ["react", "typescript"],
+                "categories": { "correctness": "off" },
+                "env": { "browser": true, "node": true },
+                "globals": { "process": "readonly" },
+                "rules": {
+                    "eqeqeq": "error",
+                    "react/jsx-key": "error",
+                    "@typescript-eslint/no-explicit-any": "warn"
+                },
+                "ignorePatterns": ["dist/**", "coverage/**"],
+                "overrides": [{
+                    "files": ["**/*.test.ts"],
+                    "plugins": ["vitest"],
+                    "rules": { "vitest/no-focused-tests": "error" }
+                }]
+            })
+        );
+        assert_eq!(migration.unsupported_rules, ["no-restricted-syntax"]);
+        assert_eq!(migration.unsupported_plugins, ["foo"]);
+        assert_eq!(
+            migration.skipped,
+            [
+                "`js.configs.recommended`",
+                "`tseslint.configs.recommended`",
+                "`no-magic-numbers`: `[\"error\", { ignore: [getIgnored()] }]`"
+            ]
+        );
+    }
+
+    #[test]
+    fn report() {
+        let migration = migrate("fixtures/migrate/flat/eslint.config.mjs");
+        assert_eq!(
+            migration.report("eslint.config.mjs"),
+            "Migrated 4 rules from eslint.config.mjs to .oxlintrc.json
+
+Rules without an oxlint equivalent:
+  - no-restricted-syntax
+
+Plugins without an oxlint equivalent:
+  - foo
+
+Not migrated:
+  - `js.configs.recommended`
+  - `tseslint.configs.recommended`
+  - `no-magic-numbers`: `[\"error\", { ignore: [getIgnored()] }]`
+"
+        );
+    }
+}
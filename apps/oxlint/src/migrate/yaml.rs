@@ -0,0 +1,372 @@
+//! A parser for the subset of YAML which ESLint configuration files use: block and flow mappings
+//! and sequences of scalars. Anchors, tags, multi-line scalars and multiple documents are not
+//! supported.
+
+use serde_json::{Map, Value};
+
+/// Parse a YAML document to JSON.
+pub fn parse(source_text: &str) -> Result<Value, String> {
+    let lines = source_text
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let text = strip_comment(line).trim_end();
+            let indent = text.len() - text.trim_start().len();
+            let text = text.trim_start();
+            (!text.is_empty() && text != "---").then_some(Line { number: index + 1, indent, text })
+        })
+        .collect::<Vec<_>>();
+    let mut parser = YamlParser { lines, pos: 0 };
+    let Some(indent) = parser.lines.first().map(|line| line.indent) else {
+        return Ok(Value::Null);
+    };
+    let value = parser.block(indent)?;
+    match parser.lines.get(parser.pos) {
+        Some(line) => Err(line.error("unexpected indentation")),
+        None => Ok(value),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Line<'s> {
+    number: usize,
+    indent: usize,
+    /// Text of the line without indentation and comments
+    text: &'s str,
+}
+
+impl Line<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("{message} on line {}", self.number)
+    }
+
+    fn is_sequence_item(&self) -> bool {
+        self.text == "-" || self.text.starts_with("- ")
+    }
+}
+
+struct YamlParser<'s> {
+    lines: Vec<Line<'s>>,
+    pos: usize,
+}
+
+impl YamlParser<'_> {
+    /// Parse the node which starts at the current line, which is indented by `indent`.
+    fn block(&mut self, indent: usize) -> Result<Value, String> {
+        let line = self.lines[self.pos];
+        if line.is_sequence_item() {
+            self.sequence(indent)
+        } else if split_key(line.text).is_some() {
+            self.mapping(indent)
+        } else {
+            self.pos += 1;
+            flow(line.text).map_err(|message| line.error(&message))
+        }
+    }
+
+    fn sequence(&mut self, indent: usize) -> Result<Value, String> {
+        let mut values = vec![];
+        while let Some(&line) = self.lines.get(self.pos) {
+            if line.indent != indent || !line.is_sequence_item() {
+                break;
+            }
+            let item = line.text[1..].trim_start();
+            if item.is_empty() {
+                self.pos += 1;
+                values.push(self.nested_block(indent, false)?);
+            } else {
+                // The item continues on the following lines with the indentation of its text,
+                // e.g. the mapping in `- files: []\n  rules: {}`
+                let indent = indent + (line.text.len() - item.len());
+                self.lines[self.pos] = Line { indent, text: item, ..line };
+                values.push(self.block(indent)?);
+            }
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn mapping(&mut self, indent: usize) -> Result<Value, String> {
+        let mut properties = Map::new();
+        while let Some(&line) = self.lines.get(self.pos) {
+            if line.indent < indent || (line.indent == indent && line.is_sequence_item()) {
+                break;
+            }
+            if line.indent > indent {
+                return Err(line.error("unexpected indentation"));
+            }
+            let Some((key, value)) = split_key(line.text) else {
+                return Err(line.error("expected a key"));
+            };
+            let key = match flow(key).map_err(|message| line.error(&message))? {
+                Value::String(key) => key,
+                key => key.to_string(),
+            };
+            self.pos += 1;
+            let value = if value.is_empty() {
+                self.nested_block(indent, true)?
+            } else {
+                flow(value).map_err(|message| line.error(&message))?
+            };
+            properties.insert(key, value);
+        }
+        Ok(Value::Object(properties))
+    }
+
+    /// Parse the value of a sequence item or a key without a value on its line. A sequence can be
+    /// the value of a key without being indented further.
+    fn nested_block(&mut self, indent: usize, is_key: bool) -> Result<Value, String> {
+        match self.lines.get(self.pos) {
+            Some(line) if line.indent > indent => self.block(line.indent),
+            Some(line) if is_key && line.indent == indent && line.is_sequence_item() => {
+                self.sequence(indent)
+            }
+            _ => Ok(Value::Null),
+        }
+    }
+}
+
+/// Remove a comment, which starts with `#` at the start of the line or after whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (index, ch) in line.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(q), _) if q == ch => quote = None,
+            (None, '#') if prev.is_whitespace() => return &line[..index],
+            _ => {}
+        }
+        prev = ch;
+    }
+    line
+}
+
+/// Split `key: value` into the key and the value, which is empty if it's on the following lines.
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    if text.starts_with(['[', '{']) {
+        return None;
+    }
+    let mut quote = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(q), _) if q == ch => quote = None,
+            (None, ':') if chars.peek().is_none_or(|(_, next)| next.is_whitespace()) => {
+                return Some((text[..index].trim_end(), text[index + 1..].trim_start()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse a scalar or a flow collection, e.g. `error`, `"error"` or `[error, { allow: [warn] }]`.
+fn flow(text: &str) -> Result<Value, String> {
+    let mut parser = FlowParser { text, pos: 0 };
+    let value = parser.value(false)?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(format!("unexpected `{}`", &text[parser.pos..]));
+    }
+    Ok(value)
+}
+
+struct FlowParser<'s> {
+    text: &'s str,
+    pos: usize,
+}
+
+impl FlowParser<'_> {
+    fn value(&mut self, in_collection: bool) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('[') => {
+                self.pos += 1;
+                let mut values = vec![];
+                while !self.eat(']')? {
+                    values.push(self.value(true)?);
+                    self.eat_separator(']')?;
+                }
+                Ok(Value::Array(values))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let mut properties = Map::new();
+                while !self.eat('}')? {
+                    let key = match self.value(true)? {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    self.skip_whitespace();
+                    if self.peek() != Some(':') {
+                        return Err(format!("expected `:` after `{key}`"));
+                    }
+                    self.pos += 1;
+                    properties.insert(key, self.value(true)?);
+                    self.eat_separator('}')?;
+                }
+                Ok(Value::Object(properties))
+            }
+            Some(quote @ ('"' | '\'')) => self.quoted(quote).map(Value::String),
+            _ => Ok(self.plain(in_collection)),
+        }
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<String, String> {
+        let mut value = String::new();
+        let mut chars = self.text[self.pos + 1..].char_indices();
+        while let Some((index, ch)) = chars.next() {
+            match ch {
+                // `''` is an escaped quote in single quoted scalars
+                '\'' if quote == '\''
+                    && self.text[self.pos + 1 + index + 1..].starts_with('\'') =>
+                {
+                    chars.next();
+                    value.push('\'');
+                }
+                ch if ch == quote => {
+                    self.pos += 1 + index + 1;
+                    return Ok(value);
+                }
+                '\\' if quote == '"' => match chars.next().map(|(_, ch)| ch) {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(ch) => value.push(ch),
+                    None => break,
+                },
+                ch => value.push(ch),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    /// A plain scalar, which ends at `,`, `]`, `}` or `: ` in flow collections.
+    fn plain(&mut self, in_collection: bool) -> Value {
+        let rest = &self.text[self.pos..];
+        let len = if in_collection {
+            rest.char_indices()
+                .find(|&(index, ch)| {
+                    matches!(ch, ',' | ']' | '}')
+                        || (ch == ':'
+                            && rest[index + 1..].chars().next().is_none_or(char::is_whitespace))
+                })
+                .map_or(rest.len(), |(index, _)| index)
+        } else {
+            rest.len()
+        };
+        self.pos += len;
+        scalar(rest[..len].trim())
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    /// Consume `end` after optional whitespace, and return whether it was found.
+    fn eat(&mut self, end: char) -> Result<bool, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(ch) if ch == end => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(format!("expected `{end}`")),
+        }
+    }
+
+    /// Consume the `,` after an item of a collection, unless it's the last item before `end`.
+    fn eat_separator(&mut self, end: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(ch) if ch == end => Ok(()),
+            _ => Err(format!("expected `,` or `{end}`")),
+        }
+    }
+}
+
+/// Resolve a plain scalar with the core schema of YAML 1.2, which `js-yaml` uses too, so `off` is a
+/// string and not `false`.
+fn scalar(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => Value::Null,
+        "true" | "True" | "TRUE" => Value::Bool(true),
+        "false" | "False" | "FALSE" => Value::Bool(false),
+        _ => {
+            if let Ok(int) = text.parse::<i64>() {
+                Value::from(int)
+            } else if let Some(number) = text
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .and_then(serde_json::Number::from_f64)
+            {
+                Value::Number(number)
+            } else {
+                Value::from(text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::parse;
+
+    #[test]
+    fn mappings_and_sequences() {
+        let source_text = "
+# comment
+root: true
+extends:
+  - eslint:recommended
+  - 'plugin:react/recommended' # comment
+env: { browser: true, node: false }
+rules:
+  eqeqeq: [error, always]
+  no-console: off
+  quotes:
+  - error
+  - \"single\"
+  max-len: [warn, { code: 100, ignoreUrls: true }]
+overrides:
+  - files: ['*.test.js']
+    rules:
+      no-unused-expressions: 0
+";
+        assert_eq!(
+            parse(source_text).unwrap(),
+            json!({
+                "root": true,
+                "extends": ["eslint:recommended", "plugin:react/recommended"],
+                "env": { "browser": true, "node": false },
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "no-console": "off",
+                    "quotes": ["error", "single"],
+                    "max-len": ["warn", { "code": 100, "ignoreUrls": true }]
+                },
+                "overrides": [{ "files": ["*.test.js"], "rules": { "no-unused-expressions": 0 } }]
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("a: 1\n    b: 2").unwrap_err(), "unexpected indentation on line 2");
+        assert_eq!(parse("a: [1, 2").unwrap_err(), "expected `,` or `]` on line 1");
+        assert_eq!(parse("a: 'b").unwrap_err(), "unterminated string on line 1");
+    }
+}
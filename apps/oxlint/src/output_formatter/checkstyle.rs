@@ -43,7 +43,10 @@ fn format_checkstyle(diagnostics: &[Error]) -> String {
     for info in infos {
         grouped.entry(info.filename.clone()).or_default().push(info);
     }
-    let messages = grouped.into_values().map(|infos| {
+    // Sort by file name so the output is stable across runs.
+    let mut grouped = grouped.into_values().collect::<Vec<_>>();
+    grouped.sort_unstable_by(|a, b| a[0].filename.cmp(&b[0].filename));
+    let messages = grouped.into_iter().map(|infos| {
          let messages = infos
              .iter()
              .fold(String::new(), |mut acc, info| {
@@ -58,7 +61,7 @@ fn format_checkstyle(diagnostics: &[Error]) -> String {
                  acc.push_str(&line);
                  acc
              });
-         let filename = &infos[0].filename;
+         let filename = xml_escape(&infos[0].filename);
          format!(r#"<file name="{filename}">{messages}</file>"#)
      }).collect::<Vec<_>>().join(" ");
     format!(
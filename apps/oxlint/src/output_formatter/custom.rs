@@ -0,0 +1,138 @@
+use std::{
+    path::Path,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use oxc_diagnostics::{
+    Error,
+    reporter::{DiagnosticReporter, DiagnosticResult, ProjectRoot},
+};
+
+use crate::output_formatter::InternalFormatter;
+
+/// A custom output format, which can be selected with `--format` after it is registered with
+/// [`register_reporter`].
+///
+/// Each hook returns the output to write to stdout, or [`None`] to write nothing.
+/// [`Info`](oxc_diagnostics::reporter::Info) and
+/// [`fingerprint`](oxc_diagnostics::reporter::fingerprint) help with rendering diagnostics.
+///
+/// ## Example
+/// ```
+/// use std::sync::Arc;
+///
+/// use oxc_diagnostics::{Error, reporter::DiagnosticResult};
+/// use oxlint::{Reporter, register_reporter};
+///
+/// #[derive(Default)]
+/// struct CountReporter {
+///     count: usize,
+/// }
+///
+/// impl Reporter for CountReporter {
+///     fn on_diagnostic(&mut self, _diagnostic: Error) -> Option<String> {
+///         self.count += 1;
+///         None
+///     }
+///
+///     fn on_end(&mut self, _result: &DiagnosticResult) -> Option<String> {
+///         Some(format!("{}\n", self.count))
+///     }
+/// }
+///
+/// register_reporter("count", Arc::new(|| Box::new(CountReporter::default())));
+/// ```
+pub trait Reporter {
+    /// Called once before [`on_start`](Reporter::on_start) with the root of the project, to pass
+    /// to [`fingerprint`](oxc_diagnostics::reporter::fingerprint).
+    fn on_project_root(&mut self, _project: &ProjectRoot) {}
+
+    /// Called once before linting starts.
+    fn on_start(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Called with the absolute path of a file before its diagnostics.
+    /// Files without diagnostics are not reported.
+    fn on_file(&mut self, _path: &Path) -> Option<String> {
+        None
+    }
+
+    /// Called for each diagnostic which is reported, so not for warnings with `--quiet`.
+    fn on_diagnostic(&mut self, diagnostic: Error) -> Option<String>;
+
+    /// Called once after all files are linted.
+    fn on_end(&mut self, _result: &DiagnosticResult) -> Option<String> {
+        None
+    }
+}
+
+/// Creates a [`Reporter`] for each run of the linter, e.g. each time files change in `--watch` mode.
+pub type ReporterFactory = Arc<dyn Fn() -> Box<dyn Reporter> + Send + Sync>;
+
+static REPORTERS: Mutex<Vec<(String, ReporterFactory)>> = Mutex::new(Vec::new());
+
+/// Register a custom output format, so `--format <name>` uses the [`Reporter`]s created by `factory`.
+///
+/// Must be called before [`lint`](crate::lint). Registering a name again replaces the previous
+/// format. The names of the built-in formats can't be replaced.
+pub fn register_reporter(name: &str, factory: ReporterFactory) {
+    let mut reporters = REPORTERS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(entry) = reporters.iter_mut().find(|(registered, _)| registered == name) {
+        entry.1 = factory;
+    } else {
+        reporters.push((name.to_string(), factory));
+    }
+}
+
+pub(super) fn find_reporter(name: &str) -> Option<ReporterFactory> {
+    let reporters = REPORTERS.lock().unwrap_or_else(PoisonError::into_inner);
+    reporters
+        .iter()
+        .find(|(registered, _)| registered == name)
+        .map(|(_, factory)| Arc::clone(factory))
+}
+
+pub struct CustomOutputFormatter {
+    factory: ReporterFactory,
+    project: ProjectRoot,
+}
+
+impl CustomOutputFormatter {
+    pub fn new(factory: ReporterFactory) -> Self {
+        Self { factory, project: ProjectRoot::default() }
+    }
+}
+
+impl InternalFormatter for CustomOutputFormatter {
+    fn set_project_root(&mut self, project: ProjectRoot) {
+        self.project = project;
+    }
+
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        let mut reporter = (self.factory)();
+        reporter.on_project_root(&self.project);
+        Box::new(CustomReporter(reporter))
+    }
+}
+
+/// Adapts a [`Reporter`] to the [`DiagnosticService`](oxc_diagnostics::DiagnosticService).
+struct CustomReporter(Box<dyn Reporter>);
+
+impl DiagnosticReporter for CustomReporter {
+    fn start(&mut self) -> Option<String> {
+        self.0.on_start()
+    }
+
+    fn start_file(&mut self, path: &Path) -> Option<String> {
+        self.0.on_file(path)
+    }
+
+    fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
+        self.0.on_end(result)
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.0.on_diagnostic(error)
+    }
+}
@@ -1,14 +1,26 @@
-use std::time::Duration;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    time::Duration,
+};
 
-use crate::output_formatter::InternalFormatter;
+use crate::output_formatter::{GroupBy, InternalFormatter};
 use oxc_diagnostics::{
-    Error, GraphicalReportHandler,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    Error, GraphicalReportHandler, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
 };
 use oxc_linter::table::RuleTable;
 
 #[derive(Debug)]
-pub struct DefaultOutputFormatter;
+pub struct DefaultOutputFormatter {
+    group_by: GroupBy,
+}
+
+impl DefaultOutputFormatter {
+    pub fn new(group_by: GroupBy) -> Self {
+        Self { group_by }
+    }
+}
 
 impl InternalFormatter for DefaultOutputFormatter {
     fn all_rules(&self) -> Option<String> {
@@ -42,14 +54,24 @@ impl InternalFormatter for DefaultOutputFormatter {
 
     #[cfg(not(any(test, feature = "force_test_reporter")))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
-        Box::new(GraphicalReporter::default())
+        match self.group_by {
+            GroupBy::File => Box::new(GraphicalReporter::default()),
+            GroupBy::Rule => Box::new(RuleGroupedReporter::new(GraphicalReportHandler::new())),
+        }
     }
 
     #[cfg(any(test, feature = "force_test_reporter"))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        use oxc_diagnostics::GraphicalTheme;
+
         use crate::output_formatter::default::test_implementation::GraphicalReporterTester;
 
-        Box::new(GraphicalReporterTester::default())
+        match self.group_by {
+            GroupBy::File => Box::new(GraphicalReporterTester::default()),
+            GroupBy::Rule => Box::new(RuleGroupedReporter::new(
+                GraphicalReportHandler::new_themed(GraphicalTheme::none()),
+            )),
+        }
     }
 }
 
@@ -85,6 +107,92 @@ impl DiagnosticReporter for GraphicalReporter {
     }
 }
 
+/// Prints the number of diagnostics of each rule and the files they occur in, instead of each
+/// diagnostic. Useful when planning to enable or fix a single rule across a codebase.
+///
+/// Diagnostics which are not reported by a rule (e.g. parse errors) are printed as usual.
+struct RuleGroupedReporter {
+    handler: GraphicalReportHandler,
+    /// Rule name -> file name -> number of diagnostics
+    rules: BTreeMap<String, BTreeMap<String, DiagnosticCounts>>,
+}
+
+impl RuleGroupedReporter {
+    fn new(handler: GraphicalReportHandler) -> Self {
+        Self { handler, rules: BTreeMap::new() }
+    }
+}
+
+impl DiagnosticReporter for RuleGroupedReporter {
+    fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
+        let mut rules = self
+            .rules
+            .iter()
+            .map(|(rule, files)| {
+                let total =
+                    files.values().fold(DiagnosticCounts::default(), |mut total, counts| {
+                        total.errors += counts.errors;
+                        total.warnings += counts.warnings;
+                        total
+                    });
+                (rule, files, total)
+            })
+            .collect::<Vec<_>>();
+        // Rules with the most diagnostics first
+        rules.sort_by(|(a_rule, _, a), (b_rule, _, b)| {
+            (b.errors + b.warnings).cmp(&(a.errors + a.warnings)).then_with(|| a_rule.cmp(b_rule))
+        });
+
+        let mut output = String::new();
+        for (rule, files, total) in rules {
+            let s = if files.len() == 1 { "" } else { "s" };
+            writeln!(output, "\n{rule}: {total} in {} file{s}", files.len()).unwrap();
+            for (file, counts) in files {
+                writeln!(output, "  {file}: {counts}").unwrap();
+            }
+        }
+        output.push_str(&get_diagnostic_result_output(result));
+        Some(output)
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let info = Info::new(&error);
+        let Some(rule_id) = info.rule_id else {
+            let mut output = String::new();
+            self.handler.render_report(&mut output, error.as_ref()).unwrap();
+            return Some(output);
+        };
+        let counts = self.rules.entry(rule_id).or_default().entry(info.filename).or_default();
+        if info.severity == Severity::Error {
+            counts.errors += 1;
+        } else {
+            counts.warnings += 1;
+        }
+        None
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct DiagnosticCounts {
+    errors: usize,
+    warnings: usize,
+}
+
+impl fmt::Display for DiagnosticCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors = (self.errors > 0)
+            .then(|| format!("{} error{}", self.errors, if self.errors == 1 { "" } else { "s" }));
+        let warnings = (self.warnings > 0).then(|| {
+            format!("{} warning{}", self.warnings, if self.warnings == 1 { "" } else { "s" })
+        });
+        match (errors, warnings) {
+            (Some(errors), Some(warnings)) => write!(f, "{errors} and {warnings}"),
+            (Some(count), None) | (None, Some(count)) => f.write_str(&count),
+            (None, None) => f.write_str("0 warnings"),
+        }
+    }
+}
+
 fn get_diagnostic_result_output(result: &DiagnosticResult) -> String {
     let mut output = String::new();
 
@@ -103,10 +211,14 @@ fn get_diagnostic_result_output(result: &DiagnosticResult) -> String {
         .as_str(),
     );
 
-    if result.max_warnings_exceeded() {
+    if let Some(max_warnings) = result.max_warnings().filter(|_| result.max_warnings_exceeded()) {
         output.push_str(
-            format!("Exceeded maximum number of warnings. Found {}.\n", result.warnings_count())
-                .as_str(),
+            format!(
+                "Exceeded maximum number of warnings ({max_warnings}) by {}. Found {}.\n",
+                result.warnings_over_max(),
+                result.warnings_count()
+            )
+            .as_str(),
         );
     }
 
@@ -158,14 +270,14 @@ mod test {
     use std::time::Duration;
 
     use crate::output_formatter::{
-        InternalFormatter, LintCommandInfo,
+        GroupBy, InternalFormatter, LintCommandInfo,
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
     use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
 
     #[test]
     fn all_rules() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(GroupBy::File);
         let result = formatter.all_rules();
 
         assert!(result.is_some());
@@ -173,7 +285,7 @@ mod test {
 
     #[test]
     fn lint_command_info() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(GroupBy::File);
         let result = formatter.lint_command_info(&LintCommandInfo {
             number_of_files: 5,
             number_of_rules: Some(10),
@@ -190,7 +302,7 @@ mod test {
 
     #[test]
     fn lint_command_info_unknown_rules() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(GroupBy::File);
         let result = formatter.lint_command_info(&LintCommandInfo {
             number_of_files: 5,
             number_of_rules: None,
@@ -216,7 +328,7 @@ mod test {
     fn reporter_finish_one_warning_and_one_error() {
         let mut reporter = GraphicalReporter::default();
 
-        let result = reporter.finish(&DiagnosticResult::new(1, 1, false));
+        let result = reporter.finish(&DiagnosticResult::new(1, 1, None));
 
         assert!(result.is_some());
         assert_eq!(result.unwrap(), "\nFound 1 warning and 1 error.\n");
@@ -226,7 +338,7 @@ mod test {
     fn reporter_finish_multiple_warning_and_errors() {
         let mut reporter = GraphicalReporter::default();
 
-        let result = reporter.finish(&DiagnosticResult::new(6, 4, false));
+        let result = reporter.finish(&DiagnosticResult::new(6, 4, Some(6)));
 
         assert!(result.is_some());
         assert_eq!(result.unwrap(), "\nFound 6 warnings and 4 errors.\n");
@@ -236,12 +348,12 @@ mod test {
     fn reporter_finish_exceeded_warnings() {
         let mut reporter = GraphicalReporter::default();
 
-        let result = reporter.finish(&DiagnosticResult::new(6, 4, true));
+        let result = reporter.finish(&DiagnosticResult::new(6, 4, Some(4)));
 
         assert!(result.is_some());
         assert_eq!(
             result.unwrap(),
-            "\nFound 6 warnings and 4 errors.\nExceeded maximum number of warnings. Found 6.\n"
+            "\nFound 6 warnings and 4 errors.\nExceeded maximum number of warnings (4) by 2. Found 6.\n"
         );
     }
 }
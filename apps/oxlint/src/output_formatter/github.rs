@@ -36,7 +36,7 @@ fn format_github(diagnostic: &Error) -> String {
         Severity::Error => "error",
         Severity::Warning | miette::Severity::Advice => "warning",
     };
-    let title = rule_id.map_or(Cow::Borrowed("oxlint"), Cow::Owned);
+    let title = escape_property(&rule_id.map_or(Cow::Borrowed("oxlint"), Cow::Owned));
     let filename = escape_property(&filename);
     let message = escape_data(&message);
     format!(
@@ -113,4 +113,20 @@ mod test {
             "::warning file=file%3A//test.ts,line=1,endLine=1,col=1,endColumn=9,title=oxlint::error message\n"
         );
     }
+
+    #[test]
+    fn reporter_error_escaped_title() {
+        let mut reporter = GithubReporter;
+        let error = OxcDiagnostic::error("first line\nsecond line")
+            .with_error_code("plugin", "rule:name,with-comma")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("test.ts", "debugger;"));
+
+        let result = reporter.render_error(error);
+
+        assert_eq!(
+            result.unwrap(),
+            "::error file=test.ts,line=1,endLine=1,col=1,endColumn=9,title=plugin(rule%3Aname%2Cwith-comma)::first line%0Asecond line\n"
+        );
+    }
 }
@@ -3,9 +3,11 @@ use std::rc::Rc;
 
 use oxc_diagnostics::{
     Error,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    reporter::{DiagnosticReporter, DiagnosticResult, ProjectRoot, fingerprints},
 };
-use oxc_linter::{RuleCategory, rules::RULES};
+use oxc_linter::{FixKind, RuleCategory, RuleFixMeta, rules::RULES, table::RuleTable};
+use rustc_hash::FxHashSet;
+use schemars::{SchemaGenerator, r#gen::SchemaSettings, schema::Schema};
 
 use miette::JSONReportHandler;
 
@@ -23,12 +25,44 @@ impl InternalFormatter for JsonOutputFormatter {
             scope: &'a str,
             value: &'a str,
             category: RuleCategory,
+            /// See [`fix_capability`].
+            fix: String,
+            /// The severity without a configuration file, `warn` or `off`.
+            default: &'a str,
+            /// JSON Schema of the options of the rule, `null` if it has no options.
+            schema: Option<Schema>,
+            /// Whether the rule is deprecated.
+            deprecated: bool,
+            /// Rules replacing the rule if it is deprecated, as `plugin/rule-name`.
+            replaced_by: &'a [&'a str],
         }
 
+        let turned_on_by_default = RuleTable::default()
+            .sections
+            .into_iter()
+            .flat_map(|section| section.rows)
+            .filter(|row| row.turned_on_by_default)
+            .map(|row| (row.plugin, row.name))
+            .collect::<FxHashSet<_>>();
+        // Inline subschemas, so each schema is self-contained without shared definitions.
+        let mut generator =
+            SchemaGenerator::new(SchemaSettings::draft07().with(|s| s.inline_subschemas = true));
+
         let rules_info = RULES.iter().map(|rule| RuleInfoJson {
             scope: rule.plugin_name(),
             value: rule.name(),
             category: rule.category(),
+            fix: fix_capability(rule.fix()),
+            default: if turned_on_by_default
+                .contains(&(rule.plugin_name().to_string(), rule.name()))
+            {
+                "warn"
+            } else {
+                "off"
+            },
+            schema: rule.schema(&mut generator),
+            deprecated: rule.deprecated().is_some(),
+            replaced_by: rule.deprecated().map_or(&[], |deprecated| deprecated.replaced_by),
         });
 
         Some(
@@ -59,11 +93,35 @@ impl InternalFormatter for JsonOutputFormatter {
         ))
     }
 
+    fn set_project_root(&mut self, project: ProjectRoot) {
+        self.reporter.0.borrow_mut().project = project;
+    }
+
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
         Box::new(self.reporter.clone())
     }
 }
 
+/// The auto-fix capability of a rule: `none`, `pending`, or the kind of fix, e.g. `fix`,
+/// `suggestion` or `dangerous_fix_or_suggestion`. The kind is prefixed with `conditional_` when
+/// only some violations can be fixed.
+fn fix_capability(fix: RuleFixMeta) -> String {
+    let (kind, conditional) = match fix {
+        RuleFixMeta::None => return "none".to_string(),
+        RuleFixMeta::FixPending => return "pending".to_string(),
+        RuleFixMeta::Fixable(kind) => (kind, false),
+        RuleFixMeta::Conditional(kind) => (kind, true),
+    };
+    let noun = match (kind.contains(FixKind::Fix), kind.contains(FixKind::Suggestion)) {
+        (true, true) => "fix_or_suggestion",
+        (false, true) => "suggestion",
+        _ => "fix",
+    };
+    let dangerous = if kind.is_dangerous() { "dangerous_" } else { "" };
+    let conditional = if conditional { "conditional_" } else { "" };
+    format!("{conditional}{dangerous}{noun}")
+}
+
 /// Renders reports as a JSON array of objects.
 ///
 /// Note that, due to syntactic restrictions of JSON arrays, this reporter waits until all
@@ -71,6 +129,7 @@ impl InternalFormatter for JsonOutputFormatter {
 #[derive(Default, Debug)]
 struct JsonReporter {
     diagnostics: Vec<Error>,
+    project: ProjectRoot,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -99,19 +158,24 @@ impl DiagnosticReporter for JsonReporter {
 
 impl JsonReporter {
     pub(super) fn render(&mut self) -> String {
-        format_json(&mut self.diagnostics)
+        format_json(&mut self.diagnostics, &self.project)
     }
 }
 
 /// <https://github.com/fregante/eslint-formatters/tree/ae1fd9748596447d1fd09625c33d9e7ba9a3d06d/packages/eslint-formatter-json>
-fn format_json(diagnostics: &mut Vec<Error>) -> String {
+fn format_json(diagnostics: &mut Vec<Error>, project: &ProjectRoot) -> String {
     let handler = JSONReportHandler::new();
+    let fingerprints = fingerprints(diagnostics, project);
     let messages = diagnostics
         .drain(..)
-        .map(|error| {
+        .zip(fingerprints)
+        .map(|(error, fingerprint)| {
             let mut output = String::new();
             handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
+            let mut diagnostic: serde_json::Map<String, serde_json::Value> =
+                serde_json::from_str(&output).expect("Failed to parse diagnostic");
+            diagnostic.insert("fingerprint".to_string(), fingerprint.into());
+            serde_json::to_string(&diagnostic).expect("Failed to serialize")
         })
         .collect::<Vec<_>>()
         .join(",\n");
@@ -155,7 +219,28 @@ mod test {
             .unwrap();
         assert_eq!(
             &output,
-            "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
+            "{ \"diagnostics\": [{\"causes\":[],\"filename\":\"file://test.ts\",\"fingerprint\":\"c1f96a4141628aba\",\"labels\":[{\"span\":{\"column\":1,\"length\":8,\"line\":1,\"offset\":0}}],\"message\":\"error message\",\"related\":[],\"severity\":\"warning\"}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
         );
     }
+
+    #[test]
+    fn all_rules() {
+        let output = JsonOutputFormatter::default().all_rules().unwrap();
+        let rules: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let rule = |name: &str| {
+            rules.as_array().unwrap().iter().find(|rule| rule["value"] == name).unwrap().clone()
+        };
+
+        let eqeqeq = rule("eqeqeq");
+        assert_eq!(eqeqeq["scope"], "eslint");
+        assert_eq!(eqeqeq["category"], "pedantic");
+        assert_eq!(eqeqeq["fix"], "conditional_dangerous_fix");
+        assert_eq!(eqeqeq["default"], "off");
+        assert_eq!(eqeqeq["schema"]["properties"]["compareType"]["enum"][0], "always");
+
+        let no_debugger = rule("no-debugger");
+        assert_eq!(no_debugger["fix"], "fix");
+        assert_eq!(no_debugger["default"], "warn");
+        assert!(no_debugger["schema"].is_null());
+    }
 }
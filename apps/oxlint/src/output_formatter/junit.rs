@@ -41,10 +41,14 @@ fn format_junit(diagnostics: &[Error]) -> String {
         grouped.entry(info.filename).or_default().push(diagnostic);
     }
 
-    let mut test_suite = String::new();
-    for diagnostics in grouped.values() {
+    // Sort by file name so the output is stable across runs.
+    let mut grouped = grouped.into_iter().collect::<Vec<_>>();
+    grouped.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut test_suites = vec![];
+    for (_, diagnostics) in &grouped {
         let diagnostic = diagnostics[0];
-        let filename = Info::new(diagnostic).filename;
+        let filename = xml_escape(&Info::new(diagnostic).filename).into_owned();
         let mut test_cases = String::new();
         let mut error = 0;
         let mut warning = 0;
@@ -72,25 +76,27 @@ fn format_junit(diagnostics: &[Error]) -> String {
                 description,
                 severity
             );
-            let test_case =
-                format!("\n        <testcase name=\"{rule}\">\n{status}\n        </testcase>");
+            let test_case = format!(
+                "\n        <testcase name=\"{}\">\n{status}\n        </testcase>",
+                xml_escape(&rule)
+            );
             test_cases = format!("{test_cases}{test_case}");
         }
-        test_suite = format!(
+        test_suites.push(format!(
             "    <testsuite name=\"{}\" tests=\"{}\" disabled=\"0\" errors=\"{}\" failures=\"{}\">{}\n    </testsuite>",
             filename,
             diagnostics.len(),
             error,
             warning,
             test_cases
-        );
+        ));
     }
     let test_suites = format!(
         "<testsuites name=\"Oxlint\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n{}\n</testsuites>\n",
         total_errors + total_warnings,
         total_warnings,
         total_errors,
-        test_suite
+        test_suites.join("\n")
     );
 
     format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{test_suites}")
@@ -132,4 +138,34 @@ mod test {
         let output = reporter.finish(&DiagnosticResult::default()).unwrap();
         assert_eq!(output, EXPECTED_REPORT);
     }
+
+    #[test]
+    fn test_junit_reporter_multiple_files() {
+        const EXPECTED_REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="Oxlint" tests="2" failures="2" errors="0">
+    <testsuite name="a.js" tests="1" disabled="0" errors="0" failures="1">
+        <testcase name="eslint(no-debugger)">
+            <failure message="debugger">line 1, column 1, debugger</failure>
+        </testcase>
+    </testsuite>
+    <testsuite name="b.js" tests="1" disabled="0" errors="0" failures="1">
+        <testcase name="eslint(no-debugger)">
+            <failure message="debugger">line 1, column 1, debugger</failure>
+        </testcase>
+    </testsuite>
+</testsuites>
+"#;
+        let mut reporter = JUnitReporter::default();
+
+        for filename in ["b.js", "a.js"] {
+            let warning = OxcDiagnostic::warn("debugger")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(0, 9))
+                .with_source_code(NamedSource::new(filename, "debugger;"));
+            reporter.render_error(warning);
+        }
+
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        assert_eq!(output, EXPECTED_REPORT);
+    }
 }
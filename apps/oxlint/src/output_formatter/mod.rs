@@ -1,9 +1,11 @@
 mod checkstyle;
+mod custom;
 mod default;
 mod github;
 mod gitlab;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
@@ -12,17 +14,21 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use checkstyle::CheckStyleOutputFormatter;
+use custom::{CustomOutputFormatter, find_reporter};
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
-use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_diagnostics::reporter::{DiagnosticReporter, ProjectRoot};
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub use custom::{Reporter, ReporterFactory, register_reporter};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
     /// GitHub Check Annotation
@@ -34,6 +40,11 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// Static Analysis Results Interchange Format
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
+    /// A format registered with [`register_reporter`].
+    Custom(String),
 }
 
 impl FromStr for OutputFormat {
@@ -49,11 +60,35 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
+            _ if find_reporter(s).is_some() => Ok(Self::Custom(s.to_string())),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
 }
 
+/// How diagnostics are grouped in the output. Only supported by [`OutputFormat::Default`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum GroupBy {
+    /// Print each diagnostic.
+    #[default]
+    File,
+    /// Print the number of diagnostics of each rule, and the files they occur in.
+    Rule,
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Self::File),
+            "rule" => Ok(Self::Rule),
+            _ => Err(format!("'{s}' is not a known grouping")),
+        }
+    }
+}
+
 /// Some extra lint information, which can be outputted
 /// at the end of the command
 pub struct LintCommandInfo {
@@ -81,6 +116,9 @@ trait InternalFormatter {
         None
     }
 
+    /// Set the root of the project, which paths in fingerprints of diagnostics are relative to.
+    fn set_project_root(&mut self, _project: ProjectRoot) {}
+
     /// oxlint words with [`DiagnosticService`](oxc_diagnostics::DiagnosticService),
     /// which uses a own reporter to output to stdout.
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter>;
@@ -91,20 +129,27 @@ pub struct OutputFormatter {
 }
 
 impl OutputFormatter {
-    pub fn new(format: OutputFormat) -> Self {
-        Self { internal: Self::get_internal_formatter(format) }
+    pub fn new(format: OutputFormat, group_by: GroupBy) -> Self {
+        Self { internal: Self::get_internal_formatter(format, group_by) }
     }
 
-    fn get_internal_formatter(format: OutputFormat) -> Box<dyn InternalFormatter> {
+    fn get_internal_formatter(
+        format: OutputFormat,
+        group_by: GroupBy,
+    ) -> Box<dyn InternalFormatter> {
         match format {
             OutputFormat::Json => Box::<JsonOutputFormatter>::default(),
             OutputFormat::Checkstyle => Box::<CheckStyleOutputFormatter>::default(),
             OutputFormat::Github => Box::new(GithubOutputFormatter),
             OutputFormat::Gitlab => Box::<GitlabOutputFormatter>::default(),
             OutputFormat::Unix => Box::<UnixOutputFormatter>::default(),
-            OutputFormat::Default => Box::new(DefaultOutputFormatter),
+            OutputFormat::Default => Box::new(DefaultOutputFormatter::new(group_by)),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
+            OutputFormat::Custom(name) => Box::new(CustomOutputFormatter::new(
+                find_reporter(&name).expect("custom formats are registered before parsing"),
+            )),
         }
     }
 
@@ -119,6 +164,12 @@ impl OutputFormatter {
         self.internal.lint_command_info(lint_command_info)
     }
 
+    /// Set the root of the project, which paths in fingerprints of diagnostics are relative to.
+    /// See [`InternalFormatter::set_project_root`] for more details.
+    pub fn set_project_root(&mut self, project: ProjectRoot) {
+        self.internal.set_project_root(project);
+    }
+
     /// Returns the [`DiagnosticReporter`] which then will be used by [`DiagnosticService`](oxc_diagnostics::DiagnosticService)
     /// See [`InternalFormatter::get_diagnostic_reporter`] for more details.
     pub fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
@@ -164,6 +215,13 @@ mod test {
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_output_formatter_diagnostic_default_group_by_rule() {
+        let args = &["--group-by=rule", "test.js", "test2.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_output_formatter_diagnostic_stylish() {
         let args = &["--format=stylish", "test.js"];
@@ -177,4 +235,42 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_output_formatter_diagnostic_custom() {
+        use std::{path::Path, sync::Arc};
+
+        use oxc_diagnostics::{
+            Error,
+            reporter::{DiagnosticResult, Info},
+        };
+
+        use crate::output_formatter::{Reporter, register_reporter};
+
+        struct EventReporter;
+
+        impl Reporter for EventReporter {
+            fn on_start(&mut self) -> Option<String> {
+                Some("start\n".to_string())
+            }
+
+            fn on_file(&mut self, path: &Path) -> Option<String> {
+                Some(format!("file {}\n", path.file_name().unwrap().to_string_lossy()))
+            }
+
+            fn on_diagnostic(&mut self, diagnostic: Error) -> Option<String> {
+                let Info { start, rule_id, .. } = Info::new(&diagnostic);
+                Some(format!("{}:{} {}\n", start.line, start.column, rule_id.unwrap_or_default()))
+            }
+
+            fn on_end(&mut self, result: &DiagnosticResult) -> Option<String> {
+                Some(format!("end {} {}\n", result.errors_count(), result.warnings_count()))
+            }
+        }
+
+        register_reporter("events", Arc::new(|| Box::new(EventReporter)));
+        let args = &["--format=events", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
 }
@@ -0,0 +1,393 @@
+use miette::{SourceCode, SourceSpan};
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::{
+    Error, OxcDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info, ProjectRoot, fingerprints},
+};
+use oxc_linter::rules::RULES;
+
+use crate::output_formatter::InternalFormatter;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter {
+    project: ProjectRoot,
+}
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn set_project_root(&mut self, project: ProjectRoot) {
+        self.project = project;
+    }
+
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter { diagnostics: vec![], project: self.project.clone() })
+    }
+}
+
+/// Renders reports as a [SARIF 2.1.0] log.
+///
+/// SARIF is consumed by GitHub code scanning and other static analysis dashboards.
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+///
+/// [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+    project: ProjectRoot,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&mut self.diagnostics, &self.project))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    short_description: SarifMessage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifRuleProperties {
+    category: &'static str,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    partial_fingerprints: SarifFingerprints,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// One of the alternative fixes of a result.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifArtifactContent,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifFingerprints {
+    /// See [`fingerprints`].
+    #[serde(rename = "oxlint/v1")]
+    oxlint_v1: String,
+}
+
+fn format_sarif(diagnostics: &mut Vec<Error>, project: &ProjectRoot) -> String {
+    let mut rules: Vec<SarifRule> = vec![];
+    let mut rule_indices: FxHashMap<String, usize> = FxHashMap::default();
+
+    let fingerprints = fingerprints(diagnostics, project);
+    let results = diagnostics
+        .drain(..)
+        .zip(fingerprints)
+        .map(|(error, fingerprint)| {
+            let url = error.url().map(|url| url.to_string());
+            let Info { start, end, filename, message, severity, rule_id } = Info::new(&error);
+
+            let rule_index = rule_id.as_ref().map(|rule_id| {
+                *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    rules.push(sarif_rule(rule_id, url));
+                    rules.len() - 1
+                })
+            });
+
+            let level = match severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "note",
+            };
+
+            let fixes = sarif_fixes(&error, &filename);
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level,
+                message: SarifMessage { text: message },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation { uri: filename },
+                        region: SarifRegion {
+                            start_line: start.line,
+                            start_column: start.column,
+                            end_line: end.line,
+                            end_column: end.column,
+                        },
+                    },
+                }],
+                partial_fingerprints: SarifFingerprints { oxlint_v1: fingerprint },
+                fixes,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("Failed to serialize")
+}
+
+/// Builds a SARIF fix for each fix of the diagnostic. A fix replaces a single region of the file.
+fn sarif_fixes(error: &Error, uri: &str) -> Vec<SarifFix> {
+    let (Some(diagnostic), Some(source)) =
+        (OxcDiagnostic::downcast_ref(error), error.source_code())
+    else {
+        return vec![];
+    };
+    diagnostic
+        .fixes
+        .iter()
+        .filter_map(|fix| {
+            Some(SarifFix {
+                description: fix
+                    .message
+                    .as_ref()
+                    .map(|message| SarifMessage { text: message.to_string() }),
+                artifact_changes: vec![SarifArtifactChange {
+                    artifact_location: SarifArtifactLocation { uri: uri.to_string() },
+                    replacements: vec![SarifReplacement {
+                        deleted_region: sarif_region(source, fix.span)?,
+                        inserted_content: SarifArtifactContent { text: fix.content.to_string() },
+                    }],
+                }],
+            })
+        })
+        .collect()
+}
+
+/// Converts a byte range of the source code to a region with 1-based lines and columns.
+fn sarif_region(source: &dyn SourceCode, span: SourceSpan) -> Option<SarifRegion> {
+    let start = source.read_span(&SourceSpan::from((span.offset(), 0)), 0, 0).ok()?;
+    let end = source.read_span(&SourceSpan::from((span.offset() + span.len(), 0)), 0, 0).ok()?;
+    Some(SarifRegion {
+        start_line: start.line() + 1,
+        start_column: start.column() + 1,
+        end_line: end.line() + 1,
+        end_column: end.column() + 1,
+    })
+}
+
+/// Builds the rule metadata for a rule id in the format `scope(rule-name)`.
+fn sarif_rule(rule_id: &str, help_uri: Option<String>) -> SarifRule {
+    let rule_name =
+        rule_id.split_once('(').and_then(|(_, name)| name.strip_suffix(')')).unwrap_or(rule_id);
+
+    // The documentation url contains the plugin name, which disambiguates rules with the same name.
+    let rule = RULES.iter().find(|rule| {
+        rule.name() == rule_name
+            && help_uri.as_ref().is_none_or(|url| {
+                url.ends_with(&format!("/{}/{}.html", rule.plugin_name(), rule.name()))
+            })
+    });
+
+    SarifRule {
+        id: rule_id.to_string(),
+        short_description: SarifMessage { text: rule_id.to_string() },
+        help_uri,
+        properties: rule.map(|rule| SarifRuleProperties { category: rule.category().as_str() }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        DiagnosticFix, NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_error_code("eslint", "no-debugger")
+            .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("file://test.ts", "debugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // reporter gives results when finishing
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        let json: serde_json::Value = serde_json::from_str(&second_result.unwrap()).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "oxlint");
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "eslint(no-debugger)");
+        assert_eq!(
+            rules[0]["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rules[0]["properties"]["category"], "correctness");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result["ruleId"], "eslint(no-debugger)");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "error message");
+        assert_eq!(result["partialFingerprints"]["oxlint/v1"], "316d6057c03711fb");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "file://test.ts");
+        assert_eq!(location["region"]["startLine"], 1);
+        assert_eq!(location["region"]["startColumn"], 1);
+        assert_eq!(location["region"]["endLine"], 1);
+        assert_eq!(location["region"]["endColumn"], 9);
+        assert!(result.get("fixes").is_none());
+    }
+
+    #[test]
+    fn fixes() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(Span::new(4, 13))
+            .with_fixes([DiagnosticFix {
+                span: Span::new(4, 13).into(),
+                content: "".into(),
+                message: Some("Remove the debugger statement".into()),
+            }])
+            .with_source_code(NamedSource::new("test.ts", "a;\n\tdebugger;"));
+        reporter.render_error(error);
+
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let fixes = json["runs"][0]["results"][0]["fixes"].as_array().unwrap();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0]["description"]["text"], "Remove the debugger statement");
+        let change = &fixes[0]["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], "test.ts");
+        let replacement = &change["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startLine"], 2);
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 2);
+        assert_eq!(replacement["deletedRegion"]["endLine"], 2);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 11);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+    }
+}
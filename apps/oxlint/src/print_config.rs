@@ -0,0 +1,32 @@
+//! `oxlint --print-config [FILE]`: print the resolved configuration, of a single file if one is
+//! passed.
+
+use std::{ffi::OsStr, path::Path, sync::Arc};
+
+use oxc_linter::ConfigStore;
+
+use crate::cli::CliRunResult;
+
+/// The configuration which applies to the file in `paths`, with all overrides and nested
+/// configuration files applied.
+///
+/// # Errors
+///
+/// Returns an error if `paths` is not a single file.
+pub fn config_of_file(
+    paths: &[Arc<OsStr>],
+    config_store: &ConfigStore,
+) -> Result<String, (CliRunResult, String)> {
+    let [path] = paths else {
+        return Err((
+            CliRunResult::InvalidOptionConfig,
+            format!(
+                "`--print-config` expects a single file, but {} files were found.\n",
+                paths.len()
+            ),
+        ));
+    };
+    let mut config = config_store.resolve_final_config_file_for_path(Path::new(path));
+    config.push('\n');
+    Ok(config)
+}
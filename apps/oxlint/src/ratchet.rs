@@ -0,0 +1,289 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+use miette::Severity;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticTuple};
+
+use crate::cli::CliRunResult;
+
+/// Number of violations of each rule, recorded by `--ratchet`.
+///
+/// Unlike a [`Baseline`](crate::baseline::Baseline), individual violations are not tracked,
+/// so the file stays small and only changes when the number of violations of a rule changes.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatchetState {
+    /// Number of violations by rule, e.g. `eslint(no-debugger)`.
+    rules: BTreeMap<String, usize>,
+}
+
+impl RatchetState {
+    /// Returns `Ok(None)` if the file does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn from_file(path: &Path) -> Result<Option<Self>, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(format!("Failed to read ratchet file {}: {err}", path.display()));
+            }
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|err| format!("Failed to parse ratchet file {}: {err}", path.display()))
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, content + "\n")
+    }
+
+    /// Total number of violations.
+    pub fn len(&self) -> usize {
+        self.rules.values().sum()
+    }
+
+    /// Compare the current counts against the recorded counts.
+    pub fn compare(&self, current: &Self) -> RatchetComparison {
+        let mut comparison = RatchetComparison::default();
+        for (rule, &count) in &current.rules {
+            let recorded = self.rules.get(rule).copied().unwrap_or_default();
+            match count.cmp(&recorded) {
+                Ordering::Greater => comparison.increased.push((rule.clone(), recorded, count)),
+                Ordering::Less => comparison.decreased.push((rule.clone(), recorded, count)),
+                Ordering::Equal => {}
+            }
+        }
+        for (rule, &recorded) in &self.rules {
+            if !current.rules.contains_key(rule) {
+                comparison.decreased.push((rule.clone(), recorded, 0));
+            }
+        }
+        comparison.decreased.sort_unstable();
+        comparison
+    }
+
+    fn add(&mut self, rule: String) {
+        *self.rules.entry(rule).or_default() += 1;
+    }
+}
+
+/// Rules whose number of violations changed, with the recorded and the current count.
+#[derive(Debug, Default)]
+pub struct RatchetComparison {
+    pub increased: Vec<(String, usize, usize)>,
+    pub decreased: Vec<(String, usize, usize)>,
+}
+
+impl RatchetComparison {
+    /// Render the rules whose count increased, for the end of the output.
+    pub fn render_increased(&self) -> String {
+        let mut message = format!(
+            "Number of violations increased for {} rules, fix the new violations:\n",
+            self.increased.len()
+        );
+        for (rule, recorded, current) in &self.increased {
+            let _ = writeln!(message, "  {rule}: {recorded} -> {current}");
+        }
+        message
+    }
+}
+
+/// Result of [`count_by_rule`].
+#[derive(Debug, Default)]
+pub struct RatchetCounts {
+    /// Violations of lint rules.
+    pub state: RatchetState,
+    /// Errors which are not violations of a lint rule, e.g. parse errors.
+    /// These always fail the run.
+    pub other_errors: usize,
+}
+
+/// The ratchet file of `--ratchet`, relative to `cwd`, and the violations recorded in it.
+///
+/// # Errors
+///
+/// Returns an error if the ratchet file can't be read.
+pub fn load(
+    path: Option<&Path>,
+    cwd: &Path,
+) -> Result<Option<(PathBuf, Option<RatchetState>)>, (CliRunResult, String)> {
+    let Some(path) = path else { return Ok(None) };
+    let path = cwd.join(path);
+    match RatchetState::from_file(&path) {
+        Ok(state) => Ok(Some((path, state))),
+        Err(err) => Err((CliRunResult::InvalidOptionRatchet, format!("{err}\n"))),
+    }
+}
+
+/// Compare the violations `counts` with the violations `recorded` in the ratchet file at `path`,
+/// and write them to the file unless the number of violations of any rule increased.
+///
+/// Returns the message to print, and the result of the run: only new violations and errors which
+/// are not from a rule fail it.
+pub fn finish(
+    path: &Path,
+    recorded: Option<RatchetState>,
+    counts: RatchetCounts,
+) -> (Option<String>, CliRunResult) {
+    let RatchetCounts { state, other_errors } = counts;
+    let write = |message: String| match state.write(path) {
+        Ok(()) => message,
+        Err(err) => format!("Failed to write ratchet file {}: {err}\n", path.display()),
+    };
+    let mut increased = false;
+    let message = match recorded {
+        None => Some(write(format!(
+            "Ratchet file with {} violations written to {}\n",
+            state.len(),
+            path.display()
+        ))),
+        Some(recorded) => {
+            let comparison = recorded.compare(&state);
+            if !comparison.increased.is_empty() {
+                increased = true;
+                Some(comparison.render_increased())
+            } else if !comparison.decreased.is_empty() {
+                Some(write(format!(
+                    "Number of violations decreased for {} rules, updated {}\n",
+                    comparison.decreased.len(),
+                    path.display()
+                )))
+            } else {
+                None
+            }
+        }
+    };
+    let result = if increased {
+        CliRunResult::LintRatchetIncreased
+    } else if other_errors > 0 {
+        CliRunResult::LintFoundErrors
+    } else {
+        CliRunResult::LintSucceeded
+    };
+    (message, result)
+}
+
+/// Forward diagnostics to `tx_error`, counting the violations of each rule.
+///
+/// Returns the sender to pass to the linter, and a handle which resolves to the counts once the
+/// returned sender has been dropped.
+pub fn count_by_rule(tx_error: DiagnosticSender) -> (DiagnosticSender, JoinHandle<RatchetCounts>) {
+    let (sender, receiver) = mpsc::channel::<DiagnosticTuple>();
+    let handle = thread::spawn(move || {
+        let mut counts = RatchetCounts::default();
+        for (path, diagnostics) in receiver {
+            for diagnostic in &diagnostics {
+                match diagnostic.code() {
+                    Some(code) => counts.state.add(code.to_string()),
+                    None => {
+                        if matches!(diagnostic.severity(), Some(Severity::Error) | None) {
+                            counts.other_errors += 1;
+                        }
+                    }
+                }
+            }
+            // Receiver is only dropped once all diagnostics have been reported
+            let _ = tx_error.send((path, diagnostics));
+        }
+        counts
+    });
+    (sender, handle)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use oxc_diagnostics::{DiagnosticService, NamedSource, OxcDiagnostic, reporter::*};
+    use oxc_span::Span;
+
+    use super::{RatchetCounts, RatchetState, count_by_rule};
+
+    #[derive(Default)]
+    struct SilentReporter;
+
+    impl DiagnosticReporter for SilentReporter {
+        fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+            None
+        }
+
+        fn render_error(&mut self, _: oxc_diagnostics::Error) -> Option<String> {
+            None
+        }
+    }
+
+    fn diagnostic(rule: Option<&str>) -> oxc_diagnostics::Error {
+        let diagnostic = OxcDiagnostic::error("error").with_label(Span::new(0, 8));
+        let diagnostic = match rule {
+            Some(rule) => diagnostic.with_error_code("eslint", rule.to_string()),
+            None => diagnostic,
+        };
+        diagnostic.with_source_code(NamedSource::new("test.js", "debugger;".to_string()))
+    }
+
+    fn count(diagnostics: Vec<oxc_diagnostics::Error>) -> RatchetCounts {
+        let (mut service, tx_error) = DiagnosticService::new(Box::new(SilentReporter));
+        let (sender, handle) = count_by_rule(tx_error);
+        sender.send((PathBuf::from("test.js"), diagnostics)).unwrap();
+        drop(sender);
+        service.run(&mut Vec::new());
+        handle.join().unwrap()
+    }
+
+    #[test]
+    fn counts_and_compares() {
+        let recorded = count(vec![
+            diagnostic(Some("no-debugger")),
+            diagnostic(Some("no-debugger")),
+            diagnostic(Some("no-console")),
+        ]);
+        assert_eq!(recorded.state.len(), 3);
+        assert_eq!(recorded.other_errors, 0);
+
+        let current = count(vec![
+            diagnostic(Some("no-debugger")),
+            diagnostic(Some("no-empty")),
+            diagnostic(None),
+        ]);
+        assert_eq!(current.other_errors, 1);
+
+        let comparison = recorded.state.compare(&current.state);
+        assert_eq!(comparison.increased, vec![("eslint(no-empty)".to_string(), 0, 1)]);
+        assert_eq!(
+            comparison.decreased,
+            vec![
+                ("eslint(no-console)".to_string(), 1, 0),
+                ("eslint(no-debugger)".to_string(), 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ratchet.json");
+        assert_eq!(RatchetState::from_file(&path), Ok(None));
+
+        let state = count(vec![diagnostic(Some("no-debugger"))]).state;
+        state.write(&path).unwrap();
+        assert_eq!(RatchetState::from_file(&path), Ok(Some(state)));
+
+        std::fs::write(&path, "{").unwrap();
+        assert!(RatchetState::from_file(&path).is_err());
+    }
+}
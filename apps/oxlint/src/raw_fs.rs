@@ -1,5 +1,5 @@
 use std::{
-    fs::{self, File},
+    fs::File,
     io::{self, Read},
     mem::ManuallyDrop,
     path::Path,
@@ -7,12 +7,14 @@ use std::{
 };
 
 use oxc_allocator::Allocator;
-use oxc_linter::RuntimeFileSystem;
+use oxc_linter::{RuntimeFileSystem, write_in_source_encoding};
 
 /// File system used by `oxlint2`.
 ///
 /// Identical to `OsFileSystem`, except that `read_to_arena_str` reads the file's contents into
 /// start of the allocator, instead of the end. This conforms to what raw transfer needs.
+/// Files which are not UTF-8 are not transcoded, because the transcoded text can't be written
+/// at start of the allocator.
 ///
 /// Must only be used in conjunction with `AllocatorPool` with `fixed_size` feature enabled,
 /// which wraps `Allocator`s with a custom `Drop` impl, which makes `read_to_arena_str` safe.
@@ -40,7 +42,7 @@ impl RuntimeFileSystem for RawTransferFileSystem {
     }
 
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
-        fs::write(path, content)
+        write_in_source_encoding(path, content)
     }
 }
 
@@ -5,6 +5,11 @@ pub enum CliRunResult {
     None,
     InvalidOptionConfig,
     InvalidOptionTsConfig,
+    InvalidOptionTypeChecker,
+    InvalidOptionBaseline,
+    InvalidOptionRatchet,
+    InvalidOptionStdinFilename,
+    InvalidOptionDaemon,
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
@@ -12,30 +17,46 @@ pub enum CliRunResult {
     LintFoundErrors,
     LintMaxWarningsExceeded,
     LintNoWarningsAllowed,
+    LintRatchetIncreased,
     LintNoFilesFound,
     PrintConfigResult,
     ConfigFileInitFailed,
     ConfigFileInitSucceeded,
+    WatchFailed,
 }
 
-impl Termination for CliRunResult {
-    fn report(self) -> ExitCode {
+impl CliRunResult {
+    /// Whether oxlint exits with a success status.
+    pub fn is_success(&self) -> bool {
         match self {
             Self::None
             | Self::PrintConfigResult
             | Self::ConfigFileInitSucceeded
             | Self::LintSucceeded
             // ToDo: when oxc_linter (config) validates the configuration, we can use exit_code = 1 to fail
-            | Self::LintNoFilesFound => ExitCode::SUCCESS,
+            | Self::LintNoFilesFound => true,
             Self::ConfigFileInitFailed
             | Self::LintFoundErrors
             | Self::LintNoWarningsAllowed
             | Self::LintMaxWarningsExceeded
+            | Self::LintRatchetIncreased
             | Self::InvalidOptionConfig
             | Self::InvalidOptionTsConfig
+            | Self::InvalidOptionTypeChecker
+            | Self::InvalidOptionBaseline
+            | Self::InvalidOptionRatchet
+            | Self::InvalidOptionStdinFilename
+            | Self::InvalidOptionDaemon
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
-            | Self::InvalidOptionSeverityWithoutRuleName => ExitCode::FAILURE,
+            | Self::InvalidOptionSeverityWithoutRuleName
+            | Self::WatchFailed => false,
         }
     }
 }
+
+impl Termination for CliRunResult {
+    fn report(self) -> ExitCode {
+        if self.is_success() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+    }
+}
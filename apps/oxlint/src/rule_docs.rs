@@ -0,0 +1,180 @@
+//! `oxlint explain <RULE>`: print the documentation of a rule in the terminal, rendered from the
+//! same metadata as the rule pages of the website.
+
+use std::{fmt::Write as _, io::Write};
+
+use cow_utils::CowUtils;
+use oxc_linter::{
+    BuiltinLintPlugins,
+    table::{RuleTable, RuleTableRow},
+};
+use schemars::{SchemaGenerator, r#gen::SchemaSettings, schema::Schema};
+
+use crate::cli::CliRunResult;
+
+/// Print the documentation of `rule` to `stdout`.
+pub fn run_explain(rule: &str, stdout: &mut dyn Write) -> CliRunResult {
+    let (output, result) = explain(rule);
+    stdout.write_all(output.as_bytes()).and_then(|()| stdout.flush()).unwrap();
+    result
+}
+
+fn explain(rule: &str) -> (String, CliRunResult) {
+    let mut generator = SchemaGenerator::new(SchemaSettings::default());
+    let table = RuleTable::new(Some(&mut generator));
+    let rows = table
+        .sections
+        .iter()
+        .flat_map(|section| &section.rows)
+        .filter(|row| matches_rule(row, rule))
+        .collect::<Vec<_>>();
+    match rows.as_slice() {
+        [] => (
+            format!("Rule `{rule}` was not found. Run `oxlint --rules` to list all rules.\n"),
+            CliRunResult::InvalidOptionConfig,
+        ),
+        [row] => (render_rule(row, &generator), CliRunResult::None),
+        rows => {
+            let names = rows.iter().map(|row| full_name(row)).collect::<Vec<_>>();
+            (
+                format!("Rule `{rule}` is ambiguous, it is one of: {}\n", names.join(", ")),
+                CliRunResult::InvalidOptionConfig,
+            )
+        }
+    }
+}
+
+/// Whether `rule` names the rule of `row`, as `rule-name` or `plugin/rule-name`,
+/// with the plugin names accepted in configuration files, e.g. `@typescript-eslint`.
+fn matches_rule(row: &RuleTableRow, rule: &str) -> bool {
+    let Some((plugin, name)) = rule.rsplit_once('/') else {
+        return rule == row.name;
+    };
+    if name != row.name {
+        return false;
+    }
+    match BuiltinLintPlugins::from(row.plugin.as_str()) {
+        // Plugins which are not built-in plugins, i.e. `eslint`, must match exactly
+        builtin if builtin.is_empty() => plugin == row.plugin,
+        builtin => BuiltinLintPlugins::from(plugin) == builtin,
+    }
+}
+
+/// Name of the plugin of `row` as in configuration files, e.g. `jsx-a11y`.
+fn plugin_name(row: &RuleTableRow) -> &str {
+    let name: &'static str = BuiltinLintPlugins::from(row.plugin.as_str()).into();
+    if name.is_empty() { &row.plugin } else { name }
+}
+
+fn full_name(row: &RuleTableRow) -> String {
+    format!("{}/{}", plugin_name(row), row.name)
+}
+
+fn render_rule(row: &RuleTableRow, generator: &SchemaGenerator) -> String {
+    let full_name = full_name(row);
+    let mut out = String::new();
+
+    // Writing to a `String` can't fail
+    let _ = writeln!(out, "{full_name} ({})\n", row.category.as_str());
+    if let Some(deprecated) = &row.deprecated {
+        let _ = writeln!(out, "⚠️ This rule is {deprecated}.");
+    }
+    if row.turned_on_by_default {
+        out.push_str("✅ This rule is turned on by default.\n");
+    }
+    match row.autofix.emoji() {
+        Some(emoji) => {
+            let _ = writeln!(out, "{emoji} {}", row.autofix.description());
+        }
+        None => {
+            let _ = writeln!(out, "{}", row.autofix.description());
+        }
+    }
+
+    if let Some(documentation) = row.documentation {
+        out.push('\n');
+        render_markdown(documentation, &mut out);
+    }
+
+    let schema = row.schema.as_ref().map(|schema| generator.dereference(schema).unwrap_or(schema));
+    if let Some(schema @ Schema::Object(_)) = schema {
+        if let Ok(schema) = serde_json::to_string_pretty(schema) {
+            push_heading(&mut out, "Configuration");
+            for line in schema.lines() {
+                let _ = writeln!(out, "    {line}");
+            }
+        }
+    }
+
+    push_heading(&mut out, "How to use");
+    let plugin = plugin_name(row);
+    if BuiltinLintPlugins::default().contains(BuiltinLintPlugins::from(row.plugin.as_str())) {
+        let _ = writeln!(out, "    oxlint --deny {full_name}");
+    } else {
+        let _ = writeln!(out, "    oxlint --deny {full_name} --{plugin}-plugin");
+    }
+    out
+}
+
+/// Render the Markdown of rule documentation as plain text: headings are underlined, code blocks
+/// are indented, and emphasis is removed.
+fn render_markdown(markdown: &str, out: &mut String) {
+    let mut in_code_block = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            let _ = writeln!(out, "    {line}");
+        } else if line.starts_with('#') {
+            push_heading(out, line.trim_start_matches('#').trim());
+        } else {
+            let _ = writeln!(out, "{}", line.cow_replace("**", ""));
+        }
+    }
+}
+
+fn push_heading(out: &mut String, heading: &str) {
+    if !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+    let _ = writeln!(out, "{heading}\n{}", "-".repeat(heading.chars().count()));
+}
+
+#[cfg(test)]
+mod test {
+    use super::explain;
+    use crate::cli::CliRunResult;
+
+    #[test]
+    fn explain_rule() {
+        let (output, result) = explain("no-debugger");
+        assert!(matches!(result, CliRunResult::None));
+        assert!(output.starts_with("eslint/no-debugger (correctness)\n"), "{output}");
+        assert!(output.contains("✅ This rule is turned on by default."), "{output}");
+        assert!(output.contains("What it does\n------------\n"), "{output}");
+        assert!(output.contains("Examples of incorrect code for this rule:\n"), "{output}");
+        assert!(output.contains("\n      debugger;\n"), "{output}");
+        assert!(output.contains("\n    oxlint --deny eslint/no-debugger\n"), "{output}");
+
+        let (output, _) = explain("@typescript-eslint/no-explicit-any");
+        assert!(output.starts_with("typescript/no-explicit-any (restriction)\n"), "{output}");
+
+        let (output, _) = explain("eqeqeq");
+        assert!(output.contains("Configuration\n-------------\n"), "{output}");
+
+        let (output, _) = explain("jsx-a11y/alt-text");
+        assert!(output.contains("oxlint --deny jsx-a11y/alt-text --jsx-a11y-plugin\n"), "{output}");
+    }
+
+    #[test]
+    fn rule_not_found() {
+        let (output, result) = explain("no-such-rule");
+        assert!(matches!(result, CliRunResult::InvalidOptionConfig));
+        assert_eq!(
+            output,
+            "Rule `no-such-rule` was not found. Run `oxlint --rules` to list all rules.\n"
+        );
+        let (_, result) = explain("react/no-debugger");
+        assert!(matches!(result, CliRunResult::InvalidOptionConfig));
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{FileMetadata, FileSystem};
+
+use crate::cli::{CliRunResult, FixOptions, MiscOptions};
+
+/// File system for `--stdin-filename`: the file at `path` has the source text read from stdin,
+/// whether or not it exists, and all other paths are read from `inner`.
+pub struct StdinFileSystem {
+    inner: Arc<dyn FileSystem>,
+    path: PathBuf,
+    source_text: String,
+}
+
+impl StdinFileSystem {
+    pub fn new(inner: Arc<dyn FileSystem>, path: PathBuf, source_text: String) -> Self {
+        Self { inner, path, source_text }
+    }
+}
+
+impl FileSystem for StdinFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        if path == self.path {
+            return Ok(self.source_text.clone());
+        }
+        self.inner.read_to_string(path)
+    }
+
+    fn read_to_arena_str<'a>(&self, path: &Path, allocator: &'a Allocator) -> io::Result<&'a str> {
+        if path == self.path {
+            return Ok(allocator.alloc_str(&self.source_text));
+        }
+        self.inner.read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> io::Result<()> {
+        if path == self.path {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} was read from stdin and can't be written", path.display()),
+            ));
+        }
+        self.inner.write_file(path, content)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        if path == self.path {
+            return Ok(FileMetadata::new(true, false, false));
+        }
+        self.inner.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        if path == self.path {
+            return Ok(FileMetadata::new(true, false, false));
+        }
+        self.inner.symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.inner.read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.inner.read_dir(path)
+    }
+
+    fn fetch(&self, url: &str) -> io::Result<String> {
+        self.inner.fetch(url)
+    }
+}
+
+/// File system for `--stdin-filename` with the file at `path`, with `source_text`,
+/// or the source text read from stdin if it's not provided.
+///
+/// # Errors
+///
+/// Returns an error if `paths`, `--watch` or `--fix` are passed too, or stdin can't be read.
+pub fn stdin_file_system(
+    inner: Arc<dyn FileSystem>,
+    path: PathBuf,
+    source_text: Option<String>,
+    paths: &[PathBuf],
+    misc_options: &MiscOptions,
+    fix_options: &FixOptions,
+) -> Result<Arc<dyn FileSystem>, (CliRunResult, String)> {
+    if !paths.is_empty() || misc_options.watch || fix_options.is_enabled() {
+        return Err((
+            CliRunResult::InvalidOptionStdinFilename,
+            "`--stdin-filename` can't be used with paths, `--watch` or `--fix`.\n".to_string(),
+        ));
+    }
+    let source_text = if let Some(source_text) = source_text {
+        source_text
+    } else {
+        let mut source_text = String::new();
+        io::stdin().read_to_string(&mut source_text).map_err(|err| {
+            (
+                CliRunResult::InvalidOptionStdinFilename,
+                format!("Failed to read source text from stdin: {err}\n"),
+            )
+        })?;
+        source_text
+    };
+    Ok(Arc::new(StdinFileSystem::new(inner, path, source_text)))
+}
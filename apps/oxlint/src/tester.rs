@@ -1,17 +1,21 @@
 #[cfg(test)]
-use std::{env, path::PathBuf};
+use std::{env, path::PathBuf, sync::Arc};
 
 #[cfg(test)]
 use cow_utils::CowUtils;
 #[cfg(test)]
 use lazy_regex::Regex;
+#[cfg(test)]
+use oxc_linter::{FileSystem, MemoryFileSystem};
 
 #[cfg(test)]
-use crate::cli::{LintRunner, lint_command};
+use crate::cli::{CliRunResult, LintRunner, lint_command};
 
 #[cfg(test)]
 pub struct Tester {
     cwd: PathBuf,
+    file_system: Option<Arc<MemoryFileSystem>>,
+    stdin: Option<String>,
 }
 
 #[cfg(test)]
@@ -24,7 +28,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, file_system: None, stdin: None }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -32,6 +36,35 @@ impl Tester {
         self
     }
 
+    /// Lint the files of `file_system` instead of the file system of the operating system, in
+    /// the working directory `/project`.
+    pub fn with_file_system(mut self, file_system: &Arc<MemoryFileSystem>) -> Self {
+        self.cwd = PathBuf::from("/project");
+        self.file_system = Some(Arc::clone(file_system));
+        self
+    }
+
+    /// Lint `source_text` as if it was read from the standard input.
+    pub fn with_stdin(mut self, source_text: &str) -> Self {
+        self.stdin = Some(source_text.to_string());
+        self
+    }
+
+    /// Run oxlint with `args`, returning the result and the output.
+    pub fn lint(&self, args: &[&str]) -> (CliRunResult, String) {
+        let options = lint_command().run_inner(args).unwrap();
+        let mut runner = LintRunner::new(options, None).with_cwd(self.cwd.clone());
+        if let Some(file_system) = &self.file_system {
+            runner = runner.with_file_system(Arc::clone(file_system) as Arc<dyn FileSystem>);
+        }
+        if let Some(stdin) = &self.stdin {
+            runner = runner.with_stdin(stdin.clone());
+        }
+        let mut output = Vec::new();
+        let result = runner.run(&mut output);
+        (result, String::from_utf8(output).unwrap())
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
@@ -0,0 +1,146 @@
+//! Output of `--timing` and `--timing-json`.
+
+use std::{fmt::Write as _, fs, path::Path, time::Duration};
+
+use oxc_linter::{RuleTiming, RuleTimings};
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+
+/// The number of rules printed by `--timing`, like `TIMING=1` of ESLint.
+const TABLE_ROWS: usize = 10;
+
+/// Write the timings of `--timing-json` to its path relative to `cwd`. Returns the output to
+/// print: the table of `--timing`, and whether the timings couldn't be written.
+pub fn report(timings: &RuleTimings, options: &OutputOptions, cwd: &Path) -> String {
+    let timings = timings.sorted();
+    let mut output = String::new();
+    if options.timing {
+        output.push_str(&render_table(&timings));
+    }
+    if let Some(path) = &options.timing_json {
+        let path = cwd.join(path);
+        if let Err(err) = fs::write(&path, render_json(&timings)) {
+            let _ = writeln!(output, "Failed to write timing file {}: {err}", path.display());
+        }
+    }
+    output
+}
+
+/// A table of the slowest rules. `timings` must be sorted, the slowest rule first.
+pub fn render_table(timings: &[(&str, &str, RuleTiming)]) -> String {
+    let total: Duration = timings.iter().map(|(_, _, timing)| timing.duration).sum();
+    let rows = timings
+        .iter()
+        .take(TABLE_ROWS)
+        .map(|(plugin_name, rule_name, timing)| {
+            let relative = if total.is_zero() {
+                0.0
+            } else {
+                timing.duration.as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            [
+                format!("{plugin_name}/{rule_name}"),
+                format!("{:.3}", timing.duration.as_secs_f64() * 1000.0),
+                format!("{relative:.1}%"),
+                timing.allocations.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Rule", "Time (ms)", "Relative", "Allocations"];
+    let widths: [usize; 4] = std::array::from_fn(|column| {
+        rows.iter().map(|row| row[column].len()).chain([header[column].len()]).max().unwrap()
+    });
+
+    let mut output = String::new();
+    write_row(&mut output, &header, &widths);
+    let _ = writeln!(
+        output,
+        ":{}|{}:|{}:|{}:",
+        "-".repeat(widths[0]),
+        "-".repeat(widths[1] + 1),
+        "-".repeat(widths[2] + 1),
+        "-".repeat(widths[3]),
+    );
+    for row in &rows {
+        write_row(&mut output, row, &widths);
+    }
+    output
+}
+
+/// The first column is aligned to the left, the numbers to the right.
+fn write_row<S: AsRef<str>>(output: &mut String, cells: &[S; 4], widths: &[usize; 4]) {
+    let [rule, time, relative, allocations] = cells.each_ref().map(AsRef::as_ref);
+    let _ = writeln!(
+        output,
+        "{rule:<w0$} | {time:>w1$} | {relative:>w2$} | {allocations:>w3$}",
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+    );
+}
+
+/// The timings of all rules for `--timing-json`, the slowest rule first.
+pub fn render_json(timings: &[(&str, &str, RuleTiming)]) -> String {
+    let rules = timings
+        .iter()
+        .map(|(plugin_name, rule_name, timing)| {
+            json!({
+                "rule": format!("{plugin_name}/{rule_name}"),
+                "timeMs": timing.duration.as_secs_f64() * 1000.0,
+                "allocations": timing.allocations,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&json!({ "rules": rules })).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use oxc_linter::RuleTiming;
+
+    use super::{render_json, render_table};
+
+    fn timing(millis: u64, allocations: u64) -> RuleTiming {
+        RuleTiming { duration: Duration::from_millis(millis), allocations }
+    }
+
+    #[test]
+    fn table() {
+        let timings = [
+            ("eslint", "no-unused-vars", timing(30, 1200)),
+            ("oxc", "no-const-enum", timing(10, 0)),
+        ];
+        assert_eq!(
+            render_table(&timings),
+            "\
+Rule                  | Time (ms) | Relative | Allocations
+:---------------------|----------:|---------:|-----------:
+eslint/no-unused-vars |    30.000 |    75.0% |        1200
+oxc/no-const-enum     |    10.000 |    25.0% |           0
+"
+        );
+    }
+
+    #[test]
+    fn table_rows() {
+        let timings = (0..20).map(|i| ("eslint", "no-debugger", timing(i, 0))).collect::<Vec<_>>();
+        assert_eq!(render_table(&timings).lines().count(), 12);
+    }
+
+    #[test]
+    fn json() {
+        let timings = [("eslint", "no-debugger", timing(2, 3))];
+        let json: serde_json::Value = serde_json::from_str(&render_json(&timings)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "rules": [{ "rule": "eslint/no-debugger", "timeMs": 2.0, "allocations": 3 }]
+            })
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! `oxlint --type-aware`: type information for rules, from a type checker running in another
+//! process.
+
+use std::{ffi::OsStr, path::Path};
+
+use oxc_linter::SidecarTypeChecker;
+
+use crate::cli::{BasicOptions, CliRunResult};
+
+/// Start the type checker of `--type-checker` in `cwd`, with `--tsconfig` if it is provided.
+///
+/// # Errors
+///
+/// Returns an error if the type checker can't be started.
+pub fn spawn_type_checker(
+    options: &BasicOptions,
+    cwd: &Path,
+) -> Result<SidecarTypeChecker, (CliRunResult, String)> {
+    let args =
+        options.tsconfig.iter().flat_map(|path| [OsStr::new("--tsconfig"), path.as_os_str()]);
+    SidecarTypeChecker::spawn(&options.type_checker, args, cwd).map_err(|err| {
+        (
+            CliRunResult::InvalidOptionTypeChecker,
+            format!("Failed to start the type checker {:?}: {err}\n", options.type_checker),
+        )
+    })
+}
@@ -1,7 +1,16 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+use std::{
+    ffi::OsStr,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    sync::mpsc,
+};
 
-use ignore::{DirEntry, overrides::Override};
-use oxc_linter::LINTABLE_EXTENSIONS;
+use ignore::{
+    DirEntry, Match,
+    gitignore::{Gitignore, GitignoreBuilder},
+    overrides::Override,
+};
+use oxc_linter::{FileMetadata, FileSystem, LINTABLE_EXTENSIONS, MARKDOWN_EXTENSIONS};
 
 use crate::cli::IgnoreOptions;
 
@@ -14,12 +23,43 @@ impl Default for Extensions {
     }
 }
 
+impl Extensions {
+    /// The default extensions, plus Markdown extensions with `--markdown`.
+    fn from_options(options: &IgnoreOptions) -> Self {
+        let mut extensions = Self::default();
+        if options.markdown {
+            extensions.0.extend(MARKDOWN_EXTENSIONS);
+        }
+        extensions
+    }
+}
+
 pub struct Walk {
-    inner: ignore::WalkParallel,
+    inner: WalkInner,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
 }
 
+enum WalkInner {
+    /// Walk the file system of the operating system, respecting `.gitignore` files.
+    Os(ignore::WalkParallel),
+    /// Walk a file system provided by an embedder.
+    FileSystem(FileSystemWalk),
+}
+
+/// Walks a [`FileSystem`] like the walk of the operating system's file system: hidden files and
+/// directories are skipped, symbolic links are followed unless `--no-follow-symlinks` is set,
+/// `--max-depth` is respected, and ignore patterns and ignore files named like `--ignore-path`
+/// are respected. `.gitignore` files are not.
+struct FileSystemWalk {
+    file_system: Arc<dyn FileSystem>,
+    paths: Vec<PathBuf>,
+    ignore_file_name: Option<PathBuf>,
+    overrides: Option<Override>,
+    follow_symlinks: bool,
+    max_depth: Option<usize>,
+}
+
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
@@ -62,7 +102,8 @@ impl ignore::ParallelVisitor for WalkCollector {
     }
 }
 impl Walk {
-    /// Will not canonicalize paths.
+    /// Walk `paths` in parallel, with directories being distributed between threads by work
+    /// stealing. Will not canonicalize paths.
     /// # Panics
     pub fn new(
         paths: &[PathBuf],
@@ -92,16 +133,55 @@ impl Walk {
             }
         }
 
-        let inner = inner.ignore(false).git_global(false).follow_links(true).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        let inner = inner
+            .ignore(false)
+            .git_global(false)
+            .git_ignore(!options.no_gitignore)
+            .git_exclude(!options.no_gitignore)
+            .follow_links(!options.no_follow_symlinks)
+            .max_depth(options.max_depth)
+            .build_parallel();
+        Self { inner: WalkInner::Os(inner), extensions: Extensions::from_options(options) }
+    }
+
+    /// Walk `paths` in `file_system` instead of the file system of the operating system.
+    pub fn new_with_file_system(
+        paths: &[PathBuf],
+        options: &IgnoreOptions,
+        override_builder: Option<Override>,
+        file_system: Arc<dyn FileSystem>,
+    ) -> Self {
+        let (ignore_file_name, overrides) = if options.no_ignore {
+            (None, None)
+        } else {
+            (Some(PathBuf::from(&options.ignore_path)), override_builder)
+        };
+        let walk = FileSystemWalk {
+            file_system,
+            paths: paths.to_vec(),
+            ignore_file_name,
+            overrides,
+            follow_symlinks: !options.no_follow_symlinks,
+            max_depth: options.max_depth,
+        };
+        Self { inner: WalkInner::FileSystem(walk), extensions: Extensions::from_options(options) }
     }
 
+    /// The paths of the files to lint, sorted so that they do not depend on the order of the
+    /// traversal.
     pub fn paths(self) -> Vec<Arc<OsStr>> {
-        let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
-        self.inner.visit(&mut builder);
-        drop(builder);
-        receiver.into_iter().flatten().collect()
+        let mut paths = match self.inner {
+            WalkInner::Os(inner) => {
+                let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
+                let mut builder = WalkBuilder { sender, extensions: self.extensions };
+                inner.visit(&mut builder);
+                drop(builder);
+                receiver.into_iter().flatten().collect()
+            }
+            WalkInner::FileSystem(walk) => walk.paths(&self.extensions),
+        };
+        paths.sort_unstable();
+        paths
     }
 
     #[cfg_attr(not(test), expect(dead_code))]
@@ -111,24 +191,148 @@ impl Walk {
     }
 
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
+        // Symbolic links which are not followed are skipped.
         let Some(file_type) = dir_entry.file_type() else { return false };
-        if file_type.is_dir() {
+        if file_type.is_dir() || file_type.is_symlink() {
             return false;
         }
-        let Some(file_name) = dir_entry.path().file_name() else { return false };
+        Self::is_wanted_file(dir_entry.path(), extensions)
+    }
+
+    fn is_wanted_file(path: &Path, extensions: &Extensions) -> bool {
+        let Some(file_name) = path.file_name() else { return false };
         if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
             return false;
         }
-        let Some(extension) = dir_entry.path().extension() else { return false };
+        let Some(extension) = path.extension() else { return false };
         let extension = extension.to_string_lossy();
         extensions.0.contains(&extension.as_ref())
     }
 }
 
+impl FileSystemWalk {
+    fn paths(&self, extensions: &Extensions) -> Vec<Arc<OsStr>> {
+        let mut paths = vec![];
+        for path in &self.paths {
+            if self.file_system.is_dir(path) {
+                self.visit_dir(path, 0, &mut vec![], extensions, &mut paths);
+            } else if self.file_system.is_file(path) && Walk::is_wanted_file(path, extensions) {
+                paths.push(path.as_os_str().into());
+            }
+        }
+        paths
+    }
+
+    fn visit_dir(
+        &self,
+        dir: &Path,
+        depth: usize,
+        ignore_files: &mut Vec<Gitignore>,
+        extensions: &Extensions,
+        paths: &mut Vec<Arc<OsStr>>,
+    ) {
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            return;
+        }
+        let Ok(mut entries) = self.file_system.read_dir(dir) else { return };
+        entries.sort_unstable();
+
+        let ignore_file = self
+            .ignore_file_name
+            .as_ref()
+            .map(|name| dir.join(name))
+            .filter(|path| self.file_system.is_file(path));
+        let has_ignore_file = ignore_file.is_some();
+        if let Some(path) = ignore_file {
+            ignore_files.push(read_ignore_file(self.file_system.as_ref(), &path));
+        }
+
+        for entry in entries {
+            let is_hidden =
+                entry.file_name().is_some_and(|name| name.as_encoded_bytes().starts_with(b"."));
+            let Ok(metadata) = self.file_system.metadata(&entry) else { continue };
+            if is_hidden || self.is_ignored(&entry, metadata.is_dir(), ignore_files) {
+                continue;
+            }
+            if !self.follow_symlinks && self.is_symlink(&entry) {
+                continue;
+            }
+            if metadata.is_dir() {
+                if !self.is_symlink_loop(dir, &entry) {
+                    self.visit_dir(&entry, depth + 1, ignore_files, extensions, paths);
+                }
+            } else if metadata.is_file() && Walk::is_wanted_file(&entry, extensions) {
+                paths.push(entry.as_os_str().into());
+            }
+        }
+
+        if has_ignore_file {
+            ignore_files.pop();
+        }
+    }
+
+    fn is_ignored(&self, path: &Path, is_dir: bool, ignore_files: &[Gitignore]) -> bool {
+        if self
+            .overrides
+            .as_ref()
+            .is_some_and(|overrides| overrides.matched(path, is_dir).is_ignore())
+        {
+            return true;
+        }
+        // The closest ignore file with a matching pattern decides.
+        ignore_files.iter().rev().find_map(|ignore| match ignore.matched(path, is_dir) {
+            Match::None => None,
+            Match::Ignore(_) => Some(true),
+            Match::Whitelist(_) => Some(false),
+        }) == Some(true)
+    }
+
+    fn is_symlink(&self, path: &Path) -> bool {
+        self.file_system.symlink_metadata(path).is_ok_and(FileMetadata::is_symlink)
+    }
+
+    /// Whether `path` is a symbolic link to one of its ancestors.
+    fn is_symlink_loop(&self, dir: &Path, path: &Path) -> bool {
+        if !self.is_symlink(path) {
+            return false;
+        }
+        let Ok(target) = self.file_system.read_link(path) else { return true };
+        path.starts_with(normalize(&dir.join(target)))
+    }
+}
+
+/// Read an ignore file with patterns relative to its directory.
+pub fn read_ignore_file(file_system: &dyn FileSystem, path: &Path) -> Gitignore {
+    let mut builder = GitignoreBuilder::new(path.parent().unwrap_or(Path::new("")));
+    if let Ok(content) = file_system.read_to_string(path) {
+        for line in content.lines() {
+            // Invalid patterns are skipped, like `Gitignore::new` does.
+            let _ = builder.add_line(Some(path.to_path_buf()), line);
+        }
+    }
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+/// Remove `.` and `..` components without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
 #[cfg(test)]
 mod test {
     use std::{env, ffi::OsString, path::Path};
 
+    use cow_utils::CowUtils;
     use ignore::overrides::OverrideBuilder;
 
     use super::{Extensions, Walk};
@@ -142,6 +346,10 @@ mod test {
             no_ignore: false,
             ignore_path: OsString::from(".gitignore"),
             ignore_pattern: vec![],
+            no_gitignore: false,
+            no_follow_symlinks: false,
+            max_depth: None,
+            markdown: false,
         };
 
         let override_builder = OverrideBuilder::new("/").build().unwrap();
@@ -158,4 +366,71 @@ mod test {
 
         assert_eq!(paths, vec!["bar.vue", "foo.js"]);
     }
+
+    fn walk(path: &Path, ignore_options: &IgnoreOptions) -> Vec<String> {
+        Walk::new(&[path.to_path_buf()], ignore_options, None)
+            .paths()
+            .into_iter()
+            .map(|walked| {
+                Path::new(&walked)
+                    .strip_prefix(path)
+                    .unwrap()
+                    .to_string_lossy()
+                    .cow_replace('\\', "/")
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    fn ignore_options() -> IgnoreOptions {
+        IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec![],
+            no_gitignore: false,
+            no_follow_symlinks: false,
+            max_depth: None,
+            markdown: false,
+        }
+    }
+
+    #[test]
+    fn test_walk_max_depth() {
+        let fixture = env::current_dir().unwrap().join("fixtures/walk_depth");
+
+        // The paths are sorted regardless of the order of the parallel traversal.
+        assert_eq!(
+            walk(&fixture, &ignore_options()),
+            ["a.js", "nested/b.js", "nested/deeper/c.js"]
+        );
+        assert_eq!(
+            walk(&fixture, &IgnoreOptions { max_depth: Some(2), ..ignore_options() }),
+            ["a.js", "nested/b.js"]
+        );
+        assert_eq!(
+            walk(&fixture, &IgnoreOptions { max_depth: Some(1), ..ignore_options() }),
+            ["a.js"]
+        );
+        assert!(
+            walk(&fixture, &IgnoreOptions { max_depth: Some(0), ..ignore_options() }).is_empty()
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_walk_no_follow_symlinks() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/foo.js"), "debugger;").unwrap();
+        std::os::unix::fs::symlink(dir.join("src"), dir.join("linked")).unwrap();
+        std::os::unix::fs::symlink(dir.join("src/foo.js"), dir.join("bar.js")).unwrap();
+
+        let followed = walk(dir, &ignore_options());
+        let not_followed =
+            walk(dir, &IgnoreOptions { no_follow_symlinks: true, ..ignore_options() });
+
+        assert_eq!(followed, ["bar.js", "linked/foo.js", "src/foo.js"]);
+        assert_eq!(not_followed, ["src/foo.js"]);
+    }
 }
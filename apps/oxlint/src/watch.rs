@@ -0,0 +1,296 @@
+use std::{
+    convert::Infallible,
+    ffi::OsStr,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        mpsc::{self, Receiver},
+    },
+    time::{Duration, SystemTime},
+};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    cli::{CliRunResult, IgnoreOptions},
+    lint::print_and_flush_stdout,
+    walk::Walk,
+};
+
+/// Lint all files, then watch them for changes, and lint changed files again.
+/// When a configuration file changes, all files are linted again.
+///
+/// `lint` lints the changed files, or all files if [`None`], and writes the paths of the files
+/// it found to [`LintedPaths`].
+pub fn run(
+    stdout: &mut dyn Write,
+    ignore_options: &IgnoreOptions,
+    mut lint: impl FnMut(Option<Vec<PathBuf>>, &mut dyn Write, &mut LintedPaths),
+) -> CliRunResult {
+    let Err(err) = watch_files(stdout, ignore_options, &mut lint);
+    print_and_flush_stdout(stdout, &format!("Failed to watch files: {err}\n"));
+    CliRunResult::WatchFailed
+}
+
+/// Returns only when the files can't be watched.
+fn watch_files(
+    stdout: &mut dyn Write,
+    ignore_options: &IgnoreOptions,
+    lint: &mut impl FnMut(Option<Vec<PathBuf>>, &mut dyn Write, &mut LintedPaths),
+) -> notify::Result<Infallible> {
+    let mut linted = LintedPaths::default();
+    lint(None, stdout, &mut linted);
+    let mut watcher = Watcher::new(&linted.files, &linted.configs)?;
+    print_and_flush_stdout(stdout, "Watching for file changes...\n");
+
+    loop {
+        let changes = watcher.wait(Duration::MAX, |dir| {
+            Walk::new(&[dir.to_path_buf()], ignore_options, None).paths()
+        })?;
+
+        if changes.config_changed {
+            print_and_flush_stdout(stdout, "\nConfiguration changed, linting all files...\n");
+            lint(None, stdout, &mut linted);
+            watcher = Watcher::new(&linted.files, &linted.configs)?;
+        } else if !changes.files.is_empty() {
+            let count = changes.files.len();
+            let noun = if count == 1 { "file" } else { "files" };
+            print_and_flush_stdout(stdout, &format!("\n{count} {noun} changed, linting...\n"));
+            lint(Some(changes.files), stdout, &mut LintedPaths::default());
+        }
+    }
+}
+
+/// Detects changes to linted files and configuration files, with the file system events of the
+/// operating system.
+///
+/// The directories which contain linted files or configuration files are watched, so files added
+/// to them and configuration files created later are detected too.
+pub struct Watcher {
+    watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<notify::Event>>,
+    /// Watched directories, by canonical path, with the path their files are linted with,
+    /// or [`None`] if they only contain configuration files.
+    directories: FxHashMap<PathBuf, Option<PathBuf>>,
+    /// Linted files, by canonical path, with the path they are linted with.
+    files: FxHashMap<PathBuf, PathBuf>,
+    /// Canonical paths of configuration files, including ones which don't exist (yet).
+    configs: FxHashSet<PathBuf>,
+}
+
+/// Files found by a run of the linter, to watch them.
+#[derive(Debug, Default)]
+pub struct LintedPaths {
+    /// Linted files.
+    pub files: Vec<Arc<OsStr>>,
+    /// Files which were read or looked for while resolving the configuration.
+    pub configs: Vec<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub struct Changes {
+    /// Files which were added or modified.
+    pub files: Vec<PathBuf>,
+    /// Whether any configuration file was added, modified or removed.
+    pub config_changed: bool,
+}
+
+/// Length and modification time of a file, to detect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl FileState {
+    /// Returns [`None`] if the file doesn't exist.
+    pub fn new(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self { len: metadata.len(), modified: metadata.modified().ok() })
+    }
+}
+
+/// Canonical path of the directory of `path`, and the name of `path`.
+/// Returns [`None`] if the directory doesn't exist.
+fn split_canonical(path: &Path) -> Option<(PathBuf, &OsStr)> {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    Some((fs::canonicalize(dir).ok()?, path.file_name()?))
+}
+
+impl Watcher {
+    /// Events which arrive within this duration of each other are reported together, e.g. when
+    /// an editor saves several files, or writes a file in several steps.
+    const DEBOUNCE: Duration = Duration::from_millis(50);
+
+    /// Watch the linted files `paths`, and the configuration files `configs`, i.e. all files
+    /// which were read or looked for while resolving the configuration, including nested
+    /// configuration files in ancestor directories and the files configurations extend.
+    pub fn new(paths: &[Arc<OsStr>], configs: &[PathBuf]) -> notify::Result<Self> {
+        let (sender, events) = mpsc::channel();
+        let watcher = notify::recommended_watcher(move |event| {
+            // The receiver is only dropped with the watcher
+            let _ = sender.send(event);
+        })?;
+        let mut watcher = Self {
+            watcher,
+            events,
+            directories: FxHashMap::default(),
+            files: FxHashMap::default(),
+            configs: FxHashSet::default(),
+        };
+        for config in configs {
+            let Some((dir, name)) = split_canonical(config) else { continue };
+            watcher.configs.insert(dir.join(name));
+            watcher.watch_directory(dir, None)?;
+        }
+        for path in paths {
+            watcher.add_file(PathBuf::from(path))?;
+        }
+        Ok(watcher)
+    }
+
+    fn watch_directory(&mut self, dir: PathBuf, original: Option<PathBuf>) -> notify::Result<()> {
+        match self.directories.get_mut(&dir) {
+            Some(entry) => {
+                if entry.is_none() {
+                    *entry = original;
+                }
+            }
+            None => {
+                self.watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+                self.directories.insert(dir, original);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `path` was not linted before.
+    fn add_file(&mut self, path: PathBuf) -> notify::Result<bool> {
+        let Some((dir, name)) = split_canonical(&path) else { return Ok(false) };
+        let key = dir.join(name);
+        let original = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        self.watch_directory(dir, Some(original))?;
+        Ok(self.files.insert(key, path).is_none())
+    }
+
+    /// Wait up to `timeout` for changes.
+    ///
+    /// `walk` is called with directories which files may have been added to,
+    /// and must return the lintable files within them.
+    pub fn wait(
+        &mut self,
+        timeout: Duration,
+        walk: impl Fn(&Path) -> Vec<Arc<OsStr>>,
+    ) -> notify::Result<Changes> {
+        let mut changes = Changes::default();
+        let Ok(event) = self.events.recv_timeout(timeout) else { return Ok(changes) };
+        let mut events = vec![event];
+        while let Ok(event) = self.events.recv_timeout(Self::DEBOUNCE) {
+            events.push(event);
+        }
+
+        let mut changed_directories = FxHashSet::default();
+        for event in events {
+            let event = event?;
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+            for path in event.paths {
+                if self.configs.contains(&path) {
+                    changes.config_changed = true;
+                } else if self.files.contains_key(&path) {
+                    // Deleted files are forgotten
+                    if path.exists() {
+                        changes.files.push(self.files[&path].clone());
+                    } else {
+                        self.files.remove(&path);
+                    }
+                } else if let Some(Some(dir)) =
+                    path.parent().and_then(|dir| self.directories.get(dir))
+                {
+                    changed_directories.insert(dir.clone());
+                }
+            }
+        }
+
+        // Added files
+        for dir in changed_directories {
+            for path in walk(&dir) {
+                let path = PathBuf::from(&*path);
+                if self.add_file(path.clone())? {
+                    changes.files.push(path);
+                }
+            }
+        }
+
+        changes.files.sort_unstable();
+        changes.files.dedup();
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{ffi::OsStr, fs, path::Path, sync::Arc, time::Duration};
+
+    use super::Watcher;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn walk(dir: &Path) -> Vec<Arc<OsStr>> {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "js"))
+            .map(|path| path.as_os_str().into())
+            .collect()
+    }
+
+    #[test]
+    fn detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("nested");
+        fs::create_dir(&nested).unwrap();
+        let file = nested.join("a.js");
+        fs::write(&file, "debugger;").unwrap();
+        // A configuration file in an ancestor directory, which doesn't exist yet
+        let config = dir.path().join(".oxlintrc.json");
+        let extended = dir.path().join("base.json");
+        fs::write(&extended, "{}").unwrap();
+
+        let mut watcher =
+            Watcher::new(&[file.as_os_str().into()], &[config.clone(), extended.clone()]).unwrap();
+        let changes = watcher.wait(Duration::from_millis(100), walk).unwrap();
+        assert!(changes.files.is_empty());
+        assert!(!changes.config_changed);
+
+        // Modified file
+        fs::write(&file, "debugger; debugger;").unwrap();
+        let changes = watcher.wait(TIMEOUT, walk).unwrap();
+        assert_eq!(changes.files, [file.clone()]);
+        assert!(!changes.config_changed);
+
+        // Added file
+        let added = nested.join("b.js");
+        fs::write(&added, "").unwrap();
+        let changes = watcher.wait(TIMEOUT, walk).unwrap();
+        assert_eq!(changes.files, [added]);
+        assert!(!changes.config_changed);
+
+        // Deleted file
+        fs::remove_file(&file).unwrap();
+        let changes = watcher.wait(TIMEOUT, walk).unwrap();
+        assert!(changes.files.is_empty());
+
+        // Added configuration file
+        fs::write(&config, "{}").unwrap();
+        assert!(watcher.wait(TIMEOUT, walk).unwrap().config_changed);
+
+        // Modified extended configuration file
+        fs::write(&extended, r#"{ "rules": {} }"#).unwrap();
+        assert!(watcher.wait(TIMEOUT, walk).unwrap().config_changed);
+    }
+}
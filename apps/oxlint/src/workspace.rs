@@ -0,0 +1,377 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+    thread::{self, JoinHandle},
+};
+
+use cow_utils::CowUtils;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use miette::Severity;
+use serde_json::Value;
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticTuple, reporter::DiagnosticResult};
+
+use crate::{cli::CliRunResult, output_formatter::OutputFormat};
+
+/// Directories which are never searched for packages.
+const SKIPPED_DIRS: [&str; 1] = ["node_modules"];
+
+/// How deep packages are searched for patterns with `**`.
+const MAX_DEPTH: usize = 8;
+
+/// The packages of a monorepo, declared by the `workspaces` of `package.json` (npm, yarn) or by
+/// `pnpm-workspace.yaml` (pnpm).
+#[derive(Debug)]
+pub struct Workspace {
+    packages: Vec<Package>,
+}
+
+#[derive(Debug)]
+pub struct Package {
+    /// `name` of the `package.json` of the package, or its path if it has no name.
+    pub name: String,
+    /// Absolute path of the directory of the package.
+    pub path: PathBuf,
+}
+
+impl Workspace {
+    /// Find the packages of the workspace with the root `cwd`.
+    /// Returns `None` if `cwd` is not the root of a workspace.
+    pub fn detect(cwd: &Path) -> Option<Self> {
+        let patterns = read_pnpm_workspace(cwd).or_else(|| read_package_json_workspaces(cwd))?;
+        let mut packages = find_packages(cwd, &patterns);
+        // Nested packages first, so a file belongs to the innermost package.
+        packages.sort_unstable_by(|a, b| {
+            b.path.components().count().cmp(&a.path.components().count()).then(a.name.cmp(&b.name))
+        });
+        Some(Self { packages })
+    }
+
+    /// Whether `dir` is the root of a workspace, i.e. declares the packages of a monorepo.
+    pub fn is_root(dir: &Path) -> bool {
+        read_pnpm_workspace(dir).is_some() || read_package_json_workspaces(dir).is_some()
+    }
+
+    pub fn packages(&self) -> &[Package] {
+        &self.packages
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.packages.iter().position(|package| package.name == name)
+    }
+
+    /// Index of the package which contains `path`.
+    pub fn package_of(&self, path: &Path) -> Option<usize> {
+        self.packages.iter().position(|package| path.starts_with(&package.path))
+    }
+}
+
+fn read_pnpm_workspace(cwd: &Path) -> Option<Vec<String>> {
+    let content = fs::read_to_string(cwd.join("pnpm-workspace.yaml")).ok()?;
+    Some(parse_pnpm_workspace(&content))
+}
+
+/// Read the `packages` list of `pnpm-workspace.yaml`, which is the only key needed here.
+fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+    let mut patterns = vec![];
+    let mut in_packages = false;
+    for line in content.lines() {
+        let trimmed = line.split(" #").next().unwrap_or_default().trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages {
+            if let Some(pattern) = trimmed.strip_prefix('-') {
+                patterns.push(pattern.trim().trim_matches(['\'', '"']).to_string());
+            }
+        }
+    }
+    patterns
+}
+
+fn read_package_json_workspaces(cwd: &Path) -> Option<Vec<String>> {
+    let content = fs::read_to_string(cwd.join("package.json")).ok()?;
+    let package_json: Value = serde_json::from_str(&content).ok()?;
+    let workspaces = package_json.get("workspaces")?;
+    // Yarn also accepts `{ "packages": [...] }`
+    let workspaces = workspaces.get("packages").unwrap_or(workspaces);
+    let patterns = workspaces.as_array()?.iter().filter_map(Value::as_str).map(String::from);
+    Some(patterns.collect())
+}
+
+fn find_packages(cwd: &Path, patterns: &[String]) -> Vec<Package> {
+    let mut include = GlobSetBuilder::new();
+    let mut exclude = GlobSetBuilder::new();
+    let mut max_depth = 0;
+    for pattern in patterns {
+        let (is_exclude, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern.as_str()),
+        };
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() else {
+            continue;
+        };
+        if is_exclude {
+            exclude.add(glob);
+        } else {
+            include.add(glob);
+            let depth = if pattern.contains("**") { MAX_DEPTH } else { pattern.split('/').count() };
+            max_depth = max_depth.max(depth);
+        }
+    }
+    let (Ok(include), Ok(exclude)) = (include.build(), exclude.build()) else {
+        return vec![];
+    };
+
+    let mut packages = vec![];
+    collect_packages(cwd, cwd, max_depth, &include, &exclude, &mut packages);
+    packages
+}
+
+fn collect_packages(
+    cwd: &Path,
+    dir: &Path,
+    depth: usize,
+    include: &GlobSet,
+    exclude: &GlobSet,
+    packages: &mut Vec<Package>,
+) {
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.')
+            || SKIPPED_DIRS.contains(&name.as_ref())
+            || !entry.file_type().is_ok_and(|file_type| file_type.is_dir())
+        {
+            continue;
+        }
+        let path = entry.path();
+        let relative = path.strip_prefix(cwd).unwrap_or(&path);
+        if include.is_match(relative) && !exclude.is_match(relative) {
+            if let Some(name) = read_package_name(&path, relative) {
+                packages.push(Package { name, path: path.clone() });
+            }
+        }
+        collect_packages(cwd, &path, depth - 1, include, exclude, packages);
+    }
+}
+
+/// `None` if `dir` has no `package.json`, `relative` if the `package.json` has no name.
+fn read_package_name(dir: &Path, relative: &Path) -> Option<String> {
+    let content = fs::read_to_string(dir.join("package.json")).ok()?;
+    let name = serde_json::from_str::<Value>(&content)
+        .ok()
+        .and_then(|json| json.get("name")?.as_str().map(String::from));
+    Some(name.unwrap_or_else(|| relative.to_string_lossy().cow_replace('\\', "/").into_owned()))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProblemCounts {
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Errors and warnings of each package of a [`Workspace`].
+#[derive(Debug)]
+pub struct WorkspaceSummary {
+    /// Indexed like [`Workspace::packages`].
+    packages: Vec<ProblemCounts>,
+    /// Files which are not in any package.
+    root: ProblemCounts,
+}
+
+impl WorkspaceSummary {
+    /// Sum of the problems of the packages at `indices`.
+    pub fn counts_of(&self, indices: &[usize]) -> ProblemCounts {
+        indices.iter().fold(ProblemCounts::default(), |sum, index| ProblemCounts {
+            errors: sum.errors + self.packages[*index].errors,
+            warnings: sum.warnings + self.packages[*index].warnings,
+        })
+    }
+
+    /// A breakdown of the problems of packages with problems, `None` if there are no problems.
+    pub fn render(&self, workspace: &Workspace) -> Option<String> {
+        let mut rows = workspace
+            .packages()
+            .iter()
+            .zip(&self.packages)
+            .map(|(package, counts)| (package.name.as_str(), *counts))
+            .collect::<Vec<_>>();
+        rows.sort_unstable_by_key(|(name, _)| *name);
+        // Files outside of packages last
+        rows.push(("(root)", self.root));
+        rows.retain(|(_, counts)| counts.errors + counts.warnings > 0);
+        if rows.is_empty() {
+            return None;
+        }
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or_default();
+        let mut output = String::from("Problems by package:\n");
+        for (name, ProblemCounts { errors, warnings }) in rows {
+            let errors_noun = if errors == 1 { "error" } else { "errors" };
+            let warnings_noun = if warnings == 1 { "warning" } else { "warnings" };
+            let _ = writeln!(
+                output,
+                "  {name:<width$}  {errors} {errors_noun}, {warnings} {warnings_noun}"
+            );
+        }
+        Some(output)
+    }
+}
+
+/// The workspace of `cwd`, to break down the problems by package with the default output format,
+/// or to only fail on the problems of the packages of `--fail-on-package`, and the indices of
+/// these packages.
+///
+/// # Errors
+///
+/// Returns an error if a package of `fail_on_package` is not a package of the workspace.
+pub fn load(
+    cwd: &Path,
+    format: &OutputFormat,
+    fail_on_package: &[String],
+) -> Result<(Option<Arc<Workspace>>, Vec<usize>), (CliRunResult, String)> {
+    let workspace = (*format == OutputFormat::Default || !fail_on_package.is_empty())
+        .then(|| Workspace::detect(cwd))
+        .flatten()
+        .map(Arc::new);
+    let indices = fail_on_package
+        .iter()
+        .map(|name| {
+            workspace.as_ref().and_then(|workspace| workspace.find(name)).ok_or_else(|| {
+                (
+                    CliRunResult::InvalidOptionConfig,
+                    format!(
+                        "The package `{name}` passed to `--fail-on-package` is not a workspace package.\n"
+                    ),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((workspace, indices))
+}
+
+/// The breakdown of `summary` to print with the default output format, and the result of the run:
+/// only the problems of the packages at `fail_on_package` count if there are any.
+pub fn finish(
+    workspace: &Workspace,
+    summary: &WorkspaceSummary,
+    format: &OutputFormat,
+    fail_on_package: &[usize],
+    diagnostic_result: DiagnosticResult,
+    max_warnings: Option<usize>,
+) -> (Option<String>, DiagnosticResult) {
+    let breakdown = if *format == OutputFormat::Default { summary.render(workspace) } else { None };
+    if fail_on_package.is_empty() {
+        return (breakdown, diagnostic_result);
+    }
+    let counts = summary.counts_of(fail_on_package);
+    (breakdown, DiagnosticResult::new(counts.warnings, counts.errors, max_warnings))
+}
+
+/// Forward diagnostics to `tx_error`, counting the errors and warnings of each package.
+///
+/// Returns the sender to pass to the linter, and a handle which resolves to the counts once the
+/// returned sender has been dropped.
+pub fn count_by_package(
+    tx_error: DiagnosticSender,
+    workspace: Arc<Workspace>,
+) -> (DiagnosticSender, JoinHandle<WorkspaceSummary>) {
+    let (sender, receiver) = mpsc::channel::<DiagnosticTuple>();
+    let handle = thread::spawn(move || {
+        let mut summary = WorkspaceSummary {
+            packages: vec![ProblemCounts::default(); workspace.packages().len()],
+            root: ProblemCounts::default(),
+        };
+        for (path, diagnostics) in receiver {
+            let counts = match workspace.package_of(&path) {
+                Some(index) => &mut summary.packages[index],
+                None => &mut summary.root,
+            };
+            // Counted like the `DiagnosticService` does
+            for diagnostic in &diagnostics {
+                match diagnostic.severity() {
+                    Some(Severity::Warning) => counts.warnings += 1,
+                    Some(Severity::Error) | None => counts.errors += 1,
+                    Some(Severity::Advice) => {}
+                }
+            }
+            // Receiver is only dropped once all diagnostics have been reported
+            let _ = tx_error.send((path, diagnostics));
+        }
+        summary
+    });
+    (sender, handle)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{Workspace, parse_pnpm_workspace};
+
+    #[test]
+    fn pnpm_workspace() {
+        let content = "\
+# comment
+packages:
+  - 'packages/*'
+  - \"apps/**\" # comment
+  - '!**/test/**'
+catalog:
+  - 'not-a-package'
+";
+        assert_eq!(parse_pnpm_workspace(content), ["packages/*", "apps/**", "!**/test/**"]);
+    }
+
+    #[test]
+    fn detect() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("pnpm-workspace.yaml"),
+            "packages:\n  - 'packages/*'\n  - '!packages/skipped'\n",
+        )
+        .unwrap();
+        for (path, package_json) in [
+            ("packages/a", r#"{ "name": "@scope/a" }"#),
+            ("packages/unnamed", "{}"),
+            ("packages/skipped", r#"{ "name": "skipped" }"#),
+            ("packages/a/nested", r#"{ "name": "nested" }"#),
+            ("other", r#"{ "name": "other" }"#),
+        ] {
+            fs::create_dir_all(root.join(path)).unwrap();
+            fs::write(root.join(path).join("package.json"), package_json).unwrap();
+        }
+        fs::create_dir_all(root.join("packages/no-package-json")).unwrap();
+
+        let workspace = Workspace::detect(root).unwrap();
+        let mut names =
+            workspace.packages().iter().map(|package| package.name.as_str()).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["@scope/a", "packages/unnamed"]);
+        assert_eq!(
+            workspace.package_of(&root.join("packages/a/nested/index.js")),
+            workspace.find("@scope/a")
+        );
+        assert_eq!(workspace.package_of(&root.join("other/index.js")), None);
+    }
+
+    #[test]
+    fn no_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("package.json"), r#"{ "name": "app" }"#).unwrap();
+        assert!(Workspace::detect(dir.path()).is_none());
+    }
+}
@@ -0,0 +1,125 @@
+use std::{fmt, path::PathBuf, str::FromStr};
+
+use bpaf::Bpaf;
+
+const VERSION: &str = match option_env!("OXC_VERSION") {
+    Some(v) => v,
+    None => "dev",
+};
+
+/// Inspect source maps while debugging build pipelines
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub enum SourcemapCommand {
+    /// Print the original location which a position of the generated file maps to
+    #[bpaf(command)]
+    Explain {
+        /// Source map of the generated file
+        #[bpaf(positional("MAP"))]
+        sourcemap: PathBuf,
+
+        /// Position in the generated file, as `LINE:COLUMN` starting at 1,
+        /// like the positions of stack traces
+        #[bpaf(positional("LINE:COLUMN"))]
+        position: Position,
+    },
+
+    /// Compose the source maps of consecutive build steps into a source map from the output of
+    /// the last step to the sources of the first step
+    #[bpaf(command)]
+    Compose {
+        /// Write the composed source map to FILE instead of stdout
+        #[bpaf(short, long, argument("FILE"))]
+        output: Option<PathBuf>,
+
+        /// Source maps, from the first build step to the last
+        #[bpaf(positional("MAP"), some("at least one source map is required"))]
+        sourcemaps: Vec<PathBuf>,
+    },
+
+    /// Check that the mappings of a source map point into its sources and the generated file
+    #[bpaf(command)]
+    Validate {
+        /// The generated file, to check that the mappings point into it
+        #[bpaf(long, argument("FILE"))]
+        generated: Option<PathBuf>,
+
+        /// Source map to validate
+        #[bpaf(positional("MAP"))]
+        sourcemap: PathBuf,
+    },
+}
+
+/// A 0-based line and column, written as `LINE:COLUMN` starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl FromStr for Position {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |n: &str| n.parse::<u32>().ok().and_then(|n| n.checked_sub(1));
+        s.split_once(':')
+            .and_then(|(line, column)| Some(Self { line: parse(line)?, column: parse(column)? }))
+            .ok_or_else(|| format!("expected `LINE:COLUMN` starting at 1, found `{s}`"))
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.column + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{Position, SourcemapCommand, sourcemap_command};
+
+    fn parse(args: &[&str]) -> SourcemapCommand {
+        sourcemap_command().run_inner(args).unwrap()
+    }
+
+    #[test]
+    fn explain() {
+        let SourcemapCommand::Explain { sourcemap, position } =
+            parse(&["explain", "out.js.map", "3:10"])
+        else {
+            panic!("expected explain");
+        };
+        assert_eq!(sourcemap, PathBuf::from("out.js.map"));
+        assert_eq!(position, Position { line: 2, column: 9 });
+        assert_eq!(position.to_string(), "3:10");
+
+        assert!(sourcemap_command().run_inner(&["explain", "out.js.map", "0:1"]).is_err());
+        assert!(sourcemap_command().run_inner(&["explain", "out.js.map", "3"]).is_err());
+    }
+
+    #[test]
+    fn compose() {
+        let SourcemapCommand::Compose { output, sourcemaps } =
+            parse(&["compose", "-o", "out.js.map", "a.js.map", "b.js.map"])
+        else {
+            panic!("expected compose");
+        };
+        assert_eq!(output, Some(PathBuf::from("out.js.map")));
+        assert_eq!(sourcemaps, [PathBuf::from("a.js.map"), PathBuf::from("b.js.map")]);
+
+        assert!(sourcemap_command().run_inner(&["compose"]).is_err());
+    }
+
+    #[test]
+    fn validate() {
+        let SourcemapCommand::Validate { generated, sourcemap } =
+            parse(&["validate", "--generated", "out.js", "out.js.map"])
+        else {
+            panic!("expected validate");
+        };
+        assert_eq!(generated, Some(PathBuf::from("out.js")));
+        assert_eq!(sourcemap, PathBuf::from("out.js.map"));
+    }
+}
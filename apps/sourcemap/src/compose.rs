@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use oxc_sourcemap::{SourceMap, Token};
+use rustc_hash::FxHashMap;
+
+/// Compose the source maps of consecutive build steps, from the first step to the last, into a
+/// source map from the output of the last step to the sources of the first step.
+///
+/// Mappings of a step which do not map to an original location of the previous step are dropped.
+pub fn compose(sourcemaps: &[SourceMap]) -> Option<SourceMap> {
+    let (first, rest) = sourcemaps.split_first()?;
+    let mut composed = first.clone();
+    for sourcemap in rest {
+        composed = compose_two(&composed, sourcemap);
+    }
+    Some(composed)
+}
+
+/// Compose the source map `first` of a build step with the source map `second` of the step
+/// which transformed its output.
+fn compose_two(first: &SourceMap, second: &SourceMap) -> SourceMap {
+    let lookup_table = first.generate_lookup_table();
+    let mut names = Vec::<Arc<str>>::new();
+    let mut name_ids = FxHashMap::<Arc<str>, u32>::default();
+    let mut add_name = |name: &Arc<str>| {
+        *name_ids.entry(Arc::clone(name)).or_insert_with(|| {
+            names.push(Arc::clone(name));
+            u32::try_from(names.len() - 1).unwrap_or(u32::MAX)
+        })
+    };
+
+    let mut tokens = vec![];
+    for token in second.get_source_view_tokens() {
+        let Some(original) = first.lookup_source_view_token(
+            &lookup_table,
+            token.get_src_line(),
+            token.get_src_col(),
+        ) else {
+            continue;
+        };
+        let Some(source_id) = original.get_source_id() else { continue };
+        // The name of the last step is the name in the generated code.
+        let name_id = token.get_name().or_else(|| original.get_name()).map(&mut add_name);
+        tokens.push(Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        ));
+    }
+
+    SourceMap::new(
+        second.get_file().cloned(),
+        names,
+        first.get_source_root().map(ToString::to_string),
+        first.get_sources().cloned().collect(),
+        first.get_source_contents().map(|content| content.map(Arc::clone)).collect(),
+        tokens,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_sourcemap::SourceMapBuilder;
+
+    use super::compose;
+    use crate::{command::Position, explain::explain};
+
+    #[test]
+    fn compose_steps() {
+        // `input.ts` is compiled to `const foo = 1;\nfoo;`
+        let mut builder = SourceMapBuilder::default();
+        let source = builder.add_source_and_content("input.ts", "const foo: number = 1;\nfoo;");
+        builder.add_token(0, 0, 0, 0, Some(source), None);
+        builder.add_token(0, 6, 0, 6, Some(source), None);
+        builder.add_token(0, 12, 0, 20, Some(source), None);
+        builder.add_token(1, 0, 1, 0, Some(source), None);
+        let compiled = builder.into_sourcemap();
+
+        // which is minified to `let a=1;a;`
+        let mut builder = SourceMapBuilder::default();
+        let source = builder.add_source_and_content("compiled.js", "const foo = 1;\nfoo;");
+        let name = builder.add_name("foo");
+        builder.set_file("minified.js");
+        builder.add_token(0, 0, 0, 0, Some(source), None);
+        builder.add_token(0, 4, 0, 6, Some(source), Some(name));
+        builder.add_token(0, 6, 0, 12, Some(source), None);
+        builder.add_token(0, 8, 1, 0, Some(source), Some(name));
+        // Not mapped by the first step
+        builder.add_token(0, 9, 5, 0, Some(source), None);
+        let minified = builder.into_sourcemap();
+
+        let composed = compose(&[compiled, minified]).unwrap();
+        assert_eq!(composed.get_file().map(AsRef::as_ref), Some("minified.js"));
+        assert_eq!(composed.get_sources().map(AsRef::as_ref).collect::<Vec<_>>(), ["input.ts"]);
+        assert_eq!(
+            composed
+                .get_tokens()
+                .map(|token| (
+                    token.get_dst_line(),
+                    token.get_dst_col(),
+                    token.get_src_line(),
+                    token.get_src_col()
+                ))
+                .collect::<Vec<_>>(),
+            [(0, 0, 0, 0), (0, 4, 0, 6), (0, 6, 0, 20), (0, 8, 1, 0)]
+        );
+        assert_eq!(
+            explain(&composed, Position { line: 0, column: 6 }).unwrap(),
+            "input.ts:1:21\n1 | const foo: number = 1;\n  |                     ^\n"
+        );
+
+        assert!(compose(&[]).is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use oxc_sourcemap::SourceMap;
+
+use crate::{command::Position, utf16_column_to_char_index};
+
+/// Describe the original location which `position` of the generated file maps to.
+///
+/// The closest mapping at or before `position` on the same line is used. The original line is
+/// printed with a marker when the source map contains the content of the source.
+pub fn explain(sourcemap: &SourceMap, position: Position) -> Option<String> {
+    let lookup_table = sourcemap.generate_lookup_table();
+    let token =
+        sourcemap.lookup_source_view_token(&lookup_table, position.line, position.column)?;
+    let source = token.get_source()?;
+    let original = Position { line: token.get_src_line(), column: token.get_src_col() };
+
+    let mut output = format!("{source}:{original}");
+    if let Some(name) = token.get_name() {
+        write!(output, " ({name})").unwrap();
+    }
+    output.push('\n');
+
+    let line = token
+        .get_source_content()
+        .and_then(|content| content.split('\n').nth(original.line as usize));
+    if let Some(line) = line {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let number = (original.line + 1).to_string();
+        let column = utf16_column_to_char_index(line, original.column);
+        writeln!(output, "{number} | {line}").unwrap();
+        writeln!(output, "{:width$} | {:column$}^", "", "", width = number.len()).unwrap();
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_sourcemap::SourceMapBuilder;
+
+    use super::explain;
+    use crate::command::Position;
+
+    #[test]
+    fn explain_position() {
+        // `const foo = 1;\nfoo;` from `input.ts`, minified to `let a=1;a;`
+        let mut builder = SourceMapBuilder::default();
+        let source = builder.add_source_and_content("input.ts", "const foo = 1;\nfoo;");
+        let name = builder.add_name("foo");
+        builder.add_token(0, 0, 0, 0, Some(source), None);
+        builder.add_token(0, 4, 0, 6, Some(source), Some(name));
+        builder.add_token(0, 6, 0, 12, Some(source), None);
+        builder.add_token(0, 8, 1, 0, Some(source), Some(name));
+        let sourcemap = builder.into_sourcemap();
+
+        assert_eq!(
+            explain(&sourcemap, Position { line: 0, column: 4 }).unwrap(),
+            "input.ts:1:7 (foo)\n1 | const foo = 1;\n  |       ^\n"
+        );
+        assert_eq!(
+            explain(&sourcemap, Position { line: 0, column: 9 }).unwrap(),
+            "input.ts:2:1 (foo)\n2 | foo;\n  | ^\n"
+        );
+        assert_eq!(explain(&sourcemap, Position { line: 1, column: 0 }), None);
+    }
+}
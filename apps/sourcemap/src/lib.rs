@@ -0,0 +1,102 @@
+//! A command line utility to validate, compose and explain source maps.
+
+mod command;
+mod compose;
+mod explain;
+mod validate;
+
+use std::{fmt::Write as _, fs, io::Write, path::Path, process::ExitCode};
+
+use oxc_sourcemap::SourceMap;
+
+pub use crate::{
+    command::{Position, SourcemapCommand, sourcemap_command},
+    compose::compose,
+    explain::explain,
+    validate::validate,
+};
+
+/// Run `command`, writing its output to `stdout`.
+///
+/// # Panics
+///
+/// * When writing to `stdout` fails
+pub fn run(command: SourcemapCommand, stdout: &mut dyn Write) -> ExitCode {
+    let (output, succeeded) = match execute(command) {
+        Ok(output) => (output, true),
+        Err(output) => (output, false),
+    };
+    stdout.write_all(output.as_bytes()).unwrap();
+    stdout.flush().unwrap();
+    if succeeded { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+fn execute(command: SourcemapCommand) -> Result<String, String> {
+    match command {
+        SourcemapCommand::Explain { sourcemap: path, position } => {
+            let sourcemap = read_sourcemap(&path)?;
+            explain(&sourcemap, position)
+                .ok_or_else(|| format!("{position} of {} is not mapped.\n", path.display()))
+        }
+        SourcemapCommand::Compose { output, sourcemaps } => {
+            let sourcemaps = sourcemaps
+                .iter()
+                .map(|path| read_sourcemap(path))
+                .collect::<Result<Vec<_>, _>>()?;
+            let composed = compose(&sourcemaps)
+                .ok_or_else(|| "At least one source map is required.\n".to_string())?
+                .to_json_string();
+            match output {
+                Some(path) => fs::write(&path, composed)
+                    .map(|()| format!("Composed source map written to {}.\n", path.display()))
+                    .map_err(|err| format!("Failed to write {}: {err}\n", path.display())),
+                None => Ok(composed + "\n"),
+            }
+        }
+        SourcemapCommand::Validate { generated, sourcemap: path } => {
+            let sourcemap = read_sourcemap(&path)?;
+            let generated = generated
+                .map(|path| {
+                    fs::read_to_string(&path)
+                        .map_err(|err| format!("Failed to read {}: {err}\n", path.display()))
+                })
+                .transpose()?;
+            let problems = validate(&sourcemap, generated.as_deref());
+            if problems.is_empty() {
+                let mappings = sourcemap.get_tokens().count();
+                let sources = sourcemap.get_sources().count();
+                return Ok(format!(
+                    "{} is valid, with {mappings} mappings from {sources} sources.\n",
+                    path.display()
+                ));
+            }
+            let mut output = problems.join("\n");
+            let s = if problems.len() == 1 { "" } else { "s" };
+            write!(output, "\n\n{} problem{s} in {}.\n", problems.len(), path.display()).unwrap();
+            Err(output)
+        }
+    }
+}
+
+fn read_sourcemap(path: &Path) -> Result<SourceMap, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}\n", path.display()))?;
+    SourceMap::from_json_string(&json)
+        .map_err(|err| format!("Invalid source map {}: {err}\n", path.display()))
+}
+
+/// Length of `line` in UTF-16 code units, the unit of the columns of source maps.
+fn utf16_len(line: &str) -> u32 {
+    u32::try_from(line.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+/// Number of characters of `line` before the UTF-16 `column`.
+fn utf16_column_to_char_index(line: &str, column: u32) -> usize {
+    let mut utf16_len = 0;
+    line.chars()
+        .take_while(|c| {
+            utf16_len += c.len_utf16();
+            utf16_len <= column as usize
+        })
+        .count()
+}
@@ -0,0 +1,8 @@
+use std::{io, process::ExitCode};
+
+use oxc_sourcemap_cli::{run, sourcemap_command};
+
+fn main() -> ExitCode {
+    let command = sourcemap_command().run();
+    run(command, &mut io::stdout().lock())
+}
@@ -0,0 +1,102 @@
+use oxc_sourcemap::SourceMap;
+
+use crate::{command::Position, utf16_len};
+
+/// Check that the mappings of `sourcemap` are sorted, and point into the contents of their
+/// sources, if the source map contains them, and into the `generated` file.
+///
+/// Returns the problems which were found.
+pub fn validate(sourcemap: &SourceMap, generated: Option<&str>) -> Vec<String> {
+    let generated_lines = generated.map(lines);
+    let source_lines = sourcemap
+        .get_source_contents()
+        .map(|content| content.map(|c| lines(c)))
+        .collect::<Vec<_>>();
+
+    let mut problems = vec![];
+    let mut previous = None;
+    for token in sourcemap.get_source_view_tokens() {
+        let generated_position =
+            Position { line: token.get_dst_line(), column: token.get_dst_col() };
+        let mapping = format!("The mapping at {generated_position}");
+
+        let position = (generated_position.line, generated_position.column);
+        if previous.is_some_and(|previous| position < previous) {
+            problems.push(format!("{mapping} is not sorted after the previous mapping"));
+        }
+        previous = Some(position);
+
+        if let Some(lines) = &generated_lines {
+            if !contains(lines, generated_position) {
+                problems.push(format!("{mapping} is outside of the generated file"));
+            }
+        }
+
+        let (Some(source_id), Some(source)) = (token.get_source_id(), token.get_source()) else {
+            continue;
+        };
+        let original = Position { line: token.get_src_line(), column: token.get_src_col() };
+        let lines = source_lines.get(source_id as usize).and_then(Option::as_ref);
+        if lines.is_some_and(|lines| !contains(lines, original)) {
+            problems
+                .push(format!("{mapping} points to {source}:{original}, outside of the source"));
+        }
+    }
+    problems
+}
+
+fn lines(text: &str) -> Vec<&str> {
+    text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).collect()
+}
+
+/// Whether `position` is in `lines`, or right after the end of a line.
+fn contains(lines: &[&str], position: Position) -> bool {
+    lines.get(position.line as usize).is_some_and(|line| position.column <= utf16_len(line))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_sourcemap::{SourceMap, SourceMapBuilder, Token};
+
+    use super::validate;
+
+    #[test]
+    fn valid() {
+        let mut builder = SourceMapBuilder::default();
+        let source = builder.add_source_and_content("input.ts", "const foo = 1;\nfoo;");
+        builder.add_token(0, 0, 0, 0, Some(source), None);
+        builder.add_token(0, 8, 1, 4, Some(source), None);
+        builder.add_token(1, 0, 1, 0, None, None);
+        let sourcemap = builder.into_sourcemap();
+
+        assert!(validate(&sourcemap, None).is_empty());
+        assert!(validate(&sourcemap, Some("let a=1;a;\n")).is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        let sourcemap = SourceMap::new(
+            None,
+            vec![],
+            None,
+            vec!["input.ts".into(), "other.ts".into()],
+            vec![Some("const foo = 1;\nfoo;".into()), None],
+            vec![
+                Token::new(0, 4, 0, 20, Some(0), None),
+                Token::new(0, 2, 5, 0, Some(0), None),
+                Token::new(0, 3, 5, 0, Some(1), None),
+                Token::new(2, 0, 0, 0, Some(0), None),
+            ],
+            None,
+        );
+        assert_eq!(
+            validate(&sourcemap, Some("let a=1;a;")),
+            [
+                "The mapping at 1:5 points to input.ts:1:21, outside of the source",
+                "The mapping at 1:3 is not sorted after the previous mapping",
+                "The mapping at 1:3 points to input.ts:6:1, outside of the source",
+                "The mapping at 3:1 is outside of the generated file",
+            ]
+        );
+    }
+}
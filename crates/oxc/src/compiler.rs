@@ -3,7 +3,7 @@ use std::{mem, ops::ControlFlow, path::Path};
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
 use oxc_mangler::{MangleOptions, Mangler};
 use oxc_minifier::{CompressOptions, Compressor};
@@ -158,9 +158,14 @@ pub trait CompilerInterface {
             let mut transformer_return =
                 self.transform(options, &allocator, &mut program, source_path, scoping);
 
+            // Warnings are reported alongside the transformed code, only errors stop the pipeline.
             if !transformer_return.errors.is_empty() {
-                self.handle_errors(transformer_return.errors);
-                return;
+                let errors = mem::take(&mut transformer_return.errors);
+                let has_error = errors.iter().any(|error| error.severity == Severity::Error);
+                self.handle_errors(errors);
+                if has_error {
+                    return;
+                }
             }
 
             if self.after_transform(&mut program, &mut transformer_return).is_break() {
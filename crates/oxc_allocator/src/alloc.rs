@@ -29,6 +29,14 @@ pub trait Alloc {
     /// Panics if reserving space for `layout` fails.
     fn alloc(&self, layout: Layout) -> NonNull<u8>;
 
+    /// Allocate space for an object with the given [`Layout`].
+    ///
+    /// Same as [`alloc`], but returns `None` if reserving space for `layout` fails,
+    /// instead of panicking.
+    ///
+    /// [`alloc`]: Alloc::alloc
+    fn try_alloc(&self, layout: Layout) -> Option<NonNull<u8>>;
+
     /// Deallocate the memory referenced by `ptr`.
     ///
     /// # SAFETY
@@ -56,6 +64,23 @@ pub trait Alloc {
     /// * `new_layout.size()` must be greater than or equal to `old_layout.size()`.
     unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> NonNull<u8>;
 
+    /// Grow an existing allocation to new [`Layout`].
+    ///
+    /// Same as [`grow`], but returns `None` if reserving space for `new_layout` fails,
+    /// instead of panicking / aborting. If `None` is returned, the old allocation is still valid.
+    ///
+    /// # SAFETY
+    ///
+    /// Same as [`grow`].
+    ///
+    /// [`grow`]: Alloc::grow
+    unsafe fn try_grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>>;
+
     /// Shrink an existing allocation to new [`Layout`].
     ///
     /// If the allocation cannot be shrunk in place, `new_layout.size()` bytes of data
@@ -83,7 +108,8 @@ pub trait Alloc {
 
 /// Implement [`Alloc`] for [`bumpalo::Bump`].
 ///
-/// All methods except `alloc` delegate to [`Bump`]'s impl of `allocator_api2`'s [`Allocator`] trait.
+/// All methods except `alloc` and `try_alloc` delegate to [`Bump`]'s impl of `allocator_api2`'s
+/// [`Allocator`] trait.
 impl Alloc for Bump {
     /// Allocate space for an object with the given [`Layout`].
     ///
@@ -98,6 +124,14 @@ impl Alloc for Bump {
         self.alloc_layout(layout)
     }
 
+    /// Allocate space for an object with the given [`Layout`].
+    ///
+    /// Returns `None` if reserving space for `layout` fails.
+    #[inline(always)]
+    fn try_alloc(&self, layout: Layout) -> Option<NonNull<u8>> {
+        self.try_alloc_layout(layout).ok()
+    }
+
     /// Deallocate the memory referenced by `ptr`.
     ///
     /// # SAFETY
@@ -141,6 +175,27 @@ impl Alloc for Bump {
         }
     }
 
+    /// Grow an existing allocation to new [`Layout`].
+    ///
+    /// Returns `None` if reserving space for `new_layout` fails.
+    ///
+    /// # SAFETY
+    ///
+    /// * `ptr` must denote a block of memory currently allocated via this allocator.
+    /// * `old_layout` must be the same [`Layout`] that block was originally allocated with.
+    /// * `new_layout.size()` must be greater than or equal to `old_layout.size()`.
+    #[inline(always)]
+    unsafe fn try_grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        // SAFETY: Safety requirements of `Allocator::grow` are the same as for this method
+        let res = unsafe { Allocator::grow(&self, ptr, old_layout, new_layout) };
+        res.ok().map(NonNull::cast::<u8>)
+    }
+
     /// Shrink an existing allocation to new [`Layout`].
     ///
     /// If the allocation cannot be shrunk in place, the `layout.new()` bytes of data
@@ -8,6 +8,12 @@ use bumpalo::Bump;
 
 use oxc_data_structures::assert_unchecked;
 
+use crate::AllocError;
+
+/// Size of a WebAssembly memory page. Linear memory can only grow in multiples of this size.
+#[cfg(target_arch = "wasm32")]
+const WASM_PAGE_SIZE: usize = 64 * 1024;
+
 /// A bump-allocated memory arena.
 ///
 /// # Anatomy of an Allocator
@@ -247,14 +253,70 @@ impl Allocator {
     /// Create a new [`Allocator`] with specified capacity.
     ///
     /// See [`Allocator`] docs for more information on efficient use of [`Allocator`].
+    ///
+    /// On `wasm32`, `capacity` is treated as a hint. If linear memory cannot grow enough to reserve
+    /// the requested capacity, the initial chunk is repeatedly halved until it fits (but no smaller
+    /// than a single WebAssembly page). The allocator grows later as required, so a huge estimate
+    /// for a huge input does not abort before parsing has even started.
+    ///
+    /// # Panics
+    /// Panics if reserving the initial chunk fails.
     //
     // `#[inline(always)]` because just delegates to `bumpalo` method
+    #[cfg(not(target_arch = "wasm32"))]
     #[expect(clippy::inline_always)]
     #[inline(always)]
     pub fn with_capacity(capacity: usize) -> Self {
         Self { bump: Bump::with_capacity(capacity) }
     }
 
+    /// Create a new [`Allocator`] with specified capacity.
+    ///
+    /// See [`Allocator`] docs for more information on efficient use of [`Allocator`].
+    ///
+    /// On `wasm32`, `capacity` is treated as a hint. If linear memory cannot grow enough to reserve
+    /// the requested capacity, the initial chunk is repeatedly halved until it fits (but no smaller
+    /// than a single WebAssembly page). The allocator grows later as required, so a huge estimate
+    /// for a huge input does not abort before parsing has even started.
+    ///
+    /// # Panics
+    /// Panics if reserving the initial chunk fails.
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut capacity = capacity;
+        loop {
+            match Self::try_with_capacity(capacity) {
+                Ok(allocator) => return allocator,
+                Err(_) if capacity > WASM_PAGE_SIZE => capacity /= 2,
+                Err(_) => panic!("failed to reserve initial chunk for `Allocator`"),
+            }
+        }
+    }
+
+    /// Create a new [`Allocator`] with specified capacity.
+    ///
+    /// Same as [`with_capacity`], but returns an error if reserving `capacity` bytes fails,
+    /// instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving the initial chunk fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_allocator::Allocator;
+    ///
+    /// let allocator = Allocator::try_with_capacity(64 * 1024).unwrap();
+    /// assert!(allocator.capacity() >= 64 * 1024);
+    /// ```
+    ///
+    /// [`with_capacity`]: Allocator::with_capacity
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        match Bump::try_with_capacity(capacity) {
+            Ok(bump) => Ok(Self { bump }),
+            Err(_) => Err(AllocError::AllocErr),
+        }
+    }
+
     /// Allocate an object in this [`Allocator`] and return an exclusive reference to it.
     ///
     /// # Panics
@@ -279,6 +341,30 @@ impl Allocator {
         self.bump.alloc(val)
     }
 
+    /// Allocate an object in this [`Allocator`] and return an exclusive reference to it.
+    ///
+    /// Same as [`alloc`], but returns an error if reserving space for `T` fails, instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving space for `T` fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_allocator::Allocator;
+    ///
+    /// let allocator = Allocator::default();
+    /// let x = allocator.try_alloc([1u8; 20]).unwrap();
+    /// assert_eq!(x, &[1u8; 20]);
+    /// ```
+    ///
+    /// [`alloc`]: Allocator::alloc
+    #[inline]
+    pub fn try_alloc<T>(&self, val: T) -> Result<&mut T, AllocError> {
+        const { assert!(!std::mem::needs_drop::<T>(), "Cannot allocate Drop type in arena") };
+
+        self.bump.try_alloc(val).map_err(|_| AllocError::AllocErr)
+    }
+
     /// Copy a string slice into this [`Allocator`] and return a reference to it.
     ///
     /// # Panics
@@ -300,6 +386,23 @@ impl Allocator {
         self.bump.alloc_str(src)
     }
 
+    /// Copy a string slice into this [`Allocator`] and return a reference to it.
+    ///
+    /// Same as [`alloc_str`], but returns an error if reserving space for the string fails,
+    /// instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving space for the string fails.
+    ///
+    /// [`alloc_str`]: Allocator::alloc_str
+    #[inline]
+    pub fn try_alloc_str<'alloc>(&'alloc self, src: &str) -> Result<&'alloc str, AllocError> {
+        match self.bump.try_alloc_str(src) {
+            Ok(s) => Ok(s),
+            Err(_) => Err(AllocError::AllocErr),
+        }
+    }
+
     /// `Copy` a slice into this `Bump` and return an exclusive reference to the copy.
     ///
     /// # Panics
@@ -320,6 +423,20 @@ impl Allocator {
         self.bump.alloc_slice_copy(src)
     }
 
+    /// `Copy` a slice into this `Bump` and return an exclusive reference to the copy.
+    ///
+    /// Same as [`alloc_slice_copy`], but returns an error if reserving space for the slice fails,
+    /// instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving space for the slice fails.
+    ///
+    /// [`alloc_slice_copy`]: Allocator::alloc_slice_copy
+    #[inline]
+    pub fn try_alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Result<&mut [T], AllocError> {
+        self.bump.try_alloc_slice_copy(src).map_err(|_| AllocError::AllocErr)
+    }
+
     /// Allocate space for an object with the given [`Layout`].
     ///
     /// The returned pointer points at uninitialized memory, and should be initialized with
@@ -332,6 +449,19 @@ impl Allocator {
         self.bump.alloc_layout(layout)
     }
 
+    /// Allocate space for an object with the given [`Layout`].
+    ///
+    /// Same as [`alloc_layout`], but returns an error if reserving space matching `layout` fails,
+    /// instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving space matching `layout` fails.
+    ///
+    /// [`alloc_layout`]: Allocator::alloc_layout
+    pub fn try_alloc_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.bump.try_alloc_layout(layout).map_err(|_| AllocError::AllocErr)
+    }
+
     /// Create new `&str` from a fixed-size array of `&str`s concatenated together,
     /// allocated in the given `allocator`.
     ///
@@ -571,6 +701,51 @@ impl Allocator {
         bytes
     }
 
+    /// Get the limit on the total capacity of this [`Allocator`], in bytes, if one has been set.
+    ///
+    /// See [`set_allocation_limit`] for details.
+    ///
+    /// [`set_allocation_limit`]: Allocator::set_allocation_limit
+    //
+    // `#[inline(always)]` because it just delegates to `bumpalo`
+    #[expect(clippy::inline_always)]
+    #[inline(always)]
+    pub fn allocation_limit(&self) -> Option<usize> {
+        self.bump.allocation_limit()
+    }
+
+    /// Set a limit on the total capacity of this [`Allocator`], in bytes.
+    ///
+    /// Once the limit is reached, the allocator will not create any more chunks. Fallible methods
+    /// (e.g. [`try_alloc`]) return an error, and infallible methods (e.g. [`alloc`]) panic.
+    ///
+    /// This is useful for embedders with constrained memory (e.g. WebAssembly in a browser) which
+    /// want to bail out gracefully on huge inputs, rather than exhausting all available memory.
+    ///
+    /// Pass `None` to remove the limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_allocator::Allocator;
+    ///
+    /// let allocator = Allocator::new();
+    /// allocator.set_allocation_limit(Some(0));
+    /// assert!(allocator.try_alloc(123u64).is_err());
+    ///
+    /// allocator.set_allocation_limit(None);
+    /// assert!(allocator.try_alloc(123u64).is_ok());
+    /// ```
+    ///
+    /// [`alloc`]: Allocator::alloc
+    /// [`try_alloc`]: Allocator::try_alloc
+    //
+    // `#[inline(always)]` because it just delegates to `bumpalo`
+    #[expect(clippy::inline_always)]
+    #[inline(always)]
+    pub fn set_allocation_limit(&self, limit: Option<usize>) {
+        self.bump.set_allocation_limit(limit);
+    }
+
     /// Get inner [`bumpalo::Bump`].
     ///
     /// This method is not public. We don't want to expose `Bump` to user.
@@ -603,6 +778,8 @@ unsafe impl Sync for Allocator {}
 
 #[cfg(test)]
 mod test {
+    use std::alloc::Layout;
+
     use super::Allocator;
 
     #[test]
@@ -617,6 +794,22 @@ mod test {
         allocator.reset();
     }
 
+    #[test]
+    fn test_try_api() {
+        let allocator = Allocator::try_with_capacity(1024).unwrap();
+        assert_eq!(allocator.try_alloc([123; 10]).unwrap(), &[123; 10]);
+        assert_eq!(allocator.try_alloc_str("hello").unwrap(), "hello");
+        assert_eq!(allocator.try_alloc_slice_copy(&[1, 2, 3]).unwrap(), &[1, 2, 3]);
+
+        allocator.set_allocation_limit(Some(allocator.capacity()));
+        assert_eq!(allocator.allocation_limit(), Some(allocator.capacity()));
+        assert!(allocator.try_alloc([0u8; 4096]).is_err());
+        assert!(allocator.try_alloc_str(&"x".repeat(4096)).is_err());
+        assert!(allocator.try_alloc_layout(Layout::new::<[u64; 1024]>()).is_err());
+        // Small allocations which fit in the existing chunk still succeed
+        assert_eq!(allocator.try_alloc(1u8).unwrap(), &1);
+    }
+
     #[test]
     fn string_from_array_len_1() {
         let allocator = Allocator::default();
@@ -16,7 +16,7 @@ use oxc_estree::{ESTree, Serializer as ESTreeSerializer};
 #[cfg(any(feature = "serialize", test))]
 use serde::{Serialize, Serializer as SerdeSerializer};
 
-use crate::Allocator;
+use crate::{AllocError, Allocator};
 
 /// A `Box` without [`Drop`], which stores its data in the arena allocator.
 ///
@@ -32,6 +32,11 @@ use crate::Allocator;
 /// with a [`Drop`] type.
 pub struct Box<'alloc, T: ?Sized>(NonNull<T>, PhantomData<(&'alloc (), T)>);
 
+/// SAFETY: `Box` has exclusive access to its contents, like `&mut T`, so it's `Send` if `T` is.
+unsafe impl<T: ?Sized + Send> Send for Box<'_, T> {}
+/// SAFETY: `Box` has exclusive access to its contents, like `&mut T`, so it's `Sync` if `T` is.
+unsafe impl<T: ?Sized + Sync> Sync for Box<'_, T> {}
+
 impl<T: ?Sized> Box<'_, T> {
     /// Const assertion that `T` is not `Drop`.
     /// Must be referenced in all methods which create a `Box`.
@@ -61,6 +66,28 @@ impl<T> Box<'_, T> {
         Self(NonNull::from(allocator.alloc(value)), PhantomData)
     }
 
+    /// Put a `value` into a memory arena and get back a [`Box`] with ownership
+    /// to the allocation.
+    ///
+    /// Same as [`Box::new_in`], but returns an error if the allocation fails, instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if reserving space for `value` fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_allocator::{Allocator, Box};
+    ///
+    /// let arena = Allocator::default();
+    /// let in_arena: Box<i32> = Box::try_new_in(5, &arena).unwrap();
+    /// ```
+    #[inline]
+    pub fn try_new_in(value: T, allocator: &Allocator) -> Result<Self, AllocError> {
+        const { Self::ASSERT_T_IS_NOT_DROP };
+
+        Ok(Self(NonNull::from(allocator.try_alloc(value)?), PhantomData))
+    }
+
     /// Create a fake [`Box`] with a dangling pointer.
     ///
     /// # SAFETY
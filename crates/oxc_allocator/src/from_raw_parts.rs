@@ -1,6 +1,8 @@
 //! Define additional methods, used only by raw transfer:
 //!
 //! * [`Allocator::from_raw_parts`]
+//! * [`Allocator::alloc_scope`]
+//! * [`Allocator::free_capacity`]
 //! * [`Allocator::alloc_bytes_start`]
 //! * [`Allocator::data_ptr`]
 //! * [`Allocator::set_data_ptr`]
@@ -9,7 +11,9 @@
 use std::{
     alloc::Layout,
     cell::Cell,
+    marker::PhantomData,
     mem::ManuallyDrop,
+    ops::Deref,
     ptr::{self, NonNull},
 };
 
@@ -126,6 +130,45 @@ impl Allocator {
         Self::from_bump(bump)
     }
 
+    /// Reserve a contiguous region of `bytes` bytes in this [`Allocator`]'s arena up front,
+    /// and return a sub-allocator which allocates only within that region.
+    ///
+    /// Returns `None` if the region cannot be reserved. For a fixed-size allocator created with
+    /// [`Allocator::from_raw_parts`], this means there is insufficient free capacity remaining.
+    /// This allows the caller to fail fast, before doing any work, rather than panicking
+    /// part-way through when the allocator runs out of space.
+    ///
+    /// The returned [`AllocatorScope`] is guaranteed to be able to hold at least `bytes` bytes
+    /// (minus any padding required for alignment of the values allocated into it).
+    /// It cannot grow beyond that. If it runs out of capacity, allocating into it will panic.
+    ///
+    /// The reserved region is owned by this `Allocator`, so is freed when this `Allocator` is reset
+    /// or dropped. The `AllocatorScope` borrows this `Allocator` to ensure it cannot outlive it.
+    pub fn alloc_scope(&self, bytes: usize) -> Option<AllocatorScope<'_>> {
+        // Round up to multiple of `MIN_ALIGN`, and add space for the `ChunkFooter`
+        let size = bytes.checked_next_multiple_of(MIN_ALIGN)?.checked_add(CHUNK_FOOTER_SIZE)?;
+        let layout = Layout::from_size_align(size, MIN_ALIGN).ok()?;
+        let ptr = self.bump().try_alloc_layout(layout).ok()?;
+
+        // SAFETY: `ptr` is aligned on `MIN_ALIGN` and is the start of an allocation of `size` bytes.
+        // `size` is a multiple of `MIN_ALIGN` and is at least `CHUNK_FOOTER_SIZE`.
+        let allocator = unsafe { Self::from_raw_parts(ptr, size) };
+        // Wrap in `ManuallyDrop`, because the memory is owned by `self`, so must not be freed
+        // when the `AllocatorScope` is dropped
+        Some(AllocatorScope { allocator: ManuallyDrop::new(allocator), _marker: PhantomData })
+    }
+
+    /// Get number of bytes which can be allocated in this [`Allocator`]'s current chunk,
+    /// before any padding for alignment.
+    ///
+    /// For a fixed-size allocator created with [`Allocator::from_raw_parts`], this is the space
+    /// remaining before it runs out of capacity.
+    pub fn free_capacity(&self) -> usize {
+        // SAFETY: Cursor pointer is always `>=` data pointer.
+        // Both pointers are within same allocation, and derived from the same original pointer.
+        unsafe { self.cursor_ptr().offset_from_usize(self.data_ptr()) }
+    }
+
     /// Allocate space for `bytes` bytes at start of [`Allocator`]'s current chunk.
     ///
     /// Returns a pointer to the start of an uninitialized section of `bytes` bytes.
@@ -294,6 +337,23 @@ impl Allocator {
     }
 }
 
+/// Sub-allocator over a region of an [`Allocator`]'s arena, created by [`Allocator::alloc_scope`].
+///
+/// Dereferences to an [`Allocator`] which allocates only within the reserved region, and cannot grow.
+pub struct AllocatorScope<'a> {
+    allocator: ManuallyDrop<Allocator>,
+    _marker: PhantomData<&'a Allocator>,
+}
+
+impl Deref for AllocatorScope<'_> {
+    type Target = Allocator;
+
+    #[inline]
+    fn deref(&self) -> &Allocator {
+        &self.allocator
+    }
+}
+
 /// Allocator chunk footer.
 ///
 /// Copied exactly from `bumpalo` v3.19.0.
@@ -381,3 +441,39 @@ fn get_current_chunk_footer_field_offset() -> usize {
 const fn is_multiple_of(n: usize, divisor: usize) -> bool {
     n % divisor == 0
 }
+
+#[cfg(test)]
+mod test {
+    use super::Allocator;
+
+    #[test]
+    fn alloc_scope() {
+        let allocator = Allocator::default();
+        let scope = allocator.alloc_scope(64).unwrap();
+        let values = scope.alloc([1u64; 8]);
+        assert_eq!(values, &[1; 8]);
+
+        // Region for the scope is reserved in the parent allocator
+        assert!(allocator.used_bytes() >= 64);
+    }
+
+    #[test]
+    fn alloc_scope_insufficient_capacity() {
+        let mut bytes = vec![0u128; 64];
+        let size = bytes.len() * size_of::<u128>();
+        let ptr = std::ptr::NonNull::new(bytes.as_mut_ptr().cast::<u8>()).unwrap();
+        // SAFETY: `ptr` is aligned on 16 and is the start of an allocation of `size` bytes.
+        // `size` is a multiple of 16 and greater than `RAW_MIN_SIZE`.
+        let allocator =
+            std::mem::ManuallyDrop::new(unsafe { Allocator::from_raw_parts(ptr, size) });
+
+        assert!(allocator.alloc_scope(size).is_none());
+        assert_eq!(allocator.free_capacity(), size - Allocator::RAW_MIN_SIZE);
+
+        let scope = allocator.alloc_scope(128).unwrap();
+        scope.alloc([0u8; 128]);
+        assert!(allocator.alloc_scope(size - 128).is_none());
+        // The scope's region includes a chunk footer
+        assert_eq!(allocator.free_capacity(), size - 2 * Allocator::RAW_MIN_SIZE - 128);
+    }
+}
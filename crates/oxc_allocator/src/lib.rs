@@ -10,13 +10,16 @@
 //! * [`String`]
 //! * [`HashMap`]
 //!
+//! [`SendAllocator`] bundles an [`Allocator`] with data allocated in it, e.g. an AST, so they can
+//! be moved between threads together.
+//!
 //! See [`Allocator`] docs for information on efficient use of [`Allocator`].
 //!
 //! ## Features
 //!
 //! * `serialize` - Enables serialization support for [`Box`] and [`Vec`] with `serde` and `oxc_estree`.
 //!
-//! * `from_raw_parts` - Adds [`Allocator::from_raw_parts`] method.
+//! * `from_raw_parts` - Adds [`Allocator::from_raw_parts`] and [`Allocator::alloc_scope`] methods.
 //!   Usage of this feature is not advisable, and it will be removed as soon as we're able to.
 //!
 //! * `fixed_size` - Makes [`AllocatorPool`] create large fixed-size allocators, instead of
@@ -40,6 +43,7 @@ mod convert;
 #[cfg(feature = "from_raw_parts")]
 mod from_raw_parts;
 pub mod hash_map;
+mod send_allocator;
 mod string_builder;
 mod take_in;
 mod vec;
@@ -51,10 +55,14 @@ pub use allocator::Allocator;
 pub use boxed::Box;
 pub use clone_in::CloneIn;
 pub use convert::{FromIn, IntoIn};
+#[cfg(feature = "from_raw_parts")]
+pub use from_raw_parts::AllocatorScope;
 pub use hash_map::HashMap;
+pub use send_allocator::{AllocatorData, SendAllocator};
 pub use string_builder::StringBuilder;
 pub use take_in::{Dummy, TakeIn};
 pub use vec::Vec;
+pub use vec2::AllocError;
 
 // Fixed size allocators are only supported on 64-bit little-endian platforms at present
 
@@ -100,7 +108,7 @@ use pool_fixed_size::FixedSizeAllocatorMetadata;
 ))]
 pub use pool_fixed_size::free_fixed_size_allocator;
 
-pub use pool::{AllocatorGuard, AllocatorPool};
+pub use pool::{AllocatorGuard, AllocatorPool, AllocatorPoolStats};
 
 // Dummy implementations of interfaces from `pool_fixed_size`, just to stop clippy complaining.
 // Seems to be necessary due to feature unification.
@@ -1,4 +1,12 @@
-use std::{iter, mem::ManuallyDrop, ops::Deref, sync::Mutex};
+use std::{
+    iter,
+    mem::ManuallyDrop,
+    ops::Deref,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 use crate::Allocator;
 
@@ -8,13 +16,24 @@ use crate::Allocator;
 #[derive(Default)]
 pub struct AllocatorPool {
     allocators: Mutex<Vec<Allocator>>,
+    /// Number of `Allocator`s created by the pool
+    created: AtomicUsize,
+}
+
+/// Statistics of an [`AllocatorPool`], returned by [`AllocatorPool::stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocatorPoolStats {
+    /// Number of allocators in the pool, ready to be re-used.
+    pub idle: usize,
+    /// Number of allocators created by the pool since it was created.
+    pub created: usize,
 }
 
 impl AllocatorPool {
     /// Creates a new [`AllocatorPool`] pre-filled with the given number of default [`Allocator`] instances.
     pub fn new(size: usize) -> AllocatorPool {
         let allocators = iter::repeat_with(Allocator::new).take(size).collect();
-        AllocatorPool { allocators: Mutex::new(allocators) }
+        AllocatorPool { allocators: Mutex::new(allocators), created: AtomicUsize::new(size) }
     }
 
     /// Retrieves an [`Allocator`] from the pool, or creates a new one if the pool is empty.
@@ -29,11 +48,24 @@ impl AllocatorPool {
             let mut allocators = self.allocators.lock().unwrap();
             allocators.pop()
         };
-        let allocator = allocator.unwrap_or_else(Allocator::new);
+        let allocator = allocator.unwrap_or_else(|| {
+            self.created.fetch_add(1, Ordering::Relaxed);
+            Allocator::new()
+        });
 
         AllocatorGuard { allocator: ManuallyDrop::new(allocator), pool: self }
     }
 
+    /// Returns the number of idle allocators in the pool, and the number of allocators it has created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying mutex is poisoned.
+    pub fn stats(&self) -> AllocatorPoolStats {
+        let idle = self.allocators.lock().unwrap().len();
+        AllocatorPoolStats { idle, created: self.created.load(Ordering::Relaxed) }
+    }
+
     /// Add an [`Allocator`] to the pool.
     ///
     /// The `Allocator` should be empty, ready to be re-used.
@@ -30,6 +30,15 @@ pub struct AllocatorPool {
     next_id: AtomicU32,
 }
 
+/// Statistics of an [`AllocatorPool`], returned by [`AllocatorPool::stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocatorPoolStats {
+    /// Number of allocators in the pool, ready to be re-used.
+    pub idle: usize,
+    /// Number of allocators created by the pool since it was created.
+    pub created: usize,
+}
+
 impl AllocatorPool {
     /// Creates a new [`AllocatorPool`] with capacity for the given number of `FixedSizeAllocator` instances.
     pub fn new(size: usize) -> AllocatorPool {
@@ -64,6 +73,17 @@ impl AllocatorPool {
         AllocatorGuard { allocator: ManuallyDrop::new(allocator), pool: self }
     }
 
+    /// Returns the number of idle allocators in the pool, and the number of allocators it has created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying mutex is poisoned.
+    pub fn stats(&self) -> AllocatorPoolStats {
+        let idle = self.allocators.lock().unwrap().len();
+        // IDs are assigned sequentially from 0, so the next ID is the number of allocators created
+        AllocatorPoolStats { idle, created: self.next_id.load(Ordering::Relaxed) as usize }
+    }
+
     /// Add a [`FixedSizeAllocator`] to the pool.
     ///
     /// The `Allocator` should be empty, ready to be re-used.
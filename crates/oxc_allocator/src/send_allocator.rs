@@ -0,0 +1,240 @@
+//! [`SendAllocator`]: An [`Allocator`] bundled with data allocated in it, which can be moved
+//! between threads.
+
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ptr::{self, NonNull},
+};
+
+use crate::Allocator;
+
+/// Type of the data stored in a [`SendAllocator`], for any lifetime of the [`Allocator`].
+///
+/// Implement this trait on a marker type to name a type which borrows from an [`Allocator`],
+/// e.g. `Program<'a>`, without naming the lifetime:
+///
+/// ```ignore
+/// struct ProgramData;
+///
+/// impl AllocatorData for ProgramData {
+///     type Data<'a> = Program<'a>;
+/// }
+/// ```
+pub trait AllocatorData {
+    /// Type of the data, with the allocator borrowed for lifetime `'a`.
+    type Data<'a>;
+}
+
+/// No data. `SendAllocator<()>` is just an [`Allocator`].
+impl AllocatorData for () {
+    type Data<'a> = ();
+}
+
+/// An [`Allocator`] together with data allocated in it, e.g. an AST.
+///
+/// Data borrows from the `Allocator` it was allocated in, which makes it impossible to move
+/// the two to another thread together without `unsafe` code. A `SendAllocator` owns both,
+/// so it can be passed from one stage of a pipeline to the next, e.g. from a parser thread
+/// to a linter thread, and then to a codegen thread.
+///
+/// The data is only accessible within closures, which receive it with a lifetime which can not
+/// escape the closure. [`SendAllocator::map`] replaces the data with new data, e.g. a `Program`
+/// with the `Program` and its `Semantic`.
+///
+/// `SendAllocator` is [`Send`] when the data is `Send`, but not [`Sync`], because the closures
+/// can allocate into the `Allocator`.
+///
+/// # Example
+///
+/// ```
+/// use std::thread;
+///
+/// use oxc_allocator::{Allocator, AllocatorData, Box, SendAllocator, Vec};
+///
+/// struct Numbers;
+///
+/// impl AllocatorData for Numbers {
+///     type Data<'a> = Vec<'a, Box<'a, u32>>;
+/// }
+///
+/// let numbers = SendAllocator::<Numbers>::new(Allocator::default(), |allocator| {
+///     Vec::from_iter_in([1, 2, 3].map(|n| Box::new_in(n, allocator)), allocator)
+/// });
+/// let sum = thread::spawn(move || {
+///     numbers.with_data(|numbers, _| numbers.iter().map(|n| **n).sum::<u32>())
+/// })
+/// .join()
+/// .unwrap();
+/// assert_eq!(sum, 6);
+/// ```
+pub struct SendAllocator<D: AllocatorData> {
+    /// Data which borrows from `allocator`.
+    /// `'static` is a lie, the lifetime is the lifetime of `allocator`.
+    /// Must be dropped before `allocator`.
+    data: ManuallyDrop<D::Data<'static>>,
+    /// Boxed `Allocator`, so its address does not change when `SendAllocator` is moved.
+    /// Stored as a raw pointer, because moving a `Box` would assert exclusive access to the
+    /// `Allocator`, which `data` borrows.
+    allocator: NonNull<Allocator>,
+    /// Marker to make `SendAllocator` `!Sync`
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<D: AllocatorData> SendAllocator<D> {
+    /// Take ownership of `allocator`, and create data in it with `create`.
+    pub fn new<F>(allocator: Allocator, create: F) -> Self
+    where
+        F: for<'a> FnOnce(&'a Allocator) -> D::Data<'a>,
+    {
+        let allocator = NonNull::from(Box::leak(Box::new(allocator)));
+        // SAFETY: `allocator` is valid until `SendAllocator` is dropped, and we never create
+        // a `&mut` reference to it while `data` exists
+        let data = create(unsafe { allocator.as_ref() });
+        // SAFETY: `Data<'a>` and `Data<'static>` are the same type, apart from lifetimes.
+        // `data` can only be accessed with a lifetime which does not outlive `allocator`.
+        let data = unsafe { extend_lifetime::<D>(data) };
+        Self { data: ManuallyDrop::new(data), allocator, _not_sync: PhantomData }
+    }
+
+    /// Get the [`Allocator`].
+    pub fn allocator(&self) -> &Allocator {
+        // SAFETY: `allocator` is valid for as long as `self`
+        unsafe { self.allocator.as_ref() }
+    }
+
+    /// Access the data, and the [`Allocator`] it's allocated in.
+    pub fn with_data<'s, R, F>(&'s self, f: F) -> R
+    where
+        F: for<'a> FnOnce(&'s D::Data<'a>, &'a Allocator) -> R,
+    {
+        // SAFETY: The lifetime of the data is shortened to the lifetime of the `Allocator`.
+        // The closure is generic over `'a`, so it can't leak anything borrowing from the data.
+        let data = unsafe { &*ptr::from_ref::<D::Data<'static>>(&self.data).cast::<D::Data<'s>>() };
+        f(data, self.allocator())
+    }
+
+    /// Mutate the data, with the [`Allocator`] it's allocated in to allocate new data.
+    pub fn with_data_mut<'s, R, F>(&'s mut self, f: F) -> R
+    where
+        F: for<'a> FnOnce(&'s mut D::Data<'a>, &'a Allocator) -> R,
+    {
+        // SAFETY: `allocator` is valid for as long as `self`, and is never borrowed mutably
+        let allocator = unsafe { self.allocator.as_ref() };
+        // SAFETY: As in `with_data`. The closure can only store data in `Data<'a>` which is
+        // allocated in the `Allocator` or lives for `'static`, as it must work for any `'a`.
+        let data = unsafe {
+            &mut *ptr::from_mut::<D::Data<'static>>(&mut self.data).cast::<D::Data<'s>>()
+        };
+        f(data, allocator)
+    }
+
+    /// Replace the data with new data created from it, in the same [`Allocator`].
+    pub fn map<E, F>(self, f: F) -> SendAllocator<E>
+    where
+        E: AllocatorData,
+        F: for<'a> FnOnce(D::Data<'a>, &'a Allocator) -> E::Data<'a>,
+    {
+        let (data, allocator) = self.into_parts();
+        // SAFETY: `allocator` is valid until the returned `SendAllocator` is dropped
+        let new_data = f(data, unsafe { allocator.as_ref() });
+        // SAFETY: Same as in `new`
+        let new_data = unsafe { extend_lifetime::<E>(new_data) };
+        SendAllocator { data: ManuallyDrop::new(new_data), allocator, _not_sync: PhantomData }
+    }
+
+    /// Drop the data, and get back the [`Allocator`], e.g. to reset and re-use it.
+    pub fn into_allocator(self) -> Allocator {
+        let (data, allocator) = self.into_parts();
+        drop(data);
+        // SAFETY: `allocator` was created from a `Box` in `new`, and `data` which borrowed it
+        // has been dropped
+        *unsafe { Box::from_raw(allocator.as_ptr()) }
+    }
+
+    /// Take the data and the pointer to the [`Allocator`] out of `self`, without dropping them.
+    ///
+    /// Caller must ensure that the data is dropped before the `Allocator`.
+    fn into_parts(self) -> (D::Data<'static>, NonNull<Allocator>) {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again, and is not dropped
+        let data = unsafe { ManuallyDrop::take(&mut this.data) };
+        (data, this.allocator)
+    }
+}
+
+impl<D: AllocatorData> Drop for SendAllocator<D> {
+    fn drop(&mut self) {
+        // SAFETY: `data` is not used again after this. It must be dropped before the `Allocator`
+        // which it borrows from.
+        unsafe { ManuallyDrop::drop(&mut self.data) };
+        // SAFETY: `allocator` was created from a `Box` in `new`, and is not used again after this
+        drop(unsafe { Box::from_raw(self.allocator.as_ptr()) });
+    }
+}
+
+// SAFETY: `SendAllocator` owns both the `Allocator` and the data which borrows from it,
+// so nothing on the original thread can access either of them after it's moved.
+// Auto traits do not depend on lifetimes, so `Data<'static>: Send` means `Data<'a>: Send`.
+unsafe impl<D: AllocatorData> Send for SendAllocator<D> where D::Data<'static>: Send {}
+
+/// Extend the lifetime of `data` to `'static`.
+///
+/// # SAFETY
+/// Caller must ensure `data` is only accessed while the `Allocator` it borrows from is alive.
+unsafe fn extend_lifetime<D: AllocatorData>(data: D::Data<'_>) -> D::Data<'static> {
+    let data = ManuallyDrop::new(data);
+    // SAFETY: The two types only differ in lifetimes, so have the same layout.
+    // `data` is wrapped in `ManuallyDrop`, so it's not dropped twice.
+    unsafe { ptr::from_ref::<D::Data<'_>>(&data).cast::<D::Data<'static>>().read() }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::{AllocatorData, SendAllocator};
+    use crate::{Allocator, Vec};
+
+    struct Words;
+
+    impl AllocatorData for Words {
+        type Data<'a> = Vec<'a, &'a str>;
+    }
+
+    struct Sentence;
+
+    impl AllocatorData for Sentence {
+        type Data<'a> = &'a str;
+    }
+
+    #[test]
+    fn pipeline() {
+        let words = SendAllocator::<Words>::new(Allocator::default(), |allocator| {
+            Vec::from_iter_in(["hello", "world"].map(|word| allocator.alloc_str(word)), allocator)
+        });
+
+        let words = thread::spawn(move || {
+            let mut words = words;
+            words.with_data_mut(|words, allocator| {
+                words.push(allocator.alloc_str("again"));
+            });
+            words
+        })
+        .join()
+        .unwrap();
+
+        let sentence = thread::spawn(move || {
+            words.map::<Sentence, _>(|words, allocator| allocator.alloc_str(&words.join(" ")))
+        })
+        .join()
+        .unwrap();
+
+        sentence.with_data(|sentence, _| assert_eq!(*sentence, "hello world again"));
+
+        let mut allocator = sentence.into_allocator();
+        assert!(allocator.used_bytes() > 0);
+        allocator.reset();
+    }
+}
@@ -20,7 +20,7 @@ use serde::{Serialize, Serializer as SerdeSerializer};
 #[cfg(any(feature = "serialize", test))]
 use oxc_estree::{ConcatElement, ESTree, SequenceSerializer, Serializer as ESTreeSerializer};
 
-use crate::{Allocator, Box, vec2::Vec as InnerVecGeneric};
+use crate::{AllocError, Allocator, Box, vec2::Vec as InnerVecGeneric};
 
 type InnerVec<'a, T> = InnerVecGeneric<'a, T, Bump>;
 
@@ -123,6 +123,37 @@ impl<'alloc, T> Vec<'alloc, T> {
         Self(InnerVec::with_capacity_in(capacity, allocator.bump()))
     }
 
+    /// Constructs a new, empty `Vec<T>` with at least the specified capacity
+    /// with the provided allocator.
+    ///
+    /// Same as [`Vec::with_capacity_in`], but returns an error if the allocation fails,
+    /// instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `Err(AllocError)` if the capacity exceeds maximum, or reserving space fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_allocator::{AllocError, Allocator, Vec};
+    ///
+    /// let arena = Allocator::default();
+    ///
+    /// let vec = Vec::<u32>::try_with_capacity_in(10, &arena).unwrap();
+    /// assert!(vec.capacity() >= 10);
+    ///
+    /// let err = Vec::<u64>::try_with_capacity_in(usize::MAX, &arena).unwrap_err();
+    /// assert_eq!(err, AllocError::CapacityOverflow);
+    /// ```
+    #[inline(always)]
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        allocator: &'alloc Allocator,
+    ) -> Result<Self, AllocError> {
+        const { Self::ASSERT_T_IS_NOT_DROP };
+
+        InnerVec::try_with_capacity_in(capacity, allocator.bump()).map(Self)
+    }
+
     /// Create a new [`Vec`] whose elements are taken from an iterator and
     /// allocated in the given `allocator`.
     ///
@@ -276,7 +307,7 @@ impl<T: Debug> Debug for Vec<'_, T> {
 #[cfg(test)]
 mod test {
     use super::Vec;
-    use crate::Allocator;
+    use crate::{AllocError, Allocator};
 
     #[test]
     fn vec_with_capacity() {
@@ -285,6 +316,22 @@ mod test {
         assert!(v.is_empty());
     }
 
+    #[test]
+    fn vec_try_reserve() {
+        let allocator = Allocator::with_capacity(1024);
+        allocator.set_allocation_limit(Some(allocator.capacity()));
+
+        let mut v: Vec<u8> = Vec::try_with_capacity_in(16, &allocator).unwrap();
+        v.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(v.try_reserve(1 << 20), Err(AllocError::AllocErr));
+        assert_eq!(v.try_reserve_exact(1 << 20), Err(AllocError::AllocErr));
+        // Failed reservation leaves `Vec` untouched
+        assert_eq!(v.as_slice(), &[1, 2, 3]);
+        assert!(v.try_reserve(32).is_ok());
+
+        assert!(Vec::<u64>::try_with_capacity_in(1 << 20, &allocator).is_err());
+    }
+
     #[test]
     fn vec_debug() {
         let allocator = Allocator::default();
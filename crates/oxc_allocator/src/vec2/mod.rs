@@ -114,7 +114,8 @@ use oxc_data_structures::assert_unchecked;
 use crate::alloc::Alloc;
 
 mod raw_vec;
-use raw_vec::{AllocError, RawVec};
+pub use raw_vec::AllocError;
+use raw_vec::RawVec;
 
 unsafe fn arith_offset<T>(p: *const T, offset: isize) -> *const T {
     p.offset(offset)
@@ -501,6 +502,19 @@ impl<'a, T: 'a, A: Alloc> Vec<'a, T, A> {
         Vec { buf: RawVec::with_capacity_in(capacity, alloc) }
     }
 
+    /// The same as `with_capacity_in`, but returns on errors instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(AllocError)` if `capacity` is too large, or if the allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        alloc: &'a A,
+    ) -> Result<Vec<'a, T, A>, AllocError> {
+        Ok(Vec { buf: RawVec::try_with_capacity_in(capacity, alloc)? })
+    }
+
     /// Construct a new `Vec` from the given iterator's items.
     ///
     /// # Examples
@@ -30,13 +30,34 @@ use core::ptr::{self, NonNull};
 
 use crate::alloc::Alloc;
 
-/// Error type for fallible methods:
-/// [`RawVec::try_reserve`], [`RawVec::try_reserve_exact`].
+/// Error type for fallible allocation methods.
+///
+/// Returned by [`Allocator::try_alloc`] and friends, [`Box::try_new_in`],
+/// [`Vec::try_with_capacity_in`], and [`Vec`]'s `try_reserve` and `try_reserve_exact` methods.
+///
+/// [`Allocator::try_alloc`]: crate::Allocator::try_alloc
+/// [`Box::try_new_in`]: crate::Box::try_new_in
+/// [`Vec`]: crate::Vec
+/// [`Vec::try_with_capacity_in`]: crate::Vec::try_with_capacity_in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AllocError {
+    /// The memory allocator returned an error, or the allocation limit was reached.
     AllocErr,
+    /// The computed capacity exceeded the collection's maximum (usually `isize::MAX` bytes).
     CapacityOverflow,
 }
 
+impl core::fmt::Display for AllocError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AllocError::AllocErr => f.write_str("memory allocation failed"),
+            AllocError::CapacityOverflow => f.write_str("capacity overflow"),
+        }
+    }
+}
+
+impl std::error::Error for AllocError {}
+
 // use boxed::Box;
 
 /// A low-level utility for more ergonomically allocating, reallocating, and deallocating
@@ -114,6 +135,34 @@ impl<'a, T, A: Alloc> RawVec<'a, T, A> {
         }
     }
 
+    /// The same as `with_capacity_in`, but returns on errors instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(AllocError)` if `cap` is too large, or if the allocation fails.
+    pub fn try_with_capacity_in(cap: usize, alloc: &'a A) -> Result<Self, AllocError> {
+        let elem_size = mem::size_of::<T>();
+
+        let alloc_size = cap.checked_mul(elem_size).ok_or(AllocError::CapacityOverflow)?;
+        alloc_guard(alloc_size)?;
+
+        // handles ZSTs and `cap = 0` alike
+        let ptr = if alloc_size == 0 {
+            NonNull::<T>::dangling()
+        } else {
+            let align = mem::align_of::<T>();
+            let layout = Layout::from_size_align(alloc_size, align)
+                .map_err(|_| AllocError::CapacityOverflow)?;
+            alloc.try_alloc(layout).ok_or(AllocError::AllocErr)?.cast::<T>()
+        };
+
+        // `cap as u32` is safe because `alloc_guard` ensures that `cap`
+        // cannot exceed `u32::MAX`.
+        #[expect(clippy::cast_possible_truncation)]
+        let cap = cap as u32;
+        Ok(RawVec { ptr, alloc, cap, len: 0 })
+    }
+
     /// Reconstitutes a RawVec from a pointer, capacity, and allocator.
     ///
     /// # SAFETY
@@ -753,16 +802,16 @@ impl<T, A: Alloc> RawVec<'_, T, A> {
                     ptr: NonNull<T>,
                     old_layout: Layout,
                     new_layout: Layout,
-                ) -> NonNull<u8> {
-                    alloc.grow(ptr.cast(), old_layout, new_layout)
+                ) -> Option<NonNull<u8>> {
+                    alloc.try_grow(ptr.cast(), old_layout, new_layout)
                 }
                 debug_assert!(new_layout.align() == layout.align());
                 grow(self.alloc, self.ptr, layout, new_layout)
             },
-            None => self.alloc.alloc(new_layout),
+            None => self.alloc.try_alloc(new_layout),
         };
 
-        Ok(new_ptr)
+        new_ptr.ok_or(AllocError::AllocErr)
     }
 }
 
@@ -146,6 +146,10 @@ pub enum Expression<'a> {
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
     /// See [`V8IntrinsicExpression`] for AST node details.
     V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
+    /// See [`TopicReference`] for AST node details.
+    TopicReference(Box<'a, TopicReference>) = 40,
+    /// See [`PipelineExpression`] for AST node details.
+    PipelineExpression(Box<'a, PipelineExpression<'a>>) = 41,
 
     // `MemberExpression` variants added here by `inherit_variants!` macro
     @inherit MemberExpression
@@ -200,6 +204,8 @@ macro_rules! match_expression {
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
             | $ty::V8IntrinsicExpression(_)
+            | $ty::TopicReference(_)
+            | $ty::PipelineExpression(_)
     };
 }
 pub use match_expression;
@@ -2710,3 +2716,31 @@ pub struct V8IntrinsicExpression<'a> {
     pub name: IdentifierName<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
 }
+
+/// `%` in `value |> f(%)`
+///
+/// The topic reference of the [Hack-style pipeline proposal](https://github.com/tc39/proposal-pipeline-operator),
+/// which refers to the head of the closest [`PipelineExpression`] whose body contains it.
+///
+/// Only parsed with `ParseOptions::experimental_pipeline`.
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+pub struct TopicReference {
+    pub span: Span,
+}
+
+/// `value |> f(%)`
+///
+/// A pipe expression of the [Hack-style pipeline proposal](https://github.com/tc39/proposal-pipeline-operator),
+/// which evaluates `body` with each [`TopicReference`] `%` in it referring to the value of `head`.
+///
+/// Only parsed with `ParseOptions::experimental_pipeline`.
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+pub struct PipelineExpression<'a> {
+    pub span: Span,
+    pub head: Expression<'a>,
+    pub body: Expression<'a>,
+}
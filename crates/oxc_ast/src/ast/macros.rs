@@ -173,6 +173,10 @@ macro_rules! inherit_variants {
                 TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
                 /// Inherited from [`Expression`]
                 V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
+                /// Inherited from [`Expression`]
+                TopicReference(Box<'a, TopicReference>) = 40,
+                /// Inherited from [`Expression`]
+                PipelineExpression(Box<'a, PipelineExpression<'a>>) = 41,
 
                 // Inherited from `MemberExpression`
                 @inherit MemberExpression
@@ -231,6 +235,8 @@ macro_rules! inherit_variants {
                 TSNonNullExpression,
                 TSInstantiationExpression,
                 V8IntrinsicExpression,
+                TopicReference,
+                PipelineExpression,
                 ComputedMemberExpression,
                 StaticMemberExpression,
                 PrivateFieldExpression,
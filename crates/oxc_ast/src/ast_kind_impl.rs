@@ -182,6 +182,8 @@ impl<'a> AstKind<'a> {
             Expression::TSNonNullExpression(e) => Self::TSNonNullExpression(e),
             Expression::TSInstantiationExpression(e) => Self::TSInstantiationExpression(e),
             Expression::V8IntrinsicExpression(e) => Self::V8IntrinsicExpression(e),
+            Expression::TopicReference(e) => Self::TopicReference(e),
+            Expression::PipelineExpression(e) => Self::PipelineExpression(e),
         }
     }
 
@@ -493,6 +495,8 @@ impl AstKind<'_> {
             Self::TSInterfaceBody(_) => "TSInterfaceBody".into(),
             Self::TSIndexSignature(_) => "TSIndexSignature".into(),
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression".into(),
+            Self::TopicReference(_) => "TopicReference".into(),
+            Self::PipelineExpression(_) => "PipelineExpression".into(),
 
             Self::JSDocNullableType(_) => "JSDocNullableType".into(),
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType".into(),
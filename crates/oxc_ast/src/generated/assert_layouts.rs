@@ -854,6 +854,18 @@ const _: () = {
     assert!(offset_of!(V8IntrinsicExpression, name) == 8);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 32);
 
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 8);
+    assert!(align_of::<TopicReference>() == 8);
+    assert!(offset_of!(TopicReference, span) == 0);
+
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 40);
+    assert!(align_of::<PipelineExpression>() == 8);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, head) == 8);
+    assert!(offset_of!(PipelineExpression, body) == 24);
+
     // Padding: 7 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 8);
@@ -2449,6 +2461,18 @@ const _: () = {
     assert!(offset_of!(V8IntrinsicExpression, name) == 8);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 24);
 
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 8);
+    assert!(align_of::<TopicReference>() == 4);
+    assert!(offset_of!(TopicReference, span) == 0);
+
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 24);
+    assert!(align_of::<PipelineExpression>() == 4);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, head) == 8);
+    assert!(offset_of!(PipelineExpression, body) == 16);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 12);
     assert!(align_of::<BooleanLiteral>() == 4);
@@ -108,98 +108,100 @@ pub enum AstType {
     ExportAllDeclaration = 91,
     ExportSpecifier = 92,
     V8IntrinsicExpression = 93,
-    BooleanLiteral = 94,
-    NullLiteral = 95,
-    NumericLiteral = 96,
-    StringLiteral = 97,
-    BigIntLiteral = 98,
-    RegExpLiteral = 99,
-    JSXElement = 100,
-    JSXOpeningElement = 101,
-    JSXClosingElement = 102,
-    JSXFragment = 103,
-    JSXOpeningFragment = 104,
-    JSXClosingFragment = 105,
-    JSXNamespacedName = 106,
-    JSXMemberExpression = 107,
-    JSXExpressionContainer = 108,
-    JSXEmptyExpression = 109,
-    JSXAttribute = 110,
-    JSXSpreadAttribute = 111,
-    JSXIdentifier = 112,
-    JSXSpreadChild = 113,
-    JSXText = 114,
-    TSThisParameter = 115,
-    TSEnumDeclaration = 116,
-    TSEnumBody = 117,
-    TSEnumMember = 118,
-    TSTypeAnnotation = 119,
-    TSLiteralType = 120,
-    TSConditionalType = 121,
-    TSUnionType = 122,
-    TSIntersectionType = 123,
-    TSParenthesizedType = 124,
-    TSTypeOperator = 125,
-    TSArrayType = 126,
-    TSIndexedAccessType = 127,
-    TSTupleType = 128,
-    TSNamedTupleMember = 129,
-    TSOptionalType = 130,
-    TSRestType = 131,
-    TSAnyKeyword = 132,
-    TSStringKeyword = 133,
-    TSBooleanKeyword = 134,
-    TSNumberKeyword = 135,
-    TSNeverKeyword = 136,
-    TSIntrinsicKeyword = 137,
-    TSUnknownKeyword = 138,
-    TSNullKeyword = 139,
-    TSUndefinedKeyword = 140,
-    TSVoidKeyword = 141,
-    TSSymbolKeyword = 142,
-    TSThisType = 143,
-    TSObjectKeyword = 144,
-    TSBigIntKeyword = 145,
-    TSTypeReference = 146,
-    TSQualifiedName = 147,
-    TSTypeParameterInstantiation = 148,
-    TSTypeParameter = 149,
-    TSTypeParameterDeclaration = 150,
-    TSTypeAliasDeclaration = 151,
-    TSClassImplements = 152,
-    TSInterfaceDeclaration = 153,
-    TSInterfaceBody = 154,
-    TSPropertySignature = 155,
-    TSIndexSignature = 156,
-    TSCallSignatureDeclaration = 157,
-    TSMethodSignature = 158,
-    TSConstructSignatureDeclaration = 159,
-    TSIndexSignatureName = 160,
-    TSInterfaceHeritage = 161,
-    TSTypePredicate = 162,
-    TSModuleDeclaration = 163,
-    TSModuleBlock = 164,
-    TSTypeLiteral = 165,
-    TSInferType = 166,
-    TSTypeQuery = 167,
-    TSImportType = 168,
-    TSFunctionType = 169,
-    TSConstructorType = 170,
-    TSMappedType = 171,
-    TSTemplateLiteralType = 172,
-    TSAsExpression = 173,
-    TSSatisfiesExpression = 174,
-    TSTypeAssertion = 175,
-    TSImportEqualsDeclaration = 176,
-    TSExternalModuleReference = 177,
-    TSNonNullExpression = 178,
-    Decorator = 179,
-    TSExportAssignment = 180,
-    TSNamespaceExportDeclaration = 181,
-    TSInstantiationExpression = 182,
-    JSDocNullableType = 183,
-    JSDocNonNullableType = 184,
-    JSDocUnknownType = 185,
+    TopicReference = 94,
+    PipelineExpression = 95,
+    BooleanLiteral = 96,
+    NullLiteral = 97,
+    NumericLiteral = 98,
+    StringLiteral = 99,
+    BigIntLiteral = 100,
+    RegExpLiteral = 101,
+    JSXElement = 102,
+    JSXOpeningElement = 103,
+    JSXClosingElement = 104,
+    JSXFragment = 105,
+    JSXOpeningFragment = 106,
+    JSXClosingFragment = 107,
+    JSXNamespacedName = 108,
+    JSXMemberExpression = 109,
+    JSXExpressionContainer = 110,
+    JSXEmptyExpression = 111,
+    JSXAttribute = 112,
+    JSXSpreadAttribute = 113,
+    JSXIdentifier = 114,
+    JSXSpreadChild = 115,
+    JSXText = 116,
+    TSThisParameter = 117,
+    TSEnumDeclaration = 118,
+    TSEnumBody = 119,
+    TSEnumMember = 120,
+    TSTypeAnnotation = 121,
+    TSLiteralType = 122,
+    TSConditionalType = 123,
+    TSUnionType = 124,
+    TSIntersectionType = 125,
+    TSParenthesizedType = 126,
+    TSTypeOperator = 127,
+    TSArrayType = 128,
+    TSIndexedAccessType = 129,
+    TSTupleType = 130,
+    TSNamedTupleMember = 131,
+    TSOptionalType = 132,
+    TSRestType = 133,
+    TSAnyKeyword = 134,
+    TSStringKeyword = 135,
+    TSBooleanKeyword = 136,
+    TSNumberKeyword = 137,
+    TSNeverKeyword = 138,
+    TSIntrinsicKeyword = 139,
+    TSUnknownKeyword = 140,
+    TSNullKeyword = 141,
+    TSUndefinedKeyword = 142,
+    TSVoidKeyword = 143,
+    TSSymbolKeyword = 144,
+    TSThisType = 145,
+    TSObjectKeyword = 146,
+    TSBigIntKeyword = 147,
+    TSTypeReference = 148,
+    TSQualifiedName = 149,
+    TSTypeParameterInstantiation = 150,
+    TSTypeParameter = 151,
+    TSTypeParameterDeclaration = 152,
+    TSTypeAliasDeclaration = 153,
+    TSClassImplements = 154,
+    TSInterfaceDeclaration = 155,
+    TSInterfaceBody = 156,
+    TSPropertySignature = 157,
+    TSIndexSignature = 158,
+    TSCallSignatureDeclaration = 159,
+    TSMethodSignature = 160,
+    TSConstructSignatureDeclaration = 161,
+    TSIndexSignatureName = 162,
+    TSInterfaceHeritage = 163,
+    TSTypePredicate = 164,
+    TSModuleDeclaration = 165,
+    TSModuleBlock = 166,
+    TSTypeLiteral = 167,
+    TSInferType = 168,
+    TSTypeQuery = 169,
+    TSImportType = 170,
+    TSFunctionType = 171,
+    TSConstructorType = 172,
+    TSMappedType = 173,
+    TSTemplateLiteralType = 174,
+    TSAsExpression = 175,
+    TSSatisfiesExpression = 176,
+    TSTypeAssertion = 177,
+    TSImportEqualsDeclaration = 178,
+    TSExternalModuleReference = 179,
+    TSNonNullExpression = 180,
+    Decorator = 181,
+    TSExportAssignment = 182,
+    TSNamespaceExportDeclaration = 183,
+    TSInstantiationExpression = 184,
+    JSDocNullableType = 185,
+    JSDocNonNullableType = 186,
+    JSDocUnknownType = 187,
 }
 
 /// Untyped AST Node Kind
@@ -309,6 +311,8 @@ pub enum AstKind<'a> {
     ExportAllDeclaration(&'a ExportAllDeclaration<'a>) = AstType::ExportAllDeclaration as u8,
     ExportSpecifier(&'a ExportSpecifier<'a>) = AstType::ExportSpecifier as u8,
     V8IntrinsicExpression(&'a V8IntrinsicExpression<'a>) = AstType::V8IntrinsicExpression as u8,
+    TopicReference(&'a TopicReference) = AstType::TopicReference as u8,
+    PipelineExpression(&'a PipelineExpression<'a>) = AstType::PipelineExpression as u8,
     BooleanLiteral(&'a BooleanLiteral) = AstType::BooleanLiteral as u8,
     NullLiteral(&'a NullLiteral) = AstType::NullLiteral as u8,
     NumericLiteral(&'a NumericLiteral<'a>) = AstType::NumericLiteral as u8,
@@ -520,6 +524,8 @@ impl GetSpan for AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.span(),
             Self::ExportSpecifier(it) => it.span(),
             Self::V8IntrinsicExpression(it) => it.span(),
+            Self::TopicReference(it) => it.span(),
+            Self::PipelineExpression(it) => it.span(),
             Self::BooleanLiteral(it) => it.span(),
             Self::NullLiteral(it) => it.span(),
             Self::NumericLiteral(it) => it.span(),
@@ -713,6 +719,8 @@ impl GetAddress for AstKind<'_> {
             Self::ExportAllDeclaration(it) => Address::from_ptr(it),
             Self::ExportSpecifier(it) => Address::from_ptr(it),
             Self::V8IntrinsicExpression(it) => Address::from_ptr(it),
+            Self::TopicReference(it) => Address::from_ptr(it),
+            Self::PipelineExpression(it) => Address::from_ptr(it),
             Self::BooleanLiteral(it) => Address::from_ptr(it),
             Self::NullLiteral(it) => Address::from_ptr(it),
             Self::NumericLiteral(it) => Address::from_ptr(it),
@@ -1284,6 +1292,16 @@ impl<'a> AstKind<'a> {
         if let Self::V8IntrinsicExpression(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_topic_reference(self) -> Option<&'a TopicReference> {
+        if let Self::TopicReference(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_pipeline_expression(self) -> Option<&'a PipelineExpression<'a>> {
+        if let Self::PipelineExpression(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_boolean_literal(self) -> Option<&'a BooleanLiteral> {
         if let Self::BooleanLiteral(v) = self { Some(v) } else { None }
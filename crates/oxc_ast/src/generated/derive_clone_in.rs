@@ -146,6 +146,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Expression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Expression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -280,6 +286,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => Expression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Expression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -526,6 +538,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ArrayExpressionElement::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => {
+                ArrayExpressionElement::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ArrayExpressionElement::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ArrayExpressionElement::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -668,6 +686,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => ArrayExpressionElement::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => ArrayExpressionElement::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ArrayExpressionElement::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ArrayExpressionElement::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -876,6 +900,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => {
                 PropertyKey::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 PropertyKey::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1016,6 +1046,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => PropertyKey::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => PropertyKey::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -1407,6 +1443,10 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => Argument::TopicReference(CloneIn::clone_in(it, allocator)),
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Argument::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1542,6 +1582,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::TopicReference(it) => {
+                Argument::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Argument::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -2859,6 +2905,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ForStatementInit::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ForStatementInit::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ForStatementInit::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -2996,6 +3048,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => ForStatementInit::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => ForStatementInit::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ForStatementInit::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -4566,6 +4624,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in(it, allocator),
             ),
+            Self::TopicReference(it) => {
+                ExportDefaultDeclarationKind::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ExportDefaultDeclarationKind::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(CloneIn::clone_in(
                     it, allocator,
@@ -4725,6 +4789,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => ExportDefaultDeclarationKind::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ExportDefaultDeclarationKind::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(
                     CloneIn::clone_in_with_semantic_ids(it, allocator),
@@ -4796,6 +4866,38 @@ impl<'new_alloc> CloneIn<'new_alloc> for V8IntrinsicExpression<'_> {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for TopicReference {
+    type Cloned = TopicReference;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference { span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator) }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for PipelineExpression<'_> {
+    type Cloned = PipelineExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            span: CloneIn::clone_in(&self.span, allocator),
+            head: CloneIn::clone_in(&self.head, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            head: CloneIn::clone_in_with_semantic_ids(&self.head, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for BooleanLiteral {
     type Cloned = BooleanLiteral;
 
@@ -5310,6 +5412,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 JSXExpression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                JSXExpression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 JSXExpression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -5447,6 +5555,12 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => JSXExpression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => JSXExpression::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => JSXExpression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -69,6 +69,8 @@ impl ContentEq for Expression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -164,6 +166,8 @@ impl ContentEq for ArrayExpressionElement<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -256,6 +260,8 @@ impl ContentEq for PropertyKey<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -419,6 +425,8 @@ impl ContentEq for Argument<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -860,6 +868,8 @@ impl ContentEq for ForStatementInit<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1432,6 +1442,8 @@ impl ContentEq for ExportDefaultDeclarationKind<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1460,6 +1472,19 @@ impl ContentEq for V8IntrinsicExpression<'_> {
     }
 }
 
+impl ContentEq for TopicReference {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl ContentEq for PipelineExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.head, &other.head)
+            && ContentEq::content_eq(&self.body, &other.body)
+    }
+}
+
 impl ContentEq for BooleanLiteral {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.value, &other.value)
@@ -1644,6 +1669,8 @@ impl ContentEq for JSXExpression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -54,6 +54,8 @@ impl GetAddress for Expression<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -119,6 +121,8 @@ impl GetAddress for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -184,6 +188,8 @@ impl GetAddress for Argument<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -387,6 +393,8 @@ impl GetAddress for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -516,6 +524,8 @@ impl GetAddress for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
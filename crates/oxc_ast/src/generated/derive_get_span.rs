@@ -60,6 +60,8 @@ impl GetSpan for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -154,6 +156,8 @@ impl GetSpan for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -236,6 +240,8 @@ impl GetSpan for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -367,6 +373,8 @@ impl GetSpan for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -755,6 +763,8 @@ impl GetSpan for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -1189,6 +1199,8 @@ impl GetSpan for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -1213,6 +1225,20 @@ impl GetSpan for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpan for TopicReference {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for PipelineExpression<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl GetSpan for BooleanLiteral {
     #[inline]
     fn span(&self) -> Span {
@@ -1384,6 +1410,8 @@ impl GetSpan for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -60,6 +60,8 @@ impl GetSpanMut for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -154,6 +156,8 @@ impl GetSpanMut for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -236,6 +240,8 @@ impl GetSpanMut for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -367,6 +373,8 @@ impl GetSpanMut for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -755,6 +763,8 @@ impl GetSpanMut for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -1189,6 +1199,8 @@ impl GetSpanMut for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -1213,6 +1225,20 @@ impl GetSpanMut for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpanMut for TopicReference {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for PipelineExpression<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
 impl GetSpanMut for BooleanLiteral {
     #[inline]
     fn span_mut(&mut self) -> &mut Span {
@@ -1384,6 +1410,8 @@ impl GetSpanMut for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
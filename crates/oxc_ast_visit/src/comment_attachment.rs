@@ -0,0 +1,344 @@
+//! Attachment of comments to the AST nodes around them, computed once for all comments.
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_span::{GetSpan, Span};
+
+use crate::Visit;
+
+/// How a comment is attached to a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommentPlacement {
+    /// Before the node, e.g. `/* leading */ node`.
+    Leading,
+    /// After the node, e.g. `node /* trailing */`.
+    Trailing,
+    /// Inside a node without children around the comment, e.g. `function f(/* dangling */) {}`.
+    Dangling,
+}
+
+/// The comments of a program, attached to the nodes around them with the heuristics of Prettier
+/// and Babel.
+///
+/// Each comment is attached to exactly one node. For the innermost node which encloses a comment,
+/// the child nodes before and after it are found, and:
+///
+/// * A comment on its own line is a leading comment of the node after it, e.g. a comment above a
+///   statement.
+/// * A comment at the end of a line is a trailing comment of the node before it, e.g.
+///   `a = 1; // a`.
+/// * Otherwise, a comment followed only by whitespace and `(` before the node after it is a leading
+///   comment of that node, e.g. `f(a, /* b */ b)`, and other comments are trailing comments of the
+///   node before them, e.g. `f(a /* a */, b)`.
+///
+/// When there is no node on the preferred side, the node on the other side is used, and when there
+/// are no child nodes around the comment at all, it's a dangling comment of the enclosing node,
+/// e.g. `{ /* empty */ }`.
+///
+/// Nodes are identified by their spans. Nodes with the same span, e.g. an expression statement
+/// without a semicolon and its expression, share their comments.
+///
+/// # Example
+///
+/// ```ignore
+/// let comments = Comments::new(&program);
+/// for statement in &program.body {
+///     for comment in comments.leading(statement.span()) {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Comments<'c> {
+    comments: &'c [Comment],
+    /// Attachments of all comments, sorted by the span of the node and placement.
+    attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Attachment {
+    node_start: u32,
+    node_end: u32,
+    placement: CommentPlacement,
+    /// Index of the comment in `Program::comments`
+    comment: u32,
+}
+
+impl<'c> Comments<'c> {
+    /// Attach the comments of `program` to its nodes.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn new(program: &'c Program<'_>) -> Self {
+        let comments = program.comments.as_slice();
+        if comments.is_empty() {
+            return Self { comments, attachments: vec![] };
+        }
+
+        let mut collector = NodeCollector::default();
+        collector.visit_program(program);
+        let mut tree = collector.tree;
+        for children in &mut tree.children {
+            children.sort_by_key(|&child| tree.spans[child as usize].start);
+        }
+
+        let source_text = program.source_text;
+        let mut attachments = comments
+            .iter()
+            .enumerate()
+            .map(|(index, comment)| {
+                let (node, placement) = tree.attach(comment, comments, source_text);
+                Attachment {
+                    node_start: node.start,
+                    node_end: node.end,
+                    placement,
+                    comment: index as u32,
+                }
+            })
+            .collect::<Vec<_>>();
+        attachments.sort_unstable();
+        Self { comments, attachments }
+    }
+
+    /// Leading comments of the node at `span`, in source order.
+    pub fn leading(&self, span: Span) -> impl DoubleEndedIterator<Item = &'c Comment> + '_ {
+        self.attached(span, CommentPlacement::Leading)
+    }
+
+    /// Trailing comments of the node at `span`, in source order.
+    pub fn trailing(&self, span: Span) -> impl DoubleEndedIterator<Item = &'c Comment> + '_ {
+        self.attached(span, CommentPlacement::Trailing)
+    }
+
+    /// Dangling comments of the node at `span`, in source order.
+    pub fn dangling(&self, span: Span) -> impl DoubleEndedIterator<Item = &'c Comment> + '_ {
+        self.attached(span, CommentPlacement::Dangling)
+    }
+
+    /// Whether any comments are attached to the node at `span`.
+    pub fn has_comments(&self, span: Span) -> bool {
+        let start = self.partition_point(span, CommentPlacement::Leading);
+        self.attachments
+            .get(start)
+            .is_some_and(|a| a.node_start == span.start && a.node_end == span.end)
+    }
+
+    /// The node a comment is attached to, and how.
+    ///
+    /// # Panics
+    /// If `comment` is not a comment of the program.
+    pub fn attachment(&self, comment: &Comment) -> (Span, CommentPlacement) {
+        let index = self
+            .comments
+            .binary_search_by_key(&comment.span.start, |c| c.span.start)
+            .expect("not a comment of the program");
+        let attachment = self
+            .attachments
+            .iter()
+            .find(|a| a.comment as usize == index)
+            .expect("every comment is attached");
+        (Span::new(attachment.node_start, attachment.node_end), attachment.placement)
+    }
+
+    fn attached(
+        &self,
+        span: Span,
+        placement: CommentPlacement,
+    ) -> impl DoubleEndedIterator<Item = &'c Comment> + '_ {
+        let start = self.partition_point(span, placement);
+        let len = self.attachments[start..].partition_point(|a| {
+            a.node_start == span.start && a.node_end == span.end && a.placement == placement
+        });
+        let comments = self.comments;
+        self.attachments[start..start + len].iter().map(move |a| &comments[a.comment as usize])
+    }
+
+    fn partition_point(&self, span: Span, placement: CommentPlacement) -> usize {
+        self.attachments.partition_point(|a| {
+            (a.node_start, a.node_end, a.placement) < (span.start, span.end, placement)
+        })
+    }
+}
+
+/// Spans of the nodes of a program, and their children.
+#[derive(Default)]
+struct NodeTree {
+    spans: Vec<Span>,
+    /// Indices of the children of each node, sorted by their start.
+    children: Vec<Vec<u32>>,
+}
+
+impl NodeTree {
+    /// Find the node to attach `comment` to.
+    fn attach(
+        &self,
+        comment: &Comment,
+        comments: &[Comment],
+        source_text: &str,
+    ) -> (Span, CommentPlacement) {
+        let span = comment.span;
+        // Descend from `Program` to the innermost node which encloses the comment
+        let mut enclosing = 0;
+        let (preceding, following) = loop {
+            let children = &self.children[enclosing];
+            let index =
+                children.partition_point(|&child| self.spans[child as usize].start <= span.start);
+            let preceding = index.checked_sub(1).map(|index| children[index] as usize);
+            if let Some(child) = preceding {
+                if self.spans[child].end >= span.end {
+                    enclosing = child;
+                    continue;
+                }
+            }
+            let preceding = preceding.map(|child| self.spans[child]);
+            let following = children.get(index).map(|&child| self.spans[child as usize]);
+            break (preceding, following);
+        };
+
+        let before = &source_text[..span.start as usize];
+        let after = &source_text[span.end as usize..];
+        let own_line = before.trim_end_matches(is_whitespace).ends_with(is_line_break)
+            || before.trim_end_matches(is_whitespace).is_empty();
+        let end_of_line = after.trim_start_matches(is_whitespace).starts_with(is_line_break)
+            || after.trim_start_matches(is_whitespace).is_empty();
+
+        match (preceding, following) {
+            (_, Some(following)) if own_line => (following, CommentPlacement::Leading),
+            (Some(preceding), _) if own_line || end_of_line => {
+                (preceding, CommentPlacement::Trailing)
+            }
+            (Some(preceding), Some(following)) => {
+                if is_gap_before_node(span.end, following.start, comments, source_text) {
+                    (following, CommentPlacement::Leading)
+                } else {
+                    (preceding, CommentPlacement::Trailing)
+                }
+            }
+            (Some(preceding), None) => (preceding, CommentPlacement::Trailing),
+            (None, Some(following)) => (following, CommentPlacement::Leading),
+            (None, None) => (self.spans[enclosing], CommentPlacement::Dangling),
+        }
+    }
+}
+
+/// Whether the text from `start` to `end` only contains whitespace, `(` and comments, so the
+/// comment before it belongs to the node after it.
+fn is_gap_before_node(start: u32, end: u32, comments: &[Comment], source_text: &str) -> bool {
+    let mut position = start;
+    let mut comments =
+        comments[comments.partition_point(|comment| comment.span.start < start)..].iter();
+    let mut next_comment = comments.next();
+    while position < end {
+        if let Some(comment) = next_comment.filter(|comment| comment.span.start == position) {
+            position = comment.span.end;
+            next_comment = comments.next();
+            continue;
+        }
+        let Some(ch) = source_text[position as usize..].chars().next() else { break };
+        if ch != '(' && !is_whitespace(ch) && !is_line_break(ch) {
+            return false;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let len = ch.len_utf8() as u32;
+        position += len;
+    }
+    true
+}
+
+fn is_whitespace(ch: char) -> bool {
+    ch.is_whitespace() && !is_line_break(ch)
+}
+
+fn is_line_break(ch: char) -> bool {
+    matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+#[derive(Default)]
+struct NodeCollector {
+    tree: NodeTree,
+    /// Indices of the nodes being visited
+    stack: Vec<u32>,
+}
+
+impl<'a> Visit<'a> for NodeCollector {
+    #[expect(clippy::cast_possible_truncation)]
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let index = self.tree.spans.len() as u32;
+        self.tree.spans.push(kind.span());
+        self.tree.children.push(vec![]);
+        if let Some(&parent) = self.stack.last() {
+            self.tree.children[parent as usize].push(index);
+        }
+        self.stack.push(index);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Comment;
+    use oxc_parser::Parser;
+    use oxc_span::{SourceType, Span};
+
+    use super::{CommentPlacement, Comments};
+
+    /// The source text of the node each comment is attached to, and how.
+    fn attachments(source_text: &str) -> Vec<(&str, CommentPlacement, &str)> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let comments = Comments::new(&ret.program);
+        ret.program
+            .comments
+            .iter()
+            .map(|comment| {
+                let (node, placement) = comments.attachment(comment);
+                (comment.span.source_text(source_text), placement, node.source_text(source_text))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn placement() {
+        use CommentPlacement::{Dangling, Leading, Trailing};
+
+        let source_text =
+            "// a\nlet a = 1; // b\n/* c */ f(a /* d */, /* e */ b);\nfunction g(/* f */) {}\n";
+        assert_eq!(
+            attachments(source_text),
+            [
+                ("// a", Leading, "let a = 1;"),
+                ("// b", Trailing, "let a = 1;"),
+                ("/* c */", Leading, "f(a /* d */, /* e */ b);"),
+                ("/* d */", Trailing, "a"),
+                ("/* e */", Leading, "b"),
+                ("/* f */", Dangling, "(/* f */)"),
+            ]
+        );
+
+        assert_eq!(attachments("const a = {\n  b: 1,\n  // c\n};\n"), [("// c", Trailing, "b: 1")]);
+        assert_eq!(
+            attachments("if (a) {\n  // empty\n}\n"),
+            [("// empty", Dangling, "{\n  // empty\n}")]
+        );
+    }
+
+    #[test]
+    fn lookup() {
+        let allocator = Allocator::default();
+        let source_text = "/* a */ /* b */ x; // c\ny;";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let comments = Comments::new(&ret.program);
+        let statement = Span::new(16, 18);
+        let text = |comment: &Comment| comment.span.source_text(source_text);
+        assert_eq!(
+            comments.leading(statement).map(text).collect::<Vec<_>>(),
+            ["/* a */", "/* b */"]
+        );
+        assert_eq!(comments.trailing(statement).map(text).collect::<Vec<_>>(), ["// c"]);
+        assert_eq!(comments.dangling(statement).count(), 0);
+        assert!(comments.has_comments(statement));
+        assert!(!comments.has_comments(Span::new(24, 26)));
+    }
+}
@@ -623,6 +623,16 @@ pub trait VisitMut<'a>: Sized {
         walk_v_8_intrinsic_expression(self, it);
     }
 
+    #[inline]
+    fn visit_topic_reference(&mut self, it: &mut TopicReference) {
+        walk_topic_reference(self, it);
+    }
+
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &mut PipelineExpression<'a>) {
+        walk_pipeline_expression(self, it);
+    }
+
     #[inline]
     fn visit_boolean_literal(&mut self, it: &mut BooleanLiteral) {
         walk_boolean_literal(self, it);
@@ -1405,6 +1415,8 @@ pub mod walk_mut {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v_8_intrinsic_expression(it),
+            Expression::TopicReference(it) => visitor.visit_topic_reference(it),
+            Expression::PipelineExpression(it) => visitor.visit_pipeline_expression(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression_mut())
             }
@@ -2994,6 +3006,27 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_topic_reference<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut TopicReference) {
+        let kind = AstType::TopicReference;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_pipeline_expression<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut PipelineExpression<'a>,
+    ) {
+        let kind = AstType::PipelineExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_expression(&mut it.head);
+        visitor.visit_expression(&mut it.body);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_boolean_literal<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut BooleanLiteral) {
         let kind = AstType::BooleanLiteral;
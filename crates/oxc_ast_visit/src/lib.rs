@@ -7,5 +7,8 @@ mod generated {
 
 pub use generated::{visit::*, visit_mut::*};
 
+pub mod comment_attachment;
+pub mod module_facts;
+
 #[cfg(feature = "serialize")]
 pub mod utf8_to_utf16;
@@ -0,0 +1,394 @@
+//! Facts about a module which bundlers need, collected in a single traversal.
+
+use bitflags::bitflags;
+
+use oxc_ast::ast::*;
+use oxc_span::{Atom, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{Visit, walk};
+
+bitflags! {
+    /// Boolean facts of a [`ModuleFacts`].
+    ///
+    /// Identifiers such as `eval`, `require`, `module` and `exports` are matched by name, without
+    /// checking whether they are shadowed by a local binding.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct ModuleFlags: u8 {
+        /// `import` or `export` declarations, or `import.meta`.
+        const MODULE_SYNTAX = 1 << 0;
+        /// A direct `eval(...)` call, which can access every binding in scope.
+        const DIRECT_EVAL = 1 << 1;
+        /// `await` or `for await` outside of functions.
+        const TOP_LEVEL_AWAIT = 1 << 2;
+        /// `this` outside of functions and classes.
+        const TOP_LEVEL_THIS = 1 << 3;
+        /// A `require(...)` call.
+        const REQUIRE = 1 << 4;
+        /// A reference to `module.exports`.
+        const MODULE_EXPORTS = 1 << 5;
+        /// A reference to `exports`.
+        const EXPORTS = 1 << 6;
+        /// An `import(...)` expression.
+        const DYNAMIC_IMPORT = 1 << 7;
+    }
+}
+
+/// How a module is imported by an [`ImportFact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFactKind {
+    /// `import ... from "x"` or `import "x"`
+    Static,
+    /// `export ... from "x"` or `export * from "x"`
+    ReExport,
+    /// `import("x")`
+    Dynamic,
+    /// `require("x")` or `import x = require("x")`
+    Require,
+}
+
+/// A module imported with a string literal specifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportFact<'a> {
+    pub specifier: Atom<'a>,
+    pub kind: ImportFactKind,
+    /// Span of the import declaration or expression.
+    pub span: Span,
+}
+
+/// Facts about a module which bundlers commonly need, collected in a single traversal of the
+/// AST instead of one traversal per fact.
+///
+/// Type-only imports and exports are not included, and neither are exported namespaces and
+/// `const enum`s.
+#[derive(Debug, Default, Clone)]
+pub struct ModuleFacts<'a> {
+    pub flags: ModuleFlags,
+    /// Imported modules, in source order.
+    pub imports: Vec<ImportFact<'a>>,
+    /// Exported names, in source order. `export default` is named `default`.
+    pub exports: Vec<Atom<'a>>,
+}
+
+impl<'a> ModuleFacts<'a> {
+    /// Collect the facts of `program`.
+    pub fn new(program: &Program<'a>) -> Self {
+        let mut collector = FactsCollector::default();
+        collector.visit_program(program);
+        collector.facts
+    }
+
+    /// Whether the module uses any of `require`, `module.exports` or `exports`.
+    pub fn uses_commonjs(&self) -> bool {
+        self.flags
+            .intersects(ModuleFlags::REQUIRE | ModuleFlags::MODULE_EXPORTS | ModuleFlags::EXPORTS)
+    }
+}
+
+#[derive(Default)]
+struct FactsCollector<'a> {
+    facts: ModuleFacts<'a>,
+    /// Depth of functions, including arrow functions, which is where `await` stops being at the
+    /// top level.
+    function_depth: u32,
+    /// Depth of non-arrow functions and class members, which is where `this` stops being at the
+    /// top level.
+    this_depth: u32,
+}
+
+impl<'a> FactsCollector<'a> {
+    fn add_import(&mut self, specifier: Atom<'a>, kind: ImportFactKind, span: Span) {
+        self.facts.imports.push(ImportFact { specifier, kind, span });
+    }
+
+    /// Visit `f` in the scope of a class member, where `this` is the class or its instance.
+    fn in_class_member(&mut self, f: impl FnOnce(&mut Self)) {
+        self.this_depth += 1;
+        f(self);
+        self.this_depth -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for FactsCollector<'a> {
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_function(self, it, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, it);
+        self.function_depth -= 1;
+    }
+
+    // Decorators and computed keys are evaluated outside of the class.
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        self.visit_decorators(&it.decorators);
+        self.visit_property_key(&it.key);
+        if let Some(value) = &it.value {
+            self.in_class_member(|collector| collector.visit_expression(value));
+        }
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.visit_decorators(&it.decorators);
+        self.visit_property_key(&it.key);
+        if let Some(value) = &it.value {
+            self.in_class_member(|collector| collector.visit_expression(value));
+        }
+    }
+
+    fn visit_static_block(&mut self, it: &StaticBlock<'a>) {
+        self.in_class_member(|collector| walk::walk_static_block(collector, it));
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        if self.this_depth == 0 {
+            self.facts.flags |= ModuleFlags::TOP_LEVEL_THIS;
+        }
+    }
+
+    fn visit_await_expression(&mut self, it: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.facts.flags |= ModuleFlags::TOP_LEVEL_AWAIT;
+        }
+        walk::walk_await_expression(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        if it.r#await && self.function_depth == 0 {
+            self.facts.flags |= ModuleFlags::TOP_LEVEL_AWAIT;
+        }
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        if it.kind == VariableDeclarationKind::AwaitUsing && self.function_depth == 0 {
+            self.facts.flags |= ModuleFlags::TOP_LEVEL_AWAIT;
+        }
+        walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &it.callee {
+            match callee.name.as_str() {
+                "eval" => self.facts.flags |= ModuleFlags::DIRECT_EVAL,
+                "require" => {
+                    self.facts.flags |= ModuleFlags::REQUIRE;
+                    if let Some(Argument::StringLiteral(specifier)) = it.arguments.first() {
+                        self.add_import(specifier.value, ImportFactKind::Require, it.span);
+                    }
+                }
+                _ => {}
+            }
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+        self.facts.flags |= ModuleFlags::DYNAMIC_IMPORT;
+        if let Expression::StringLiteral(specifier) = &it.source {
+            self.add_import(specifier.value, ImportFactKind::Dynamic, it.span);
+        }
+        walk::walk_import_expression(self, it);
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if it.meta.name == "import" {
+            self.facts.flags |= ModuleFlags::MODULE_SYNTAX;
+        }
+    }
+
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        if it.property.name == "exports" && it.object.is_specific_id("module") {
+            self.facts.flags |= ModuleFlags::MODULE_EXPORTS;
+        }
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "exports" {
+            self.facts.flags |= ModuleFlags::EXPORTS;
+        }
+    }
+
+    fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        self.facts.flags |= ModuleFlags::MODULE_SYNTAX;
+        if !it.import_kind.is_type() {
+            self.add_import(it.source.value, ImportFactKind::Static, it.span);
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        self.facts.flags |= ModuleFlags::MODULE_SYNTAX;
+        if it.export_kind.is_type() {
+            return;
+        }
+        if let Some(source) = &it.source {
+            self.add_import(source.value, ImportFactKind::ReExport, it.span);
+        }
+        match &it.declaration {
+            Some(Declaration::VariableDeclaration(decl)) if !decl.declare => {
+                for declarator in &decl.declarations {
+                    for id in declarator.id.get_binding_identifiers() {
+                        self.facts.exports.push(id.name);
+                    }
+                }
+            }
+            Some(Declaration::FunctionDeclaration(func)) if !func.is_typescript_syntax() => {
+                self.facts.exports.extend(func.id.as_ref().map(|id| id.name));
+            }
+            Some(Declaration::ClassDeclaration(class)) if !class.declare => {
+                self.facts.exports.extend(class.id.as_ref().map(|id| id.name));
+            }
+            Some(Declaration::TSEnumDeclaration(decl)) if !decl.declare && !decl.r#const => {
+                self.facts.exports.push(decl.id.name);
+            }
+            Some(Declaration::TSImportEqualsDeclaration(decl)) if !decl.import_kind.is_type() => {
+                self.facts.exports.push(decl.id.name);
+            }
+            _ => {}
+        }
+        for specifier in &it.specifiers {
+            if !specifier.export_kind.is_type() {
+                self.facts.exports.push(specifier.exported.name());
+            }
+        }
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        self.facts.flags |= ModuleFlags::MODULE_SYNTAX;
+        if !it.declaration.is_typescript_syntax() {
+            self.facts.exports.push(Atom::from("default"));
+        }
+        walk::walk_export_default_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ExportAllDeclaration<'a>) {
+        self.facts.flags |= ModuleFlags::MODULE_SYNTAX;
+        if it.export_kind.is_type() {
+            return;
+        }
+        self.add_import(it.source.value, ImportFactKind::ReExport, it.span);
+        if let Some(exported) = &it.exported {
+            self.facts.exports.push(exported.name());
+        }
+    }
+
+    fn visit_ts_import_equals_declaration(&mut self, it: &TSImportEqualsDeclaration<'a>) {
+        if let TSModuleReference::ExternalModuleReference(reference) = &it.module_reference {
+            if !it.import_kind.is_type() {
+                self.add_import(reference.expression.value, ImportFactKind::Require, it.span);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{Atom, SourceType};
+
+    use super::{ImportFactKind, ModuleFacts, ModuleFlags};
+
+    fn flags(source_text: &str) -> ModuleFlags {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        ModuleFacts::new(&ret.program).flags
+    }
+
+    #[test]
+    fn module_flags() {
+        let cases = [
+            ("let a = 1;", ModuleFlags::empty()),
+            ("export {}", ModuleFlags::MODULE_SYNTAX),
+            ("import.meta.url", ModuleFlags::MODULE_SYNTAX),
+            ("eval('a')", ModuleFlags::DIRECT_EVAL),
+            ("(0, eval)('a')", ModuleFlags::empty()),
+            ("await a;", ModuleFlags::TOP_LEVEL_AWAIT),
+            ("for await (const a of b);", ModuleFlags::TOP_LEVEL_AWAIT),
+            ("{ await using a = b; }", ModuleFlags::TOP_LEVEL_AWAIT),
+            ("async function f() { await a; }", ModuleFlags::empty()),
+            ("const f = async () => { for await (const a of b); };", ModuleFlags::empty()),
+            ("this.a;", ModuleFlags::TOP_LEVEL_THIS),
+            ("const f = () => this;", ModuleFlags::TOP_LEVEL_THIS),
+            ("function f() { return this; }", ModuleFlags::empty()),
+            ("class A { a = this; static { this; } m() { this; } }", ModuleFlags::empty()),
+            ("class A { [this.a] = 1; }", ModuleFlags::TOP_LEVEL_THIS),
+            ("require('a');", ModuleFlags::REQUIRE),
+            ("module.exports = a;", ModuleFlags::MODULE_EXPORTS),
+            ("exports.a = 1;", ModuleFlags::EXPORTS),
+            ("module.a;", ModuleFlags::empty()),
+            ("import(a);", ModuleFlags::DYNAMIC_IMPORT),
+        ];
+        for (source_text, expected) in cases {
+            assert_eq!(flags(source_text), expected, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn imports_and_exports() {
+        let source_text = "
+            import a from 'a';
+            import type { B } from 'b';
+            import 'c';
+            export * from 'd';
+            export * as e from 'e';
+            export { f, g as h } from 'f';
+            export type { I } from 'i';
+            import j = require('j');
+            const k = require('k');
+            const l = import('l');
+            export const { m, n: [o] } = p;
+            export function q(): void;
+            export function q() {}
+            export class R {}
+            export abstract class W {}
+            export const enum X {}
+            export interface S {}
+            export type T = 1;
+            export declare const u: number;
+            export enum V {}
+            export default 1;
+        ";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let facts = ModuleFacts::new(&ret.program);
+
+        let imports = facts
+            .imports
+            .iter()
+            .map(|import| (import.specifier.as_str(), import.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            imports,
+            [
+                ("a", ImportFactKind::Static),
+                ("c", ImportFactKind::Static),
+                ("d", ImportFactKind::ReExport),
+                ("e", ImportFactKind::ReExport),
+                ("f", ImportFactKind::ReExport),
+                ("j", ImportFactKind::Require),
+                ("k", ImportFactKind::Require),
+                ("l", ImportFactKind::Dynamic),
+            ]
+        );
+        assert_eq!(&source_text[facts.imports[0].span], "import a from 'a';");
+
+        let exports = facts.exports.iter().map(Atom::as_str).collect::<Vec<_>>();
+        assert_eq!(exports, ["e", "f", "h", "m", "o", "q", "R", "W", "V", "default"]);
+
+        assert_eq!(
+            facts.flags,
+            ModuleFlags::MODULE_SYNTAX | ModuleFlags::REQUIRE | ModuleFlags::DYNAMIC_IMPORT
+        );
+        assert!(facts.uses_commonjs());
+    }
+}
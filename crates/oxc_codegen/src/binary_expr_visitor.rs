@@ -60,6 +60,10 @@ fn print_binary_operator(op: BinaryOperator, p: &mut Codegen) {
     } else {
         let op: Operator = op.into();
         p.print_space_before_operator(op);
+        // `% == a` with a topic reference `%` is not `%= = a`
+        if operator.starts_with('=') && p.last_byte() == Some(b'%') {
+            p.print_hard_space();
+        }
         p.print_str(operator);
         p.prev_op = Some(op);
         p.prev_op_end = p.code().len();
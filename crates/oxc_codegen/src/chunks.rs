@@ -0,0 +1,205 @@
+//! Splitting of printed scripts into chunks of limited size.
+
+use std::{mem, ops::Range};
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_syntax::{precedence::Precedence, scope::ScopeFlags};
+
+use crate::{Codegen, Context, Gen, GenExpr, str::Quote};
+
+impl<'a> Codegen<'a> {
+    /// Print a script [`Program`] into chunks of source code which are each at most
+    /// `max_chunk_size` bytes long, and which have the same effect as the whole program when
+    /// evaluated as separate scripts one after another.
+    ///
+    /// This is useful for environments which limit the size of a single script, such as some
+    /// embedded engines. Scripts share their global scope, so the program is split between
+    /// top-level statements, and each chunk is a continuation of the previous one:
+    ///
+    /// * Directives such as `"use strict"` are repeated at the start of every chunk.
+    /// * Top-level function declarations are moved before all other statements, since they are
+    ///   hoisted within a script, but not across scripts.
+    /// * `var` declarations of later chunks are declared in the first chunk, so they are
+    ///   `undefined` rather than undeclared before their chunk is evaluated.
+    ///
+    /// A single top-level statement is never split, so a chunk can exceed `max_chunk_size` if
+    /// one statement is larger than it. Programs which fit in `max_chunk_size` are printed as a
+    /// single chunk in their original order, and modules are always printed as a single chunk,
+    /// as imports and exports can't be split across scripts.
+    ///
+    /// Source maps and linked or external legal comments are not generated for chunks.
+    #[must_use]
+    pub fn build_script_chunks(
+        mut self,
+        program: &Program<'a>,
+        max_chunk_size: usize,
+    ) -> Vec<String> {
+        if program.source_type.is_module() {
+            return vec![self.build(program).code];
+        }
+
+        self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
+        self.source_text = Some(program.source_text);
+        self.code.reserve(program.source_text.len());
+        self.build_comments(&program.comments);
+        self.is_jsx = program.source_type.is_jsx();
+        let ctx = Context::default();
+
+        let hashbang = self.print_range(|p| {
+            if let Some(hashbang) = &program.hashbang {
+                hashbang.print(p, ctx);
+            }
+        });
+        let directives = self.print_range(|p| {
+            for directive in &program.directives {
+                directive.print(p, ctx);
+            }
+        });
+        let statements = program
+            .body
+            .iter()
+            .map(|stmt| self.print_range(|p| p.print_chunk_statement(stmt, ctx)))
+            .collect::<Vec<_>>();
+        let trailing_comments = self.print_range(|p| p.print_comments_at(program.span.end));
+
+        let total_size = hashbang.len()
+            + directives.len()
+            + statements.iter().map(Range::len).sum::<usize>()
+            + trailing_comments.len();
+        if total_size <= max_chunk_size {
+            let code = self.code.into_string();
+            let mut chunk = String::with_capacity(total_size);
+            for range in
+                [hashbang, directives].into_iter().chain(statements).chain([trailing_comments])
+            {
+                chunk.push_str(&code[range]);
+            }
+            return vec![chunk];
+        }
+
+        // Function declarations are hoisted, so they go first.
+        let (functions, others): (Vec<_>, Vec<_>) = program
+            .body
+            .iter()
+            .zip(statements)
+            .partition(|(stmt, _)| matches!(stmt, Statement::FunctionDeclaration(_)));
+
+        let mut var_collector = VarCollector::new(&self, program.has_use_strict_directive());
+        for (stmt, _) in &others {
+            var_collector.visit_statement(stmt);
+        }
+        let var_names = var_collector.names;
+        let var_prelude = self.print_range(|p| p.print_var_prelude(&var_names));
+
+        let code = self.code.into_string();
+        let mut chunks = vec![];
+        let mut chunk =
+            format!("{}{}{}", &code[hashbang], &code[directives.clone()], &code[var_prelude]);
+        let mut chunk_is_empty = true;
+        for (_, range) in functions.into_iter().chain(others) {
+            let statement = &code[range];
+            if !chunk_is_empty && chunk.len() + statement.len() > max_chunk_size {
+                chunks.push(mem::replace(&mut chunk, code[directives.clone()].to_string()));
+            }
+            chunk.push_str(statement);
+            chunk_is_empty = false;
+        }
+        chunk.push_str(&code[trailing_comments]);
+        chunks.push(chunk);
+        chunks
+    }
+
+    /// Byte range of the code printed by `f`.
+    fn print_range(&mut self, f: impl FnOnce(&mut Self)) -> Range<usize> {
+        let start = self.code.len();
+        f(self);
+        start..self.code.len()
+    }
+
+    /// Print a top-level statement which can be moved to the start of a chunk.
+    fn print_chunk_statement(&mut self, stmt: &Statement<'_>, ctx: Context) {
+        // A string literal at the start of a chunk would be a directive.
+        if let Statement::ExpressionStatement(s) = stmt {
+            let expr = s.expression.without_parentheses();
+            if matches!(expr, Expression::StringLiteral(_)) {
+                self.print_comments_at(s.span.start);
+                self.print_indent();
+                self.print_ascii_byte(b'(');
+                expr.print_expr(self, Precedence::Lowest, ctx);
+                self.print_ascii_byte(b')');
+                self.print_semicolon_after_statement();
+                return;
+            }
+        }
+        stmt.print(self, ctx);
+        self.print_semicolon_if_needed();
+    }
+
+    /// Print `var a, b;` to declare `names` before the chunks which declare them are evaluated.
+    fn print_var_prelude(&mut self, names: &[&str]) {
+        let Some((first, rest)) = names.split_first() else {
+            return;
+        };
+        self.print_indent();
+        self.print_str("var ");
+        self.print_str(first);
+        for name in rest {
+            self.print_comma();
+            self.print_soft_space();
+            self.print_str(name);
+        }
+        self.print_semicolon_after_statement();
+        self.print_semicolon_if_needed();
+    }
+}
+
+/// Collects names of bindings which are hoisted to the top level of a script, i.e. `var`
+/// declarations and, in sloppy mode, function declarations in blocks.
+struct VarCollector<'c, 'a> {
+    codegen: &'c Codegen<'a>,
+    is_strict: bool,
+    names: Vec<&'a str>,
+    seen: FxHashSet<&'a str>,
+}
+
+impl<'c, 'a> VarCollector<'c, 'a> {
+    fn new(codegen: &'c Codegen<'a>, is_strict: bool) -> Self {
+        Self { codegen, is_strict, names: vec![], seen: FxHashSet::default() }
+    }
+
+    fn add(&mut self, ident: &BindingIdentifier<'a>) {
+        let name = self.codegen.get_binding_identifier_name(ident);
+        if self.seen.insert(name) {
+            self.names.push(name);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for VarCollector<'_, 'a> {
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        if it.kind.is_var() {
+            for declarator in &it.declarations {
+                for ident in declarator.id.get_binding_identifiers() {
+                    self.add(ident);
+                }
+            }
+        }
+        walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, _flags: ScopeFlags) {
+        // Only function declarations nested in blocks are visited, as top-level ones are not.
+        if it.is_declaration() && !self.is_strict {
+            if let Some(id) = &it.id {
+                self.add(id);
+            }
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
@@ -552,7 +552,7 @@ impl Gen for ReturnStatement<'_> {
 impl Gen for LabeledStatement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_comments_at(self.span.start);
-        if !p.options.minify && (p.indent > 0 || p.print_next_indent_as_space) {
+        if p.options.print_line_breaks() && (p.indent > 0 || p.print_next_indent_as_space) {
             p.add_source_mapping(self.span);
             p.print_indent();
         }
@@ -1180,13 +1180,25 @@ impl GenExpr for Expression<'_> {
             Self::TSNonNullExpression(e) => e.print_expr(p, precedence, ctx),
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::TopicReference(e) => e.print(p, ctx),
+            Self::PipelineExpression(e) => e.print_expr(p, precedence, ctx),
         }
     }
 }
 
 impl GenExpr for ParenthesizedExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        self.expression.print_expr(p, precedence, ctx);
+        if p.options.preserve_parens {
+            p.print_ascii_byte(b'(');
+            self.expression.print_expr(
+                p,
+                Precedence::Lowest,
+                ctx.and_forbid_in(false).and_forbid_call(false),
+            );
+            p.print_ascii_byte(b')');
+        } else {
+            self.expression.print_expr(p, precedence, ctx);
+        }
     }
 }
 
@@ -1363,6 +1375,16 @@ impl GenExpr for ComputedMemberExpression<'_> {
 impl GenExpr for StaticMemberExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         self.object.print_expr(p, Precedence::Postfix, ctx.intersection(Context::FORBID_CALL));
+        // `a.default` -> `a["default"]` for ES3
+        if p.needs_quoted_property_name(&self.property.name) {
+            if self.optional {
+                p.print_str("?.");
+            }
+            p.print_ascii_byte(b'[');
+            p.print_quoted_property_name(&self.property);
+            p.print_ascii_byte(b']');
+            return;
+        }
         if self.optional {
             p.print_ascii_byte(b'?');
         } else if p.need_space_before_dot == p.code_len() {
@@ -1446,7 +1468,8 @@ impl Gen for SpreadElement<'_> {
 
 impl Gen for ArrayExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        let is_multi_line = self.elements.len() > 2;
+        // Expressions are printed on a single line with `keep_line_breaks`.
+        let is_multi_line = self.elements.len() > 2 && !p.options.minify;
         p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         if is_multi_line {
@@ -1481,7 +1504,7 @@ impl GenExpr for ObjectExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         let n = p.code_len();
         let len = self.properties.len();
-        let is_multi_line = len > 1;
+        let is_multi_line = len > 1 && !p.options.minify;
         let has_comment = p.has_comment(self.span.start);
         let wrap = has_comment || p.start_of_stmt == n || p.start_of_arrow_expr == n;
         p.wrap(wrap, |p| {
@@ -1627,6 +1650,9 @@ impl Gen for ObjectProperty<'_> {
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
+            Self::StaticIdentifier(ident) if p.needs_quoted_property_name(&ident.name) => {
+                p.print_quoted_property_name(ident);
+            }
             Self::StaticIdentifier(ident) => ident.print(p, ctx),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
             Self::StringLiteral(s) => p.print_string_literal(s, /* allow_backtick */ false),
@@ -1994,11 +2020,8 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
         };
         if !omit_key {
             match &self.name {
-                PropertyKey::StaticIdentifier(ident) => {
-                    ident.print(p, ctx);
-                }
-                PropertyKey::PrivateIdentifier(ident) => {
-                    ident.print(p, ctx);
+                PropertyKey::StaticIdentifier(_) | PropertyKey::PrivateIdentifier(_) => {
+                    self.name.print(p, ctx);
                 }
                 key => {
                     if self.computed {
@@ -2217,7 +2240,9 @@ impl GenExpr for TSSatisfiesExpression<'_> {
 
 impl GenExpr for TSNonNullExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.wrap(matches!(self.expression, Expression::ParenthesizedExpression(_)), |p| {
+        let wrap = !p.options.preserve_parens
+            && matches!(self.expression, Expression::ParenthesizedExpression(_));
+        p.wrap(wrap, |p| {
             self.expression.print_expr(p, precedence, ctx);
         });
         p.print_ascii_byte(b'!');
@@ -2936,7 +2961,7 @@ impl Gen for TSClassImplements<'_> {
 
 impl Gen for TSTypeParameterDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        let is_multi_line = self.params.len() >= 2;
+        let is_multi_line = self.params.len() >= 2 && !p.options.minify;
         p.print_ascii_byte(b'<');
         if is_multi_line {
             p.indent();
@@ -3786,3 +3811,30 @@ impl GenExpr for V8IntrinsicExpression<'_> {
         });
     }
 }
+
+impl Gen for TopicReference {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        p.print_ascii_byte(b'%');
+    }
+}
+
+impl GenExpr for PipelineExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        p.wrap(precedence >= Precedence::Assign, |p| {
+            p.add_source_mapping(self.span);
+            self.head.print_expr(p, Precedence::Conditional, ctx);
+            p.print_soft_space();
+            p.print_str("|>");
+            p.print_soft_space();
+            // `|>` nests to the right, and the other expressions with the precedence of `=>`
+            // must be parenthesized in the body
+            let precedence = if matches!(self.body, Expression::PipelineExpression(_)) {
+                Precedence::Comma
+            } else {
+                Precedence::Conditional
+            };
+            self.body.print_expr(p, precedence, ctx);
+        });
+    }
+}
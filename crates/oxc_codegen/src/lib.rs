@@ -10,11 +10,14 @@ use std::{cmp, slice};
 use oxc_data_structures::pointer_ext::PointerExt;
 
 mod binary_expr_visitor;
+mod chunks;
 mod comment;
 mod context;
 mod r#gen;
 mod operator;
 mod options;
+#[cfg(feature = "round_trip")]
+mod round_trip;
 mod sourcemap_builder;
 mod str;
 
@@ -30,6 +33,8 @@ use oxc_syntax::{
     precedence::Precedence,
 };
 
+#[cfg(feature = "round_trip")]
+pub use crate::round_trip::{RoundTripError, check_round_trip, check_source_round_trip};
 use crate::{
     binary_expr_visitor::BinaryExpressionVisitor,
     comment::CommentsMap,
@@ -45,6 +50,7 @@ pub use crate::{
 
 // Re-export `IndentChar` from `oxc_data_structures`
 pub use oxc_data_structures::code_buffer::IndentChar;
+pub use oxc_syntax::identifier::PropertyNameTarget;
 
 /// Output from [`Codegen::build`]
 #[non_exhaustive]
@@ -387,7 +393,7 @@ impl<'a> Codegen<'a> {
 
     #[inline]
     fn print_soft_newline(&mut self) {
-        if !self.options.minify {
+        if self.options.print_line_breaks() {
             self.print_ascii_byte(b'\n');
         }
     }
@@ -438,14 +444,14 @@ impl<'a> Codegen<'a> {
 
     #[inline]
     fn indent(&mut self) {
-        if !self.options.minify {
+        if self.options.print_line_breaks() {
             self.indent += 1;
         }
     }
 
     #[inline]
     fn dedent(&mut self) {
-        if !self.options.minify {
+        if self.options.print_line_breaks() {
             self.indent -= 1;
         }
     }
@@ -463,7 +469,7 @@ impl<'a> Codegen<'a> {
 
     #[inline]
     fn print_indent(&mut self) {
-        if self.options.minify {
+        if !self.options.print_line_breaks() {
             return;
         }
         if self.print_next_indent_as_space {
@@ -476,10 +482,10 @@ impl<'a> Codegen<'a> {
 
     #[inline]
     fn print_semicolon_after_statement(&mut self) {
-        if self.options.minify {
-            self.needs_semicolon = true;
-        } else {
+        if self.options.print_line_breaks() {
             self.print_str(";\n");
+        } else {
+            self.needs_semicolon = true;
         }
     }
 
@@ -548,7 +554,7 @@ impl<'a> Codegen<'a> {
                 self.print_soft_newline();
             }
             stmt => {
-                if need_space && self.options.minify {
+                if need_space && !self.options.print_line_breaks() {
                     self.print_hard_space();
                 }
                 self.print_next_indent_as_space = true;
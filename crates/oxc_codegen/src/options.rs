@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use oxc_data_structures::code_buffer::{DEFAULT_INDENT_WIDTH, IndentChar};
+use oxc_syntax::identifier::PropertyNameTarget;
 
 /// Codegen Options.
 #[derive(Debug, Clone)]
@@ -15,6 +16,15 @@ pub struct CodegenOptions {
     /// Default is `false`.
     pub minify: bool,
 
+    /// Keep a line break after each statement and the indentation of blocks when minifying.
+    ///
+    /// All other whitespace is removed, and the output is minified otherwise. This produces
+    /// "readable minified" code, which can still be diffed line by line and debugged without
+    /// source maps. Only has an effect with [`CodegenOptions::minify`].
+    ///
+    /// Default is `false`.
+    pub keep_line_breaks: bool,
+
     /// Print comments?
     ///
     /// At present, only some leading comments are preserved.
@@ -38,6 +48,25 @@ pub struct CodegenOptions {
     ///
     /// Default is `1`.
     pub indent_width: usize,
+
+    /// Oldest ECMAScript edition which has to parse the output.
+    ///
+    /// Property names which are not safe for this edition are quoted,
+    /// e.g. `a.default` is printed as `a["default"]` for [`PropertyNameTarget::ES3`].
+    ///
+    /// Default is [`PropertyNameTarget::ES2015`].
+    pub property_name_target: PropertyNameTarget,
+
+    /// Print the parentheses of [`ParenthesizedExpression`]s, even where they are not needed.
+    ///
+    /// Parse with `ParseOptions::preserve_parens` to keep the parentheses of the source text in
+    /// the AST. Without this option, parentheses are only printed where they are needed by the
+    /// precedence of operators.
+    ///
+    /// Default is `false`.
+    ///
+    /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
+    pub preserve_parens: bool,
 }
 
 impl Default for CodegenOptions {
@@ -45,10 +74,13 @@ impl Default for CodegenOptions {
         Self {
             single_quote: false,
             minify: false,
+            keep_line_breaks: false,
             comments: CommentOptions::default(),
             source_map_path: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
+            property_name_target: PropertyNameTarget::ES2015,
+            preserve_parens: false,
         }
     }
 }
@@ -59,13 +91,28 @@ impl CodegenOptions {
         Self {
             single_quote: false,
             minify: true,
+            keep_line_breaks: false,
             comments: CommentOptions::disabled(),
             source_map_path: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
+            property_name_target: PropertyNameTarget::ES2015,
+            preserve_parens: false,
         }
     }
 
+    /// Minify whitespace and remove comments, but keep a line break after each statement and
+    /// the indentation of blocks. See [`CodegenOptions::keep_line_breaks`].
+    pub fn readable_minify() -> Self {
+        Self { keep_line_breaks: true, ..Self::minify() }
+    }
+
+    /// Whether line breaks and indentation are printed.
+    #[inline]
+    pub(crate) fn print_line_breaks(&self) -> bool {
+        !self.minify || self.keep_line_breaks
+    }
+
     #[inline]
     pub(crate) fn print_normal_comment(&self) -> bool {
         self.comments.normal
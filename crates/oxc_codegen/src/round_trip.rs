@@ -0,0 +1,125 @@
+//! Round-trip checks: print an AST, parse the printed code, and compare the ASTs.
+
+use std::fmt;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{ContentEq, SourceType};
+
+use crate::{Codegen, CodegenOptions};
+
+/// Error from [`check_round_trip`] and [`check_source_round_trip`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundTripError {
+    /// The source text has syntax errors.
+    Parse(Vec<String>),
+    /// The printed code has syntax errors.
+    Reparse {
+        /// The printed code.
+        code: String,
+        /// Messages of the syntax errors.
+        errors: Vec<String>,
+    },
+    /// The AST of the printed code is different from the original AST.
+    Mismatch {
+        /// The printed code.
+        code: String,
+        /// Index of the first top-level statement which is different,
+        /// `None` if the hashbang or the directives are different.
+        statement: Option<usize>,
+    },
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(errors) => {
+                write!(f, "Failed to parse the source text: {}", errors.join(", "))
+            }
+            Self::Reparse { errors, .. } => {
+                write!(f, "Failed to parse the printed code: {}", errors.join(", "))
+            }
+            Self::Mismatch { statement: Some(index), .. } => {
+                write!(f, "The printed code is different at top-level statement {index}")
+            }
+            Self::Mismatch { statement: None, .. } => {
+                write!(f, "The printed code has different hashbang or directives")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Print `program` with `options`, parse the printed code, and check that its AST is
+/// structurally equal to `program`, ignoring spans and comments.
+///
+/// Use this to make sure that printing a transformed AST, e.g. in a codemod, doesn't change the
+/// meaning of the code.
+///
+/// The printed code is parsed with `ParseOptions::preserve_parens` set to
+/// [`CodegenOptions::preserve_parens`], so parse `program` with the same value.
+///
+/// Returns the printed code.
+///
+/// # Errors
+///
+/// * [`RoundTripError::Reparse`] if the printed code has syntax errors.
+/// * [`RoundTripError::Mismatch`] if the AST of the printed code is different.
+pub fn check_round_trip(
+    program: &Program<'_>,
+    options: CodegenOptions,
+) -> Result<String, RoundTripError> {
+    let parse_options =
+        ParseOptions { preserve_parens: options.preserve_parens, ..ParseOptions::default() };
+    let code = Codegen::new().with_options(options).build(program).code;
+
+    let allocator = Allocator::default();
+    let ret =
+        Parser::new(&allocator, &code, program.source_type).with_options(parse_options).parse();
+    if !ret.errors.is_empty() {
+        let errors = ret.errors.iter().map(ToString::to_string).collect();
+        return Err(RoundTripError::Reparse { code, errors });
+    }
+
+    let printed = &ret.program;
+    if !program.hashbang.content_eq(&printed.hashbang)
+        || !program.directives.content_eq(&printed.directives)
+    {
+        return Err(RoundTripError::Mismatch { code, statement: None });
+    }
+    let statement =
+        program.body.iter().zip(&printed.body).position(|(a, b)| !a.content_eq(b)).or_else(|| {
+            (program.body.len() != printed.body.len())
+                .then_some(program.body.len().min(printed.body.len()))
+        });
+    match statement {
+        Some(statement) => Err(RoundTripError::Mismatch { code, statement: Some(statement) }),
+        None => Ok(code),
+    }
+}
+
+/// Parse `source_text` and check that it round-trips, see [`check_round_trip`].
+///
+/// The source text is parsed with `ParseOptions::preserve_parens` set to
+/// [`CodegenOptions::preserve_parens`].
+///
+/// # Errors
+///
+/// * [`RoundTripError::Parse`] if the source text has syntax errors.
+/// * The errors of [`check_round_trip`].
+pub fn check_source_round_trip(
+    source_text: &str,
+    source_type: SourceType,
+    options: CodegenOptions,
+) -> Result<String, RoundTripError> {
+    let parse_options =
+        ParseOptions { preserve_parens: options.preserve_parens, ..ParseOptions::default() };
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).with_options(parse_options).parse();
+    if !ret.errors.is_empty() {
+        return Err(RoundTripError::Parse(ret.errors.iter().map(ToString::to_string).collect()));
+    }
+    check_round_trip(&ret.program, options)
+}
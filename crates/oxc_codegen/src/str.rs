@@ -1,10 +1,10 @@
 use std::slice;
 
-use oxc_ast::ast::StringLiteral;
+use oxc_ast::ast::{IdentifierName, StringLiteral};
 use oxc_data_structures::{
     assert_unchecked, pointer_ext::PointerExt, slice_iter_ext::SliceIterExt,
 };
-use oxc_syntax::identifier::{LS, NBSP, PS};
+use oxc_syntax::identifier::{LS, NBSP, PS, PropertyNameTarget};
 
 use crate::Codegen;
 
@@ -36,6 +36,10 @@ impl Codegen<'_> {
         // Don't print opening quote now, because we don't know what it is yet.
         //
         // If not in `minify` mode, print the quote requested in options.
+        // The string is printed in chunks between escapes. Reserve space for it all up front, so the
+        // buffer grows at most once per string, instead of possibly once per chunk.
+        self.code.reserve(s.value.len() + 2);
+
         let quote = if self.options.minify {
             None
         } else {
@@ -56,7 +60,14 @@ impl Codegen<'_> {
         };
 
         // Loop through bytes.
-        while let Some(b) = state.peek() {
+        loop {
+            // Skip over bytes which don't need escaping a block at a time, which is the bulk of most
+            // strings. This leaves `bytes` iterator on next byte which may need escaping,
+            // or in the last few bytes of the string.
+            state.skip_unescaped();
+
+            let Some(b) = state.peek() else { break };
+
             // Look up whether byte needs escaping
             let escape = ESCAPES.0[b as usize];
             if escape == Escape::__ {
@@ -88,6 +99,26 @@ impl Codegen<'_> {
         let quote = unsafe { state.quote.unwrap_unchecked() };
         quote.print(self);
     }
+
+    /// Whether the property name `name` has to be quoted for [`CodegenOptions::property_name_target`].
+    ///
+    /// [`CodegenOptions::property_name_target`]: crate::CodegenOptions::property_name_target
+    #[inline]
+    pub(crate) fn needs_quoted_property_name(&self, name: &str) -> bool {
+        let target = self.options.property_name_target;
+        target != PropertyNameTarget::ES2015 && !target.is_unquoted_property_name(name)
+    }
+
+    /// Print a property name as a string literal, e.g. `default` as `"default"`.
+    pub(crate) fn print_quoted_property_name(&mut self, ident: &IdentifierName<'_>) {
+        let s = StringLiteral {
+            span: ident.span,
+            value: ident.name,
+            raw: None,
+            lone_surrogates: false,
+        };
+        self.print_string_literal(&s, /* allow_backtick */ false);
+    }
 }
 
 /// String printer state.
@@ -138,6 +169,46 @@ impl PrintStringState<'_> {
         unsafe { self.bytes.advance_unchecked(count) };
     }
 
+    /// Advance the `bytes` iterator over bytes which do not need escaping, 16 bytes at a time with
+    /// SIMD on x86_64 and aarch64, then 8 bytes at a time.
+    ///
+    /// Stops on the first byte which may need escaping, or when fewer than 8 bytes remain.
+    /// Skipped bytes stay in the current chunk.
+    ///
+    /// If this stops before the end of the string because fewer than 8 bytes remain, `bytes` iterator
+    /// may be left in the middle of a Unicode character. The rest of that character are continuation
+    /// bytes, which do not need escaping, so they'll be consumed one at a time before any flush.
+    #[inline]
+    fn skip_unescaped(&mut self) {
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        while let Some(block) = self.bytes.as_slice().first_chunk::<simd::BLOCK_SIZE>() {
+            if let Some(count) = simd::find_maybe_escaped(block) {
+                // SAFETY: `count < 16`, and there are at least 16 bytes remaining.
+                // Next byte is one which may need escaping, so this leaves `bytes` on a UTF-8 char
+                // boundary. See below.
+                unsafe { self.consume_bytes_unchecked(count) };
+                return;
+            }
+            // SAFETY: There are at least 16 bytes remaining. See below about UTF-8 char boundaries.
+            unsafe { self.consume_bytes_unchecked(simd::BLOCK_SIZE) };
+        }
+
+        while let Some(word) = self.bytes.as_slice().first_chunk::<WORD_SIZE>() {
+            let matches = find_maybe_escaped(u64::from_le_bytes(*word));
+            if matches != 0 {
+                // Lowest set bit is in the first byte which may need escaping
+                let count = matches.trailing_zeros() as usize / 8;
+                // SAFETY: `count < 8`, and there are at least 8 bytes remaining.
+                // Next byte is one which may need escaping. All such bytes are ASCII or the first byte
+                // of a UTF-8 character sequence, so this leaves `bytes` on a UTF-8 char boundary.
+                unsafe { self.consume_bytes_unchecked(count) };
+                return;
+            }
+            // SAFETY: There are at least 8 bytes remaining. See above about UTF-8 char boundaries.
+            unsafe { self.consume_bytes_unchecked(WORD_SIZE) };
+        }
+    }
+
     /// Set the start of next chunk to be current position of `bytes` iterator.
     #[inline]
     fn start_chunk(&mut self) {
@@ -368,6 +439,126 @@ static ESCAPES: Aligned128<[Escape; 256]> = {
     ])
 };
 
+/// Number of bytes [`find_maybe_escaped`] checks at once.
+const WORD_SIZE: usize = size_of::<u64>();
+
+/// Bytes which may need escaping, other than ASCII control characters (`< 0x20`).
+const ESCAPE_BYTES: [u8; 9] = [b'"', b'$', b'\'', b'<', b'\\', b'`', 0xC2, 0xE2, 0xEF];
+
+// Ensure `find_maybe_escaped` finds every byte which `ESCAPES` has an `Escape` for
+const _: () = {
+    let mut b = 0;
+    while b < 256 {
+        if b >= 0x20 && !matches!(ESCAPES.0[b], Escape::__) {
+            let mut found = false;
+            let mut i = 0;
+            while i < ESCAPE_BYTES.len() {
+                found |= ESCAPE_BYTES[i] as usize == b;
+                i += 1;
+            }
+            assert!(found);
+        }
+        b += 1;
+    }
+};
+
+/// Create a `u64` with all bytes set to `b`.
+const fn splat(b: u8) -> u64 {
+    u64::from_ne_bytes([b; WORD_SIZE])
+}
+
+/// Find bytes of `word` which may need escaping, checking all 8 bytes together (SWAR).
+///
+/// Returns a mask with the top bit set in each byte which may need escaping.
+/// Bytes after the first match may be included spuriously, due to borrows in the subtractions,
+/// so only the lowest set bit is meaningful. `0` if no bytes need escaping.
+#[inline]
+fn find_maybe_escaped(word: u64) -> u64 {
+    const HIGH_BITS: u64 = splat(0x80);
+
+    /// Top bit set in each byte which is `0`.
+    #[inline]
+    fn zero_bytes(word: u64) -> u64 {
+        word.wrapping_sub(splat(0x01)) & !word & HIGH_BITS
+    }
+
+    // Bytes less than `0x20`
+    let mut matches = word.wrapping_sub(splat(0x20)) & !word & HIGH_BITS;
+    for b in ESCAPE_BYTES {
+        matches |= zero_bytes(word ^ splat(b));
+    }
+    matches
+}
+
+/// Search for bytes which may need escaping 16 bytes at a time, with SSE2, which every x86_64 CPU
+/// supports.
+#[cfg(target_arch = "x86_64")]
+mod simd {
+    use std::arch::x86_64::{
+        _mm_cmpeq_epi8, _mm_loadu_si128, _mm_min_epu8, _mm_movemask_epi8, _mm_or_si128,
+        _mm_set1_epi8,
+    };
+
+    use super::ESCAPE_BYTES;
+
+    /// Number of bytes [`find_maybe_escaped`] checks at once.
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// Index of the first byte of `block` which may need escaping. `None` if no bytes need escaping.
+    #[inline]
+    pub fn find_maybe_escaped(block: &[u8; BLOCK_SIZE]) -> Option<usize> {
+        // SAFETY: SSE2 is always available on x86_64. `block` is 16 bytes, and `_mm_loadu_si128`
+        // does not require alignment.
+        let mask = unsafe {
+            let bytes = _mm_loadu_si128(block.as_ptr().cast());
+            // Bytes less than `0x20`, i.e. `min(byte, 0x1F) == byte`
+            let mut matches = _mm_cmpeq_epi8(_mm_min_epu8(bytes, _mm_set1_epi8(0x1F)), bytes);
+            for b in ESCAPE_BYTES {
+                let b = _mm_set1_epi8(i8::from_ne_bytes([b]));
+                matches = _mm_or_si128(matches, _mm_cmpeq_epi8(bytes, b));
+            }
+            // Top bit of each byte of `matches`, one bit per byte
+            _mm_movemask_epi8(matches)
+        };
+        (mask != 0).then(|| mask.trailing_zeros() as usize)
+    }
+}
+
+/// Search for bytes which may need escaping 16 bytes at a time, with NEON, which every aarch64 CPU
+/// supports.
+#[cfg(target_arch = "aarch64")]
+mod simd {
+    use std::arch::aarch64::{
+        vceqq_u8, vcleq_u8, vdupq_n_u8, vget_lane_u64, vld1q_u8, vorrq_u8, vreinterpret_u64_u8,
+        vreinterpretq_u16_u8, vshrn_n_u16,
+    };
+
+    use super::ESCAPE_BYTES;
+
+    /// Number of bytes [`find_maybe_escaped`] checks at once.
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// Index of the first byte of `block` which may need escaping. `None` if no bytes need escaping.
+    #[inline]
+    pub fn find_maybe_escaped(block: &[u8; BLOCK_SIZE]) -> Option<usize> {
+        // SAFETY: NEON is always available on aarch64. `block` is 16 bytes, and `vld1q_u8`
+        // does not require alignment.
+        let mask = unsafe {
+            let bytes = vld1q_u8(block.as_ptr());
+            // Bytes less than `0x20`
+            let mut matches = vcleq_u8(bytes, vdupq_n_u8(0x1F));
+            for b in ESCAPE_BYTES {
+                matches = vorrq_u8(matches, vceqq_u8(bytes, vdupq_n_u8(b)));
+            }
+            // NEON has no `movemask`. Shift each pair of bytes right by 4 and narrow them to a byte,
+            // which leaves 4 bits of `matches` per byte of `block` in a `u64`.
+            let narrowed = vshrn_n_u16::<4>(vreinterpretq_u16_u8(matches));
+            vget_lane_u64::<0>(vreinterpret_u64_u8(narrowed))
+        };
+        (mask != 0).then(|| mask.trailing_zeros() as usize / 4)
+    }
+}
+
 type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
 
 /// Byte handlers.
@@ -0,0 +1,66 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn chunks(source_text: &str, source_type: SourceType, max_chunk_size: usize) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    Codegen::new().build_script_chunks(&ret.program, max_chunk_size)
+}
+
+#[test]
+fn fits_in_single_chunk() {
+    let source_text = "#!/usr/bin/env node\n\"use strict\";\nfoo();\nfunction foo() {}\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::cjs()).parse();
+    let expected = Codegen::new().build(&ret.program).code;
+    assert_eq!(chunks(source_text, SourceType::cjs(), 1000), [expected]);
+}
+
+#[test]
+fn split_script() {
+    let source_text = "\"use strict\";\nfoo(bar);\nvar bar = 1;\nlet baz = 2;\n\"not a directive\";\nif (baz) { for (var i of x) {} }\nfunction foo() { var local; }\n";
+    assert_eq!(
+        chunks(source_text, SourceType::cjs(), 40),
+        [
+            "\"use strict\";\nvar bar, i;\nfunction foo() {\n\tvar local;\n}\n",
+            "\"use strict\";\nfoo(bar);\nvar bar = 1;\n",
+            "\"use strict\";\nlet baz = 2;\n",
+            "\"use strict\";\n(\"not a directive\");\n",
+            "\"use strict\";\nif (baz) {\n\tfor (var i of x) {}\n}\n",
+        ]
+    );
+}
+
+#[test]
+fn sloppy_block_functions() {
+    let source_text = "a();\n{ function f() {} }\n";
+    assert_eq!(
+        chunks(source_text, SourceType::cjs(), 5),
+        ["var f;\na();\n", "{\n\tfunction f() {}\n}\n"]
+    );
+    // Block-level functions are block scoped in strict mode.
+    let source_text = "\"use strict\";\na();\n{ function f() {} }\n";
+    assert_eq!(
+        chunks(source_text, SourceType::cjs(), 5),
+        ["\"use strict\";\na();\n", "\"use strict\";\n{\n\tfunction f() {}\n}\n"]
+    );
+}
+
+#[test]
+fn minify() {
+    let source_text = "a();\nvar b = 1;\nc();\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::cjs()).parse();
+    let chunks =
+        Codegen::new().with_options(CodegenOptions::minify()).build_script_chunks(&ret.program, 12);
+    assert_eq!(chunks, ["var b;a();", "var b=1;c();"]);
+}
+
+#[test]
+fn module_is_not_split() {
+    let source_text = "import a from 'a';\na();\nexport const b = 1;\n";
+    assert_eq!(chunks(source_text, SourceType::mjs(), 1).len(), 1);
+}
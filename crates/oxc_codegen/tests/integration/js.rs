@@ -1,4 +1,4 @@
-use oxc_codegen::{CodegenOptions, IndentChar};
+use oxc_codegen::{CodegenOptions, IndentChar, PropertyNameTarget};
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -165,6 +165,33 @@ fn unicode_escape() {
     test("console.log(\"\\uD800\\uD801\")", "console.log(\"\\ud800\\ud801\");\n");
 }
 
+#[test]
+fn string_escape_in_long_string() {
+    // Strings are searched for characters to escape 16 bytes at a time with SIMD, then 8 bytes
+    // at a time. Check escapes are found at every position within and across those blocks.
+    let escapes = [
+        ("\\n", "\\n"),
+        ("\\\"", "\\\""),
+        ("'", "'"),
+        ("\\\\", "\\\\"),
+        ("\\t", "\t"),
+        ("\\0", "\\0"),
+        ("\\xA0", "\\xA0"),
+        ("\\u2028", "\\u2028"),
+        ("</script", "<\\/script"),
+    ];
+    for (source, expected) in escapes {
+        for i in 0..40 {
+            let prefix = "a".repeat(i);
+            let suffix = "é".repeat(40 - i);
+            test(
+                &format!("x = \"{prefix}{source}{suffix}\";"),
+                &format!("x = \"{prefix}{expected}{suffix}\";\n"),
+            );
+        }
+    }
+}
+
 #[test]
 fn regex() {
     fn test_all(source: &str, expect: &str, minify: &str) {
@@ -606,6 +633,20 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn pipeline() {
+    let parse_opts = oxc_parser::ParseOptions {
+        experimental_pipeline: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    test_with_parse_options("a |> f(%) |> g(%, 1)", "a |> f(%) |> g(%, 1);\n", parse_opts);
+    test_with_parse_options("x = a |> (% ? b : c)", "x = a |> (% ? b : c);\n", parse_opts);
+    test_with_parse_options("a |> % == 1 |> [%]", "a |> % == 1 |> [%];\n", parse_opts);
+    test_with_parse_options("(a |> f(%)) |> g(%)", "(a |> f(%)) |> g(%);\n", parse_opts);
+    test_with_parse_options("(a |> %).b ?? c", "(a |> %).b ?? c;\n", parse_opts);
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
@@ -648,3 +689,66 @@ fn indentation() {
         },
     );
 }
+
+#[test]
+fn readable_minify() {
+    let options = CodegenOptions::readable_minify();
+    test_options(
+        "function foo(a, b) { if (a) { return [1, 2, 3]; } else return { a, b }; }\nfoo(1, 2);",
+        "function foo(a,b){\n\tif(a){\n\t\treturn[1,2,3];\n\t}else return{a,b};\n}\nfoo(1,2);\n",
+        options.clone(),
+    );
+    test_options(
+        "class A { constructor() { this.x = 1 } method() { return x } }",
+        "class A{\n\tconstructor(){\n\t\tthis.x=1;\n\t}\n\tmethod(){\n\t\treturn x;\n\t}\n}\n",
+        options.clone(),
+    );
+    test_options(
+        "const f = (a) => { const b = a + 1; return b; }; let x = 1, y = 2",
+        "const f=a=>{\n\tconst b=a+1;\n\treturn b;\n};\nlet x=1,y=2;\n",
+        options.clone(),
+    );
+    test_options(
+        "if (a) b(); else if (c) d(); else e()",
+        "if(a) b();\nelse if(c) d();\nelse e();\n",
+        options,
+    );
+}
+
+#[test]
+fn property_name_target() {
+    let es3 = CodegenOptions {
+        property_name_target: PropertyNameTarget::ES3,
+        ..CodegenOptions::default()
+    };
+    test_options("a.default; a?.int;", "a[\"default\"];\na?.[\"int\"];\n", es3.clone());
+    test_options(
+        "x = { default: 1, class() {}, get if() {} };",
+        "x = {\n\t\"default\": 1,\n\t\"class\"() {},\n\tget \"if\"() {}\n};\n",
+        es3.clone(),
+    );
+    test_options("({ default: x } = y);", "({\"default\": x} = y);\n", es3.clone());
+    test_options("var { default: x } = y;", "var { \"default\": x } = y;\n", es3.clone());
+    test_options("a.let; a.ñ; a.ᴗ;", "a.let;\na.ñ;\na[\"ᴗ\"];\n", es3);
+
+    let es5 = CodegenOptions {
+        property_name_target: PropertyNameTarget::ES5,
+        ..CodegenOptions::default()
+    };
+    test_options("a.default; x = { ᴗ: 1 };", "a.default;\nx = { \"ᴗ\": 1 };\n", es5);
+    test_same("a.default;\na.ᴗ;\n");
+}
+
+#[test]
+fn preserve_parens() {
+    let options = CodegenOptions { preserve_parens: true, ..CodegenOptions::default() };
+    test_options("(a);", "(a);\n", options.clone());
+    test_options("((a + b)) * c;", "((a + b)) * c;\n", options.clone());
+    test_options("x = (1, 2);", "x = (1, 2);\n", options.clone());
+    test_options("(a?.b).c;", "(a?.b).c;\n", options.clone());
+    test_options("new (a.b())();", "new (a.b())();\n", options.clone());
+    test_options("for ((a in b);;);", "for ((a in b);;);\n", options.clone());
+    test_options("(function() {})();", "(function() {})();\n", options.clone());
+    test_options("let x = (a)!;", "let x = (a)!;\n", options);
+    test("((a + b)) * c;", "(a + b) * c;\n");
+}
@@ -1,7 +1,10 @@
 #![expect(clippy::missing_panics_doc, clippy::literal_string_with_formatting_args)]
+pub mod chunks;
 pub mod comments;
 pub mod esbuild;
 pub mod js;
+#[cfg(feature = "round_trip")]
+pub mod round_trip;
 pub mod sourcemap;
 pub mod ts;
 
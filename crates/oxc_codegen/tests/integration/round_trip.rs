@@ -0,0 +1,45 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodegenOptions, RoundTripError, check_round_trip, check_source_round_trip};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
+
+#[test]
+fn check() {
+    let options = CodegenOptions { preserve_parens: true, ..CodegenOptions::default() };
+    for source_text in [
+        "#!/usr/bin/env node\n'use strict';\n(a) + ((b));",
+        "const x = (1, 2), y = (a?.b).c;",
+        "for ((a in b);;);\nnew (a.b())();",
+        "label: { break label; }\nclass A extends (B, C) {}",
+    ] {
+        assert!(
+            check_source_round_trip(source_text, SourceType::mjs(), options.clone()).is_ok(),
+            "{source_text}"
+        );
+        assert!(
+            check_source_round_trip(source_text, SourceType::mjs(), CodegenOptions::minify())
+                .is_ok(),
+            "{source_text}"
+        );
+    }
+
+    assert!(matches!(
+        check_source_round_trip("let x = ;", SourceType::mjs(), CodegenOptions::default()),
+        Err(RoundTripError::Parse(_))
+    ));
+
+    // Reparsing with `preserve_parens` finds parentheses which are not in the original AST
+    let allocator = Allocator::default();
+    let source_text = "a;\n(b + c) * d;";
+    let parse_options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+    let ret =
+        Parser::new(&allocator, source_text, SourceType::mjs()).with_options(parse_options).parse();
+    assert!(check_round_trip(&ret.program, CodegenOptions::default()).is_ok());
+    assert_eq!(
+        check_round_trip(&ret.program, options),
+        Err(RoundTripError::Mismatch {
+            code: "a;\n(b + c) * d;\n".to_string(),
+            statement: Some(1)
+        })
+    );
+}
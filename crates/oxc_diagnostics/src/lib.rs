@@ -63,7 +63,7 @@ pub type Severity = miette::Severity;
 
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
-use miette::{Diagnostic, SourceCode};
+use miette::{Diagnostic, SourceCode, SourceSpan};
 pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
 
 /// Describes an error or warning that occurred.
@@ -122,6 +122,18 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    pub fixes: Vec<DiagnosticFix>,
+}
+
+/// A replacement of source code which fixes the problem of an [`OxcDiagnostic`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    /// The range of source code to replace.
+    pub span: SourceSpan,
+    /// The code to insert in place of `span`.
+    pub content: Cow<'static, str>,
+    /// A brief description of the fix.
+    pub message: Option<Cow<'static, str>>,
 }
 
 impl Display for OxcDiagnostic {
@@ -178,6 +190,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -192,6 +205,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -332,10 +346,73 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add fixes for the problem of this diagnostic, e.g. to report them in SARIF output.
+    pub fn with_fixes<T: IntoIterator<Item = DiagnosticFix>>(mut self, fixes: T) -> Self {
+        self.inner.fixes = fixes.into_iter().collect();
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from, if any.
+    pub fn downcast_ref(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<WithSourceCode>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
+    }
+}
+
+/// An [`OxcDiagnostic`] with source code.
+///
+/// Unlike the wrapper of [`miette::Report::with_source_code`], this one can be downcast, so
+/// reporters can get at the data of the diagnostic, see [`OxcDiagnostic::downcast_ref`].
+struct WithSourceCode {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
     }
 }
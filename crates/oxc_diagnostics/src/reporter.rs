@@ -1,5 +1,8 @@
 //! [Reporters](DiagnosticReporter) for rendering and writing diagnostics.
 
+use std::path::{Component, Path, PathBuf};
+
+use cow_utils::CowUtils;
 use miette::SourceSpan;
 
 use crate::{Error, Severity};
@@ -38,6 +41,17 @@ use crate::{Error, Severity};
 /// }
 /// ```
 pub trait DiagnosticReporter {
+    /// Lifecycle hook that gets called before any diagnostics are reported.
+    fn start(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Lifecycle hook that gets called before the diagnostics of the file at `path` are
+    /// rendered. Files without diagnostics are not reported.
+    fn start_file(&mut self, _path: &Path) -> Option<String> {
+        None
+    }
+
     /// Lifecycle hook that gets called when no more diagnostics will be reported.
     ///
     /// Some reporters (e.g. `JSONReporter`) store all diagnostics in memory, then write them
@@ -65,14 +79,13 @@ pub struct DiagnosticResult {
     /// Total number of errors received
     errors_count: usize,
 
-    /// Did the threshold for warnings exceeded the max_warnings?
-    /// ToDo: We giving the input from outside, let the owner calculate the result
-    max_warnings_exceeded: bool,
+    /// Maximum number of warnings allowed, if any.
+    max_warnings: Option<usize>,
 }
 
 impl DiagnosticResult {
-    pub fn new(warnings_count: usize, errors_count: usize, max_warnings_exceeded: bool) -> Self {
-        Self { warnings_count, errors_count, max_warnings_exceeded }
+    pub fn new(warnings_count: usize, errors_count: usize, max_warnings: Option<usize>) -> Self {
+        Self { warnings_count, errors_count, max_warnings }
     }
 
     /// Get the number of warning-level diagnostics received.
@@ -85,9 +98,19 @@ impl DiagnosticResult {
         self.errors_count
     }
 
+    /// Get the maximum number of warnings allowed, if any.
+    pub fn max_warnings(&self) -> Option<usize> {
+        self.max_warnings
+    }
+
     /// Did the threshold for warnings exceeded the max_warnings?
     pub fn max_warnings_exceeded(&self) -> bool {
-        self.max_warnings_exceeded
+        self.warnings_over_max() > 0
+    }
+
+    /// Get the number of warnings above max_warnings, or `0` if it's not exceeded.
+    pub fn warnings_over_max(&self) -> usize {
+        self.max_warnings.map_or(0, |max_warnings| self.warnings_count.saturating_sub(max_warnings))
     }
 }
 
@@ -157,3 +180,197 @@ impl Info {
         Self { start, end, filename, message, severity, rule_id }
     }
 }
+
+/// A fingerprint of a diagnostic, to recognize the same problem across runs and commits.
+///
+/// The fingerprint is a hash of the rule, the path of the file, the message and the labeled code
+/// with whitespace normalized, so it does not change when the code moves to another line or is
+/// reindented. Identical problems in the same file have the same fingerprint, [`fingerprints`]
+/// tells them apart.
+///
+/// The path of the file is relative to the [`ProjectRoot`], so the fingerprint does not depend
+/// on the directory the tool was run in. Paths are compared with `/` separators and without `.`
+/// components, so the fingerprint is the same on all platforms.
+///
+/// Fingerprints are stored by external tools, so they must not depend on the platform or the
+/// version of Rust, which rules out [`std::hash::DefaultHasher`].
+pub fn fingerprint(diagnostic: &Error, project: &ProjectRoot) -> String {
+    let Info { message, rule_id, filename, .. } = Info::new(diagnostic);
+    let filename = project.relative_path(&filename);
+
+    let mut hasher = Fnv1a::default();
+    hasher.write(rule_id.as_deref().unwrap_or_default().as_bytes());
+    hasher.write(&[0]);
+    hasher.write(filename.as_bytes());
+    hasher.write(&[0]);
+    hasher.write(message.as_bytes());
+    if let (Some(source), Some(label)) =
+        (diagnostic.source_code(), diagnostic.labels().and_then(|mut labels| labels.next()))
+    {
+        if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
+            for word in String::from_utf8_lossy(span_content.data()).split_whitespace() {
+                hasher.write(&[0]);
+                hasher.write(word.as_bytes());
+            }
+        }
+    }
+
+    format!("{:016x}", hasher.0)
+}
+
+/// The [`fingerprint`]s of `diagnostics`, in the same order.
+///
+/// Identical problems in the same file would have the same fingerprint, so all but the first of
+/// them, ordered by position, get the index of their occurrence appended, e.g. `316d6057c03711fb:1`.
+/// A problem which only occurs once keeps the plain fingerprint.
+pub fn fingerprints(diagnostics: &[Error], project: &ProjectRoot) -> Vec<String> {
+    let mut fingerprints: Vec<String> =
+        diagnostics.iter().map(|diagnostic| fingerprint(diagnostic, project)).collect();
+
+    let mut order: Vec<usize> = (0..diagnostics.len()).collect();
+    order.sort_by_key(|&i| {
+        let offset = diagnostics[i].labels().and_then(|mut labels| labels.next());
+        (&fingerprints[i], offset.map(|label| label.offset()))
+    });
+    let mut occurrences = vec![0; diagnostics.len()];
+    for pair in order.windows(2) {
+        if fingerprints[pair[0]] == fingerprints[pair[1]] {
+            occurrences[pair[1]] = occurrences[pair[0]] + 1;
+        }
+    }
+
+    for (fingerprint, occurrence) in fingerprints.iter_mut().zip(occurrences) {
+        if occurrence > 0 {
+            fingerprint.push(':');
+            fingerprint.push_str(&occurrence.to_string());
+        }
+    }
+    fingerprints
+}
+
+/// The root of the project, which paths in [`fingerprint`]s are relative to.
+///
+/// The default is the working directory, so paths are used as they are.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectRoot {
+    /// The working directory, which paths of diagnostics are relative to.
+    pub cwd: PathBuf,
+    /// The root of the project, e.g. the directory of its configuration file.
+    pub root: PathBuf,
+}
+
+impl ProjectRoot {
+    pub fn new(cwd: PathBuf, root: PathBuf) -> Self {
+        Self { cwd, root }
+    }
+
+    /// The path of a file relative to the project root, with `/` separators and without `.` and
+    /// `..` components. `filename` is relative to the working directory. Files outside of the
+    /// project keep `filename`.
+    fn relative_path(&self, filename: &str) -> String {
+        let filename = filename.cow_replace('\\', "/");
+        let path = normalize(&self.cwd.join(&*filename));
+        let root = normalize(&self.root);
+        let Ok(path) = path.strip_prefix(&root) else {
+            return normalize(Path::new(&*filename)).to_string_lossy().into_owned();
+        };
+        let components: Vec<_> = path.iter().map(|component| component.to_string_lossy()).collect();
+        components.join("/")
+    }
+}
+
+/// Remove `.` and `..` components of `path` without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(normalized.components().next_back(), Some(Component::Normal(_))) =>
+            {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// 64-bit FNV-1a hash.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LabeledSpan, NamedSource, OxcDiagnostic};
+
+    use super::{ProjectRoot, fingerprint, fingerprints};
+
+    fn debugger(source_text: &str, offset: usize) -> crate::Error {
+        debugger_in("src/test.js", source_text, offset)
+    }
+
+    fn debugger_in(path: &str, source_text: &str, offset: usize) -> crate::Error {
+        OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(LabeledSpan::underline((offset, 9)))
+            .with_source_code(NamedSource::new(path, source_text.to_string()))
+    }
+
+    #[test]
+    fn fingerprint_is_stable() {
+        let project = ProjectRoot::default();
+        let expected = fingerprint(&debugger("debugger;", 0), &project);
+        // Moved to another line and reindented
+        assert_eq!(fingerprint(&debugger("\n\n    debugger;", 6), &project), expected);
+        // Other offending code
+        assert_ne!(fingerprint(&debugger("debugger ;", 0), &project), expected);
+        // Same path on another platform
+        assert_eq!(fingerprint(&debugger_in("src\\test.js", "debugger;", 0), &project), expected);
+        assert_eq!(fingerprint(&debugger_in("./src/test.js", "debugger;", 0), &project), expected);
+        // Other file
+        assert_ne!(fingerprint(&debugger_in("src/other.js", "debugger;", 0), &project), expected);
+    }
+
+    #[test]
+    fn fingerprint_is_relative_to_project_root() {
+        let project = ProjectRoot::default();
+        let expected = fingerprint(&debugger("debugger;", 0), &project);
+        let in_project = |path: &str, cwd: &str| {
+            let project = ProjectRoot::new(cwd.into(), "/project".into());
+            fingerprint(&debugger_in(path, "debugger;", 0), &project)
+        };
+        assert_eq!(in_project("src/test.js", "/project"), expected);
+        assert_eq!(in_project("test.js", "/project/src"), expected);
+        assert_eq!(in_project("../src/test.js", "/project/lib"), expected);
+        assert_eq!(in_project("project/src/test.js", "/"), expected);
+        // Outside of the project
+        assert_ne!(in_project("../src/test.js", "/project"), expected);
+    }
+
+    #[test]
+    fn fingerprints_of_identical_problems() {
+        let project = ProjectRoot::default();
+        let source_text = "debugger;\ndebugger;\ndebug ger;";
+        let diagnostics =
+            [debugger(source_text, 10), debugger(source_text, 0), debugger(source_text, 20)];
+        let expected = fingerprint(&diagnostics[1], &project);
+        assert_eq!(
+            fingerprints(&diagnostics, &project),
+            [format!("{expected}:1"), expected, fingerprint(&diagnostics[2], &project)]
+        );
+    }
+}
@@ -61,6 +61,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Report files sorted by path instead of in the order they are received
+    sorted: bool,
+
     receiver: DiagnosticReceiver,
 }
 
@@ -69,7 +72,17 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> (Self, DiagnosticSender) {
         let (sender, receiver) = mpsc::channel();
-        (Self { reporter, quiet: false, silent: false, max_warnings: None, receiver }, sender)
+        (
+            Self {
+                reporter,
+                quiet: false,
+                silent: false,
+                max_warnings: None,
+                sorted: false,
+                receiver,
+            },
+            sender,
+        )
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -108,10 +121,14 @@ impl DiagnosticService {
         self
     }
 
-    /// Check if the max warning threshold, as set by
-    /// [`with_max_warnings`](DiagnosticService::with_max_warnings), has been exceeded.
-    fn max_warnings_exceeded(&self, warnings_count: usize) -> bool {
-        self.max_warnings.is_some_and(|max_warnings| warnings_count > max_warnings)
+    /// Set to `true` to report the diagnostics of files sorted by their path once all of them are
+    /// received, so that the output does not depend on the order in which jobs finish.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn with_sorted(mut self, yes: bool) -> Self {
+        self.sorted = yes;
+        self
     }
 
     /// Wrap [diagnostics] with the source code and path, converting them into [Error]s.
@@ -156,7 +173,31 @@ impl DiagnosticService {
         let mut warnings_count: usize = 0;
         let mut errors_count: usize = 0;
 
-        while let Ok((path, diagnostics)) = self.receiver.recv() {
+        if let Some(start_output) = self.reporter.start() {
+            writer
+                .write_all(start_output.as_bytes())
+                .or_else(Self::check_for_writer_error)
+                .unwrap();
+        }
+
+        let messages: Box<dyn Iterator<Item = DiagnosticTuple>> = if self.sorted {
+            let mut messages = self.receiver.iter().collect::<Vec<_>>();
+            messages.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Box::new(messages.into_iter())
+        } else {
+            Box::new(self.receiver.iter())
+        };
+
+        for (path, diagnostics) in messages {
+            if !self.silent {
+                if let Some(file_output) = self.reporter.start_file(&path) {
+                    writer
+                        .write_all(file_output.as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                }
+            }
+
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -206,11 +247,7 @@ impl DiagnosticService {
             }
         }
 
-        let result = DiagnosticResult::new(
-            warnings_count,
-            errors_count,
-            self.max_warnings_exceeded(warnings_count),
-        );
+        let result = DiagnosticResult::new(warnings_count, errors_count, self.max_warnings);
 
         if let Some(finish_output) = self.reporter.finish(&result) {
             writer
@@ -327,8 +364,12 @@ fn strict_canonicalize<P: AsRef<Path>>(path: P) -> std::io::Result<PathBuf> {
 
 #[cfg(test)]
 mod tests {
-    use crate::service::from_file_path;
-    use std::path::PathBuf;
+    use crate::{
+        DiagnosticService, Error,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+        service::from_file_path,
+    };
+    use std::path::{Path, PathBuf};
 
     fn with_schema(path: &str) -> String {
         const EXPECTED_SCHEMA: &str = if cfg!(windows) { "file:///" } else { "file://" };
@@ -395,4 +436,36 @@ mod tests {
             assert_eq!(uri, expected);
         }
     }
+
+    struct FileNameReporter;
+
+    impl DiagnosticReporter for FileNameReporter {
+        fn start_file(&mut self, path: &Path) -> Option<String> {
+            Some(format!("{}\n", path.display()))
+        }
+
+        fn finish(&mut self, _result: &DiagnosticResult) -> Option<String> {
+            None
+        }
+
+        fn render_error(&mut self, _error: Error) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_sorted() {
+        let run = |sorted: bool| {
+            let (service, sender) = DiagnosticService::new(Box::new(FileNameReporter));
+            for path in ["b.js", "c.js", "a.js"] {
+                sender.send((PathBuf::from(path), vec![])).unwrap();
+            }
+            drop(sender);
+            let mut output = Vec::new();
+            service.with_sorted(sorted).run(&mut output);
+            String::from_utf8(output).unwrap()
+        };
+        assert_eq!(run(false), "b.js\nc.js\na.js\n");
+        assert_eq!(run(true), "a.js\nb.js\nc.js\n");
+    }
 }
@@ -121,6 +121,8 @@ pub enum AstNodes<'a> {
     ExportAllDeclaration(&'a AstNode<'a, ExportAllDeclaration<'a>>),
     ExportSpecifier(&'a AstNode<'a, ExportSpecifier<'a>>),
     V8IntrinsicExpression(&'a AstNode<'a, V8IntrinsicExpression<'a>>),
+    TopicReference(&'a AstNode<'a, TopicReference>),
+    PipelineExpression(&'a AstNode<'a, PipelineExpression<'a>>),
     BooleanLiteral(&'a AstNode<'a, BooleanLiteral>),
     NullLiteral(&'a AstNode<'a, NullLiteral>),
     NumericLiteral(&'a AstNode<'a, NumericLiteral<'a>>),
@@ -311,6 +313,8 @@ pub enum SiblingNode<'a> {
     ExportAllDeclaration(&'a ExportAllDeclaration<'a>),
     ExportSpecifier(&'a ExportSpecifier<'a>),
     V8IntrinsicExpression(&'a V8IntrinsicExpression<'a>),
+    TopicReference(&'a TopicReference),
+    PipelineExpression(&'a PipelineExpression<'a>),
     BooleanLiteral(&'a BooleanLiteral),
     NullLiteral(&'a NullLiteral),
     NumericLiteral(&'a NumericLiteral<'a>),
@@ -969,6 +973,18 @@ impl<'a> From<&'a V8IntrinsicExpression<'a>> for SiblingNode<'a> {
     }
 }
 
+impl<'a> From<&'a TopicReference> for SiblingNode<'a> {
+    fn from(node: &'a TopicReference) -> Self {
+        SiblingNode::TopicReference(node)
+    }
+}
+
+impl<'a> From<&'a PipelineExpression<'a>> for SiblingNode<'a> {
+    fn from(node: &'a PipelineExpression<'a>) -> Self {
+        SiblingNode::PipelineExpression(node)
+    }
+}
+
 impl<'a> From<&'a BooleanLiteral> for SiblingNode<'a> {
     fn from(node: &'a BooleanLiteral) -> Self {
         SiblingNode::BooleanLiteral(node)
@@ -1572,6 +1588,8 @@ impl<'a> From<&'a Expression<'a>> for SiblingNode<'a> {
                 SiblingNode::TSInstantiationExpression(inner)
             }
             Expression::V8IntrinsicExpression(inner) => SiblingNode::V8IntrinsicExpression(inner),
+            Expression::TopicReference(inner) => SiblingNode::TopicReference(inner),
+            Expression::PipelineExpression(inner) => SiblingNode::PipelineExpression(inner),
             it @ match_member_expression!(Expression) => {
                 SiblingNode::from(it.to_member_expression())
             }
@@ -2214,6 +2232,8 @@ impl SiblingNode<'_> {
             Self::ExportAllDeclaration(n) => n.span(),
             Self::ExportSpecifier(n) => n.span(),
             Self::V8IntrinsicExpression(n) => n.span(),
+            Self::TopicReference(n) => n.span(),
+            Self::PipelineExpression(n) => n.span(),
             Self::BooleanLiteral(n) => n.span(),
             Self::NullLiteral(n) => n.span(),
             Self::NumericLiteral(n) => n.span(),
@@ -2408,6 +2428,8 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(n) => n.span(),
             Self::ExportSpecifier(n) => n.span(),
             Self::V8IntrinsicExpression(n) => n.span(),
+            Self::TopicReference(n) => n.span(),
+            Self::PipelineExpression(n) => n.span(),
             Self::BooleanLiteral(n) => n.span(),
             Self::NullLiteral(n) => n.span(),
             Self::NumericLiteral(n) => n.span(),
@@ -2600,6 +2622,8 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(n) => n.parent,
             Self::ExportSpecifier(n) => n.parent,
             Self::V8IntrinsicExpression(n) => n.parent,
+            Self::TopicReference(n) => n.parent,
+            Self::PipelineExpression(n) => n.parent,
             Self::BooleanLiteral(n) => n.parent,
             Self::NullLiteral(n) => n.parent,
             Self::NumericLiteral(n) => n.parent,
@@ -2792,6 +2816,8 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(n) => SiblingNode::from(n.inner),
             Self::ExportSpecifier(n) => SiblingNode::from(n.inner),
             Self::V8IntrinsicExpression(n) => SiblingNode::from(n.inner),
+            Self::TopicReference(n) => SiblingNode::from(n.inner),
+            Self::PipelineExpression(n) => SiblingNode::from(n.inner),
             Self::BooleanLiteral(n) => SiblingNode::from(n.inner),
             Self::NullLiteral(n) => SiblingNode::from(n.inner),
             Self::NumericLiteral(n) => SiblingNode::from(n.inner),
@@ -2984,6 +3010,8 @@ impl<'a> AstNodes<'a> {
             Self::ExportAllDeclaration(_) => "ExportAllDeclaration",
             Self::ExportSpecifier(_) => "ExportSpecifier",
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+            Self::TopicReference(_) => "TopicReference",
+            Self::PipelineExpression(_) => "PipelineExpression",
             Self::BooleanLiteral(_) => "BooleanLiteral",
             Self::NullLiteral(_) => "NullLiteral",
             Self::NumericLiteral(_) => "NumericLiteral",
@@ -3528,6 +3556,22 @@ impl<'a> AstNode<'a, Expression<'a>> {
                     following_node: self.following_node,
                 }))
             }
+            Expression::TopicReference(s) => {
+                AstNodes::TopicReference(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_node: self.following_node,
+                }))
+            }
+            Expression::PipelineExpression(s) => {
+                AstNodes::PipelineExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_node: self.following_node,
+                }))
+            }
             it @ match_member_expression!(Expression) => {
                 return self
                     .allocator
@@ -9241,6 +9285,58 @@ impl<'a> AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> AstNode<'a, TopicReference> {
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_leading_comments(self.span()).fmt(f)
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_trailing_comments(
+            &self.parent.as_sibling_node(),
+            &SiblingNode::from(self.inner),
+            self.following_node.as_ref(),
+        )
+        .fmt(f)
+    }
+}
+
+impl<'a> AstNode<'a, PipelineExpression<'a>> {
+    #[inline]
+    pub fn head(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_node = Some(SiblingNode::from(&self.inner.body));
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.head,
+            allocator: self.allocator,
+            parent: self.allocator.alloc(AstNodes::PipelineExpression(transmute_self(self))),
+            following_node,
+        })
+    }
+
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_node = self.following_node;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.body,
+            allocator: self.allocator,
+            parent: self.allocator.alloc(AstNodes::PipelineExpression(transmute_self(self))),
+            following_node,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_leading_comments(self.span()).fmt(f)
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_trailing_comments(
+            &self.parent.as_sibling_node(),
+            &SiblingNode::from(self.inner),
+            self.following_node.as_ref(),
+        )
+        .fmt(f)
+    }
+}
+
 impl<'a> GetSpan for AstNode<'a, V8IntrinsicExpression<'a>> {
     #[inline]
     fn span(&self) -> oxc_span::Span {
@@ -9248,6 +9344,20 @@ impl<'a> GetSpan for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> GetSpan for AstNode<'a, TopicReference> {
+    #[inline]
+    fn span(&self) -> oxc_span::Span {
+        self.inner.span()
+    }
+}
+
+impl<'a> GetSpan for AstNode<'a, PipelineExpression<'a>> {
+    #[inline]
+    fn span(&self) -> oxc_span::Span {
+        self.inner.span()
+    }
+}
+
 impl<'a> AstNode<'a, BooleanLiteral> {
     #[inline]
     pub fn value(&self) -> bool {
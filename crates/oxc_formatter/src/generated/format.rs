@@ -1207,6 +1207,38 @@ impl<'a> Format<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> Format<'a> for AstNode<'a, TopicReference> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        self.format_leading_comments(f)?;
+        let needs_parentheses = self.needs_parentheses(f);
+        if needs_parentheses {
+            "(".fmt(f)?;
+        }
+        let result = self.write(f);
+        if needs_parentheses {
+            ")".fmt(f)?;
+        }
+        self.format_trailing_comments(f)?;
+        result
+    }
+}
+
+impl<'a> Format<'a> for AstNode<'a, PipelineExpression<'a>> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        self.format_leading_comments(f)?;
+        let needs_parentheses = self.needs_parentheses(f);
+        if needs_parentheses {
+            "(".fmt(f)?;
+        }
+        let result = self.write(f);
+        if needs_parentheses {
+            ")".fmt(f)?;
+        }
+        self.format_trailing_comments(f)?;
+        result
+    }
+}
+
 impl<'a> Format<'a> for AstNode<'a, BooleanLiteral> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
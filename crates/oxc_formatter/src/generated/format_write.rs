@@ -340,6 +340,22 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Expression<'a>> {
                     following_node: self.following_node,
                 })
                 .fmt(f),
+            Expression::TopicReference(inner) => allocator
+                .alloc(AstNode::<TopicReference> {
+                    inner,
+                    parent,
+                    allocator,
+                    following_node: self.following_node,
+                })
+                .fmt(f),
+            Expression::PipelineExpression(inner) => allocator
+                .alloc(AstNode::<PipelineExpression> {
+                    inner,
+                    parent,
+                    allocator,
+                    following_node: self.following_node,
+                })
+                .fmt(f),
             it @ match_member_expression!(Expression) => {
                 let inner = it.to_member_expression();
                 allocator
@@ -59,6 +59,8 @@ impl<'a> NeedsParentheses<'a> for AstNode<'a, Expression<'a>> {
             AstNodes::TSNonNullExpression(it) => it.needs_parentheses(f),
             AstNodes::TSInstantiationExpression(it) => it.needs_parentheses(f),
             AstNodes::V8IntrinsicExpression(it) => it.needs_parentheses(f),
+            AstNodes::TopicReference(it) => it.needs_parentheses(f),
+            AstNodes::PipelineExpression(it) => it.needs_parentheses(f),
             AstNodes::StaticMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::ComputedMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::PrivateFieldExpression(it) => it.needs_parentheses(f),
@@ -405,6 +407,38 @@ impl<'a> NeedsParentheses<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> NeedsParentheses<'a> for AstNode<'a, TopicReference> {
+    fn needs_parentheses(&self, f: &Formatter<'_, 'a>) -> bool {
+        false
+    }
+}
+
+impl<'a> NeedsParentheses<'a> for AstNode<'a, PipelineExpression<'a>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, 'a>) -> bool {
+        let parent = self.parent;
+        if matches!(
+            parent,
+            AstNodes::TSAsExpression(_)
+                | AstNodes::TSSatisfiesExpression(_)
+                | AstNodes::TSTypeAssertion(_)
+                | AstNodes::UnaryExpression(_)
+                | AstNodes::AwaitExpression(_)
+                | AstNodes::LogicalExpression(_)
+                | AstNodes::BinaryExpression(_)
+        ) {
+            return true;
+        }
+        match parent {
+            // `a |> f(%) |> g(%)` nests to the right
+            AstNodes::PipelineExpression(e) => e.head.without_parentheses().span() == self.span(),
+            AstNodes::ConditionalExpression(e) => {
+                e.test.without_parentheses().span() == self.span()
+            }
+            _ => update_or_lower_expression_needs_parens(self.span(), parent),
+        }
+    }
+}
+
 impl<'a> NeedsParentheses<'a> for AstNode<'a, JSXMemberExpression<'a>> {
     fn needs_parentheses(&self, f: &Formatter<'_, 'a>) -> bool {
         false
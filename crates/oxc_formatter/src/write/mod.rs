@@ -1643,6 +1643,18 @@ impl<'a> FormatWrite<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, TopicReference> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, "%")
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, PipelineExpression<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [self.head(), space(), "|>", space(), self.body()])
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, BooleanLiteral> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, if self.value() { "true" } else { "false" })
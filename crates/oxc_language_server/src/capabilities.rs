@@ -5,15 +5,25 @@ use tower_lsp_server::lsp_types::{
     WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
-use crate::{code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID};
+use crate::{
+    code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
+    commands::{FIX_ALL_COMMAND_ID, FIX_ALL_WORKSPACE_COMMAND_ID},
+};
 
 #[derive(Clone, Default)]
 pub struct Capabilities {
     pub code_action_provider: bool,
+    /// The client resolves the edits of code actions with `codeAction/resolve`.
+    pub code_action_resolve: bool,
     pub workspace_apply_edit: bool,
+    /// The client supports `documentChanges` with change annotations in workspace edits,
+    /// and asks the user to confirm edits whose annotation needs confirmation.
+    pub workspace_edit_change_annotations: bool,
     pub workspace_execute_command: bool,
     pub workspace_configuration: bool,
     pub dynamic_watchers: bool,
+    /// Experimental: the client handles `oxc/showOutputChannelOnError` notifications.
+    pub show_output_channel_on_error: bool,
 }
 
 impl From<ClientCapabilities> for Capabilities {
@@ -26,14 +36,33 @@ impl From<ClientCapabilities> for Capabilities {
                 })
             })
         });
+        let code_action_resolve = value.text_document.as_ref().is_some_and(|capability| {
+            capability.code_action.as_ref().is_some_and(|code_action| {
+                code_action.resolve_support.as_ref().is_some_and(|resolve_support| {
+                    resolve_support.properties.iter().any(|property| property == "edit")
+                })
+            })
+        });
         let workspace_apply_edit =
             value.workspace.as_ref().is_some_and(|workspace| workspace.apply_edit.is_some());
+        let workspace_edit_change_annotations = value.workspace.as_ref().is_some_and(|workspace| {
+            workspace.workspace_edit.as_ref().is_some_and(|workspace_edit| {
+                workspace_edit.document_changes == Some(true)
+                    && workspace_edit.change_annotation_support.is_some()
+            })
+        });
         let workspace_execute_command =
             value.workspace.as_ref().is_some_and(|workspace| workspace.execute_command.is_some());
         let workspace_configuration = value
             .workspace
             .as_ref()
             .is_some_and(|workspace| workspace.configuration.is_some_and(|config| config));
+        let show_output_channel_on_error =
+            value.experimental.as_ref().is_some_and(|experimental| {
+                experimental
+                    .get("showOutputChannelOnError")
+                    .is_some_and(|value| value.as_bool() == Some(true))
+            });
         let dynamic_watchers = value.workspace.is_some_and(|workspace| {
             workspace.did_change_watched_files.is_some_and(|watched_files| {
                 watched_files.dynamic_registration.is_some_and(|dynamic| dynamic)
@@ -42,10 +71,13 @@ impl From<ClientCapabilities> for Capabilities {
 
         Self {
             code_action_provider,
+            code_action_resolve,
             workspace_apply_edit,
+            workspace_edit_change_annotations,
             workspace_execute_command,
             workspace_configuration,
             dynamic_watchers,
+            show_output_channel_on_error,
         }
     }
 }
@@ -79,14 +111,18 @@ impl From<Capabilities> for ServerCapabilities {
                     work_done_progress_options: WorkDoneProgressOptions {
                         work_done_progress: None,
                     },
-                    resolve_provider: None,
+                    resolve_provider: value.code_action_resolve.then_some(true),
                 }))
             } else {
                 None
             },
+            document_highlight_provider: Some(OneOf::Left(true)),
             execute_command_provider: if value.workspace_execute_command {
                 Some(ExecuteCommandOptions {
-                    commands: vec![FIX_ALL_COMMAND_ID.to_string()],
+                    commands: vec![
+                        FIX_ALL_COMMAND_ID.to_string(),
+                        FIX_ALL_WORKSPACE_COMMAND_ID.to_string(),
+                    ],
                     ..Default::default()
                 })
             } else {
@@ -100,10 +136,12 @@ impl From<Capabilities> for ServerCapabilities {
 #[cfg(test)]
 mod test {
     use tower_lsp_server::lsp_types::{
-        ClientCapabilities, CodeActionClientCapabilities, CodeActionKindLiteralSupport,
-        CodeActionLiteralSupport, DidChangeWatchedFilesClientCapabilities,
-        DynamicRegistrationClientCapabilities, TextDocumentClientCapabilities,
-        WorkspaceClientCapabilities,
+        ChangeAnnotationWorkspaceEditClientCapabilities, ClientCapabilities,
+        CodeActionCapabilityResolveSupport, CodeActionClientCapabilities,
+        CodeActionKindLiteralSupport, CodeActionLiteralSupport,
+        DidChangeWatchedFilesClientCapabilities, DynamicRegistrationClientCapabilities,
+        TextDocumentClientCapabilities, WorkspaceClientCapabilities,
+        WorkspaceEditClientCapabilities,
     };
 
     use super::Capabilities;
@@ -206,6 +244,28 @@ mod test {
         assert!(capabilities.code_action_provider);
     }
 
+    #[test]
+    fn test_code_action_resolve_vscode() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                code_action: Some(CodeActionClientCapabilities {
+                    resolve_support: Some(CodeActionCapabilityResolveSupport {
+                        properties: vec!["edit".into()],
+                    }),
+                    ..CodeActionClientCapabilities::default()
+                }),
+                ..TextDocumentClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.code_action_resolve);
+
+        let capabilities = Capabilities::from(ClientCapabilities::default());
+        assert!(!capabilities.code_action_resolve);
+    }
+
     // This tests code, intellij and neovim (at least nvim 0.10.0+), as they all support dynamic registration.
     #[test]
     fn test_workspace_execute_command() {
@@ -240,6 +300,45 @@ mod test {
         assert!(capabilities.workspace_apply_edit);
     }
 
+    #[test]
+    fn test_workspace_edit_change_annotations_vscode() {
+        let client_capabilities = ClientCapabilities {
+            workspace: Some(WorkspaceClientCapabilities {
+                workspace_edit: Some(WorkspaceEditClientCapabilities {
+                    document_changes: Some(true),
+                    change_annotation_support: Some(
+                        ChangeAnnotationWorkspaceEditClientCapabilities {
+                            groups_on_label: Some(true),
+                        },
+                    ),
+                    ..WorkspaceEditClientCapabilities::default()
+                }),
+                ..WorkspaceClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.workspace_edit_change_annotations);
+
+        // Annotations can only be sent with `documentChanges`
+        let client_capabilities = ClientCapabilities {
+            workspace: Some(WorkspaceClientCapabilities {
+                workspace_edit: Some(WorkspaceEditClientCapabilities {
+                    change_annotation_support: Some(
+                        ChangeAnnotationWorkspaceEditClientCapabilities { groups_on_label: None },
+                    ),
+                    ..WorkspaceEditClientCapabilities::default()
+                }),
+                ..WorkspaceClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(!capabilities.workspace_edit_change_annotations);
+    }
+
     #[test]
     fn test_dynamic_watchers_vscode() {
         let client_capabilities = ClientCapabilities {
@@ -273,4 +372,18 @@ mod test {
         let capabilities = Capabilities::from(client_capabilities);
         assert!(capabilities.dynamic_watchers);
     }
+
+    #[test]
+    fn test_show_output_channel_on_error() {
+        let client_capabilities = ClientCapabilities {
+            experimental: Some(serde_json::json!({ "showOutputChannelOnError": true })),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.show_output_channel_on_error);
+
+        let capabilities = Capabilities::from(ClientCapabilities::default());
+        assert!(!capabilities.show_output_channel_on_error);
+    }
 }
@@ -1,5 +1,10 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
 use tower_lsp_server::lsp_types::{
-    CodeAction, CodeActionKind, Position, Range, TextEdit, Uri, WorkspaceEdit,
+    AnnotatedTextEdit, ChangeAnnotation, CodeAction, CodeActionKind, DocumentChanges,
+    NumberOrString, OneOf, OptionalVersionedTextDocumentIdentifier, Position, Range,
+    TextDocumentEdit, TextEdit, Uri, WorkspaceEdit,
 };
 
 use crate::linter::error_with_position::{DiagnosticReport, FixedContent, PossibleFixContent};
@@ -11,6 +16,7 @@ fn fix_content_to_code_action(
     fixed_content: &FixedContent,
     uri: &Uri,
     alternative_message: &str,
+    is_preferred: bool,
 ) -> CodeAction {
     // 1) Use `fixed_content.message` if it exists
     // 2) Try to parse the report diagnostic message
@@ -29,7 +35,7 @@ fn fix_content_to_code_action(
     CodeAction {
         title,
         kind: Some(CodeActionKind::QUICKFIX),
-        is_preferred: Some(true),
+        is_preferred: Some(is_preferred),
         edit: Some(WorkspaceEdit {
             #[expect(clippy::disallowed_types)]
             changes: Some(std::collections::HashMap::from([(
@@ -48,14 +54,24 @@ fn fix_content_to_code_action(
 pub fn apply_fix_code_actions(report: &DiagnosticReport, uri: &Uri) -> Option<Vec<CodeAction>> {
     match &report.fixed_content {
         PossibleFixContent::None => None,
-        PossibleFixContent::Single(fixed_content) => {
-            Some(vec![fix_content_to_code_action(fixed_content, uri, &report.diagnostic.message)])
-        }
+        PossibleFixContent::Single(fixed_content) => Some(vec![fix_content_to_code_action(
+            fixed_content,
+            uri,
+            &report.diagnostic.message,
+            true,
+        )]),
+        // Alternative suggestions, only the first one is preferred like in `source.fixAll.oxc`.
         PossibleFixContent::Multiple(fixed_contents) => Some(
             fixed_contents
                 .iter()
-                .map(|fixed_content| {
-                    fix_content_to_code_action(fixed_content, uri, &report.diagnostic.message)
+                .enumerate()
+                .map(|(index, fixed_content)| {
+                    fix_content_to_code_action(
+                        fixed_content,
+                        uri,
+                        &report.diagnostic.message,
+                        index == 0,
+                    )
                 })
                 .collect(),
         ),
@@ -109,81 +125,436 @@ pub fn apply_all_fix_code_action<'a>(
     })
 }
 
-pub fn ignore_this_line_code_action(report: &DiagnosticReport, uri: &Uri) -> CodeAction {
-    let rule_name = report.rule_name.as_ref();
+/// Where the comment of an ignore code action is inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DisablePlacement {
+    /// `// oxlint-disable-next-line` on its own line above the diagnostics.
+    NextLine,
+    /// `// oxlint-disable-line` at the end of the line of the diagnostics.
+    SameLine,
+    /// `// oxlint-disable` at the top of the file.
+    File,
+}
 
-    // TODO: This CodeAction doesn't support disabling multiple rules by name for a given line.
-    //  To do that, we need to read `report.diagnostic.range.start.line` and check if a disable comment already exists.
-    //  If it does, it needs to be appended to instead of a completely new line inserted.
-    CodeAction {
-        title: rule_name.as_ref().map_or_else(
-            || "Disable oxlint for this line".into(),
-            |s| format!("Disable {s} for this line"),
-        ),
-        kind: Some(CodeActionKind::QUICKFIX),
-        is_preferred: Some(false),
-        edit: Some(WorkspaceEdit {
-            #[expect(clippy::disallowed_types)]
-            changes: Some(std::collections::HashMap::from([(
-                uri.clone(),
+/// The `data` of an ignore code action, from which its edit is computed in `codeAction/resolve`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IgnoreCodeActionData {
+    pub uri: String,
+    /// The line of the diagnostics.
+    pub line: u32,
+    /// The rules to disable, all rules when empty.
+    pub rules: Vec<String>,
+    pub placement: DisablePlacement,
+}
+
+impl IgnoreCodeActionData {
+    pub fn from_code_action(action: &CodeAction) -> Option<Self> {
+        serde_json::from_value(action.data.clone()?).ok()
+    }
+
+    fn title(&self) -> String {
+        let rules =
+            if self.rules.is_empty() { "oxlint".to_string() } else { self.rules.join(", ") };
+        match self.placement {
+            DisablePlacement::NextLine => format!("Disable {rules} for this line"),
+            DisablePlacement::SameLine => format!("Disable {rules} at the end of this line"),
+            DisablePlacement::File => format!("Disable {rules} for this file"),
+        }
+    }
+
+    /// The edits which insert the comment, `None` when there is no matching report anymore.
+    fn edits<'a>(
+        &self,
+        mut reports: impl Iterator<Item = &'a DiagnosticReport>,
+    ) -> Option<Vec<TextEdit>> {
+        let report = reports.find(|report| {
+            report.diagnostic.range.start.line == self.line
+                && (self.rules.is_empty()
+                    || report.rule_name.as_ref().is_some_and(|rule| self.rules.contains(rule)))
+                && (self.placement != DisablePlacement::SameLine
+                    || report.disable_same_line_fix.is_some())
+        })?;
+
+        let rules = self.rules.iter().map(|rule| format!(" {rule}")).collect::<Vec<_>>().join(",");
+        // The edits of a report disable its own rule, which is replaced with all selected rules.
+        let with_rules = |fix: &FixedContent| TextEdit {
+            range: fix.range,
+            new_text: match &report.rule_name {
+                Some(rule) if !rules.is_empty() => fix.code.replace(&format!(" {rule}"), &rules),
+                _ => fix.code.clone(),
+            },
+        };
+
+        let edits = match self.placement {
+            // Diagnostics without an AST, e.g. parse errors
+            DisablePlacement::NextLine if report.disable_line_fixes.is_empty() => {
+                let start = Position { line: self.line, character: 0 };
                 vec![TextEdit {
-                    range: Range {
-                        start: Position {
-                            line: report.diagnostic.range.start.line,
-                            // TODO: character should be set to match the first non-whitespace character in the source text to match the existing indentation.
-                            character: 0,
-                        },
-                        end: Position {
-                            line: report.diagnostic.range.start.line,
-                            // TODO: character should be set to match the first non-whitespace character in the source text to match the existing indentation.
-                            character: 0,
-                        },
-                    },
-                    new_text: rule_name.as_ref().map_or_else(
-                        || "// oxlint-disable-next-line\n".into(),
-                        |s| format!("// oxlint-disable-next-line {s}\n"),
-                    ),
-                }],
-            )])),
-            ..WorkspaceEdit::default()
-        }),
-        disabled: None,
-        data: None,
-        diagnostics: None,
-        command: None,
+                    range: Range { start, end: start },
+                    new_text: format!("// oxlint-disable-next-line{rules}\n"),
+                }]
+            }
+            // The comment respects the indentation, JSX children and multi line literals
+            DisablePlacement::NextLine => {
+                report.disable_line_fixes.iter().map(with_rules).collect()
+            }
+            DisablePlacement::SameLine => vec![with_rules(report.disable_same_line_fix.as_ref()?)],
+            DisablePlacement::File => {
+                let start = Position { line: 0, character: 0 };
+                vec![TextEdit {
+                    range: Range { start, end: start },
+                    new_text: format!("// oxlint-disable{rules}\n"),
+                }]
+            }
+        };
+        Some(edits)
     }
 }
 
-pub fn ignore_this_rule_code_action(report: &DiagnosticReport, uri: &Uri) -> CodeAction {
-    let rule_name = report.rule_name.as_ref();
+/// Code actions which disable the rules of `reports` for their line or the file.
+///
+/// Reports on the same line share their actions, which disable all rules of the line at once.
+/// When a line has diagnostics of several rules, additional actions disable a single rule for the
+/// line, so each rule can be picked.
+///
+/// With `resolve`, the actions only have [`IgnoreCodeActionData`] and their edits are computed
+/// by [`resolve_ignore_code_action`] when one of them is selected.
+pub fn ignore_code_actions(
+    reports: &[&DiagnosticReport],
+    uri: &Uri,
+    resolve: bool,
+) -> Vec<CodeAction> {
+    // Lines in the order of their first report, with their rules
+    let mut lines: Vec<(u32, Vec<Option<&str>>)> = vec![];
+    for report in reports {
+        let line = report.diagnostic.range.start.line;
+        let rule = report.rule_name.as_deref();
+        match lines.iter_mut().find(|(other, _)| *other == line) {
+            Some((_, rules)) if !rules.contains(&rule) => rules.push(rule),
+            Some(_) => {}
+            None => lines.push((line, vec![rule])),
+        }
+    }
 
+    let mut actions = vec![];
+    for (line, rules) in lines {
+        // Diagnostics without a rule, e.g. parse errors, can only be disabled with all rules
+        let rules = if rules.contains(&None) {
+            vec![]
+        } else {
+            rules.into_iter().flatten().map(ToString::to_string).collect::<Vec<_>>()
+        };
+        let has_same_line_fix = reports.iter().any(|report| {
+            report.diagnostic.range.start.line == line && report.disable_same_line_fix.is_some()
+        });
+
+        let mut placements = vec![DisablePlacement::NextLine];
+        if has_same_line_fix {
+            placements.push(DisablePlacement::SameLine);
+        }
+        placements.push(DisablePlacement::File);
+        let mut data = placements
+            .into_iter()
+            .map(|placement| IgnoreCodeActionData {
+                uri: uri.to_string(),
+                line,
+                rules: rules.clone(),
+                placement,
+            })
+            .collect::<Vec<_>>();
+        if rules.len() > 1 {
+            data.extend(rules.iter().map(|rule| IgnoreCodeActionData {
+                uri: uri.to_string(),
+                line,
+                rules: vec![rule.clone()],
+                placement: DisablePlacement::NextLine,
+            }));
+        }
+
+        for data in data {
+            let action = ignore_code_action(&data);
+            actions.push(if resolve {
+                CodeAction { data: serde_json::to_value(&data).ok(), ..action }
+            } else {
+                resolve_ignore_code_action(action, &data, reports.iter().copied())
+            });
+        }
+    }
+    actions
+}
+
+fn ignore_code_action(data: &IgnoreCodeActionData) -> CodeAction {
     CodeAction {
-        title: rule_name.as_ref().map_or_else(
-            || "Disable oxlint for this file".into(),
-            |s| format!("Disable {s} for this file"),
-        ),
+        title: data.title(),
         kind: Some(CodeActionKind::QUICKFIX),
         is_preferred: Some(false),
-        edit: Some(WorkspaceEdit {
-            #[expect(clippy::disallowed_types)]
-            changes: Some(std::collections::HashMap::from([(
-                uri.clone(),
-                vec![TextEdit {
-                    range: Range {
-                        start: Position { line: 0, character: 0 },
-                        end: Position { line: 0, character: 0 },
-                    },
-                    new_text: rule_name.as_ref().map_or_else(
-                        || "// oxlint-disable\n".into(),
-                        |s| format!("// oxlint-disable {s}\n"),
-                    ),
-                }],
-            )])),
-            ..WorkspaceEdit::default()
-        }),
+        edit: None,
         disabled: None,
         data: None,
         diagnostics: None,
         command: None,
     }
 }
+
+/// Add the edit of an ignore code action, computed from `reports` of the document.
+pub fn resolve_ignore_code_action<'a>(
+    action: CodeAction,
+    data: &IgnoreCodeActionData,
+    reports: impl Iterator<Item = &'a DiagnosticReport>,
+) -> CodeAction {
+    let Some(uri) = Uri::from_str(&data.uri).ok() else { return action };
+    let edit = data.edits(reports).map(|edits| WorkspaceEdit {
+        #[expect(clippy::disallowed_types)]
+        changes: Some(std::collections::HashMap::from([(uri, edits)])),
+        ..WorkspaceEdit::default()
+    });
+    CodeAction { edit, ..action }
+}
+
+/// Annotation of fixes whose diagnostic has no code, e.g. of unused disable directives.
+const FIX_ANNOTATION_ID: &str = "oxc";
+
+/// The edit of the fix of each report which has one, with the code of its diagnostic,
+/// e.g. `eslint(no-debugger)`.
+pub fn fix_text_edits(
+    reports: &[DiagnosticReport],
+) -> impl Iterator<Item = (Option<&str>, TextEdit)> + '_ {
+    reports.iter().filter_map(|report| {
+        let fixed_content = match &report.fixed_content {
+            PossibleFixContent::None => None,
+            PossibleFixContent::Single(fixed_content) => Some(fixed_content),
+            // For multiple fixes, we take the first one as a representative fix.
+            // Applying all possible fixes at once is not possible in this context.
+            PossibleFixContent::Multiple(multi) => multi.first(),
+        }?;
+        let code = match &report.diagnostic.code {
+            Some(NumberOrString::String(code)) => Some(code.as_str()),
+            _ => None,
+        };
+        let edit = TextEdit { range: fixed_content.range, new_text: fixed_content.code.clone() };
+        Some((code, edit))
+    })
+}
+
+/// Edit which applies the fixes of many documents at once, see [`fix_text_edits`].
+///
+/// With `change_annotations`, each edit is annotated with the code of its diagnostic and every
+/// annotation needs confirmation, so editors show a preview of the edits grouped by rule before
+/// applying them. Otherwise, the edits are applied right away.
+pub fn fix_all_workspace_edit(
+    files: Vec<(Uri, Vec<(Option<String>, TextEdit)>)>,
+    change_annotations: bool,
+) -> WorkspaceEdit {
+    if !change_annotations {
+        let changes = files
+            .into_iter()
+            .map(|(uri, edits)| (uri, edits.into_iter().map(|(_, edit)| edit).collect()))
+            .collect();
+        return WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() };
+    }
+
+    // Number of fixes of each annotation, ordered for stable output
+    let mut fix_counts = BTreeMap::<String, usize>::new();
+    let document_changes = files
+        .into_iter()
+        .map(|(uri, edits)| TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+            edits: edits
+                .into_iter()
+                .map(|(code, text_edit)| {
+                    let annotation_id = code.unwrap_or_else(|| FIX_ANNOTATION_ID.to_string());
+                    *fix_counts.entry(annotation_id.clone()).or_default() += 1;
+                    OneOf::Right(AnnotatedTextEdit { text_edit, annotation_id })
+                })
+                .collect(),
+        })
+        .collect();
+    let change_annotations = fix_counts
+        .into_iter()
+        .map(|(id, count)| {
+            let annotation = ChangeAnnotation {
+                label: id.clone(),
+                needs_confirmation: Some(true),
+                description: Some(if count == 1 {
+                    "1 fix".into()
+                } else {
+                    format!("{count} fixes")
+                }),
+            };
+            (id, annotation)
+        })
+        .collect();
+
+    WorkspaceEdit {
+        changes: None,
+        document_changes: Some(DocumentChanges::Edits(document_changes)),
+        change_annotations: Some(change_annotations),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tower_lsp_server::lsp_types::{
+        Diagnostic, DocumentChanges, OneOf, Position, Range, TextEdit, Uri,
+    };
+
+    use super::{IgnoreCodeActionData, apply_fix_code_actions, ignore_code_actions};
+    use crate::linter::error_with_position::{DiagnosticReport, FixedContent, PossibleFixContent};
+
+    #[test]
+    fn multiple_suggestions() {
+        let suggestion = |message: &str, code: &str| FixedContent {
+            message: Some(message.to_string()),
+            code: code.to_string(),
+            range: Range::new(Position::new(0, 0), Position::new(0, 1)),
+        };
+        let report = DiagnosticReport {
+            diagnostic: Diagnostic::default(),
+            fixed_content: PossibleFixContent::Multiple(vec![
+                suggestion("remove `forwardRef` wrapper", "a"),
+                suggestion("add `ref` parameter", "b"),
+            ]),
+            rule_name: None,
+            disable_line_fixes: vec![],
+            disable_same_line_fix: None,
+        };
+        let uri = Uri::from_str("file:///test.js").unwrap();
+
+        let actions = apply_fix_code_actions(&report, &uri).unwrap();
+        let actions = actions
+            .iter()
+            .map(|action| (action.title.as_str(), action.is_preferred))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            [("remove `forwardRef` wrapper", Some(true)), ("add `ref` parameter", Some(false))]
+        );
+    }
+
+    #[test]
+    fn ignore_rules_of_line() {
+        let report = |rule: &str| DiagnosticReport {
+            diagnostic: Diagnostic {
+                range: Range::new(Position::new(1, 2), Position::new(1, 11)),
+                ..Diagnostic::default()
+            },
+            fixed_content: PossibleFixContent::None,
+            rule_name: Some(rule.to_string()),
+            disable_line_fixes: vec![FixedContent {
+                message: None,
+                code: format!("  // oxlint-disable-next-line {rule}\n"),
+                range: Range::new(Position::new(1, 0), Position::new(1, 0)),
+            }],
+            disable_same_line_fix: Some(FixedContent {
+                message: None,
+                code: format!(" // oxlint-disable-line {rule}"),
+                range: Range::new(Position::new(1, 11), Position::new(1, 11)),
+            }),
+        };
+        let reports = [report("no-debugger"), report("no-console")];
+        let reports = reports.iter().collect::<Vec<_>>();
+        let uri = Uri::from_str("file:///test.js").unwrap();
+
+        let actions = ignore_code_actions(&reports, &uri, false);
+        let actions = actions
+            .iter()
+            .map(|action| {
+                let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+                (action.title.as_str(), changes[&uri][0].new_text.as_str())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            [
+                (
+                    "Disable no-debugger, no-console for this line",
+                    "  // oxlint-disable-next-line no-debugger, no-console\n"
+                ),
+                (
+                    "Disable no-debugger, no-console at the end of this line",
+                    " // oxlint-disable-line no-debugger, no-console"
+                ),
+                (
+                    "Disable no-debugger, no-console for this file",
+                    "// oxlint-disable no-debugger, no-console\n"
+                ),
+                (
+                    "Disable no-debugger for this line",
+                    "  // oxlint-disable-next-line no-debugger\n"
+                ),
+                ("Disable no-console for this line", "  // oxlint-disable-next-line no-console\n"),
+            ]
+        );
+
+        // With resolve, the edits are computed from the data of the action
+        let actions = ignore_code_actions(&reports, &uri, true);
+        assert!(actions.iter().all(|action| action.edit.is_none()));
+        let data = IgnoreCodeActionData::from_code_action(&actions[1]).unwrap();
+        let resolved =
+            super::resolve_ignore_code_action(actions[1].clone(), &data, reports.iter().copied());
+        let changes = resolved.edit.unwrap().changes.unwrap();
+        assert_eq!(changes[&uri][0].new_text, " // oxlint-disable-line no-debugger, no-console");
+    }
+
+    #[test]
+    fn fix_all_workspace_edit() {
+        let edit = |line: u32, text: &str| TextEdit {
+            range: Range::new(Position::new(line, 0), Position::new(line, 9)),
+            new_text: text.to_string(),
+        };
+        let a = Uri::from_str("file:///a.js").unwrap();
+        let b = Uri::from_str("file:///b.js").unwrap();
+        let files = vec![
+            (
+                a.clone(),
+                vec![(Some("eslint(no-debugger)".to_string()), edit(0, "")), (None, edit(1, ""))],
+            ),
+            (b.clone(), vec![(Some("eslint(no-debugger)".to_string()), edit(0, ""))]),
+        ];
+
+        // Without change annotations, the edits are applied right away
+        let workspace_edit = super::fix_all_workspace_edit(files.clone(), false);
+        assert!(workspace_edit.document_changes.is_none());
+        let changes = workspace_edit.changes.unwrap();
+        assert_eq!(changes[&a].len(), 2);
+        assert_eq!(changes[&b].len(), 1);
+
+        let workspace_edit = super::fix_all_workspace_edit(files, true);
+        assert!(workspace_edit.changes.is_none());
+        let Some(DocumentChanges::Edits(document_changes)) = workspace_edit.document_changes else {
+            panic!("expected document edits");
+        };
+        let annotation_ids = document_changes
+            .iter()
+            .map(|document| {
+                let ids = document
+                    .edits
+                    .iter()
+                    .map(|edit| match edit {
+                        OneOf::Right(edit) => edit.annotation_id.as_str(),
+                        OneOf::Left(_) => panic!("expected annotated edit"),
+                    })
+                    .collect::<Vec<_>>();
+                (document.text_document.uri.as_str(), ids)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            annotation_ids,
+            [
+                ("file:///a.js", vec!["eslint(no-debugger)", "oxc"]),
+                ("file:///b.js", vec!["eslint(no-debugger)"]),
+            ]
+        );
+
+        let annotations = workspace_edit.change_annotations.unwrap();
+        let annotation = &annotations["eslint(no-debugger)"];
+        assert_eq!(annotation.label, "eslint(no-debugger)");
+        assert_eq!(annotation.needs_confirmation, Some(true));
+        assert_eq!(annotation.description.as_deref(), Some("2 fixes"));
+        assert_eq!(annotations["oxc"].description.as_deref(), Some("1 fix"));
+    }
+}
@@ -1,6 +1,8 @@
 use serde::Deserialize;
 
 pub const FIX_ALL_COMMAND_ID: &str = "oxc.fixAll";
+/// Applies the fixes of all documents with diagnostics in all workspaces, takes no arguments.
+pub const FIX_ALL_WORKSPACE_COMMAND_ID: &str = "oxc.fixAllWorkspace";
 
 #[derive(Deserialize)]
 pub struct FixAllCommandArgs {
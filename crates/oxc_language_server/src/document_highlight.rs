@@ -0,0 +1,274 @@
+use std::path::Path;
+
+use tower_lsp_server::lsp_types::{DocumentHighlight, DocumentHighlightKind, Position, Range};
+
+use oxc_allocator::Allocator;
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_linter::loader::{JavaScriptSource, PartialLoader};
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder};
+use oxc_span::{SourceType, Span};
+
+/// Highlights for `textDocument/documentHighlight`.
+///
+/// When the cursor is on a rule name inside a disable directive comment, all occurrences of the
+/// rule in disable directives are highlighted. Otherwise the symbol under the cursor is
+/// highlighted, with its declaration and writes as [`DocumentHighlightKind::WRITE`] and its reads
+/// as [`DocumentHighlightKind::READ`].
+pub fn document_highlights(
+    path: &Path,
+    source_text: &str,
+    position: Position,
+) -> Option<Vec<DocumentHighlight>> {
+    let rope = Rope::from_str(source_text);
+    let offset = position_to_offset(&rope, source_text, position)?;
+
+    let sources = if let Ok(source_type) = SourceType::from_path(path) {
+        vec![JavaScriptSource::new(source_text, source_type)]
+    } else {
+        let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
+        PartialLoader::parse(ext, source_text)?
+    };
+    // The cursor is in at most one script of the file
+    let source = sources.into_iter().find(|source| {
+        let len = u32::try_from(source.source_text.len()).unwrap_or(u32::MAX);
+        source.start <= offset && offset <= source.start.saturating_add(len)
+    })?;
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source.source_text, source.source_type).parse();
+    let semantic = SemanticBuilder::new().build(allocator.alloc(ret.program)).semantic;
+
+    let relative_offset = offset - source.start;
+    let spans = disable_directive_highlights(&semantic, relative_offset)
+        .or_else(|| symbol_highlights(&semantic, relative_offset))?;
+
+    let highlights = spans
+        .into_iter()
+        .map(|(span, kind)| DocumentHighlight {
+            range: Range::new(
+                offset_to_position(&rope, source_text, span.start + source.start),
+                offset_to_position(&rope, source_text, span.end + source.start),
+            ),
+            kind: Some(kind),
+        })
+        .collect();
+    Some(highlights)
+}
+
+/// Occurrences of the rule under the cursor in `eslint-disable` and `oxlint-disable` comments.
+fn disable_directive_highlights(
+    semantic: &Semantic,
+    offset: u32,
+) -> Option<Vec<(Span, DocumentHighlightKind)>> {
+    let source_text = semantic.source_text();
+    let rules = semantic
+        .comments()
+        .iter()
+        .flat_map(|comment| directive_rule_names(comment.content_span(), source_text))
+        .collect::<Vec<_>>();
+
+    let selected = rules.iter().find(|span| span.start <= offset && offset <= span.end)?;
+    let selected_name = selected.source_text(source_text);
+
+    Some(
+        rules
+            .iter()
+            .filter(|span| span.source_text(source_text) == selected_name)
+            .map(|span| (*span, DocumentHighlightKind::TEXT))
+            .collect(),
+    )
+}
+
+/// Spans of the rule names of a disable directive, e.g. `no-console` and `eqeqeq` in
+/// `// eslint-disable-next-line no-console, eqeqeq -- reason`.
+#[expect(clippy::cast_possible_truncation)]
+fn directive_rule_names(content_span: Span, source_text: &str) -> Vec<Span> {
+    let content = content_span.source_text(source_text);
+    let trimmed = content.trim_start();
+    let Some(directive) =
+        trimmed.strip_prefix("eslint-").or_else(|| trimmed.strip_prefix("oxlint-"))
+    else {
+        return vec![];
+    };
+    if !directive.starts_with("disable") && !directive.starts_with("enable") {
+        return vec![];
+    }
+
+    // Skip the directive itself, e.g. `disable-next-line`
+    let rules_start = directive.find(char::is_whitespace).unwrap_or(directive.len());
+    let rules = &directive[rules_start..];
+    // Everything after `--` is a description
+    let rules = rules.find("--").map_or(rules, |end| &rules[..end]);
+
+    let mut start = content_span.start + (content.len() - directive.len() + rules_start) as u32;
+    let mut spans = vec![];
+    for rule in rules.split(',') {
+        let name = rule.trim();
+        if !name.is_empty() {
+            let name_start = start + (rule.len() - rule.trim_start().len()) as u32;
+            spans.push(Span::sized(name_start, name.len() as u32));
+        }
+        // `+ 1` for the comma
+        start += rule.len() as u32 + 1;
+    }
+    spans
+}
+
+/// The declaration and the references of the symbol under the cursor.
+fn symbol_highlights(
+    semantic: &Semantic,
+    offset: u32,
+) -> Option<Vec<(Span, DocumentHighlightKind)>> {
+    let scoping = semantic.scoping();
+    let contains = |span: Span| span.start <= offset && offset <= span.end;
+
+    let symbol_id = scoping.symbol_ids().find(|&symbol_id| {
+        contains(scoping.symbol_span(symbol_id))
+            || scoping
+                .symbol_redeclarations(symbol_id)
+                .iter()
+                .any(|redeclaration| contains(redeclaration.span))
+            || semantic
+                .symbol_references(symbol_id)
+                .any(|reference| contains(semantic.reference_span(reference)))
+    })?;
+
+    let mut highlights = vec![(scoping.symbol_span(symbol_id), DocumentHighlightKind::WRITE)];
+    highlights.extend(
+        scoping
+            .symbol_redeclarations(symbol_id)
+            .iter()
+            .map(|redeclaration| (redeclaration.span, DocumentHighlightKind::WRITE)),
+    );
+    highlights.extend(semantic.symbol_references(symbol_id).map(|reference| {
+        let kind = if reference.is_write() {
+            DocumentHighlightKind::WRITE
+        } else {
+            DocumentHighlightKind::READ
+        };
+        (semantic.reference_span(reference), kind)
+    }));
+    highlights.sort_unstable_by_key(|(span, _)| span.start);
+    highlights.dedup_by_key(|(span, _)| *span);
+    Some(highlights)
+}
+
+/// Convert a LSP position (UTF-16 column) to a byte offset.
+#[expect(clippy::cast_possible_truncation)]
+fn position_to_offset(rope: &Rope, source_text: &str, position: Position) -> Option<u32> {
+    let line = position.line as usize;
+    if line >= rope.len_lines() {
+        return None;
+    }
+    let line_start = rope.line_to_byte(line);
+    let line_end =
+        if line + 1 < rope.len_lines() { rope.line_to_byte(line + 1) } else { rope.len_bytes() };
+
+    let mut column = 0;
+    for (index, ch) in source_text[line_start..line_end].char_indices() {
+        if column >= position.character {
+            return u32::try_from(line_start + index).ok();
+        }
+        column += ch.len_utf16() as u32;
+    }
+    u32::try_from(line_end).ok()
+}
+
+fn offset_to_position(rope: &Rope, source_text: &str, offset: u32) -> Position {
+    let (line, character) = get_line_column(rope, offset, source_text);
+    Position::new(line, character)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp_server::lsp_types::{DocumentHighlightKind, Position};
+
+    use super::document_highlights;
+
+    /// Returns the highlighted text and kind for the cursor at `line:character`.
+    fn highlights(
+        path: &str,
+        source_text: &str,
+        line: u32,
+        character: u32,
+    ) -> Vec<(String, DocumentHighlightKind)> {
+        let lines = source_text.lines().collect::<Vec<_>>();
+        document_highlights(Path::new(path), source_text, Position::new(line, character))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|highlight| {
+                let range = highlight.range;
+                assert_eq!(range.start.line, range.end.line);
+                let line = lines[range.start.line as usize].encode_utf16().collect::<Vec<_>>();
+                let text = String::from_utf16(
+                    &line[range.start.character as usize..range.end.character as usize],
+                )
+                .unwrap();
+                (text, highlight.kind.unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn symbol_reads_and_writes() {
+        let source = "let foo = 1;\nfoo = foo + 1;\nconsole.log(foo);";
+        let expected = vec![
+            ("foo".to_string(), DocumentHighlightKind::WRITE),
+            ("foo".to_string(), DocumentHighlightKind::WRITE),
+            ("foo".to_string(), DocumentHighlightKind::READ),
+            ("foo".to_string(), DocumentHighlightKind::READ),
+        ];
+        // on the declaration
+        assert_eq!(highlights("test.js", source, 0, 5), expected);
+        // on a reference
+        assert_eq!(highlights("test.js", source, 2, 13), expected);
+        // not on a symbol
+        assert_eq!(highlights("test.js", source, 0, 10), vec![]);
+        // global references are not highlighted
+        assert_eq!(highlights("test.js", source, 2, 2), vec![]);
+    }
+
+    #[test]
+    fn symbol_utf16_columns() {
+        let source = "const s = '👍'; const bar = s;\nbar;";
+        assert_eq!(
+            highlights("test.ts", source, 0, 23),
+            vec![
+                ("bar".to_string(), DocumentHighlightKind::WRITE),
+                ("bar".to_string(), DocumentHighlightKind::READ),
+            ]
+        );
+    }
+
+    #[test]
+    fn disable_directive_rules() {
+        let source = "// eslint-disable-next-line no-console, eqeqeq -- reason\nconsole.log(a == b);\n/* oxlint-disable no-console */";
+        let expected = vec![
+            ("no-console".to_string(), DocumentHighlightKind::TEXT),
+            ("no-console".to_string(), DocumentHighlightKind::TEXT),
+        ];
+        assert_eq!(highlights("test.js", source, 0, 30), expected);
+        assert_eq!(highlights("test.js", source, 2, 20), expected);
+        assert_eq!(
+            highlights("test.js", source, 0, 42),
+            vec![("eqeqeq".to_string(), DocumentHighlightKind::TEXT)]
+        );
+        // the description is not a rule
+        assert_eq!(highlights("test.js", source, 0, 52), vec![]);
+    }
+
+    #[test]
+    fn partial_loader() {
+        let source = "<template></template>\n<script>\nconst foo = 1;\nfoo;\n</script>";
+        assert_eq!(
+            highlights("test.vue", source, 3, 1),
+            vec![
+                ("foo".to_string(), DocumentHighlightKind::WRITE),
+                ("foo".to_string(), DocumentHighlightKind::READ),
+            ]
+        );
+    }
+}
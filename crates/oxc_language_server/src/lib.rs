@@ -0,0 +1,828 @@
+use std::{str::FromStr, sync::Arc};
+
+use futures::future::join_all;
+use rustc_hash::FxBuildHasher;
+use serde_json::json;
+use tokio::sync::{OnceCell, RwLock, SetError};
+use tower_lsp_server::{
+    Client, LanguageServer, LspService, Server, UriExt,
+    jsonrpc::{Error, ErrorCode, Result},
+    lsp_types::{
+        CodeAction, CodeActionParams, CodeActionResponse, ConfigurationItem, Diagnostic,
+        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+        DidChangeWatchedFilesRegistrationOptions, DidChangeWorkspaceFoldersParams,
+        DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+        DocumentHighlight, DocumentHighlightParams, ExecuteCommandParams, InitializeParams,
+        InitializeResult, InitializedParams, Registration, ServerInfo, SetTraceParams,
+        Unregistration, Uri, WorkspaceEdit,
+    },
+};
+use tracing::{debug, info, instrument, warn};
+
+mod capabilities;
+mod code_actions;
+mod commands;
+mod document_highlight;
+mod linter;
+mod options;
+mod scheduler;
+mod stats;
+#[cfg(test)]
+mod tester;
+mod trace;
+mod worker;
+
+use capabilities::Capabilities;
+use code_actions::{
+    CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, IgnoreCodeActionData, fix_all_workspace_edit,
+};
+use commands::{FIX_ALL_COMMAND_ID, FIX_ALL_WORKSPACE_COMMAND_ID, FixAllCommandArgs};
+use options::{Options, Run, WorkspaceOption};
+use stats::ServerStats;
+use trace::{LspTraceLayer, TraceState};
+use worker::WorkspaceWorker;
+
+type ConcurrentHashMap<K, V> = papaya::HashMap<K, V, FxBuildHasher>;
+
+const OXC_CONFIG_FILE: &str = ".oxlintrc.json";
+
+struct Backend {
+    client: Client,
+    // Each Workspace has it own worker with Linter (and in the future the formatter).
+    // We must respect each program inside with its own root folder
+    // and can not use shared programmes across multiple workspaces.
+    // Each Workspace can have its own server configuration and program root configuration.
+    // WorkspaceWorkers are only written on 3 occasions:
+    // 1. `initialize` request with workspace folders
+    // 2. `workspace/didChangeWorkspaceFolders` request
+    // 3. `textDocument/didOpen` and `textDocument/didClose` for documents outside of any workspace folder
+    workspace_workers: Arc<RwLock<Vec<WorkspaceWorker>>>,
+    // Options the client sent without a workspace folder, e.g. the deprecated `settings` of the
+    // initialization options. Used for documents outside of any workspace folder.
+    global_options: RwLock<Option<Options>>,
+    capabilities: OnceCell<Capabilities>,
+    // Verbosity of `$/logTrace` notifications, shared with the `LspTraceLayer`.
+    trace_state: Arc<TraceState>,
+}
+
+impl LanguageServer for Backend {
+    #[expect(deprecated)]
+    // `params.root_uri` is deprecated, we are only falling back to it if no workspace folder is provided
+    #[instrument(name = "initialize", skip_all)]
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let server_version = env!("CARGO_PKG_VERSION");
+        // initialization_options can be anything, so we are requesting `workspace/configuration` when no initialize options are provided
+        let mut global_options = None;
+        let options = params.initialization_options.and_then(|mut value| {
+            // the client supports the new settings object
+            if let Ok(new_settings) = serde_json::from_value::<Vec<WorkspaceOption>>(value.clone())
+            {
+                // ToDo: validate they have the same length as params.workspace_folders
+                return Some(new_settings);
+            }
+
+            let deprecated_settings = Options::try_from(value.get_mut("settings")?.take()).ok();
+            global_options.clone_from(&deprecated_settings);
+
+            // the client has deprecated settings and has a deprecated root uri.
+            // handle all things like the old way
+            if deprecated_settings.is_some() && params.root_uri.is_some() {
+                return Some(vec![WorkspaceOption {
+                    workspace_uri: params.root_uri.clone().unwrap(),
+                    options: deprecated_settings.unwrap(),
+                }]);
+            }
+
+            // no workspace options could be generated fallback to default one or request when possible
+            None
+        });
+
+        info!("initialize: {options:?}");
+        *self.global_options.write().await = global_options;
+        info!("language server version: {server_version}");
+
+        if let Some(trace) = params.trace {
+            self.trace_state.set_trace(trace);
+        }
+
+        let capabilities = Capabilities::from(params.capabilities);
+        self.trace_state
+            .set_show_output_channel_on_error(capabilities.show_output_channel_on_error);
+
+        // client sent workspace folders
+        let workers = if let Some(workspace_folders) = &params.workspace_folders {
+            workspace_folders
+                .iter()
+                .map(|workspace_folder| WorkspaceWorker::new(workspace_folder.uri.clone()))
+                .collect()
+        // client sent deprecated root uri
+        } else if let Some(root_uri) = params.root_uri {
+            vec![WorkspaceWorker::new(root_uri)]
+        // client is in single file mode, workers are created when a document is opened
+        } else {
+            vec![]
+        };
+
+        // When the client did not send our custom `initialization_options`,
+        // or the client does not support `workspace/configuration` request,
+        // start the linter. We do not start the linter when the client support the request,
+        // we will init the linter after requesting for the workspace configuration.
+        if !capabilities.workspace_configuration || options.is_some() {
+            for worker in &workers {
+                worker
+                    .init_linter(
+                        &options
+                            .clone()
+                            .unwrap_or_default()
+                            .iter()
+                            .find(|workspace_option| {
+                                worker.is_responsible_for_uri(&workspace_option.workspace_uri)
+                            })
+                            .map(|workspace_options| workspace_options.options.clone())
+                            .unwrap_or_default(),
+                    )
+                    .await;
+            }
+        }
+
+        *self.workspace_workers.write().await = workers;
+
+        self.capabilities.set(capabilities.clone()).map_err(|err| {
+            let message = match err {
+                SetError::AlreadyInitializedError(_) => {
+                    "capabilities are already initialized".into()
+                }
+                SetError::InitializingError(_) => "initializing error".into(),
+            };
+
+            Error { code: ErrorCode::ParseError, message, data: None }
+        })?;
+
+        Ok(InitializeResult {
+            server_info: Some(ServerInfo {
+                name: "oxc".into(),
+                version: Some(server_version.to_string()),
+            }),
+            offset_encoding: None,
+            capabilities: capabilities.into(),
+        })
+    }
+
+    #[instrument(name = "initialized", skip_all)]
+    async fn initialized(&self, _params: InitializedParams) {
+        debug!("oxc initialized.");
+        let Some(capabilities) = self.capabilities.get() else {
+            return;
+        };
+
+        let workers = &*self.workspace_workers.read().await;
+        let needed_configurations =
+            ConcurrentHashMap::with_capacity_and_hasher(workers.len(), FxBuildHasher);
+        let needed_configurations = needed_configurations.pin_owned();
+        for worker in workers {
+            if worker.needs_init_linter().await {
+                needed_configurations.insert(worker.get_root_uri().clone(), worker);
+            }
+        }
+
+        if !needed_configurations.is_empty() {
+            let configurations = if capabilities.workspace_configuration {
+                self.request_workspace_configuration(needed_configurations.keys().collect()).await
+            } else {
+                // every worker should be initialized already in `initialize` request
+                vec![Some(Options::default()); needed_configurations.len()]
+            };
+
+            for (index, worker) in needed_configurations.values().enumerate() {
+                worker
+                    .init_linter(
+                        configurations
+                            .get(index)
+                            .unwrap_or(&None)
+                            .as_ref()
+                            .unwrap_or(&Options::default()),
+                    )
+                    .await;
+            }
+        }
+
+        // init all file watchers
+        if capabilities.dynamic_watchers {
+            let mut registrations = vec![];
+            for worker in workers {
+                registrations.push(Registration {
+                    id: format!("watcher-{}", worker.get_root_uri().as_str()),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                    register_options: Some(json!(DidChangeWatchedFilesRegistrationOptions {
+                        watchers: worker.init_watchers().await
+                    })),
+                });
+            }
+
+            if let Err(err) = self.client.register_capability(registrations).await {
+                warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
+            }
+        }
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        self.clear_all_diagnostics().await;
+        Ok(())
+    }
+
+    #[instrument(name = "workspace/didChangeConfiguration", skip_all)]
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        let workers = self.workspace_workers.read().await;
+        let mut new_diagnostics: Vec<(String, Vec<Diagnostic>)> = vec![];
+        let mut removing_registrations = vec![];
+        let mut adding_registrations = vec![];
+
+        // new valid configuration is passed
+        let mut global_options = None;
+        let options = serde_json::from_value::<Vec<WorkspaceOption>>(params.settings.clone())
+            .ok()
+            .or_else(|| {
+                // fallback to old configuration
+                let options = serde_json::from_value::<Options>(params.settings).ok()?;
+                global_options = Some(options.clone());
+
+                // for all workers (default only one)
+                let options = workers
+                    .iter()
+                    .map(|worker| WorkspaceOption {
+                        workspace_uri: worker.get_root_uri().clone(),
+                        options: options.clone(),
+                    })
+                    .collect();
+
+                Some(options)
+            });
+
+        if global_options.is_some() {
+            *self.global_options.write().await = global_options;
+        }
+
+        // the client passed valid options.
+        let resolved_options = if let Some(options) = options {
+            options
+            // else check if the client support workspace configuration requests
+        } else if self
+            .capabilities
+            .get()
+            .is_some_and(|capabilities| capabilities.workspace_configuration)
+        {
+            let configs = self
+                .request_workspace_configuration(
+                    workers.iter().map(worker::WorkspaceWorker::get_root_uri).collect(),
+                )
+                .await;
+
+            // Only create WorkspaceOption when the config is Some
+            configs
+                .iter()
+                .enumerate()
+                // filter out results where the client did not return a configuration
+                .filter_map(|(index, config)| {
+                    config.as_ref().map(|options| WorkspaceOption {
+                        workspace_uri: workers[index].get_root_uri().clone(),
+                        options: options.clone(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        } else {
+            warn!(
+                "could not update the configuration for a worker. Send a custom configuration with `workspace/didChangeConfiguration` or support `workspace/configuration`."
+            );
+            return;
+        };
+
+        for option in resolved_options {
+            let Some(worker) =
+                workers.iter().find(|worker| worker.is_responsible_for_uri(&option.workspace_uri))
+            else {
+                continue;
+            };
+
+            let (diagnostics, watcher) = worker.did_change_configuration(&option.options).await;
+
+            if let Some(diagnostics) = diagnostics {
+                new_diagnostics.extend(diagnostics.into_iter().map(|(uri, reports)| {
+                    (uri, reports.into_iter().map(|d| d.diagnostic).collect())
+                }));
+            }
+
+            if let Some(watcher) = watcher {
+                // remove the old watcher
+                removing_registrations.push(Unregistration {
+                    id: format!("watcher-{}", worker.get_root_uri().as_str()),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                });
+                // add the new watcher
+                adding_registrations.push(Registration {
+                    id: format!("watcher-{}", worker.get_root_uri().as_str()),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                    register_options: Some(json!(DidChangeWatchedFilesRegistrationOptions {
+                        watchers: vec![watcher]
+                    })),
+                });
+            }
+        }
+
+        if !new_diagnostics.is_empty() {
+            self.publish_all_diagnostics(&new_diagnostics).await;
+        }
+
+        if self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers) {
+            if !removing_registrations.is_empty() {
+                if let Err(err) = self.client.unregister_capability(removing_registrations).await {
+                    warn!("sending unregisterCapability.didChangeWatchedFiles failed: {err}");
+                }
+            }
+            if !adding_registrations.is_empty() {
+                if let Err(err) = self.client.register_capability(adding_registrations).await {
+                    warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
+                }
+            }
+        }
+    }
+
+    #[instrument(name = "workspace/didChangeWatchedFiles", skip_all)]
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let workers = self.workspace_workers.read().await;
+        // ToDo: what if an empty changes flag is passed?
+        debug!("watched file did change");
+        let mut all_diagnostics: Vec<(String, Vec<Diagnostic>)> = vec![];
+        for file_event in &params.changes {
+            // We do not expect multiple changes from the same workspace folder.
+            // If we should consider it, we need to map the events to the workers first,
+            // to only restart the internal linter / diagnostics for once
+            let Some(worker) =
+                workers.iter().find(|worker| worker.is_responsible_for_uri(&file_event.uri))
+            else {
+                continue;
+            };
+            let Some(diagnostics) = worker.did_change_watched_files(file_event).await else {
+                continue;
+            };
+
+            for (uri, reports) in diagnostics {
+                // the worker may lint its documents again for multiple events, publish the latest
+                all_diagnostics.retain(|(existing, _)| existing != &uri);
+                all_diagnostics.push((uri, reports.into_iter().map(|d| d.diagnostic).collect()));
+            }
+        }
+
+        if all_diagnostics.is_empty() {
+            return;
+        }
+
+        self.publish_all_diagnostics(&all_diagnostics).await;
+    }
+
+    #[instrument(name = "workspace/didChangeWorkspaceFolders", skip_all)]
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        let mut workers = self.workspace_workers.write().await;
+        let mut cleared_diagnostics = vec![];
+        let mut added_registrations = vec![];
+        let mut removed_registrations = vec![];
+
+        for folder in params.event.removed {
+            let Some((index, worker)) = workers
+                .iter()
+                .enumerate()
+                .find(|(_, worker)| worker.is_responsible_for_uri(&folder.uri))
+            else {
+                continue;
+            };
+            cleared_diagnostics.extend(worker.get_clear_diagnostics());
+            removed_registrations.push(Unregistration {
+                id: format!("watcher-{}", worker.get_root_uri().as_str()),
+                method: "workspace/didChangeWatchedFiles".to_string(),
+            });
+            workers.remove(index);
+        }
+
+        self.publish_all_diagnostics(&cleared_diagnostics).await;
+
+        // documents of single file workers are now handled by the added workspace folders
+        workers.retain(|worker| {
+            let Some(document_path) = worker.single_file_uri().and_then(UriExt::to_file_path)
+            else {
+                return true;
+            };
+            !params.event.added.iter().any(|folder| {
+                folder.uri.to_file_path().is_some_and(|path| document_path.starts_with(path))
+            })
+        });
+
+        // client support `workspace/configuration` request
+        if self.capabilities.get().is_some_and(|capabilities| capabilities.workspace_configuration)
+        {
+            let configurations = self
+                .request_workspace_configuration(
+                    params.event.added.iter().map(|w| &w.uri).collect(),
+                )
+                .await;
+
+            for (index, folder) in params.event.added.iter().enumerate() {
+                let worker = WorkspaceWorker::new(folder.uri.clone());
+                // get the configuration from the response and init the linter
+                let options = configurations.get(index).unwrap_or(&None);
+                worker.init_linter(options.as_ref().unwrap_or(&Options::default())).await;
+                added_registrations.push(Registration {
+                    id: format!("watcher-{}", worker.get_root_uri().as_str()),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                    register_options: Some(json!(DidChangeWatchedFilesRegistrationOptions {
+                        watchers: worker.init_watchers().await
+                    })),
+                });
+                workers.push(worker);
+            }
+        // client does not support the request
+        } else {
+            for folder in params.event.added {
+                let worker = WorkspaceWorker::new(folder.uri);
+                // use default options
+                worker.init_linter(&Options::default()).await;
+                workers.push(worker);
+            }
+        }
+
+        // tell client to stop / start watching for files
+        if self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers) {
+            if !added_registrations.is_empty() {
+                if let Err(err) = self.client.register_capability(added_registrations).await {
+                    warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
+                }
+            }
+
+            if !removed_registrations.is_empty() {
+                if let Err(err) = self.client.unregister_capability(removed_registrations).await {
+                    warn!("sending unregisterCapability.didChangeWatchedFiles failed: {err}");
+                }
+            }
+        }
+    }
+
+    #[instrument(name = "textDocument/didSave", skip_all, fields(uri = params.text_document.uri.as_str()))]
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return;
+        };
+        if !worker.should_lint_on_run_type(Run::OnSave).await {
+            return;
+        }
+        if let Some(diagnostics) = worker.lint_file(uri, None).await {
+            self.client
+                .publish_diagnostics(
+                    uri.clone(),
+                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                    None,
+                )
+                .await;
+        }
+    }
+
+    /// When the document changed, it may not be written to disk, so we should
+    /// get the file context from the language client
+    #[instrument(name = "textDocument/didChange", skip_all, fields(uri = params.text_document.uri.as_str()))]
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return;
+        };
+        let content = params.content_changes.first().map(|c| c.text.clone());
+        if let Some(content) = &content {
+            worker.update_document(uri, content.clone());
+        }
+        if !worker.should_lint_on_run_type(Run::OnType).await {
+            return;
+        }
+        if let Some(diagnostics) = worker.lint_file(uri, content).await {
+            self.client
+                .publish_diagnostics(
+                    uri.clone(),
+                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                    Some(params.text_document.version),
+                )
+                .await;
+        }
+    }
+
+    #[instrument(name = "textDocument/didOpen", skip_all, fields(uri = params.text_document.uri.as_str()))]
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        self.ensure_single_file_worker(uri, &params.text_document.language_id).await;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return;
+        };
+
+        let content = params.text_document.text;
+        worker.update_document(uri, content.clone());
+        if let Some(diagnostics) = worker.lint_file(uri, Some(content)).await {
+            self.client
+                .publish_diagnostics(
+                    uri.clone(),
+                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                    Some(params.text_document.version),
+                )
+                .await;
+        }
+    }
+
+    #[instrument(name = "textDocument/didClose", skip_all, fields(uri = params.text_document.uri.as_str()))]
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        let mut workers = self.workspace_workers.write().await;
+        let Some(index) = workers.iter().position(|worker| worker.is_responsible_for_uri(uri))
+        else {
+            return;
+        };
+        workers[index].remove_diagnostics(&params.text_document.uri);
+        workers[index].remove_document(&params.text_document.uri);
+        // single file workers only exist as long as their document is open
+        if workers[index].is_single_file() {
+            workers.remove(index);
+        }
+    }
+
+    #[instrument(name = "textDocument/codeAction", skip_all, fields(uri = params.text_document.uri.as_str()))]
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+
+        let is_source_fix_all_oxc = params
+            .context
+            .only
+            .is_some_and(|only| only.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
+
+        let resolve =
+            self.capabilities.get().is_some_and(|capabilities| capabilities.code_action_resolve);
+
+        let code_actions = worker
+            .get_code_actions_or_commands(uri, &params.range, is_source_fix_all_oxc, resolve)
+            .await;
+
+        if code_actions.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(code_actions))
+    }
+
+    #[instrument(name = "codeAction/resolve", skip_all, fields(title = params.title.as_str()))]
+    async fn code_action_resolve(&self, params: CodeAction) -> Result<CodeAction> {
+        let Some(data) = IgnoreCodeActionData::from_code_action(&params) else {
+            return Ok(params);
+        };
+        let Ok(uri) = Uri::from_str(&data.uri) else {
+            return Ok(params);
+        };
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(&uri)) else {
+            return Ok(params);
+        };
+
+        Ok(worker.resolve_code_action(params, &data).await)
+    }
+
+    #[instrument(name = "textDocument/documentHighlight", skip_all, fields(uri = params.text_document_position_params.text_document.uri.as_str()))]
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+
+        Ok(worker.get_document_highlights(uri, params.text_document_position_params.position))
+    }
+
+    #[instrument(name = "workspace/executeCommand", skip_all, fields(command = params.command))]
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        if params.command == FIX_ALL_COMMAND_ID {
+            if !self.capabilities.get().unwrap().workspace_apply_edit {
+                return Err(Error::invalid_params("client does not support workspace apply edit"));
+            }
+
+            let args =
+                FixAllCommandArgs::try_from(params.arguments).map_err(Error::invalid_params)?;
+
+            let uri = &Uri::from_str(&args.uri).unwrap();
+            let workers = self.workspace_workers.read().await;
+            let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri))
+            else {
+                return Ok(None);
+            };
+
+            let text_edits = worker.get_diagnostic_text_edits(uri).await;
+
+            self.client
+                .apply_edit(WorkspaceEdit {
+                    #[expect(clippy::disallowed_types)]
+                    changes: Some(std::collections::HashMap::from([(uri.clone(), text_edits)])),
+                    document_changes: None,
+                    change_annotations: None,
+                })
+                .await?;
+
+            return Ok(None);
+        }
+
+        if params.command == FIX_ALL_WORKSPACE_COMMAND_ID {
+            let capabilities = self.capabilities.get().unwrap();
+            if !capabilities.workspace_apply_edit {
+                return Err(Error::invalid_params("client does not support workspace apply edit"));
+            }
+
+            let workers = self.workspace_workers.read().await;
+            let files = workers
+                .iter()
+                .flat_map(WorkspaceWorker::get_workspace_fix_edits)
+                .collect::<Vec<_>>();
+            if files.is_empty() {
+                return Ok(None);
+            }
+
+            // Clients supporting change annotations show a preview of the fixes of all files
+            // and ask the user to confirm them.
+            let edit =
+                fix_all_workspace_edit(files, capabilities.workspace_edit_change_annotations);
+            let response = self.client.apply_edit(edit).await?;
+            if !response.applied {
+                debug!("fixes of the workspace were not applied: {:?}", response.failure_reason);
+            }
+
+            return Ok(None);
+        }
+
+        Err(Error::invalid_request())
+    }
+}
+
+impl Backend {
+    /// `$/setTrace` notification, which changes the verbosity of `$/logTrace` notifications.
+    #[expect(clippy::unused_async)] // `LspServiceBuilder::custom_method` requires an async method
+    async fn set_trace(&self, params: SetTraceParams) {
+        self.trace_state.set_trace(params.value);
+    }
+
+    /// `oxc/serverStats` request, which returns the lint counts, durations and cache hit rates of each
+    /// workspace, for users to attach to performance issues. The stats are never sent anywhere else.
+    async fn server_stats(&self) -> Result<ServerStats> {
+        let workers = self.workspace_workers.read().await;
+        let workspaces = join_all(workers.iter().map(WorkspaceWorker::stats)).await;
+        Ok(ServerStats { workspaces })
+    }
+
+    /// Documents which are not part of any workspace folder (e.g. single opened files or untitled buffers)
+    /// get their own worker, which resolves the config from the directory of the file.
+    async fn ensure_single_file_worker(&self, uri: &Uri, language_id: &str) {
+        if self
+            .workspace_workers
+            .read()
+            .await
+            .iter()
+            .any(|worker| worker.is_responsible_for_uri(uri))
+        {
+            return;
+        }
+        let Some(worker) = WorkspaceWorker::new_single_file(uri, language_id) else {
+            return;
+        };
+        debug!("creating single file worker for {}", uri.as_str());
+        worker.init_linter(&self.single_file_options(uri).await).await;
+
+        let mut workers = self.workspace_workers.write().await;
+        // another request could have created a worker while the linter was initialized
+        if !workers.iter().any(|worker| worker.is_responsible_for_uri(uri)) {
+            workers.push(worker);
+        }
+    }
+
+    /// Options of the worker of a document outside of any workspace folder: the configuration of
+    /// the document when the client supports `workspace/configuration`, or else the options the
+    /// client sent without a workspace folder.
+    async fn single_file_options(&self, uri: &Uri) -> Options {
+        if self.capabilities.get().is_some_and(|capabilities| capabilities.workspace_configuration)
+        {
+            if let Some(Some(options)) = self.request_workspace_configuration(vec![uri]).await.pop()
+            {
+                return options;
+            }
+        }
+        self.global_options.read().await.clone().unwrap_or_default()
+    }
+
+    /// Request the workspace configuration from the client
+    /// and return the options for each workspace folder.
+    /// The check if the client support workspace configuration, should be done before.
+    async fn request_workspace_configuration(&self, uris: Vec<&Uri>) -> Vec<Option<Options>> {
+        let length = uris.len();
+        let config_items = uris
+            .into_iter()
+            .map(|uri| ConfigurationItem {
+                scope_uri: Some(uri.clone()),
+                section: Some("oxc_language_server".into()),
+            })
+            .collect::<Vec<_>>();
+
+        let Ok(configs) = self.client.configuration(config_items).await else {
+            debug!("failed to get configuration");
+            // return none for each workspace folder
+            return vec![None; length];
+        };
+
+        let mut options = vec![];
+        for config in configs {
+            options.push(serde_json::from_value::<Options>(config).ok());
+        }
+
+        debug_assert!(
+            options.len() == length,
+            "the number of configuration items should be the same as the number of workspace folders"
+        );
+
+        options
+    }
+
+    // clears all diagnostics for workspace folders
+    async fn clear_all_diagnostics(&self) {
+        let mut cleared_diagnostics = vec![];
+        for worker in self.workspace_workers.read().await.iter() {
+            cleared_diagnostics.extend(worker.get_clear_diagnostics());
+        }
+        self.publish_all_diagnostics(&cleared_diagnostics).await;
+    }
+
+    async fn publish_all_diagnostics(&self, result: &[(String, Vec<Diagnostic>)]) {
+        join_all(result.iter().map(|(path, diagnostics)| {
+            self.client.publish_diagnostics(Uri::from_str(path).unwrap(), diagnostics.clone(), None)
+        }))
+        .await;
+    }
+}
+
+/// Logs to stderr, filtered by `RUST_LOG` (e.g. `RUST_LOG=oxc_language_server=debug`),
+/// and to the client with `$/logTrace` notifications, filtered by the trace value of the client.
+fn init_tracing(lsp_layer: LspTraceLayer) {
+    use tracing::Level;
+    use tracing_subscriber::{filter::Targets, prelude::*};
+
+    // Usage without the `regex` feature.
+    // <https://github.com/tokio-rs/tracing/issues/1436#issuecomment-918528013>
+    let stderr_targets = std::env::var("RUST_LOG").map_or_else(
+        |_| Targets::new().with_default(Level::ERROR),
+        |env_var| env_var.parse::<Targets>().unwrap_or_default(),
+    );
+    let lsp_targets = Targets::new().with_target(env!("CARGO_CRATE_NAME"), Level::DEBUG);
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_filter(stderr_targets),
+        )
+        .with(lsp_layer.with_filter(lsp_targets))
+        .init();
+}
+
+/// Run the language server, communicating with the client over stdin and stdout.
+///
+/// Must be called at most once, because it installs the global tracing subscriber.
+pub async fn run_stdio() {
+    let trace_state = Arc::new(TraceState::default());
+    let (trace_sender, trace_receiver) = tokio::sync::mpsc::unbounded_channel();
+    init_tracing(LspTraceLayer::new(Arc::clone(&trace_state), trace_sender));
+
+    let stdin = tokio::io::stdin();
+    let stdout = tokio::io::stdout();
+
+    let (service, socket) = LspService::build(|client| {
+        tokio::spawn(trace::forward_notifications(client.clone(), trace_receiver));
+        Backend {
+            client,
+            workspace_workers: Arc::new(RwLock::new(vec![])),
+            global_options: RwLock::new(None),
+            capabilities: OnceCell::new(),
+            trace_state,
+        }
+    })
+    .custom_method("$/setTrace", Backend::set_trace)
+    .custom_method("oxc/serverStats", Backend::server_stats)
+    .finish();
+
+    Server::new(stdin, stdout, socket).serve(service).await;
+}
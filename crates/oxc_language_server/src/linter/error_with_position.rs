@@ -16,6 +16,11 @@ pub struct DiagnosticReport {
     pub diagnostic: lsp_types::Diagnostic,
     pub fixed_content: PossibleFixContent,
     pub rule_name: Option<String>,
+    /// Edits which insert a comment disabling the rule for the line of the diagnostic.
+    pub disable_line_fixes: Vec<FixedContent>,
+    /// Edit which appends a comment disabling the rule to the line of the diagnostic,
+    /// `None` when the line can't end with a comment.
+    pub disable_same_line_fix: Option<FixedContent>,
 }
 
 #[derive(Debug, Clone)]
@@ -147,5 +152,14 @@ pub fn message_with_position_to_lsp_diagnostic_report(
             ),
         },
         rule_name: message.code.number.as_ref().map(std::string::ToString::to_string),
+        disable_line_fixes: message
+            .disable_line_fixes
+            .iter()
+            .map(fix_with_position_to_fix_content)
+            .collect(),
+        disable_same_line_fix: message
+            .disable_same_line_fix
+            .as_ref()
+            .map(fix_with_position_to_fix_content),
     }
 }
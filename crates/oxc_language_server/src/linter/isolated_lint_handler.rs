@@ -3,14 +3,11 @@ use std::{
     sync::{Arc, OnceLock},
 };
 
-use log::debug;
 use rustc_hash::FxHashSet;
-use tower_lsp_server::{
-    UriExt,
-    lsp_types::{self, DiagnosticRelatedInformation, DiagnosticSeverity, Uri},
-};
+use tower_lsp_server::lsp_types::{self, DiagnosticRelatedInformation, DiagnosticSeverity, Uri};
+use tracing::debug;
 
-use oxc_allocator::{Allocator, AllocatorPool};
+use oxc_allocator::{Allocator, AllocatorPool, AllocatorPoolStats};
 use oxc_linter::{
     ConfigStore, LINTABLE_EXTENSIONS, LintOptions, LintService, LintServiceOptions, Linter,
     MessageWithPosition, loader::Loader, read_to_arena_str,
@@ -76,20 +73,23 @@ impl IsolatedLintHandler {
         Self { service }
     }
 
+    pub fn allocator_pool_stats(&self) -> AllocatorPoolStats {
+        self.service.allocator_pool_stats()
+    }
+
     pub fn run_single(
         &mut self,
         uri: &Uri,
+        path: &Path,
         content: Option<String>,
     ) -> Option<Vec<DiagnosticReport>> {
-        let path = uri.to_file_path()?;
-
-        if !Self::should_lint_path(&path) {
+        if !Self::should_lint_path(path) {
             return None;
         }
 
         let allocator = Allocator::default();
 
-        Some(self.lint_path(&allocator, &path, content).map_or(vec![], |errors| {
+        Some(self.lint_path(&allocator, path, content).map_or(vec![], |errors| {
             let mut diagnostics: Vec<DiagnosticReport> = errors
                 .iter()
                 .map(|e| message_with_position_to_lsp_diagnostic_report(e, uri))
@@ -128,6 +128,8 @@ impl IsolatedLintHandler {
                         },
                         fixed_content: PossibleFixContent::None,
                         rule_name: None,
+                        disable_line_fixes: vec![],
+                        disable_same_line_fix: None,
                     });
                 }
             }
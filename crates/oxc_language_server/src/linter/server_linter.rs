@@ -3,11 +3,12 @@ use std::sync::Arc;
 
 use globset::Glob;
 use ignore::gitignore::Gitignore;
-use log::{debug, warn};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tokio::sync::Mutex;
 use tower_lsp_server::lsp_types::Uri;
+use tracing::{debug, error, warn};
 
+use oxc_allocator::AllocatorPoolStats;
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions,
     Oxlintrc,
@@ -31,15 +32,30 @@ pub struct ServerLinter {
 
 impl ServerLinter {
     pub fn new(root_uri: &Uri, options: &Options) -> Self {
+        Self::create(root_uri, options, false)
+    }
+
+    /// Creates a linter for a single document outside of any workspace folder.
+    /// Nested configs are not searched and only the ignore files of the root directory are respected,
+    /// because the root can be a large directory which was never opened as a workspace.
+    pub fn new_single_file(root_uri: &Uri, options: &Options) -> Self {
+        Self::create(root_uri, options, true)
+    }
+
+    fn create(root_uri: &Uri, options: &Options, single_file: bool) -> Self {
         let root_path = root_uri.to_file_path().unwrap();
-        let (nested_configs, mut extended_paths) = Self::create_nested_configs(&root_path, options);
+        let (nested_configs, mut extended_paths) = if single_file {
+            (ConcurrentHashMap::default(), Vec::new())
+        } else {
+            Self::create_nested_configs(&root_path, options)
+        };
         let config_path = options.config_path.as_ref().map_or(OXC_CONFIG_FILE, |v| v);
         let config = normalize_path(root_path.join(config_path));
         let oxlintrc = if config.try_exists().is_ok_and(|exists| exists) {
             if let Ok(oxlintrc) = Oxlintrc::from_file(&config) {
                 oxlintrc
             } else {
-                warn!("Failed to initialize oxlintrc config: {}", config.to_string_lossy());
+                error!("Failed to initialize oxlintrc config: {}", config.to_string_lossy());
                 Oxlintrc::default()
             }
         } else {
@@ -60,7 +76,7 @@ impl ServerLinter {
         .unwrap_or_default();
 
         // TODO(refactor): pull this into a shared function, because in oxlint we have the same functionality.
-        let use_nested_config = options.use_nested_configs();
+        let use_nested_config = !single_file && options.use_nested_configs();
 
         let use_cross_module = config_builder.plugins().has_import()
             || (use_nested_config
@@ -101,7 +117,7 @@ impl ServerLinter {
 
         Self {
             isolated_linter: Arc::new(Mutex::new(isolated_linter)),
-            gitignore_glob: Self::create_ignore_glob(&root_path, &oxlintrc),
+            gitignore_glob: Self::create_ignore_glob(&root_path, &oxlintrc, single_file),
             extended_paths,
         }
     }
@@ -129,7 +145,7 @@ impl ServerLinter {
             };
 
             let Ok(oxlintrc) = Oxlintrc::from_file(file_path) else {
-                warn!("Skipping invalid config file: {}", file_path.display());
+                error!("Skipping invalid config file: {}", file_path.display());
                 continue;
             };
             let Ok(config_store_builder) = ConfigStoreBuilder::from_oxlintrc(
@@ -138,7 +154,7 @@ impl ServerLinter {
                 None,
                 &mut ExternalPluginStore::default(),
             ) else {
-                warn!("Skipping config (builder failed): {}", file_path.display());
+                error!("Skipping config (builder failed): {}", file_path.display());
                 continue;
             };
             extended_paths.extend(config_store_builder.extended_paths.clone());
@@ -148,7 +164,11 @@ impl ServerLinter {
         (nested_configs, extended_paths)
     }
 
-    fn create_ignore_glob(root_path: &Path, oxlintrc: &Oxlintrc) -> Vec<Gitignore> {
+    fn create_ignore_glob(
+        root_path: &Path,
+        oxlintrc: &Oxlintrc,
+        single_file: bool,
+    ) -> Vec<Gitignore> {
         let mut builder = globset::GlobSetBuilder::new();
         // Collecting all ignore files
         builder.add(Glob::new("**/.eslintignore").unwrap());
@@ -160,6 +180,7 @@ impl ServerLinter {
             .ignore(true)
             .hidden(false)
             .git_global(false)
+            .max_depth(single_file.then_some(1))
             .build()
             .flatten();
 
@@ -196,31 +217,36 @@ impl ServerLinter {
         gitignore_globs
     }
 
-    fn is_ignored(&self, uri: &Uri) -> bool {
+    fn is_ignored(&self, path: &Path) -> bool {
         for gitignore in &self.gitignore_glob {
-            if let Some(uri_path) = uri.to_file_path() {
-                if !uri_path.starts_with(gitignore.path()) {
-                    continue;
-                }
-                if gitignore.matched_path_or_any_parents(&uri_path, uri_path.is_dir()).is_ignore() {
-                    debug!("ignored: {uri:?}");
-                    return true;
-                }
+            if !path.starts_with(gitignore.path()) {
+                continue;
+            }
+            if gitignore.matched_path_or_any_parents(path, path.is_dir()).is_ignore() {
+                debug!("ignored: {}", path.display());
+                return true;
             }
         }
         false
     }
 
+    /// Lints the document with the given `uri`. `path` is used for config resolution and
+    /// does not need to exist on disk when `content` is provided.
     pub async fn run_single(
         &self,
         uri: &Uri,
+        path: &Path,
         content: Option<String>,
     ) -> Option<Vec<DiagnosticReport>> {
-        if self.is_ignored(uri) {
+        if self.is_ignored(path) {
             return None;
         }
 
-        self.isolated_linter.lock().await.run_single(uri, content)
+        self.isolated_linter.lock().await.run_single(uri, path, content)
+    }
+
+    pub async fn allocator_pool_stats(&self) -> AllocatorPoolStats {
+        self.isolated_linter.lock().await.allocator_pool_stats()
     }
 }
 
@@ -1,9 +1,9 @@
-use log::info;
 use oxc_linter::FixKind;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde::{Deserialize, Deserializer, Serialize, de::Error};
 use serde_json::Value;
 use tower_lsp_server::lsp_types::Uri;
+use tracing::info;
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
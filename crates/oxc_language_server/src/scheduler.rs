@@ -0,0 +1,125 @@
+use std::{
+    collections::BinaryHeap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::ConcurrentHashMap;
+
+/// How urgently a document is linted when all documents of a workspace are linted again,
+/// e.g. after a configuration change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Documents which are not open, e.g. files which were saved and closed.
+    Background,
+    /// Documents which are open in the editor.
+    Open,
+    /// The document which was opened, changed or saved last, which is most likely the focused one.
+    Active,
+}
+
+/// Orders the documents of a worker for linting, so the documents the user is working on are
+/// linted before the others.
+///
+/// The language server protocol does not tell which documents are focused or visible, so the
+/// documents which were used most recently come first.
+#[derive(Default)]
+pub struct Scheduler {
+    clock: AtomicU64,
+    /// When each open document was last opened, changed or saved.
+    open_documents: ConcurrentHashMap<String, u64>,
+}
+
+impl Scheduler {
+    /// Records that the document was opened, changed or saved.
+    pub fn touch(&self, uri: &str) {
+        let now = self.clock.fetch_add(1, Ordering::Relaxed) + 1;
+        self.open_documents.pin().insert(uri.to_string(), now);
+    }
+
+    pub fn close(&self, uri: &str) {
+        self.open_documents.pin().remove(uri);
+    }
+
+    /// The current time of the scheduler, to find documents which were used after it with
+    /// [`Scheduler::used_since`].
+    pub fn now(&self) -> u64 {
+        self.clock.load(Ordering::Relaxed)
+    }
+
+    pub fn used_since(&self, uri: &str, time: u64) -> bool {
+        self.open_documents.pin().get(uri).is_some_and(|last_used| *last_used > time)
+    }
+
+    /// Queue `uris`, the most urgent documents are returned first.
+    pub fn queue(&self, uris: impl IntoIterator<Item = String>) -> LintQueue {
+        let open_documents = self.open_documents.pin();
+        let active = open_documents.values().max().copied();
+        let heap = uris
+            .into_iter()
+            .map(|uri| {
+                let last_used = open_documents.get(&uri).copied();
+                let priority = match last_used {
+                    Some(last_used) if Some(last_used) == active => Priority::Active,
+                    Some(_) => Priority::Open,
+                    None => Priority::Background,
+                };
+                QueuedDocument { priority, last_used: last_used.unwrap_or_default(), uri }
+            })
+            .collect();
+        LintQueue { heap }
+    }
+}
+
+/// A priority queue of documents, see [`Scheduler::queue`].
+pub struct LintQueue {
+    heap: BinaryHeap<QueuedDocument>,
+}
+
+impl Iterator for LintQueue {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop().map(|document| document.uri)
+    }
+}
+
+/// Ordered by priority, then by how recently the document was used.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct QueuedDocument {
+    priority: Priority,
+    last_used: u64,
+    uri: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scheduler;
+
+    #[test]
+    fn test_queue_order() {
+        let scheduler = Scheduler::default();
+        scheduler.touch("file:///a.js");
+        scheduler.touch("file:///b.js");
+        scheduler.touch("file:///c.js");
+        scheduler.touch("file:///a.js");
+        scheduler.close("file:///c.js");
+
+        let uris = ["file:///c.js", "file:///a.js", "file:///b.js"].map(String::from);
+        assert_eq!(
+            scheduler.queue(uris).collect::<Vec<_>>(),
+            ["file:///a.js", "file:///b.js", "file:///c.js"]
+        );
+    }
+
+    #[test]
+    fn test_used_since() {
+        let scheduler = Scheduler::default();
+        scheduler.touch("file:///a.js");
+        let time = scheduler.now();
+        assert!(!scheduler.used_since("file:///a.js", time));
+
+        scheduler.touch("file:///a.js");
+        assert!(scheduler.used_since("file:///a.js", time));
+        assert!(!scheduler.used_since("file:///b.js", time));
+    }
+}
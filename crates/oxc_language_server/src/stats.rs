@@ -0,0 +1,192 @@
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+
+use serde::Serialize;
+use tower_lsp_server::lsp_types::Uri;
+
+use oxc_allocator::AllocatorPoolStats;
+
+/// Number of most recent lint durations which the averages and percentiles are computed from.
+const MAX_DURATIONS: usize = 1024;
+
+/// Response of the `oxc/serverStats` request.
+///
+/// Nothing is sent anywhere, the numbers are only collected so users can attach them
+/// when reporting performance issues.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStats {
+    pub workspaces: Vec<WorkspaceStats>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceStats {
+    pub workspace_uri: Uri,
+    /// Number of documents linted since the worker was created.
+    pub lint_count: u64,
+    /// Durations of the most recent lints, in milliseconds.
+    pub lint_duration: LintDurationStats,
+    /// Lookups of the diagnostics of a document for code actions and the `oxc.fixAll` command.
+    /// A miss lints the document again.
+    pub diagnostics_cache: CacheStats,
+    /// `None` when the linter is not initialized yet.
+    pub allocator_pool: Option<ArenaPoolStats>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintDurationStats {
+    pub average: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// `None` when there were no lookups.
+    pub hit_rate: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaPoolStats {
+    /// Allocators ready to be re-used.
+    pub idle: usize,
+    /// Allocators created since the linter was created.
+    pub created: usize,
+}
+
+impl From<AllocatorPoolStats> for ArenaPoolStats {
+    fn from(stats: AllocatorPoolStats) -> Self {
+        Self { idle: stats.idle, created: stats.created }
+    }
+}
+
+/// Collects the lint counts, durations and cache lookups of a worker.
+#[derive(Default)]
+pub struct LintStats {
+    inner: Mutex<LintStatsInner>,
+}
+
+#[derive(Default)]
+struct LintStatsInner {
+    lint_count: u64,
+    durations: VecDeque<Duration>,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+impl LintStats {
+    pub fn record_lint(&self, duration: Duration) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.lint_count += 1;
+        if inner.durations.len() == MAX_DURATIONS {
+            inner.durations.pop_front();
+        }
+        inner.durations.push_back(duration);
+    }
+
+    pub fn record_cache_lookup(&self, hit: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        if hit {
+            inner.cache_hits += 1;
+        } else {
+            inner.cache_misses += 1;
+        }
+    }
+
+    pub fn lint_count(&self) -> u64 {
+        self.inner.lock().unwrap().lint_count
+    }
+
+    pub fn lint_duration(&self) -> LintDurationStats {
+        let mut durations =
+            self.inner.lock().unwrap().durations.iter().copied().collect::<Vec<_>>();
+        if durations.is_empty() {
+            return LintDurationStats::default();
+        }
+        durations.sort_unstable();
+
+        #[expect(clippy::cast_precision_loss)]
+        let average =
+            durations.iter().sum::<Duration>().as_secs_f64() * 1000.0 / durations.len() as f64;
+        // Nearest-rank percentile
+        let percentile = |p: usize| {
+            let rank = (durations.len() * p).div_ceil(100).max(1);
+            as_millis(durations[rank - 1])
+        };
+        LintDurationStats {
+            average,
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: as_millis(durations[durations.len() - 1]),
+        }
+    }
+
+    pub fn diagnostics_cache(&self) -> CacheStats {
+        let inner = self.inner.lock().unwrap();
+        let (hits, misses) = (inner.cache_hits, inner.cache_misses);
+        let total = hits + misses;
+        #[expect(clippy::cast_precision_loss)]
+        let hit_rate = (total > 0).then(|| hits as f64 / total as f64);
+        CacheStats { hits, misses, hit_rate }
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CacheStats, LintDurationStats, LintStats, MAX_DURATIONS};
+
+    #[test]
+    fn test_lint_duration() {
+        let stats = LintStats::default();
+        assert_eq!(stats.lint_duration(), LintDurationStats::default());
+
+        for ms in (1..=100).rev() {
+            stats.record_lint(Duration::from_millis(ms));
+        }
+        assert_eq!(stats.lint_count(), 100);
+        assert_eq!(
+            stats.lint_duration(),
+            LintDurationStats { average: 50.5, p50: 50.0, p90: 90.0, p99: 99.0, max: 100.0 }
+        );
+    }
+
+    #[test]
+    fn test_lint_duration_keeps_recent() {
+        let stats = LintStats::default();
+        stats.record_lint(Duration::from_secs(10));
+        for _ in 0..MAX_DURATIONS {
+            stats.record_lint(Duration::from_millis(2));
+        }
+        assert_eq!(stats.lint_count(), MAX_DURATIONS as u64 + 1);
+        assert!((stats.lint_duration().max - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_diagnostics_cache() {
+        let stats = LintStats::default();
+        assert_eq!(stats.diagnostics_cache(), CacheStats { hits: 0, misses: 0, hit_rate: None });
+
+        stats.record_cache_lookup(true);
+        stats.record_cache_lookup(true);
+        stats.record_cache_lookup(true);
+        stats.record_cache_lookup(false);
+        assert_eq!(
+            stats.diagnostics_cache(),
+            CacheStats { hits: 3, misses: 1, hit_rate: Some(0.75) }
+        );
+    }
+}
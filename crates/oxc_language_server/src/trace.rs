@@ -0,0 +1,322 @@
+use std::{
+    fmt::{self, Write},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU8, Ordering},
+    },
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tower_lsp_server::{
+    Client,
+    lsp_types::{
+        LogTraceParams, TraceValue,
+        notification::{LogTrace, Notification},
+    },
+};
+use tracing::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+    span,
+};
+use tracing_subscriber::{Layer, layer::Context, registry::LookupSpan};
+
+/// Experimental notification which asks the client to reveal its output channel,
+/// because the server logged an error.
+///
+/// Only sent when the client sets `showOutputChannelOnError` in its experimental capabilities.
+pub enum ShowOutputChannelOnError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct ShowOutputChannelOnErrorParams {
+    /// The error which was logged.
+    pub message: String,
+}
+
+impl Notification for ShowOutputChannelOnError {
+    type Params = ShowOutputChannelOnErrorParams;
+    const METHOD: &'static str = "oxc/showOutputChannelOnError";
+}
+
+/// Notifications produced by [`LspTraceLayer`], which are sent to the client by [`forward_notifications`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum TraceNotification {
+    LogTrace(LogTraceParams),
+    ShowOutputChannelOnError(ShowOutputChannelOnErrorParams),
+}
+
+/// Trace settings of the client, shared between the [`Backend`](crate::Backend) and [`LspTraceLayer`].
+#[derive(Debug, Default)]
+pub struct TraceState {
+    trace: AtomicU8,
+    show_output_channel_on_error: AtomicBool,
+}
+
+impl TraceState {
+    /// Set the verbosity of `$/logTrace` notifications,
+    /// from the `trace` of the `initialize` request or a `$/setTrace` notification.
+    pub fn set_trace(&self, value: TraceValue) {
+        let value = match value {
+            TraceValue::Off => 0,
+            TraceValue::Messages => 1,
+            TraceValue::Verbose => 2,
+        };
+        self.trace.store(value, Ordering::Relaxed);
+    }
+
+    fn trace(&self) -> TraceValue {
+        match self.trace.load(Ordering::Relaxed) {
+            0 => TraceValue::Off,
+            1 => TraceValue::Messages,
+            _ => TraceValue::Verbose,
+        }
+    }
+
+    pub fn set_show_output_channel_on_error(&self, value: bool) {
+        self.show_output_channel_on_error.store(value, Ordering::Relaxed);
+    }
+}
+
+/// A [`Layer`] which reports events and the duration of spans to the client
+/// with `$/logTrace` notifications.
+///
+/// * `off`: nothing is reported.
+/// * `messages`: events with level `INFO` or higher and the duration of every closed span are reported.
+/// * `verbose`: events of all levels are reported, with their fields and the spans they occurred in.
+pub struct LspTraceLayer {
+    state: Arc<TraceState>,
+    sender: UnboundedSender<TraceNotification>,
+}
+
+impl LspTraceLayer {
+    pub fn new(state: Arc<TraceState>, sender: UnboundedSender<TraceNotification>) -> Self {
+        Self { state, sender }
+    }
+
+    fn log_trace(&self, message: String, verbose: Option<String>) {
+        // The receiver is only dropped when the server shuts down.
+        let _ = self.sender.send(TraceNotification::LogTrace(LogTraceParams { message, verbose }));
+    }
+}
+
+/// Start time and fields of a span, stored in its extensions.
+struct SpanTiming {
+    start: Instant,
+    fields: String,
+}
+
+impl<S> Layer<S> for LspTraceLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if self.state.trace() == TraceValue::Off {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = FieldFormatter::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanTiming { start: Instant::now(), fields: fields.fields });
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let trace = self.state.trace();
+        if trace == TraceValue::Off {
+            return;
+        }
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+        let message = format!("{} finished in {:.2?}", span.name(), timing.start.elapsed());
+        let verbose = (trace == TraceValue::Verbose)
+            .then(|| format!("{}{{{}}}", span.name(), timing.fields.trim_start()));
+        self.log_trace(message, verbose);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let level = *event.metadata().level();
+        let trace = self.state.trace();
+        let show_output_channel = level == Level::ERROR
+            && self.state.show_output_channel_on_error.load(Ordering::Relaxed);
+        if !show_output_channel
+            && (trace == TraceValue::Off || (trace == TraceValue::Messages && level > Level::INFO))
+        {
+            return;
+        }
+
+        let mut fields = FieldFormatter::default();
+        event.record(&mut fields);
+
+        if show_output_channel {
+            let _ = self.sender.send(TraceNotification::ShowOutputChannelOnError(
+                ShowOutputChannelOnErrorParams { message: fields.message.clone() },
+            ));
+        }
+
+        let message = format!("[{level}] {}", fields.message);
+        match trace {
+            TraceValue::Off => {}
+            TraceValue::Messages => self.log_trace(message, None),
+            TraceValue::Verbose => {
+                let mut verbose = format!("target: {}", event.metadata().target());
+                if !fields.fields.is_empty() {
+                    let _ = write!(verbose, "\nfields:{}", fields.fields);
+                }
+                if let Some(scope) = ctx.event_scope(event) {
+                    for span in scope {
+                        let _ = write!(verbose, "\nin {}", span.name());
+                        if let Some(timing) = span.extensions().get::<SpanTiming>() {
+                            let _ = write!(verbose, "{{{}}}", timing.fields.trim_start());
+                        }
+                    }
+                }
+                self.log_trace(message, Some(verbose));
+            }
+        }
+    }
+}
+
+/// Formats the `message` field and all other fields as ` name=value` pairs.
+#[derive(Default)]
+struct FieldFormatter {
+    message: String,
+    fields: String,
+}
+
+impl Visit for FieldFormatter {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Send the notifications of [`LspTraceLayer`] to the client, until the layer is dropped.
+pub async fn forward_notifications(
+    client: Client,
+    mut receiver: UnboundedReceiver<TraceNotification>,
+) {
+    while let Some(notification) = receiver.recv().await {
+        match notification {
+            TraceNotification::LogTrace(params) => {
+                client.send_notification::<LogTrace>(params).await;
+            }
+            TraceNotification::ShowOutputChannelOnError(params) => {
+                client.send_notification::<ShowOutputChannelOnError>(params).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+    use tower_lsp_server::lsp_types::{LogTraceParams, TraceValue};
+    use tracing_subscriber::prelude::*;
+
+    use super::{LspTraceLayer, ShowOutputChannelOnErrorParams, TraceNotification, TraceState};
+
+    fn collect(
+        trace: TraceValue,
+        show_output_channel_on_error: bool,
+        f: impl FnOnce(),
+    ) -> Vec<TraceNotification> {
+        let state = Arc::new(TraceState::default());
+        state.set_trace(trace);
+        state.set_show_output_channel_on_error(show_output_channel_on_error);
+        let (sender, mut receiver): (_, UnboundedReceiver<_>) = unbounded_channel();
+        let subscriber = tracing_subscriber::registry().with(LspTraceLayer::new(state, sender));
+        tracing::subscriber::with_default(subscriber, f);
+
+        let mut notifications = vec![];
+        while let Ok(notification) = receiver.try_recv() {
+            notifications.push(notification);
+        }
+        notifications
+    }
+
+    fn log_trace(notification: &TraceNotification) -> &LogTraceParams {
+        match notification {
+            TraceNotification::LogTrace(params) => params,
+            TraceNotification::ShowOutputChannelOnError(_) => panic!("expected `$/logTrace`"),
+        }
+    }
+
+    fn lint_file() {
+        let _span = tracing::info_span!("textDocument/didSave", uri = "file:///a.js").entered();
+        tracing::debug!(rules = 3, "lint file");
+        tracing::info!("linted");
+    }
+
+    #[test]
+    fn off() {
+        assert!(collect(TraceValue::Off, false, lint_file).is_empty());
+    }
+
+    #[test]
+    fn messages() {
+        let notifications = collect(TraceValue::Messages, false, lint_file);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(
+            log_trace(&notifications[0]),
+            &LogTraceParams { message: "[INFO] linted".to_string(), verbose: None }
+        );
+        let span_closed = log_trace(&notifications[1]);
+        assert!(span_closed.message.starts_with("textDocument/didSave finished in "));
+        assert_eq!(span_closed.verbose, None);
+    }
+
+    #[test]
+    fn verbose() {
+        let notifications = collect(TraceValue::Verbose, false, lint_file);
+        assert_eq!(notifications.len(), 3);
+        assert_eq!(
+            log_trace(&notifications[0]),
+            &LogTraceParams {
+                message: "[DEBUG] lint file".to_string(),
+                verbose: Some(format!(
+                    "target: {}\nfields: rules=3\nin textDocument/didSave{{uri=file:///a.js}}",
+                    module_path!()
+                )),
+            }
+        );
+        assert_eq!(
+            log_trace(&notifications[2]).verbose.as_deref(),
+            Some("textDocument/didSave{uri=file:///a.js}")
+        );
+    }
+
+    #[test]
+    fn show_output_channel_on_error() {
+        let notifications = collect(TraceValue::Off, true, || {
+            tracing::warn!("not shown");
+            tracing::error!("invalid config");
+        });
+        assert_eq!(
+            notifications,
+            [TraceNotification::ShowOutputChannelOnError(ShowOutputChannelOnErrorParams {
+                message: "invalid config".to_string()
+            })]
+        );
+        assert!(collect(TraceValue::Off, false, || tracing::error!("invalid config")).is_empty());
+    }
+}
@@ -1,50 +1,129 @@
-use std::{str::FromStr, sync::Arc, vec};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Instant,
+    vec,
+};
 
-use log::debug;
-use rustc_hash::FxBuildHasher;
 use tokio::sync::{Mutex, RwLock};
 use tower_lsp_server::{
     UriExt,
     lsp_types::{
-        CodeActionOrCommand, Diagnostic, FileEvent, FileSystemWatcher, GlobPattern, OneOf, Range,
-        RelativePattern, TextEdit, Uri, WatchKind,
+        CodeAction, CodeActionOrCommand, Diagnostic, DocumentHighlight, FileEvent,
+        FileSystemWatcher, GlobPattern, OneOf, Position, Range, RelativePattern, TextEdit, Uri,
+        WatchKind,
     },
 };
+use tracing::debug;
 
 use crate::{
-    ConcurrentHashMap, Options, Run,
+    ConcurrentHashMap, OXC_CONFIG_FILE, Options, Run,
     code_actions::{
-        apply_all_fix_code_action, apply_fix_code_actions, ignore_this_line_code_action,
-        ignore_this_rule_code_action,
+        IgnoreCodeActionData, apply_all_fix_code_action, apply_fix_code_actions, fix_text_edits,
+        ignore_code_actions, resolve_ignore_code_action,
     },
+    document_highlight::document_highlights,
     linter::{
-        error_with_position::{DiagnosticReport, PossibleFixContent},
+        error_with_position::DiagnosticReport,
         server_linter::{ServerLinter, normalize_path},
     },
+    scheduler::Scheduler,
+    stats::{LintStats, WorkspaceStats},
 };
 
 pub struct WorkspaceWorker {
     root_uri: Uri,
+    /// Set when the worker was created for a document outside of any workspace folder.
+    single_file: Option<SingleFileDocument>,
     server_linter: RwLock<Option<ServerLinter>>,
     diagnostics_report_map: Arc<ConcurrentHashMap<String, Vec<DiagnosticReport>>>,
+    /// Content of the open documents, which may not be written to disk yet.
+    documents: ConcurrentHashMap<String, String>,
+    /// Orders the documents when all of them are linted again.
+    scheduler: Scheduler,
+    /// Lint counts and durations, for the `oxc/serverStats` request.
+    stats: LintStats,
     options: Mutex<Options>,
 }
 
+/// A document which is linted without a workspace folder, e.g. a single opened file or an untitled buffer.
+struct SingleFileDocument {
+    uri: Uri,
+    /// The path used for linting. Untitled buffers get a virtual path, which does not exist on disk.
+    path: PathBuf,
+}
+
 impl WorkspaceWorker {
     pub fn new(root_uri: Uri) -> Self {
         Self {
             root_uri,
+            single_file: None,
             server_linter: RwLock::new(None),
             diagnostics_report_map: Arc::new(ConcurrentHashMap::default()),
+            documents: ConcurrentHashMap::default(),
+            scheduler: Scheduler::default(),
+            stats: LintStats::default(),
             options: Mutex::new(Options::default()),
         }
     }
 
+    /// Creates a worker for a document which is not part of any workspace folder.
+    ///
+    /// The root of the worker is the nearest parent directory containing an `.oxlintrc.json`,
+    /// falling back to the directory of the file.
+    /// Untitled buffers are linted with a virtual path inside the current working directory,
+    /// the file extension is derived from the `language_id` of the document.
+    pub fn new_single_file(uri: &Uri, language_id: &str) -> Option<Self> {
+        let (root_path, path) = match uri.scheme()?.as_str() {
+            "file" => {
+                let path = uri.to_file_path()?.to_path_buf();
+                let dir = path.parent()?;
+                let root = dir
+                    .ancestors()
+                    .find(|dir| dir.join(OXC_CONFIG_FILE).is_file())
+                    .unwrap_or(dir)
+                    .to_path_buf();
+                (root, path)
+            }
+            "untitled" => {
+                let extension = language_id_to_extension(language_id)?;
+                let root = std::env::current_dir().ok()?;
+                let path = root.join(format!("untitled.{extension}"));
+                (root, path)
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            root_uri: Uri::from_file_path(root_path)?,
+            single_file: Some(SingleFileDocument { uri: uri.clone(), path }),
+            server_linter: RwLock::new(None),
+            diagnostics_report_map: Arc::new(ConcurrentHashMap::default()),
+            documents: ConcurrentHashMap::default(),
+            scheduler: Scheduler::default(),
+            stats: LintStats::default(),
+            options: Mutex::new(Options::default()),
+        })
+    }
+
     pub fn get_root_uri(&self) -> &Uri {
         &self.root_uri
     }
 
+    pub fn is_single_file(&self) -> bool {
+        self.single_file.is_some()
+    }
+
+    /// The uri of the document, when the worker was created for a single document.
+    pub fn single_file_uri(&self) -> Option<&Uri> {
+        self.single_file.as_ref().map(|single_file| &single_file.uri)
+    }
+
     pub fn is_responsible_for_uri(&self, uri: &Uri) -> bool {
+        if let Some(single_file) = &self.single_file {
+            return &single_file.uri == uri;
+        }
         if let Some(path) = uri.to_file_path() {
             return path.starts_with(self.root_uri.to_file_path().unwrap());
         }
@@ -53,7 +132,23 @@ impl WorkspaceWorker {
 
     pub async fn init_linter(&self, options: &Options) {
         *self.options.lock().await = options.clone();
-        *self.server_linter.write().await = Some(ServerLinter::new(&self.root_uri, options));
+        *self.server_linter.write().await = Some(self.create_server_linter(options));
+    }
+
+    fn create_server_linter(&self, options: &Options) -> ServerLinter {
+        if self.is_single_file() {
+            ServerLinter::new_single_file(&self.root_uri, options)
+        } else {
+            ServerLinter::new(&self.root_uri, options)
+        }
+    }
+
+    /// The path which should be linted for the given uri.
+    fn lint_path(&self, uri: &Uri) -> Option<PathBuf> {
+        match &self.single_file {
+            Some(single_file) if &single_file.uri == uri => Some(single_file.path.clone()),
+            _ => uri.to_file_path().map(|path| path.to_path_buf()),
+        }
     }
 
     // WARNING: start all programs (linter, formatter) before calling this function
@@ -114,11 +209,20 @@ impl WorkspaceWorker {
 
     pub fn remove_diagnostics(&self, uri: &Uri) {
         self.diagnostics_report_map.pin().remove(&uri.to_string());
+        self.scheduler.close(uri.as_str());
+    }
+
+    pub fn update_document(&self, uri: &Uri, content: String) {
+        self.documents.pin().insert(uri.to_string(), content);
+    }
+
+    pub fn remove_document(&self, uri: &Uri) {
+        self.documents.pin().remove(&uri.to_string());
     }
 
     async fn refresh_server_linter(&self) {
         let options = self.options.lock().await;
-        let server_linter = ServerLinter::new(&self.root_uri, &options);
+        let server_linter = self.create_server_linter(&options);
 
         *self.server_linter.write().await = Some(server_linter);
     }
@@ -141,6 +245,7 @@ impl WorkspaceWorker {
         uri: &Uri,
         content: Option<String>,
     ) -> Option<Vec<DiagnosticReport>> {
+        self.scheduler.touch(uri.as_str());
         let diagnostics = self.lint_file_internal(uri, content).await;
 
         if let Some(diagnostics) = &diagnostics {
@@ -158,37 +263,94 @@ impl WorkspaceWorker {
         let Some(server_linter) = &*self.server_linter.read().await else {
             return None;
         };
+        let path = self.lint_path(uri)?;
 
-        server_linter.run_single(uri, content).await
+        self.run_linter(server_linter, uri, &path, content).await
+    }
+
+    /// Lints the document and records how long it took, including waiting for other lints to finish.
+    async fn run_linter(
+        &self,
+        server_linter: &ServerLinter,
+        uri: &Uri,
+        path: &Path,
+        content: Option<String>,
+    ) -> Option<Vec<DiagnosticReport>> {
+        let start = Instant::now();
+        let diagnostics = server_linter.run_single(uri, path, content).await;
+        if diagnostics.is_some() {
+            self.stats.record_lint(start.elapsed());
+        }
+        diagnostics
+    }
+
+    /// Returns the diagnostics of the document, linting it when they are not cached.
+    async fn cached_diagnostics(&self, uri: &Uri) -> Vec<DiagnosticReport> {
+        let cached = self.diagnostics_report_map.pin().get(&uri.to_string()).cloned();
+        self.stats.record_cache_lookup(cached.is_some());
+        match cached {
+            Some(diagnostics) => diagnostics,
+            // code actions / commands can be requested without opening the file
+            // we just internally lint and provide the code actions / commands without refreshing the diagnostic map.
+            None => self.lint_file_internal(uri, None).await.unwrap_or_default(),
+        }
+    }
+
+    pub async fn stats(&self) -> WorkspaceStats {
+        let allocator_pool = match &*self.server_linter.read().await {
+            Some(server_linter) => Some(server_linter.allocator_pool_stats().await.into()),
+            None => None,
+        };
+        WorkspaceStats {
+            workspace_uri: self.root_uri.clone(),
+            lint_count: self.stats.lint_count(),
+            lint_duration: self.stats.lint_duration(),
+            diagnostics_cache: self.stats.diagnostics_cache(),
+            allocator_pool,
+        }
     }
 
     fn update_diagnostics(&self, uri: &Uri, diagnostics: &[DiagnosticReport]) {
         self.diagnostics_report_map.pin().insert(uri.to_string(), diagnostics.to_owned());
     }
 
-    async fn revalidate_diagnostics(&self) -> ConcurrentHashMap<String, Vec<DiagnosticReport>> {
-        let diagnostics_map = ConcurrentHashMap::with_capacity_and_hasher(
-            self.diagnostics_report_map.len(),
-            FxBuildHasher,
-        );
+    /// Lint all documents with diagnostics again, the documents which were used most recently first.
+    ///
+    /// Returns the new diagnostics in the order the documents were linted. Documents which are
+    /// linted with their new content while the others are linted again are skipped, their
+    /// diagnostics are already up to date.
+    async fn revalidate_diagnostics(&self) -> Vec<(String, Vec<DiagnosticReport>)> {
+        let mut diagnostics_list = vec![];
         let server_linter = self.server_linter.read().await;
         let Some(server_linter) = &*server_linter else {
             debug!("no server_linter initialized in the worker");
-            return diagnostics_map;
+            return diagnostics_list;
         };
 
-        for uri in self.diagnostics_report_map.pin_owned().keys() {
+        let started = self.scheduler.now();
+        let queue = self.scheduler.queue(self.diagnostics_report_map.pin().keys().cloned());
+        for uri in queue {
+            if self.scheduler.used_since(&uri, started) {
+                continue;
+            }
+            let parsed_uri = Uri::from_str(&uri).unwrap();
+            let Some(path) = self.lint_path(&parsed_uri) else {
+                self.diagnostics_report_map.pin().remove(&uri);
+                continue;
+            };
             if let Some(diagnostics) =
-                server_linter.run_single(&Uri::from_str(uri).unwrap(), None).await
+                self.run_linter(server_linter, &parsed_uri, &path, None).await
             {
                 self.diagnostics_report_map.pin().insert(uri.clone(), diagnostics.clone());
-                diagnostics_map.pin().insert(uri.clone(), diagnostics);
+                diagnostics_list.push((uri, diagnostics));
             } else {
-                self.diagnostics_report_map.pin().remove(uri);
+                self.diagnostics_report_map.pin().remove(&uri);
             }
+            // Let requests for the document the user is editing run between the other documents.
+            tokio::task::yield_now().await;
         }
 
-        diagnostics_map
+        diagnostics_list
     }
 
     pub fn get_clear_diagnostics(&self) -> Vec<(String, Vec<Diagnostic>)> {
@@ -199,19 +361,16 @@ impl WorkspaceWorker {
             .collect::<Vec<_>>()
     }
 
+    /// `resolve` is set when the client resolves the edits of code actions lazily,
+    /// see [`ignore_code_actions`].
     pub async fn get_code_actions_or_commands(
         &self,
         uri: &Uri,
         range: &Range,
         is_source_fix_all_oxc: bool,
+        resolve: bool,
     ) -> Vec<CodeActionOrCommand> {
-        let report_map_ref = self.diagnostics_report_map.pin_owned();
-        let value = match report_map_ref.get(&uri.to_string()) {
-            Some(value) => value,
-            // code actions / commands can be requested without opening the file
-            // we just internally lint and provide the code actions / commands without refreshing the diagnostic map.
-            None => &self.lint_file_internal(uri, None).await.unwrap_or_default(),
-        };
+        let value = &self.cached_diagnostics(uri).await;
 
         if value.is_empty() {
             return vec![];
@@ -228,6 +387,7 @@ impl WorkspaceWorker {
         }
 
         let mut code_actions_vec: Vec<CodeActionOrCommand> = vec![];
+        let mut ignorable_reports = vec![];
 
         for report in reports {
             let mut append_ignore_code_actions = true;
@@ -246,59 +406,77 @@ impl WorkspaceWorker {
             }
 
             if append_ignore_code_actions {
-                code_actions_vec.push(CodeActionOrCommand::CodeAction(
-                    ignore_this_line_code_action(report, uri),
-                ));
-
-                code_actions_vec.push(CodeActionOrCommand::CodeAction(
-                    ignore_this_rule_code_action(report, uri),
-                ));
+                ignorable_reports.push(report);
             }
         }
 
+        code_actions_vec.extend(
+            ignore_code_actions(&ignorable_reports, uri, resolve)
+                .into_iter()
+                .map(CodeActionOrCommand::CodeAction),
+        );
+
         code_actions_vec
     }
 
+    /// Compute the edit of a code action from [`ignore_code_actions`] for `codeAction/resolve`.
+    pub async fn resolve_code_action(
+        &self,
+        action: CodeAction,
+        data: &IgnoreCodeActionData,
+    ) -> CodeAction {
+        let Ok(uri) = Uri::from_str(&data.uri) else { return action };
+        let reports = self.cached_diagnostics(&uri).await;
+        resolve_ignore_code_action(action, data, reports.iter())
+    }
+
     /// This function is used for executing the `oxc.fixAll` command
     pub async fn get_diagnostic_text_edits(&self, uri: &Uri) -> Vec<TextEdit> {
-        let report_map_ref = self.diagnostics_report_map.pin_owned();
-        let value = match report_map_ref.get(&uri.to_string()) {
-            Some(value) => value,
-            // code actions / commands can be requested without opening the file
-            // we just internally lint and provide the code actions / commands without refreshing the diagnostic map.
-            None => &self.lint_file_internal(uri, None).await.unwrap_or_default(),
-        };
-
-        if value.is_empty() {
-            return vec![];
-        }
-
-        let mut text_edits = vec![];
-
-        for report in value {
-            let fix = match &report.fixed_content {
-                PossibleFixContent::None => None,
-                PossibleFixContent::Single(fixed_content) => Some(fixed_content),
-                // For multiple fixes, we take the first one as a representative fix.
-                // Applying all possible fixes at once is not possible in this context.
-                PossibleFixContent::Multiple(multi) => multi.first(),
-            };
+        let reports = self.cached_diagnostics(uri).await;
+        fix_text_edits(&reports).map(|(_, edit)| edit).collect()
+    }
 
-            if let Some(fixed_content) = &fix {
-                text_edits.push(TextEdit {
-                    range: fixed_content.range,
-                    new_text: fixed_content.code.clone(),
-                });
-            }
-        }
+    /// Edits of the fixes of all documents with diagnostics, ordered by uri, with the code of the
+    /// diagnostic of each fix. Documents without fixes are left out.
+    pub fn get_workspace_fix_edits(&self) -> Vec<(Uri, Vec<(Option<String>, TextEdit)>)> {
+        let mut files = self
+            .diagnostics_report_map
+            .pin()
+            .iter()
+            .filter_map(|(uri, reports)| {
+                let edits = fix_text_edits(reports)
+                    .map(|(code, edit)| (code.map(ToString::to_string), edit))
+                    .collect::<Vec<_>>();
+                if edits.is_empty() {
+                    return None;
+                }
+                Some((Uri::from_str(uri).ok()?, edits))
+            })
+            .collect::<Vec<_>>();
+        files.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        files
+    }
 
-        text_edits
+    /// Highlights the symbol or the disabled rule under the cursor.
+    /// Documents which are not open are read from disk.
+    pub fn get_document_highlights(
+        &self,
+        uri: &Uri,
+        position: Position,
+    ) -> Option<Vec<DocumentHighlight>> {
+        let path = self.lint_path(uri)?;
+        let documents = self.documents.pin();
+        let content = match documents.get(&uri.to_string()) {
+            Some(content) => content.clone(),
+            None => std::fs::read_to_string(&path).ok()?,
+        };
+        document_highlights(&path, &content, position)
     }
 
     pub async fn did_change_watched_files(
         &self,
         _file_event: &FileEvent,
-    ) -> Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>> {
+    ) -> Option<Vec<(String, Vec<DiagnosticReport>)>> {
         self.refresh_server_linter().await;
         Some(self.revalidate_diagnostics().await)
     }
@@ -306,7 +484,7 @@ impl WorkspaceWorker {
     pub async fn did_change_configuration(
         &self,
         changed_options: &Options,
-    ) -> (Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>>, Option<FileSystemWatcher>) {
+    ) -> (Option<Vec<(String, Vec<DiagnosticReport>)>>, Option<FileSystemWatcher>) {
         // clone the current options to avoid locking the mutex
         let current_option = &self.options.lock().await.clone();
 
@@ -351,9 +529,25 @@ fn range_overlaps(a: Range, b: Range) -> bool {
     a.start <= b.end && a.end >= b.start
 }
 
+/// Maps the language identifier of a text document to a file extension the linter can load.
+/// <https://code.visualstudio.com/docs/languages/identifiers#_known-language-identifiers>
+fn language_id_to_extension(language_id: &str) -> Option<&'static str> {
+    match language_id {
+        "javascript" => Some("js"),
+        "javascriptreact" => Some("jsx"),
+        "typescript" => Some("ts"),
+        "typescriptreact" => Some("tsx"),
+        "vue" => Some("vue"),
+        "svelte" => Some("svelte"),
+        "astro" => Some("astro"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tester::get_file_uri;
 
     #[test]
     fn test_get_root_uri() {
@@ -377,4 +571,56 @@ mod tests {
                 .is_responsible_for_uri(&Uri::from_str("file:///path/to/other/file.js").unwrap())
         );
     }
+
+    #[test]
+    fn test_single_file_nearest_config() {
+        let uri = get_file_uri("fixtures/linter/deny_no_console/hello_world.js");
+        let worker = WorkspaceWorker::new_single_file(&uri, "javascript").unwrap();
+
+        assert!(worker.is_single_file());
+        assert_eq!(worker.get_root_uri(), &get_file_uri("fixtures/linter/deny_no_console"));
+        assert!(worker.is_responsible_for_uri(&uri));
+        assert!(!worker.is_responsible_for_uri(&get_file_uri(
+            "fixtures/linter/deny_no_console/other_file.js"
+        )));
+    }
+
+    #[test]
+    fn test_single_file_untitled() {
+        let uri = Uri::from_str("untitled:Untitled-1").unwrap();
+        let worker = WorkspaceWorker::new_single_file(&uri, "typescript").unwrap();
+
+        assert!(worker.is_responsible_for_uri(&uri));
+        assert!(worker.lint_path(&uri).unwrap().ends_with("untitled.ts"));
+
+        assert!(WorkspaceWorker::new_single_file(&uri, "plaintext").is_none());
+    }
+
+    #[test]
+    fn test_single_file_lint_untitled() {
+        let uri = Uri::from_str("untitled:Untitled-1").unwrap();
+        let reports = tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let worker = WorkspaceWorker::new_single_file(&uri, "javascript").unwrap();
+            worker.init_linter(&Options::default()).await;
+            worker.lint_file(&uri, Some("debugger;".to_string())).await
+        });
+
+        let reports = reports.unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].rule_name.as_deref(), Some("no-debugger"));
+    }
+
+    #[test]
+    fn test_document_highlights_use_open_document() {
+        let uri = Uri::from_str("untitled:Untitled-1").unwrap();
+        let worker = WorkspaceWorker::new_single_file(&uri, "javascript").unwrap();
+        assert!(worker.get_document_highlights(&uri, Position::new(0, 4)).is_none());
+
+        worker.update_document(&uri, "let a = 1;\na;".to_string());
+        let highlights = worker.get_document_highlights(&uri, Position::new(0, 4)).unwrap();
+        assert_eq!(highlights.len(), 2);
+
+        worker.remove_document(&uri);
+        assert!(worker.get_document_highlights(&uri, Position::new(0, 4)).is_none());
+    }
 }
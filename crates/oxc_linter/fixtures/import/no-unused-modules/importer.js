@@ -0,0 +1,3 @@
+import def, { used } from './index.js';
+
+export { def, used };
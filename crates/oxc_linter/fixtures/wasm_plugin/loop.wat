@@ -0,0 +1,20 @@
+;; A plugin whose rule never finishes, to test the limits of WASM plugins.
+;; Compiled to `loop.wasm` with `wat2wasm loop.wat`.
+(module
+  (memory (export "memory") 1)
+
+  (data (i32.const 0) "{\"name\":\"loop\",\"rules\":[\"loop\"]}")
+
+  (func (export "oxlint_abi_version") (result i32)
+    (i32.const 1))
+
+  (func (export "oxlint_plugin") (result i64)
+    (i64.const 32))
+
+  (func (export "oxlint_alloc") (param $len i32) (result i32)
+    (i32.const 1024))
+
+  (func (export "oxlint_lint") (param $ptr i32) (param $len i32) (result i64)
+    (loop $forever
+      (br $forever))
+    (unreachable)))
@@ -893,3 +893,47 @@ pub fn get_function_name_with_kind<'a>(
 
     Cow::Owned(tokens.join(" "))
 }
+
+const ARRAY_METHODS_RETURNS_ARRAY: [&str; 15] = [
+    "concat",
+    "copyWithin",
+    "fill",
+    "filter",
+    "flat",
+    "flatMap",
+    "map",
+    "reverse",
+    "slice",
+    "sort",
+    "splice",
+    "toReversed",
+    "toSorted",
+    "toSpliced",
+    "with",
+];
+
+/// Whether `expr` creates an array, e.g. `[]`, `new Array(n)`, `Array.from(x)` or `x.map(f)`.
+pub fn is_kind_of_array_expr(expr: &Expression) -> bool {
+    match expr {
+        Expression::NewExpression(new_expr) => {
+            new_expr.callee.get_identifier_reference().is_some_and(|ident| ident.name == "Array")
+        }
+        Expression::CallExpression(call_expr) => {
+            let Some(callee) = call_expr.callee.get_member_expr() else {
+                return call_expr.callee_name().is_some_and(|name| name == "Array");
+            };
+
+            if callee.is_computed() || callee.optional() {
+                return false;
+            }
+
+            let Some(name) = callee.static_property_name() else { return false };
+
+            callee.is_specific_member_access("Array", "of")
+                || callee.is_specific_member_access("Array", "from")
+                || ARRAY_METHODS_RETURNS_ARRAY.contains(&name)
+        }
+        Expression::ArrayExpression(_) => true,
+        _ => false,
+    }
+}
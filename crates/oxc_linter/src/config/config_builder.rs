@@ -1,23 +1,28 @@
 use std::{
     fmt::{self, Debug, Display},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use itertools::Itertools;
-use oxc_resolver::Resolver;
+use oxc_resolver::{ResolveError, ResolveOptions, Resolver, ResolverGeneric};
 use rustc_hash::FxHashMap;
 
 use oxc_span::{CompactStr, format_compact_str};
 
 use crate::{
-    AllowWarnDeny, ExternalPluginStore, LintConfig, LintFilter, LintFilterKind, Oxlintrc,
-    RuleCategory, RuleEnum,
+    AllowWarnDeny, ExternalPluginStore, FileSystem, LintConfig, LintFilter, LintFilterKind,
+    OsFileSystem, Oxlintrc, PluginManifestError, RuleCategory, RuleEnum,
     config::{
-        ESLintRule, LintPlugins, OxlintOverrides, OxlintRules, overrides::OxlintOverride,
+        ESLintRule, LintPlugins, OxlintOverrides, OxlintRules,
+        coverage::{CoveragePolicy, CoverageReport, normalize},
+        overrides::OxlintOverride,
+        oxlintrc::is_url,
         plugins::BuiltinLintPlugins,
     },
     external_linter::ExternalLinter,
     external_plugin_store::{ExternalRuleId, ExternalRuleLookupError},
+    file_system::BorrowedResolverFileSystem,
     rules::RULES,
 };
 
@@ -30,6 +35,12 @@ pub struct ConfigStoreBuilder {
     config: LintConfig,
     categories: OxlintCategories,
     overrides: OxlintOverrides,
+    /// Options of the configured rules, for printing the configuration of a file.
+    rule_options: FxHashMap<(CompactStr, CompactStr), serde_json::Value>,
+    /// Severities of the configured rules, for explaining the configuration of a file.
+    rule_severities: FxHashMap<(CompactStr, CompactStr), AllowWarnDeny>,
+    /// Severities of rules depending on the test coverage of a file.
+    coverage: Option<Arc<CoveragePolicy>>,
 
     // Collect all `extends` file paths for the language server.
     // The server will tell the clients to watch for the extends files.
@@ -55,7 +66,17 @@ impl ConfigStoreBuilder {
         let overrides = OxlintOverrides::default();
         let extended_paths = Vec::new();
 
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        Self {
+            rules,
+            external_rules,
+            config,
+            categories,
+            overrides,
+            rule_options: FxHashMap::default(),
+            rule_severities: FxHashMap::default(),
+            coverage: None,
+            extended_paths,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -70,7 +91,17 @@ impl ConfigStoreBuilder {
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let external_rules = FxHashMap::default();
         let extended_paths = Vec::new();
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        Self {
+            rules,
+            external_rules,
+            config,
+            categories,
+            overrides,
+            rule_options: FxHashMap::default(),
+            rule_severities: FxHashMap::default(),
+            coverage: None,
+            extended_paths,
+        }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -91,15 +122,42 @@ impl ConfigStoreBuilder {
     /// # Errors
     ///
     /// Returns [`ConfigBuilderError::InvalidConfigFile`] if a referenced config file is not valid.
+    /// Returns [`ConfigBuilderError::InvalidRuleOptions`] if the options of a rule are not valid.
     pub fn from_oxlintrc(
         start_empty: bool,
         oxlintrc: Oxlintrc,
         external_linter: Option<&ExternalLinter>,
         external_plugin_store: &mut ExternalPluginStore,
+    ) -> Result<Self, ConfigBuilderError> {
+        Self::from_oxlintrc_with_file_system(
+            start_empty,
+            oxlintrc,
+            &OsFileSystem,
+            external_linter,
+            external_plugin_store,
+        )
+    }
+
+    /// Same as [`ConfigStoreBuilder::from_oxlintrc`], but reads the files in `extends` from
+    /// `file_system`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigBuilderError::InvalidConfigFile`] if a referenced config file is not valid.
+    /// Returns [`ConfigBuilderError::InvalidRuleOptions`] if the options of a rule are not valid.
+    pub fn from_oxlintrc_with_file_system(
+        start_empty: bool,
+        oxlintrc: Oxlintrc,
+        file_system: &dyn FileSystem,
+        external_linter: Option<&ExternalLinter>,
+        external_plugin_store: &mut ExternalPluginStore,
     ) -> Result<Self, ConfigBuilderError> {
         // TODO: this can be cached to avoid re-computing the same oxlintrc
         fn resolve_oxlintrc_config(
             config: Oxlintrc,
+            file_system: &dyn FileSystem,
+            // Normalized paths of the configs which extend `config`, to detect cycles
+            ancestors: &mut Vec<PathBuf>,
         ) -> Result<(Oxlintrc, Vec<PathBuf>), ConfigBuilderError> {
             let path = config.path.clone();
             let root_path = path.parent();
@@ -108,33 +166,26 @@ impl ConfigStoreBuilder {
 
             let mut oxlintrc = config;
 
-            for path in extends.iter().rev() {
-                if path.starts_with("eslint:") || path.starts_with("plugin:") {
+            for specifier in extends.iter().rev() {
+                if specifier.starts_with("eslint:") || specifier.starts_with("plugin:") {
                     // `eslint:` and `plugin:` named configs are not supported
                     continue;
                 }
-                // if path does not include a ".", then we will heuristically skip it since it
-                // kind of looks like it might be a named config
-                if !path.to_string_lossy().contains('.') {
-                    continue;
-                }
 
-                let path = match root_path {
-                    Some(p) => &p.join(path),
-                    None => path,
-                };
+                let extended =
+                    ConfigStoreBuilder::resolve_extends(specifier, root_path, file_system)
+                        .and_then(|extends_path| {
+                            extends_path
+                                .map(|extends_path| {
+                                    load_extended_config(extends_path, file_system, ancestors)
+                                })
+                                .transpose()
+                        })
+                        .map_err(|err| err.extended_by(&path))?;
 
-                let extends_oxlintrc = Oxlintrc::from_file(path).map_err(|e| {
-                    ConfigBuilderError::InvalidConfigFile {
-                        file: path.display().to_string(),
-                        reason: e.to_string(),
-                    }
-                })?;
-
-                extended_paths.push(path.clone());
-
-                let (extends, extends_paths) = resolve_oxlintrc_config(extends_oxlintrc)?;
+                let Some((extends_path, (extends, extends_paths))) = extended else { continue };
 
+                extended_paths.push(extends_path);
                 oxlintrc = oxlintrc.merge(extends);
                 extended_paths.extend(extends_paths);
             }
@@ -142,7 +193,51 @@ impl ConfigStoreBuilder {
             Ok((oxlintrc, extended_paths))
         }
 
-        let (oxlintrc, extended_paths) = resolve_oxlintrc_config(oxlintrc)?;
+        fn load_extended_config(
+            extends_path: PathBuf,
+            file_system: &dyn FileSystem,
+            ancestors: &mut Vec<PathBuf>,
+        ) -> Result<(PathBuf, (Oxlintrc, Vec<PathBuf>)), ConfigBuilderError> {
+            // URLs are normalized by `join_url`
+            let normalized = if extends_path.to_str().is_some_and(is_url) {
+                extends_path.clone()
+            } else {
+                normalize(&extends_path)
+            };
+            if let Some(start) = ancestors.iter().position(|p| *p == normalized) {
+                let cycle = ancestors[start..]
+                    .iter()
+                    .chain(std::iter::once(&normalized))
+                    .map(|p| p.display().to_string())
+                    .join(" -> ");
+                return Err(ConfigBuilderError::InvalidConfigFile {
+                    file: extends_path.display().to_string(),
+                    reason: format!("circular `extends`: {cycle}"),
+                });
+            }
+
+            let extends_oxlintrc = Oxlintrc::from_file_with_file_system(&extends_path, file_system)
+                .map_err(|e| ConfigBuilderError::InvalidConfigFile {
+                    file: extends_path.display().to_string(),
+                    reason: e.to_string(),
+                })?;
+
+            ancestors.push(normalized);
+            let resolved = resolve_oxlintrc_config(extends_oxlintrc, file_system, ancestors);
+            ancestors.pop();
+            Ok((extends_path, resolved?))
+        }
+
+        let mut ancestors = vec![normalize(&oxlintrc.path)];
+        let (oxlintrc, extended_paths) =
+            resolve_oxlintrc_config(oxlintrc, file_system, &mut ancestors)?;
+
+        let invalid_options =
+            |(rule, reason)| ConfigBuilderError::InvalidRuleOptions { rule, reason };
+        oxlintrc.rules.validate_options().map_err(invalid_options)?;
+        for r#override in &oxlintrc.overrides {
+            r#override.rules.validate_options().map_err(invalid_options)?;
+        }
 
         if let Some(base_plugins) = oxlintrc.plugins.as_ref() {
             let mut external_plugins = base_plugins.external.clone();
@@ -153,25 +248,38 @@ impl ConfigStoreBuilder {
             }
 
             if !external_plugins.is_empty() {
-                let external_linter =
-                    external_linter.ok_or(ConfigBuilderError::NoExternalLinterConfigured)?;
-
-                let resolver = Resolver::default();
-
                 #[expect(
                     clippy::missing_panics_doc,
                     reason = "oxlintrc.path is always a file path"
                 )]
                 let oxlintrc_dir = oxlintrc.path.parent().unwrap();
 
-                for plugin_specifier in &external_plugins {
-                    Self::load_external_plugin(
-                        oxlintrc_dir,
-                        plugin_specifier,
-                        external_linter,
-                        &resolver,
-                        external_plugin_store,
-                    )?;
+                // Plugins compiled to WASM are run by oxlint itself, so don't need an external linter
+                let (wasm_plugins, external_plugins): (Vec<_>, Vec<_>) =
+                    external_plugins.iter().partition(|specifier| {
+                        Path::new(specifier)
+                            .extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("wasm"))
+                    });
+                for plugin_specifier in wasm_plugins {
+                    Self::load_wasm_plugin(oxlintrc_dir, plugin_specifier, external_plugin_store)?;
+                }
+
+                if !external_plugins.is_empty() {
+                    let external_linter =
+                        external_linter.ok_or(ConfigBuilderError::NoExternalLinterConfigured)?;
+
+                    let resolver = Resolver::default();
+
+                    for plugin_specifier in external_plugins {
+                        Self::load_external_plugin(
+                            oxlintrc_dir,
+                            plugin_specifier,
+                            external_linter,
+                            &resolver,
+                            external_plugin_store,
+                        )?;
+                    }
                 }
             }
         }
@@ -189,6 +297,20 @@ impl ConfigStoreBuilder {
             categories.insert(RuleCategory::Correctness, AllowWarnDeny::Warn);
         }
 
+        let coverage = match &oxlintrc.coverage {
+            Some(coverage) => {
+                let config_dir = oxlintrc.path.parent().unwrap_or(Path::new(""));
+                let report_path = config_dir.join(&coverage.report);
+                let report = CoverageReport::from_file(&report_path, config_dir, file_system)
+                    .map_err(|reason| ConfigBuilderError::InvalidCoverageReport {
+                        file: report_path.display().to_string(),
+                        reason,
+                    })?;
+                Some(Arc::new(CoveragePolicy::new(coverage, report)))
+            }
+            None => None,
+        };
+
         let config = LintConfig {
             plugins,
             settings: oxlintrc.settings,
@@ -197,12 +319,19 @@ impl ConfigStoreBuilder {
             path: Some(oxlintrc.path),
         };
 
+        let rule_options =
+            oxlintrc.rules.options().map(|(name, options)| (name, options.clone())).collect();
+        let rule_severities = oxlintrc.rules.severities().collect();
+
         let mut builder = Self {
             rules,
             external_rules: FxHashMap::default(),
             config,
             categories,
             overrides: oxlintrc.overrides,
+            rule_options,
+            rule_severities,
+            coverage,
             extended_paths,
         };
 
@@ -381,6 +510,9 @@ impl ConfigStoreBuilder {
         external_rules.sort_unstable_by_key(|(r, _)| *r);
 
         Config::new(rules, external_rules, self.categories, self.config, self.overrides)
+            .with_rule_options(self.rule_options)
+            .with_rule_severities(self.rule_severities)
+            .with_coverage(self.coverage)
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -427,6 +559,108 @@ impl ConfigStoreBuilder {
         serde_json::to_string_pretty(&oxlintrc).unwrap()
     }
 
+    /// Path of the config file which `specifier` in `extends` refers to, relative to the
+    /// directory of the config file which extends it, `root_path`.
+    ///
+    /// Specifiers which are not paths are resolved as shareable configs in packages, e.g.
+    /// `@company/oxlint-config` or `@company/oxlint-config/strict.json`. Returns `None` for ESLint
+    /// named configs, such as `next/core-web-vitals` or a package with a JavaScript config.
+    ///
+    /// URLs are returned as they are, and are fetched with [`FileSystem::fetch`].
+    fn resolve_extends(
+        specifier: &Path,
+        root_path: Option<&Path>,
+        file_system: &dyn FileSystem,
+    ) -> Result<Option<PathBuf>, ConfigBuilderError> {
+        let path = match root_path {
+            Some(root_path) => root_path.join(specifier),
+            None => specifier.to_path_buf(),
+        };
+        let Some(specifier) = specifier.to_str() else { return Ok(Some(path)) };
+
+        if is_url(specifier) {
+            return Ok(Some(PathBuf::from(specifier)));
+        }
+        // Packages and paths on disk can't be resolved from a config extended from a URL
+        if let Some(base) = root_path.and_then(Path::to_str).filter(|path| is_url(path)) {
+            if !specifier.starts_with('.') {
+                return Err(ConfigBuilderError::InvalidConfigFile {
+                    file: specifier.to_string(),
+                    reason: "a config extended from a URL can only extend URLs and relative paths"
+                        .to_string(),
+                });
+            }
+            return Ok(Some(PathBuf::from(join_url(base, specifier))));
+        }
+        if specifier.starts_with('.')
+            || Path::new(specifier).is_absolute()
+            || file_system.is_file(&path)
+        {
+            return Ok(Some(path));
+        }
+
+        let resolver = ResolverGeneric::new_with_file_system(
+            BorrowedResolverFileSystem(file_system),
+            ResolveOptions {
+                condition_names: vec!["oxlint".into(), "require".into(), "default".into()],
+                extensions: vec![".json".into(), ".jsonc".into()],
+                ..ResolveOptions::default()
+            },
+        );
+        let directory = root_path.filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        match resolver.resolve(directory, specifier) {
+            Ok(resolution) => {
+                let resolved = resolution.into_path_buf();
+                let is_json = resolved.extension().is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("json") || ext.eq_ignore_ascii_case("jsonc")
+                });
+                Ok(is_json.then_some(resolved))
+            }
+            // If the specifier does not include a ".", and is not an installed package, then we
+            // will heuristically skip it since it kind of looks like it might be a named config
+            Err(ResolveError::NotFound(_)) if !specifier.contains('.') => Ok(None),
+            Err(err) => Err(ConfigBuilderError::InvalidConfigFile {
+                file: specifier.to_string(),
+                reason: format!("failed to resolve the config: {err}"),
+            }),
+        }
+    }
+
+    #[cfg(feature = "wasm_plugins")]
+    fn load_wasm_plugin(
+        oxlintrc_dir_path: &Path,
+        plugin_specifier: &str,
+        external_plugin_store: &mut ExternalPluginStore,
+    ) -> Result<(), ConfigBuilderError> {
+        use crate::WasmPlugin;
+
+        let path = oxlintrc_dir_path.join(plugin_specifier);
+        let plugin_path = path.to_string_lossy().into_owned();
+        if external_plugin_store.is_plugin_registered(&plugin_path) {
+            return Ok(());
+        }
+
+        let plugin =
+            WasmPlugin::load(&path).map_err(|error| ConfigBuilderError::PluginLoadFailed {
+                plugin_specifier: plugin_specifier.to_string(),
+                error,
+            })?;
+        external_plugin_store.register_wasm_plugin(plugin_path, plugin);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "wasm_plugins"))]
+    fn load_wasm_plugin(
+        _oxlintrc_dir_path: &Path,
+        plugin_specifier: &str,
+        _external_plugin_store: &mut ExternalPluginStore,
+    ) -> Result<(), ConfigBuilderError> {
+        Err(ConfigBuilderError::PluginLoadFailed {
+            plugin_specifier: plugin_specifier.to_string(),
+            error: "WASM plugins are not supported in this build of oxlint".to_string(),
+        })
+    }
+
     #[cfg(not(all(feature = "oxlint2", not(feature = "disable_oxlint2"))))]
     #[expect(unused_variables, clippy::needless_pass_by_ref_mut)]
     fn load_external_plugin(
@@ -447,7 +681,7 @@ impl ConfigStoreBuilder {
         resolver: &Resolver,
         external_plugin_store: &mut ExternalPluginStore,
     ) -> Result<(), ConfigBuilderError> {
-        use crate::PluginLoadResult;
+        use crate::{OXLINT_VERSION, PluginLoadResult, PluginManifest, read_to_string};
 
         let resolved = resolver.resolve(oxlintrc_dir_path, plugin_specifier).map_err(|e| {
             ConfigBuilderError::PluginLoadFailed {
@@ -462,6 +696,25 @@ impl ConfigStoreBuilder {
             return Ok(());
         }
 
+        // Check the manifest before the plugin is loaded, so incompatible plugins don't fail in
+        // unexpected ways while they are loaded or run.
+        let incompatible = |error| ConfigBuilderError::IncompatiblePlugin {
+            plugin_specifier: plugin_specifier.to_string(),
+            error,
+        };
+        let manifest = match resolved.package_json() {
+            Some(package_json) => read_to_string(package_json.path())
+                .ok()
+                .map(|content| PluginManifest::from_package_json(&content))
+                .transpose()
+                .map_err(incompatible)?
+                .flatten(),
+            None => None,
+        };
+        if let Some(manifest) = &manifest {
+            manifest.check_oxlint_version(OXLINT_VERSION).map_err(incompatible)?;
+        }
+
         let result = {
             let plugin_path = plugin_path.clone();
             tokio::task::block_in_place(move || {
@@ -476,6 +729,9 @@ impl ConfigStoreBuilder {
 
         match result {
             PluginLoadResult::Success { name, offset, rule_names } => {
+                if let Some(manifest) = &manifest {
+                    manifest.check_loaded(&name, &rule_names).map_err(incompatible)?;
+                }
                 external_plugin_store.register_plugin(plugin_path, name, offset, rule_names);
                 Ok(())
             }
@@ -495,6 +751,25 @@ fn get_name(plugin_name: &str, rule_name: &str) -> CompactStr {
     }
 }
 
+/// Resolve the relative path `specifier` against `base`, the URL of a directory, e.g.
+/// `../shared.json` against `https://example.com/configs` is `https://example.com/shared.json`.
+fn join_url(base: &str, specifier: &str) -> String {
+    let path_start = base.find("://").map_or(0, |i| i + 3);
+    let path_start = base[path_start..].find('/').map_or(base.len(), |i| path_start + i);
+    let (origin, path) = base.split_at(path_start);
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+    for segment in specifier.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("{origin}/{}", segments.join("/"))
+}
+
 impl Debug for ConfigStoreBuilder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ConfigStoreBuilder")
@@ -520,6 +795,21 @@ pub enum ConfigBuilderError {
         plugin_specifier: String,
         error: String,
     },
+    /// A plugin does not support this version of oxlint, or does not match its manifest.
+    IncompatiblePlugin {
+        plugin_specifier: String,
+        error: PluginManifestError,
+    },
+    /// The options of a rule are invalid.
+    InvalidRuleOptions {
+        rule: String,
+        reason: String,
+    },
+    /// The coverage report of the `coverage` configuration could not be read.
+    InvalidCoverageReport {
+        file: String,
+        reason: String,
+    },
     ExternalRuleLookupError(ExternalRuleLookupError),
     NoExternalLinterConfigured,
 }
@@ -541,10 +831,19 @@ impl Display for ConfigBuilderError {
             ConfigBuilderError::InvalidConfigFile { file, reason } => {
                 write!(f, "invalid config file {file}: {reason}")
             }
+            ConfigBuilderError::InvalidRuleOptions { rule, reason } => {
+                write!(f, "invalid options of rule {rule}: {reason}")
+            }
+            ConfigBuilderError::InvalidCoverageReport { file, reason } => {
+                write!(f, "invalid coverage report {file}: {reason}")
+            }
             ConfigBuilderError::PluginLoadFailed { plugin_specifier, error } => {
                 write!(f, "Failed to load external plugin: {plugin_specifier}\n  {error}")?;
                 Ok(())
             }
+            ConfigBuilderError::IncompatiblePlugin { plugin_specifier, error } => {
+                write!(f, "Incompatible external plugin: {plugin_specifier}\n  {error}")
+            }
             ConfigBuilderError::NoExternalLinterConfigured => {
                 f.write_str("Failed to load external plugin because no external linter was configured. This means the Oxlint binary was executed directly rather than via napi bindings.")?;
                 Ok(())
@@ -554,6 +853,24 @@ impl Display for ConfigBuilderError {
     }
 }
 
+impl ConfigBuilderError {
+    /// Add the config file which extends the invalid config file to the error, so the error
+    /// shows the chain of `extends` which led to it.
+    fn extended_by(self, path: &Path) -> Self {
+        match self {
+            ConfigBuilderError::InvalidConfigFile { file, reason }
+                if !path.as_os_str().is_empty() =>
+            {
+                ConfigBuilderError::InvalidConfigFile {
+                    file,
+                    reason: format!("{reason}\n  extended by {}", path.display()),
+                }
+            }
+            err => err,
+        }
+    }
+}
+
 impl std::error::Error for ConfigBuilderError {}
 
 #[cfg(test)]
@@ -977,6 +1294,222 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_invalid_rule_options() {
+        let oxlintrc = serde_json::from_value(serde_json::json!({
+            "overrides": [{
+                "files": ["*.js"],
+                "rules": { "no-restricted-syntax": ["error", "WithStatement ~ EmptyStatement"] }
+            }]
+        }))
+        .unwrap();
+        let err = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut ExternalPluginStore::default(),
+        )
+        .unwrap_err();
+        let ConfigBuilderError::InvalidRuleOptions { rule, reason } = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(rule, "no-restricted-syntax");
+        assert!(reason.starts_with("invalid selector `WithStatement ~ EmptyStatement`"));
+    }
+
+    #[test]
+    fn test_extends_with_file_system() {
+        let file_system = crate::MemoryFileSystem::default()
+            .with_file(
+                "/project/.oxlintrc.json",
+                r#"{ "extends": ["./configs/base.json"], "rules": { "no-console": "warn" } }"#,
+            )
+            .with_file("/project/configs/base.json", r#"{ "rules": { "no-debugger": "error" } }"#);
+        let oxlintrc = Oxlintrc::from_file_with_file_system(
+            Path::new("/project/.oxlintrc.json"),
+            &file_system,
+        )
+        .unwrap();
+        let builder = ConfigStoreBuilder::from_oxlintrc_with_file_system(
+            true,
+            oxlintrc,
+            &file_system,
+            None,
+            &mut ExternalPluginStore::default(),
+        )
+        .unwrap();
+
+        assert_eq!(builder.extended_paths, [PathBuf::from("/project/./configs/base.json")]);
+        let config = builder.build();
+        assert!(
+            config
+                .rules()
+                .iter()
+                .any(|(r, severity)| r.name() == "no-debugger" && *severity == AllowWarnDeny::Deny)
+        );
+        assert!(
+            config
+                .rules()
+                .iter()
+                .any(|(r, severity)| r.name() == "no-console" && *severity == AllowWarnDeny::Warn)
+        );
+    }
+
+    fn builder_from_file_system(
+        file_system: &crate::MemoryFileSystem,
+    ) -> Result<ConfigStoreBuilder, ConfigBuilderError> {
+        let oxlintrc =
+            Oxlintrc::from_file_with_file_system(Path::new("/project/.oxlintrc.json"), file_system)
+                .unwrap();
+        ConfigStoreBuilder::from_oxlintrc_with_file_system(
+            true,
+            oxlintrc,
+            file_system,
+            None,
+            &mut ExternalPluginStore::default(),
+        )
+    }
+
+    #[test]
+    fn test_extends_packages() {
+        let file_system = crate::MemoryFileSystem::default()
+            .with_file(
+                "/project/.oxlintrc.json",
+                r#"{ "extends": ["@company/oxlint-config", "shared/strict.json", "prettier"] }"#,
+            )
+            .with_file(
+                "/project/node_modules/@company/oxlint-config/package.json",
+                r#"{ "name": "@company/oxlint-config", "main": "oxlintrc.json" }"#,
+            )
+            .with_file(
+                "/project/node_modules/@company/oxlint-config/oxlintrc.json",
+                r#"{ "rules": { "no-debugger": "error" } }"#,
+            )
+            .with_file("/project/node_modules/shared/package.json", r#"{ "name": "shared" }"#)
+            .with_file(
+                "/project/node_modules/shared/strict.json",
+                r#"{ "rules": { "no-console": "warn" } }"#,
+            );
+        let builder = builder_from_file_system(&file_system).unwrap();
+
+        assert_eq!(
+            builder.extended_paths,
+            [
+                PathBuf::from("/project/node_modules/shared/strict.json"),
+                PathBuf::from("/project/node_modules/@company/oxlint-config/oxlintrc.json")
+            ]
+        );
+        let config = builder.build();
+        assert!(config.rules().iter().any(|(r, _)| r.name() == "no-debugger"));
+        assert!(config.rules().iter().any(|(r, _)| r.name() == "no-console"));
+    }
+
+    #[test]
+    fn test_extends_errors() {
+        let invalid_config_file = |file_system| match builder_from_file_system(&file_system) {
+            Err(ConfigBuilderError::InvalidConfigFile { file, reason }) => (file, reason),
+            Err(err) => panic!("unexpected error: {err}"),
+            Ok(_) => panic!("expected an error"),
+        };
+
+        // Cycle
+        let (file, reason) = invalid_config_file(
+            crate::MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "extends": ["./a.json"] }"#)
+                .with_file("/project/a.json", r#"{ "extends": ["./b.json"] }"#)
+                .with_file("/project/b.json", r#"{ "extends": ["./a.json"] }"#),
+        );
+        assert_eq!(file, "/project/./a.json");
+        assert_eq!(
+            reason,
+            "circular `extends`: /project/a.json -> /project/b.json -> /project/a.json\n  extended by /project/./b.json\n  extended by /project/./a.json\n  extended by /project/.oxlintrc.json"
+        );
+
+        // Missing package in an extended config
+        let (file, reason) = invalid_config_file(
+            crate::MemoryFileSystem::default()
+                .with_file("/project/.oxlintrc.json", r#"{ "extends": ["./a.json"] }"#)
+                .with_file("/project/a.json", r#"{ "extends": ["@company/missing/config.json"] }"#),
+        );
+        assert_eq!(file, "@company/missing/config.json");
+        assert!(reason.starts_with("failed to resolve the config: "));
+        assert!(
+            reason.ends_with(
+                "\n  extended by /project/./a.json\n  extended by /project/.oxlintrc.json"
+            )
+        );
+
+        // Package in a config extended from a URL
+        let (file, reason) = invalid_config_file(
+            crate::MemoryFileSystem::default()
+                .with_file(
+                    "/project/.oxlintrc.json",
+                    r#"{ "extends": ["https://example.com/oxlintrc.json"] }"#,
+                )
+                .with_file(
+                    "https://example.com/oxlintrc.json",
+                    r#"{ "extends": ["@company/oxlint-config"] }"#,
+                ),
+        );
+        assert_eq!(file, "@company/oxlint-config");
+        assert_eq!(
+            reason,
+            "a config extended from a URL can only extend URLs and relative paths\n  extended by https://example.com/oxlintrc.json\n  extended by /project/.oxlintrc.json"
+        );
+
+        // URLs are only fetched from the operating system with the `remote_config` feature
+        #[cfg(not(feature = "remote_config"))]
+        assert_eq!(
+            crate::FileSystem::fetch(&OsFileSystem, "https://example.com/oxlintrc.json")
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_extends_urls() {
+        let file_system = crate::MemoryFileSystem::default()
+            .with_file(
+                "/project/.oxlintrc.json",
+                r#"{ "extends": ["https://example.com/configs/base.json"] }"#,
+            )
+            .with_file(
+                "https://example.com/configs/base.json",
+                r#"{ "extends": ["./strict.json", "../shared.json"] }"#,
+            )
+            .with_file(
+                "https://example.com/configs/strict.json",
+                r#"{ "rules": { "no-debugger": "error" } }"#,
+            )
+            .with_file(
+                "https://example.com/shared.json",
+                r#"{ "rules": { "no-console": "warn" } }"#,
+            );
+        let builder = builder_from_file_system(&file_system).unwrap();
+
+        assert_eq!(
+            builder.extended_paths,
+            [
+                PathBuf::from("https://example.com/configs/base.json"),
+                PathBuf::from("https://example.com/shared.json"),
+                PathBuf::from("https://example.com/configs/strict.json"),
+            ]
+        );
+        let config = builder.build();
+        assert!(config.rules().iter().any(|(r, _)| r.name() == "no-debugger"));
+        assert!(config.rules().iter().any(|(r, _)| r.name() == "no-console"));
+
+        assert_eq!(
+            super::join_url("https://example.com/a/b", "../../../c/./d.json"),
+            "https://example.com/c/d.json"
+        );
+        assert_eq!(
+            super::join_url("http://localhost:8080", "./a.json"),
+            "http://localhost:8080/a.json"
+        );
+    }
+
     #[test]
     fn test_extends_plugins() {
         // Test 1: Default plugins when none are specified
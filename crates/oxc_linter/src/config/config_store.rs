@@ -3,18 +3,34 @@ use std::{
     sync::Arc,
 };
 
+use itertools::Itertools;
 use rustc_hash::FxHashMap;
 
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{CompactStr, Span};
+
 use crate::{
-    AllowWarnDeny, LintPlugins,
+    AllowWarnDeny, LintPlugins, Oxlintrc,
     external_plugin_store::{ExternalPluginStore, ExternalRuleId},
+    rule_directives::{RuleDirective, SeverityRanges},
     rules::{RULES, RuleEnum},
 };
 
 use super::{
-    BuiltinLintPlugins, LintConfig, categories::OxlintCategories, overrides::OxlintOverrides,
+    BuiltinLintPlugins, ESLintRule, LintConfig,
+    categories::OxlintCategories,
+    coverage::CoveragePolicy,
+    explain::ConfigResolution,
+    overrides::{OxlintOverride, OxlintOverrides},
+    rules::{OxlintRules, transform_rule_and_plugin_name},
 };
 
+fn rule_not_found_diagnostic(rule_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Definition for rule '{rule_name}' was not found."))
+        .with_help("Check the name of the rule, and that its plugin is enabled.")
+        .with_label(span)
+}
+
 // TODO: support `categories` et. al. in overrides.
 #[derive(Debug)]
 pub struct ResolvedLinterState {
@@ -35,6 +51,16 @@ impl Clone for ResolvedLinterState {
     }
 }
 
+/// Severity of the rules of a [`ResolvedLinterState`] in each part of a file, as configured by
+/// the inline configuration comments of the file.
+#[derive(Debug, Default)]
+pub(crate) struct DirectiveSeverities {
+    /// Severities of each rule of the state, by index.
+    pub rules: Vec<SeverityRanges>,
+    /// Severities of the external rules configured by the comments.
+    pub external_rules: FxHashMap<ExternalRuleId, SeverityRanges>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// The basic linter state for this configuration.
@@ -52,6 +78,16 @@ pub struct Config {
 
     /// An optional set of overrides to apply to the base state depending on the file being linted.
     pub(crate) overrides: OxlintOverrides,
+
+    /// Options of the configured rules by plugin and rule name, only used to print the configuration.
+    pub(crate) rule_options: FxHashMap<(CompactStr, CompactStr), serde_json::Value>,
+
+    /// Severities of the configured rules by plugin and rule name, only used to explain the
+    /// configuration of a file.
+    pub(crate) rule_severities: FxHashMap<(CompactStr, CompactStr), AllowWarnDeny>,
+
+    /// Changes the severities of the rules of a file depending on its test coverage.
+    pub(crate) coverage: Option<Arc<CoveragePolicy>>,
 }
 
 impl Config {
@@ -81,9 +117,36 @@ impl Config {
             base_rules: rules,
             categories,
             overrides,
+            rule_options: FxHashMap::default(),
+            rule_severities: FxHashMap::default(),
+            coverage: None,
         }
     }
 
+    #[must_use]
+    pub(crate) fn with_rule_options(
+        mut self,
+        rule_options: FxHashMap<(CompactStr, CompactStr), serde_json::Value>,
+    ) -> Self {
+        self.rule_options = rule_options;
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn with_rule_severities(
+        mut self,
+        rule_severities: FxHashMap<(CompactStr, CompactStr), AllowWarnDeny>,
+    ) -> Self {
+        self.rule_severities = rule_severities;
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn with_coverage(mut self, coverage: Option<Arc<CoveragePolicy>>) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
     pub fn plugins(&self) -> &LintPlugins {
         &self.base.config.plugins
     }
@@ -96,6 +159,22 @@ impl Config {
         self.base.rules.len()
     }
 
+    /// The linter state of the file at `path`, with the overrides which match it applied and the
+    /// severities of rules changed according to its test coverage.
+    pub(crate) fn resolve_for_path(
+        &self,
+        path: &Path,
+        external_plugin_store: &ExternalPluginStore,
+    ) -> ResolvedLinterState {
+        let mut state = self.apply_overrides(path, external_plugin_store);
+        if let Some(rules) =
+            self.coverage.as_ref().and_then(|coverage| coverage.apply(path, &state.rules))
+        {
+            state.rules = Arc::from(rules.into_boxed_slice());
+        }
+        state
+    }
+
     pub fn apply_overrides(
         &self,
         path: &Path,
@@ -105,20 +184,7 @@ impl Config {
             return self.base.clone();
         }
 
-        let relative_path = self
-            .base
-            .config
-            .path
-            .as_ref()
-            .and_then(|config_path| {
-                config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
-            })
-            .unwrap_or(path);
-
-        let overrides_to_apply =
-            self.overrides.iter().filter(|config| config.files.is_match(relative_path));
-
-        let mut overrides_to_apply = overrides_to_apply.peekable();
+        let mut overrides_to_apply = self.overrides_for_path(path).peekable();
 
         if overrides_to_apply.peek().is_none() {
             return self.base.clone();
@@ -221,6 +287,28 @@ impl Config {
             external_rules: Arc::from(external_rules.into_boxed_slice()),
         }
     }
+
+    /// Overrides whose `files` match `path`, in the order they are applied.
+    fn overrides_for_path<'c>(
+        &'c self,
+        path: &'c Path,
+    ) -> impl Iterator<Item = &'c OxlintOverride> + Clone {
+        let relative_path = self.override_path(path);
+        self.overrides.iter().filter(move |config| config.files.is_match(relative_path))
+    }
+
+    /// Path which the `files` of overrides are matched against: `path` relative to the directory
+    /// of the configuration file.
+    pub(super) fn override_path<'p>(&self, path: &'p Path) -> &'p Path {
+        self.base
+            .config
+            .path
+            .as_ref()
+            .and_then(|config_path| {
+                config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
+            })
+            .unwrap_or(path)
+    }
 }
 
 /// Stores the configuration state for the linter including:
@@ -260,6 +348,24 @@ impl ConfigStore {
         &self.base.base.config.plugins
     }
 
+    /// Whether a rule which needs the whole module graph, i.e. `import/no-unused-modules`, is
+    /// enabled by the base configuration, a nested configuration or one of their overrides.
+    pub(crate) fn needs_module_graph(&self) -> bool {
+        let is_enabled = |rule_name: &str, severity: AllowWarnDeny| {
+            rule_name == "no-unused-modules" && severity.is_warn_deny()
+        };
+        std::iter::once(&self.base).chain(self.nested_configs.values()).any(|config| {
+            config.base_rules.iter().any(|(rule, severity)| is_enabled(rule.name(), *severity))
+                || config.overrides.iter().any(|override_config| {
+                    override_config
+                        .rules
+                        .rules
+                        .iter()
+                        .any(|rule| is_enabled(&rule.rule_name, rule.severity))
+                })
+        })
+    }
+
     pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
         let resolved_config = if self.nested_configs.is_empty() {
             &self.base
@@ -269,7 +375,179 @@ impl ConfigStore {
             &self.base
         };
 
-        Config::apply_overrides(resolved_config, path, &self.external_plugin_store)
+        resolved_config.resolve_for_path(path, &self.external_plugin_store)
+    }
+
+    /// Explain how the configuration of the file at `path` is resolved: which configuration files
+    /// are found and which applies to it, which overrides match it, and what decided the severity
+    /// of each rule.
+    pub fn explain_resolution(&self, path: &Path) -> ConfigResolution {
+        let nested_config = self.get_nearest_config(path);
+        let config = nested_config.unwrap_or(&self.base);
+        let mut resolution = config.explain_resolution(path, &self.external_plugin_store);
+        resolution.root_config.clone_from(&self.base.base.config.path);
+        resolution.nested_configs = self
+            .nested_configs
+            .values()
+            .filter_map(|config| config.base.config.path.clone())
+            .sorted()
+            .collect();
+        resolution.is_nested = nested_config.is_some();
+        resolution
+    }
+
+    /// Configure the rules of a file with its inline configuration comments, on top of the linter
+    /// state resolved for it. See [`parse_rule_directives`](crate::rule_directives::parse_rule_directives).
+    ///
+    /// Each comment applies from its end to the end of the file, so the state contains every
+    /// rule which is on in any part of the file, with the severity it has in each part in the
+    /// returned [`DirectiveSeverities`]. A rule configured with options in the middle of a file
+    /// is in the state twice, with the configuration of each part.
+    ///
+    /// Unlike in overrides, a rule configured with only a severity keeps the options it is
+    /// configured with. Returns diagnostics for rules which are not found, or whose plugin is
+    /// not enabled.
+    pub(crate) fn apply_rule_directives(
+        &self,
+        state: &ResolvedLinterState,
+        directives: &[RuleDirective],
+    ) -> (ResolvedLinterState, DirectiveSeverities, Vec<OxcDiagnostic>) {
+        let mut rules = state.rules.to_vec();
+        let mut severities =
+            rules.iter().map(|(_, severity)| SeverityRanges::new(*severity)).collect::<Vec<_>>();
+        let mut external_rules = state.external_rules.to_vec();
+        let mut external_severities = FxHashMap::default();
+        let mut diagnostics = vec![];
+
+        for RuleDirective { rule, name_span, start } in directives {
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+            let severity = rule.severity;
+
+            if plugin_name == "eslint" || !BuiltinLintPlugins::from(plugin_name).is_empty() {
+                // The configuration of the rule in effect at the comment is the last one
+                let existing = rules.iter().rposition(|(existing, _)| {
+                    existing.name() == rule_name && existing.plugin_name() == plugin_name
+                });
+                if let (Some(index), None) = (existing, &rule.config) {
+                    severities[index].set(*start, severity);
+                    continue;
+                }
+                let Some(configured) = RULES
+                    .iter()
+                    .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                    .filter(|r| {
+                        state
+                            .config
+                            .plugins
+                            .builtin
+                            .contains(BuiltinLintPlugins::from(r.plugin_name()))
+                    })
+                    .map(|r| r.read_json(rule.config.clone().unwrap_or_default()))
+                else {
+                    diagnostics.push(rule_not_found_diagnostic(&rule.full_name(), *name_span));
+                    continue;
+                };
+                if let Some(index) = existing {
+                    severities[index].set(*start, AllowWarnDeny::Allow);
+                }
+                let mut configured_severities = SeverityRanges::new(AllowWarnDeny::Allow);
+                configured_severities.set(*start, severity);
+                rules.push((configured, severity));
+                severities.push(configured_severities);
+            } else {
+                let Ok(rule_id) = self.external_plugin_store.lookup_rule_id(plugin_name, rule_name)
+                else {
+                    diagnostics.push(rule_not_found_diagnostic(&rule.full_name(), *name_span));
+                    continue;
+                };
+                if !external_rules.iter().any(|(id, _)| *id == rule_id) {
+                    external_rules.push((rule_id, AllowWarnDeny::Allow));
+                }
+                external_severities
+                    .entry(rule_id)
+                    .or_insert_with(|| {
+                        let existing = state.external_rules.iter().find(|(id, _)| *id == rule_id);
+                        SeverityRanges::new(existing.map_or(AllowWarnDeny::Allow, |(_, s)| *s))
+                    })
+                    .set(*start, severity);
+            }
+        }
+
+        // The severity of each rule in the state is the most severe one in the file
+        let (rules, severities): (Vec<_>, Vec<_>) = rules
+            .into_iter()
+            .zip(severities)
+            .map(|((rule, _), severities)| ((rule, severities.max()), severities))
+            .filter(|((_, severity), _)| severity.is_warn_deny())
+            .unzip();
+        for (rule_id, severity) in &mut external_rules {
+            if let Some(severities) = external_severities.get(rule_id) {
+                *severity = severities.max();
+            }
+        }
+        external_rules.retain(|(_, severity)| severity.is_warn_deny());
+
+        let state = ResolvedLinterState {
+            rules: Arc::from(rules.into_boxed_slice()),
+            config: Arc::clone(&state.config),
+            external_rules: Arc::from(external_rules.into_boxed_slice()),
+        };
+        let severities =
+            DirectiveSeverities { rules: severities, external_rules: external_severities };
+        (state, severities, diagnostics)
+    }
+
+    /// The configuration which applies to `path`, with its nested configuration file, `extends`,
+    /// overrides and categories resolved, as a configuration file which only contains the plugins,
+    /// rules, settings, environments and globals.
+    ///
+    /// # Panics
+    /// This function will panic if the configuration can't be serialized to JSON.
+    pub fn resolve_final_config_file_for_path(&self, path: &Path) -> String {
+        let config = self.get_nearest_config(path).unwrap_or(&self.base);
+        let resolved = config.resolve_for_path(path, &self.external_plugin_store);
+
+        let mut rule_options = config.rule_options.clone();
+        for override_config in config.overrides_for_path(path) {
+            rule_options.extend(
+                override_config.rules.options().map(|(name, options)| (name, options.clone())),
+            );
+        }
+
+        let rules = resolved
+            .rules
+            .iter()
+            .sorted_by_key(|(rule, _)| (rule.plugin_name(), rule.name()))
+            .map(|(rule, severity)| ESLintRule {
+                plugin_name: rule.plugin_name().to_string(),
+                rule_name: rule.name().to_string(),
+                severity: *severity,
+                config: rule_options
+                    .get(&(CompactStr::from(rule.plugin_name()), CompactStr::from(rule.name())))
+                    .cloned(),
+            })
+            .chain(resolved.external_rules.iter().map(|(rule_id, severity)| {
+                let (plugin_name, rule_name) = self.resolve_plugin_rule_names(*rule_id);
+                ESLintRule {
+                    plugin_name: plugin_name.to_string(),
+                    rule_name: rule_name.to_string(),
+                    severity: *severity,
+                    config: None,
+                }
+            }))
+            .collect();
+
+        let lint_config = &*resolved.config;
+        let oxlintrc = Oxlintrc {
+            plugins: Some(lint_config.plugins.clone()),
+            rules: OxlintRules::new(rules),
+            settings: lint_config.settings.clone(),
+            env: lint_config.env.clone(),
+            globals: lint_config.globals.clone(),
+            ..Oxlintrc::default()
+        };
+        serde_json::to_string_pretty(&oxlintrc).unwrap()
     }
 
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
@@ -285,13 +563,23 @@ impl ConfigStore {
         None
     }
 
-    #[cfg_attr(not(all(feature = "oxlint2", not(feature = "disable_oxlint2"))), expect(dead_code))]
     pub(crate) fn resolve_plugin_rule_names(
         &self,
         external_rule_id: ExternalRuleId,
     ) -> (/* plugin name */ &str, /* rule name */ &str) {
         self.external_plugin_store.resolve_plugin_rule_names(external_rule_id)
     }
+
+    #[cfg_attr(
+        not(any(
+            all(feature = "oxlint2", not(feature = "disable_oxlint2")),
+            feature = "wasm_plugins"
+        )),
+        expect(dead_code)
+    )]
+    pub(crate) fn external_plugin_store(&self) -> &ExternalPluginStore {
+        &self.external_plugin_store
+    }
 }
 
 #[cfg(test)]
@@ -631,4 +919,132 @@ mod test {
         assert!(!app.globals.is_enabled("React"));
         assert!(!app.globals.is_enabled("Secret"));
     }
+
+    #[test]
+    fn test_apply_rule_directives() {
+        use oxc_allocator::Allocator;
+        use oxc_parser::Parser;
+        use oxc_span::SourceType;
+
+        use crate::rule_directives::parse_rule_directives;
+
+        let store = ConfigStore::new(
+            Config::new(
+                vec![no_explicit_any()],
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                OxlintOverrides::default(),
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+        // The rules of the state, with their severity at `offset`
+        let apply = |source_text: &str, offset: u32| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+            let (directives, _) = parse_rule_directives(source_text, &ret.program.comments);
+            let (state, severities, diagnostics) =
+                store.apply_rule_directives(&store.resolve("App.ts".as_ref()), &directives);
+            let rules = state
+                .rules
+                .iter()
+                .zip(&severities.rules)
+                .map(|((rule, _), severities)| (rule.name(), severities.at(offset)))
+                .collect::<Vec<_>>();
+            (rules, diagnostics.len())
+        };
+
+        let source_text = "/* eslint @typescript-eslint/no-explicit-any: error */";
+        assert_eq!(apply(source_text, 0), (vec![("no-explicit-any", AllowWarnDeny::Warn)], 0));
+        assert_eq!(apply(source_text, 60), (vec![("no-explicit-any", AllowWarnDeny::Deny)], 0));
+        let source_text = "a; /* oxlint typescript/no-explicit-any: off */ b;";
+        assert_eq!(apply(source_text, 0), (vec![("no-explicit-any", AllowWarnDeny::Warn)], 0));
+        assert_eq!(apply(source_text, 48), (vec![("no-explicit-any", AllowWarnDeny::Allow)], 0));
+        assert_eq!(
+            apply("/* eslint eqeqeq: [\"warn\", \"smart\"] */", 40),
+            (vec![("no-explicit-any", AllowWarnDeny::Warn), ("eqeqeq", AllowWarnDeny::Warn)], 0)
+        );
+        // A rule configured with options again is in the state twice, for each part of the file
+        let source_text =
+            "/* eslint eqeqeq: warn */ a == b; /* eslint eqeqeq: [\"error\", \"smart\"] */ a == b;";
+        assert_eq!(
+            apply(source_text, 30),
+            (
+                vec![
+                    ("no-explicit-any", AllowWarnDeny::Warn),
+                    ("eqeqeq", AllowWarnDeny::Warn),
+                    ("eqeqeq", AllowWarnDeny::Allow)
+                ],
+                0
+            )
+        );
+        assert_eq!(
+            apply(source_text, 80),
+            (
+                vec![
+                    ("no-explicit-any", AllowWarnDeny::Warn),
+                    ("eqeqeq", AllowWarnDeny::Allow),
+                    ("eqeqeq", AllowWarnDeny::Deny)
+                ],
+                0
+            )
+        );
+        // The `react` plugin is not enabled
+        assert_eq!(
+            apply("/* eslint react/jsx-key: error, no-such-rule: error */", 60),
+            (vec![("no-explicit-any", AllowWarnDeny::Warn)], 2)
+        );
+    }
+
+    #[test]
+    fn test_explain_resolution() {
+        use crate::config::RuleSource;
+
+        let overrides = from_json!([{
+            "files": ["*.test.ts"],
+            "rules": {
+                "@typescript-eslint/no-explicit-any": "off",
+                "eqeqeq": "error"
+            }
+        }]);
+        let store = ConfigStore::new(
+            Config::new(
+                vec![no_explicit_any()],
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                overrides,
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let source = store.explain_resolution("App.ts".as_ref());
+        assert!(!source.overrides[0].matched);
+        assert_eq!(source.rules.len(), 1);
+        assert_eq!(source.rules[0].name, "typescript/no-explicit-any");
+        assert_eq!(source.rules[0].severity, AllowWarnDeny::Warn);
+        assert_eq!(source.rules[0].source, RuleSource::Filter);
+
+        let test = store.explain_resolution("App.test.ts".as_ref());
+        assert!(test.overrides[0].matched);
+        assert_eq!(test.overrides[0].files, ["*.test.ts"]);
+        let rules = test
+            .rules
+            .iter()
+            .map(|rule| (rule.name.as_str(), rule.severity, rule.source))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rules,
+            [
+                ("eslint/eqeqeq", AllowWarnDeny::Deny, RuleSource::Override { index: 0 }),
+                (
+                    "typescript/no-explicit-any",
+                    AllowWarnDeny::Allow,
+                    RuleSource::Override { index: 0 }
+                ),
+            ]
+        );
+    }
 }
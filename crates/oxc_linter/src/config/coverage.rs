@@ -0,0 +1,356 @@
+use std::path::{Component, Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use oxc_span::CompactStr;
+
+use crate::{AllowWarnDeny, FileSystem, RuleEnum};
+
+use super::rules::OxlintRules;
+
+/// Change the severity of rules depending on how well a file is covered by tests.
+///
+/// For example, complex code may be acceptable in fully covered files, while it should be an error
+/// in files without any tests.
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "rules": {
+///     "complexity": "warn"
+///   },
+///   "coverage": {
+///     "report": "coverage/lcov.info",
+///     "covered": {
+///       "complexity": "off"
+///     },
+///     "uncovered": {
+///       "complexity": "error"
+///     }
+///   }
+/// }
+/// ```
+///
+/// Only the severity of rules which are enabled for a file is changed, rule options are ignored.
+/// Files which are not in the coverage report keep the configured severities.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OxlintCoverage {
+    /// Path of the coverage report, resolved relative to the configuration file.
+    ///
+    /// LCOV reports (`lcov.info`) and istanbul JSON reports (`coverage-final.json` or
+    /// `coverage-summary.json`) are supported. Relative paths of source files in the report are
+    /// also resolved relative to the configuration file.
+    pub report: PathBuf,
+    /// Minimum percentage of covered lines for a file to use the `covered` severities.
+    /// Defaults to `100`.
+    pub covered_threshold: f64,
+    /// Maximum percentage of covered lines for a file to use the `uncovered` severities.
+    /// Defaults to `0`.
+    pub uncovered_threshold: f64,
+    /// Severities of rules in files which are covered by tests.
+    #[serde(skip_serializing_if = "OxlintRules::is_empty")]
+    pub covered: OxlintRules,
+    /// Severities of rules in files which are not covered by tests.
+    #[serde(skip_serializing_if = "OxlintRules::is_empty")]
+    pub uncovered: OxlintRules,
+}
+
+impl Default for OxlintCoverage {
+    fn default() -> Self {
+        Self {
+            report: PathBuf::new(),
+            covered_threshold: 100.0,
+            uncovered_threshold: 0.0,
+            covered: OxlintRules::default(),
+            uncovered: OxlintRules::default(),
+        }
+    }
+}
+
+/// Number of lines of a file which are executable, and which were executed by tests.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FileCoverage {
+    pub total: u32,
+    pub covered: u32,
+}
+
+impl FileCoverage {
+    /// Percentage of covered lines. A file without executable lines is fully covered.
+    pub fn percentage(self) -> f64 {
+        if self.total == 0 {
+            return 100.0;
+        }
+        f64::from(self.covered) * 100.0 / f64::from(self.total)
+    }
+}
+
+/// Line coverage of source files, read from an LCOV or istanbul JSON coverage report.
+#[derive(Debug, Default, Clone)]
+pub struct CoverageReport {
+    files: FxHashMap<PathBuf, FileCoverage>,
+}
+
+impl CoverageReport {
+    /// Read the report at `path`. Reports with a `.json` extension are read as istanbul reports,
+    /// other reports as LCOV. Relative source file paths are resolved against `base_dir`.
+    ///
+    /// # Errors
+    /// When the report can't be read or is not a valid istanbul report
+    pub fn from_file(
+        path: &Path,
+        base_dir: &Path,
+        file_system: &dyn FileSystem,
+    ) -> Result<Self, String> {
+        let content = file_system.read_to_string(path).map_err(|err| err.to_string())?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            Self::from_istanbul(&content, base_dir)
+        } else {
+            Ok(Self::from_lcov(&content, base_dir))
+        }
+    }
+
+    /// Parse an LCOV report. Relative source file paths are resolved against `base_dir`.
+    pub fn from_lcov(content: &str, base_dir: &Path) -> Self {
+        let mut files = FxHashMap::default();
+        let mut source_file = None;
+        let mut coverage = FileCoverage::default();
+        let mut lines = FileCoverage::default();
+
+        for line in content.lines().map(str::trim) {
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            match key {
+                "SF" => {
+                    source_file = Some(normalize(&base_dir.join(value)));
+                    coverage = FileCoverage::default();
+                    lines = FileCoverage::default();
+                }
+                "LF" => coverage.total = value.parse().unwrap_or_default(),
+                "LH" => coverage.covered = value.parse().unwrap_or_default(),
+                "DA" => {
+                    lines.total += 1;
+                    let count = value.split(',').nth(1).and_then(|count| count.parse::<u64>().ok());
+                    if count.is_some_and(|count| count > 0) {
+                        lines.covered += 1;
+                    }
+                }
+                "end_of_record" => {
+                    if let Some(path) = source_file.take() {
+                        // `LF` and `LH` are optional, count the `DA` records instead.
+                        let coverage = if coverage.total == 0 { lines } else { coverage };
+                        files.insert(path, coverage);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self { files }
+    }
+
+    /// Parse an istanbul JSON report, either the detailed `coverage-final.json` or
+    /// `coverage-summary.json`. Relative source file paths are resolved against `base_dir`.
+    ///
+    /// The detailed report only contains statements, which are counted as lines.
+    ///
+    /// # Errors
+    /// When the report is not a JSON object
+    pub fn from_istanbul(content: &str, base_dir: &Path) -> Result<Self, String> {
+        let json = serde_json::from_str::<Value>(content).map_err(|err| err.to_string())?;
+        let Value::Object(entries) = json else {
+            return Err("expected a JSON object of source files".to_string());
+        };
+
+        let mut files = FxHashMap::default();
+        for (path, entry) in entries {
+            // The summary report contains the total of all files.
+            if path == "total" {
+                continue;
+            }
+            let coverage = if let Some(lines) = entry.get("lines") {
+                let count = |key| {
+                    lines
+                        .get(key)
+                        .and_then(Value::as_u64)
+                        .map_or(0, |n| u32::try_from(n).unwrap_or(u32::MAX))
+                };
+                FileCoverage { total: count("total"), covered: count("covered") }
+            } else if let Some(Value::Object(statements)) = entry.get("s") {
+                let counts = statements.values().filter_map(Value::as_u64);
+                FileCoverage {
+                    total: u32::try_from(statements.len()).unwrap_or(u32::MAX),
+                    covered: u32::try_from(counts.filter(|count| *count > 0).count())
+                        .unwrap_or(u32::MAX),
+                }
+            } else {
+                continue;
+            };
+            files.insert(normalize(&base_dir.join(path)), coverage);
+        }
+
+        Ok(Self { files })
+    }
+
+    /// Coverage of the source file at `path`, if it is in the report.
+    pub fn get(&self, path: &Path) -> Option<FileCoverage> {
+        self.files.get(path).or_else(|| self.files.get(&normalize(path))).copied()
+    }
+}
+
+/// Changes the severities of the rules of a file according to its coverage.
+#[derive(Debug)]
+pub struct CoveragePolicy {
+    report: CoverageReport,
+    covered_threshold: f64,
+    uncovered_threshold: f64,
+    /// Severities by plugin and rule name.
+    covered: FxHashMap<(CompactStr, CompactStr), AllowWarnDeny>,
+    uncovered: FxHashMap<(CompactStr, CompactStr), AllowWarnDeny>,
+}
+
+impl CoveragePolicy {
+    pub fn new(config: &OxlintCoverage, report: CoverageReport) -> Self {
+        Self {
+            report,
+            covered_threshold: config.covered_threshold,
+            uncovered_threshold: config.uncovered_threshold,
+            covered: config.covered.severities().collect(),
+            uncovered: config.uncovered.severities().collect(),
+        }
+    }
+
+    /// Change the severities of `rules` for the file at `path`, and remove the rules which are
+    /// turned off. Returns `None` if no severity changes.
+    pub fn apply(
+        &self,
+        path: &Path,
+        rules: &[(RuleEnum, AllowWarnDeny)],
+    ) -> Option<Vec<(RuleEnum, AllowWarnDeny)>> {
+        let percentage = self.report.get(path)?.percentage();
+        let severities = if percentage >= self.covered_threshold {
+            &self.covered
+        } else if percentage <= self.uncovered_threshold {
+            &self.uncovered
+        } else {
+            return None;
+        };
+        if severities.is_empty() {
+            return None;
+        }
+
+        let rules = rules
+            .iter()
+            .filter_map(|(rule, severity)| {
+                let key = (CompactStr::from(rule.plugin_name()), CompactStr::from(rule.name()));
+                let severity = severities.get(&key).copied().unwrap_or(*severity);
+                severity.is_warn_deny().then(|| (rule.clone(), severity))
+            })
+            .collect();
+        Some(rules)
+    }
+}
+
+/// Remove `.` and `..` components without touching the file system.
+pub(super) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use crate::{AllowWarnDeny, RuleEnum, rules::EslintNoDebugger};
+
+    use super::{CoveragePolicy, CoverageReport, FileCoverage, OxlintCoverage};
+
+    #[test]
+    fn lcov() {
+        let report = CoverageReport::from_lcov(
+            "TN:\nSF:src/a.js\nDA:1,1\nDA:2,0\nLF:2\nLH:1\nend_of_record\nSF:./src/b.js\nDA:1,3\nend_of_record\n",
+            Path::new("/project"),
+        );
+        assert_eq!(
+            report.get(Path::new("/project/src/a.js")),
+            Some(FileCoverage { total: 2, covered: 1 })
+        );
+        assert_eq!(
+            report.get(Path::new("/project/src/b.js")),
+            Some(FileCoverage { total: 1, covered: 1 })
+        );
+        assert_eq!(
+            report.get(Path::new("/project/src/../src/b.js")),
+            Some(FileCoverage { total: 1, covered: 1 })
+        );
+        assert_eq!(report.get(Path::new("/project/src/c.js")), None);
+    }
+
+    #[test]
+    fn istanbul() {
+        let final_report = json!({
+            "/project/src/a.js": { "path": "/project/src/a.js", "s": { "0": 1, "1": 0, "2": 4 } },
+        });
+        let report = CoverageReport::from_istanbul(
+            &final_report.to_string(),
+            Path::new("/project/coverage"),
+        )
+        .unwrap();
+        assert_eq!(
+            report.get(Path::new("/project/src/a.js")),
+            Some(FileCoverage { total: 3, covered: 2 })
+        );
+
+        let summary = json!({
+            "total": { "lines": { "total": 10, "covered": 5, "pct": 50 } },
+            "src/a.js": { "lines": { "total": 4, "covered": 0, "pct": 0 } },
+        });
+        let report =
+            CoverageReport::from_istanbul(&summary.to_string(), Path::new("/project")).unwrap();
+        assert_eq!(
+            report.get(Path::new("/project/src/a.js")),
+            Some(FileCoverage { total: 4, covered: 0 })
+        );
+        assert_eq!(report.get(Path::new("/project/total")), None);
+
+        assert!(CoverageReport::from_istanbul("[]", Path::new("/project")).is_err());
+    }
+
+    #[test]
+    fn policy() {
+        let config: OxlintCoverage = serde_json::from_value(json!({
+            "report": "lcov.info",
+            "coveredThreshold": 80,
+            "covered": { "no-debugger": "off" },
+            "uncovered": { "no-debugger": "error" },
+        }))
+        .unwrap();
+        let report = CoverageReport::from_lcov(
+            "SF:covered.js\nLF:5\nLH:4\nend_of_record\nSF:partial.js\nLF:5\nLH:1\nend_of_record\nSF:uncovered.js\nLF:5\nLH:0\nend_of_record\n",
+            Path::new("/project"),
+        );
+        let policy = CoveragePolicy::new(&config, report);
+        let rules = [(RuleEnum::EslintNoDebugger(EslintNoDebugger), AllowWarnDeny::Warn)];
+
+        assert!(policy.apply(Path::new("/project/covered.js"), &rules).unwrap().is_empty());
+        assert!(policy.apply(Path::new("/project/partial.js"), &rules).is_none());
+        assert!(policy.apply(Path::new("/project/other.js"), &rules).is_none());
+        let uncovered = policy.apply(Path::new("/project/uncovered.js"), &rules).unwrap();
+        assert_eq!(uncovered.len(), 1);
+        assert_eq!(uncovered[0].1, AllowWarnDeny::Deny);
+    }
+}
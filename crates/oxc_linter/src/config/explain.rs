@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use oxc_span::CompactStr;
+use serde::Serialize;
+
+use crate::{AllowWarnDeny, BuiltinLintPlugins, ExternalPluginStore};
+
+use super::{Config, rules::transform_rule_and_plugin_name};
+
+/// How the configuration of a file is resolved, from the configuration files which are found to
+/// the severity of each rule. See [`ConfigStore::explain_resolution`](super::ConfigStore::explain_resolution).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigResolution {
+    /// Path of the root configuration file, `None` without a configuration file.
+    pub root_config: Option<PathBuf>,
+    /// Paths of the nested configuration files, sorted.
+    pub nested_configs: Vec<PathBuf>,
+    /// Path of the configuration file which applies to the file: the nearest nested
+    /// configuration file, or the root configuration file.
+    pub config: Option<PathBuf>,
+    /// Whether the configuration which applies to the file is a nested configuration.
+    pub is_nested: bool,
+    /// Path which the `files` of overrides are matched against, relative to the directory of the
+    /// configuration file.
+    pub override_path: PathBuf,
+    /// Overrides of the configuration, in the order they are applied.
+    pub overrides: Vec<OverrideResolution>,
+    /// Plugins enabled for the file, with the plugins of matching overrides.
+    pub plugins: Vec<String>,
+    /// Severities of rule categories.
+    pub categories: Vec<(&'static str, AllowWarnDeny)>,
+    /// Built-in rules enabled for the file, and built-in rules disabled by matching overrides,
+    /// sorted by name.
+    pub rules: Vec<RuleResolution>,
+}
+
+/// Whether an override applies to the file.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideResolution {
+    /// Index of the override in `overrides`.
+    pub index: usize,
+    /// Glob patterns of the override.
+    pub files: Vec<String>,
+    pub matched: bool,
+}
+
+/// The severity of a rule for the file, and what decided it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleResolution {
+    /// Full name of the rule, e.g. `eslint/no-console`.
+    pub name: String,
+    pub severity: AllowWarnDeny,
+    pub source: RuleSource,
+}
+
+/// What decided the severity of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RuleSource {
+    /// Configured by the override at `index`.
+    Override { index: usize },
+    /// Changed by the coverage policy, depending on the test coverage of the file.
+    Coverage,
+    /// Configured in `rules` of the configuration file, or with `--rule`.
+    Rules,
+    /// Enabled by the severity of its category.
+    Category { category: &'static str },
+    /// Enabled or disabled with `-A`, `-W` or `-D`, or by default.
+    Filter,
+}
+
+impl Config {
+    /// Explain the resolution of the configuration of the file at `path`, see [`ConfigResolution`].
+    /// `nested_configs` and `is_nested` are filled in by the [`ConfigStore`](super::ConfigStore).
+    pub(super) fn explain_resolution(
+        &self,
+        path: &Path,
+        external_plugin_store: &ExternalPluginStore,
+    ) -> ConfigResolution {
+        let override_path = self.override_path(path).to_path_buf();
+        let overrides = self
+            .overrides
+            .iter()
+            .enumerate()
+            .map(|(index, override_config)| OverrideResolution {
+                index,
+                files: override_config.files.patterns().to_vec(),
+                matched: override_config.files.is_match(&override_path),
+            })
+            .collect::<Vec<_>>();
+
+        let without_coverage = self.apply_overrides(path, external_plugin_store);
+        let state = self.resolve_for_path(path, external_plugin_store);
+
+        let configured_by_override = |plugin_name: &str, rule_name: &str| {
+            overrides.iter().rev().filter(|o| o.matched).find_map(|o| {
+                self.overrides[o.index].rules.rules.iter().find_map(|rule| {
+                    let (name, plugin) =
+                        transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+                    (name == rule_name && plugin == plugin_name).then_some((o.index, rule.severity))
+                })
+            })
+        };
+
+        let mut rules = state
+            .rules
+            .iter()
+            .map(|(rule, severity)| {
+                let (plugin_name, rule_name) = (rule.plugin_name(), rule.name());
+                let source =
+                    if let Some((index, _)) = configured_by_override(plugin_name, rule_name) {
+                        RuleSource::Override { index }
+                    } else if without_coverage
+                        .rules
+                        .iter()
+                        .find(|(r, _)| r.id() == rule.id())
+                        .is_none_or(|(_, s)| s != severity)
+                    {
+                        RuleSource::Coverage
+                    } else if self
+                        .rule_severities
+                        .get(&(CompactStr::from(plugin_name), CompactStr::from(rule_name)))
+                        .is_some_and(|s| s == severity)
+                    {
+                        RuleSource::Rules
+                    } else if self.categories.get(&rule.category()).is_some_and(|s| s == severity) {
+                        RuleSource::Category { category: rule.category().as_str() }
+                    } else {
+                        RuleSource::Filter
+                    };
+                RuleResolution {
+                    name: format!("{plugin_name}/{rule_name}"),
+                    severity: *severity,
+                    source,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Rules disabled by overrides don't appear in the resolved state
+        for o in overrides.iter().filter(|o| o.matched) {
+            for rule in &self.overrides[o.index].rules.rules {
+                let (rule_name, plugin_name) =
+                    transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+                let name = format!("{plugin_name}/{rule_name}");
+                if rules.iter().any(|r| r.name == name)
+                    || configured_by_override(plugin_name, rule_name)
+                        .is_none_or(|(index, severity)| index != o.index || severity.is_warn_deny())
+                {
+                    continue;
+                }
+                rules.push(RuleResolution {
+                    name,
+                    severity: AllowWarnDeny::Allow,
+                    source: RuleSource::Override { index: o.index },
+                });
+            }
+        }
+        rules.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        let plugins = &state.config.plugins;
+        let mut external_plugins = plugins.external.iter().cloned().collect::<Vec<_>>();
+        external_plugins.sort_unstable();
+        let plugins = BuiltinLintPlugins::all()
+            .iter()
+            .filter(|flag| plugins.builtin.contains(*flag))
+            .map(|flag| <&'static str>::from(flag).to_string())
+            .filter(|name| !name.is_empty())
+            .chain(external_plugins)
+            .collect();
+
+        let mut categories = self
+            .categories
+            .iter()
+            .map(|(category, severity)| (category.as_str(), *severity))
+            .collect::<Vec<_>>();
+        categories.sort_unstable_by_key(|(category, _)| *category);
+
+        ConfigResolution {
+            root_config: None,
+            nested_configs: vec![],
+            config: self.base.config.path.clone(),
+            is_nested: false,
+            override_path,
+            overrides,
+            plugins,
+            categories,
+            rules,
+        }
+    }
+}
@@ -3,7 +3,9 @@ use std::path::PathBuf;
 mod categories;
 mod config_builder;
 mod config_store;
+mod coverage;
 mod env;
+mod explain;
 mod globals;
 mod overrides;
 mod oxlintrc;
@@ -11,15 +13,18 @@ mod plugins;
 mod rules;
 mod settings;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
+pub(crate) use config_store::DirectiveSeverities;
 pub use config_store::ResolvedLinterState;
 pub use config_store::{Config, ConfigStore};
+pub use coverage::OxlintCoverage;
 pub use env::OxlintEnv;
+pub use explain::{ConfigResolution, OverrideResolution, RuleResolution, RuleSource};
 pub use globals::{GlobalValue, OxlintGlobals};
 pub use overrides::OxlintOverrides;
 pub use oxlintrc::Oxlintrc;
 pub use plugins::{BuiltinLintPlugins, LintPlugins};
-pub use rules::{ESLintRule, OxlintRules};
-pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings};
+pub use rules::{ESLintRule, OxlintRules, RenamedRule};
+pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings, react::JsxRuntime};
 
 #[derive(Debug, Default, Clone)]
 pub struct LintConfig {
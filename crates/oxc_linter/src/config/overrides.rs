@@ -78,6 +78,9 @@ impl JsonSchema for OxlintOverrides {
 pub struct OxlintOverride {
     /// A list of glob patterns to override.
     ///
+    /// Patterns are resolved from the directory of the configuration file, so the overrides of a
+    /// nested configuration file only apply to files in its directory.
+    ///
     /// ## Example
     /// `[ "*.test.ts", "*.spec.ts" ]`
     pub files: GlobSet,
@@ -133,6 +136,11 @@ impl GlobSet {
     pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
         self.globs.is_match(path)
     }
+
+    /// The glob patterns, as configured.
+    pub fn patterns(&self) -> &[String] {
+        &self.raw
+    }
 }
 
 impl std::fmt::Debug for GlobSet {
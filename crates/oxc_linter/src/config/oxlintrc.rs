@@ -9,11 +9,16 @@ use serde::{Deserialize, Serialize};
 
 use oxc_diagnostics::OxcDiagnostic;
 
-use crate::{LintPlugins, utils::read_to_string};
+use crate::{FileSystem, LintPlugins, OsFileSystem};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals,
-    overrides::OxlintOverrides, rules::OxlintRules, settings::OxlintSettings,
+    categories::OxlintCategories,
+    coverage::OxlintCoverage,
+    env::OxlintEnv,
+    globals::OxlintGlobals,
+    overrides::OxlintOverrides,
+    rules::{OxlintRules, RenamedRule},
+    settings::OxlintSettings,
 };
 
 /// Oxlint Configuration File
@@ -101,8 +106,35 @@ pub struct Oxlintrc {
     /// are resolved relative to the location of the configuration file that contains the `extends`
     /// property. The configuration files are merged from the first to the last, with the last file
     /// overriding the previous ones.
+    ///
+    /// Shareable configurations can be extended from packages in `node_modules`, e.g.
+    /// `"@company/oxlint-config"` for the JSON file in the `main` or `exports` of its
+    /// `package.json`, or `"@company/oxlint-config/strict.json"` for a file in the package.
+    ///
+    /// With the `remote_config` feature of oxlint, configurations can also be extended from
+    /// `http://` and `https://` URLs. Relative paths in the `extends` of such a configuration
+    /// are resolved relative to its URL.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// Number of warnings to trigger nonzero exit code. Same as the `--max-warnings` CLI option,
+    /// which takes precedence over this setting.
+    ///
+    /// Only used in the root configuration file. It is not inherited from `extends`, and ignored in
+    /// nested configuration files.
+    #[serde(rename = "maxWarnings", skip_serializing_if = "Option::is_none")]
+    pub max_warnings: Option<usize>,
+    /// Change the severity of rules in files depending on their test coverage.
+    ///
+    /// Not inherited from `extends`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<OxlintCoverage>,
+    /// Mark the directory of this configuration file as the root of the project. Configuration
+    /// files in its parent directories, e.g. of an enclosing repository or of the home directory,
+    /// are not loaded when searching for nested configuration files.
+    ///
+    /// Not inherited from `extends`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub root: bool,
 }
 
 impl Oxlintrc {
@@ -110,7 +142,23 @@ impl Oxlintrc {
     ///
     /// * Parse Failure
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
-        let mut string = read_to_string(path).map_err(|e| {
+        Self::from_file_with_file_system(path, &OsFileSystem)
+    }
+
+    /// Read a configuration file from `file_system`.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    pub fn from_file_with_file_system(
+        path: &Path,
+        file_system: &dyn FileSystem,
+    ) -> Result<Self, OxcDiagnostic> {
+        let content = match path.to_str().filter(|path| is_url(path)) {
+            Some(url) => file_system.fetch(url),
+            None => file_system.read_to_string(path),
+        };
+        let mut string = content.map_err(|e| {
             OxcDiagnostic::error(format!(
                 "Failed to parse config {} with error {e:?}",
                 path.display()
@@ -208,14 +256,59 @@ impl Oxlintrc {
             path: self.path.clone(),
             ignore_patterns: self.ignore_patterns.clone(),
             extends: self.extends.clone(),
+            max_warnings: self.max_warnings,
+            coverage: self.coverage.clone(),
+            root: self.root,
         }
     }
+
+    /// Rules which are configured by the names they were renamed from, in `rules` and in
+    /// `overrides`.
+    pub fn renamed_rules(&self) -> impl Iterator<Item = &RenamedRule> {
+        self.rules
+            .renamed()
+            .iter()
+            .chain((&self.overrides).into_iter().flat_map(|r#override| r#override.rules.renamed()))
+    }
+
+    /// Replace the old names of [renamed rules](Self::renamed_rules) in `source_text`, the source
+    /// text of this configuration file, with their current names. Comments and formatting are
+    /// kept.
+    ///
+    /// Returns `None` if no names are replaced.
+    pub fn fix_renamed_rules(&self, source_text: &str) -> Option<String> {
+        let mut fixed = source_text.to_string();
+        let mut changed = false;
+        for renamed in self.renamed_rules() {
+            let old_key = format!("\"{}\"", renamed.old_name);
+            let new_key = format!("\"{}\"", renamed.new_name);
+            let mut start = 0;
+            while let Some(index) = fixed[start..].find(&old_key) {
+                let index = start + index;
+                let end = index + old_key.len();
+                // Only replace keys, not the same string as a value, e.g. in the options of a rule
+                if fixed[end..].trim_start().starts_with(':') {
+                    fixed.replace_range(index..end, &new_key);
+                    start = index + new_key.len();
+                    changed = true;
+                } else {
+                    start = end;
+                }
+            }
+        }
+        changed.then_some(fixed)
+    }
 }
 
 fn is_json_ext(ext: &str) -> bool {
     ext == "json" || ext == "jsonc"
 }
 
+/// Whether a path in `extends`, or of a configuration file, is a URL.
+pub(super) fn is_url(path: &str) -> bool {
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
@@ -234,6 +327,47 @@ mod test {
         assert_eq!(config.env, OxlintEnv::default());
         assert_eq!(config.path, PathBuf::default());
         assert_eq!(config.extends, Vec::<PathBuf>::default());
+        assert_eq!(config.max_warnings, None);
+        assert!(!config.root);
+    }
+
+    #[test]
+    fn test_oxlintrc_de_root() {
+        let config: Oxlintrc = serde_json::from_value(json!({ "root": true })).unwrap();
+        assert!(config.root);
+        // Not inherited from `extends`
+        assert!(!Oxlintrc::default().merge(config).root);
+    }
+
+    #[test]
+    fn test_oxlintrc_fix_renamed_rules() {
+        let source_text = r#"{
+  // "unicorn/no-reduce" is the old name
+  "rules": { "unicorn/no-reduce" : "error", "no-restricted-syntax": ["error", "no-new-symbol"] },
+  "overrides": [{ "files": ["*.ts"], "rules": { "no-new-symbol": "off" } }]
+}"#;
+        let mut json = source_text.to_string();
+        json_strip_comments::strip(&mut json).unwrap();
+        let config: Oxlintrc = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            config.renamed_rules().map(|renamed| renamed.old_name.as_str()).collect::<Vec<_>>(),
+            ["unicorn/no-reduce", "no-new-symbol"]
+        );
+        assert_eq!(
+            config.fix_renamed_rules(source_text).unwrap(),
+            r#"{
+  // "unicorn/no-reduce" is the old name
+  "rules": { "unicorn/no-array-reduce" : "error", "no-restricted-syntax": ["error", "no-new-symbol"] },
+  "overrides": [{ "files": ["*.ts"], "rules": { "no-new-native-nonconstructor": "off" } }]
+}"#
+        );
+        assert_eq!(Oxlintrc::default().fix_renamed_rules(source_text), None);
+    }
+
+    #[test]
+    fn test_oxlintrc_de_max_warnings() {
+        let config: Oxlintrc = serde_json::from_value(json!({ "maxWarnings": 10 })).unwrap();
+        assert_eq!(config.max_warnings, Some(10));
     }
 
     #[test]
@@ -263,7 +397,7 @@ mod test {
             Some(BuiltinLintPlugins::TYPESCRIPT.union(BuiltinLintPlugins::UNICORN).into())
         );
         let config: Oxlintrc =
-            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "regex", "vue"] }"#).unwrap();
+            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "regex", "vue", "security"] }"#).unwrap();
         assert_eq!(config.plugins, Some(BuiltinLintPlugins::all().into()));
 
         let config: Oxlintrc =
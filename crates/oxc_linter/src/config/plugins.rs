@@ -51,6 +51,8 @@ bitflags! {
         const REGEX = 1 << 13;
         /// `eslint-plugin-vue`
         const VUE = 1 << 14;
+        /// `eslint-plugin-security`
+        const SECURITY = 1 << 15;
     }
 }
 
@@ -125,6 +127,7 @@ impl From<&str> for BuiltinLintPlugins {
             "node" => BuiltinLintPlugins::NODE,
             "regex" => BuiltinLintPlugins::REGEX,
             "vue" => BuiltinLintPlugins::VUE,
+            "security" => BuiltinLintPlugins::SECURITY,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => BuiltinLintPlugins::empty(),
@@ -150,6 +153,7 @@ impl From<BuiltinLintPlugins> for &'static str {
             BuiltinLintPlugins::NODE => "node",
             BuiltinLintPlugins::REGEX => "regex",
             BuiltinLintPlugins::VUE => "vue",
+            BuiltinLintPlugins::SECURITY => "security",
             _ => "",
         }
     }
@@ -235,6 +239,7 @@ impl JsonSchema for LintPlugins {
             Node,
             Regex,
             Vue,
+            Security,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -10,6 +10,7 @@ use serde::{
 };
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
+use oxc_span::CompactStr;
 
 use crate::{
     AllowWarnDeny, BuiltinLintPlugins, ExternalPluginStore,
@@ -29,17 +30,123 @@ type RuleSet = FxHashMap<RuleEnum, AllowWarnDeny>;
 pub struct OxlintRules {
     /// List of all configured rules
     pub(crate) rules: Vec<ESLintRule>,
+    /// Rules configured by the names they were renamed from
+    pub(crate) renamed: Vec<RenamedRule>,
 }
 
 impl OxlintRules {
     pub fn new(rules: Vec<ESLintRule>) -> Self {
-        Self { rules }
+        Self { rules, renamed: vec![] }
+    }
+
+    /// Rules which are configured by the names they were renamed from. They are configured by
+    /// their current names.
+    pub fn renamed(&self) -> &[RenamedRule] {
+        &self.renamed
     }
 
     /// Returns `true` if there are no rules.
     pub fn is_empty(&self) -> bool {
         self.rules.is_empty()
     }
+
+    /// Iterate over the configured rules, in the order they were configured.
+    pub fn iter(&self) -> impl Iterator<Item = &ESLintRule> {
+        self.rules.iter()
+    }
+
+    /// Parse rules configured on the command line in the form of `name=options`, where `options`
+    /// is a severity or an array of a severity and the options of the rule in JSON, e.g.
+    /// `eqeqeq=error` or `no-console=["warn", { "allow": ["error"] }]`.
+    ///
+    /// # Errors
+    /// When a value is not `name=options`, or `options` is not a rule configuration.
+    pub fn parse<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> Result<Self, Error> {
+        let mut rules = Self::default();
+        for value in values {
+            let value = value.as_ref();
+            let Some((key, options)) = value
+                .split_once('=')
+                .filter(|(key, options)| !key.is_empty() && !options.is_empty())
+            else {
+                return Err(OxcDiagnostic::error(format!(
+                    "Invalid rule `{value}`, expected `name=options`, e.g. `eqeqeq=error`"
+                ))
+                .into());
+            };
+            // A bare severity doesn't need to be quoted, e.g. `eqeqeq=error`
+            let options = serde_json::from_str(options)
+                .unwrap_or_else(|_| serde_json::Value::String(options.to_string()));
+            let mut renamed = vec![];
+            let (plugin_name, rule_name) = parse_renamed_rule_key(key, &mut renamed);
+            let (severity, config) = parse_rule_value(&options)?;
+            let rule = ESLintRule { plugin_name, rule_name, severity, config };
+            rules.extend(Self { rules: vec![rule], renamed });
+        }
+        Ok(rules)
+    }
+
+    /// Configure the rules of `other`, replacing the configuration of rules which are
+    /// configured in both.
+    pub fn extend(&mut self, other: Self) {
+        for renamed in other.renamed {
+            if !self.renamed.contains(&renamed) {
+                self.renamed.push(renamed);
+            }
+        }
+        for rule in other.rules {
+            match self.rules.iter_mut().find(|existing| {
+                existing.plugin_name == rule.plugin_name && existing.rule_name == rule.rule_name
+            }) {
+                Some(existing) => *existing = rule,
+                None => self.rules.push(rule),
+            }
+        }
+    }
+
+    /// Check the options of the configured builtin rules, which rules ignore when they are
+    /// invalid.
+    ///
+    /// # Errors
+    /// The name of the first rule with invalid options, and why they are invalid.
+    pub(crate) fn validate_options(&self) -> Result<(), (String, String)> {
+        for rule_config in &self.rules {
+            let Some(options) = &rule_config.config else { continue };
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule_config.rule_name, &rule_config.plugin_name);
+            let Some(rule) =
+                RULES.iter().find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+            else {
+                continue;
+            };
+            rule.validate_json(options)
+                .map_err(|reason| (rule_config.full_name().into_owned(), reason))?;
+        }
+        Ok(())
+    }
+
+    /// Options of the configured rules, by the names of the plugin and the rule they configure.
+    pub(crate) fn options(
+        &self,
+    ) -> impl Iterator<Item = ((CompactStr, CompactStr), &serde_json::Value)> {
+        self.rules.iter().filter_map(|rule| {
+            let config = rule.config.as_ref()?;
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+            Some(((CompactStr::from(plugin_name), CompactStr::from(rule_name)), config))
+        })
+    }
+
+    /// Severities of the configured rules, by the names of the plugin and the rule they configure.
+    pub(crate) fn severities(
+        &self,
+    ) -> impl Iterator<Item = ((CompactStr, CompactStr), AllowWarnDeny)> {
+        self.rules.iter().map(|rule| {
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+            ((CompactStr::from(plugin_name), CompactStr::from(rule_name)), rule.severity)
+        })
+    }
 }
 
 /// A fully qualified rule name.
@@ -59,6 +166,43 @@ pub struct ESLintRule {
     pub config: Option<serde_json::Value>,
 }
 
+/// A rule configured by a name it was renamed from, e.g. its name in ESLint or in an old version
+/// of the plugin.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RenamedRule {
+    /// Name of the rule in the configuration, e.g. `unicorn/no-reduce`.
+    pub old_name: String,
+    /// Current name of the rule, e.g. `unicorn/no-array-reduce`.
+    pub new_name: String,
+}
+
+/// Rules which were renamed, or replaced by the same rule in another plugin, as the plugin and
+/// rule names they were renamed from and to.
+/// Configurations using the old names keep working, with a warning.
+const RENAMED_RULES: &[((&str, &str), (&str, &str))] = &[
+    (("eslint", "no-native-reassign"), ("eslint", "no-global-assign")),
+    (("eslint", "no-negated-in-lhs"), ("eslint", "no-unsafe-negation")),
+    (("eslint", "no-new-symbol"), ("eslint", "no-new-native-nonconstructor")),
+    (("import", "imports-first"), ("import", "first")),
+    (("jest", "no-if"), ("jest", "no-conditional-in-test")),
+    (("jest", "prefer-to-be-null"), ("jest", "prefer-to-be")),
+    (("jest", "prefer-to-be-undefined"), ("jest", "prefer-to-be")),
+    (("jest", "valid-describe"), ("jest", "valid-describe-callback")),
+    (("jsx_a11y", "label-has-for"), ("jsx_a11y", "label-has-associated-control")),
+    (("typescript", "no-duplicate-imports"), ("eslint", "no-duplicate-imports")),
+    (("unicorn", "no-array-instanceof"), ("unicorn", "no-instanceof-array")),
+    (("unicorn", "no-reduce"), ("unicorn", "no-array-reduce")),
+    (("unicorn", "prefer-dataset"), ("unicorn", "prefer-dom-node-dataset")),
+    (("unicorn", "prefer-exponentiation-operator"), ("eslint", "prefer-exponentiation-operator")),
+    (("unicorn", "prefer-flat-map"), ("unicorn", "prefer-array-flat-map")),
+    (("unicorn", "prefer-node-append"), ("unicorn", "prefer-dom-node-append")),
+    (("unicorn", "prefer-node-remove"), ("unicorn", "prefer-dom-node-remove")),
+    (("unicorn", "prefer-replace-all"), ("unicorn", "prefer-string-replace-all")),
+    (("unicorn", "prefer-starts-ends-with"), ("unicorn", "prefer-string-starts-ends-with")),
+    (("unicorn", "prefer-text-content"), ("unicorn", "prefer-dom-node-text-content")),
+    (("unicorn", "prefer-trim-start-end"), ("unicorn", "prefer-string-trim-start-end")),
+];
+
 impl OxlintRules {
     pub(crate) fn override_rules(
         &self,
@@ -116,7 +260,7 @@ impl OxlintRules {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+pub(super) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -205,13 +349,14 @@ impl<'de> Deserialize<'de> for OxlintRules {
                 M: de::MapAccess<'de>,
             {
                 let mut rules = vec![];
+                let mut renamed = vec![];
                 while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
-                    let (plugin_name, rule_name) = parse_rule_key(&key);
+                    let (plugin_name, rule_name) = parse_renamed_rule_key(&key, &mut renamed);
                     let (severity, config) = parse_rule_value(&value).map_err(de::Error::custom)?;
                     rules.push(ESLintRule { plugin_name, rule_name, severity, config });
                 }
 
-                Ok(OxlintRules { rules })
+                Ok(OxlintRules { rules, renamed })
             }
         }
 
@@ -251,6 +396,24 @@ fn parse_rule_key(name: &str) -> (String, String) {
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
+/// Parse a rule key like [`parse_rule_key`], replacing the names of renamed rules with their
+/// current names, and recording the rename in `renamed`.
+fn parse_renamed_rule_key(name: &str, renamed: &mut Vec<RenamedRule>) -> (String, String) {
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    let Some(&(_, (new_plugin_name, new_rule_name))) =
+        RENAMED_RULES.iter().find(|(old, _)| *old == (plugin_name.as_str(), rule_name.as_str()))
+    else {
+        return (plugin_name, rule_name);
+    };
+    let new_name = if new_plugin_name == "eslint" {
+        new_rule_name.to_string()
+    } else {
+        format!("{new_plugin_name}/{new_rule_name}")
+    };
+    renamed.push(RenamedRule { old_name: name.to_string(), new_name });
+    (new_plugin_name.to_string(), new_rule_name.to_string())
+}
+
 fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
@@ -305,6 +468,14 @@ impl ESLintRule {
             Cow::Owned(format!("{}/{}", self.plugin_name, self.rule_name))
         }
     }
+
+    /// Returns `true` if the rule is implemented by oxlint, rather than by a JS plugin or not
+    /// at all.
+    pub fn is_builtin(&self) -> bool {
+        let (rule_name, plugin_name) =
+            transform_rule_and_plugin_name(&self.rule_name, &self.plugin_name);
+        RULES.iter().any(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+    }
 }
 
 #[cfg(test)]
@@ -319,7 +490,7 @@ mod test {
         rules::{RULES, RuleEnum},
     };
 
-    use super::{OxlintRules, RuleSet};
+    use super::{OxlintRules, RENAMED_RULES, RenamedRule, RuleSet};
 
     #[test]
     fn test_parse_rules() {
@@ -357,6 +528,90 @@ mod test {
         assert!(r4.config.is_none());
     }
 
+    #[test]
+    fn test_parse_cli_rules() {
+        let rules = OxlintRules::parse([
+            "no-console=error",
+            "@typescript-eslint/no-explicit-any=[\"warn\", { \"fixToUnknown\": true }]",
+            "no-console=off",
+        ])
+        .unwrap();
+        let mut rules = rules.rules.iter();
+
+        let r1 = rules.next().unwrap();
+        assert_eq!(r1.rule_name, "no-console");
+        assert_eq!(r1.plugin_name, "eslint");
+        assert!(r1.severity.is_allow());
+
+        let r2 = rules.next().unwrap();
+        assert_eq!(r2.rule_name, "no-explicit-any");
+        assert_eq!(r2.plugin_name, "typescript");
+        assert_eq!(r2.severity, AllowWarnDeny::Warn);
+        assert_eq!(r2.config, Some(json!([{ "fixToUnknown": true }])));
+        assert!(rules.next().is_none());
+
+        assert!(OxlintRules::parse(["no-console"]).is_err());
+        assert!(OxlintRules::parse(["=error"]).is_err());
+        assert!(OxlintRules::parse(["no-console=loud"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_renamed_rules() {
+        let rules = OxlintRules::deserialize(&json!({
+            "unicorn/no-reduce": "error",
+            "no-new-symbol": "warn",
+            "@typescript-eslint/no-duplicate-imports": ["error", { "includeExports": true }],
+            "unicorn/no-array-reduce": "off",
+        }))
+        .unwrap();
+        let names = rules
+            .rules
+            .iter()
+            .map(|rule| (rule.plugin_name.as_str(), rule.rule_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("unicorn", "no-array-reduce"),
+                ("eslint", "no-new-native-nonconstructor"),
+                ("eslint", "no-duplicate-imports"),
+                ("unicorn", "no-array-reduce"),
+            ]
+        );
+        assert_eq!(rules.rules[2].config, Some(json!([{ "includeExports": true }])));
+        let renamed = |old_name: &str, new_name: &str| RenamedRule {
+            old_name: old_name.to_string(),
+            new_name: new_name.to_string(),
+        };
+        assert_eq!(
+            rules.renamed(),
+            [
+                renamed("unicorn/no-reduce", "unicorn/no-array-reduce"),
+                renamed("no-new-symbol", "no-new-native-nonconstructor"),
+                renamed("@typescript-eslint/no-duplicate-imports", "no-duplicate-imports"),
+            ]
+        );
+
+        let mut cli_rules = OxlintRules::parse(["import/imports-first=error"]).unwrap();
+        assert_eq!(cli_rules.rules[0].rule_name, "first");
+        cli_rules.extend(rules);
+        assert_eq!(cli_rules.renamed().len(), 4);
+    }
+
+    #[test]
+    fn test_renamed_rules_exist() {
+        for ((old_plugin, old_rule), (new_plugin, new_rule)) in RENAMED_RULES {
+            assert!(
+                !RULES.iter().any(|r| r.plugin_name() == *old_plugin && r.name() == *old_rule),
+                "{old_plugin}/{old_rule} is renamed, but still exists"
+            );
+            assert!(
+                RULES.iter().any(|r| r.plugin_name() == *new_plugin && r.name() == *new_rule),
+                "{old_plugin}/{old_rule} is renamed to {new_plugin}/{new_rule}, which doesn't exist"
+            );
+        }
+    }
+
     #[test]
     fn test_parse_rules_default() {
         let rules = OxlintRules::default();
@@ -388,6 +643,22 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_override_vitest_rules() {
+        let mut rules = RuleSet::default();
+        for rule_name in ["prefer-to-be", "prefer-spy-on", "valid-title", "no-focused-tests"] {
+            let config = json!({ format!("vitest/{rule_name}"): "error" });
+            rules.clear();
+            r#override(&mut rules, &config);
+
+            assert_eq!(rules.len(), 1, "{config:?}");
+            let (rule, severity) = rules.iter().next().unwrap();
+            assert_eq!(rule.name(), rule_name, "{config:?}");
+            assert_eq!(rule.plugin_name(), "jest", "{config:?}");
+            assert_eq!(severity, &AllowWarnDeny::Deny, "{config:?}");
+        }
+    }
+
     // FIXME
     #[test]
     #[should_panic(
@@ -456,4 +727,18 @@ mod test {
             assert_eq!(severity, &AllowWarnDeny::Deny, "{config:?}");
         }
     }
+
+    #[test]
+    fn test_is_builtin() {
+        let rules = OxlintRules::deserialize(&json!({
+            "eqeqeq": "error",
+            "@typescript-eslint/no-unused-vars": "error",
+            "vitest/no-disabled-tests": "error",
+            "no-restricted-syntax": "error",
+            "foo/bar": "error",
+        }))
+        .unwrap();
+        let builtin = rules.iter().map(super::ESLintRule::is_builtin).collect::<Vec<_>>();
+        assert_eq!(builtin, [true, true, true, false, false]);
+    }
 }
@@ -1,14 +1,15 @@
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
-mod react;
+pub mod react;
+mod react_hooks;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
     jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings,
+    react::ReactPluginSettings, react_hooks::ReactHooksPluginSettings,
 };
 
 /// # Oxlint Plugin Settings
@@ -24,6 +25,7 @@ use self::{
 ///       "rootDir": "apps/dashboard/"
 ///     },
 ///     "react": {
+///       "version": "18.2.0",
 ///       "linkComponents": [
 ///         { "name": "Link", "linkAttribute": "to" }
 ///       ]
@@ -50,6 +52,10 @@ pub struct OxlintSettings {
     #[serde(default)]
     pub react: ReactPluginSettings,
 
+    #[serde(default)]
+    #[serde(rename = "react-hooks")]
+    pub react_hooks: ReactHooksPluginSettings,
+
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
 }
@@ -62,7 +68,7 @@ mod test {
     use serde::Deserialize;
 
     use super::OxlintSettings;
-    use crate::config::settings::react::ComponentAttrs;
+    use crate::config::settings::react::{ComponentAttrs, JsxRuntime};
 
     fn as_attrs<S: Into<CompactStr>, I: IntoIterator<Item = S>>(
         attrs: I,
@@ -125,5 +131,51 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.react.version().is_none());
+        assert!(settings.react.version_at_least(99, 0, 0));
+        assert_eq!(settings.react.jsx_runtime(), JsxRuntime::Classic);
+    }
+
+    #[test]
+    fn test_parse_react_version() {
+        let parse = |version: &str| {
+            OxlintSettings::deserialize(&serde_json::json!({ "react": { "version": version } }))
+                .map(|settings| settings.react.version().map(|version| version.to_string()))
+                .map_err(|_| ())
+        };
+        assert_eq!(parse("18.2.0"), Ok(Some("18.2.0".to_string())));
+        assert_eq!(parse("17"), Ok(Some("17.0.0".to_string())));
+        assert_eq!(parse("16.9"), Ok(Some("16.9.0".to_string())));
+        assert_eq!(parse("^19.0.0-rc.1"), Ok(Some("19.0.0".to_string())));
+        assert_eq!(parse("detect"), Ok(None));
+        assert_eq!(parse("latest"), Err(()));
+        assert_eq!(parse("1.2.3.4"), Err(()));
+
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "react": { "version": "16.8.6", "jsxRuntime": "automatic" }
+        }))
+        .unwrap();
+        assert!(settings.react.version_at_least(16, 8, 0));
+        assert!(!settings.react.version_at_least(16, 9, 0));
+        assert_eq!(settings.react.jsx_runtime(), JsxRuntime::Automatic);
+    }
+
+    #[test]
+    fn test_parse_react_hooks() {
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "react-hooks": { "additionalEffectHooks": "^use(Special|Custom)Effect$" }
+        }))
+        .unwrap();
+        let regex = settings.react_hooks.additional_effect_hooks().unwrap();
+        assert!(regex.is_match("useSpecialEffect"));
+        assert!(!regex.is_match("useEffectEvent"));
+
+        assert!(OxlintSettings::default().react_hooks.additional_effect_hooks().is_none());
+        assert!(
+            OxlintSettings::deserialize(&serde_json::json!({
+                "react-hooks": { "additionalEffectHooks": "(" }
+            }))
+            .is_err()
+        );
     }
 }
@@ -1,8 +1,8 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
 use oxc_span::CompactStr;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 /// Configure React plugin rules.
 ///
@@ -56,6 +56,46 @@ pub struct ReactPluginSettings {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+
+    /// React version to use for version dependent rules, such as `react/no-deprecated`.
+    ///
+    /// Defaults to the latest version of React. `"detect"` is accepted for compatibility with
+    /// eslint-plugin-react, and also uses the latest version.
+    ///
+    /// Example:
+    ///
+    /// ```jsonc
+    /// {
+    ///   "settings": {
+    ///     "react": {
+    ///       "version": "17.0.2"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, deserialize_with = "deserialize_version")]
+    #[schemars(with = "Option<String>")]
+    version: Option<ReactVersion>,
+
+    /// The JSX runtime of the project.
+    ///
+    /// With the `"automatic"` runtime of React 17 and later, JSX does not need `React` to be in
+    /// scope, so `react/react-in-jsx-scope` reports nothing. Defaults to `"classic"`.
+    ///
+    /// Example:
+    ///
+    /// ```jsonc
+    /// {
+    ///   "settings": {
+    ///     "react": {
+    ///       "jsxRuntime": "automatic"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default)]
+    #[serde(rename = "jsxRuntime")]
+    jsx_runtime: JsxRuntime,
     // TODO: More properties should be added
 }
 
@@ -68,6 +108,80 @@ impl ReactPluginSettings {
     pub fn get_link_component_attrs(&self, name: &str) -> Option<ComponentAttrs<'_>> {
         get_component_attrs_by_name(&self.link_components, name)
     }
+
+    /// The configured React version, [`None`] for the latest version.
+    pub fn version(&self) -> Option<ReactVersion> {
+        self.version
+    }
+
+    /// Whether the configured React version is `major.minor.patch` or later.
+    /// Always `true` when no version is configured.
+    pub fn version_at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.version.is_none_or(|version| version >= ReactVersion { major, minor, patch })
+    }
+
+    pub fn jsx_runtime(&self) -> JsxRuntime {
+        self.jsx_runtime
+    }
+}
+
+/// A `major.minor.patch` version of React, see [`ReactPluginSettings::version`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReactVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ReactVersion {
+    /// Parse versions like `18`, `18.2` or `18.2.0`. Pre-release and build suffixes are ignored.
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches(['v', '^', '~', '=']);
+        let version = version.split(['-', '+']).next().unwrap_or_default();
+        let mut parts = version.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |part| part.parse().ok())?;
+        let patch = parts.next().map_or(Some(0), |part| part.parse().ok())?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { major, minor, patch })
+    }
+}
+
+impl fmt::Display for ReactVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+fn deserialize_version<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ReactVersion>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(version) if version == "detect" => Ok(None),
+        Some(version) => ReactVersion::parse(&version).map(Some).ok_or_else(|| {
+            de::Error::custom(format!("invalid React version `{version}`, expected e.g. `18.2.0`"))
+        }),
+    }
+}
+
+impl Serialize for ReactVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// See [`ReactPluginSettings::jsx_runtime`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JsxRuntime {
+    /// JSX is transformed to `React.createElement` calls.
+    #[default]
+    Classic,
+    /// JSX is transformed to calls of functions imported from `react/jsx-runtime`.
+    Automatic,
 }
 
 // Deserialize helper types
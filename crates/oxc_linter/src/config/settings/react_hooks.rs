@@ -0,0 +1,62 @@
+use lazy_regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// Configure the rules of `eslint-plugin-react-hooks`.
+///
+/// Derived from [eslint-plugin-react-hooks](https://github.com/facebook/react/tree/main/packages/eslint-plugin-react-hooks#advanced-configuration)
+#[derive(Debug, Clone, Deserialize, Default, Serialize, JsonSchema)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct ReactHooksPluginSettings {
+    /// Regex of custom hooks which take a callback and a dependency array like `useEffect`,
+    /// checked by `react/exhaustive-deps`.
+    ///
+    /// The `additionalHooks` option of `react/exhaustive-deps` takes precedence over this setting.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "react-hooks": {
+    ///       "additionalEffectHooks": "(useMyEffect|useCustomEffect)"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default)]
+    #[serde(rename = "additionalEffectHooks")]
+    #[schemars(with = "Option<String>")]
+    additional_effect_hooks: Option<HooksRegex>,
+}
+
+impl ReactHooksPluginSettings {
+    /// Regex of custom effect hooks, configured by `additionalEffectHooks`.
+    pub fn additional_effect_hooks(&self) -> Option<&Regex> {
+        self.additional_effect_hooks.as_ref().map(|regex| &regex.0)
+    }
+}
+
+/// A regex of hook names, compiled once when the settings are loaded.
+#[derive(Debug, Clone)]
+struct HooksRegex(Regex);
+
+#[cfg(test)]
+impl PartialEq for HooksRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Serialize for HooksRegex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for HooksRegex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern).map(Self).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,269 @@
+//! Values of expressions which are known without running the code, see
+//! [`LintContext::constant_value`].
+
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingPatternKind, Expression, IdentifierReference, MemberExpression, ObjectExpression,
+        ObjectPropertyKind, PropertyKind, TSEnumDeclaration, TemplateLiteral,
+        VariableDeclarationKind, VariableDeclarator,
+    },
+};
+use oxc_ecmascript::{
+    ToBoolean, ToJsString, ToNumber, constant_evaluation::ConstantValue,
+    is_global_reference::WithoutGlobalReferenceInformation,
+};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use super::LintContext;
+
+/// How many references to other constants are followed, e.g. for `const a = b, b = a;`.
+const MAX_DEPTH: u8 = 16;
+
+pub(super) fn constant_value<'a>(
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ConstantValue<'a>> {
+    evaluate(expr, ctx, MAX_DEPTH)
+}
+
+fn evaluate<'a>(
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => {
+            Some(ConstantValue::String(Cow::Borrowed(lit.value.as_str())))
+        }
+        Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+        Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+        Expression::NullLiteral(_) => Some(ConstantValue::Null),
+        Expression::TemplateLiteral(template) => template_value(template, ctx, depth),
+        Expression::Identifier(ident) => identifier_value(ident, ctx, depth),
+        Expression::UnaryExpression(unary) => {
+            if unary.operator == UnaryOperator::Void {
+                return Some(ConstantValue::Undefined);
+            }
+            let value = evaluate(&unary.argument, ctx, depth)?;
+            match unary.operator {
+                UnaryOperator::LogicalNot => Some(ConstantValue::Boolean(!to_boolean(&value))),
+                UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-to_number(&value)?)),
+                UnaryOperator::UnaryPlus => Some(ConstantValue::Number(to_number(&value)?)),
+                UnaryOperator::Typeof => {
+                    Some(ConstantValue::String(Cow::Borrowed(type_of(&value))))
+                }
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(binary) => {
+            let left = evaluate(&binary.left, ctx, depth)?;
+            let right = evaluate(&binary.right, ctx, depth)?;
+            match binary.operator {
+                BinaryOperator::Addition if left.is_string() || right.is_string() => {
+                    let left = to_string(&left)?;
+                    let right = to_string(&right)?;
+                    Some(ConstantValue::String(Cow::Owned(format!("{left}{right}"))))
+                }
+                BinaryOperator::Addition => {
+                    Some(ConstantValue::Number(to_number(&left)? + to_number(&right)?))
+                }
+                BinaryOperator::StrictEquality => Some(ConstantValue::Boolean(left == right)),
+                BinaryOperator::StrictInequality => Some(ConstantValue::Boolean(left != right)),
+                _ => None,
+            }
+        }
+        Expression::LogicalExpression(logical) => {
+            let left = evaluate(&logical.left, ctx, depth)?;
+            let short_circuits = match logical.operator {
+                LogicalOperator::And => !to_boolean(&left),
+                LogicalOperator::Or => to_boolean(&left),
+                LogicalOperator::Coalesce => {
+                    !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+                }
+            };
+            if short_circuits { Some(left) } else { evaluate(&logical.right, ctx, depth) }
+        }
+        Expression::ConditionalExpression(conditional) => {
+            let test = evaluate(&conditional.test, ctx, depth)?;
+            let branch =
+                if to_boolean(&test) { &conditional.consequent } else { &conditional.alternate };
+            evaluate(branch, ctx, depth)
+        }
+        expr => member_value(expr.as_member_expression()?, ctx, depth),
+    }
+}
+
+fn template_value<'a>(
+    template: &TemplateLiteral<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    if let Some(quasi) = template.single_quasi() {
+        return Some(ConstantValue::String(Cow::Borrowed(quasi.as_str())));
+    }
+    let mut value = String::new();
+    for (i, quasi) in template.quasis.iter().enumerate() {
+        value.push_str(quasi.value.cooked.as_ref()?);
+        if let Some(expr) = template.expressions.get(i) {
+            value.push_str(&to_string(&evaluate(expr, ctx, depth)?)?);
+        }
+    }
+    Some(ConstantValue::String(Cow::Owned(value)))
+}
+
+fn identifier_value<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    match ident.name.as_str() {
+        "undefined" if ctx.is_reference_to_global_variable(ident) => Some(ConstantValue::Undefined),
+        "NaN" if ctx.is_reference_to_global_variable(ident) => {
+            Some(ConstantValue::Number(f64::NAN))
+        }
+        "Infinity" if ctx.is_reference_to_global_variable(ident) => {
+            Some(ConstantValue::Number(f64::INFINITY))
+        }
+        _ => {
+            let declarator = const_declarator(ident, ctx)?;
+            evaluate(declarator.init.as_ref()?, ctx, depth.checked_sub(1)?)
+        }
+    }
+}
+
+/// The value of a property of a frozen object, an object `as const`, or a member of an enum, e.g.
+/// `E.A` for `const E = Object.freeze({ A: 'a' })`.
+fn member_value<'a>(
+    member: &MemberExpression<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    let name = member.static_property_name()?;
+    let Expression::Identifier(object) = member.object().get_inner_expression() else {
+        return None;
+    };
+    let depth = depth.checked_sub(1)?;
+
+    let symbol_id = ctx.scoping().get_reference(object.reference_id()).symbol_id()?;
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    if let AstKind::TSEnumDeclaration(declaration) = declaration.kind() {
+        // Members of merged enums may be declared elsewhere
+        if !ctx.scoping().symbol_redeclarations(symbol_id).is_empty() {
+            return None;
+        }
+        return enum_member_value(declaration, name, ctx, depth);
+    }
+
+    let init = const_declarator(object, ctx)?.init.as_ref()?;
+    let object = match init.without_parentheses() {
+        Expression::TSAsExpression(expr) if expr.type_annotation.is_const_type_reference() => {
+            let Expression::ObjectExpression(object) = expr.expression.without_parentheses() else {
+                return None;
+            };
+            object
+        }
+        Expression::CallExpression(call) => {
+            let callee = call.callee.get_inner_expression().as_member_expression()?;
+            let Expression::Identifier(callee_object) = callee.object() else { return None };
+            if callee.static_property_name() != Some("freeze")
+                || callee_object.name != "Object"
+                || !ctx.is_reference_to_global_variable(callee_object)
+            {
+                return None;
+            }
+            let Some(Expression::ObjectExpression(object)) =
+                call.arguments.first()?.as_expression().map(Expression::get_inner_expression)
+            else {
+                return None;
+            };
+            object
+        }
+        _ => return None,
+    };
+    object_property_value(object, name, ctx, depth)
+}
+
+fn object_property_value<'a>(
+    object: &ObjectExpression<'a>,
+    name: &str,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    // The last property with the name wins, unless a spread may override it
+    for property in object.properties.iter().rev() {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+        if property.key.static_name().is_none_or(|key| key != name) {
+            continue;
+        }
+        if property.kind != PropertyKind::Init || property.method {
+            return None;
+        }
+        return evaluate(&property.value, ctx, depth);
+    }
+    None
+}
+
+fn enum_member_value<'a>(
+    declaration: &TSEnumDeclaration<'a>,
+    name: &str,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Option<ConstantValue<'a>> {
+    // Members without an initializer are numbered after the previous member
+    let mut next_number = Some(0.0);
+    for member in &declaration.body.members {
+        let value = match &member.initializer {
+            Some(initializer) => evaluate(initializer, ctx, depth),
+            None => next_number.map(ConstantValue::Number),
+        };
+        if member.id.static_name().as_str() == name {
+            return value;
+        }
+        next_number = match value {
+            Some(ConstantValue::Number(number)) => Some(number + 1.0),
+            _ => None,
+        };
+    }
+    None
+}
+
+/// The declarator of `ident` if it is a `const` which is not destructured.
+fn const_declarator<'a, 'c>(
+    ident: &IdentifierReference<'a>,
+    ctx: &'c LintContext<'a>,
+) -> Option<&'c VariableDeclarator<'a>> {
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+        return None;
+    };
+    (declarator.kind == VariableDeclarationKind::Const
+        && matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)))
+    .then_some(declarator)
+}
+
+fn to_boolean(value: &ConstantValue) -> bool {
+    value.to_boolean(&WithoutGlobalReferenceInformation).unwrap_or(true)
+}
+
+fn to_number(value: &ConstantValue) -> Option<f64> {
+    value.to_number(&WithoutGlobalReferenceInformation)
+}
+
+fn to_string<'a>(value: &ConstantValue<'a>) -> Option<Cow<'a, str>> {
+    value.to_js_string(&WithoutGlobalReferenceInformation)
+}
+
+fn type_of(value: &ConstantValue) -> &'static str {
+    match value {
+        ConstantValue::Number(_) => "number",
+        ConstantValue::BigInt(_) => "bigint",
+        ConstantValue::String(_) => "string",
+        ConstantValue::Boolean(_) => "boolean",
+        ConstantValue::Undefined => "undefined",
+        ConstantValue::Null => "object",
+    }
+}
@@ -2,17 +2,21 @@ use std::{borrow::Cow, cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
-use oxc_span::{SourceType, Span};
+use oxc_span::SourceType;
 
 use crate::{
     AllowWarnDeny, FrameworkFlags, LintPlugins,
-    config::LintConfig,
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
-    fixer::{Fix, FixKind, Message, PossibleFixes},
+    config::{GlobalValue, LintConfig},
+    disable_directives::{
+        DisableDirectives, DisableDirectivesBuilder, RuleCommentType, create_delete_comment_fix,
+    },
+    env_directives::{parse_env_directives, parse_global_directives},
+    fixer::{FixKind, Message, PossibleFixes, SuggestionChoices},
     frameworks,
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    type_checker::TypeChecker,
 };
 
 use super::{LintContext, plugin_name_to_prefix};
@@ -63,6 +67,14 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// Environments enabled by `eslint-env` and triple-slash reference directives in the file.
+    pub(super) file_envs: Vec<&'a str>,
+    /// Global variables declared by `/* global */` directives in the file.
+    pub(super) file_globals: Vec<(&'a str, GlobalValue)>,
+    /// Provides the types of expressions in type-aware mode.
+    pub(super) type_checker: Option<Arc<dyn TypeChecker>>,
+    /// Suggestions chosen with `--apply-suggestions`.
+    pub(super) suggestion_choices: Option<Arc<SuggestionChoices>>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -87,6 +99,9 @@ impl<'a> ContextHost<'a> {
         let disable_directives =
             DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
 
+        let file_envs = parse_env_directives(semantic.source_text(), semantic.comments());
+        let file_globals = parse_global_directives(semantic.source_text(), semantic.comments());
+
         let file_path = file_path.as_ref().to_path_buf().into_boxed_path();
 
         Self {
@@ -94,14 +109,44 @@ impl<'a> ContextHost<'a> {
             module_record,
             disable_directives,
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
-            fix: options.fix,
+            // Without fixes applied, collect the fixes `--fix` would apply to preview them.
+            fix: if options.fix_preview && options.fix.is_none() {
+                FixKind::SafeFix
+            } else {
+                options.fix
+            },
             file_path,
             config,
             frameworks: options.framework_hints,
+            file_envs,
+            file_globals,
+            type_checker: None,
+            suggestion_choices: None,
         }
         .sniff_for_frameworks()
     }
 
+    /// Query the types of expressions from `type_checker`, see [`LintContext::type_of`].
+    pub fn with_type_checker(mut self, type_checker: Option<Arc<dyn TypeChecker>>) -> Self {
+        self.type_checker = type_checker;
+        self
+    }
+
+    /// Apply the suggestions chosen in `suggestion_choices`, see [`LintContext::diagnostic_with_suggestions`].
+    pub fn with_suggestion_choices(
+        mut self,
+        suggestion_choices: Option<Arc<SuggestionChoices>>,
+    ) -> Self {
+        self.suggestion_choices = suggestion_choices;
+        self
+    }
+
+    /// Whether types can be queried, i.e. the linter runs in type-aware mode.
+    #[inline]
+    pub fn has_type_checker(&self) -> bool {
+        self.type_checker.is_some()
+    }
+
     /// Shared reference to the [`Semantic`] analysis of the file.
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -135,6 +180,18 @@ impl<'a> ContextHost<'a> {
         &self.config.plugins
     }
 
+    /// Whether fixes of `kind` are applied, so should be reported with diagnostics.
+    #[cfg_attr(
+        not(any(
+            all(feature = "oxlint2", not(feature = "disable_oxlint2")),
+            feature = "wasm_plugins"
+        )),
+        expect(dead_code)
+    )]
+    pub(crate) fn can_apply_fix(&self, kind: FixKind) -> bool {
+        self.fix.can_apply(kind)
+    }
+
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
@@ -142,11 +199,6 @@ impl<'a> ContextHost<'a> {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
-    // Append a list of diagnostics. Only used in report_unused_directives.
-    fn append_diagnostics(&self, diagnostics: Vec<Message<'a>>) {
-        self.diagnostics.borrow_mut().extend(diagnostics);
-    }
-
     /// report unused enable/disable directives, add these as Messages to diagnostics
     pub fn report_unused_directives(&self, rule_severity: Severity) {
         // report unused disable
@@ -161,11 +213,13 @@ impl<'a> ContextHost<'a> {
             match &unused_disable_comment.r#type {
                 RuleCommentType::All => {
                     // eslint-disable
+                    let fix =
+                        create_delete_comment_fix(source_text, span).with_message(fix_message);
                     self.push_diagnostic(Message::new(
                         OxcDiagnostic::error(message_for_disable)
                             .with_label(span)
                             .with_severity(rule_severity),
-                        PossibleFixes::Single(Fix::delete(span).with_message(fix_message)),
+                        PossibleFixes::Single(fix),
                     ));
                 }
                 RuleCommentType::Single(rules_vec) => {
@@ -188,42 +242,54 @@ impl<'a> ContextHost<'a> {
             }
         }
 
-        let unused_enable_comments = self.disable_directives.unused_enable_comments();
-        let mut unused_directive_diagnostics: Vec<(Cow<str>, Span)> =
-            Vec::with_capacity(unused_enable_comments.len());
         // report unused enable
         // not relate to lint result, check during comment directives' construction
         let message_for_enable =
             "Unused eslint-enable directive (no matching eslint-disable directives were found).";
-        for (rule_name, enable_comment_span) in self.disable_directives.unused_enable_comments() {
-            unused_directive_diagnostics.push((
-                rule_name.map_or(Cow::Borrowed(message_for_enable), |name| {
-                    Cow::Owned(format!(
-                        "Unused eslint-enable directive (no matching eslint-disable directives were found for {name})."
-                    ))
-                }),
-                *enable_comment_span,
-            ));
-        }
+        let fix_message = "remove unused enable directive";
+        for unused_enable_comment in self.disable_directives.unused_enable_comments() {
+            let span = unused_enable_comment.span;
+            match &unused_enable_comment.r#type {
+                RuleCommentType::All => {
+                    // eslint-enable
+                    let fix =
+                        create_delete_comment_fix(source_text, span).with_message(fix_message);
+                    self.push_diagnostic(Message::new(
+                        OxcDiagnostic::error(message_for_enable)
+                            .with_label(span)
+                            .with_severity(rule_severity),
+                        PossibleFixes::Single(fix),
+                    ));
+                }
+                RuleCommentType::Single(rules_vec) => {
+                    for rule in rules_vec {
+                        let rule_message = Cow::<str>::Owned(format!(
+                            "Unused eslint-enable directive (no matching eslint-disable directives were found for {}).",
+                            rule.rule_name
+                        ));
 
-        self.append_diagnostics(
-            unused_directive_diagnostics
-                .into_iter()
-                .map(|(message, span)| {
-                    Message::new(
-                        OxcDiagnostic::error(message).with_label(span).with_severity(rule_severity),
-                        // TODO: fixer
-                        // copy the structure of disable directives
-                        PossibleFixes::None,
-                    )
-                })
-                .collect(),
-        );
+                        // remove the whole comment if none of its rules were disabled
+                        let fix = if unused_enable_comment.is_entire_comment {
+                            create_delete_comment_fix(source_text, span)
+                        } else {
+                            rule.create_fix(source_text, span)
+                        };
+
+                        self.push_diagnostic(Message::new(
+                            OxcDiagnostic::error(rule_message)
+                                .with_label(rule.name_span)
+                                .with_severity(rule_severity),
+                            PossibleFixes::Single(fix.with_message(fix_message)),
+                        ));
+                    }
+                }
+            }
+        }
     }
 
     /// Take ownership of all diagnostics collected during linting.
     pub fn take_diagnostics(&self) -> Vec<Message<'a>> {
-        // NOTE: diagnostics are only ever borrowed here and in push_diagnostic, append_diagnostics.
+        // NOTE: diagnostics are only ever borrowed here and in push_diagnostic.
         // The latter drops the reference as soon as the function returns, so
         // this should never panic.
         let mut messages = self.diagnostics.borrow_mut();
@@ -243,6 +309,7 @@ impl<'a> ContextHost<'a> {
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: rule.fix(),
             severity: severity.into(),
+            severity_ranges: None,
         }
     }
 
@@ -257,6 +324,7 @@ impl<'a> ContextHost<'a> {
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: crate::rule::RuleFixMeta::None,
             severity: oxc_diagnostics::Severity::Warning,
+            severity_ranges: None,
         }
     }
 
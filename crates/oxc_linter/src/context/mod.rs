@@ -4,9 +4,10 @@ use std::{ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_ecmascript::constant_evaluation::ConstantValue;
 use oxc_semantic::Semantic;
 use oxc_span::{GetSpan, Span};
 
@@ -17,8 +18,11 @@ use crate::{
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
+    rule_directives::SeverityRanges,
+    type_checker::{TypeInfo, TypeQuery},
 };
 
+mod constant_value;
 mod host;
 pub use host::ContextHost;
 
@@ -57,6 +61,9 @@ pub struct LintContext<'a> {
     /// }
     /// ```
     severity: Severity,
+    /// Severity of the rule in each part of the file, when it is configured by inline
+    /// configuration comments. Overrides `severity`.
+    severity_ranges: Option<Rc<SeverityRanges>>,
 }
 
 impl<'a> Deref for LintContext<'a> {
@@ -100,6 +107,14 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    /// Set the severity of diagnostics by their position in the file, see
+    /// [`parse_rule_directives`](crate::rule_directives::parse_rule_directives). Diagnostics in
+    /// parts of the file where the rule is off are dropped.
+    pub(crate) fn with_severity_ranges(mut self, ranges: Rc<SeverityRanges>) -> Self {
+        self.severity_ranges = Some(ranges);
+        self
+    }
+
     /// Get information such as the control flow graph, bound symbols, AST, etc.
     /// for the file being linted.
     ///
@@ -140,6 +155,24 @@ impl<'a> LintContext<'a> {
         &self.parent.file_path
     }
 
+    /// Type of the expression at `span`.
+    ///
+    /// Types are only known in type-aware mode, and are computed when they are queried, so rules
+    /// should only ask for the types of the expressions they would report.
+    pub fn type_of(&self, span: Span) -> Option<TypeInfo> {
+        self.query_type(span, TypeQuery::TypeOf)
+    }
+
+    /// Type the expression at `span` is expected to have from its position, e.g. the type of the
+    /// parameter an argument is passed to. See [`LintContext::type_of`].
+    pub fn contextual_type_of(&self, span: Span) -> Option<TypeInfo> {
+        self.query_type(span, TypeQuery::ContextualTypeOf)
+    }
+
+    fn query_type(&self, span: Span, query: TypeQuery) -> Option<TypeInfo> {
+        self.parent.type_checker.as_ref()?.query(&self.parent.file_path, span, query)
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -147,16 +180,46 @@ impl<'a> LintContext<'a> {
     }
 
     /// Sets of global variables that have been enabled or disabled.
+    ///
+    /// Does not include global variables declared by directives in the file, see
+    /// [`LintContext::global_value`].
     #[inline]
     pub fn globals(&self) -> &OxlintGlobals {
         &self.parent.config.globals
     }
 
+    /// Value of the global variable `name` declared by a `/* global */` directive in the file, or
+    /// else by the `globals` of the configuration.
+    pub fn global_value(&self, name: &str) -> Option<GlobalValue> {
+        self.parent
+            .file_globals
+            .iter()
+            .rev()
+            .find(|(global, _)| *global == name)
+            .map(|(_, value)| *value)
+            .or_else(|| self.globals().get(name).copied())
+    }
+
+    /// Whether the global variable `name` is declared and not turned off, by a `/* global */`
+    /// directive in the file or by the `globals` of the configuration.
+    pub fn is_global_enabled(&self, name: &str) -> bool {
+        self.global_value(name).is_some_and(|value| value != GlobalValue::Off)
+    }
+
     /// Checks if the provided identifier is a reference to a global variable.
     pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
         let name = ident.name.as_str();
         self.scoping().root_unresolved_references().contains_key(name)
-            && !self.globals().get(name).is_some_and(|value| *value == GlobalValue::Off)
+            && self.global_value(name) != Some(GlobalValue::Off)
+    }
+
+    /// The value of `expr` if it is known without running the code.
+    ///
+    /// Beyond literals, this follows references to `const` variables and reads properties of
+    /// frozen objects (`Object.freeze({ ... })`), objects `as const` and enum members, e.g.
+    /// `` `${PREFIX}-${Kind.A}` `` is a known string with `const PREFIX = 'x'` and `enum Kind { A }`.
+    pub fn constant_value(&self, expr: &Expression<'a>) -> Option<ConstantValue<'a>> {
+        constant_value::constant_value(expr, self)
     }
 
     /// Checks if the provided identifier is a reference to a global variable.
@@ -165,8 +228,8 @@ impl<'a> LintContext<'a> {
             return None;
         }
 
-        if let Some(value) = self.globals().get(name) {
-            return Some(*value);
+        if let Some(value) = self.global_value(name) {
+            return Some(value);
         }
 
         self.get_env_global_entry(name)
@@ -175,18 +238,26 @@ impl<'a> LintContext<'a> {
     /// Runtime environments turned on/off by the user.
     ///
     /// Examples of environments are `builtin`, `browser`, `node`, etc.
+    ///
+    /// Does not include environments enabled by directives in the file, see [`LintContext::envs`].
     #[inline]
     pub fn env(&self) -> &OxlintEnv {
         &self.parent.config.env
     }
 
+    /// Names of all enabled environments: those turned on by the user, and those enabled by
+    /// `/* eslint-env */` and `/// <reference lib="..." />` directives in the file.
+    pub fn envs(&self) -> impl Iterator<Item = &str> + '_ {
+        self.env().iter().chain(self.parent.file_envs.iter().copied())
+    }
+
     fn get_env_global_entry(&self, var: &str) -> Option<GlobalValue> {
         // builtin is always readonly
         if GLOBALS["builtin"].contains_key(var) {
             return Some(GlobalValue::Readonly);
         }
 
-        for env in self.env().iter() {
+        for env in self.envs() {
             if let Some(env) = GLOBALS.get(env) {
                 if let Some(value) = env.get(var) {
                     return Some(GlobalValue::from(*value));
@@ -207,7 +278,7 @@ impl<'a> LintContext<'a> {
         if GLOBALS["builtin"].contains_key(var) {
             return true;
         }
-        for env in self.env().iter() {
+        for env in self.envs() {
             if let Some(env) = GLOBALS.get(env) {
                 if env.contains_key(var) {
                     return true;
@@ -225,6 +296,13 @@ impl<'a> LintContext<'a> {
         if self.parent.disable_directives.contains(self.current_rule_name, message.span()) {
             return;
         }
+        let severity = match &self.severity_ranges {
+            Some(ranges) => match ranges.at(message.span().start) {
+                AllowWarnDeny::Allow => return,
+                severity => Severity::from(severity),
+            },
+            None => self.severity,
+        };
         message.error = message
             .error
             .with_error_code(self.current_plugin_prefix, self.current_rule_name)
@@ -234,8 +312,8 @@ impl<'a> LintContext<'a> {
                 self.current_plugin_name,
                 self.current_rule_name
             ));
-        if message.error.severity != self.severity {
-            message.error = message.error.with_severity(self.severity);
+        if message.error.severity != severity {
+            message.error = message.error.with_severity(severity);
         }
 
         self.parent.push_diagnostic(message);
@@ -363,31 +441,52 @@ impl<'a> LintContext<'a> {
         }
     }
 
-    /// Report a lint rule violation and provide an automatic fix of a specific kind.
-    /// This method is used when the rule can provide multiple fixes for the same diagnostic.
-    pub fn diagnostics_with_multiple_fixes<C, F1, F2>(
-        &self,
-        diagnostic: OxcDiagnostic,
-        fix_one: (FixKind, F1),
-        fix_two: (FixKind, F2),
-    ) where
-        C: Into<RuleFix<'a>>,
-        F1: FnOnce(RuleFixer<'_, 'a>) -> C,
-        F2: FnOnce(RuleFixer<'_, 'a>) -> C,
+    /// Report a lint rule violation and provide several alternative suggestions for fixing it,
+    /// like the `suggest` property of ESLint reports.
+    ///
+    /// The second argument is a [closure] that takes a [`RuleFixer`] and
+    /// returns the suggestions. Describe each suggestion with [`RuleFix::with_message`],
+    /// which is shown in editors via code actions, and name it with [`RuleFix::with_name`],
+    /// which selects it with `--apply-suggestions <rule:name>`.
+    ///
+    /// When a suggestion of the rule is chosen with `--apply-suggestions`, by its name or
+    /// its index, only that suggestion is provided, whatever kinds of fixes are enabled.
+    /// Otherwise, the first suggestion is applied with `--fix-suggestions`.
+    ///
+    /// [closure]: <https://doc.rust-lang.org/book/ch13-01-closures.html>
+    pub fn diagnostic_with_suggestions<F>(&self, diagnostic: OxcDiagnostic, suggestions: F)
+    where
+        F: FnOnce(RuleFixer<'_, 'a>) -> Vec<RuleFix<'a>>,
     {
-        let fixes_result: Vec<Fix<'a>> = vec![
-            self.create_fix(fix_one.0, fix_one.1, diagnostic.clone()).1,
-            self.create_fix(fix_two.0, fix_two.1, diagnostic.clone()).1,
-        ]
-        .into_iter()
-        .flatten()
-        .collect();
-
-        if fixes_result.is_empty() {
-            self.diagnostic(diagnostic);
+        let rule_fixes = suggestions(RuleFixer::new(FixKind::Suggestion, self));
+
+        let chosen = self
+            .parent
+            .suggestion_choices
+            .as_ref()
+            .and_then(|choices| choices.get(self.current_plugin_name, self.current_rule_name))
+            .and_then(|choice| {
+                rule_fixes.iter().position(|rule_fix| rule_fix.name() == Some(choice)).or_else(
+                    || choice.parse::<usize>().ok().filter(|&index| index < rule_fixes.len()),
+                )
+            });
+
+        let mut fixes = rule_fixes
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| chosen.is_none_or(|chosen| chosen == *index))
+            .filter_map(|(_, rule_fix)| {
+                let can_apply = chosen.is_some() || self.parent.fix.can_apply(rule_fix.kind());
+                self.rule_fix_to_fix(FixKind::Suggestion, rule_fix, can_apply)
+            })
+            .collect::<Vec<_>>();
+
+        let fixes = if fixes.len() > 1 {
+            PossibleFixes::Multiple(fixes)
         } else {
-            self.add_diagnostic(Message::new(diagnostic, PossibleFixes::Multiple(fixes_result)));
-        }
+            fixes.pop().map_or(PossibleFixes::None, PossibleFixes::Single)
+        };
+        self.add_diagnostic(Message::new(diagnostic, fixes));
     }
 
     fn create_fix<C, F>(
@@ -402,6 +501,25 @@ impl<'a> LintContext<'a> {
     {
         let fixer = RuleFixer::new(fix_kind, self);
         let rule_fix: RuleFix<'a> = fix(fixer).into();
+
+        let diagnostic = match (rule_fix.message(), &diagnostic.help) {
+            (Some(message), None) => diagnostic.with_help(message.to_owned()),
+            _ => diagnostic,
+        };
+
+        let can_apply = self.parent.fix.can_apply(rule_fix.kind());
+        let fix = self.rule_fix_to_fix(fix_kind, rule_fix, can_apply);
+        (diagnostic, fix)
+    }
+
+    /// Normalize `rule_fix` into a [`Fix`], if it can be applied and is not empty.
+    #[cfg_attr(not(debug_assertions), expect(unused_variables))]
+    fn rule_fix_to_fix(
+        &self,
+        fix_kind: FixKind,
+        rule_fix: RuleFix<'a>,
+        can_apply: bool,
+    ) -> Option<Fix<'a>> {
         #[cfg(debug_assertions)]
         debug_assert!(
             self.current_rule_fix_capabilities.supports_fix(fix_kind),
@@ -411,31 +529,24 @@ impl<'a> LintContext<'a> {
             rule_fix.kind()
         );
 
-        let diagnostic = match (rule_fix.message(), &diagnostic.help) {
-            (Some(message), None) => diagnostic.with_help(message.to_owned()),
-            _ => diagnostic,
-        };
-
-        if self.parent.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
-            let fix = rule_fix.into_fix(self.source_text());
-            #[cfg(debug_assertions)]
-            {
-                if fix.span.size() > 1 {
-                    debug_assert!(
-                        fix.message.as_ref().is_some_and(|msg| !msg.is_empty()),
-                        "Rule `{}/{}` fix should have a message for a complex fix. Did you forget to add a message?\n   Source text: {:?}\n    Fixed text: {:?}\nhelp: You can add a message to a fix with `RuleFix.with_message()`",
-                        self.current_plugin_name,
-                        self.current_rule_name,
-                        self.source_range(fix.span),
-                        fix.content
-                    );
-                }
+        if !can_apply || rule_fix.is_empty() {
+            return None;
+        }
+        let fix = rule_fix.into_fix(self.source_text());
+        #[cfg(debug_assertions)]
+        {
+            if fix.span.size() > 1 {
+                debug_assert!(
+                    fix.message.as_ref().is_some_and(|msg| !msg.is_empty()),
+                    "Rule `{}/{}` fix should have a message for a complex fix. Did you forget to add a message?\n   Source text: {:?}\n    Fixed text: {:?}\nhelp: You can add a message to a fix with `RuleFix.with_message()`",
+                    self.current_plugin_name,
+                    self.current_rule_name,
+                    self.source_range(fix.span),
+                    fix.content
+                );
             }
-
-            (diagnostic, Some(fix))
-        } else {
-            (diagnostic, None)
         }
+        Some(fix)
     }
 
     /// Framework flags, indicating front-end frameworks that might be in use.
@@ -470,4 +581,5 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
+    "security" => "eslint-plugin-security",
 };
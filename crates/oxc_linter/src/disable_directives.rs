@@ -84,6 +84,32 @@ impl RuleCommentRule<'_> {
     }
 }
 
+/// Create a fix which removes the comment with content `comment_span`, including its `//` or
+/// `/* */` delimiters.
+///
+/// If the comment is the only thing on its line, the whole line is removed. If it follows code on
+/// the same line, the whitespace before it is removed as well.
+#[expect(clippy::cast_possible_truncation)] // for `as u32`
+pub fn create_delete_comment_fix<'a>(source_text: &str, comment_span: Span) -> Fix<'a> {
+    let start = comment_span.start as usize - 2;
+    let end = if source_text[start..].starts_with("/*") {
+        comment_span.end as usize + 2
+    } else {
+        comment_span.end as usize
+    };
+
+    let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i + 1);
+    let before = &source_text[line_start..start];
+
+    if before.trim().is_empty() && source_text[end..line_end].trim().is_empty() {
+        return Fix::delete(Span::new(line_start as u32, line_end as u32));
+    }
+
+    let start = line_start + before.trim_end().len();
+    Fix::delete(Span::new(start as u32, end as u32))
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum RuleCommentType<'a> {
     // disable/enable all the rules
@@ -101,13 +127,24 @@ pub struct DisableRuleComment<'a> {
     pub r#type: RuleCommentType<'a>,
 }
 
+/// An enable comment which enables one or more rules that were not disabled
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UnusedEnableComment<'a> {
+    /// Span of the comment
+    pub span: Span,
+    /// Unused rules enabled by the comment
+    pub r#type: RuleCommentType<'a>,
+    /// Whether none of the rules enabled by the comment were disabled before
+    pub is_entire_comment: bool,
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Box<[DisableRuleComment<'a>]>,
-    /// Spans of unused enable directives
-    unused_enable_comments: Box<[(Option<&'a str>, Span)]>,
+    /// Unused enable directives
+    unused_enable_comments: Box<[UnusedEnableComment<'a>]>,
     /// Spans of used enable directives, to filter out unused
     used_disable_comments: RefCell<Vec<DisabledRule<'a>>>,
 }
@@ -144,7 +181,7 @@ impl<'a> DisableDirectives<'a> {
         &self.disable_rule_comments
     }
 
-    pub fn unused_enable_comments(&self) -> &[(Option<&'a str>, Span)] {
+    pub fn unused_enable_comments(&self) -> &[UnusedEnableComment<'a>] {
         &self.unused_enable_comments
     }
 
@@ -212,8 +249,8 @@ pub struct DisableDirectivesBuilder<'a> {
     disable_start_map: FxHashMap<&'a str, (u32, Span, Span)>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
-    /// Spans of unused enable directives
-    unused_enable_comments: Vec<(Option<&'a str>, Span)>,
+    /// Unused enable directives
+    unused_enable_comments: Vec<UnusedEnableComment<'a>>,
 }
 
 impl<'a> DisableDirectivesBuilder<'a> {
@@ -255,7 +292,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
         // https://github.com/eslint/eslint/blob/f67d5e875324a9d899598b11807a9c7624021432/lib/linter/apply-disable-directives.js#L308
 
         // enable directive keep the same logic for checking unused
-        let mut unused_enable_directives: Vec<(Option<&str>, Span)> = vec![];
+        let mut unused_enable_directives: Vec<UnusedEnableComment<'a>> = vec![];
 
         for comment in comments {
             let comment_span = comment.content_span();
@@ -394,11 +431,18 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         );
                     } else {
                         // collect as unused enable (see more at note comments in beginning of this method)
-                        unused_enable_directives.push((None, comment_span));
+                        unused_enable_directives.push(UnusedEnableComment {
+                            span: comment_span,
+                            r#type: RuleCommentType::All,
+                            is_entire_comment: true,
+                        });
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
+                    let mut rules_count = 0;
+                    let mut unused_rules = vec![];
                     Self::get_rule_names(text, rule_name_start, |rule_name, name_span| {
+                        rules_count += 1;
                         if let Some((start, _, _)) = self.disable_start_map.remove(rule_name) {
                             self.add_interval(
                                 start,
@@ -407,9 +451,16 @@ impl<'a> DisableDirectivesBuilder<'a> {
                             );
                         } else {
                             // collect as unused enable (see more at note comments in beginning of this method)
-                            unused_enable_directives.push((Some(rule_name), name_span));
+                            unused_rules.push(RuleCommentRule { rule_name, name_span });
                         }
                     });
+                    if !unused_rules.is_empty() {
+                        unused_enable_directives.push(UnusedEnableComment {
+                            span: comment_span,
+                            is_entire_comment: unused_rules.len() == rules_count,
+                            r#type: RuleCommentType::Single(unused_rules),
+                        });
+                    }
                 }
             }
         }
@@ -863,7 +914,9 @@ mod tests {
     use oxc_semantic::{Semantic, SemanticBuilder};
     use oxc_span::{SourceType, Span};
 
-    use crate::disable_directives::{DisabledRule, RuleCommentRule, RuleCommentType};
+    use crate::disable_directives::{
+        DisabledRule, RuleCommentRule, RuleCommentType, create_delete_comment_fix,
+    };
 
     use super::{DisableDirectives, DisableDirectivesBuilder};
 
@@ -917,10 +970,11 @@ mod tests {
 
                 assert_eq!(unused.len(), 1);
 
-                let (unused_rule_name, unused_span) = unused.first().unwrap();
+                let unused = unused.first().unwrap();
                 let comment_span = comments.first().unwrap().content_span();
-                assert_eq!(*unused_rule_name, None);
-                assert_eq!(*unused_span, comment_span);
+                assert_eq!(unused.r#type, RuleCommentType::All);
+                assert_eq!(unused.span, comment_span);
+                assert!(unused.is_entire_comment);
             },
         );
     }
@@ -940,21 +994,23 @@ mod tests {
             |comments, directives| {
                 let unused = directives.unused_enable_comments();
 
-                assert_eq!(unused.len(), 2);
-
-                let (unused_rule_name_no_debugger, unused_span_no_debugger) =
-                    unused.first().unwrap();
-                assert_eq!(*unused_rule_name_no_debugger, Some("no-debugger"));
-                assert_eq!(
-                    *unused_span_no_debugger,
-                    Span::sized(comments[0].content_span().start + 15, 11)
-                );
+                assert_eq!(unused.len(), 1);
 
-                let (unused_rule_name_no_console, unused_span_no_console) = unused.last().unwrap();
-                assert_eq!(*unused_rule_name_no_console, Some("no-console"));
+                let unused = unused.first().unwrap();
+                assert_eq!(unused.span, comments[0].content_span());
+                assert!(unused.is_entire_comment);
                 assert_eq!(
-                    *unused_span_no_console,
-                    Span::sized(comments[0].content_span().start + 28, 10)
+                    unused.r#type,
+                    RuleCommentType::Single(vec![
+                        RuleCommentRule {
+                            rule_name: "no-debugger",
+                            name_span: Span::sized(comments[0].content_span().start + 15, 11),
+                        },
+                        RuleCommentRule {
+                            rule_name: "no-console",
+                            name_span: Span::sized(comments[0].content_span().start + 28, 10),
+                        },
+                    ])
                 );
             },
         );
@@ -1118,4 +1174,26 @@ mod tests {
         RuleCommentRule { rule_name: "max-params", name_span: Span::sized(28, 10) }
             .create_fix(source_text, comment_span);
     }
+
+    #[test]
+    fn test_create_delete_comment_fix() {
+        let delete = |source_text: &str| {
+            let allocator = Allocator::default();
+            let semantic = process_source(&allocator, source_text);
+            let comment_span = semantic.comments()[0].content_span();
+            let fix = create_delete_comment_fix(source_text, comment_span);
+            format!(
+                "{}{}",
+                &source_text[..fix.span.start as usize],
+                &source_text[fix.span.end as usize..]
+            )
+        };
+
+        assert_eq!(delete("a();\n// eslint-disable\nb();\n"), "a();\nb();\n");
+        assert_eq!(delete("a();\n  /* eslint-enable */  \r\nb();"), "a();\nb();");
+        assert_eq!(delete("a(); // eslint-disable-line\nb();"), "a();\nb();");
+        assert_eq!(delete("a(); /* eslint-disable-line */ b();"), "a(); b();");
+        assert_eq!(delete("/* eslint-enable no-console */ a();"), " a();");
+        assert_eq!(delete("// eslint-disable"), "");
+    }
 }
@@ -0,0 +1,208 @@
+use javascript_globals::GLOBALS;
+
+use oxc_ast::Comment;
+
+use crate::config::GlobalValue;
+
+/// Collect environments enabled by directives in comments of a file:
+///
+/// - ESLint's `/* eslint-env node, browser */`
+/// - TypeScript's `/// <reference lib="dom" />` and `/// <reference types="node" />`
+///
+/// Environments enabled by directives are added to the environments of the configuration,
+/// so globals they define are available in the file.
+pub fn parse_env_directives<'a>(source_text: &'a str, comments: &[Comment]) -> Vec<&'a str> {
+    let mut envs = vec![];
+    for comment in comments {
+        let text = comment.content_span().source_text(source_text);
+        if comment.is_block() {
+            if let Some(names) = text.trim_start().strip_prefix("eslint-env") {
+                if names.starts_with(char::is_whitespace) {
+                    envs.extend(
+                        names
+                            .split(|c: char| c == ',' || c.is_whitespace())
+                            .filter(|name| !name.is_empty()),
+                    );
+                }
+            }
+        } else if let Some(reference) = text
+            .strip_prefix('/')
+            .map(str::trim_start)
+            .and_then(|text| text.strip_prefix("<reference"))
+        {
+            if let Some(lib) = attribute(reference, "lib") {
+                envs.extend(lib_env(lib));
+            } else if let Some(types) = attribute(reference, "types") {
+                envs.extend(types_env(types));
+            }
+        }
+    }
+    envs
+}
+
+/// Collect global variables declared by `/* global */` and `/* globals */` directives in comments
+/// of a file, e.g. `/* global foo, bar: writable */`, in the order they are declared.
+///
+/// As in ESLint, a variable without a value is read-only, `true` means writable and `false` means
+/// read-only. Variables with an invalid value are ignored.
+pub fn parse_global_directives<'a>(
+    source_text: &'a str,
+    comments: &[Comment],
+) -> Vec<(&'a str, GlobalValue)> {
+    let mut globals = vec![];
+    for comment in comments.iter().filter(|comment| comment.is_block()) {
+        let text = comment.content_span().source_text(source_text).trim_start();
+        let Some(names) = text.strip_prefix("globals").or_else(|| text.strip_prefix("global"))
+        else {
+            continue;
+        };
+        if !names.is_empty() && !names.starts_with(char::is_whitespace) {
+            continue;
+        }
+        // Strip the description, e.g. `/* global foo -- defined by the bundler */`
+        let names = names.split(" --").next().unwrap_or_default();
+        for segment in names.split(',') {
+            let mut tokens = segment.split_whitespace().peekable();
+            while let Some(token) = tokens.next() {
+                // Spaces are allowed around the colon, e.g. `foo : readonly`
+                let (name, value) = match token.split_once(':') {
+                    Some((name, "")) => (name, tokens.next()),
+                    Some((name, value)) => (name, Some(value)),
+                    None if tokens.peek().is_some_and(|next| next.starts_with(':')) => {
+                        let value = &tokens.next().unwrap_or_default()[1..];
+                        (token, if value.is_empty() { tokens.next() } else { Some(value) })
+                    }
+                    None => (token, None),
+                };
+                let value = match value {
+                    None | Some("false") => Some(GlobalValue::Readonly),
+                    Some("true") => Some(GlobalValue::Writeable),
+                    Some(value) => GlobalValue::try_from(value).ok(),
+                };
+                if let Some(value) = value.filter(|_| !name.is_empty()) {
+                    globals.push((name, value));
+                }
+            }
+        }
+    }
+    globals
+}
+
+/// Value of the attribute `name` in `<reference name="value" />`. The attributes are read as
+/// `name="value"` pairs, so `name` only matches a whole attribute name.
+fn attribute<'a>(reference: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = reference;
+    loop {
+        rest = rest.trim_start();
+        let name_end = rest.find(|c: char| c == '=' || c == '/' || c.is_whitespace())?;
+        let attribute_name = &rest[..name_end];
+        let value = rest[name_end..].trim_start().strip_prefix('=')?.trim_start();
+        let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+        let value = &value[1..];
+        let value_end = value.find(quote)?;
+        if attribute_name == name {
+            return Some(&value[..value_end]);
+        }
+        rest = &value[value_end + 1..];
+    }
+}
+
+/// Environment of a TypeScript `lib`, e.g. `dom`, `webworker` or `es2020.bigint`.
+fn lib_env(lib: &str) -> Option<&str> {
+    let lib = lib.split('.').next()?;
+    if lib.eq_ignore_ascii_case("dom") {
+        Some("browser")
+    } else if lib.eq_ignore_ascii_case("webworker") {
+        Some("worker")
+    } else if lib.eq_ignore_ascii_case("esnext") {
+        newest_es_env()
+    } else if lib.len() == 6 && lib.starts_with("es20") {
+        Some(lib)
+    } else {
+        None
+    }
+}
+
+/// The newest ECMAScript environment, e.g. `es2026`.
+fn newest_es_env() -> Option<&'static str> {
+    GLOBALS.keys().copied().filter(|env| env.len() == 6 && env.starts_with("es20")).max()
+}
+
+/// Environment of a types package, e.g. `node` or `vitest/globals`.
+fn types_env(types: &str) -> Option<&str> {
+    let package = types.strip_prefix("@types/").unwrap_or(types);
+    match package {
+        "node" | "jest" | "mocha" | "jasmine" | "qunit" | "jquery" => Some(package),
+        "vitest/globals" => Some("vitest"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::config::GlobalValue;
+
+    use super::{newest_es_env, parse_env_directives, parse_global_directives};
+
+    fn envs(source_text: &str) -> Vec<&str> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        parse_env_directives(source_text, &ret.program.comments)
+    }
+
+    #[test]
+    fn eslint_env() {
+        assert_eq!(envs("/* eslint-env node, browser */"), ["node", "browser"]);
+        assert_eq!(envs("/*eslint-env\n  mocha\n*/"), ["mocha"]);
+        assert!(envs("// eslint-env node").is_empty());
+        assert!(envs("/* eslint-environment node */").is_empty());
+    }
+
+    fn globals(source_text: &str) -> Vec<(&str, GlobalValue)> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        parse_global_directives(source_text, &ret.program.comments)
+    }
+
+    #[test]
+    fn global() {
+        use GlobalValue::{Off, Readonly, Writeable};
+
+        assert_eq!(globals("/* global foo */"), [("foo", Readonly)]);
+        assert_eq!(
+            globals("/* globals foo, bar: writable, baz:readonly qux : off */"),
+            [("foo", Readonly), ("bar", Writeable), ("baz", Readonly), ("qux", Off)]
+        );
+        assert_eq!(
+            globals("/*global\n  foo:true,\n  bar:false\n*/"),
+            [("foo", Writeable), ("bar", Readonly)]
+        );
+        assert_eq!(globals("/* global foo -- defined by the bundler */"), [("foo", Readonly)]);
+        assert_eq!(globals("/* global foo: invalid, bar */"), [("bar", Readonly)]);
+        assert!(globals("// global foo").is_empty());
+        assert!(globals("/* globalThis foo */").is_empty());
+    }
+
+    #[test]
+    fn triple_slash_reference() {
+        assert_eq!(envs(r#"/// <reference lib="dom" />"#), ["browser"]);
+        assert_eq!(envs("/// <reference lib='DOM.Iterable' />"), ["browser"]);
+        assert_eq!(envs(r#"/// <reference lib="webworker" />"#), ["worker"]);
+        assert_eq!(envs(r#"/// <reference lib="es2020.bigint" />"#), ["es2020"]);
+        assert_eq!(envs(r#"/// <reference lib="esnext" />"#), [newest_es_env().unwrap()]);
+        assert!(newest_es_env().unwrap() >= "es2026");
+        assert_eq!(envs(r#"/// <reference types="node" />"#), ["node"]);
+        assert_eq!(envs(r#"/// <reference types="vitest/globals" />"#), ["vitest"]);
+        assert!(envs(r#"/// <reference path="./foo.d.ts" />"#).is_empty());
+        // Attribute names are only matched as a whole, not in other names or values
+        assert!(envs(r#"/// <reference path="lib='dom'.d.ts" />"#).is_empty());
+        assert_eq!(envs(r#"/// <reference path="./@types/x.d.ts" types="jest" />"#), ["jest"]);
+        assert!(envs(r#"/// <reference no-default-lib="true" />"#).is_empty());
+        assert_eq!(envs(r#"/// <reference  resolution-mode="import" types = "node" />"#), ["node"]);
+        assert!(envs(r#"// <reference lib="dom" />"#).is_empty());
+    }
+}
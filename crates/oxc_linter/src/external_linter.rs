@@ -38,6 +38,27 @@ pub struct LintFileResult {
     pub rule_index: u32,
     pub message: String,
     pub loc: Loc,
+    /// Edits of the fix of the diagnostic, applied together with `--fix`.
+    #[serde(default)]
+    pub fixes: Vec<ExternalFix>,
+    /// Suggestions to fix the diagnostic, applied with `--fix-suggestions`.
+    #[serde(default)]
+    pub suggestions: Vec<ExternalSuggestion>,
+}
+
+/// Replaces the source text from `start` to `end` with `text`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExternalFix {
+    pub start: u32,
+    pub end: u32,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExternalSuggestion {
+    /// Description of the suggestion, shown in editors.
+    pub message: String,
+    pub fixes: Vec<ExternalFix>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1,9 +1,14 @@
 use std::fmt;
+#[cfg(feature = "wasm_plugins")]
+use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_index::{IndexVec, define_index_type};
 
+#[cfg(feature = "wasm_plugins")]
+use crate::wasm_plugin::WasmPlugin;
+
 define_index_type! {
     pub struct ExternalPluginId = u32;
 }
@@ -19,9 +24,15 @@ pub struct ExternalPluginStore {
     plugins: IndexVec<ExternalPluginId, ExternalPlugin>,
     plugin_names: FxHashMap<String, ExternalPluginId>,
     rules: IndexVec<ExternalRuleId, ExternalRule>,
+    /// Number of rules of JS plugins, which JS refers to by their index in all JS rules.
+    js_rule_count: usize,
 }
 
 impl ExternalPluginStore {
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
     pub fn is_plugin_registered(&self, plugin_path: &str) -> bool {
         self.registered_plugin_paths.contains(plugin_path)
     }
@@ -31,7 +42,7 @@ impl ExternalPluginStore {
     /// # Panics
     /// Panics if:
     /// - Plugin at `plugin_path` is already registered.
-    /// - `offset` does not equal the number of registered rules.
+    /// - `offset` does not equal the number of registered rules of JS plugins.
     pub fn register_plugin(
         &mut self,
         plugin_path: String,
@@ -42,20 +53,54 @@ impl ExternalPluginStore {
         let newly_inserted = self.registered_plugin_paths.insert(plugin_path);
         assert!(newly_inserted, "register_plugin: plugin already registered");
 
-        let plugin_id = self
-            .plugins
-            .push(ExternalPlugin { name: plugin_name.clone(), rules: FxHashMap::default() });
-        self.plugin_names.insert(plugin_name, plugin_id);
-
         assert!(
-            offset == self.rules.len(),
+            offset == self.js_rule_count,
             "register_plugin: received offset {}, but rule table is currently {} long",
             offset,
-            self.rules.len()
+            self.js_rule_count
         );
+        self.js_rule_count += rule_names.len();
+
+        let plugin_id = self.push_plugin(ExternalPlugin {
+            name: plugin_name,
+            rules: FxHashMap::default(),
+            #[cfg(feature = "wasm_plugins")]
+            wasm: None,
+        });
+        self.push_rules(plugin_id, offset, rule_names);
+    }
+
+    /// Register a plugin compiled to WASM.
+    ///
+    /// # Panics
+    /// Panics if plugin at `plugin_path` is already registered.
+    #[cfg(feature = "wasm_plugins")]
+    pub fn register_wasm_plugin(&mut self, plugin_path: String, plugin: WasmPlugin) {
+        let newly_inserted = self.registered_plugin_paths.insert(plugin_path);
+        assert!(newly_inserted, "register_wasm_plugin: plugin already registered");
+
+        let rule_names = plugin.rule_names().to_vec();
+        let plugin_id = self.push_plugin(ExternalPlugin {
+            name: plugin.name().to_string(),
+            rules: FxHashMap::default(),
+            wasm: Some(Arc::new(plugin)),
+        });
+        self.push_rules(plugin_id, 0, rule_names);
+    }
+
+    fn push_plugin(&mut self, plugin: ExternalPlugin) -> ExternalPluginId {
+        let plugin_name = plugin.name.clone();
+        let plugin_id = self.plugins.push(plugin);
+        self.plugin_names.insert(plugin_name, plugin_id);
+        plugin_id
+    }
 
-        for rule_name in rule_names {
-            let rule_id = self.rules.push(ExternalRule { name: rule_name.clone(), plugin_id });
+    fn push_rules(&mut self, plugin_id: ExternalPluginId, offset: usize, rule_names: Vec<String>) {
+        for (i, rule_name) in rule_names.into_iter().enumerate() {
+            #[expect(clippy::cast_possible_truncation)]
+            let index = (offset + i) as u32;
+            let rule_id =
+                self.rules.push(ExternalRule { name: rule_name.clone(), plugin_id, index });
             self.plugins[plugin_id].rules.insert(rule_name, rule_id);
         }
     }
@@ -87,6 +132,24 @@ impl ExternalPluginStore {
         let plugin = &self.plugins[external_rule.plugin_id];
         (&plugin.name, &external_rule.name)
     }
+
+    /// Index of the rule in all rules of JS plugins, or in the rules of its WASM plugin.
+    #[cfg_attr(
+        not(any(
+            all(feature = "oxlint2", not(feature = "disable_oxlint2")),
+            feature = "wasm_plugins"
+        )),
+        expect(dead_code)
+    )]
+    pub(crate) fn rule_index(&self, external_rule_id: ExternalRuleId) -> u32 {
+        self.rules[external_rule_id].index
+    }
+
+    /// The WASM plugin of the rule, or `None` if the rule is from a JS plugin.
+    #[cfg(feature = "wasm_plugins")]
+    pub(crate) fn wasm_plugin(&self, external_rule_id: ExternalRuleId) -> Option<&Arc<WasmPlugin>> {
+        self.plugins[self.rules[external_rule_id].plugin_id].wasm.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -114,10 +177,15 @@ impl std::error::Error for ExternalRuleLookupError {}
 struct ExternalPlugin {
     name: String,
     rules: FxHashMap<String, ExternalRuleId>,
+    /// `None` for JS plugins.
+    #[cfg(feature = "wasm_plugins")]
+    wasm: Option<Arc<WasmPlugin>>,
 }
 
 #[derive(Debug)]
 struct ExternalRule {
     name: String,
     plugin_id: ExternalPluginId,
+    /// See [`ExternalPluginStore::rule_index`].
+    index: u32,
 }
@@ -0,0 +1,340 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+pub use oxc_resolver::FileMetadata;
+use oxc_resolver::ResolveError;
+
+use crate::{
+    service::RuntimeFileSystem,
+    utils::{read_to_arena_str, read_to_string, write_in_source_encoding},
+};
+
+/// A file system used to discover, read and write the files to lint, their configuration files
+/// and the modules they import.
+///
+/// Linting uses [`OsFileSystem`] by default. Embedders can provide their own implementation to
+/// lint files in a sandbox, in memory (see [`MemoryFileSystem`]), or with contents which are
+/// not saved to disk yet.
+pub trait FileSystem: Send + Sync {
+    /// Read the content of a UTF-8 file.
+    ///
+    /// # Errors
+    /// When the file does not exist or is not valid UTF-8
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Read the content of a source file into `allocator`, transcoding it to UTF-8 if necessary.
+    ///
+    /// # Errors
+    /// When the file does not exist or is not valid in its encoding
+    fn read_to_arena_str<'a>(&self, path: &Path, allocator: &'a Allocator) -> io::Result<&'a str> {
+        self.read_to_string(path).map(|source_text| allocator.alloc_str(&source_text))
+    }
+
+    /// Write a file, in the encoding the file was read in.
+    ///
+    /// # Errors
+    /// When the file can't be written
+    fn write_file(&self, path: &Path, content: &str) -> io::Result<()>;
+
+    /// Metadata of a path, following symbolic links.
+    ///
+    /// # Errors
+    /// When the path does not exist
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata>;
+
+    /// Metadata of a path, without following symbolic links.
+    ///
+    /// # Errors
+    /// When the path does not exist
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.metadata(path)
+    }
+
+    /// Target of a symbolic link.
+    ///
+    /// # Errors
+    /// When the path is not a symbolic link
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a symbolic link", path.display()),
+        ))
+    }
+
+    /// Paths of the entries of a directory, in any order.
+    ///
+    /// # Errors
+    /// When the path is not a directory
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// Size of a file in bytes, `None` when it is unknown.
+    fn file_size(&self, _path: &Path) -> Option<u64> {
+        None
+    }
+
+    /// Whether `path` is a file, following symbolic links.
+    fn is_file(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok_and(FileMetadata::is_file)
+    }
+
+    /// Whether `path` is a directory, following symbolic links.
+    fn is_dir(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok_and(FileMetadata::is_dir)
+    }
+
+    /// Fetch the content of a configuration file extended from an `http://` or `https://` URL.
+    ///
+    /// # Errors
+    /// When the URL can't be fetched, or URLs are not supported, which is the default
+    fn fetch(&self, url: &str) -> io::Result<String> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!(
+                "extending configs from URLs is not supported in this build of oxlint, install the config as a package instead: {url}"
+            ),
+        ))
+    }
+}
+
+/// The file system of the operating system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        read_to_string(path)
+    }
+
+    fn read_to_arena_str<'a>(&self, path: &Path, allocator: &'a Allocator) -> io::Result<&'a str> {
+        read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> io::Result<()> {
+        write_in_source_encoding(path, content)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        oxc_resolver::FileSystemOs::metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        oxc_resolver::FileSystemOs::symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        oxc_resolver::FileSystemOs::read_link(path).map_err(|err| match err {
+            ResolveError::IOError(err) => err.into(),
+            err => io::Error::other(err),
+        })
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect()
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        std::fs::metadata(path).ok().map(|metadata| metadata.len())
+    }
+
+    #[cfg(feature = "remote_config")]
+    fn fetch(&self, url: &str) -> io::Result<String> {
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(std::time::Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        let mut response = agent.get(url).call().map_err(io::Error::other)?;
+        match response.status().as_u16() {
+            200..=299 => response.body_mut().read_to_string().map_err(io::Error::other),
+            status => Err(io::Error::other(format!("GET {url} returned {status}"))),
+        }
+    }
+}
+
+/// A file system which only exists in memory, for tests and editors.
+///
+/// Directories exist implicitly as the ancestors of the files which were added.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    files: RwLock<FxHashMap<PathBuf, String>>,
+}
+
+impl MemoryFileSystem {
+    /// Add a file, or replace the content of an existing one.
+    #[must_use]
+    pub fn with_file(self, path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        self.files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.into(), content.into());
+        self
+    }
+
+    /// Content of a file, e.g. to check the result of fixes.
+    pub fn file(&self, path: &Path) -> Option<String> {
+        self.files.read().unwrap_or_else(PoisonError::into_inner).get(path).cloned()
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.file(path).ok_or_else(|| not_found(path))
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> io::Result<()> {
+        self.files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_path_buf(), content.to_string());
+        Ok(())
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let files = self.files.read().unwrap_or_else(PoisonError::into_inner);
+        if files.contains_key(path) {
+            Ok(FileMetadata::new(true, false, false))
+        } else if files.keys().any(|file| file.starts_with(path)) {
+            Ok(FileMetadata::new(false, true, false))
+        } else {
+            Err(not_found(path))
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let files = self.files.read().unwrap_or_else(PoisonError::into_inner);
+        let mut entries = files
+            .keys()
+            .filter_map(|file| {
+                let child = file.strip_prefix(path).ok()?.components().next()?;
+                Some(path.join(child))
+            })
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            return Err(not_found(path));
+        }
+        entries.sort_unstable();
+        entries.dedup();
+        Ok(entries)
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        let files = self.files.read().unwrap_or_else(PoisonError::into_inner);
+        files.get(path).map(|content| content.len() as u64)
+    }
+
+    /// URLs are read from the files added with the URL as their path.
+    fn fetch(&self, url: &str) -> io::Result<String> {
+        self.read_to_string(Path::new(url))
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", path.display()))
+}
+
+/// Reads and writes source files of the `Runtime` through a [`FileSystem`].
+pub struct SourceFileSystem(pub Arc<dyn FileSystem>);
+
+impl RuntimeFileSystem for SourceFileSystem {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        self.0.read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), io::Error> {
+        self.0.write_file(path, content)
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        self.0.file_size(path)
+    }
+}
+
+/// Resolves imported modules through a [`FileSystem`].
+pub struct ResolverFileSystem(pub Arc<dyn FileSystem>);
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+    fn new() -> Self {
+        Self(Arc::new(OsFileSystem))
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.0.read_to_string(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.0.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.0.symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> Result<PathBuf, ResolveError> {
+        self.0.read_link(path).map_err(ResolveError::from)
+    }
+}
+
+/// Resolves modules through a borrowed [`FileSystem`], e.g. shareable configurations in `extends`.
+pub struct BorrowedResolverFileSystem<'a>(pub &'a dyn FileSystem);
+
+impl oxc_resolver::FileSystem for BorrowedResolverFileSystem<'_> {
+    fn new() -> Self {
+        Self(&OsFileSystem)
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.0.read_to_string(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.0.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.0.symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> Result<PathBuf, ResolveError> {
+        self.0.read_link(path).map_err(ResolveError::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{FileSystem, MemoryFileSystem};
+
+    #[test]
+    fn memory_file_system() {
+        let fs = MemoryFileSystem::default()
+            .with_file("/project/src/a.js", "a")
+            .with_file("/project/src/nested/b.js", "b")
+            .with_file("/project/.oxlintrc.json", "{}");
+
+        assert_eq!(fs.read_to_string(Path::new("/project/src/a.js")).unwrap(), "a");
+        assert!(fs.read_to_string(Path::new("/project/src")).is_err());
+        assert!(fs.is_file(Path::new("/project/.oxlintrc.json")));
+        assert!(fs.is_dir(Path::new("/project/src/nested")));
+        assert!(!fs.is_dir(Path::new("/project/src/nest")));
+        assert_eq!(
+            fs.read_dir(Path::new("/project/src")).unwrap(),
+            [PathBuf::from("/project/src/a.js"), PathBuf::from("/project/src/nested")]
+        );
+        assert!(fs.read_dir(Path::new("/other")).is_err());
+        assert!(fs.read_link(Path::new("/project/src/a.js")).is_err());
+
+        fs.write_file(Path::new("/project/src/a.js"), "fixed").unwrap();
+        assert_eq!(fs.file(Path::new("/project/src/a.js")).as_deref(), Some("fixed"));
+    }
+}
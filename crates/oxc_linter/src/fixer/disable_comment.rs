@@ -0,0 +1,292 @@
+//! Comments which disable rules for the line of a diagnostic, e.g. for the
+//! "Disable for this line" code actions of the language server.
+
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{GetSpan, Span};
+
+use super::Fix;
+
+/// Where a comment is inserted in front of the first token of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    /// JavaScript code, where a `//` comment can be inserted.
+    Code,
+    /// The children of a JSX element, where comments must be wrapped in `{}`.
+    JsxChildren,
+    /// A multi line string or template literal, where anything inserted changes its value.
+    Literal,
+}
+
+/// Returns the edits which insert a comment disabling `rule` (or all rules when [`None`]) for the
+/// line which contains `offset`.
+///
+/// The comment is inserted on its own line above, with the indentation of the line:
+/// * `// oxlint-disable-next-line` in code and inside of JSX tags.
+/// * `{/* oxlint-disable-next-line */}` in the children of JSX elements.
+/// * When the line starts inside of a multi line string or template literal, the enclosing
+///   statement is wrapped in `// oxlint-disable` and `// oxlint-enable` instead.
+pub fn disable_for_line<'a>(
+    semantic: &Semantic<'_>,
+    offset: u32,
+    rule: Option<&str>,
+) -> Vec<Fix<'a>> {
+    let source_text = semantic.source_text();
+    let (first_token, indent) = first_token_of_line(source_text, offset);
+    let rule = rule.map_or_else(String::new, |rule| format!(" {rule}"));
+
+    let node = innermost_node(semantic, first_token);
+    match node.map_or(Context::Code, |node| context(semantic, node, first_token)) {
+        Context::Code => vec![Fix::new(
+            format!("// oxlint-disable-next-line{rule}\n{indent}"),
+            Span::empty(first_token),
+        )],
+        Context::JsxChildren => vec![Fix::new(
+            format!("{{/* oxlint-disable-next-line{rule} */}}\n{indent}"),
+            Span::empty(first_token),
+        )],
+        Context::Literal => {
+            let statement = node.and_then(|node| {
+                semantic
+                    .nodes()
+                    .ancestors(node.id())
+                    .find(|node| node.kind().is_statement() || node.kind().is_declaration())
+            });
+            let Some(statement) = statement else { return vec![] };
+            let span = statement.kind().span();
+            let (_, statement_indent) = first_token_of_line(source_text, span.start);
+            let rest_of_line = source_text[span.end as usize..].split('\n').next().unwrap_or("");
+            let enable = if rest_of_line.trim().is_empty() {
+                format!("\n{statement_indent}// oxlint-enable{rule}")
+            } else {
+                format!(" /* oxlint-enable{rule} */")
+            };
+            vec![
+                Fix::new(
+                    format!("// oxlint-disable{rule}\n{statement_indent}"),
+                    Span::empty(span.start),
+                ),
+                Fix::new(enable, Span::empty(span.end)),
+            ]
+        }
+    }
+}
+
+/// Returns the edit which appends a comment disabling `rule` (or all rules when [`None`]) to the
+/// end of the line which contains `offset`, e.g. `debugger; // oxlint-disable-line no-debugger`.
+///
+/// Returns [`None`] when the line ends in a comment, a multi line string or template literal, or
+/// the children of a JSX element, where the comment would not be a directive.
+pub fn disable_at_end_of_line<'a>(
+    semantic: &Semantic<'_>,
+    offset: u32,
+    rule: Option<&str>,
+) -> Option<Fix<'a>> {
+    let source_text = semantic.source_text();
+    let rest = &source_text[offset as usize..];
+    let line_end = offset as usize + rest.find('\n').unwrap_or(rest.len());
+    let line_end = u32::try_from(source_text[..line_end].trim_end().len()).ok()?.max(offset);
+
+    let in_comment = semantic.comments().iter().any(|comment| {
+        comment.span.start < line_end
+            && (line_end < comment.span.end || (comment.is_line() && line_end == comment.span.end))
+    });
+    if in_comment {
+        return None;
+    }
+    let node = innermost_node(semantic, line_end);
+    if node.is_some_and(|node| context(semantic, node, line_end) != Context::Code) {
+        return None;
+    }
+
+    let rule = rule.map_or_else(String::new, |rule| format!(" {rule}"));
+    Some(Fix::new(format!(" // oxlint-disable-line{rule}"), Span::empty(line_end)))
+}
+
+/// The offset of the first token of the line which contains `offset`, and the indentation before it.
+#[expect(clippy::cast_possible_truncation)]
+fn first_token_of_line(source_text: &str, offset: u32) -> (u32, &str) {
+    let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |index| index + 1);
+    let line = &source_text[line_start..];
+    let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+    ((line_start + indent.len()) as u32, indent)
+}
+
+/// The smallest node which contains `offset`.
+fn innermost_node<'s, 'a>(semantic: &'s Semantic<'a>, offset: u32) -> Option<&'s AstNode<'a>> {
+    semantic
+        .nodes()
+        .iter()
+        .filter(|node| {
+            let span = node.kind().span();
+            span.start <= offset && offset < span.end
+        })
+        // Children are created after their parents, so the last node wins for equal spans
+        .min_by_key(|node| (node.kind().span().size(), std::cmp::Reverse(node.id())))
+}
+
+fn context(semantic: &Semantic<'_>, node: &AstNode<'_>, offset: u32) -> Context {
+    if matches!(
+        node.kind(),
+        AstKind::StringLiteral(_) | AstKind::TemplateLiteral(_) | AstKind::TemplateElement(_)
+    ) {
+        return Context::Literal;
+    }
+
+    for node in std::iter::once(node).chain(semantic.nodes().ancestors(node.id())) {
+        let span = node.kind().span();
+        let parent_is_element = matches!(
+            semantic.nodes().parent_kind(node.id()),
+            AstKind::JSXElement(_) | AstKind::JSXFragment(_)
+        );
+        match node.kind() {
+            // The line is in the children or at the closing tag of the element
+            AstKind::JSXElement(_) | AstKind::JSXFragment(_) if span.start < offset => {
+                return Context::JsxChildren;
+            }
+            AstKind::JSXElement(_)
+            | AstKind::JSXFragment(_)
+            | AstKind::JSXExpressionContainer(_)
+            | AstKind::JSXText(_)
+            | AstKind::JSXSpreadChild(_)
+                if parent_is_element =>
+            {
+                return Context::JsxChildren;
+            }
+            // Comments are allowed between the attributes of a tag
+            AstKind::JSXOpeningElement(_) if span.start < offset => return Context::Code,
+            AstKind::JSXElement(_)
+            | AstKind::JSXFragment(_)
+            | AstKind::JSXExpressionContainer(_)
+            | AstKind::JSXText(_)
+            | AstKind::JSXSpreadChild(_)
+            | AstKind::JSXOpeningElement(_)
+            | AstKind::JSXClosingElement(_)
+            | AstKind::JSXOpeningFragment(_)
+            | AstKind::JSXClosingFragment(_)
+            | AstKind::JSXNamespacedName(_)
+            | AstKind::JSXMemberExpression(_)
+            | AstKind::JSXEmptyExpression(_)
+            | AstKind::JSXAttribute(_)
+            | AstKind::JSXSpreadAttribute(_)
+            | AstKind::JSXIdentifier(_) => {}
+            _ => return Context::Code,
+        }
+    }
+    Context::Code
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{disable_at_end_of_line, disable_for_line};
+
+    /// Disable `no-debugger` for the line of the first `debugger`.
+    fn disable(source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        #[expect(clippy::cast_possible_truncation)]
+        let offset = source_text.find("debugger").unwrap() as u32;
+
+        let mut fixes = disable_for_line(&semantic, offset, Some("no-debugger"));
+        fixes.sort_by_key(|fix| std::cmp::Reverse(fix.span.start));
+        let mut output = source_text.to_string();
+        for fix in fixes {
+            output.insert_str(fix.span.start as usize, &fix.content);
+        }
+        output
+    }
+
+    /// Disable `no-debugger` at the end of the line of the first `debugger`.
+    fn disable_at_end(source_text: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        #[expect(clippy::cast_possible_truncation)]
+        let offset = source_text.find("debugger").unwrap() as u32;
+
+        let fix = disable_at_end_of_line(&semantic, offset, Some("no-debugger"))?;
+        let mut output = source_text.to_string();
+        output.insert_str(fix.span.start as usize, &fix.content);
+        Some(output)
+    }
+
+    #[test]
+    fn end_of_line() {
+        assert_eq!(
+            disable_at_end("function foo() {\n  debugger;  \r\n}").as_deref(),
+            Some("function foo() {\n  debugger; // oxlint-disable-line no-debugger  \r\n}")
+        );
+        assert_eq!(
+            disable_at_end("if (a) { debugger; } /* b */").as_deref(),
+            Some("if (a) { debugger; } /* b */ // oxlint-disable-line no-debugger")
+        );
+        assert_eq!(
+            disable_at_end("<div\n  onClick={() => { debugger; }}\n/>;").as_deref(),
+            Some("<div\n  onClick={() => { debugger; }} // oxlint-disable-line no-debugger\n/>;")
+        );
+        // The comment would not be a directive
+        assert_eq!(disable_at_end("debugger; // reason"), None);
+        assert_eq!(disable_at_end("debugger; /* reason\n */"), None);
+        assert_eq!(disable_at_end("const a = `${(() => { debugger; })()}\n`;"), None);
+        assert_eq!(disable_at_end("<div>{(() => { debugger; })()}\n</div>;"), None);
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(
+            disable("function foo() {\n  debugger;\n}"),
+            "function foo() {\n  // oxlint-disable-next-line no-debugger\n  debugger;\n}"
+        );
+        assert_eq!(disable("debugger;"), "// oxlint-disable-next-line no-debugger\ndebugger;");
+    }
+
+    #[test]
+    fn jsx_children() {
+        assert_eq!(
+            disable("<div>\n  {(() => { debugger; })()}\n</div>;"),
+            "<div>\n  {/* oxlint-disable-next-line no-debugger */}\n  {(() => { debugger; })()}\n</div>;"
+        );
+        assert_eq!(
+            disable("<>\n  <span onClick={() => { debugger; }} />\n</>;"),
+            "<>\n  {/* oxlint-disable-next-line no-debugger */}\n  <span onClick={() => { debugger; }} />\n</>;"
+        );
+    }
+
+    #[test]
+    fn jsx_attributes() {
+        assert_eq!(
+            disable("<div\n  onClick={() => { debugger; }}\n/>;"),
+            "<div\n  // oxlint-disable-next-line no-debugger\n  onClick={() => { debugger; }}\n/>;"
+        );
+    }
+
+    #[test]
+    fn jsx_expression() {
+        assert_eq!(
+            disable("<div>\n  {items.map(() => {\n    debugger;\n  })}\n</div>;"),
+            "<div>\n  {items.map(() => {\n    // oxlint-disable-next-line no-debugger\n    debugger;\n  })}\n</div>;"
+        );
+    }
+
+    #[test]
+    fn template_literal() {
+        assert_eq!(
+            disable("  const a = `\n${(() => { debugger; })()}`;\n"),
+            "  // oxlint-disable no-debugger\n  const a = `\n${(() => { debugger; })()}`;\n  // oxlint-enable no-debugger\n"
+        );
+        assert_eq!(
+            disable("if (a) { foo(`\n${(() => { debugger; })()}`); }"),
+            "if (a) { // oxlint-disable no-debugger\nfoo(`\n${(() => { debugger; })()}`); /* oxlint-enable no-debugger */ }"
+        );
+    }
+}
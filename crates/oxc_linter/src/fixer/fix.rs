@@ -126,6 +126,11 @@ pub struct RuleFix<'a> {
     kind: FixKind,
     /// A suggestion message. Will be shown in editors via code actions.
     message: Option<Cow<'a, str>>,
+    /// Name of the suggestion, which selects it among the alternatives of
+    /// [`LintContext::diagnostic_with_suggestions`], e.g. with `--apply-suggestions`.
+    ///
+    /// [`LintContext::diagnostic_with_suggestions`]: crate::LintContext::diagnostic_with_suggestions
+    name: Option<Cow<'a, str>>,
     /// The actual that will be applied to the source code.
     ///
     /// See: [`Fix`]
@@ -137,7 +142,7 @@ macro_rules! impl_from {
         $(
             impl<'a> From<$ty> for RuleFix<'a> {
                 fn from(fix: $ty) -> Self {
-                    Self { kind: FixKind::SafeFix, message: None, fix: fix.into() }
+                    Self { kind: FixKind::SafeFix, message: None, name: None, fix: fix.into() }
                 }
             }
         )*
@@ -153,6 +158,7 @@ impl<'a> FromIterator<Fix<'a>> for RuleFix<'a> {
         Self {
             kind: FixKind::SafeFix,
             message: None,
+            name: None,
             fix: iter.into_iter().collect::<Vec<_>>().into(),
         }
     }
@@ -168,25 +174,25 @@ impl<'a> From<RuleFix<'a>> for CompositeFix<'a> {
 impl<'a> RuleFix<'a> {
     #[inline]
     pub(super) fn new(kind: FixKind, message: Option<Cow<'a, str>>, fix: CompositeFix<'a>) -> Self {
-        Self { kind, message, fix }
+        Self { kind, message, name: None, fix }
     }
 
     /// Create a new safe fix.
     #[inline]
     pub fn fix(fix: CompositeFix<'a>) -> Self {
-        Self { kind: FixKind::Fix, message: None, fix }
+        Self { kind: FixKind::Fix, message: None, name: None, fix }
     }
 
     /// Create a new suggestion
     #[inline]
     pub const fn suggestion(fix: CompositeFix<'a>, message: Cow<'a, str>) -> Self {
-        Self { kind: FixKind::Suggestion, message: Some(message), fix }
+        Self { kind: FixKind::Suggestion, message: Some(message), name: None, fix }
     }
 
     /// Create a dangerous fix.
     #[inline]
     pub fn dangerous(fix: CompositeFix<'a>) -> Self {
-        Self { kind: FixKind::DangerousFix, message: None, fix }
+        Self { kind: FixKind::DangerousFix, message: None, name: None, fix }
     }
 
     /// Mark this [`RuleFix`] as dangerous.
@@ -229,6 +235,16 @@ impl<'a> RuleFix<'a> {
         self
     }
 
+    /// Name this suggestion, so that it can be selected among the alternatives of
+    /// [`LintContext::diagnostic_with_suggestions`]. Names are kebab-case, e.g. `add-ref-parameter`.
+    ///
+    /// [`LintContext::diagnostic_with_suggestions`]: crate::LintContext::diagnostic_with_suggestions
+    #[inline]
+    pub fn with_name<S: Into<Cow<'a, str>>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     #[inline]
     pub fn kind(&self) -> FixKind {
         self.kind
@@ -239,6 +255,11 @@ impl<'a> RuleFix<'a> {
         self.message.as_deref()
     }
 
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     #[inline]
     pub fn into_fix(self, source_text: &str) -> Fix<'a> {
         // If there is only one fix, use the message from that fix.
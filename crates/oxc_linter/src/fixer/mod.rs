@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, OxcDiagnostic};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -13,9 +13,15 @@ pub use fix::{FixWithPosition, PossibleFixesWithPosition};
 #[cfg(feature = "language_server")]
 use oxc_diagnostics::{OxcCode, Severity};
 
+#[cfg(feature = "language_server")]
+mod disable_comment;
 mod fix;
+mod suggestion_choices;
+#[cfg(feature = "language_server")]
+pub use disable_comment::{disable_at_end_of_line, disable_for_line};
 pub use fix::{CompositeFix, Fix, FixKind, PossibleFixes, RuleFix};
 use oxc_allocator::{Allocator, CloneIn};
+pub use suggestion_choices::{InvalidSuggestionChoice, SuggestionChoices};
 
 /// Produces [`RuleFix`] instances. Inspired by ESLint's [`RuleFixer`].
 ///
@@ -243,6 +249,12 @@ pub struct MessageWithPosition<'a> {
     pub code: OxcCode,
     pub url: Option<Cow<'a, str>>,
     pub fixes: PossibleFixesWithPosition<'a>,
+    /// Inserts a comment which disables the rule for the line of the diagnostic,
+    /// see [`disable_for_line`].
+    pub disable_line_fixes: Vec<FixWithPosition<'a>>,
+    /// Appends a comment which disables the rule to the line of the diagnostic,
+    /// see [`disable_at_end_of_line`].
+    pub disable_same_line_fix: Option<FixWithPosition<'a>>,
 }
 
 #[cfg(feature = "language_server")]
@@ -256,6 +268,8 @@ impl From<OxcDiagnostic> for MessageWithPosition<'_> {
             code: from.code.clone(),
             url: from.url.clone(),
             fixes: PossibleFixesWithPosition::None,
+            disable_line_fixes: vec![],
+            disable_same_line_fix: None,
         }
     }
 }
@@ -327,10 +341,78 @@ impl<'a> Message<'a> {
     }
 }
 
+impl Message<'_> {
+    /// Append a preview of the fixes of this message to the help of its diagnostic: the lines
+    /// changed by each fix, before (`-`) and after (`+`) applying it.
+    ///
+    /// Spans of the fixes must be relative to `source_text`.
+    pub fn add_fix_preview(&mut self, source_text: &str) {
+        let mut help = self.error.help.take().map(Cow::into_owned).unwrap_or_default();
+        match &self.fixes {
+            PossibleFixes::None => {}
+            PossibleFixes::Single(fix) => push_fix_preview(&mut help, source_text, fix, false),
+            PossibleFixes::Multiple(fixes) => {
+                for fix in fixes {
+                    push_fix_preview(&mut help, source_text, fix, true);
+                }
+            }
+        }
+        self.error.help = (!help.is_empty()).then_some(Cow::Owned(help));
+    }
+}
+
+fn push_fix_preview(help: &mut String, source_text: &str, fix: &Fix, with_message: bool) {
+    let start = fix.span.start as usize;
+    let end = fix.span.end as usize;
+    if start > end || end > source_text.len() {
+        return;
+    }
+    let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i);
+    let before = &source_text[line_start..line_end];
+    let after = format!(
+        "{}{}{}",
+        &source_text[line_start..start],
+        fix.content,
+        &source_text[end..line_end]
+    );
+
+    if !help.is_empty() {
+        help.push('\n');
+    }
+    match &fix.message {
+        Some(message) if with_message => {
+            help.push_str(message);
+            help.push_str(":\n");
+        }
+        _ => help.push_str("Fix:\n"),
+    }
+    let lines = before.lines().map(|line| ('-', line)).chain(after.lines().map(|line| ('+', line)));
+    for (i, (sign, line)) in lines.enumerate() {
+        if i > 0 {
+            help.push('\n');
+        }
+        help.push(sign);
+        help.push(' ');
+        help.push_str(line);
+    }
+}
+
 impl From<Message<'_>> for OxcDiagnostic {
-    #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        let fixes: Vec<_> = match message.fixes {
+            PossibleFixes::None => vec![],
+            PossibleFixes::Single(fix) => vec![fix],
+            PossibleFixes::Multiple(fixes) => fixes,
+        };
+        if fixes.is_empty() {
+            return message.error;
+        }
+        message.error.with_fixes(fixes.into_iter().map(|fix| DiagnosticFix {
+            span: fix.span.into(),
+            content: Cow::Owned(fix.content.into_owned()),
+            message: fix.message.map(|message| Cow::Owned(message.into_owned())),
+        }))
     }
 }
 
@@ -374,7 +456,17 @@ impl<'a> Fixer<'a> {
             };
         }
 
-        self.messages.sort_unstable_by_key(|m| m.fixes.span());
+        // Of fixes with the same span, apply the one of the first rule by name, and of the same
+        // rule, the one reported first, so the result doesn't depend on the order rules run in.
+        // Fixes which overlap an applied fix are skipped, and are applied in the next fix pass.
+        self.messages.sort_by(|a, b| {
+            let (a_code, b_code) = (&a.error.code, &b.error.code);
+            a.fixes
+                .span()
+                .cmp(&b.fixes.span())
+                .then_with(|| a_code.scope.cmp(&b_code.scope))
+                .then_with(|| a_code.number.cmp(&b_code.number))
+        });
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: u32 = 0;
@@ -706,6 +798,26 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_fix_of_first_rule_when_spans_are_the_same() {
+        const REPLACE_ID_BAR: Fix =
+            Fix { span: Span::new(4, 10), content: Cow::Borrowed("bar"), message: None };
+        let foo = || OxcDiagnostic::warn("foo").with_error_code("eslint", "b-rule");
+        let bar = || OxcDiagnostic::warn("bar").with_error_code("eslint", "a-rule");
+        let result1 = get_fix_result(vec![
+            create_message(foo(), PossibleFixes::Single(REPLACE_ID)),
+            create_message(bar(), PossibleFixes::Single(REPLACE_ID_BAR)),
+        ]);
+        let result2 = get_fix_result(vec![
+            create_message(bar(), PossibleFixes::Single(REPLACE_ID_BAR)),
+            create_message(foo(), PossibleFixes::Single(REPLACE_ID)),
+        ]);
+        assert_eq!(result1.fixed_code, TEST_CODE.cow_replace("answer", "bar"));
+        assert_eq!(result1.fixed_code, result2.fixed_code);
+        assert_eq!(result1.messages.len(), 1);
+        assert_eq!(result1.messages[0].error.to_string(), "foo");
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result =
@@ -0,0 +1,95 @@
+use std::fmt;
+
+use rustc_hash::FxHashMap;
+
+/// Suggestions to apply for the violations of a rule, selected with
+/// `--apply-suggestions <rule:choice>`.
+///
+/// Rules which report several alternative suggestions with
+/// [`LintContext::diagnostic_with_suggestions`] apply the chosen one, whatever
+/// [`FixKind`]s are enabled.
+///
+/// [`LintContext::diagnostic_with_suggestions`]: crate::LintContext::diagnostic_with_suggestions
+/// [`FixKind`]: crate::FixKind
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SuggestionChoices {
+    /// Rule, either `plugin/rule-name` or `rule-name`, to the name or index of a suggestion.
+    choices: FxHashMap<String, String>,
+}
+
+/// A `--apply-suggestions` value which is not `rule:choice`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSuggestionChoice(pub String);
+
+impl fmt::Display for InvalidSuggestionChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid suggestion choice `{}`, expected `rule:choice`, e.g. `react/forward-ref-uses-ref:add-ref-parameter`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidSuggestionChoice {}
+
+impl SuggestionChoices {
+    /// Parse choices in the form of `rule:choice`, where `choice` is the name of a suggestion
+    /// or its index starting at 0.
+    ///
+    /// # Errors
+    /// When a value is not `rule:choice`.
+    pub fn parse<S: AsRef<str>>(
+        values: impl IntoIterator<Item = S>,
+    ) -> Result<Self, InvalidSuggestionChoice> {
+        let mut choices = FxHashMap::default();
+        for value in values {
+            let value = value.as_ref();
+            match value.split_once(':') {
+                Some((rule, choice)) if !rule.is_empty() && !choice.is_empty() => {
+                    choices.insert(rule.to_string(), choice.to_string());
+                }
+                _ => return Err(InvalidSuggestionChoice(value.to_string())),
+            }
+        }
+        Ok(Self { choices })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.choices.is_empty()
+    }
+
+    /// The chosen suggestion of a rule.
+    pub(crate) fn get(&self, plugin_name: &str, rule_name: &str) -> Option<&str> {
+        if self.choices.is_empty() {
+            return None;
+        }
+        self.choices
+            .get(&format!("{plugin_name}/{rule_name}"))
+            .or_else(|| self.choices.get(rule_name))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InvalidSuggestionChoice, SuggestionChoices};
+
+    #[test]
+    fn parse() {
+        let choices =
+            SuggestionChoices::parse(["react/forward-ref-uses-ref:add-ref-parameter", "no-foo:1"])
+                .unwrap();
+        assert_eq!(choices.get("react", "forward-ref-uses-ref"), Some("add-ref-parameter"));
+        assert_eq!(choices.get("eslint", "no-foo"), Some("1"));
+        assert_eq!(choices.get("eslint", "no-bar"), None);
+        assert_eq!(choices.get("jsx_a11y", "forward-ref-uses-ref"), None);
+
+        for invalid in ["no-foo", "no-foo:", ":add-ref-parameter"] {
+            assert_eq!(
+                SuggestionChoices::parse([invalid]),
+                Err(InvalidSuggestionChoice(invalid.to_string()))
+            );
+        }
+    }
+}
@@ -1,10 +1,16 @@
 #![expect(clippy::self_named_module_files)] // for rules.rs
 #![allow(clippy::literal_string_with_formatting_args)]
 
-use std::{path::Path, rc::Rc, sync::Arc};
+use std::{
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use oxc_allocator::Allocator;
 use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{GetSpan, Span};
 
 #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
 use oxc_ast_macros::ast;
@@ -16,17 +22,28 @@ mod ast_util;
 mod config;
 mod context;
 mod disable_directives;
+mod env_directives;
 mod external_linter;
 mod external_plugin_store;
+mod file_system;
 mod fixer;
 mod frameworks;
 mod globals;
+mod module_graph;
 mod module_graph_visitor;
 mod module_record;
 mod options;
+mod plugin_manifest;
 mod rule;
+mod rule_directives;
+mod selector;
 mod service;
+mod timing;
+mod type_checker;
 mod utils;
+mod virtual_files;
+#[cfg(feature = "wasm_plugins")]
+mod wasm_plugin;
 
 pub mod loader;
 pub mod rules;
@@ -40,34 +57,55 @@ mod generated {
 
 pub use crate::{
     config::{
-        BuiltinLintPlugins, Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder,
-        ESLintRule, LintPlugins, Oxlintrc,
+        BuiltinLintPlugins, Config, ConfigBuilderError, ConfigResolution, ConfigStore,
+        ConfigStoreBuilder, ESLintRule, LintPlugins, OverrideResolution, OxlintCoverage,
+        OxlintRules, Oxlintrc, RenamedRule, RuleResolution, RuleSource,
     },
     context::LintContext,
     external_linter::{
-        ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, LintFileResult,
-        PluginLoadResult,
+        ExternalFix, ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb,
+        ExternalSuggestion, LintFileResult, PluginLoadResult,
     },
     external_plugin_store::{ExternalPluginStore, ExternalRuleId},
-    fixer::FixKind,
+    file_system::{FileMetadata, FileSystem, MemoryFileSystem, OsFileSystem},
+    fixer::{FixKind, InvalidSuggestionChoice, SuggestionChoices},
     frameworks::FrameworkFlags,
-    loader::LINTABLE_EXTENSIONS,
+    loader::{LINTABLE_EXTENSIONS, MARKDOWN_EXTENSIONS},
     module_record::ModuleRecord,
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta},
-    service::{LintService, LintServiceOptions, RuntimeFileSystem},
+    plugin_manifest::{OXLINT_VERSION, PluginManifest, PluginManifestError},
+    rule::{RuleCategory, RuleDeprecation, RuleFixMeta, RuleMeta},
+    selector::{Selector, SelectorError},
+    service::{DEFAULT_MAX_FIX_PASSES, LintService, LintServiceOptions, RuntimeFileSystem},
+    timing::{CountingAllocator, RuleTiming, RuleTimings, allocations},
+    type_checker::{SidecarTypeChecker, TypeChecker, TypeFlags, TypeInfo, TypeQuery},
     utils::read_to_arena_str,
     utils::read_to_string,
+    utils::{SourceEncoding, write_in_source_encoding},
+    virtual_files::{VirtualFileResult, VirtualFiles},
 };
 use crate::{
-    config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, ResolvedLinterState},
+    config::{
+        DirectiveSeverities, LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
+        ResolvedLinterState,
+    },
     context::ContextHost,
-    fixer::{Fixer, Message},
+    fixer::{Fixer, Message, PossibleFixes},
+    rule_directives::parse_rule_directives,
     rules::RuleEnum,
     utils::iter_possible_jest_call_node,
 };
 
+#[cfg(any(
+    all(feature = "oxlint2", not(feature = "disable_oxlint2")),
+    feature = "wasm_plugins"
+))]
+use {crate::rule_directives::SeverityRanges, rustc_hash::FxHashMap};
+
+#[cfg(feature = "wasm_plugins")]
+pub use crate::wasm_plugin::WasmPlugin;
+
 #[cfg(feature = "language_server")]
 pub use crate::fixer::{FixWithPosition, MessageWithPosition, PossibleFixesWithPosition};
 
@@ -87,6 +125,12 @@ pub struct Linter {
     config: ConfigStore,
     #[cfg_attr(not(all(feature = "oxlint2", not(feature = "disable_oxlint2"))), expect(dead_code))]
     external_linter: Option<ExternalLinter>,
+    /// Provides the types of expressions to type-aware rules.
+    type_checker: Option<Arc<dyn TypeChecker>>,
+    /// Collects the time spent in each rule.
+    timings: Option<Arc<RuleTimings>>,
+    /// Suggestions chosen with `--apply-suggestions`.
+    suggestion_choices: Option<Arc<SuggestionChoices>>,
 }
 
 impl Linter {
@@ -95,7 +139,28 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self {
+            options,
+            config,
+            external_linter,
+            type_checker: None,
+            timings: None,
+            suggestion_choices: None,
+        }
+    }
+
+    /// Enable type-aware rules, which query the types of expressions from `type_checker`.
+    #[must_use]
+    pub fn with_type_checker(mut self, type_checker: Arc<dyn TypeChecker>) -> Self {
+        self.type_checker = Some(type_checker);
+        self
+    }
+
+    /// Measure the time and heap allocations spent in each rule, and add them to `timings`.
+    #[must_use]
+    pub fn with_timing(mut self, timings: Arc<RuleTimings>) -> Self {
+        self.timings = Some(timings);
+        self
     }
 
     /// Set the kind of auto fixes to apply.
@@ -105,14 +170,48 @@ impl Linter {
         self
     }
 
+    /// Apply the chosen suggestions of rules, whatever kinds of fixes are enabled with
+    /// [`Linter::with_fix`].
+    #[must_use]
+    pub fn with_suggestion_choices(mut self, choices: SuggestionChoices) -> Self {
+        self.suggestion_choices = (!choices.is_empty()).then(|| Arc::new(choices));
+        self
+    }
+
+    /// Append a preview of the remaining fixes of each diagnostic to its help, i.e. the lines
+    /// changed by the fix before and after applying it. Without any fixes enabled with
+    /// [`Linter::with_fix`], the safe fixes are previewed without being applied.
+    #[must_use]
+    pub fn with_fix_preview(mut self, yes: bool) -> Self {
+        self.options.fix_preview = yes;
+        self
+    }
+
+    /// Stop running a rule on a file after `timeout`, and report that the rule was skipped,
+    /// instead of hanging on pathological files such as large generated files.
+    ///
+    /// The timeout is checked after every few AST nodes, so a rule can exceed it before it is
+    /// stopped, e.g. on a single slow node.
+    #[must_use]
+    pub fn with_rule_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.options.rule_timeout = timeout;
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
         self
     }
 
-    pub(crate) fn options(&self) -> &LintOptions {
-        &self.options
+    /// Whether any fixes or chosen suggestions are applied to the linted files.
+    pub(crate) fn fixes_enabled(&self) -> bool {
+        self.options.fix.is_some() || self.suggestion_choices.is_some()
+    }
+
+    /// Whether fix previews are appended to the help of diagnostics.
+    pub(crate) fn fix_preview_enabled(&self) -> bool {
+        self.options.fix_preview
     }
 
     /// Returns the number of rules that will are being used, unless there
@@ -129,15 +228,44 @@ impl Linter {
         module_record: Arc<ModuleRecord>,
         allocator: &Allocator,
     ) -> Vec<Message<'a>> {
-        let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
-
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        let mut state = self.config.resolve(path);
+
+        // Inline configuration comments, e.g. `/* eslint eqeqeq: "off" */`
+        let (rule_directives, mut directive_diagnostics) =
+            parse_rule_directives(semantic.source_text(), semantic.comments());
+        let mut severities = DirectiveSeverities::default();
+        if !rule_directives.is_empty() {
+            let (directive_state, directive_severities, diagnostics) =
+                self.config.apply_rule_directives(&state, &rule_directives);
+            state = directive_state;
+            severities = directive_severities;
+            directive_diagnostics.extend(diagnostics);
+        }
+        let ResolvedLinterState { rules, config, external_rules } = state;
+        let DirectiveSeverities { rules: rule_severities, external_rules: external_severities } =
+            severities;
+        let rule_severities = rule_severities.into_iter().map(Rc::new).collect::<Vec<_>>();
+
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_type_checker(self.type_checker.clone())
+                .with_suggestion_choices(self.suggestion_choices.clone()),
+        );
+        for diagnostic in directive_diagnostics {
+            ctx_host.push_diagnostic(Message::new(diagnostic, PossibleFixes::None));
+        }
 
-        let rules = rules
-            .iter()
-            .filter(|(rule, _)| rule.should_run(&ctx_host))
-            .map(|(rule, severity)| (rule, Rc::clone(&ctx_host).spawn(rule, *severity)));
+        let rules =
+            rules.iter().enumerate().filter(|(_, (rule, _))| rule.should_run(&ctx_host)).map(
+                |(i, (rule, severity))| {
+                    let ctx = Rc::clone(&ctx_host).spawn(rule, *severity);
+                    let ctx = match rule_severities.get(i) {
+                        Some(ranges) => ctx.with_severity_ranges(Rc::clone(ranges)),
+                        None => ctx,
+                    };
+                    (rule, ctx)
+                },
+            );
 
         let semantic = ctx_host.semantic();
 
@@ -162,59 +290,74 @@ impl Linter {
         // don't thrash the cache too much. Feel free to tweak based on benchmarking.
         //
         // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-        if semantic.nodes().len() > 200_000 {
+        let rule_timeout = self.options.rule_timeout;
+        if let Some(timings) = &self.timings {
+            // Rules must run one after another to be measured.
+            let mut file_timings = vec![];
+            for (rule, ref ctx) in rules {
+                let start = Instant::now();
+                let start_allocations = allocations();
+                Self::run_rule(rule, ctx, semantic, should_run_on_jest_node, rule_timeout);
+                let timing = RuleTiming {
+                    duration: start.elapsed(),
+                    allocations: allocations() - start_allocations,
+                };
+                file_timings.push((rule, timing));
+            }
+            timings.record(&file_timings);
+        } else if semantic.nodes().len() > 200_000 {
             // Collect rules into a Vec so that we can iterate over the rules multiple times
             let rules = rules.collect::<Vec<_>>();
 
-            for (rule, ctx) in &rules {
-                rule.run_once(ctx);
-            }
-
-            for symbol in semantic.scoping().symbol_ids() {
+            if let Some(timeout) = rule_timeout {
+                Self::run_rules_with_timeout(&rules, semantic, should_run_on_jest_node, timeout);
+            } else {
                 for (rule, ctx) in &rules {
-                    rule.run_on_symbol(symbol, ctx);
+                    rule.run_once(ctx);
                 }
-            }
-
-            for node in semantic.nodes() {
-                for (rule, ctx) in &rules {
-                    rule.run(node, ctx);
-                }
-            }
 
-            if should_run_on_jest_node {
-                for jest_node in iter_possible_jest_call_node(semantic) {
+                for symbol in semantic.scoping().symbol_ids() {
                     for (rule, ctx) in &rules {
-                        rule.run_on_jest_node(&jest_node, ctx);
+                        rule.run_on_symbol(symbol, ctx);
                     }
                 }
-            }
-        } else {
-            for (rule, ref ctx) in rules {
-                rule.run_once(ctx);
-
-                for symbol in semantic.scoping().symbol_ids() {
-                    rule.run_on_symbol(symbol, ctx);
-                }
 
                 for node in semantic.nodes() {
-                    rule.run(node, ctx);
+                    for (rule, ctx) in &rules {
+                        rule.run(node, ctx);
+                    }
                 }
 
                 if should_run_on_jest_node {
                     for jest_node in iter_possible_jest_call_node(semantic) {
-                        rule.run_on_jest_node(&jest_node, ctx);
+                        for (rule, ctx) in &rules {
+                            rule.run_on_jest_node(&jest_node, ctx);
+                        }
                     }
                 }
             }
+        } else {
+            for (rule, ref ctx) in rules {
+                Self::run_rule(rule, ctx, semantic, should_run_on_jest_node, rule_timeout);
+            }
         }
 
+        #[cfg(feature = "wasm_plugins")]
+        self.run_wasm_rules(&external_rules, &external_severities, path, semantic, &ctx_host);
+
         #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
-        self.run_external_rules(&external_rules, path, semantic, &ctx_host, allocator);
+        self.run_external_rules(
+            &external_rules,
+            &external_severities,
+            path,
+            semantic,
+            &ctx_host,
+            allocator,
+        );
 
         // Stop clippy complaining about unused vars
         #[cfg(not(all(feature = "oxlint2", not(feature = "disable_oxlint2"))))]
-        let (_, _) = (external_rules, allocator);
+        let (_, _, _) = (external_rules, external_severities, allocator);
 
         if let Some(severity) = self.options.report_unused_directive {
             if severity.is_warn_deny() {
@@ -225,10 +368,124 @@ impl Linter {
         ctx_host.take_diagnostics()
     }
 
+    /// Run `rule` on the whole file. With `timeout`, stop running it after `timeout` and report
+    /// that it was skipped.
+    fn run_rule<'a>(
+        rule: &RuleEnum,
+        ctx: &LintContext<'a>,
+        semantic: &Semantic<'a>,
+        should_run_on_jest_node: bool,
+        timeout: Option<Duration>,
+    ) {
+        let start = Instant::now();
+        let mut visited = 0usize;
+        // Reading the clock for every node is too slow, check it every few nodes. `span` is where
+        // the rule stopped.
+        let mut timed_out = |span: Span| {
+            let Some(timeout) = timeout else { return false };
+            visited += 1;
+            if visited % 256 != 1 || start.elapsed() < timeout {
+                return false;
+            }
+            ctx.diagnostic(rule_timeout_diagnostic(rule, timeout, span));
+            true
+        };
+
+        rule.run_once(ctx);
+        if timed_out(Span::empty(0)) {
+            return;
+        }
+
+        for symbol in semantic.scoping().symbol_ids() {
+            rule.run_on_symbol(symbol, ctx);
+            if timed_out(semantic.scoping().symbol_span(symbol)) {
+                return;
+            }
+        }
+
+        for node in semantic.nodes() {
+            rule.run(node, ctx);
+            if timed_out(node.span()) {
+                return;
+            }
+        }
+
+        if should_run_on_jest_node {
+            for jest_node in iter_possible_jest_call_node(semantic) {
+                rule.run_on_jest_node(&jest_node, ctx);
+                if timed_out(jest_node.node.span()) {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Run `rules` on a large file with the rules in the inner loop, like without a timeout, but
+    /// over chunks of nodes instead of single nodes, so the clock is only read once per rule and
+    /// chunk. Each rule is stopped after it ran for `timeout` in total.
+    fn run_rules_with_timeout<'a>(
+        rules: &[(&RuleEnum, LintContext<'a>)],
+        semantic: &Semantic<'a>,
+        should_run_on_jest_node: bool,
+        timeout: Duration,
+    ) {
+        const CHUNK_SIZE: usize = 256;
+
+        let mut elapsed = vec![Duration::ZERO; rules.len()];
+        // Run `run` for each rule which has not timed out yet. `span` is where the rules stopped.
+        let mut run_rules = |span: Span, run: &dyn Fn(&RuleEnum, &LintContext<'a>)| {
+            for ((rule, ctx), elapsed) in rules.iter().zip(&mut elapsed) {
+                if *elapsed >= timeout {
+                    continue;
+                }
+                let start = Instant::now();
+                run(rule, ctx);
+                *elapsed += start.elapsed();
+                if *elapsed >= timeout {
+                    ctx.diagnostic(rule_timeout_diagnostic(rule, timeout, span));
+                }
+            }
+        };
+
+        run_rules(Span::empty(0), &|rule: &RuleEnum, ctx: &LintContext<'a>| rule.run_once(ctx));
+
+        let symbols = semantic.scoping().symbol_ids().collect::<Vec<_>>();
+        for chunk in symbols.chunks(CHUNK_SIZE) {
+            let span = semantic.scoping().symbol_span(chunk[chunk.len() - 1]);
+            run_rules(span, &|rule: &RuleEnum, ctx: &LintContext<'a>| {
+                for &symbol in chunk {
+                    rule.run_on_symbol(symbol, ctx);
+                }
+            });
+        }
+
+        for chunk in semantic.nodes().into_iter().as_slice().chunks(CHUNK_SIZE) {
+            let span = chunk[chunk.len() - 1].span();
+            run_rules(span, &|rule: &RuleEnum, ctx: &LintContext<'a>| {
+                for node in chunk {
+                    rule.run(node, ctx);
+                }
+            });
+        }
+
+        if should_run_on_jest_node {
+            let jest_nodes = iter_possible_jest_call_node(semantic).collect::<Vec<_>>();
+            for chunk in jest_nodes.chunks(CHUNK_SIZE) {
+                let span = chunk[chunk.len() - 1].node.span();
+                run_rules(span, &|rule: &RuleEnum, ctx: &LintContext<'a>| {
+                    for jest_node in chunk {
+                        rule.run_on_jest_node(jest_node, ctx);
+                    }
+                });
+            }
+        }
+    }
+
     #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
     fn run_external_rules(
         &self,
         external_rules: &[(ExternalRuleId, AllowWarnDeny)],
+        external_severities: &FxHashMap<ExternalRuleId, SeverityRanges>,
         path: &Path,
         semantic: &Semantic<'_>,
         ctx_host: &ContextHost,
@@ -236,11 +493,14 @@ impl Linter {
     ) {
         use std::ptr;
 
-        use oxc_diagnostics::OxcDiagnostic;
-        use oxc_span::Span;
-
-        use crate::fixer::PossibleFixes;
-
+        let store = self.config.external_plugin_store();
+        // Rules of WASM plugins are run by `run_wasm_rules`
+        #[cfg(feature = "wasm_plugins")]
+        let external_rules = &external_rules
+            .iter()
+            .filter(|(rule_id, _)| store.wasm_plugin(*rule_id).is_none())
+            .copied()
+            .collect::<Vec<_>>();
         if external_rules.is_empty() {
             return;
         }
@@ -261,31 +521,199 @@ impl Linter {
         // Pass AST and rule IDs to JS
         let result = (external_linter.lint_file)(
             path.to_str().unwrap().to_string(),
-            external_rules.iter().map(|(rule_id, _)| rule_id.raw()).collect(),
+            external_rules.iter().map(|(rule_id, _)| store.rule_index(*rule_id)).collect(),
             allocator,
         );
         match result {
             Ok(diagnostics) => {
-                for diagnostic in diagnostics {
-                    let (external_rule_id, severity) =
-                        external_rules[diagnostic.rule_index as usize];
-                    let (plugin_name, rule_name) =
-                        self.config.resolve_plugin_rule_names(external_rule_id);
-
-                    ctx_host.push_diagnostic(Message::new(
-                        OxcDiagnostic::error(diagnostic.message)
-                            .with_label(Span::new(diagnostic.loc.start, diagnostic.loc.end))
-                            .with_error_code(plugin_name.to_string(), rule_name.to_string())
-                            .with_severity(severity.into()),
-                        PossibleFixes::None,
-                    ));
-                }
+                self.report_external_diagnostics(
+                    diagnostics,
+                    external_rules,
+                    external_severities,
+                    semantic.source_text(),
+                    ctx_host,
+                );
             }
             Err(_err) => {
                 // TODO: report diagnostic
             }
         }
     }
+
+    /// Run the rules of WASM plugins. Each plugin is called once per file, with all of its rules.
+    #[cfg(feature = "wasm_plugins")]
+    fn run_wasm_rules(
+        &self,
+        external_rules: &[(ExternalRuleId, AllowWarnDeny)],
+        external_severities: &FxHashMap<ExternalRuleId, SeverityRanges>,
+        path: &Path,
+        semantic: &Semantic<'_>,
+        ctx_host: &ContextHost,
+    ) {
+        use std::fmt::Write;
+
+        use oxc_diagnostics::OxcDiagnostic;
+
+        let store = self.config.external_plugin_store();
+        let mut plugins: Vec<(&Arc<WasmPlugin>, Vec<_>)> = vec![];
+        for &(rule_id, severity) in external_rules {
+            let Some(plugin) = store.wasm_plugin(rule_id) else { continue };
+            match plugins.iter_mut().find(|(p, _)| Arc::ptr_eq(p, plugin)) {
+                Some((_, rules)) => rules.push((rule_id, severity)),
+                None => plugins.push((plugin, vec![(rule_id, severity)])),
+            }
+        }
+        if plugins.is_empty() {
+            return;
+        }
+
+        // The AST is serialized once, and shared by all plugins
+        let program = semantic.nodes().program();
+        let program_json = if program.source_type.is_typescript() {
+            program.to_estree_ts_json(false)
+        } else {
+            program.to_estree_js_json(false)
+        };
+        let filename = serde_json::to_string(&path.to_string_lossy()).unwrap();
+        let source_text = serde_json::to_string(semantic.source_text()).unwrap();
+
+        for (plugin, rules) in plugins {
+            let mut input =
+                format!(r#"{{"filename":{filename},"sourceText":{source_text},"rules":["#);
+            for (i, (rule_id, _)) in rules.iter().enumerate() {
+                let separator = if i == 0 { "" } else { "," };
+                write!(input, "{separator}{}", store.rule_index(*rule_id)).unwrap();
+            }
+            write!(input, r#"],"program":{program_json}}}"#).unwrap();
+
+            match plugin.lint(&input) {
+                Ok(diagnostics) => self.report_external_diagnostics(
+                    diagnostics,
+                    &rules,
+                    external_severities,
+                    semantic.source_text(),
+                    ctx_host,
+                ),
+                Err(err) => ctx_host.push_diagnostic(Message::new(
+                    OxcDiagnostic::error(format!(
+                        "Failed to run plugin `{}`: {err}",
+                        plugin.name()
+                    )),
+                    PossibleFixes::None,
+                )),
+            }
+        }
+    }
+
+    /// Report the diagnostics of JS or WASM plugins.
+    /// `rule_index` of each diagnostic is its index in `external_rules`. Rules configured by inline
+    /// configuration comments have their severity at the diagnostic in `external_severities`.
+    #[cfg(any(
+        all(feature = "oxlint2", not(feature = "disable_oxlint2")),
+        feature = "wasm_plugins"
+    ))]
+    fn report_external_diagnostics(
+        &self,
+        diagnostics: Vec<LintFileResult>,
+        external_rules: &[(ExternalRuleId, AllowWarnDeny)],
+        external_severities: &FxHashMap<ExternalRuleId, SeverityRanges>,
+        source_text: &str,
+        ctx_host: &ContextHost,
+    ) {
+        use oxc_diagnostics::OxcDiagnostic;
+
+        use crate::fixer::FixKind;
+
+        for diagnostic in diagnostics {
+            let Some(&(external_rule_id, severity)) =
+                external_rules.get(diagnostic.rule_index as usize)
+            else {
+                continue;
+            };
+            let span = Span::new(diagnostic.loc.start, diagnostic.loc.end);
+            let severity = match external_severities.get(&external_rule_id) {
+                Some(ranges) => match ranges.at(span.start) {
+                    AllowWarnDeny::Allow => continue,
+                    severity => severity,
+                },
+                None => severity,
+            };
+            let (plugin_name, rule_name) = self.config.resolve_plugin_rule_names(external_rule_id);
+
+            let fix = ctx_host
+                .can_apply_fix(FixKind::SafeFix)
+                .then(|| external_fix(source_text, diagnostic.fixes))
+                .flatten();
+            let suggestions = ctx_host
+                .can_apply_fix(FixKind::Suggestion)
+                .then(|| {
+                    diagnostic.suggestions.into_iter().filter_map(|suggestion| {
+                        external_fix(source_text, suggestion.fixes)
+                            .map(|fix| fix.with_message(suggestion.message))
+                    })
+                })
+                .into_iter()
+                .flatten();
+            let mut fixes = fix.into_iter().chain(suggestions).collect::<Vec<_>>();
+            let fixes = if fixes.len() > 1 {
+                PossibleFixes::Multiple(fixes)
+            } else {
+                fixes.pop().map_or(PossibleFixes::None, PossibleFixes::Single)
+            };
+
+            ctx_host.push_diagnostic(Message::new(
+                OxcDiagnostic::error(diagnostic.message)
+                    .with_label(span)
+                    .with_error_code(plugin_name.to_string(), rule_name.to_string())
+                    .with_severity(severity.into()),
+                fixes,
+            ));
+        }
+    }
+}
+
+fn rule_timeout_diagnostic(
+    rule: &RuleEnum,
+    timeout: Duration,
+    span: Span,
+) -> oxc_diagnostics::OxcDiagnostic {
+    oxc_diagnostics::OxcDiagnostic::warn(format!(
+        "Rule `{}/{}` was skipped on this file because it ran longer than {}ms",
+        rule.plugin_name(),
+        rule.name(),
+        timeout.as_millis()
+    ))
+    .with_help("Disable the rule for this file, or increase the rule timeout.")
+    .with_label(span.label("The rule was stopped here"))
+}
+
+/// Merge the edits of a fix reported by a JS or WASM plugin into a single [`Fix`].
+///
+/// Returns `None` if there are no edits, or they are out of bounds or overlap.
+#[cfg(any(all(feature = "oxlint2", not(feature = "disable_oxlint2")), feature = "wasm_plugins"))]
+fn external_fix(source_text: &str, mut edits: Vec<ExternalFix>) -> Option<fixer::Fix<'static>> {
+    use crate::fixer::{CompositeFix, Fix};
+
+    edits.sort_unstable_by_key(|edit| (edit.start, edit.end));
+    let mut last_end = 0;
+    for edit in &edits {
+        let is_valid = last_end <= edit.start
+            && edit.start <= edit.end
+            && source_text.is_char_boundary(edit.start as usize)
+            && source_text.is_char_boundary(edit.end as usize);
+        if !is_valid {
+            return None;
+        }
+        last_end = edit.end;
+    }
+    let fixes = edits
+        .into_iter()
+        .map(|edit| Fix::new(edit.text, Span::new(edit.start, edit.end)))
+        .collect::<Vec<_>>();
+    match CompositeFix::from(fixes) {
+        CompositeFix::None => None,
+        fix => Some(fix.normalize_fixes(source_text)),
+    }
 }
 
 #[cfg(all(feature = "oxlint2", not(feature = "disable_oxlint2")))]
@@ -0,0 +1,178 @@
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+/// Opening fence of a fenced code block, e.g. ```` ```ts ```` or `~~~js`.
+#[derive(Clone, Copy)]
+struct Fence {
+    marker: u8,
+    len: usize,
+}
+
+struct OpenCodeBlock {
+    fence: Fence,
+    /// `None` if the code block is not JavaScript or TypeScript.
+    source_type: Option<SourceType>,
+    content_start: usize,
+}
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// Extract the fenced code blocks of `.md` and `.mdx` files whose language is JavaScript or
+    /// TypeScript, i.e. `js`, `javascript`, `jsx`, `ts`, `typescript`, `tsx`, `mjs`, `cjs`, `mts`
+    /// or `cts`.
+    ///
+    /// Fences may be indented, e.g. in list items. Code blocks in block quotes are not extracted.
+    /// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut open_block: Option<OpenCodeBlock> = None;
+        let mut offset = 0;
+
+        for line in self.source_text.split_inclusive('\n') {
+            let line_start = offset;
+            offset += line.len();
+            match &open_block {
+                None => {
+                    open_block = parse_opening_fence(line).map(|(fence, info)| OpenCodeBlock {
+                        fence,
+                        source_type: info_source_type(info),
+                        content_start: offset,
+                    });
+                }
+                Some(block) if is_closing_fence(line, block.fence) => {
+                    results.extend(self.code_block(block, line_start));
+                    open_block = None;
+                }
+                Some(_) => {}
+            }
+        }
+
+        // A code block which is not closed ends at the end of the document
+        if let Some(block) = &open_block {
+            results.extend(self.code_block(block, self.source_text.len()));
+        }
+        results
+    }
+
+    fn code_block(&self, block: &OpenCodeBlock, end: usize) -> Option<JavaScriptSource<'a>> {
+        let source_type = block.source_type?;
+        // NOTE: loader checked that source_text.len() is less than u32::MAX
+        #[expect(clippy::cast_possible_truncation)]
+        Some(JavaScriptSource::partial(
+            &self.source_text[block.content_start..end],
+            source_type,
+            block.content_start as u32,
+        ))
+    }
+}
+
+/// Returns the fence and the info string of an opening code fence.
+fn parse_opening_fence(line: &str) -> Option<(Fence, &str)> {
+    let line = line.trim_start_matches([' ', '\t']);
+    let marker = *line.as_bytes().first()?;
+    if marker != b'`' && marker != b'~' {
+        return None;
+    }
+    let len = line.bytes().take_while(|&b| b == marker).count();
+    if len < 3 {
+        return None;
+    }
+    let info = line[len..].trim();
+    // The info string of a backtick fence can't contain backticks, e.g. ```` ```a``` ```` is inline code
+    if marker == b'`' && info.contains('`') {
+        return None;
+    }
+    Some((Fence { marker, len }, info))
+}
+
+fn is_closing_fence(line: &str, fence: Fence) -> bool {
+    let line = line.trim_start_matches([' ', '\t']);
+    let len = line.bytes().take_while(|&b| b == fence.marker).count();
+    len >= fence.len && line[len..].trim().is_empty()
+}
+
+/// Source type of the language of a code block, the first word of its info string,
+/// e.g. `ts` in ```` ```ts title="example.ts" ````.
+fn info_source_type(info: &str) -> Option<SourceType> {
+    let language = info.split(|c: char| c.is_whitespace() || c == '{').next()?;
+    let language = language.to_ascii_lowercase();
+    let extension = match language.as_str() {
+        "javascript" => "js",
+        "typescript" => "ts",
+        language => language,
+    };
+    SourceType::from_extension(extension).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = r#"# Title
+
+```js
+console.log("Hi");
+```
+
+```sh
+npm install
+```
+
+~~~ts title="example.ts"
+const a: number = 1;
+~~~
+"#;
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "console.log(\"Hi\");\n");
+        assert_eq!(sources[0].start, 15);
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "const a: number = 1;\n");
+        assert_eq!(sources[1].start, 87);
+        assert!(sources[1].source_type.is_typescript());
+    }
+
+    #[test]
+    fn test_parse_markdown_fences() {
+        let source_text = "
+- item
+
+  ````tsx
+  ```js
+  const a = <div />;
+  ````
+
+```javascript
+debugger;";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        // A shorter fence doesn't close the code block
+        assert_eq!(sources[0].source_text, "  ```js\n  const a = <div />;\n");
+        assert!(sources[0].source_type.is_jsx());
+        // A code block which is not closed ends at the end of the document
+        assert_eq!(sources[1].source_text, "debugger;");
+    }
+
+    #[test]
+    fn test_parse_markdown_without_code_blocks() {
+        assert!(parse_markdown("").is_empty());
+        assert!(parse_markdown("```js``` is inline code\n").is_empty());
+        assert!(parse_markdown("```\nplain\n```\n").is_empty());
+    }
+}
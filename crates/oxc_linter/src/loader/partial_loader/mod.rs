@@ -3,23 +3,35 @@ use oxc_span::VALID_EXTENSIONS;
 use crate::loader::JavaScriptSource;
 
 mod astro;
+mod markdown;
 mod svelte;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use markdown::MarkdownPartialLoader;
 pub use svelte::SveltePartialLoader;
 pub use vue::VuePartialLoader;
 
 const SCRIPT_START: &str = "<script";
 const SCRIPT_END: &str = "</script>";
 
+/// Extensions of framework files which contain JS/TS code in `<script>` tags or frontmatter.
+const FRAMEWORK_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+
+/// Extensions of Markdown files, whose JS/TS code blocks can be linted.
+///
+/// They are not in [`LINTABLE_EXTENSIONS`]: code blocks in documentation often show incorrect code
+/// on purpose, so Markdown files are only linted when asked for.
+pub const MARKDOWN_EXTENSIONS: &[&str] = &["md", "mdx"];
+
 /// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
 /// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] =
+    constcat::concat_slices!([&str]: FRAMEWORK_EXTENSIONS, MARKDOWN_EXTENSIONS);
 
 /// All valid JavaScript/TypeScript extensions, plus additional framework files that
 /// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
 pub const LINTABLE_EXTENSIONS: &[&str] =
-    constcat::concat_slices!([&str]: VALID_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS);
+    constcat::concat_slices!([&str]: VALID_EXTENSIONS, FRAMEWORK_EXTENSIONS);
 
 pub struct PartialLoader;
 
@@ -31,6 +43,7 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "md" | "mdx" => Some(MarkdownPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
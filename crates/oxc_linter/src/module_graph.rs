@@ -0,0 +1,107 @@
+//! Project-wide analysis of the module graph, for rules which need to know how a module is used
+//! by the other modules, e.g. `import/no-unused-modules`.
+
+use std::{path::PathBuf, sync::Arc};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_span::CompactStr;
+
+use crate::module_record::{ExportImportName, ImportImportName, ModuleRecord};
+
+/// The names imported from a module by the other modules of the module graph.
+#[derive(Debug, Default, Clone)]
+pub struct ImportedNames {
+    /// The module is imported by at least one other module, even if only for its side effects.
+    pub is_imported: bool,
+    /// All exports may be used, e.g. by `import * as ns` or `export * from`.
+    pub all: bool,
+    /// The names of the exports imported or re-exported by other modules.
+    /// The default export is named `default`.
+    pub names: FxHashSet<CompactStr>,
+}
+
+impl ImportedNames {
+    /// Whether the export named `name` is imported by another module.
+    pub fn contains(&self, name: &str) -> bool {
+        self.all || self.names.contains(name)
+    }
+}
+
+/// Collect the names imported from each module in `module_records`, and store them in
+/// [`ModuleRecord::imported_names`].
+///
+/// `module_records` must contain the whole module graph with its `loaded_modules` populated,
+/// otherwise imports from the missing modules are not found.
+pub fn link_imported_names<'a>(
+    module_records: impl Iterator<Item = &'a Arc<ModuleRecord>> + Clone,
+) {
+    let mut imported_names = FxHashMap::<PathBuf, ImportedNames>::default();
+
+    for record in module_records.clone() {
+        let loaded_modules = record.loaded_modules.read().unwrap();
+        for (specifier, loaded_module) in loaded_modules.iter() {
+            let path = &loaded_module.resolved_absolute_path;
+            if *path == record.resolved_absolute_path {
+                continue;
+            }
+            let names = imported_names.entry(path.clone()).or_default();
+            names.is_imported = true;
+            mark_imported_names(record, specifier, names);
+        }
+    }
+
+    for record in module_records {
+        let names = imported_names.get(&record.resolved_absolute_path);
+        // Sections of the same file share their imports, so they may be linked already.
+        let _ = record.imported_names.set(names.cloned().unwrap_or_default());
+    }
+}
+
+/// Mark the names imported by `record` from the module requested with `specifier`.
+fn mark_imported_names(record: &ModuleRecord, specifier: &str, names: &mut ImportedNames) {
+    let mut is_referenced = false;
+
+    for entry in
+        record.import_entries.iter().filter(|entry| entry.module_request.name() == specifier)
+    {
+        is_referenced = true;
+        match &entry.import_name {
+            ImportImportName::Name(name) => {
+                names.names.insert(name.name.clone());
+            }
+            ImportImportName::Default(_) => {
+                names.names.insert(CompactStr::new("default"));
+            }
+            ImportImportName::NamespaceObject => names.all = true,
+        }
+    }
+
+    for entry in record
+        .indirect_export_entries
+        .iter()
+        .chain(&record.star_export_entries)
+        .filter(|entry| entry.module_request.as_ref().is_some_and(|r| r.name() == specifier))
+    {
+        is_referenced = true;
+        match &entry.import_name {
+            ExportImportName::Name(name) => {
+                names.names.insert(name.name.clone());
+            }
+            ExportImportName::All | ExportImportName::AllButDefault => names.all = true,
+            ExportImportName::Null => {}
+        }
+    }
+
+    // Requested without any import or export entry: `import "./module"` only imports the module
+    // for its side effects, the exports used by other kinds of requests can't be known.
+    if !is_referenced {
+        let is_side_effect_import = record
+            .requested_modules
+            .get(specifier)
+            .is_some_and(|requests| requests.iter().all(|request| request.is_import));
+        if !is_side_effect_import {
+            names.all = true;
+        }
+    }
+}
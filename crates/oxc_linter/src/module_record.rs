@@ -12,6 +12,8 @@ use oxc_semantic::Semantic;
 use oxc_span::{CompactStr, Span};
 pub use oxc_syntax::module_record::RequestedModule;
 
+use crate::module_graph::ImportedNames;
+
 /// ESM Module Record
 ///
 /// All data inside this data structure are for ESM, no commonjs data is allowed.
@@ -83,6 +85,12 @@ pub struct ModuleRecord {
     /// `export default name`
     ///         ^^^^^^^ span
     pub export_default: Option<Span>,
+
+    /// The names imported from this module by the other modules of the module graph.
+    ///
+    /// Only set when the whole module graph is built before linting, see
+    /// [`link_imported_names`](crate::module_graph::link_imported_names).
+    pub imported_names: OnceLock<ImportedNames>,
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -109,6 +117,7 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings", &self.exported_bindings)
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
+            .field("imported_names", &self.imported_names)
             .finish()
     }
 }
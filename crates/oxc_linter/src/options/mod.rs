@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{FrameworkFlags, fixer::FixKind};
 
 mod allow_warn_deny;
@@ -13,4 +15,8 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Show a preview of the fixes of diagnostics in their help, see [`crate::Linter::with_fix_preview`].
+    pub fix_preview: bool,
+    /// Time after which a rule stops running on a file, see [`crate::Linter::with_rule_timeout`].
+    pub rule_timeout: Option<Duration>,
 }
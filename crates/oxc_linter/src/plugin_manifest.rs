@@ -0,0 +1,312 @@
+use std::fmt;
+
+use rustc_hash::FxHashSet;
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+
+/// Version of oxlint which plugins are checked against.
+pub const OXLINT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Manifest of a plugin, declared in the `oxlint` field of the `package.json` of the plugin.
+///
+/// Plugins without a manifest are loaded without any checks.
+///
+/// ```json
+/// {
+///   "name": "eslint-plugin-foo",
+///   "version": "1.2.0",
+///   "oxlint": {
+///     "name": "foo",
+///     "oxlintVersion": "^1.9.0",
+///     "rules": ["no-foo", "no-bar"]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginManifest {
+    /// Name of the plugin, which prefixes its rules in configuration files.
+    pub name: String,
+    /// Version of the plugin. Defaults to the `version` of the `package.json`.
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Range of oxlint versions which the plugin supports, in the syntax of npm,
+    /// e.g. `^1.9.0` or `>=1.9.0 <3 || 4.x`.
+    #[serde(default)]
+    pub oxlint_version: Option<String>,
+    /// Names of the rules which the plugin provides, without the name of the plugin.
+    pub rules: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct PackageJson {
+    version: Option<String>,
+    oxlint: Option<PluginManifest>,
+}
+
+impl PluginManifest {
+    /// Read the manifest from the content of a `package.json`.
+    /// Returns `Ok(None)` if it has no `oxlint` field.
+    ///
+    /// # Errors
+    /// When the `package.json` is not valid JSON, or the manifest is invalid.
+    pub fn from_package_json(content: &str) -> Result<Option<Self>, PluginManifestError> {
+        let package_json: PackageJson = serde_json::from_str(content)
+            .map_err(|err| PluginManifestError::InvalidManifest(err.to_string()))?;
+        Ok(package_json.oxlint.map(|mut manifest| {
+            if manifest.version.is_none() {
+                manifest.version = package_json.version;
+            }
+            manifest
+        }))
+    }
+
+    /// Check that the plugin supports `oxlint_version`, before the plugin is loaded.
+    ///
+    /// # Errors
+    /// When the version range of the manifest is invalid or does not contain `oxlint_version`.
+    pub fn check_oxlint_version(&self, oxlint_version: &str) -> Result<(), PluginManifestError> {
+        let Some(range) = &self.oxlint_version else {
+            return Ok(());
+        };
+        let invalid_range = |error: String| PluginManifestError::InvalidVersionRange {
+            plugin: self.display_name(),
+            range: range.clone(),
+            error,
+        };
+        let version =
+            Version::parse(oxlint_version).map_err(|err| invalid_range(err.to_string()))?;
+        let alternatives =
+            parse_version_range(range).map_err(|err| invalid_range(err.to_string()))?;
+        if alternatives.iter().any(|req| req.matches(&version)) {
+            Ok(())
+        } else {
+            Err(PluginManifestError::IncompatibleOxlintVersion {
+                plugin: self.display_name(),
+                range: range.clone(),
+                oxlint_version: oxlint_version.to_string(),
+            })
+        }
+    }
+
+    /// Check that the loaded plugin has the name and the rules declared in the manifest.
+    ///
+    /// # Errors
+    /// When the name differs, or a rule is missing or not declared.
+    pub fn check_loaded(
+        &self,
+        name: &str,
+        rule_names: &[String],
+    ) -> Result<(), PluginManifestError> {
+        if name != self.name {
+            return Err(PluginManifestError::NameMismatch {
+                declared: self.name.clone(),
+                actual: name.to_string(),
+            });
+        }
+
+        let provided = rule_names.iter().map(String::as_str).collect::<FxHashSet<_>>();
+        let declared = self.rules.iter().map(String::as_str).collect::<FxHashSet<_>>();
+        let mut missing = self
+            .rules
+            .iter()
+            .filter(|rule| !provided.contains(rule.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            return Err(PluginManifestError::MissingRules {
+                plugin: self.display_name(),
+                rules: missing,
+            });
+        }
+        let mut undeclared = rule_names
+            .iter()
+            .filter(|rule| !declared.contains(rule.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !undeclared.is_empty() {
+            undeclared.sort_unstable();
+            return Err(PluginManifestError::UndeclaredRules {
+                plugin: self.display_name(),
+                rules: undeclared,
+            });
+        }
+        Ok(())
+    }
+
+    /// `name@version`, or `name` without a version.
+    fn display_name(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}@{version}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Parse a version range in the syntax of npm, where comparators are separated by whitespace
+/// and alternatives by `||`, into the requirements of its alternatives.
+fn parse_version_range(range: &str) -> Result<Vec<VersionReq>, semver::Error> {
+    range
+        .split("||")
+        .map(|alternative| {
+            // `>= 1.0.0 < 2` -> `>=1.0.0, <2`
+            let mut comparators: Vec<String> = vec![];
+            for token in alternative.split_whitespace() {
+                match comparators.last_mut() {
+                    Some(last)
+                        if last.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) =>
+                    {
+                        last.push_str(token);
+                    }
+                    _ => comparators.push(token.to_string()),
+                }
+            }
+            if comparators.is_empty() {
+                return Ok(VersionReq::STAR);
+            }
+            VersionReq::parse(&comparators.join(", "))
+        })
+        .collect()
+}
+
+/// A plugin which does not match its [`PluginManifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginManifestError {
+    /// The `package.json` or its `oxlint` field could not be parsed.
+    InvalidManifest(String),
+    InvalidVersionRange {
+        plugin: String,
+        range: String,
+        error: String,
+    },
+    IncompatibleOxlintVersion {
+        plugin: String,
+        range: String,
+        oxlint_version: String,
+    },
+    NameMismatch {
+        declared: String,
+        actual: String,
+    },
+    /// Rules which are declared in the manifest, but not provided by the plugin.
+    MissingRules {
+        plugin: String,
+        rules: Vec<String>,
+    },
+    /// Rules which are provided by the plugin, but not declared in the manifest.
+    UndeclaredRules {
+        plugin: String,
+        rules: Vec<String>,
+    },
+}
+
+impl fmt::Display for PluginManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidManifest(error) => write!(f, "Invalid plugin manifest: {error}"),
+            Self::InvalidVersionRange { plugin, range, error } => {
+                write!(f, "Plugin {plugin} has an invalid `oxlintVersion` range `{range}`: {error}")
+            }
+            Self::IncompatibleOxlintVersion { plugin, range, oxlint_version } => write!(
+                f,
+                "Plugin {plugin} requires oxlint `{range}`, but this is oxlint {oxlint_version}. Update oxlint, or use a version of the plugin which supports oxlint {oxlint_version}."
+            ),
+            Self::NameMismatch { declared, actual } => {
+                write!(f, "Plugin is named `{actual}`, but its manifest declares `{declared}`")
+            }
+            Self::MissingRules { plugin, rules } => write!(
+                f,
+                "Plugin {plugin} does not provide the rules declared in its manifest: {}",
+                rules.join(", ")
+            ),
+            Self::UndeclaredRules { plugin, rules } => write!(
+                f,
+                "Plugin {plugin} provides rules which are not declared in its manifest: {}",
+                rules.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PluginManifestError {}
+
+#[cfg(test)]
+mod test {
+    use super::{PluginManifest, PluginManifestError};
+
+    fn manifest(oxlint_version: &str) -> PluginManifest {
+        PluginManifest::from_package_json(&format!(
+            r#"{{
+                "name": "eslint-plugin-foo",
+                "version": "1.2.0",
+                "oxlint": {{ "name": "foo", "oxlintVersion": "{oxlint_version}", "rules": ["no-foo", "no-bar"] }}
+            }}"#
+        ))
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn from_package_json() {
+        let manifest = manifest("^1.9.0");
+        assert_eq!(manifest.name, "foo");
+        assert_eq!(manifest.version.as_deref(), Some("1.2.0"));
+        assert_eq!(manifest.rules, ["no-foo", "no-bar"]);
+
+        assert_eq!(PluginManifest::from_package_json(r#"{ "name": "foo" }"#), Ok(None));
+        assert!(matches!(
+            PluginManifest::from_package_json(r#"{ "oxlint": { "name": "foo" } }"#),
+            Err(PluginManifestError::InvalidManifest(_))
+        ));
+    }
+
+    #[test]
+    fn oxlint_version() {
+        for range in ["^1.9.0", ">=1.9.0 <2", ">= 1.0 < 2.0.0", "0.x || 1.x", "*", ""] {
+            assert_eq!(manifest(range).check_oxlint_version("1.9.0"), Ok(()), "{range}");
+        }
+        for range in ["^1.10.0", ">=2", "0.x || 2.x"] {
+            assert!(
+                matches!(
+                    manifest(range).check_oxlint_version("1.9.0"),
+                    Err(PluginManifestError::IncompatibleOxlintVersion { .. })
+                ),
+                "{range}"
+            );
+        }
+        assert!(matches!(
+            manifest("not a range").check_oxlint_version("1.9.0"),
+            Err(PluginManifestError::InvalidVersionRange { .. })
+        ));
+        assert_eq!(
+            manifest(">=2").check_oxlint_version("1.9.0").unwrap_err().to_string(),
+            "Plugin foo@1.2.0 requires oxlint `>=2`, but this is oxlint 1.9.0. Update oxlint, or use a version of the plugin which supports oxlint 1.9.0."
+        );
+    }
+
+    #[test]
+    fn check_loaded() {
+        let manifest = manifest("*");
+        let rules = |names: &[&str]| names.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(manifest.check_loaded("foo", &rules(&["no-bar", "no-foo"])), Ok(()));
+        assert!(matches!(
+            manifest.check_loaded("bar", &rules(&["no-foo", "no-bar"])),
+            Err(PluginManifestError::NameMismatch { .. })
+        ));
+        assert_eq!(
+            manifest.check_loaded("foo", &rules(&["no-foo"])),
+            Err(PluginManifestError::MissingRules {
+                plugin: "foo@1.2.0".to_string(),
+                rules: rules(&["no-bar"])
+            })
+        );
+        assert_eq!(
+            manifest.check_loaded("foo", &rules(&["no-foo", "no-bar", "no-baz"])),
+            Err(PluginManifestError::UndeclaredRules {
+                plugin: "foo@1.2.0".to_string(),
+                rules: rules(&["no-baz"])
+            })
+        );
+    }
+}
@@ -19,8 +19,17 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Check the eslint json configuration for invalid options, which
+    /// [`Rule::from_configuration`] ignores.
+    ///
+    /// # Errors
+    /// A description of why the configuration is invalid.
+    #[expect(unused_variables)]
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+
     #[expect(unused_variables)]
-    #[cfg(feature = "ruledocs")]
     fn schema(generator: &mut SchemaGenerator) -> Option<Schema> {
         None
     }
@@ -76,6 +85,9 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// Whether this rule is deprecated, and which rules replace it.
+    const DEPRECATED: Option<RuleDeprecation> = None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
@@ -86,6 +98,32 @@ pub trait RuleMeta {
     }
 }
 
+/// A rule which should no longer be used. Enabling it prints a warning, or an error with
+/// `--no-deprecated`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleDeprecation {
+    /// Rules replacing the deprecated rule, as `plugin/rule-name`.
+    pub replaced_by: &'static [&'static str],
+}
+
+impl fmt::Display for RuleDeprecation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deprecated")?;
+        for (i, rule) in self.replaced_by.iter().enumerate() {
+            f.write_str(match i {
+                0 => ", use ",
+                _ if i + 1 == self.replaced_by.len() => " or ",
+                _ => ", ",
+            })?;
+            write!(f, "`{rule}`")?;
+        }
+        if !self.replaced_by.is_empty() {
+            f.write_str(" instead")?;
+        }
+        Ok(())
+    }
+}
+
 /// Rule categories defined by rust-clippy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
@@ -0,0 +1,290 @@
+use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Map, Value};
+
+use crate::{
+    AllowWarnDeny,
+    config::{ESLintRule, OxlintRules},
+};
+
+/// A rule configured by an inline configuration comment in a file, e.g. `no-console` in
+/// `/* eslint no-console: ["error", { "allow": ["warn"] }] */`.
+#[derive(Debug)]
+pub struct RuleDirective {
+    pub rule: ESLintRule,
+    /// Span of the name of the rule in the comment.
+    pub name_span: Span,
+    /// Offset from which the configuration applies, the end of the comment.
+    pub start: u32,
+}
+
+/// Severity of a rule in the parts of a file, as `(start, severity)` pairs sorted by `start`.
+/// Each severity applies from its `start` up to the next one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeverityRanges(Vec<(u32, AllowWarnDeny)>);
+
+impl SeverityRanges {
+    /// `severity` in the whole file.
+    pub fn new(severity: AllowWarnDeny) -> Self {
+        Self(vec![(0, severity)])
+    }
+
+    /// Change the severity from `start` to the end of the file. `start` must not be before the
+    /// start of the last change.
+    pub fn set(&mut self, start: u32, severity: AllowWarnDeny) {
+        match self.0.last_mut() {
+            Some(last) if last.0 == start => last.1 = severity,
+            _ => self.0.push((start, severity)),
+        }
+    }
+
+    /// The severity at `offset`.
+    pub fn at(&self, offset: u32) -> AllowWarnDeny {
+        let index = self.0.partition_point(|(start, _)| *start <= offset);
+        self.0[index.saturating_sub(1)].1
+    }
+
+    /// The most severe severity in any part of the file.
+    pub fn max(&self) -> AllowWarnDeny {
+        let has = |severity| self.0.iter().any(|(_, s)| *s == severity);
+        if has(AllowWarnDeny::Deny) {
+            AllowWarnDeny::Deny
+        } else if has(AllowWarnDeny::Warn) {
+            AllowWarnDeny::Warn
+        } else {
+            AllowWarnDeny::Allow
+        }
+    }
+}
+
+/// Collect rules configured by inline configuration comments of a file, `/* eslint ... */` or
+/// `/* oxlint ... */`, in the order they are configured.
+///
+/// As in ESLint, a comment is a list of rules and their configuration, as in the `rules` of a
+/// configuration file without the braces, e.g. `/* eslint eqeqeq: "off", curly: ["warn", "all"] */`.
+/// The configuration applies to the remainder of the file after the comment, on top of the
+/// configuration of the file, so a rule can be turned on or off for a part of a file.
+///
+/// Returns diagnostics for configurations which are invalid.
+pub fn parse_rule_directives(
+    source_text: &str,
+    comments: &[Comment],
+) -> (Vec<RuleDirective>, Vec<OxcDiagnostic>) {
+    let mut directives = vec![];
+    let mut diagnostics = vec![];
+    for comment in comments.iter().filter(|comment| comment.is_block()) {
+        let content_span = comment.content_span();
+        let text = content_span.source_text(source_text);
+        let trimmed = text.trim_start();
+        let Some(rules) = trimmed.strip_prefix("eslint").or_else(|| trimmed.strip_prefix("oxlint"))
+        else {
+            continue;
+        };
+        // Not `eslint-disable`, `eslint-env`, etc.
+        if !rules.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let offset = content_span.start + (text.len() - rules.len()) as u32;
+        for (start, entry) in split_entries(rules) {
+            if entry.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = entry.split_once(':').unwrap_or((entry, ""));
+            let name = key.trim().trim_matches(|c| c == '"' || c == '\'');
+            let name_start = offset + (start + key.find(name).unwrap_or_default()) as u32;
+            let name_span = Span::sized(name_start, name.len() as u32);
+            match parse_rule(name, value.trim()) {
+                Ok(rule) => {
+                    directives.push(RuleDirective { rule, name_span, start: comment.span.end });
+                }
+                Err(error) => diagnostics.push(
+                    OxcDiagnostic::error(format!(
+                        "Inline configuration for rule '{name}' is invalid: {error}"
+                    ))
+                    .with_label(name_span),
+                ),
+            }
+        }
+    }
+    (directives, diagnostics)
+}
+
+/// Parse the configuration of rule `name`, e.g. `"off"` or `["error", { "max": 2 }]`.
+fn parse_rule(name: &str, value: &str) -> Result<ESLintRule, String> {
+    if name.is_empty() || value.is_empty() {
+        return Err("expected `name: severity` or `name: [severity, ...options]`".to_string());
+    }
+    // A bare severity doesn't need to be quoted, e.g. `eqeqeq: off`
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    let rules: OxlintRules =
+        serde_json::from_value(Value::Object(Map::from_iter([(name.to_string(), value)])))
+            .map_err(|error| error.to_string())?;
+    rules.rules.into_iter().next().ok_or_else(|| "no rule is configured".to_string())
+}
+
+/// Split a list of rules at commas which are not in brackets or strings, with the offset of each
+/// entry in `text`. The description after ` --` is left out, e.g. in
+/// `/* eslint eqeqeq: "off" -- legacy code */`, unless ` --` is in a string.
+fn split_entries(text: &str) -> Vec<(usize, &str)> {
+    let mut entries = vec![];
+    let mut depth = 0u32;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    let mut end = text.len();
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push((start, &text[start..i]));
+                start = i + 1;
+            }
+            ' ' if text[i..].starts_with(" --") => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    entries.push((start, &text[start..end]));
+    entries
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use crate::AllowWarnDeny;
+
+    use super::{SeverityRanges, parse_rule_directives};
+
+    fn rules(source_text: &str) -> Vec<(String, AllowWarnDeny, Option<serde_json::Value>)> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let (directives, diagnostics) = parse_rule_directives(source_text, &ret.program.comments);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        directives
+            .into_iter()
+            .map(|directive| {
+                let rule = directive.rule;
+                (format!("{}/{}", rule.plugin_name, rule.rule_name), rule.severity, rule.config)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn severity() {
+        use AllowWarnDeny::{Allow, Deny, Warn};
+
+        assert_eq!(
+            rules(r#"/* eslint eqeqeq: "off", no-console: warn, curly: 2 */"#),
+            [
+                ("eslint/eqeqeq".to_string(), Allow, None),
+                ("eslint/no-console".to_string(), Warn, None),
+                ("eslint/curly".to_string(), Deny, None),
+            ]
+        );
+        assert_eq!(rules(r#"/*oxlint "no-debugger": "error"*/"#).len(), 1);
+        assert_eq!(rules("/* eslint\n  eqeqeq: off,\n  curly: off,\n*/").len(), 2);
+        assert_eq!(rules(r#"/* eslint eqeqeq: off -- legacy code, see #123 */"#).len(), 1);
+        assert_eq!(rules("/* eslint eqeqeq: off -- legacy code, curly: off */").len(), 1);
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(
+            rules(
+                r#"/* eslint no-console: ["error", { "allow": ["warn", "error"] }], curly: ["warn", "all"] */"#
+            ),
+            [
+                (
+                    "eslint/no-console".to_string(),
+                    AllowWarnDeny::Deny,
+                    Some(json!([{ "allow": ["warn", "error"] }]))
+                ),
+                ("eslint/curly".to_string(), AllowWarnDeny::Warn, Some(json!(["all"]))),
+            ]
+        );
+        assert_eq!(
+            rules(
+                r#"/* eslint no-restricted-syntax: ["error", "Identifier[name=' --']"] -- why */"#
+            ),
+            [(
+                "eslint/no-restricted-syntax".to_string(),
+                AllowWarnDeny::Deny,
+                Some(json!(["Identifier[name=' --']"]))
+            )]
+        );
+        assert_eq!(
+            rules(r#"/* eslint @typescript-eslint/no-explicit-any: ["warn", { "fixToUnknown": true }] */"#)[0].0,
+            "typescript/no-explicit-any"
+        );
+    }
+
+    #[test]
+    fn start() {
+        let source_text = "a;\n/* eslint eqeqeq: off */\nb;\n/* eslint eqeqeq: warn */";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let (directives, _) = parse_rule_directives(source_text, &ret.program.comments);
+        let starts = directives.iter().map(|directive| directive.start).collect::<Vec<_>>();
+        assert_eq!(starts, [27, 56]);
+    }
+
+    #[test]
+    fn severity_ranges() {
+        use AllowWarnDeny::{Allow, Deny, Warn};
+
+        let mut ranges = SeverityRanges::new(Warn);
+        ranges.set(10, Allow);
+        ranges.set(20, Deny);
+        ranges.set(20, Allow);
+        assert_eq!(
+            [0, 9, 10, 19, 20, 100].map(|offset| ranges.at(offset)),
+            [Warn, Warn, Allow, Allow, Allow, Allow]
+        );
+        assert_eq!(ranges.max(), Warn);
+        ranges.set(30, Deny);
+        assert_eq!(ranges.max(), Deny);
+        assert_eq!(SeverityRanges::new(Allow).max(), Allow);
+    }
+
+    #[test]
+    fn not_rule_directives() {
+        assert!(rules("/* eslint-disable eqeqeq */").is_empty());
+        assert!(rules("/* eslint-env node */").is_empty());
+        assert!(rules("/* oxlint-enable */").is_empty());
+        assert!(rules("// eslint eqeqeq: off").is_empty());
+        assert!(rules("/* eslintrc eqeqeq: off */").is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        let source_text = "/* eslint eqeqeq: sometimes, curly */";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let (directives, diagnostics) = parse_rule_directives(source_text, &ret.program.comments);
+        assert!(directives.is_empty());
+        let labels = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.labels.as_ref().unwrap()[0].offset())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, [10, 29]);
+    }
+}
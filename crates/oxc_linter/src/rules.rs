@@ -26,6 +26,7 @@ mod import {
     pub mod no_duplicates;
     pub mod no_dynamic_require;
     pub mod no_empty_named_blocks;
+    pub mod no_extraneous_dependencies;
     pub mod no_mutable_exports;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
@@ -33,6 +34,7 @@ mod import {
     pub mod no_namespace;
     pub mod no_self_import;
     pub mod no_unassigned_import;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
     pub mod prefer_default_export;
     pub mod unambiguous;
@@ -132,6 +134,7 @@ mod eslint {
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -207,6 +210,7 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_inferrable_types;
     pub mod no_misused_new;
@@ -218,6 +222,7 @@ mod typescript {
     pub mod no_this_alias;
     pub mod no_unnecessary_parameter_property_assignment;
     pub mod no_unnecessary_type_constraint;
+    pub mod no_unsafe_argument;
     pub mod no_unsafe_declaration_merging;
     pub mod no_unsafe_function_type;
 
@@ -308,6 +313,7 @@ mod react {
     pub mod no_children_prop;
     pub mod no_danger;
     pub mod no_danger_with_children;
+    pub mod no_deprecated;
     pub mod no_direct_mutation_state;
     pub mod no_find_dom_node;
     pub mod no_is_mounted;
@@ -487,6 +493,7 @@ mod oxc {
     pub mod misrefactored_assign_op;
     pub mod missing_throw;
     pub mod no_accumulating_spread;
+    pub mod no_array_search_in_loop;
     pub mod no_async_await;
     pub mod no_async_endpoint_handlers;
     pub mod no_barrel_file;
@@ -526,11 +533,13 @@ mod nextjs {
 /// <https://github.com/gajus/eslint-plugin-jsdoc>
 mod jsdoc {
     pub mod check_access;
+    pub mod check_param_names;
     pub mod check_property_names;
     pub mod check_tag_names;
     pub mod empty_tags;
     pub mod implements_on_classes;
     pub mod no_defaults;
+    pub mod no_undefined_types;
     pub mod require_param;
     pub mod require_param_description;
     pub mod require_param_name;
@@ -540,12 +549,14 @@ mod jsdoc {
     pub mod require_property_name;
     pub mod require_property_type;
     pub mod require_returns;
+    pub mod require_returns_check;
     pub mod require_returns_description;
     pub mod require_returns_type;
     pub mod require_yields;
 }
 
 mod promise {
+    pub mod always_return;
     pub mod avoid_new;
     pub mod catch_or_return;
     pub mod no_callback_in_promise;
@@ -576,6 +587,15 @@ mod node {
     pub mod no_new_require;
 }
 
+mod security {
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_non_literal_fs_filename;
+    pub mod detect_unsafe_regex;
+    pub mod no_unsanitized_method;
+    pub mod no_unsanitized_property;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::arrow_body_style,
@@ -618,6 +638,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_nested_ternary,
     eslint::no_object_constructor,
     eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_unneeded_ternary,
     eslint::no_useless_backreference,
     eslint::no_useless_call,
@@ -732,6 +753,7 @@ oxc_macros::declare_all_lint_rules! {
     import::group_exports,
     import::no_unassigned_import,
     import::no_empty_named_blocks,
+    import::no_extraneous_dependencies,
     import::no_anonymous_default_export,
     import::no_absolute_path,
     import::no_mutable_exports,
@@ -749,6 +771,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
+    import::no_unused_modules,
     import::no_webpack_loader_syntax,
     import::prefer_default_export,
     import::unambiguous,
@@ -802,11 +825,13 @@ oxc_macros::declare_all_lint_rules! {
     jest::valid_expect,
     jest::valid_title,
     jsdoc::check_access,
+    jsdoc::check_param_names,
     jsdoc::check_property_names,
     jsdoc::check_tag_names,
     jsdoc::empty_tags,
     jsdoc::implements_on_classes,
     jsdoc::no_defaults,
+    jsdoc::no_undefined_types,
     jsdoc::require_param,
     jsdoc::require_param_description,
     jsdoc::require_param_name,
@@ -816,6 +841,7 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_property_name,
     jsdoc::require_property_type,
     jsdoc::require_returns,
+    jsdoc::require_returns_check,
     jsdoc::require_returns_description,
     jsdoc::require_returns_type,
     jsdoc::require_yields,
@@ -885,6 +911,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::misrefactored_assign_op,
     oxc::missing_throw,
     oxc::no_accumulating_spread,
+    oxc::no_array_search_in_loop,
     oxc::no_async_await,
     oxc::no_async_endpoint_handlers,
     oxc::no_barrel_file,
@@ -895,6 +922,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
+    promise::always_return,
     promise::avoid_new,
     promise::catch_or_return,
     promise::no_return_wrap,
@@ -931,6 +959,7 @@ oxc_macros::declare_all_lint_rules! {
     react::no_children_prop,
     react::no_danger_with_children,
     react::no_danger,
+    react::no_deprecated,
     react::no_direct_mutation_state,
     react::no_find_dom_node,
     react::no_is_mounted,
@@ -950,6 +979,12 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    security::detect_child_process,
+    security::detect_eval_with_expression,
+    security::detect_non_literal_fs_filename,
+    security::detect_unsafe_regex,
+    security::no_unsanitized_method,
+    security::no_unsanitized_property,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -970,6 +1005,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
     typescript::no_namespace,
@@ -980,6 +1016,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_this_alias,
     typescript::no_unnecessary_parameter_property_assignment,
     typescript::no_unnecessary_type_constraint,
+    typescript::no_unsafe_argument,
     typescript::no_unsafe_declaration_merging,
     typescript::no_unsafe_function_type,
     typescript::no_useless_empty_export,
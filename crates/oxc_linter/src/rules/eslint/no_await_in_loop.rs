@@ -9,7 +9,9 @@ use oxc_span::Span;
 use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_await_in_loop_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Unexpected `await` inside a loop.").with_label(span)
+    OxcDiagnostic::warn("Unexpected `await` inside a loop.")
+        .with_help("Start the async operations in the loop, and await them together with `Promise.all()` after it, unless each iteration depends on the previous one.")
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -41,7 +43,19 @@ declare_oxc_lint!(
     /// async function good() {
     ///     await Promise.all(users.map(user => getUserRecord(user)));
     /// }
+    ///
+    /// async function alsoGood() {
+    ///     const promises = [];
+    ///     for (const user of users) {
+    ///         promises.push(getUserRecord(user));
+    ///     }
+    ///     const userRecords = await Promise.all(promises);
+    /// }
     /// ```
+    ///
+    /// When each iteration depends on the result of the previous one, e.g. when retrying or
+    /// paginating, the operations can't be run in parallel, and the rule can be disabled for
+    /// that loop.
     NoAwaitInLoop,
     eslint,
     perf
@@ -112,7 +112,7 @@ fn test() {
                 }
             })),
         ),
-        // ("/*global a:true*/ a = 1", None),
+        ("/*global a:true*/ a = 1", None, None),
     ];
 
     let fail = vec![
@@ -147,9 +147,9 @@ fn test() {
                 }
             })),
         ),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ function f() { b = 1; }", None, None),
+        ("/*global b:false*/ function f() { b++; }", None, None),
+        ("/*global b*/ b = 1;", None, None),
         ("Array = 1;", None, None),
     ];
 
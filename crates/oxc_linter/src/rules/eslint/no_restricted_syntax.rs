@@ -0,0 +1,206 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule, selector::Selector};
+
+fn no_restricted_syntax_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message.to_string()).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    message: String,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matched by the configured selectors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. As a
+    /// result, some projects choose to disallow the use of certain language features altogether,
+    /// for example `try-catch` or `class`.
+    ///
+    /// Selectors use [esquery](https://github.com/estools/esquery) syntax and
+    /// [ESTree](https://github.com/estree/estree) node types, as in ESLint. Node types, the
+    /// wildcard `*`, attributes (`[callee.name="eval"]`, `[value=/^foo/i]`, `[arguments.length>1]`),
+    /// descendant and child combinators, selector lists, `:not()`, `:matches()`, `:is()` and the
+    /// `:statement`, `:expression`, `:declaration`, `:function` and `:pattern` classes are
+    /// supported. Sibling combinators (`~` and `+`) are not supported; invalid or unsupported
+    /// selectors are reported as configuration errors.
+    ///
+    /// ### Options
+    ///
+    /// An array of selectors, or objects with a `selector` and a custom `message`:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///   "error",
+    ///   "WithStatement",
+    ///   { "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]", "message": "setTimeout must always be invoked with two arguments." }
+    /// ]
+    /// ```
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the options above:
+    /// ```js
+    /// with (me) {
+    ///   dontMess();
+    /// }
+    ///
+    /// setTimeout(function () {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the options above:
+    /// ```js
+    /// me.dontMess();
+    ///
+    /// setTimeout(function () {}, 100);
+    /// ```
+    NoRestrictedSyntax,
+    eslint,
+    restriction,
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let Value::Array(options) = value else {
+            return Self::default();
+        };
+        let restrictions = options
+            .iter()
+            .filter_map(|option| {
+                let (selector, message) = match option {
+                    // "no-restricted-syntax": ["error", "WithStatement"]
+                    Value::String(selector) => (selector.as_str(), None),
+                    // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                    Value::Object(obj) => (
+                        obj.get("selector").and_then(Value::as_str)?,
+                        obj.get("message").and_then(Value::as_str),
+                    ),
+                    _ => return None,
+                };
+                let message = message.map_or_else(
+                    || format!("Using '{selector}' is not allowed."),
+                    ToString::to_string,
+                );
+                Selector::parse(selector)
+                    .ok()
+                    .map(|selector| RestrictedSyntax { selector, message })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedSyntaxConfig { restrictions }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let Value::Array(options) = value else { return Ok(()) };
+        let selectors = options.iter().filter_map(|option| match option {
+            Value::String(selector) => Some(selector.as_str()),
+            Value::Object(obj) => obj.get("selector").and_then(Value::as_str),
+            _ => None,
+        });
+        for selector in selectors {
+            Selector::parse(selector)
+                .map_err(|err| format!("invalid selector `{selector}`: {err}"))?;
+        }
+        Ok(())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restriction in &self.0.restrictions {
+            if restriction.selector.matches(node, ctx.nodes()) {
+                ctx.diagnostic(no_restricted_syntax_diagnostic(
+                    &restriction.message,
+                    node.kind().span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "Property > Literal.key", "message": "..." }])),
+        ),
+        ("foo(1)", Some(json!(["CallExpression:not([arguments.length=1])"]))),
+        (
+            "setTimeout(f, 100)",
+            Some(json!(["CallExpression[callee.name=/^set/][arguments.length<2]"])),
+        ),
+        ("with (a) {}", Some(json!([42, { "message": "no selector" }]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voices(); } catch (e) { oho(); };",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{
+                "selector": "FunctionDeclaration[params.length>2]",
+                "message": "custom error message."
+            }])),
+        ),
+        ("with (me) { dontMess(); }", Some(json!(["WithStatement", "CallExpression"]))),
+        (
+            "setTimeout(function () {});",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+                "message": "setTimeout must always be invoked with two arguments."
+            }])),
+        ),
+        ("foo?.bar", Some(json!(["MemberExpression[optional=true]"]))),
+        ("a = /foo/iu", Some(json!(["Literal[regex.flags=/u/]"]))),
+        (
+            "const a = () => { eval('') }",
+            Some(json!([":function CallExpression[callee.name='eval']"])),
+        ),
+        ("a.b.c()", Some(json!(["CallExpression > MemberExpression.callee"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -63,7 +63,7 @@ impl Rule for NoUndef {
                     continue;
                 }
 
-                if ctx.globals().is_enabled(name) {
+                if ctx.is_global_enabled(name) {
                     continue;
                 }
 
@@ -93,16 +93,19 @@ fn test() {
 
     let pass = vec![
         ("var a = 1, b = 2; a;", None, None),
-        // "/*global b*/ function f() { b; }",
+        ("/*global b*/ function f() { b; }", None, None),
         ("function f() { b; }", None, Some(serde_json::json!({"globals": { "b": false }}))),
-        // "/*global b a:false*/  a;  function f() { b; a; }",
+        ("/*global b a:false*/  a;  function f() { b; a; }", None, None),
         ("function a(){}  a();", None, None),
         ("function f(b) { b; }", None, None),
         ("var a; a = 1; a++;", None, None),
         ("var a; function f() { a = 1; }", None, None),
-        // "/*global b:true*/ b++;",
-        // "/*eslint-env browser*/ window;",
-        // "/*eslint-env node*/ require(\"a\");",
+        ("/*global b:true*/ b++;", None, None),
+        ("/*eslint-env browser*/ window;", None, None),
+        ("/*eslint-env node*/ require(\"a\");", None, None),
+        ("/* eslint-env node, mocha */ require(\"a\"); describe();", None, None),
+        ("/// <reference lib=\"dom\" />\nwindow;", None, None),
+        ("/// <reference types=\"node\" />\nrequire(\"a\");", None, None),
         ("Object; isNaN();", None, None),
         (
             "function evilEval(stuffToEval) { var ultimateAnswer; ultimateAnswer = 42; eval(stuffToEval); }",
@@ -135,11 +138,11 @@ fn test() {
         ("PromiseRejectionEvent;", None, Some(serde_json::json!({"env": { "browser": true }}))),
         ("(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }", None, None),
         ("class C extends C {}", None, None),
-        // "/*global b:false*/ function f() { b = 1; }",
+        ("/*global b:false*/ function f() { b = 1; }", None, None),
         ("function f() { b = 1; }", None, Some(serde_json::json!({"globals": { "b": false } }))),
-        // "/*global b:false*/ function f() { b++; }",
-        // "/*global b*/ b = 1;",
-        // "/*global b:false*/ var b = 1;",
+        ("/*global b:false*/ function f() { b++; }", None, None),
+        ("/*global b*/ b = 1;", None, None),
+        ("/*global b:false*/ var b = 1;", None, None),
         ("Array = 1;", None, None),
         ("class A { constructor() { new.target; } }", None, None),
         (
@@ -180,6 +183,7 @@ fn test() {
         ("var a = b;", None, None),
         ("function f() { b; }", None, None),
         ("window;", None, None),
+        ("/*eslint-env node*/ window;", None, None),
         // ("Intl;", None, None), builtin
         ("require(\"a\");", None, None),
         ("var React; React.render(<img attr={a} />);", None, None),
@@ -129,6 +129,7 @@ impl NoUnusedExpressions {
             | Expression::BinaryExpression(_)
             | Expression::PrivateInExpression(_)
             | Expression::ThisExpression(_)
+            | Expression::TopicReference(_)
             | Expression::Identifier(_) => true,
             Expression::ChainExpression(chain_expression) => match &chain_expression.expression {
                 ChainElement::CallExpression(_) => false,
@@ -148,6 +149,9 @@ impl NoUnusedExpressions {
             | Expression::V8IntrinsicExpression(_)
             | Expression::UpdateExpression(_)
             | Expression::YieldExpression(_) => false,
+            Expression::PipelineExpression(pipeline_expression) => {
+                self.is_disallowed(&pipeline_expression.body)
+            }
             Expression::ConditionalExpression(conditional_expression) => {
                 if self.0.allow_ternary {
                     return self.is_disallowed(&conditional_expression.alternate)
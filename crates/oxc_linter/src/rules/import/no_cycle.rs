@@ -13,10 +13,10 @@ use crate::{
     rule::Rule,
 };
 
-fn no_cycle_diagnostic(span: Span, paths: &str) -> OxcDiagnostic {
+fn no_cycle_diagnostic(span: Span, specifier: &str, paths: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn("Dependency cycle detected")
         .with_help(format!("These paths form a cycle: \n{paths}"))
-        .with_label(span)
+        .with_label(span.label(format!("`{specifier}` leads back to this module")))
 }
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/v2.29.1/docs/rules/no-cycle.md>
@@ -85,6 +85,20 @@ declare_oxc_lint!(
     /// ```
     ///
     /// In this corrected version, `dep-b.js` no longer imports `dep-a.js`, breaking the cycle.
+    ///
+    /// ### Options
+    ///
+    /// - `maxDepth` (default `∞`): the maximum number of imports to follow to find a cycle.
+    /// - `ignoreExternal` (default `false`): do not follow imports into `node_modules`.
+    /// - `ignoreTypes` (default `true`): ignore type-only imports and re-exports.
+    /// - `allowUnsafeDynamicCyclicDependency` (default `false`): allow a cycle if it contains
+    ///   at least one dynamic import.
+    ///
+    /// ```json
+    /// {
+    ///     "import/no-cycle": ["error", { "maxDepth": 3, "ignoreExternal": true }]
+    /// }
+    /// ```
     NoCycle,
     import,
     restriction
@@ -123,6 +137,7 @@ impl Rule for NoCycle {
 
         let mut stack = Vec::new();
         let ignore_types = self.ignore_types;
+        let ignore_external = self.ignore_external;
         let visitor_result = ModuleGraphVisitorBuilder::default()
             .max_depth(self.max_depth)
             .filter(move |(key, val): (&CompactStr, &Arc<ModuleRecord>), parent: &ModuleRecord| {
@@ -132,7 +147,7 @@ impl Rule for NoCycle {
                     .components()
                     .any(|c| matches!(c, Component::Normal(p) if p == OsStr::new("node_modules")));
 
-                if is_node_module {
+                if ignore_external && is_node_module {
                     return false;
                 }
 
@@ -200,7 +215,8 @@ impl Rule for NoCycle {
             });
 
         if visitor_result.result {
-            let span = module_record.requested_modules[&stack[0].0][0].span;
+            let specifier = &stack[0].0;
+            let span = module_record.requested_modules[specifier][0].span;
             let help = stack
                 .iter()
                 .map(|(specifier, path)| {
@@ -214,7 +230,7 @@ impl Rule for NoCycle {
                 })
                 .collect::<Vec<_>>()
                 .join("\n");
-            ctx.diagnostic(no_cycle_diagnostic(span, &help));
+            ctx.diagnostic(no_cycle_diagnostic(span, specifier, &help));
         }
     }
 }
@@ -290,6 +306,7 @@ fn test() {
         // (r#"import { bar } from "./flow-typeof""#, None),
         (r#"import { foo } from "./typescript/ts-types-re-exporting-type";"#, None),
         (r"export function Foo() {}; export * as ns from './depth-zero'", None),
+        (r#"import { foo } from "cycle-dep""#, Some(json!([{"ignoreExternal":true}]))),
     ];
 
     let fail = vec![
@@ -399,6 +416,7 @@ fn test() {
             Some(json!([{"ignoreTypes":false}])),
         ),
         (r"export function Foo() {}; export * from './depth-zero'", None),
+        (r#"import { foo } from "cycle-dep""#, None),
     ];
 
     Tester::new(NoCycle::NAME, NoCycle::PLUGIN, pass, fail)
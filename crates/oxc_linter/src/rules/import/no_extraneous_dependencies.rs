@@ -0,0 +1,432 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use fast_glob::glob_match;
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::NODEJS_BUILTINS;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, de::IgnoredAny};
+use serde_json::Value;
+
+use crate::{ast_util::is_global_require_call, context::LintContext, rule::Rule};
+
+fn missing_dependency_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' should be listed in the project's dependencies"))
+        .with_help(format!("Add `{name}` to `dependencies` of the nearest package.json."))
+        .with_label(span)
+}
+
+fn wrong_dependency_section_diagnostic(span: Span, name: &str, section: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{name}' should be listed in the project's dependencies, not {section}"
+    ))
+    .with_help(format!(
+        "Move `{name}` to `dependencies`, or allow {section} in this file with the `{section}` option."
+    ))
+    .with_label(span)
+}
+
+fn invalid_package_json_diagnostic(path: &Path, error: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Could not read {}: {error}", path.display()))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    dev_dependencies: AllowDependencies,
+    optional_dependencies: AllowDependencies,
+    peer_dependencies: bool,
+    bundled_dependencies: bool,
+    include_types: bool,
+}
+
+impl Default for NoExtraneousDependenciesConfig {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: AllowDependencies::Always(true),
+            optional_dependencies: AllowDependencies::Always(true),
+            peer_dependencies: true,
+            bundled_dependencies: true,
+            include_types: false,
+        }
+    }
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Whether packages of a section of package.json may be imported, in all files or only in files
+/// matching some globs.
+#[derive(Debug, Clone)]
+enum AllowDependencies {
+    Always(bool),
+    InFiles(Vec<CompactStr>),
+}
+
+impl AllowDependencies {
+    fn from_configuration(value: Option<&Value>) -> Self {
+        match value {
+            Some(Value::Bool(allow)) => Self::Always(*allow),
+            Some(Value::Array(globs)) => Self::InFiles(
+                globs.iter().filter_map(Value::as_str).map(CompactStr::from).collect(),
+            ),
+            _ => Self::Always(true),
+        }
+    }
+
+    /// `path` is relative to the directory of the package.json.
+    fn allows(&self, path: &str) -> bool {
+        match self {
+            Self::Always(allow) => *allow,
+            Self::InFiles(globs) => globs.iter().any(|glob| glob_match(glob.as_str(), path)),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids importing packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`
+    /// of the nearest package.json.
+    ///
+    /// Node.js builtin modules, relative imports and imports which resolve to a file outside of
+    /// `node_modules` (e.g. path aliases) are ignored. Type-only imports are ignored unless
+    /// `includeTypes` is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A package which is not declared may be missing when the project is installed elsewhere, or
+    /// may be of an unexpected version. It only works by accident, when it happens to be installed
+    /// as a dependency of another package.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `lodash` not in package.json:
+    /// ```js
+    /// import _ from 'lodash';
+    /// const _ = require('lodash');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `react` in `dependencies`:
+    /// ```js
+    /// import React from 'react';
+    /// import { readFile } from 'node:fs';
+    /// import { helper } from './helper';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### devDependencies
+    ///
+    /// `{ type: boolean | string[], default: true }`
+    ///
+    /// Whether packages in `devDependencies` may be imported. An array of globs allows them only
+    /// in matching files, e.g. tests and configuration files. Globs are matched against the path
+    /// of the file relative to the directory of the package.json.
+    ///
+    /// ```json
+    /// {
+    ///   "import/no-extraneous-dependencies": ["error", {
+    ///     "devDependencies": ["**/*.test.ts", "**/*.config.{js,ts}", "scripts/**"]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// #### optionalDependencies
+    ///
+    /// `{ type: boolean | string[], default: true }`
+    ///
+    /// Same as `devDependencies`, for packages in `optionalDependencies`.
+    ///
+    /// #### peerDependencies
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether packages in `peerDependencies` may be imported.
+    ///
+    /// #### bundledDependencies
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether packages in `bundledDependencies` may be imported.
+    ///
+    /// #### includeTypes
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also check type-only imports, such as `import type { Foo } from 'foo'`. They are also
+    /// satisfied by the `@types` package of the imported package.
+    NoExtraneousDependencies,
+    import,
+    suspicious
+);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-extraneous-dependencies.md>
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |name: &str, default: bool| {
+            obj.and_then(|v| v.get(name)).and_then(Value::as_bool).unwrap_or(default)
+        };
+        Self(Box::new(NoExtraneousDependenciesConfig {
+            dev_dependencies: AllowDependencies::from_configuration(
+                obj.and_then(|v| v.get("devDependencies")),
+            ),
+            optional_dependencies: AllowDependencies::from_configuration(
+                obj.and_then(|v| v.get("optionalDependencies")),
+            ),
+            peer_dependencies: get_bool("peerDependencies", true),
+            bundled_dependencies: get_bool("bundledDependencies", true),
+            include_types: get_bool("includeTypes", false),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(package_json_path) = find_package_json(ctx.file_path()) else { return };
+        let dependencies = match PackageDependencies::read(&package_json_path) {
+            Ok(dependencies) => dependencies,
+            Err(error) => {
+                ctx.diagnostic(invalid_package_json_diagnostic(&package_json_path, &error));
+                return;
+            }
+        };
+        let relative_path = package_json_path
+            .parent()
+            .and_then(|dir| ctx.file_path().strip_prefix(dir).ok())
+            .unwrap_or(ctx.file_path())
+            .to_string_lossy();
+        let checker = Checker {
+            config: self,
+            dependencies: &dependencies,
+            relative_path: &relative_path,
+            ctx,
+        };
+
+        for (specifier, requested_modules) in &ctx.module_record().requested_modules {
+            for requested_module in requested_modules {
+                checker.check(specifier, requested_module.span, requested_module.is_type);
+            }
+        }
+
+        for node in ctx.nodes().iter() {
+            match node.kind() {
+                AstKind::CallExpression(call) if is_global_require_call(call, ctx.semantic()) => {
+                    if let Some(Argument::StringLiteral(source)) = call.arguments.first() {
+                        checker.check(&source.value, source.span, false);
+                    }
+                }
+                AstKind::ImportExpression(import) => {
+                    if let Expression::StringLiteral(source) = &import.source {
+                        checker.check(&source.value, source.span, false);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+struct Checker<'c, 'a> {
+    config: &'c NoExtraneousDependenciesConfig,
+    dependencies: &'c PackageDependencies,
+    relative_path: &'c str,
+    ctx: &'c LintContext<'a>,
+}
+
+impl Checker<'_, '_> {
+    fn check(&self, specifier: &str, span: Span, is_type: bool) {
+        if is_type && !self.config.include_types {
+            return;
+        }
+        let Some(name) = package_name(specifier) else { return };
+        if self.resolves_outside_node_modules(specifier) {
+            return;
+        }
+
+        // Types of a package may come from its `@types` package
+        let types_name = is_type.then(|| types_package_name(name));
+        let names = [Some(name), types_name.as_deref()];
+        let is_in = |section: &FxHashMap<String, IgnoredAny>| {
+            names.iter().flatten().any(|name| section.contains_key(*name))
+        };
+        let dependencies = self.dependencies;
+
+        let is_in_dev = is_in(&dependencies.dev_dependencies);
+        let is_in_optional = is_in(&dependencies.optional_dependencies);
+        let allow_dev = self.config.dev_dependencies.allows(self.relative_path);
+        let allow_optional = self.config.optional_dependencies.allows(self.relative_path);
+        if is_in(&dependencies.dependencies)
+            || (is_in_dev && allow_dev)
+            || (is_in_optional && allow_optional)
+            || (self.config.peer_dependencies && is_in(&dependencies.peer_dependencies))
+            || (self.config.bundled_dependencies
+                && names
+                    .iter()
+                    .flatten()
+                    .any(|name| dependencies.bundled_dependencies.contains(*name)))
+        {
+            return;
+        }
+
+        let diagnostic = if is_in_dev {
+            wrong_dependency_section_diagnostic(span, name, "devDependencies")
+        } else if is_in_optional {
+            wrong_dependency_section_diagnostic(span, name, "optionalDependencies")
+        } else {
+            missing_dependency_diagnostic(span, name)
+        };
+        self.ctx.diagnostic(diagnostic);
+    }
+
+    /// Whether `specifier` is resolved to a file of the project, e.g. by a path alias.
+    fn resolves_outside_node_modules(&self, specifier: &str) -> bool {
+        let loaded_modules = self.ctx.module_record().loaded_modules.read().unwrap();
+        loaded_modules.get(specifier).is_some_and(|module| {
+            !module
+                .resolved_absolute_path
+                .components()
+                .any(|component| component == Component::Normal("node_modules".as_ref()))
+        })
+    }
+}
+
+/// The sections of a package.json which declare dependencies.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageDependencies {
+    #[serde(default)]
+    dependencies: FxHashMap<String, IgnoredAny>,
+    #[serde(default)]
+    dev_dependencies: FxHashMap<String, IgnoredAny>,
+    #[serde(default)]
+    optional_dependencies: FxHashMap<String, IgnoredAny>,
+    #[serde(default)]
+    peer_dependencies: FxHashMap<String, IgnoredAny>,
+    /// Can also be `true` for all `dependencies`, which are allowed anyway.
+    #[serde(default, alias = "bundleDependencies", deserialize_with = "deserialize_bundled")]
+    bundled_dependencies: Vec<String>,
+}
+
+impl PackageDependencies {
+    fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|error| error.to_string())?;
+        serde_json::from_str(&content).map_err(|error| error.to_string())
+    }
+}
+
+fn deserialize_bundled<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    Ok(value
+        .as_array()
+        .map(|names| names.iter().filter_map(Value::as_str).map(String::from).collect())
+        .unwrap_or_default())
+}
+
+fn find_package_json(file_path: &Path) -> Option<PathBuf> {
+    file_path.ancestors().skip(1).map(|dir| dir.join("package.json")).find(|path| path.is_file())
+}
+
+/// Name of the package imported by `specifier`, e.g. `@scope/pkg` for `@scope/pkg/sub/path`.
+/// [`None`] for relative and absolute paths, URLs and Node.js builtin modules.
+fn package_name(specifier: &str) -> Option<&str> {
+    // `#` is for subpath imports of the package itself, `~` for a common path alias
+    if specifier.starts_with(['.', '/', '#', '~']) || specifier.contains(':') {
+        return None;
+    }
+    let mut segments = specifier.split('/');
+    let first = segments.next()?;
+    let name = if first.starts_with('@') {
+        // `@/` is a common path alias
+        let package = segments.next().filter(|package| first.len() > 1 && !package.is_empty())?;
+        &specifier[..first.len() + 1 + package.len()]
+    } else {
+        first
+    };
+    (!name.is_empty() && NODEJS_BUILTINS.binary_search(&name).is_err()).then_some(name)
+}
+
+/// Name of the package with types for package `name`, e.g. `@types/scope__pkg` for `@scope/pkg`.
+fn types_package_name(name: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(scoped) => format!("@types/{}", scoped.replacen('/', "__", 1)),
+        None => format!("@types/{name}"),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    // Dependencies of `fixtures/import/package.json`
+    let pass = vec![
+        (r"import 'lodash.cond'", None),
+        (r"import pkg from '@org/package'", None),
+        (r"import jq from 'jquery/dist/jquery.min.js'", None),
+        (r"const acorn = require('acorn')", None),
+        (r"export * from 'rxjs'", None),
+        (r"const findUp = import('find-up')", None),
+        (r"import glob from 'glob'", None),
+        (r"import isArray from 'lodash.isarray'", None),
+        (r"import eslint from 'eslint'", None),
+        (r"import foo from '@generated/foo'", None),
+        (r"import foo from './foo'", None),
+        (r"import foo from '../foo'", None),
+        (r"import foo from '/foo'", None),
+        (r"import fs from 'fs'", None),
+        (r"import fs from 'fs/promises'", None),
+        (r"import path from 'node:path'", None),
+        (r"import foo from '#internal/foo'", None),
+        (r"import foo from '@/foo'", None),
+        (r"import foo from '~/foo'", None),
+        (r"import type { Foo } from 'not-a-dependency'", None),
+        (r"const foo = require(name)", None),
+        (r"function f(require) { require('not-a-dependency') }", None),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": ["*.tsx"] }]))),
+        (
+            r"import isArray from 'lodash.isarray'",
+            Some(json!([{ "optionalDependencies": ["**/no_extraneous_dependencies.tsx"] }])),
+        ),
+        (r"import type { Foo } from 'jquery'", Some(json!([{ "includeTypes": true }]))),
+    ];
+
+    let fail = vec![
+        (r"import foo from 'not-a-dependency'", None),
+        (r"import foo from 'not-a-dependency/sub/path'", None),
+        (r"import foo from '@scope/not-a-dependency'", None),
+        (r"const foo = require('not-a-dependency')", None),
+        (r"const foo = import('not-a-dependency')", None),
+        (r"export { foo } from 'not-a-dependency'", None),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": false }]))),
+        (r"import glob from 'glob'", Some(json!([{ "devDependencies": ["**/*.test.js"] }]))),
+        (r"import isArray from 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }]))),
+        (
+            r"import eslint from 'eslint'",
+            Some(json!([{ "devDependencies": false, "peerDependencies": false }])),
+        ),
+        (r"import foo from '@generated/foo'", Some(json!([{ "bundledDependencies": false }]))),
+        (r"import type { Foo } from 'not-a-dependency'", Some(json!([{ "includeTypes": true }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
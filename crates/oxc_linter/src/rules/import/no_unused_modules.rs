@@ -0,0 +1,251 @@
+use fast_glob::glob_match;
+use serde_json::Value;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{context::LintContext, module_record::ExportExportName, rule::Rule};
+
+fn no_exports_found_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("No exports found").with_label(Span::new(0, 0))
+}
+
+fn unreachable_module_diagnostic() -> OxcDiagnostic {
+    OxcDiagnostic::warn("module is not imported by any other module")
+        .with_help("Remove the module, import it in another module, or add it to `entryPoints`")
+        .with_label(Span::new(0, 0))
+}
+
+fn unused_export_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("exported declaration '{name}' not used within other modules"))
+        .with_help("Remove the export, or import it in another module")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoUnusedModulesConfig {
+    /// Report modules without any exports
+    missing_exports: bool,
+    /// Report exports which are not imported by any other module
+    unused_exports: bool,
+    /// Glob patterns of files whose exports are not reported, e.g. the public API
+    ignore_exports: Vec<CompactStr>,
+    /// Glob patterns of the entry points of the project. When set, other modules which are not
+    /// imported by any module are reported.
+    entry_points: Vec<CompactStr>,
+}
+
+impl Default for NoUnusedModules {
+    fn default() -> Self {
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: false,
+            unused_exports: true,
+            ignore_exports: vec![],
+            entry_points: vec![],
+        }))
+    }
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports exports which are not imported by any other module of the linted files,
+    /// and optionally modules which don't export anything.
+    ///
+    /// This rule needs the whole module graph of the linted files, so it only reports
+    /// problems when running `oxlint` on a project with the import plugin enabled.
+    /// Exports which are only used through a dynamic `import()` or `require()` are reported
+    /// as unused.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Exports which are never imported are dead code, which is hard to find because
+    /// the module using them could be anywhere in the project.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// // a.js
+    /// import { used } from './b.js';
+    /// ```
+    /// ```javascript
+    /// // b.js
+    /// export const used = 1;
+    /// export const unused = 2; // reported: not imported by any module
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// // a.js
+    /// import { used } from './b.js';
+    /// ```
+    /// ```javascript
+    /// // b.js
+    /// export const used = 1;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `unusedExports` (default `true`): report exports which are not imported by any other module.
+    /// - `missingExports` (default `false`): report modules without any exports.
+    /// - `ignoreExports` (default `[]`): glob patterns of files whose exports are not reported,
+    ///   e.g. the public API of a library.
+    /// - `entryPoints` (default `[]`): glob patterns of the entry points of the project, e.g. the
+    ///   main module, scripts and tests. Their exports are not reported, and they are not reported
+    ///   by `missingExports`. When set, the other modules which are not imported by any module
+    ///   are reported as unused.
+    ///
+    /// ```json
+    /// {
+    ///     "import/no-unused-modules": ["warn", {
+    ///         "entryPoints": ["**/src/main.ts", "**/*.test.ts"],
+    ///         "ignoreExports": ["**/src/public-api.ts"]
+    ///     }]
+    /// }
+    /// ```
+    NoUnusedModules,
+    import,
+    restriction
+);
+
+impl Rule for NoUnusedModules {
+    fn from_configuration(value: Value) -> Self {
+        let obj = value.get(0);
+        let default = Self::default();
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: obj
+                .and_then(|v| v.get("missingExports"))
+                .and_then(Value::as_bool)
+                .unwrap_or(default.missing_exports),
+            unused_exports: obj
+                .and_then(|v| v.get("unusedExports"))
+                .and_then(Value::as_bool)
+                .unwrap_or(default.unused_exports),
+            ignore_exports: obj
+                .and_then(|v| v.get("ignoreExports"))
+                .and_then(Value::as_array)
+                .map(|v| v.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+                .unwrap_or_default(),
+            entry_points: obj
+                .and_then(|v| v.get("entryPoints"))
+                .and_then(Value::as_array)
+                .map(|v| v.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+
+        // Only linked when the whole module graph is built.
+        let Some(imported_names) = module_record.imported_names.get() else {
+            return;
+        };
+
+        let path = ctx.file_path().to_string_lossy();
+        let matches = |patterns: &[CompactStr]| {
+            patterns.iter().any(|pattern| glob_match(pattern.as_str(), path.as_ref()))
+        };
+        if matches(&self.entry_points) {
+            return;
+        }
+        if !self.entry_points.is_empty() && !imported_names.is_imported {
+            ctx.diagnostic(unreachable_module_diagnostic());
+            return;
+        }
+
+        if self.missing_exports
+            && module_record.local_export_entries.is_empty()
+            && module_record.indirect_export_entries.is_empty()
+            && module_record.star_export_entries.is_empty()
+        {
+            ctx.diagnostic(no_exports_found_diagnostic());
+        }
+
+        if !self.unused_exports || imported_names.all {
+            return;
+        }
+        if matches(&self.ignore_exports) {
+            return;
+        }
+
+        for entry in
+            module_record.local_export_entries.iter().chain(&module_record.indirect_export_entries)
+        {
+            let (name, span) = match &entry.export_name {
+                ExportExportName::Name(name) => (name.name(), name.span),
+                ExportExportName::Default(span) => ("default", *span),
+                ExportExportName::Null => continue,
+            };
+            if !imported_names.contains(name) {
+                ctx.diagnostic(unused_export_diagnostic(span, name));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    // `no-unused-modules/importer.js` imports `used` and the default export of the linted file.
+    let pass = vec![
+        (r"import './importer.js'; export const used = 1;", None),
+        (r"import './importer.js'; const used = 1; export { used };", None),
+        (r"import './importer.js'; export default function () {}", None),
+        (
+            r"import './importer.js'; export const used = 1, other = 2;",
+            Some(json!([{ "unusedExports": false }])),
+        ),
+        (
+            r"import './importer.js'; export const unused = 1;",
+            Some(json!([{ "ignoreExports": ["**/no-unused-modules/*.js"] }])),
+        ),
+        (r"import { used } from './importer.js'; export { used };", None),
+        (
+            r"import './importer.js'; export const used = 1;",
+            Some(json!([{ "missingExports": true }])),
+        ),
+        (
+            r"export const unused = 1;",
+            Some(json!([{ "entryPoints": ["**/no-unused-modules/index.js"] }])),
+        ),
+        (
+            r"const a = 1;",
+            Some(json!([{ "missingExports": true, "entryPoints": ["**/index.js"] }])),
+        ),
+        (
+            r"import './importer.js'; export const used = 1;",
+            Some(json!([{ "entryPoints": ["**/main.js"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r"import './importer.js'; export const used = 1, unused = 2;", None),
+        (r"import './importer.js'; export function unused() {}", None),
+        (r"import './importer.js'; const a = 1; export { a as unused, a as used };", None),
+        (r"import { used } from './importer.js'; export { used as unused };", None),
+        (r"const a = 1;", Some(json!([{ "missingExports": true }]))),
+        (r"export const unused = 1;", Some(json!([{ "entryPoints": ["**/main.js"] }]))),
+    ];
+
+    Tester::new(NoUnusedModules::NAME, NoUnusedModules::PLUGIN, pass, fail)
+        .change_rule_path("no-unused-modules/index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -52,6 +52,17 @@ declare_oxc_lint!(
     /// expect(anyArgsFunction).toBeCalledTimes(1);
     /// expect(uncalledFunction).not.toBeCalled();
     /// ```
+    ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/prefer-called-with.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/prefer-called-with": "error"
+    ///   }
+    /// }
+    /// ```
     PreferCalledWith,
     jest,
     style,
@@ -102,7 +113,7 @@ impl PreferCalledWith {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("expect(fn).toBeCalledWith();", None),
         ("expect(fn).toHaveBeenCalledWith();", None),
         ("expect(fn).toBeCalledWith(expect.anything());", None),
@@ -118,12 +129,29 @@ fn test() {
         ("expect(fn);", None),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         ("expect(fn).toBeCalled();", None),
         ("expect(fn).resolves.toBeCalled();", None),
         ("expect(fn).toHaveBeenCalled();", None),
     ];
 
+    let pass_vitest = vec![
+        ("expect(fn).toBeCalledWith(1);", None),
+        ("expect(fn).toHaveBeenCalledWith(1);", None),
+        ("expect(fn).not.toBeCalled();", None),
+        ("expect(fn).not.toHaveBeenCalled();", None),
+        ("expect(fn).toHaveBeenCalledTimes(1);", None),
+    ];
+
+    let fail_vitest = vec![
+        ("expect(fn).toBeCalled();", None),
+        ("expect(fn).toHaveBeenCalled();", None),
+        ("expect(fn).resolves.toHaveBeenCalled();", None),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+
     Tester::new(PreferCalledWith::NAME, PreferCalledWith::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .test_and_snapshot();
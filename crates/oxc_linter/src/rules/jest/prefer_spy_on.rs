@@ -17,9 +17,9 @@ use crate::{
     utils::{PossibleJestNode, get_node_name, parse_general_jest_fn_call},
 };
 
-fn use_jest_spy_on(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Suggest using `jest.spyOn()`.")
-        .with_help("Use jest.spyOn() instead")
+fn use_jest_spy_on(span: Span, local: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Suggest using `{local}.spyOn()`."))
+        .with_help(format!("Use {local}.spyOn() instead"))
         .with_label(span)
 }
 
@@ -61,6 +61,17 @@ declare_oxc_lint!(
     /// jest.spyOn(Date, 'now');
     /// jest.spyOn(Date, 'now').mockImplementation(() => 10);
     /// ```
+    ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/prefer-spy-on.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/prefer-spy-on": "error"
+    ///   }
+    /// }
+    /// ```
     PreferSpyOn,
     jest,
     style,
@@ -121,7 +132,10 @@ impl PreferSpyOn {
         }
 
         ctx.diagnostic_with_fix(
-            use_jest_spy_on(Span::new(call_expr.span.start, first_fn_member.span.end)),
+            use_jest_spy_on(
+                Span::new(call_expr.span.start, first_fn_member.span.end),
+                &jest_fn_call.local,
+            ),
             |fixer| {
                 let (end, has_mock_implementation) = if jest_fn_call.members.len() > 1 {
                     let second = &jest_fn_call.members[1];
@@ -137,8 +151,13 @@ impl PreferSpyOn {
                         false,
                     )
                 };
-                let content =
-                    Self::build_code(call_expr, left_assign, has_mock_implementation, fixer);
+                let content = Self::build_code(
+                    call_expr,
+                    left_assign,
+                    &jest_fn_call.local,
+                    has_mock_implementation,
+                    fixer,
+                );
                 fixer.replace(Span::new(assign_expr.span.start, end), content)
             },
         );
@@ -147,11 +166,13 @@ impl PreferSpyOn {
     fn build_code<'a>(
         call_expr: &'a CallExpression<'a>,
         left_assign: &MemberExpression,
+        local: &str,
         has_mock_implementation: bool,
         fixer: RuleFixer<'_, 'a>,
     ) -> String {
         let mut formatter = fixer.codegen();
-        formatter.print_str("jest.spyOn(");
+        formatter.print_str(local);
+        formatter.print_str(".spyOn(");
 
         match left_assign {
             MemberExpression::ComputedMemberExpression(cmp_mem_expr) => {
@@ -178,7 +199,7 @@ impl PreferSpyOn {
 
         formatter.print_str(".mockImplementation(");
 
-        if let Some(expr) = Self::get_jest_fn_call(call_expr) {
+        if let Some(expr) = Self::get_jest_fn_call(call_expr, local) {
             formatter.print_expression(expr);
         }
 
@@ -186,8 +207,13 @@ impl PreferSpyOn {
         formatter.into_source_text()
     }
 
-    fn get_jest_fn_call<'a>(call_expr: &'a CallExpression<'a>) -> Option<&'a Expression<'a>> {
-        let is_jest_fn = get_node_name(&call_expr.callee) == "jest.fn";
+    fn get_jest_fn_call<'a>(
+        call_expr: &'a CallExpression<'a>,
+        local: &str,
+    ) -> Option<&'a Expression<'a>> {
+        let is_jest_fn = get_node_name(&call_expr.callee)
+            .strip_suffix(".fn")
+            .is_some_and(|object| object == local);
 
         if is_jest_fn {
             return call_expr.arguments.first().and_then(Argument::as_expression);
@@ -197,11 +223,11 @@ impl PreferSpyOn {
             expr if expr.is_member_expression() => {
                 let mem_expr = expr.to_member_expression();
                 if let Some(call_expr) = Self::find_mem_expr(mem_expr) {
-                    return Self::get_jest_fn_call(call_expr);
+                    return Self::get_jest_fn_call(call_expr, local);
                 }
                 None
             }
-            Expression::CallExpression(call_expr) => Self::get_jest_fn_call(call_expr),
+            Expression::CallExpression(call_expr) => Self::get_jest_fn_call(call_expr, local),
             _ => None,
         }
     }
@@ -225,7 +251,7 @@ impl PreferSpyOn {
 fn tests() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("Date.now = () => 10", None),
         ("window.fetch = jest.fn", None),
         ("Date.now = fn()", None),
@@ -237,7 +263,7 @@ fn tests() {
         ("window[`${name}`] = jest[`fn${expression}`]()", None),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         ("obj.a = jest.fn(); const test = 10;", None),
         ("Date['now'] = jest['fn']()", None),
         ("window[`${name}`] = jest[`fn`]()", None),
@@ -259,7 +285,7 @@ fn tests() {
         ),
     ];
 
-    let fix = vec![
+    let mut fix = vec![
         (
             "obj.a = jest.fn(); const test = 10;",
             "jest.spyOn(obj, 'a').mockImplementation(); const test = 10;",
@@ -310,6 +336,40 @@ fn tests() {
         ),
     ];
 
+    let pass_vitest = vec![
+        ("Date.now = () => 10", None),
+        ("window.fetch = vi.fn", None),
+        ("obj.mock = vi.something()", None),
+        ("const mock = vi.fn()", None),
+        ("mockObj = { mock: vi.fn() }", None),
+    ];
+
+    let fail_vitest = vec![
+        ("obj.a = vi.fn(); const test = 10;", None),
+        ("Date['now'] = vi['fn']()", None),
+        ("obj.a = vi.fn(() => 10)", None),
+        ("foo[bar] = vi.fn().mockReturnValue(undefined)", None),
+    ];
+
+    let fix_vitest = vec![
+        (
+            "obj.a = vi.fn(); const test = 10;",
+            "vi.spyOn(obj, 'a').mockImplementation(); const test = 10;",
+            None,
+        ),
+        ("Date['now'] = vi['fn']()", "vi.spyOn(Date, 'now').mockImplementation()", None),
+        ("obj.a = vi.fn(() => 10)", "vi.spyOn(obj, 'a').mockImplementation(() => 10)", None),
+        (
+            "foo[bar] = vi.fn().mockReturnValue(undefined)",
+            "vi.spyOn(foo, bar).mockImplementation().mockReturnValue(undefined)",
+            None,
+        ),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+    fix.extend(fix_vitest);
+
     Tester::new(PreferSpyOn::NAME, PreferSpyOn::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .expect_fix(fix)
@@ -72,6 +72,17 @@ declare_oxc_lint!(
     /// expect(didError).not.toBe(true);
     /// expect(catchError()).toStrictEqual({ message: 'oh noes!' });
     /// ```
+    ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/prefer-to-be.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/prefer-to-be": "error"
+    ///   }
+    /// }
+    /// ```
     PreferToBe,
     jest,
     style,
@@ -276,7 +287,7 @@ impl PreferToBe {
 fn tests() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("expect(null).toBeNull();", None),
         ("expect(null).not.toBeNull();", None),
         ("expect(null).toBe(1);", None),
@@ -347,7 +358,7 @@ fn tests() {
         ),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         ("expect(value).toEqual(\"my string\");", None),
         ("expect(value).toStrictEqual(\"my string\");", None),
         ("expect(value).toStrictEqual(1);", None),
@@ -404,7 +415,7 @@ fn tests() {
         ("expect(\"a string\").toEqual(undefined as number);", None),
     ];
 
-    let fix = vec![
+    let mut fix = vec![
         ("expect(value).toEqual(\"my string\");", "expect(value).toBe(\"my string\");", None),
         ("expect(value).toStrictEqual(\"my string\");", "expect(value).toBe(\"my string\");", None),
         ("expect(value).toStrictEqual(1);", "expect(value).toBe(1);", None),
@@ -561,6 +572,36 @@ fn tests() {
         ),
     ];
 
+    let pass_vitest = vec![
+        ("expect(null).toBeNull();", None),
+        ("expect(null).not.toBeNull();", None),
+        ("expect(value).toBe(1);", None),
+        ("expect(undefined).toBeUndefined();", None),
+        ("expect(NaN).toBeNaN();", None),
+        ("expect.soft(value).toBe(1);", None),
+    ];
+
+    let fail_vitest = vec![
+        ("expect(value).toEqual(\"my string\");", None),
+        ("expect(null).toEqual(null);", None),
+        ("expect(undefined).toBe(undefined);", None),
+        ("expect(NaN).toEqual(NaN);", None),
+        ("expect(\"a string\").not.toStrictEqual(\"a string\");", None),
+        ("expect.soft(value).toEqual(1);", None),
+    ];
+
+    let fix_vitest = vec![
+        ("expect(value).toEqual(\"my string\");", "expect(value).toBe(\"my string\");", None),
+        ("expect(null).toEqual(null);", "expect(null).toBeNull();", None),
+        ("expect(undefined).toBe(undefined);", "expect(undefined).toBeUndefined();", None),
+        ("expect(NaN).toEqual(NaN);", "expect(NaN).toBeNaN();", None),
+        ("expect.soft(value).toEqual(1);", "expect.soft(value).toBe(1);", None),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+    fix.extend(fix_vitest);
+
     Tester::new(PreferToBe::NAME, PreferToBe::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .expect_fix(fix)
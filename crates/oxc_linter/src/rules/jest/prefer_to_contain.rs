@@ -49,6 +49,17 @@ declare_oxc_lint!(
     /// expect(a).toContain(b);
     /// expect(a).not.toContain(b);
     /// ```
+    ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/prefer-to-contain.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/prefer-to-contain": "error"
+    ///   }
+    /// }
+    /// ```
     PreferToContain,
     jest,
     style,
@@ -144,7 +155,7 @@ impl PreferToContain {
 fn tests() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("expect.hasAssertions", None),
         ("expect.hasAssertions()", None),
         ("expect.assertions(1)", None),
@@ -178,7 +189,7 @@ fn tests() {
         ("expect(a.includes(b)).toEqual(0 as boolean);", None),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         ("expect(a.includes(b)).toEqual(true);", None),
         ("expect(a.includes(b,),).toEqual(true,)", None),
         ("expect(a['includes'](b)).toEqual(true);", None),
@@ -221,6 +232,22 @@ fn tests() {
         ("expect(a.includes(b)).toEqual(false as boolean);", None),
     ];
 
+    let pass_vitest = vec![
+        ("expect(a).toContain(b);", None),
+        ("expect(a).not.toContain(b);", None),
+        ("expect(a.includes(b)).toEqual(c);", None),
+    ];
+
+    let fail_vitest = vec![
+        ("expect(a.includes(b)).toBe(true);", None),
+        ("expect(a.includes(b)).not.toBe(true);", None),
+        ("expect(a.includes(b)).toStrictEqual(false);", None),
+        ("expect.soft(a.includes(b)).toEqual(true);", None),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+
     Tester::new(PreferToContain::NAME, PreferToContain::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .test_and_snapshot();
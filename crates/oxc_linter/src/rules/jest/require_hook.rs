@@ -155,6 +155,17 @@ declare_oxc_lint!(
     ///     clearCityDatabase();
     /// });
     /// ```
+    ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/require-hook.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/require-hook": "error"
+    ///   }
+    /// }
+    /// ```
     RequireHook,
     jest,
     style
@@ -246,6 +257,7 @@ impl RequireHook {
             if !(parse_jest_fn_call(call_expr, &PossibleJestNode { node, original: None }, ctx)
                 .is_some()
                 || name.starts_with("jest.")
+                || name.starts_with("vi.")
                 || self.allowed_function_calls.contains(&name))
             {
                 ctx.diagnostic(use_hook(call_expr.span));
@@ -258,7 +270,7 @@ impl RequireHook {
 fn tests() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("describe()", None),
         ("describe(\"just a title\")", None),
         (
@@ -482,7 +494,7 @@ fn tests() {
         ),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         ("setup();", None),
         (
             "
@@ -633,6 +645,23 @@ fn tests() {
         ),
     ];
 
+    let pass_vitest = vec![
+        ("import { beforeEach } from 'vitest'; beforeEach(() => { setup(); });", None),
+        ("describe('a', () => { beforeAll(() => { setup(); }); });", None),
+        ("vi.mock('./module');", None),
+        ("vi.useFakeTimers();", None),
+        ("const value = 1;", None),
+    ];
+
+    let fail_vitest = vec![
+        ("setup();", None),
+        ("describe('a', () => { setup(); });", None),
+        ("let value = setup();", None),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+
     Tester::new(RequireHook::NAME, RequireHook::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .test_and_snapshot();
@@ -90,6 +90,16 @@ declare_oxc_lint!(
     /// }
     /// ```
     ///
+    /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/valid-title.md),
+    /// to use it, add the following configuration to your `.eslintrc.json`:
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///      "vitest/valid-title": "error"
+    ///   }
+    /// }
+    /// ```
     ValidTitle,
     jest,
     correctness,
@@ -433,7 +443,7 @@ impl Message {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec![
+    let mut pass = vec![
         ("describe('the correct way to properly handle all the things', () => {});", None),
         ("test('that all is as it should be', () => {});", None),
         (
@@ -587,7 +597,7 @@ fn test() {
         ("it(abc, function () {})", Some(serde_json::json!([{ "ignoreTypeOfTestName": true }]))),
     ];
 
-    let fail = vec![
+    let mut fail = vec![
         (
             "test('the correct way to properly handle all things', () => {});",
             Some(serde_json::json!([{ "disallowedWords": ["correct", "properly", "all"] }])),
@@ -929,7 +939,7 @@ fn test() {
         ("it(abc, function () {})", None),
     ];
 
-    let fix = vec![
+    let mut fix = vec![
         ("describe(' foo', function () {})", "describe('foo', function () {})"),
         ("describe.each()(' foo', function () {})", "describe.each()('foo', function () {})"),
         (
@@ -1051,6 +1061,29 @@ fn test() {
         ),
     ];
 
+    let pass_vitest = vec![
+        ("describe('the correct way to properly handle all the things', () => {});", None),
+        ("test('that all is as it should be', () => {});", None),
+        ("bench('sorting', () => {});", None),
+        ("it.concurrent('works', () => {});", None),
+    ];
+
+    let fail_vitest = vec![
+        ("describe('', () => {});", None),
+        ("test(' foo', () => {});", None),
+        ("it.concurrent(' foo', () => {});", None),
+        ("test(123, () => {});", None),
+    ];
+
+    let fix_vitest = vec![
+        ("test(' foo', () => {});", "test('foo', () => {});"),
+        ("it.concurrent(' foo', () => {});", "it.concurrent('foo', () => {});"),
+    ];
+
+    pass.extend(pass_vitest);
+    fail.extend(fail_vitest);
+    fix.extend(fix_vitest);
+
     Tester::new(ValidTitle::NAME, ValidTitle::PLUGIN, pass, fail)
         .with_jest_plugin(true)
         .expect_fix(fix)
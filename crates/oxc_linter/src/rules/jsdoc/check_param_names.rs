@@ -0,0 +1,668 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNode, JSDoc};
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        ParamKind, collect_params, default_true, get_function_nearest_jsdoc_node,
+        should_ignore_as_internal, should_ignore_as_private,
+    },
+};
+
+fn mismatched_order_diagnostic(span: Span, expected: &str, actual: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected @param names to be \"{expected}\". Got \"{actual}\"."))
+        .with_help("Reorder the `@param` tags to match the order of the function parameters.")
+        .with_label(span)
+}
+
+fn no_matching_param_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("@param \"{name}\" does not match an existing function parameter."))
+        .with_help("Remove the `@param` tag, or fix its name.")
+        .with_label(span)
+}
+
+fn duplicated_param_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate @param \"{name}\"."))
+        .with_help("Remove the redundant `@param` tag.")
+        .with_label(span)
+}
+
+fn no_root_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "@param path declaration (\"{name}\") appears before any real parameter."
+    ))
+    .with_help("Declare the root parameter first.")
+    .with_label(span)
+}
+
+fn mismatched_root_diagnostic(span: Span, name: &str, root: &str, previous: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "@param path declaration (\"{name}\") root node name (\"{root}\") does not match previous real parameter name (\"{previous}\")."
+    ))
+    .with_help("Move the `@param` tag after its root parameter.")
+    .with_label(span)
+}
+
+fn missing_property_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Missing @param \"{name}\"."))
+        .with_help("Document every destructured property.")
+        .with_label(span)
+}
+
+fn extra_property_diagnostic(span: Span, name: &str, root: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("@param \"{name}\" does not exist on {root}."))
+        .with_help("Remove the `@param` tag, or fix its name.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckParamNames(Box<CheckParamNamesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that parameter names in JSDoc match those in the function declaration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Parameter names in the documentation which are out of order, misspelled or no longer
+    /// exist are misleading for readers and tools which rely on them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param bar
+    ///  * @param foo
+    ///  */
+    /// function quux (foo, bar) {}
+    ///
+    /// /** @param baz */
+    /// function quux (foo) {}
+    ///
+    /// /**
+    ///  * @param foo
+    ///  * @param foo
+    ///  */
+    /// function quux (foo) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @param foo
+    ///  * @param bar
+    ///  */
+    /// function quux (foo, bar) {}
+    ///
+    /// /**
+    ///  * @param root0
+    ///  * @param root0.foo
+    ///  */
+    /// function quux ({ foo }) {}
+    /// ```
+    CheckParamNames,
+    jsdoc,
+    correctness
+);
+
+#[derive(Debug, Clone, Deserialize)]
+struct CheckParamNamesConfig {
+    #[serde(default, rename = "allowExtraTrailingParamDocs")]
+    allow_extra_trailing_param_docs: bool,
+    #[serde(default = "default_true", rename = "checkDestructured")]
+    check_destructured: bool,
+    #[serde(default, rename = "checkRestProperty")]
+    check_rest_property: bool,
+    #[serde(default, rename = "disableExtraPropertyReporting")]
+    disable_extra_property_reporting: bool,
+    #[serde(default, rename = "disableMissingParamChecks")]
+    disable_missing_param_checks: bool,
+}
+impl Default for CheckParamNamesConfig {
+    fn default() -> Self {
+        Self {
+            allow_extra_trailing_param_docs: false,
+            check_destructured: true,
+            check_rest_property: false,
+            disable_extra_property_reporting: false,
+            disable_missing_param_checks: false,
+        }
+    }
+}
+
+impl Rule for CheckParamNames {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => collect_params(&func.params),
+            AstKind::ArrowFunctionExpression(arrow_func) => collect_params(&arrow_func.params),
+            _ => return,
+        };
+
+        let Some(func_def_node) = get_function_nearest_jsdoc_node(node, ctx) else {
+            return;
+        };
+        let Some(jsdocs) = ctx.jsdoc().get_all_by_node(func_def_node) else {
+            return;
+        };
+
+        let settings = &ctx.settings().jsdoc;
+        if jsdocs.iter().any(|jsdoc| {
+            should_ignore_as_private(jsdoc, settings) || should_ignore_as_internal(jsdoc, settings)
+        }) {
+            return;
+        }
+
+        let tags = collect_tags(&jsdocs, settings.resolve_tag_name("param"));
+        if tags.is_empty() {
+            return;
+        }
+
+        let config = &self.0;
+        let mut seen = FxHashSet::default();
+        // The last documented top level parameter and its index
+        let mut current_root: Option<(&str, usize)> = None;
+        // Documented destructured roots, with their documented properties
+        let mut documented_destructured = vec![];
+
+        for &(name, span) in &tags {
+            if !seen.insert(name) {
+                ctx.diagnostic(duplicated_param_diagnostic(span, name));
+                continue;
+            }
+
+            let Some((root, property)) = name.split_once('.') else {
+                let index = current_root.map_or(0, |(_, index)| index + 1);
+                current_root = Some((name, index));
+
+                match params.get(index) {
+                    Some(ParamKind::Single(param)) if param.name != name => {
+                        if params
+                            .iter()
+                            .any(|p| matches!(p, ParamKind::Single(p) if p.name == name))
+                        {
+                            let expected = expected_names(&params, &tags);
+                            let actual = tags
+                                .iter()
+                                .map(|(name, _)| *name)
+                                .filter(|name| !name.contains('.'))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            ctx.diagnostic(mismatched_order_diagnostic(span, &expected, &actual));
+                            return;
+                        }
+                        ctx.diagnostic(no_matching_param_diagnostic(span, name));
+                    }
+                    Some(ParamKind::Nested(properties)) => {
+                        documented_destructured.push((name, span, properties, vec![]));
+                    }
+                    Some(ParamKind::Single(_)) => {}
+                    None => {
+                        if !config.allow_extra_trailing_param_docs {
+                            ctx.diagnostic(no_matching_param_diagnostic(span, name));
+                        }
+                    }
+                }
+                continue;
+            };
+
+            // `foo[].bar` -> `foo`
+            let root = root.trim_end_matches("[]");
+            let Some((current_root, _)) = current_root else {
+                ctx.diagnostic(no_root_diagnostic(span, name));
+                continue;
+            };
+            if root != current_root {
+                ctx.diagnostic(mismatched_root_diagnostic(span, name, root, current_root));
+                continue;
+            }
+
+            if let Some((_, _, properties, documented)) = documented_destructured
+                .last_mut()
+                .filter(|(destructured_root, ..)| *destructured_root == root)
+            {
+                documented.push(property);
+                if config.check_destructured
+                    && !config.disable_extra_property_reporting
+                    && !properties.iter().any(|p| is_property_path_prefix(property, &p.name))
+                {
+                    ctx.diagnostic(extra_property_diagnostic(span, name, root));
+                }
+            }
+        }
+
+        if !config.check_destructured || config.disable_missing_param_checks {
+            return;
+        }
+        for (root, span, properties, documented) in documented_destructured {
+            // Only check properties of roots whose properties are documented
+            if documented.is_empty() {
+                continue;
+            }
+            for property in properties {
+                if property.is_rest && !config.check_rest_property {
+                    continue;
+                }
+                if !documented.iter().any(|name| is_name_equal(name, &property.name)) {
+                    let name = format!("{root}.{}", property.name);
+                    ctx.diagnostic(missing_property_diagnostic(span, &name));
+                }
+            }
+        }
+    }
+}
+
+fn collect_tags<'a>(jsdocs: &[JSDoc<'a>], resolved_param_tag_name: &str) -> Vec<(&'a str, Span)> {
+    let mut collected = vec![];
+
+    for tag in jsdocs
+        .iter()
+        .flat_map(JSDoc::tags)
+        .filter(|tag| tag.kind.parsed() == resolved_param_tag_name)
+    {
+        let (_, Some(name_part), _) = tag.type_name_comment() else {
+            continue;
+        };
+
+        let name = name_part.parsed();
+        // thisParam is special, not collected as `FormalParameter`, should be ignored
+        if name == "this" {
+            continue;
+        }
+
+        collected.push((name, name_part.span));
+    }
+
+    collected
+}
+
+/// Names of the function parameters, using the documented name for destructured parameters.
+fn expected_names(params: &[ParamKind], tags: &[(&str, Span)]) -> String {
+    let mut documented_roots = tags.iter().map(|(name, _)| *name).filter(|n| !n.contains('.'));
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let documented = documented_roots.next();
+            match param {
+                ParamKind::Single(param) => param.name.clone(),
+                ParamKind::Nested(_) => {
+                    documented.map_or_else(|| format!("root{index}"), str::to_string)
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether `documented` (e.g. `foo` or `foo.bar`) is `property`, one of its parents or one of
+/// its children.
+fn is_property_path_prefix(documented: &str, property: &str) -> bool {
+    let is_parent = |parent: &str, child: &str| {
+        child
+            .char_indices()
+            .filter(|(_, c)| *c == '.')
+            .any(|(index, _)| is_name_equal(parent, &child[..index]))
+    };
+    is_name_equal(documented, property)
+        || is_parent(documented, property)
+        || is_parent(property, documented)
+}
+
+/// Compare to string param names without quotes
+/// e.g. `foo."bar"`
+fn is_name_equal(a: &str, b: &str) -> bool {
+    a.chars().filter(|&c| c != '"').eq(b.chars().filter(|&c| c != '"'))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			          /**
+			           * @param foo
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param bar
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param foo.bar
+			           * @param foo.bar.baz
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {object} root0
+			           * @param {string} root0.foo
+			           * @param {string} root0.bar
+			           */
+			          function quux ({ foo, bar }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           */
+			          function quux ({ foo, bar }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           * @param root0.foo.bar
+			           */
+			          function quux ({ foo: { bar } }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param args
+			           */
+			          function quux (...args) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param this
+			           * @param foo
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param bar
+			           */
+			          const quux = (foo, bar) => {};
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          class Foo {
+			            /**
+			             * @param foo
+			             */
+			            bar (foo) {}
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param bar
+			           */
+			          function quux (foo) {}
+			      ",
+            Some(serde_json::json!([{ "allowExtraTrailingParamDocs": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           */
+			          function quux ({ foo, bar }) {}
+			      ",
+            Some(serde_json::json!([{ "disableMissingParamChecks": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           * @param root0.baz
+			           */
+			          function quux ({ foo }) {}
+			      ",
+            Some(serde_json::json!([{ "disableExtraPropertyReporting": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           * @param root0.baz
+			           */
+			          function quux ({ foo, bar }) {}
+			      ",
+            Some(serde_json::json!([{ "checkDestructured": false }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           */
+			          function quux ({ foo, ...rest }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param bar
+			           * @private
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({ "settings": { "jsdoc": { "ignorePrivate": true } } })),
+        ),
+        (
+            "
+			          /**
+			           * @arg foo
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "param": "arg" } } } }),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			          /**
+			           * @param Foo
+			           */
+			          function quux (foo = 'FOO') {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param bar
+			           * @param foo
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param bar
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param foo
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo.bar
+			           * @param foo
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param foo
+			           * @param bar
+			           * @param foo.baz
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           * @param root0.baz
+			           */
+			          function quux ({ foo }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           */
+			          function quux ({ foo, bar }) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param root0
+			           * @param root0.foo
+			           */
+			          function quux ({ foo, ...rest }) {}
+			      ",
+            Some(serde_json::json!([{ "checkRestProperty": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param bar
+			           */
+			          const quux = (foo) => {};
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @arg foo
+			           * @arg bar
+			           */
+			          function quux (bar, foo) {}
+			      ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "param": "arg" } } } }),
+            ),
+        ),
+    ];
+
+    Tester::new(CheckParamNames::NAME, CheckParamNames::PLUGIN, pass, fail).test_and_snapshot();
+}
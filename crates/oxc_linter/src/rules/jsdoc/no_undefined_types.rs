@@ -0,0 +1,506 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDoc;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{JSDocTypeExpression, should_ignore_as_internal, should_ignore_as_private},
+};
+
+fn no_undefined_types_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("The type '{name}' is undefined."))
+        .with_help("Import or declare the type, or document it with `@typedef`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefinedTypes(Box<NoUndefinedTypesConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Checks that types in JSDoc comments are defined.
+    ///
+    /// A type is considered defined if it is:
+    /// - a built-in type like `string`, `object` or `Record`
+    /// - a global, including those enabled by `env` and `globals`
+    /// - declared in the file, e.g. by an `import`, a `class` or an `interface`
+    /// - declared with a `@typedef`, `@callback` or `@template` tag in the file
+    /// - listed in the `definedTypes` option
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An undefined type is most likely a typo, or a type which was renamed or removed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** @param {HerType} foo */
+    /// function quux (foo) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** @typedef {string} MyType */
+    ///
+    /// /**
+    ///  * @param {MyType} foo
+    ///  * @param {Array<string>} bar
+    ///  */
+    /// function quux (foo, bar) {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### definedTypes
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Additional type names to consider defined.
+    NoUndefinedTypes,
+    jsdoc,
+    pedantic
+);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+struct NoUndefinedTypesConfig {
+    #[serde(default, rename = "definedTypes")]
+    defined_types: Vec<String>,
+}
+
+/// Tags whose `{type}` is checked.
+const TYPED_TAG_NAMES: [&str; 21] = [
+    "arg",
+    "argument",
+    "augments",
+    "const",
+    "constant",
+    "enum",
+    "exception",
+    "extends",
+    "implements",
+    "member",
+    "param",
+    "prop",
+    "property",
+    "return",
+    "returns",
+    "satisfies",
+    "this",
+    "throws",
+    "type",
+    "typedef",
+    "yields",
+];
+
+/// Types which are built in to JSDoc or TypeScript, and are not globals.
+const BUILTIN_TYPES: [&str; 41] = [
+    "any",
+    "bigint",
+    "boolean",
+    "false",
+    "function",
+    "never",
+    "null",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "this",
+    "true",
+    "undefined",
+    "unknown",
+    "void",
+    // TypeScript utility types
+    "ArrayLike",
+    "AsyncIterable",
+    "AsyncIterableIterator",
+    "AsyncIterator",
+    "Awaited",
+    "Capitalize",
+    "ConstructorParameters",
+    "Exclude",
+    "Extract",
+    "InstanceType",
+    "Iterable",
+    "IterableIterator",
+    "Iterator",
+    "Lowercase",
+    "NonNullable",
+    "Omit",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "PromiseLike",
+    "Readonly",
+    "Record",
+    "Required",
+    "ReturnType",
+    "Uppercase",
+];
+
+impl Rule for NoUndefinedTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let settings = &ctx.settings().jsdoc;
+        let typed_tag_names = TYPED_TAG_NAMES
+            .iter()
+            .map(|name| settings.resolve_tag_name(name))
+            .collect::<FxHashSet<_>>();
+
+        let jsdocs = ctx
+            .jsdoc()
+            .iter_all()
+            .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+            .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+            .collect::<Vec<_>>();
+        if jsdocs.is_empty() {
+            return;
+        }
+
+        let jsdoc_defined_types = collect_jsdoc_defined_types(&jsdocs, ctx);
+        let symbol_names = ctx.scoping().symbol_names().collect::<FxHashSet<_>>();
+
+        let is_defined = |name: &str| {
+            BUILTIN_TYPES.contains(&name)
+                || jsdoc_defined_types.contains(name)
+                || self.0.defined_types.iter().any(|defined| defined == name)
+                || symbol_names.contains(name)
+                || ctx.env_contains_var(name)
+                || ctx.is_global_enabled(name)
+        };
+
+        for tag in jsdocs.iter().flat_map(|jsdoc| jsdoc.tags()) {
+            if !typed_tag_names.contains(tag.kind.parsed()) {
+                continue;
+            }
+            let Some(type_part) = tag.r#type() else {
+                continue;
+            };
+            // Invalid types are reported by `valid-types`
+            let Some(r#type) =
+                JSDocTypeExpression::parse_type_part(type_part.span, ctx.source_text())
+            else {
+                continue;
+            };
+
+            r#type.walk_names(&mut |name| {
+                if name.is_prefixed() {
+                    return;
+                }
+                let root = name.root();
+                if !is_defined(root) {
+                    ctx.diagnostic(no_undefined_types_diagnostic(name.span, root));
+                }
+            });
+        }
+    }
+}
+
+/// Collect names declared by `@typedef`, `@callback` and `@template` tags.
+fn collect_jsdoc_defined_types(jsdocs: &[&JSDoc], ctx: &LintContext) -> FxHashSet<String> {
+    let settings = &ctx.settings().jsdoc;
+    let resolved_typedef_tag_name = settings.resolve_tag_name("typedef");
+    let resolved_callback_tag_name = settings.resolve_tag_name("callback");
+    let resolved_template_tag_name = settings.resolve_tag_name("template");
+
+    let mut defined = FxHashSet::default();
+    for tag in jsdocs.iter().flat_map(|jsdoc| jsdoc.tags()) {
+        let tag_name = tag.kind.parsed();
+        if tag_name == resolved_typedef_tag_name {
+            if let (_, Some(name_part), _) = tag.type_name_comment() {
+                defined.insert(name_part.parsed().to_string());
+            }
+        } else if tag_name == resolved_callback_tag_name {
+            if let Some(name) = tag.comment().parsed().split_whitespace().next() {
+                defined.insert(name.to_string());
+            }
+        } else if tag_name == resolved_template_tag_name {
+            // `@template T, U` or `@template {Bound} T`
+            let (_, comment) = tag.type_comment();
+            for token in comment.parsed().split_whitespace() {
+                for name in token.split(',') {
+                    // `@template [T=string]`
+                    let name = name.trim_start_matches('[').split('=').next().unwrap_or_default();
+                    if !name.is_empty() {
+                        defined.insert(name.to_string());
+                    }
+                }
+                // The rest is the description
+                if !token.ends_with(',') {
+                    break;
+                }
+            }
+        }
+    }
+    defined
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			          /**
+			           * @param {string} foo
+			           * @param {number|boolean|null|undefined} bar
+			           * @returns {void}
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {Array<string>} foo
+			           * @param {Object.<string, Promise<Map<string, Set<RegExp>>>>} bar
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {*} foo
+			           * @param {?} bar
+			           * @param {'a' | 'b' | 1} baz
+			           */
+			          function quux (foo, bar, baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @typedef {string} MyType */
+
+			          /** @param {MyType} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @callback MyCallback
+			           * @param {string} value
+			           */
+
+			          /** @param {MyCallback} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @template T, U
+			           * @template {string} [V=string]
+			           * @param {T} foo
+			           * @param {U} bar
+			           * @returns {V}
+			           */
+			          function quux (foo, bar) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          import { MyType } from './types';
+
+			          /** @param {MyType} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          class Foo {}
+
+			          /** @param {Foo.Bar} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          interface MyInterface {}
+
+			          /** @param {MyInterface} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {function(string): number} foo
+			           * @param {(value: string) => Promise<void>} bar
+			           * @param {{ a: number, b: string[] }} baz
+			           * @param {[string, number]} qux
+			           */
+			          function quux (foo, bar, baz, qux) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {typeof quux} foo
+			           * @param {import('./foo').Foo} bar
+			           * @param {module:foo/bar} baz
+			           */
+			          function quux (foo, bar, baz) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @param {Record<string, Partial<ReturnType<typeof quux>>>} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @param {HTMLElement} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({ "env": { "browser": true } })),
+        ),
+        (
+            "
+			          /** @param {MyType} foo */
+			          function quux (foo) {}
+			      ",
+            Some(serde_json::json!([{ "definedTypes": ["MyType"] }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @example {NotAType}
+			           * @see {@link Foo}
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {MyType} foo
+			           * @private
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            Some(serde_json::json!({ "settings": { "jsdoc": { "ignorePrivate": true } } })),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			          /** @param {HerType} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @param {Array<HerType>} foo
+			           * @returns {Promise<TheirType | null>}
+			           */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @type {strnig} */
+			          const foo = 'foo';
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @typedef {object} MyType
+			           * @property {Unknown} foo
+			           */
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @param {HerType.Inner} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @param {(value: HerType) => void} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @param {HTMLElement} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /** @arg {HerType} foo */
+			          function quux (foo) {}
+			      ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "param": "arg" } } } }),
+            ),
+        ),
+    ];
+
+    Tester::new(NoUndefinedTypes::NAME, NoUndefinedTypes::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,456 @@
+use oxc_ast::{AstKind, ast::MethodDefinitionKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::JSDoc;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        JSDocTypeExpression, default_true, get_function_nearest_jsdoc_node,
+        should_ignore_as_custom_skip, should_ignore_as_internal, should_ignore_as_private,
+    },
+};
+
+fn require_returns_check_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "JSDoc `@returns` declaration present but return expression not available in function.",
+    )
+    .with_help("Remove the `@returns` tag, or return a value from the function.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireReturnsCheck(Box<RequireReturnsCheckConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires that functions with a JSDoc `@returns` tag actually return a value.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `@returns` tag on a function which never returns a value is misleading, and is
+    /// usually left over from a refactor.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** @returns {string} */
+    /// function quux () {}
+    ///
+    /// /** @returns {string} */
+    /// function quux () { return; }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** @returns {string} */
+    /// function quux () { return foo; }
+    ///
+    /// /** @returns {void} */
+    /// function quux () {}
+    /// ```
+    RequireReturnsCheck,
+    jsdoc,
+    correctness
+);
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequireReturnsCheckConfig {
+    #[serde(default = "default_true", rename = "exemptAsync")]
+    exempt_async: bool,
+    #[serde(default = "default_true", rename = "exemptGenerators")]
+    exempt_generators: bool,
+    #[serde(default, rename = "reportMissingReturnForUndefinedTypes")]
+    report_missing_return_for_undefined_types: bool,
+}
+impl Default for RequireReturnsCheckConfig {
+    fn default() -> Self {
+        Self {
+            exempt_async: true,
+            exempt_generators: true,
+            report_missing_return_for_undefined_types: false,
+        }
+    }
+}
+
+impl Rule for RequireReturnsCheck {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        // Step 1. Collect functions which return a value.
+        // Same as `require-returns`, search bottom-up from `ReturnStatement` instead of
+        // searching the body of each function.
+        let mut functions_with_return_value = FxHashSet::default();
+        for node in ctx.nodes() {
+            let AstKind::ReturnStatement(return_stmt) = node.kind() else {
+                continue;
+            };
+            if return_stmt.argument.is_none() {
+                continue;
+            }
+            if let Some(func_node) = ctx.nodes().ancestors(node.id()).find(|node| {
+                matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+            }) {
+                functions_with_return_value.insert(func_node.id());
+            }
+        }
+
+        // Step 2. Check documented functions
+        let config = &self.0;
+        let settings = &ctx.settings().jsdoc;
+        let resolved_returns_tag_name = settings.resolve_tag_name("returns");
+
+        for node in ctx.nodes() {
+            let has_return_value = match node.kind() {
+                // Declarations without body, like overloads, can't return anything
+                AstKind::Function(func) if func.body.is_some() => {
+                    if func.r#async && config.exempt_async
+                        || func.generator && config.exempt_generators
+                    {
+                        continue;
+                    }
+                    functions_with_return_value.contains(&node.id())
+                }
+                AstKind::ArrowFunctionExpression(arrow_func) => {
+                    if arrow_func.r#async && config.exempt_async {
+                        continue;
+                    }
+                    arrow_func.expression || functions_with_return_value.contains(&node.id())
+                }
+                _ => continue,
+            };
+            if has_return_value {
+                continue;
+            }
+
+            let Some(func_def_node) = get_function_nearest_jsdoc_node(node, ctx) else {
+                continue;
+            };
+            if let AstKind::MethodDefinition(method_def) = func_def_node.kind() {
+                if matches!(
+                    method_def.kind,
+                    MethodDefinitionKind::Constructor | MethodDefinitionKind::Set
+                ) {
+                    continue;
+                }
+            }
+            let Some(jsdocs) = ctx.jsdoc().get_all_by_node(func_def_node) else {
+                continue;
+            };
+
+            // If JSDoc is found but safely ignored, skip
+            if jsdocs
+                .iter()
+                .filter(|jsdoc| !should_ignore_as_custom_skip(jsdoc))
+                .filter(|jsdoc| !should_ignore_as_private(jsdoc, settings))
+                .filter(|jsdoc| !should_ignore_as_internal(jsdoc, settings))
+                .count()
+                == 0
+            {
+                continue;
+            }
+
+            for tag in jsdocs
+                .iter()
+                .flat_map(JSDoc::tags)
+                .filter(|tag| tag.kind.parsed() == resolved_returns_tag_name)
+            {
+                let type_part = tag.r#type();
+                let r#type = type_part.and_then(|part| {
+                    JSDocTypeExpression::parse_type_part(part.span, ctx.source_text())
+                });
+                // `@returns {never}` is for functions which never return at all
+                if let Some(JSDocTypeExpression::Name(name)) = &r#type {
+                    if name.name == "never" {
+                        continue;
+                    }
+                }
+                if !config.report_missing_return_for_undefined_types
+                    && r#type.as_ref().is_some_and(JSDocTypeExpression::is_void_like)
+                {
+                    continue;
+                }
+
+                ctx.diagnostic(require_returns_check_diagnostic(tag.kind.span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+			          /**
+			           * @returns Foo.
+			           */
+			          function quux () {
+			            return foo;
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          function quux () {
+			            if (true) {
+			              return foo;
+			            }
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          const quux = () => foo;
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {void} Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {undefined} Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {Promise<void>} Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {never} Foo.
+			           */
+			          function quux () {
+			            throw new Error('never');
+			          }
+			      ",
+            Some(serde_json::json!([{ "reportMissingReturnForUndefinedTypes": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {Promise<string>} Foo.
+			           */
+			          async function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {IterableIterator<string>} Foo.
+			           */
+			          function * quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           * @abstract
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          class Foo {
+			            /**
+			             * @returns {string} Foo.
+			             */
+			            constructor () {}
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          declare function quux (): string;
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           * @private
+			           */
+			          function quux () {}
+			      ",
+            None,
+            Some(serde_json::json!({ "settings": { "jsdoc": { "ignorePrivate": true } } })),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns Foo.
+			           */
+			          function quux () {
+			            return;
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          function quux () {
+			            function inner () {
+			              return foo;
+			            }
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {string} Foo.
+			           */
+			          const quux = () => {};
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          class Foo {
+			            /**
+			             * @returns {string} Foo.
+			             */
+			            bar () {}
+			          }
+			      ",
+            None,
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {void} Foo.
+			           */
+			          function quux () {}
+			      ",
+            Some(serde_json::json!([{ "reportMissingReturnForUndefinedTypes": true }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {Promise<string>} Foo.
+			           */
+			          async function quux () {}
+			      ",
+            Some(serde_json::json!([{ "exemptAsync": false }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @returns {IterableIterator<string>} Foo.
+			           */
+			          function * quux () {}
+			      ",
+            Some(serde_json::json!([{ "exemptGenerators": false }])),
+            None,
+        ),
+        (
+            "
+			          /**
+			           * @return {string} Foo.
+			           */
+			          function quux () {}
+			      ",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsdoc": { "tagNamePreference": { "returns": "return" } } } }),
+            ),
+        ),
+    ];
+
+    Tester::new(RequireReturnsCheck::NAME, RequireReturnsCheck::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -2,7 +2,7 @@ use oxc_ast::{
     AstKind,
     ast::{
         Argument, AssignmentTarget, BindingPatternKind, CallExpression, Expression, ForInStatement,
-        ForOfStatement, ForStatement, VariableDeclarationKind,
+        ForOfStatement, ForStatement, IdentifierReference, VariableDeclarationKind,
     },
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -44,6 +44,29 @@ fn reduce_unknown(spread_span: Span, reduce_span: Span) -> OxcDiagnostic {
         ])
 }
 
+fn reduce_object_assign_diagnostic(copy_span: Span, reduce_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not copy accumulators with `Object.assign({}, ...)` in Array.prototype.reduce()")
+        .with_help("Pass the accumulator as the first argument of `Object.assign()` to mutate it instead.\nCopying accumulators leads to `O(n^2)` time complexity.")
+        .with_labels([
+            copy_span.label("From this copy"),
+            reduce_span.label("For this reduce")
+        ])
+}
+
+fn loop_object_assign_diagnostic(
+    accumulator_decl_span: Span,
+    copy_span: Span,
+    loop_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not copy accumulators with `Object.assign({}, ...)` in loops")
+        .with_help("Pass the accumulator as the first argument of `Object.assign()` to mutate it instead.\nCopying accumulators leads to `O(n^2)` time complexity.")
+        .with_labels([
+            accumulator_decl_span.label("From this accumulator"),
+            copy_span.label("From this copy"),
+            loop_span.label("For this loop")
+        ])
+}
+
 fn loop_spread_likely_object_diagnostic(
     accumulator_decl_span: Span,
     spread_span: Span,
@@ -78,10 +101,12 @@ declare_oxc_lint!(
     /// ### What it does
     ///
     /// Prevents using object or array spreads on accumulators in `Array.prototype.reduce()` and in loops.
+    /// Copies with `Object.assign({}, acc, ...)` are reported too.
     ///
     /// ### Why is this bad?
     ///
-    /// Object and array spreads create a new object or array on each iteration.
+    /// Object and array spreads, like `Object.assign()` with an empty target, create a new object or
+    /// array on each iteration.
     /// In the worst case, they also cause O(n) copies (both memory and time complexity).
     /// When used on an accumulator, this can lead to `O(n^2)` memory complexity and
     /// `O(n^2)` time complexity.
@@ -95,6 +120,7 @@ declare_oxc_lint!(
     /// ```javascript
     /// arr.reduce((acc, x) => ({ ...acc, [x]: fn(x) }), {})
     /// Object.keys(obj).reduce((acc, el) => ({ ...acc, [el]: fn(el) }), {})
+    /// arr.reduce((acc, x) => Object.assign({}, acc, { [x]: fn(x) }), {})
     ///
     /// let foo = []; for (let i = 0; i < 10; i++) { foo = [...foo, i]; }
     /// ```
@@ -109,6 +135,7 @@ declare_oxc_lint!(
     /// Object.keys(obj).reduce((acc, el) => {
     ///   acc[el] = fn(el)
     /// }, {})
+    /// arr.reduce((acc, x) => Object.assign(acc, { [x]: fn(x) }), {})
     /// // spreading non-accumulators should be avoided if possible, but is not
     /// // banned by this rule
     /// Object.keys(obj).reduce((acc, el) => {
@@ -123,42 +150,79 @@ declare_oxc_lint!(
     perf,
 );
 
+/// How an accumulator is copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AccumulatorCopy {
+    /// `[...acc]` or `{ ...acc }`
+    Spread,
+    /// `Object.assign({}, acc)`
+    ObjectAssign,
+}
+
 impl Rule for NoAccumulatingSpread {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        // only check spreads on identifiers
-        let AstKind::SpreadElement(spread) = node.kind() else {
-            return;
-        };
-        let Expression::Identifier(ident) = &spread.argument else {
-            return;
-        };
-
-        let symbols = ctx.scoping();
-
-        // get the AST node + symbol id of the declaration of the identifier
-        let reference = symbols.get_reference(ident.reference_id());
-        let Some(referenced_symbol_id) = reference.symbol_id() else {
-            return;
-        };
-        let declaration_id = symbols.symbol_declaration(referenced_symbol_id);
-        let declaration = ctx.nodes().parent_node(declaration_id);
-
-        check_reduce_usage(declaration, referenced_symbol_id, spread.span, ctx);
-        check_loop_usage(
-            declaration,
-            ctx.nodes().get_node(declaration_id),
-            referenced_symbol_id,
-            node.id(),
-            spread.span,
-            ctx,
-        );
+        match node.kind() {
+            // only check spreads on identifiers
+            AstKind::SpreadElement(spread) => {
+                if let Expression::Identifier(ident) = &spread.argument {
+                    check_copy(ident, spread.span, AccumulatorCopy::Spread, node, ctx);
+                }
+            }
+            AstKind::CallExpression(call_expr) if is_object_assign_copy(call_expr) => {
+                for argument in call_expr.arguments.iter().skip(1) {
+                    if let Argument::Identifier(ident) = argument {
+                        check_copy(ident, ident.span, AccumulatorCopy::ObjectAssign, node, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
     }
 }
 
+/// `Object.assign({}, ...)`, which copies the other arguments into a new object.
+fn is_object_assign_copy(call_expr: &CallExpression) -> bool {
+    call_expr.callee.is_specific_member_access("Object", "assign")
+        && matches!(
+            call_expr.arguments.first().and_then(Argument::as_expression).map(Expression::get_inner_expression),
+            Some(Expression::ObjectExpression(object)) if object.properties.is_empty()
+        )
+}
+
+fn check_copy<'a>(
+    ident: &IdentifierReference<'a>,
+    copy_span: Span,
+    copy: AccumulatorCopy,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let symbols = ctx.scoping();
+
+    // get the AST node + symbol id of the declaration of the identifier
+    let reference = symbols.get_reference(ident.reference_id());
+    let Some(referenced_symbol_id) = reference.symbol_id() else {
+        return;
+    };
+    let declaration_id = symbols.symbol_declaration(referenced_symbol_id);
+    let declaration = ctx.nodes().parent_node(declaration_id);
+
+    check_reduce_usage(declaration, referenced_symbol_id, copy_span, copy, ctx);
+    check_loop_usage(
+        declaration,
+        ctx.nodes().get_node(declaration_id),
+        referenced_symbol_id,
+        node.id(),
+        copy_span,
+        copy,
+        ctx,
+    );
+}
+
 fn check_reduce_usage<'a>(
     declaration: &AstNode<'a>,
     referenced_symbol_id: SymbolId,
     spread_span: Span,
+    copy: AccumulatorCopy,
     ctx: &LintContext<'a>,
 ) {
     let AstKind::FormalParameters(params) = declaration.kind() else {
@@ -183,7 +247,7 @@ fn check_reduce_usage<'a>(
     for parent in ctx.nodes().ancestors(declaration.id()) {
         if let AstKind::CallExpression(call_expr) = parent.kind() {
             if is_method_call(call_expr, None, Some(&["reduce", "reduceRight"]), Some(1), Some(2)) {
-                ctx.diagnostic(get_reduce_diagnostic(call_expr, spread_span));
+                ctx.diagnostic(get_reduce_diagnostic(call_expr, spread_span, copy));
                 return;
             }
         }
@@ -196,6 +260,7 @@ fn check_loop_usage<'a>(
     referenced_symbol_id: SymbolId,
     spread_node_id: NodeId,
     spread_span: Span,
+    copy: AccumulatorCopy,
     ctx: &LintContext<'a>,
 ) {
     let AstKind::VariableDeclaration(declaration) = declaration_node.kind() else {
@@ -213,7 +278,7 @@ fn check_loop_usage<'a>(
     };
 
     let Some(expression_type) =
-        get_spread_containing_expression_type(&assignment_expr.right, spread_span)
+        get_spread_containing_expression_type(&assignment_expr.right, spread_span, copy)
     else {
         return;
     };
@@ -224,6 +289,7 @@ fn check_loop_usage<'a>(
         spread_span,
         declaration.span,
         expression_type,
+        copy,
         ctx,
     );
 }
@@ -271,9 +337,17 @@ enum SpreadExpressionType {
 fn get_spread_containing_expression_type(
     expr: &Expression,
     spread_span: Span,
+    copy: AccumulatorCopy,
 ) -> Option<SpreadExpressionType> {
     let inner_expr = expr.get_inner_expression();
     match inner_expr {
+        Expression::CallExpression(call_expr)
+            if copy == AccumulatorCopy::ObjectAssign
+                && call_expr.span.contains_inclusive(spread_span)
+                && is_object_assign_copy(call_expr) =>
+        {
+            Some(SpreadExpressionType::Object)
+        }
         Expression::ArrayExpression(array_expr)
             if array_expr.span.contains_inclusive(spread_span) =>
         {
@@ -295,6 +369,7 @@ fn emit_loop_diagnostic_if_in_loop(
     spread_span: Span,
     declaration_span: Span,
     expression_type: SpreadExpressionType,
+    copy: AccumulatorCopy,
     ctx: &LintContext,
 ) {
     for parent in ctx.nodes().ancestors(spread_node_id) {
@@ -303,15 +378,22 @@ fn emit_loop_diagnostic_if_in_loop(
             if !parent_span.contains_inclusive(declaration_span)
                 && parent_span.contains_inclusive(spread_span)
             {
-                match expression_type {
-                    SpreadExpressionType::Array => {
+                match (copy, expression_type) {
+                    (AccumulatorCopy::ObjectAssign, _) => {
+                        ctx.diagnostic(loop_object_assign_diagnostic(
+                            declarator_span,
+                            spread_span,
+                            loop_span,
+                        ));
+                    }
+                    (AccumulatorCopy::Spread, SpreadExpressionType::Array) => {
                         ctx.diagnostic(loop_spread_likely_array_diagnostic(
                             declarator_span,
                             spread_span,
                             loop_span,
                         ));
                     }
-                    SpreadExpressionType::Object => {
+                    (AccumulatorCopy::Spread, SpreadExpressionType::Object) => {
                         ctx.diagnostic(loop_spread_likely_object_diagnostic(
                             declarator_span,
                             spread_span,
@@ -339,10 +421,15 @@ fn get_loop_span(ast_kind: AstKind) -> Option<Span> {
 fn get_reduce_diagnostic<'a>(
     call_expr: &'a CallExpression<'a>,
     spread_span: Span,
+    copy: AccumulatorCopy,
 ) -> OxcDiagnostic {
     // unwrap is safe because we already checked that this is a reduce call
     let (reduce_call_span, _) = call_expr_method_callee_info(call_expr).unwrap();
 
+    if copy == AccumulatorCopy::ObjectAssign {
+        return reduce_object_assign_diagnostic(spread_span, reduce_call_span);
+    }
+
     if let Some(second_arg) = call_expr.arguments.get(1).and_then(Argument::as_expression) {
         let second_arg = second_arg.get_inner_expression();
         if matches!(second_arg, Expression::ObjectExpression(_)) {
@@ -449,6 +536,11 @@ fn test() {
         "let foo = {}; for (let i of [1,2,3]) { foo[i] = i; }",
         "let foo = {}; for (const i of [1,2,3]) { foo[i] = i; }",
         "let foo = {}; while (Object.keys(foo).length < 10) { foo[Object.keys(foo).length] = Object.keys(foo).length; }",
+        // `Object.assign` which mutates the accumulator
+        "arr.reduce((acc, x) => Object.assign(acc, { [x]: x }), {})",
+        "arr.reduce((acc, x) => Object.assign({}, x), {})",
+        "let foo = {}; for (const i of [1,2,3]) { foo = Object.assign(foo, { [i]: i }); }",
+        "let foo = {}; for (const i of [1,2,3]) { bar = Object.assign({}, foo, { [i]: i }); }",
     ];
 
     let fail = vec![
@@ -513,6 +605,9 @@ fn test() {
         "let foo = {}; for (let i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; for (const i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; while (Object.keys(foo).length < 10) { foo = { ...foo, [Object.keys(foo).length]: Object.keys(foo).length }; }",
+        // `Object.assign` which copies the accumulator
+        "arr.reduce((acc, x) => Object.assign({}, acc, { [x]: x }), {})",
+        "let foo = {}; for (const i of [1,2,3]) { foo = Object.assign({}, foo, { [i]: i }); }",
     ];
 
     Tester::new(NoAccumulatingSpread::NAME, NoAccumulatingSpread::PLUGIN, pass, fail)
@@ -0,0 +1,209 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, ForInStatement, ForOfStatement, ForStatement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    ast_util::{call_expr_method_callee_info, is_kind_of_array_expr, is_method_call},
+    context::LintContext,
+    rule::Rule,
+};
+
+fn no_array_search_in_loop_diagnostic(
+    method: &str,
+    search_span: Span,
+    loop_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Do not search arrays with `{method}()` in loops"))
+        .with_help("Consider creating a `Set` or a `Map` of the values before the loop, and using `has()` or `get()` instead.\nSearching arrays in loops leads to `O(n^2)` time complexity.")
+        .with_labels([
+            search_span.label("From this search"),
+            loop_span.label("For this loop"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArraySearchInLoop;
+
+const SEARCH_METHODS: [&str; 3] = ["includes", "indexOf", "lastIndexOf"];
+
+const ITERATION_METHODS: [&str; 12] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "reduce",
+    "reduceRight",
+    "some",
+];
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows searching arrays with `includes()`, `indexOf()` or `lastIndexOf()` inside loops and
+    /// array iteration callbacks, when the array is created outside the loop.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Each search goes through the whole array, so searching in a loop leads to `O(n^2)` time
+    /// complexity, which is slow when the arrays are large. A `Set` or a `Map` created once before
+    /// the loop finds values in constant time.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const seen = [];
+    /// for (const item of items) {
+    ///   if (!seen.includes(item)) seen.push(item);
+    /// }
+    ///
+    /// const ids = users.map((user) => user.id);
+    /// const posts = allPosts.filter((post) => ids.indexOf(post.author) !== -1);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const seen = new Set();
+    /// for (const item of items) {
+    ///   seen.add(item);
+    /// }
+    ///
+    /// const ids = new Set(users.map((user) => user.id));
+    /// const posts = allPosts.filter((post) => ids.has(post.author));
+    /// ```
+    NoArraySearchInLoop,
+    oxc,
+    perf,
+);
+
+impl Rule for NoArraySearchInLoop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        if !is_method_call(call_expr, None, Some(&SEARCH_METHODS), Some(1), Some(2)) {
+            return;
+        }
+        let Some((search_span, method)) = call_expr_method_callee_info(call_expr) else {
+            return;
+        };
+        let Some(member_expr) = call_expr.callee.get_member_expr() else {
+            return;
+        };
+        let Expression::Identifier(ident) = member_expr.object().get_inner_expression() else {
+            return;
+        };
+
+        // Only arrays which are created by their declaration, and never reassigned
+        let scoping = ctx.scoping();
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let declaration = ctx.nodes().get_node(scoping.symbol_declaration(symbol_id));
+        let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+            return;
+        };
+        if !declarator.init.as_ref().is_some_and(is_kind_of_array_expr)
+            || scoping.symbol_is_mutated(symbol_id)
+        {
+            return;
+        }
+
+        if let Some(loop_span) = find_loop(node, declarator.span, ctx) {
+            ctx.diagnostic(no_array_search_in_loop_diagnostic(method, search_span, loop_span));
+        }
+    }
+}
+
+/// Find the innermost loop which runs `node` repeatedly, and doesn't contain the declaration of
+/// the array. Returns the span of the loop keyword, or of the iteration method.
+fn find_loop(node: &AstNode, declaration_span: Span, ctx: &LintContext) -> Option<Span> {
+    let span = node.kind().span();
+    for parent in ctx.nodes().ancestors(node.id()) {
+        if parent.kind().span().contains_inclusive(declaration_span) {
+            return None;
+        }
+        match parent.kind() {
+            AstKind::ForStatement(ForStatement { span: loop_span, init, .. })
+                if init.as_ref().is_none_or(|init| !init.span().contains_inclusive(span)) =>
+            {
+                return Some(Span::sized(loop_span.start, 3));
+            }
+            AstKind::ForOfStatement(ForOfStatement { span: loop_span, body, .. })
+            | AstKind::ForInStatement(ForInStatement { span: loop_span, body, .. })
+                if body.span().contains_inclusive(span) =>
+            {
+                return Some(Span::sized(loop_span.start, 3));
+            }
+            AstKind::WhileStatement(while_stmt) => {
+                return Some(Span::sized(while_stmt.span.start, 5));
+            }
+            AstKind::DoWhileStatement(do_stmt) => {
+                return Some(Span::sized(do_stmt.span.start, 2));
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                // A callback of an array iteration method runs for each element, other functions
+                // may run only once
+                let AstKind::CallExpression(call_expr) =
+                    ctx.nodes().ancestor_kinds(parent.id()).find(|kind| {
+                        !matches!(kind, AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+                    })?
+                else {
+                    return None;
+                };
+                if !is_method_call(call_expr, None, Some(&ITERATION_METHODS), Some(1), Some(2))
+                    || call_expr.callee.span().contains_inclusive(parent.kind().span())
+                {
+                    return None;
+                }
+                return call_expr_method_callee_info(call_expr).map(|(span, _)| span);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const seen = new Set(); for (const x of xs) { if (!seen.has(x)) seen.add(x); }",
+        "const list = [1, 2, 3]; list.includes(x);",
+        "for (const x of xs) { const list = [1, 2]; list.includes(x); }",
+        "const list = [1, 2]; for (const x of list.filter(Boolean)) {}",
+        "const list = [1, 2]; for (const x of list.slice(list.indexOf(y))) {}",
+        "for (const x of xs) { str.includes(x); }",
+        "const name = 'foo'; for (const x of xs) { name.includes(x); }",
+        "let list = []; list = other; for (const x of xs) { list.includes(x); }",
+        "const list = [1, 2]; for (let i = list.indexOf(x); i < 10; i++) {}",
+        "const list = [1, 2]; function f(x) { return list.includes(x); }",
+        "const list = [1, 2]; xs.then(x => list.includes(x));",
+        "const list = [1, 2]; for (const x of xs) { list.includes(x, 1, 2); }",
+    ];
+
+    let fail = vec![
+        "const seen = []; for (const x of xs) { if (!seen.includes(x)) seen.push(x); }",
+        "const ids = users.map(u => u.id); for (const post of posts) { if (ids.indexOf(post.author) === -1) {} }",
+        "const list = Array.from(xs); while (i < n) { list.lastIndexOf(i++); }",
+        "const list = [1, 2, 3]; xs.filter(x => list.includes(x));",
+        "const list = [1, 2, 3]; xs.forEach(function (x) { list.indexOf(x); });",
+        "const list = [1, 2, 3]; for (let i = 0; list.indexOf(i) === -1; i++) {}",
+        "const list = new Array(10); do { list.includes(x); } while (x);",
+        "const list = []; for (const a of as) { for (const b of bs) { list.includes(b); } }",
+    ];
+
+    Tester::new(NoArraySearchInLoop::NAME, NoArraySearchInLoop::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,432 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, FunctionBody, Statement},
+};
+use oxc_cfg::{
+    EdgeType, ErrorEdgeKind, InstructionKind,
+    graph::{
+        Direction,
+        visit::{Control, DfsEvent, set_depth_first_search},
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_promise_callback_call, is_promise},
+};
+
+fn always_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Each then() should return a value or throw")
+        .with_help("Return a value or throw an error in every code path of the callback")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AlwaysReturn(Box<AlwaysReturnConfig>);
+
+#[derive(Debug, Clone)]
+pub struct AlwaysReturnConfig {
+    ignore_last_callback: bool,
+    ignore_assignment_variable: Vec<CompactStr>,
+}
+
+impl Default for AlwaysReturnConfig {
+    fn default() -> Self {
+        Self {
+            ignore_last_callback: false,
+            ignore_assignment_variable: vec![CompactStr::new("globalThis")],
+        }
+    }
+}
+
+impl std::ops::Deref for AlwaysReturn {
+    type Target = AlwaysReturnConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require returning inside each `then()` to create readable and reusable Promise chains.
+    /// Throwing an error is allowed as well.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The value which a `then()` callback returns is passed to the next callback of the chain.
+    /// A callback which does not return anything passes `undefined`, which is often a mistake,
+    /// e.g. a forgotten `return` of a nested promise, which is not waited for.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// myPromise.then(function (val) {})
+    /// myPromise.then(() => {
+    ///   doSomething()
+    /// })
+    /// myPromise.then((b) => {
+    ///   if (b) {
+    ///     return 'yes'
+    ///   } else {
+    ///     forgotToReturn()
+    ///   }
+    /// })
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// myPromise.then((val) => val * 2)
+    /// myPromise.then(function (val) {
+    ///   return val * 2
+    /// })
+    /// myPromise.then(doSomething) // could be either
+    /// myPromise.then((b) => {
+    ///   if (b) {
+    ///     return 'yes'
+    ///   } else {
+    ///     throw new Error('no')
+    ///   }
+    /// })
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignoreLastCallback
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignore the last callback of a promise chain, e.g. when the result of the chain is not used:
+    ///
+    /// ```javascript
+    /// // OK with `{ "ignoreLastCallback": true }`
+    /// myPromise.then((x) => {
+    ///   console.log(x)
+    /// })
+    /// ```
+    ///
+    /// #### ignoreAssignmentVariable
+    ///
+    /// `{ type: string[], default: ["globalThis"] }`
+    ///
+    /// Ignore the last callback of a promise chain if its last statement assigns to a property
+    /// of one of these variables:
+    ///
+    /// ```javascript
+    /// // OK with the default `{ "ignoreAssignmentVariable": ["globalThis"] }`
+    /// myPromise.then((x) => {
+    ///   globalThis.x = x
+    /// })
+    /// ```
+    AlwaysReturn,
+    promise,
+    suspicious,
+);
+
+impl Rule for AlwaysReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = AlwaysReturnConfig::default();
+        let Some(value) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+
+        if let Some(ignore_last_callback) =
+            value.get("ignoreLastCallback").and_then(serde_json::Value::as_bool)
+        {
+            config.ignore_last_callback = ignore_last_callback;
+        }
+        if let Some(ignore_assignment_variable) =
+            value.get("ignoreAssignmentVariable").and_then(serde_json::Value::as_array)
+        {
+            config.ignore_assignment_variable = ignore_assignment_variable
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(CompactStr::from)
+                .collect();
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let body = match node.kind() {
+            AstKind::Function(func) => func.body.as_deref(),
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => Some(&*arrow.body),
+            _ => return,
+        };
+        let Some(body) = body else { return };
+
+        // Only the fulfillment callback, i.e. the first argument of `then`
+        let Some((call_expr, prop_name)) = get_promise_callback_call(node, ctx) else { return };
+        if prop_name != "then"
+            || call_expr.arguments.first().is_none_or(|arg| arg.span() != node.kind().span())
+        {
+            return;
+        }
+
+        if (self.ignore_last_callback || !self.ignore_assignment_variable.is_empty())
+            && is_last_callback(ctx.nodes().parent_id(node.id()), ctx)
+        {
+            if self.ignore_last_callback {
+                return;
+            }
+            if body.statements.last().is_some_and(|stmt| self.is_ignored_assignment(stmt)) {
+                return;
+            }
+        }
+
+        if !returns_on_all_paths(node, ctx) {
+            ctx.diagnostic(always_return_diagnostic(callback_span(node, body)));
+        }
+    }
+}
+
+impl AlwaysReturn {
+    /// Whether `stmt` assigns to a property of one of `ignoreAssignmentVariable`,
+    /// e.g. `globalThis.x = x`.
+    fn is_ignored_assignment(&self, stmt: &Statement) -> bool {
+        let Statement::ExpressionStatement(stmt) = stmt else { return false };
+        let Expression::AssignmentExpression(assignment) = &stmt.expression else { return false };
+        let Some(member) = assignment.left.as_member_expression() else { return false };
+        let mut object = member.object();
+        while let Some(member) = object.as_member_expression() {
+            object = member.object();
+        }
+        let Expression::Identifier(ident) = object.get_inner_expression() else { return false };
+        self.ignore_assignment_variable.iter().any(|name| name == &ident.name)
+    }
+}
+
+/// Span of the callback up to the start of its body, e.g. `function (x) ` or `(x) => `.
+fn callback_span(node: &AstNode, body: &FunctionBody) -> Span {
+    Span::new(node.kind().span().start, body.span.start)
+}
+
+/// Whether the result of the promise method call which is the parent of the callback is not
+/// used, e.g. `promise.then(() => {})` as a statement, or with `catch` or `finally` chained.
+fn is_last_callback(argument_id: NodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let mut target = nodes.parent_node(argument_id);
+    loop {
+        let parent = nodes.parent_node(target.id());
+        match parent.kind() {
+            // e.g. `{ promise.then(() => value) }`
+            AstKind::ExpressionStatement(_) => return true,
+            // e.g. `void promise.then(() => value)`
+            AstKind::UnaryExpression(expr) => return expr.operator.is_void(),
+            AstKind::SequenceExpression(expr) => {
+                // e.g. `(promise.then(() => value), expr)`
+                if expr.expressions.last().is_none_or(|last| last.span() != target.kind().span()) {
+                    return true;
+                }
+            }
+            // e.g. `promise?.then(() => value)`, `await promise.then(() => value)`
+            AstKind::ChainExpression(_)
+            | AstKind::AwaitExpression(_)
+            | AstKind::ParenthesizedExpression(_) => {}
+            // e.g. `promise.then(() => value).catch(e => {})`
+            AstKind::StaticMemberExpression(_) | AstKind::ComputedMemberExpression(_) => {
+                let call = nodes.parent_node(parent.id());
+                let is_catch_or_finally = call.kind().as_call_expression().is_some_and(|call| {
+                    call.callee.span() == parent.kind().span()
+                        && is_promise(call).is_some_and(|name| name == "catch" || name == "finally")
+                });
+                if !is_catch_or_finally {
+                    return false;
+                }
+                target = call;
+                continue;
+            }
+            _ => return false,
+        }
+        target = parent;
+    }
+}
+
+/// Whether every code path of the function returns or throws.
+fn returns_on_all_paths(node: &AstNode, ctx: &LintContext) -> bool {
+    let cfg = ctx.cfg();
+    let graph = cfg.graph();
+    let output = set_depth_first_search(graph, Some(node.cfg_id()), |event| match event {
+        DfsEvent::TreeEdge(a, b) => {
+            if graph.edges_connecting(a, b).any(|edge| {
+                matches!(
+                    edge.weight(),
+                    EdgeType::Normal | EdgeType::Jump | EdgeType::Error(ErrorEdgeKind::Explicit)
+                )
+            }) {
+                Control::Continue
+            } else {
+                Control::Prune
+            }
+        }
+        DfsEvent::Discover(basic_block_id, _) => {
+            let does_return =
+                cfg.basic_block(basic_block_id).instructions().iter().any(|it| {
+                    matches!(it.kind, InstructionKind::Return(_) | InstructionKind::Throw)
+                });
+            if graph.edges_directed(basic_block_id, Direction::Outgoing).any(|edge| {
+                matches!(
+                    edge.weight(),
+                    EdgeType::Jump
+                        | EdgeType::Normal
+                        | EdgeType::Backedge
+                        | EdgeType::Error(ErrorEdgeKind::Explicit)
+                )
+            }) {
+                Control::Continue
+            } else if does_return {
+                Control::Prune
+            } else {
+                Control::Break(())
+            }
+        }
+        _ => Control::Continue,
+    });
+    output.break_value().is_none()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("hey.then(x => x)", None),
+        ("hey.then(x => ({}))", None),
+        ("hey.then(x => { return; })", None),
+        ("hey.then(x => { return x ? x.id : null })", None),
+        ("hey.then(x => { return x * 10 })", None),
+        ("hey.then(function() { return 42; })", None),
+        ("hey.then(function() { return new Promise(function() {}); })", None),
+        (r#"hey.then(function() { return "x"; }).then(doSomethingWicked)"#, None),
+        (r#"hey.then(x => x).then(function() { return "3" })"#, None),
+        (r#"hey.then(function() { throw new Error("msg"); })"#, None),
+        (r#"hey.then(function(x) { if (!x) { throw new Error("no x"); } return x; })"#, None),
+        (r#"hey.then(function(x) { if (x) { return x; } throw new Error("no x"); })"#, None),
+        ("hey.then(function(x) { if (x) { return x; } else { return x; } })", None),
+        (r#"hey.then(x => { throw new Error("msg"); })"#, None),
+        (r#"hey.then(x => { if (!x) { throw new Error("no x"); } return x; })"#, None),
+        (r#"hey.then(x => { if (x) { return x; } throw new Error("no x"); })"#, None),
+        ("hey.then(x => { var f = function() { }; return f; })", None),
+        ("hey.then(x => { if (x) { return x; } else { return x; } })", None),
+        (r#"hey.then(x => { return x; var y = "unreachable"; })"#, None),
+        (r#"hey.then(x => { return x; return "unreachable"; })"#, None),
+        ("hey.then(x => { return; }, err=>{ log(err); })", None),
+        ("hey.then(x => { return x && x(); }, err=>{ log(err); })", None),
+        ("hey.then(x => { return x.y || x(); }, err=>{ log(err); })", None),
+        (
+            "hey.then((x) => { return new Promise((resolve, reject) => { if (x) { return resolve(); } return reject(); }); })",
+            None,
+        ),
+        ("hey.then(x => { switch (x) { case 1: return 1; default: throw x; } })", None),
+        ("hey.then(x => { try { return x(); } catch (e) { throw e; } })", None),
+        // Other callbacks than the fulfillment callback of `then`
+        ("hey.then(x => x, err => { log(err); })", None),
+        ("hey.catch(err => { log(err); })", None),
+        ("hey.finally(() => { log(); })", None),
+        ("hey.then(doSomething)", None),
+        ("foo.map(x => { log(x); })", None),
+        ("hey.then(x => { globalThis.x = x })", None),
+        ("hey.then(x => { globalThis.x.y = x })", None),
+        (
+            "hey.then(x => { window.x = x })",
+            Some(serde_json::json!([{ "ignoreAssignmentVariable": ["globalThis", "window"] }])),
+        ),
+        (
+            "hey.then(x => { console.log(x) })",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "if(foo) { hey.then(x => { console.log(x) }) }",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "void hey.then(x => { console.log(x) })",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "async function foo() { await hey.then(x => { console.log(x) }) }",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey?.then(x => { console.log(x) })",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "foo = (hey.then(x => { console.log(x) }), 42)",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "(42, hey.then(x => { console.log(x) }))",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey.then(x => { console.log(x) }).catch(e => console.error(e))",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey.then(x => { console.log(x) }).catch(e => console.error(e)).finally(() => console.error('end'))",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey.then(x => { console.log(x) }).finally(() => console.error('end'))",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("hey.then(x => {})", None),
+        ("hey.then(function() { })", None),
+        ("hey.then(function() { }).then(x)", None),
+        ("hey.then(function() { }).then(function() { })", None),
+        ("hey.then(function() { return; }).then(function() { })", None),
+        ("hey.then(function() { doSomethingWicked(); })", None),
+        ("hey.then(function() { if (x) { return x; } })", None),
+        ("hey.then(function() { if (x) { return x; } else { }})", None),
+        ("hey.then(function() { if (x) { } else { return x; }})", None),
+        ("hey.then(function() { if (x) { return you.then(function() { return x; }); } })", None),
+        ("hey.then( x => { x ? x.id : null })", None),
+        ("hey.then(function(x) { x ? x.id : null })", None),
+        (
+            "(function() { return hey.then(x => { anotherFunc({ nested: { one: x === 1 ? 1 : 0, two: x === 2 ? 1 : 0 } }) }) })()",
+            None,
+        ),
+        ("hey.then(x => { switch (x) { case 1: return 1; } })", None),
+        ("hey.then(x => { try { return x(); } catch (e) { log(e); } })", None),
+        ("hey.then(x => { for (const y of x) { return y; } })", None),
+        ("hey.then(x => { window.x = x })", None),
+        ("hey.then(x => { let total = 0; total = x })", None),
+        ("const foo = hey.then(x => { globalThis.x = x })", None),
+        ("hey.then(x => { globalThis.x = x }).then(y => y)", None),
+        (
+            "const foo = hey.then(x => { console.log(x) })",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "function foo() { return hey.then(x => { console.log(x) }) }",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "async function foo() { return await hey.then(x => { console.log(x) }) }",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey.then(x => { console.log(x) }).then(y => y)",
+            Some(serde_json::json!([{ "ignoreLastCallback": true }])),
+        ),
+        (
+            "hey.then(x => { globalThis.x = x })",
+            Some(serde_json::json!([{ "ignoreAssignmentVariable": [] }])),
+        ),
+    ];
+
+    Tester::new(AlwaysReturn::NAME, AlwaysReturn::PLUGIN, pass, fail).test_and_snapshot();
+}
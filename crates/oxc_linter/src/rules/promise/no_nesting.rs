@@ -7,7 +7,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::is_promise};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_promise_callback_call, is_promise},
+};
 
 fn no_nesting_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Avoid nesting promises.")
@@ -69,18 +74,9 @@ declare_oxc_lint!(
     pending
 );
 
-fn is_inside_promise(node: &AstNode, ctx: &LintContext) -> bool {
-    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
-        || !matches!(ctx.nodes().parent_kind(node.id()), AstKind::Argument(_))
-    {
-        return false;
-    }
-
-    ctx.nodes().ancestors(node.id()).nth(1).is_some_and(|node| {
-        node.kind().as_call_expression().is_some_and(|a| {
-            is_promise(a).is_some_and(|prop_name| prop_name == "then" || prop_name == "catch")
-        })
-    })
+fn is_inside_promise<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    get_promise_callback_call(node, ctx)
+        .is_some_and(|(_, prop_name)| prop_name == "then" || prop_name == "catch")
 }
 
 /// Gets the closest promise callback function of the nested promise.
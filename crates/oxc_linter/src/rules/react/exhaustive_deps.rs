@@ -240,6 +240,13 @@ declare_oxc_lint!(
     /// ```json
     /// { "react/exhaustive-deps": ["error", { "additionalHooks": "useSpecialEffect" }] }
     /// ```
+    ///
+    /// Additional hooks can also be shared with other rules through the settings, which are used
+    /// when this option is not set:
+    ///
+    /// ```json
+    /// { "settings": { "react-hooks": { "additionalEffectHooks": "useSpecialEffect" } } }
+    /// ```
     ExhaustiveDeps,
     react,
     correctness,
@@ -283,7 +290,7 @@ impl Rule for ExhaustiveDeps {
             }
         };
 
-        let Some(callback_index) = self.get_reactive_hook_callback_index(hook_name) else {
+        let Some(callback_index) = self.get_reactive_hook_callback_index(hook_name, ctx) else {
             return;
         };
         let callback_node = call_expr.arguments.get(callback_index);
@@ -727,7 +734,11 @@ impl GetSpan for CallbackNode<'_> {
 
 impl ExhaustiveDeps {
     // https://github.com/facebook/react/blob/1b0132c05acabae5aebd32c2cadddfb16bda70bc/packages/eslint-plugin-react-hooks/src/ExhaustiveDeps.js#L1789
-    fn get_reactive_hook_callback_index(&self, hook_name: &str) -> Option<usize> {
+    fn get_reactive_hook_callback_index(
+        &self,
+        hook_name: &str,
+        ctx: &LintContext,
+    ) -> Option<usize> {
         match hook_name {
             "useEffect" | "useLayoutEffect" | "useCallback" | "useMemo" => Some(0),
             "useImperativeHandle" => Some(1),
@@ -735,6 +746,7 @@ impl ExhaustiveDeps {
                 .0
                 .additional_hooks
                 .as_ref()
+                .or_else(|| ctx.settings().react_hooks.additional_effect_hooks())
                 .is_some_and(|regex| regex.is_match(hook_name))
                 .then_some(0),
         }
@@ -4028,17 +4040,34 @@ fn test() {
         }"#,
     ];
 
-    let pass_additional_hooks = vec![(
-        "function MyComponent(props) {
+    let pass_additional_hooks = vec![
+        (
+            "function MyComponent(props) {
           useSpecialEffect(() => {
             console.log(props.foo);
           });
         }",
-        Some(serde_json::json!([{ "additionalHooks": "useSpecialEffect" }])),
-    )];
+            Some(serde_json::json!([{ "additionalHooks": "useSpecialEffect" }])),
+            None,
+        ),
+        (
+            "function MyComponent() {
+          const [state, setState] = React.useState<number>(0);
 
-    let fail_additional_hooks = vec![(
-        "function MyComponent() {
+          useSpecialEffect(() => {
+            setState(state + 1);
+          }, [])
+        }",
+            Some(serde_json::json!([{ "additionalHooks": "useOtherEffect" }])),
+            Some(serde_json::json!({
+                "settings": { "react-hooks": { "additionalEffectHooks": "useSpecialEffect" } }
+            })),
+        ),
+    ];
+
+    let fail_additional_hooks = vec![
+        (
+            "function MyComponent() {
           const [state, setState] = React.useState<number>(0);
 
           useSpecialEffect(() => {
@@ -4046,8 +4075,24 @@ fn test() {
             setState(prevState => prevState + someNumber + state);
           }, [])
         }",
-        Some(serde_json::json!([{ "additionalHooks": "useSpecialEffect" }])),
-    )];
+            Some(serde_json::json!([{ "additionalHooks": "useSpecialEffect" }])),
+            None,
+        ),
+        (
+            "function MyComponent() {
+          const [state, setState] = React.useState<number>(0);
+
+          useSpecialEffect(() => {
+            const someNumber: typeof state = 2;
+            setState(prevState => prevState + someNumber + state);
+          }, [])
+        }",
+            None,
+            Some(serde_json::json!({
+                "settings": { "react-hooks": { "additionalEffectHooks": "useSpecialEffect" } }
+            })),
+        ),
+    ];
 
     let fix = vec![
         (
@@ -4121,8 +4166,14 @@ fn test() {
     Tester::new(
         ExhaustiveDeps::NAME,
         ExhaustiveDeps::PLUGIN,
-        pass.iter().map(|&code| (code, None)).chain(pass_additional_hooks).collect::<Vec<_>>(),
-        fail.iter().map(|&code| (code, None)).chain(fail_additional_hooks).collect::<Vec<_>>(),
+        pass.iter()
+            .map(|&code| (code, None, None))
+            .chain(pass_additional_hooks)
+            .collect::<Vec<_>>(),
+        fail.iter()
+            .map(|&code| (code, None, None))
+            .chain(fail_additional_hooks)
+            .collect::<Vec<_>>(),
     )
     .expect_fix(fix)
     .test_and_snapshot();
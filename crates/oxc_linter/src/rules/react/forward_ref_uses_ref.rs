@@ -6,7 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, fixer::RuleFixer, rule::Rule};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn forward_ref_uses_ref_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Components wrapped with `forwardRef` must have a `ref` parameter")
@@ -63,11 +63,7 @@ declare_oxc_lint!(
     suggestion
 );
 
-fn check_forward_ref_inner<'a>(
-    exp: &Expression,
-    call_expr: &CallExpression,
-    ctx: &LintContext<'a>,
-) {
+fn check_forward_ref_inner(exp: &Expression, call_expr: &CallExpression, ctx: &LintContext<'_>) {
     let (params, span) = match exp {
         Expression::ArrowFunctionExpression(f) => (&f.params, f.span),
         Expression::FunctionExpression(f) => (&f.params, f.span),
@@ -77,25 +73,28 @@ fn check_forward_ref_inner<'a>(
         return;
     }
 
-    ctx.diagnostics_with_multiple_fixes(
-        forward_ref_uses_ref_diagnostic(span),
-        (FixKind::Suggestion, |fixer: RuleFixer<'_, 'a>| {
-            fixer.replace_with(call_expr, exp).with_message("remove `forwardRef` wrapper")
-        }),
-        (FixKind::Suggestion, |fixer: RuleFixer<'_, 'a>| {
-            let fixed = ctx.source_range(params.span);
-            // remove the trailing `)`, `` and `,` if they exist
-            let fixed = fixed.strip_suffix(')').unwrap_or(fixed).trim_end();
-            let mut fixed = fixed.strip_suffix(',').unwrap_or(fixed).to_string();
-
-            if !fixed.starts_with('(') {
-                fixed.insert(0, '(');
-            }
-            fixed.push_str(", ref)");
-
-            fixer.replace(params.span, fixed).with_message("add `ref` parameter")
-        }),
-    );
+    ctx.diagnostic_with_suggestions(forward_ref_uses_ref_diagnostic(span), |fixer| {
+        let fixed = ctx.source_range(params.span);
+        // remove the trailing `)`, `` and `,` if they exist
+        let fixed = fixed.strip_suffix(')').unwrap_or(fixed).trim_end();
+        let mut fixed = fixed.strip_suffix(',').unwrap_or(fixed).to_string();
+
+        if !fixed.starts_with('(') {
+            fixed.insert(0, '(');
+        }
+        fixed.push_str(", ref)");
+
+        vec![
+            fixer
+                .replace_with(call_expr, exp)
+                .with_message("remove `forwardRef` wrapper")
+                .with_name("remove-forward-ref"),
+            fixer
+                .replace(params.span, fixed)
+                .with_message("add `ref` parameter")
+                .with_name("add-ref-parameter"),
+        ]
+    });
 }
 
 impl Rule for ForwardRefUsesRef {
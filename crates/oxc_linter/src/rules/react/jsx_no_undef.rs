@@ -73,7 +73,7 @@ impl Rule for JsxNoUndef {
                     return;
                 }
                 let name = ident.name.as_str();
-                if ctx.globals().is_enabled(name) {
+                if ctx.is_global_enabled(name) {
                     return;
                 }
                 ctx.diagnostic(jsx_no_undef_diagnostic(name, ident.span));
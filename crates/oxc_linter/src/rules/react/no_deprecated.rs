@@ -0,0 +1,351 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, ImportDeclarationSpecifier, PropertyKey},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_parent_component, is_es5_component},
+};
+
+fn no_deprecated_diagnostic(span: Span, deprecation: &Deprecation) -> OxcDiagnostic {
+    let (major, minor, patch) = deprecation.since;
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "`{}` is deprecated since React {major}.{minor}.{patch}",
+        deprecation.name
+    ));
+    match deprecation.replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead")),
+        None => diagnostic,
+    }
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecated;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow usage of deprecated methods of React.
+    ///
+    /// Methods are only reported when they are deprecated in the React version configured with
+    /// `settings.react.version`, which defaults to the latest version:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "react": {
+    ///       "version": "16.8.0"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated methods are removed in later versions of React, and often have issues which
+    /// are fixed by their replacements.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// React.render(<MyComponent />, root);
+    ///
+    /// ReactDOM.render(<MyComponent />, root);
+    ///
+    /// class Foo extends React.Component {
+    ///   componentWillMount() {}
+    /// }
+    ///
+    /// import { PropTypes } from 'react';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// createRoot(root).render(<MyComponent />);
+    ///
+    /// class Foo extends React.Component {
+    ///   UNSAFE_componentWillMount() {}
+    /// }
+    ///
+    /// import PropTypes from 'prop-types';
+    /// ```
+    NoDeprecated,
+    react,
+    suspicious
+);
+
+struct Deprecation {
+    /// The method, e.g. `React.render`.
+    name: &'static str,
+    /// The version of React which deprecated the method.
+    since: (u32, u32, u32),
+    replacement: Option<&'static str>,
+}
+
+const fn deprecation(
+    name: &'static str,
+    since: (u32, u32, u32),
+    replacement: Option<&'static str>,
+) -> Deprecation {
+    Deprecation { name, since, replacement }
+}
+
+/// Ref: <https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.5/lib/rules/no-deprecated.js>
+const DEPRECATED_METHODS: [Deprecation; 31] = [
+    // 0.12.0
+    deprecation("React.renderComponent", (0, 12, 0), Some("`React.render`")),
+    deprecation("React.renderComponentToString", (0, 12, 0), Some("`React.renderToString`")),
+    deprecation(
+        "React.renderComponentToStaticMarkup",
+        (0, 12, 0),
+        Some("`React.renderToStaticMarkup`"),
+    ),
+    deprecation("React.isValidComponent", (0, 12, 0), Some("`React.isValidElement`")),
+    deprecation("React.PropTypes.component", (0, 12, 0), Some("`React.PropTypes.element`")),
+    deprecation("React.PropTypes.renderable", (0, 12, 0), Some("`React.PropTypes.node`")),
+    deprecation("React.isValidClass", (0, 12, 0), None),
+    deprecation("this.transferPropsTo", (0, 12, 0), Some("spread operator ({...})")),
+    // 0.13.0
+    deprecation("React.addons.classSet", (0, 13, 0), Some("the npm module `classnames`")),
+    deprecation("React.addons.cloneWithProps", (0, 13, 0), Some("`React.cloneElement`")),
+    // 0.14.0
+    deprecation("React.render", (0, 14, 0), Some("`ReactDOM.render`")),
+    deprecation(
+        "React.unmountComponentAtNode",
+        (0, 14, 0),
+        Some("`ReactDOM.unmountComponentAtNode`"),
+    ),
+    deprecation("React.findDOMNode", (0, 14, 0), Some("`ReactDOM.findDOMNode`")),
+    deprecation("React.renderToString", (0, 14, 0), Some("`ReactDOMServer.renderToString`")),
+    deprecation(
+        "React.renderToStaticMarkup",
+        (0, 14, 0),
+        Some("`ReactDOMServer.renderToStaticMarkup`"),
+    ),
+    // 15.0.0
+    deprecation("React.addons.LinkedStateMixin", (15, 0, 0), None),
+    deprecation("ReactPerf.printDOM", (15, 0, 0), Some("`ReactPerf.printOperations`")),
+    deprecation("Perf.printDOM", (15, 0, 0), Some("`Perf.printOperations`")),
+    deprecation("ReactPerf.getMeasurementsSummaryMap", (15, 0, 0), Some("`ReactPerf.getWasted`")),
+    deprecation("Perf.getMeasurementsSummaryMap", (15, 0, 0), Some("`Perf.getWasted`")),
+    // 15.5.0
+    deprecation("React.createClass", (15, 5, 0), Some("the npm module `create-react-class`")),
+    deprecation("React.addons.TestUtils", (15, 5, 0), Some("`react-dom/test-utils`")),
+    deprecation("React.PropTypes", (15, 5, 0), Some("the npm module `prop-types`")),
+    // 15.6.0
+    deprecation("React.DOM", (15, 6, 0), Some("the npm module `react-dom-factories`")),
+    // 16.9.0
+    deprecation("componentWillMount", (16, 9, 0), Some("`UNSAFE_componentWillMount`")),
+    deprecation(
+        "componentWillReceiveProps",
+        (16, 9, 0),
+        Some("`UNSAFE_componentWillReceiveProps`"),
+    ),
+    deprecation("componentWillUpdate", (16, 9, 0), Some("`UNSAFE_componentWillUpdate`")),
+    // 18.0.0
+    deprecation("ReactDOM.render", (18, 0, 0), Some("`createRoot`")),
+    deprecation("ReactDOM.hydrate", (18, 0, 0), Some("`hydrateRoot`")),
+    deprecation("ReactDOM.unmountComponentAtNode", (18, 0, 0), Some("`root.unmount`")),
+    deprecation("ReactDOMServer.renderToNodeStream", (18, 0, 0), Some("`renderToPipeableStream`")),
+];
+
+/// The names of the modules whose exports are checked, e.g. `React` for `react`.
+fn module_object_name(source: &str) -> Option<&'static str> {
+    match source {
+        "react" | "react/addons" => Some("React"),
+        "react-dom" => Some("ReactDOM"),
+        "react-dom/server" => Some("ReactDOMServer"),
+        _ => None,
+    }
+}
+
+const LIFECYCLE_METHODS: [&str; 3] =
+    ["componentWillMount", "componentWillReceiveProps", "componentWillUpdate"];
+
+impl NoDeprecated {
+    fn check(ctx: &LintContext, name: &str, span: Span) {
+        let Some(deprecation) = DEPRECATED_METHODS.iter().find(|d| d.name == name) else {
+            return;
+        };
+        let (major, minor, patch) = deprecation.since;
+        if ctx.settings().react.version_at_least(major, minor, patch) {
+            ctx.diagnostic(no_deprecated_diagnostic(span, deprecation));
+        }
+    }
+}
+
+impl Rule for NoDeprecated {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::StaticMemberExpression(member_expr) => {
+                let mut name = member_expr.property.name.to_string();
+                let mut object = &member_expr.object;
+                loop {
+                    match object.get_inner_expression() {
+                        Expression::Identifier(ident) => {
+                            name.insert(0, '.');
+                            name.insert_str(0, &ident.name);
+                            break;
+                        }
+                        Expression::ThisExpression(_) => {
+                            name.insert_str(0, "this.");
+                            break;
+                        }
+                        Expression::StaticMemberExpression(inner) => {
+                            name.insert(0, '.');
+                            name.insert_str(0, &inner.property.name);
+                            object = &inner.object;
+                        }
+                        _ => return,
+                    }
+                }
+                Self::check(ctx, &name, member_expr.span);
+            }
+            AstKind::ImportDeclaration(import_decl) => {
+                let Some(object_name) = module_object_name(&import_decl.source.value) else {
+                    return;
+                };
+                for specifier in import_decl.specifiers.iter().flatten() {
+                    let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else {
+                        continue;
+                    };
+                    let name = format!("{object_name}.{}", specifier.imported.name());
+                    Self::check(ctx, &name, specifier.span);
+                }
+            }
+            AstKind::MethodDefinition(method) => {
+                let Some(name) = method.key.static_name() else { return };
+                if !LIFECYCLE_METHODS.contains(&name.as_ref()) {
+                    return;
+                }
+                // The method of a class component
+                if get_parent_component(node, ctx).is_some() {
+                    Self::check(ctx, &name, method.key.span());
+                }
+            }
+            AstKind::ObjectProperty(property) => {
+                let PropertyKey::StaticIdentifier(key) = &property.key else { return };
+                if !LIFECYCLE_METHODS.contains(&key.name.as_str()) {
+                    return;
+                }
+                // A method of the spec of `createReactClass`
+                let is_create_class_spec = ctx
+                    .nodes()
+                    .ancestors(node.id())
+                    .find(|ancestor| {
+                        !matches!(
+                            ancestor.kind(),
+                            AstKind::ObjectExpression(_) | AstKind::Argument(_)
+                        )
+                    })
+                    .is_some_and(is_es5_component);
+                if is_create_class_spec {
+                    Self::check(ctx, &key.name, key.span);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var element = React.createElement('p', {}, null);", None, None),
+        ("var clone = React.cloneElement(element);", None, None),
+        ("ReactDOMServer.renderToString(element);", None, None),
+        ("ReactDOMServer.renderToStaticMarkup(element);", None, None),
+        ("createRoot(root).render(<MyComponent />);", None, None),
+        ("hydrateRoot(root, <MyComponent />);", None, None),
+        ("import PropTypes from 'prop-types';", None, None),
+        ("import { createRoot } from 'react-dom/client';", None, None),
+        ("import { render } from 'some-renderer';", None, None),
+        ("var foo = { componentWillMount() {} };", None, None),
+        ("class Foo { componentWillMount() {} }", None, None),
+        ("class Foo extends React.Component { UNSAFE_componentWillMount() {} }", None, None),
+        (
+            "ReactDOM.render(<MyComponent />, root);",
+            None,
+            Some(json!({ "settings": { "react": { "version": "17.0.2" } } })),
+        ),
+        (
+            "class Foo extends React.Component { componentWillMount() {} }",
+            None,
+            Some(json!({ "settings": { "react": { "version": "16.8" } } })),
+        ),
+        (
+            "React.createClass({});",
+            None,
+            Some(json!({ "settings": { "react": { "version": "15.4.0" } } })),
+        ),
+        (
+            "React.render(element, root);",
+            None,
+            Some(json!({ "settings": { "react": { "version": "0.13" } } })),
+        ),
+    ];
+
+    let fail = vec![
+        ("React.renderComponent()", None, None),
+        ("React.render(element, root);", None, None),
+        ("React.createClass({});", None, None),
+        ("var PropTypes = React.PropTypes;", None, None),
+        ("React.PropTypes.component;", None, None),
+        ("React.DOM.div();", None, None),
+        ("this.transferPropsTo(child);", None, None),
+        ("ReactDOM.render(<MyComponent />, root);", None, None),
+        ("ReactDOM.hydrate(<MyComponent />, root);", None, None),
+        ("ReactDOM.unmountComponentAtNode(root);", None, None),
+        ("ReactDOMServer.renderToNodeStream(element);", None, None),
+        ("import { render, hydrate } from 'react-dom';", None, None),
+        ("import { PropTypes } from 'react';", None, None),
+        ("import { renderToNodeStream } from 'react-dom/server';", None, None),
+        (
+            "
+            class Foo extends React.Component {
+              componentWillMount() {}
+              componentWillReceiveProps() {}
+              componentWillUpdate() {}
+            }
+            ",
+            None,
+            None,
+        ),
+        ("class Foo extends PureComponent { componentWillMount() {} }", None, None),
+        ("createReactClass({ componentWillUpdate() {} });", None, None),
+        ("React.createClass({ componentWillReceiveProps: function() {} });", None, None),
+        (
+            "ReactDOM.render(<MyComponent />, root);",
+            None,
+            Some(json!({ "settings": { "react": { "version": "18.2.0" } } })),
+        ),
+        (
+            "class Foo extends React.Component { componentWillMount() {} }",
+            None,
+            Some(json!({ "settings": { "react": { "version": "16.9.0" } } })),
+        ),
+        (
+            "React.render(element, root);",
+            None,
+            Some(json!({ "settings": { "react": { "version": "detect" } } })),
+        ),
+    ];
+
+    Tester::new(NoDeprecated::NAME, NoDeprecated::PLUGIN, pass, fail).test_and_snapshot();
+}
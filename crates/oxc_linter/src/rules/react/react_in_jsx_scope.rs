@@ -5,6 +5,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
+    config::JsxRuntime,
     context::{ContextHost, LintContext},
     rule::Rule,
 };
@@ -28,6 +29,9 @@ declare_oxc_lint!(
     /// When using JSX, `<a />` expands to `React.createElement("a")`. Therefore
     /// the `React` variable must be in scope.
     ///
+    /// Nothing is reported when `settings.react.jsxRuntime` is `"automatic"`, because the
+    /// automatic JSX runtime of React 17 imports the functions it needs itself.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -52,6 +56,9 @@ impl Rule for ReactInJsxScope {
             AstKind::JSXFragment(v) => v.opening_fragment.span,
             _ => return,
         };
+        if ctx.settings().react.jsx_runtime() == JsxRuntime::Automatic {
+            return;
+        }
         let scope = ctx.scoping();
         let react_name = "React";
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
@@ -73,13 +80,13 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("var React, App; <App />;", None),
-        ("var React; <img />;", None),
-        ("var React; <>fragment</>;", None),
-        ("var React; <x-gif />;", None),
-        ("var React, App, a=1; <App attr={a} />;", None),
-        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None),
-        ("var React, App; <App />;", None),
+        ("var React, App; <App />;", None, None),
+        ("var React; <img />;", None, None),
+        ("var React; <>fragment</>;", None, None),
+        ("var React; <x-gif />;", None, None),
+        ("var React, App, a=1; <App attr={a} />;", None, None),
+        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None, None),
+        ("var React, App; <App />;", None, None),
         (
             "
 			        import React from 'react/addons';
@@ -93,16 +100,27 @@ fn test() {
 			        export default Button;
 			      ",
             None,
+            None,
+        ),
+        ("var React, a = <img />;", None, None),
+        (
+            "var a = <App />;",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "jsxRuntime": "automatic" } } })),
         ),
-        ("var React, a = <img />;", None),
     ];
 
     let fail = vec![
-        ("var App, a = <App />;", None),
-        ("var a = <App />;", None),
-        ("var a = <img />;", None),
-        ("var a = <>fragment</>;", None),
-        ("var Foo, a = <img />;", None),
+        ("var App, a = <App />;", None, None),
+        ("var a = <App />;", None, None),
+        ("var a = <img />;", None, None),
+        ("var a = <>fragment</>;", None, None),
+        ("var Foo, a = <img />;", None, None),
+        (
+            "var a = <img />;",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "jsxRuntime": "classic" } } })),
+        ),
     ];
 
     Tester::new(ReactInJsxScope::NAME, ReactInJsxScope::PLUGIN, pass, fail).test_and_snapshot();
@@ -0,0 +1,227 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{ModuleImport, Sensitivity, is_static_string_argument, resolve_module_import},
+};
+
+fn non_literal_shell_command_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{method}` called with a non-literal command"))
+        .with_help("If the command contains user input, an attacker can run arbitrary shell commands. Use `execFile` or `spawn` with an array of arguments instead")
+        .with_label(span)
+}
+
+fn non_literal_file_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{method}` called with a non-literal file"))
+        .with_help("If the file contains user input, an attacker can run arbitrary programs")
+        .with_label(span)
+}
+
+fn child_process_import_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Found import of `child_process`")
+        .with_help("Make sure commands run through this module can't be influenced by user input")
+        .with_label(span)
+}
+
+const CHILD_PROCESS: &str = "child_process";
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectChildProcess {
+    /// How eagerly to report. One of `"low"`, `"medium"` or `"high"`.
+    sensitivity: Sensitivity,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects `child_process` functions called with a command which is not a string literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `exec` runs its command through a shell. If any part of the command comes from user
+    /// input, an attacker can append their own commands (e.g. `; rm -rf /`).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { exec } from "node:child_process";
+    /// exec(`git log ${branch}`);
+    ///
+    /// const childProcess = require("child_process");
+    /// childProcess.execSync("convert " + file);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { exec, execFile } from "node:child_process";
+    /// exec("git log");
+    /// execFile("git", ["log", branch]);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### sensitivity
+    ///
+    /// `{ type: "low" | "medium" | "high", default: "medium" }`
+    ///
+    /// * `"low"`: only report `exec` and `execSync`, which run the command through a shell.
+    /// * `"medium"`: also report `spawn`, `spawnSync`, `execFile`, `execFileSync` and `fork`
+    ///   called with a non-literal file.
+    /// * `"high"`: also report every import or `require` of `child_process`, so each use can be
+    ///   reviewed.
+    DetectChildProcess,
+    security,
+    restriction,
+    config = DetectChildProcess,
+);
+
+impl Rule for DetectChildProcess {
+    fn from_configuration(value: Value) -> Self {
+        Self { sensitivity: Sensitivity::from_configuration(&value) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) if self.sensitivity == Sensitivity::High => {
+                if is_child_process(decl.source.value.as_str()) {
+                    ctx.diagnostic(child_process_import_diagnostic(decl.source.span));
+                }
+            }
+            AstKind::CallExpression(call) => {
+                if self.sensitivity == Sensitivity::High && is_child_process_require(call) {
+                    ctx.diagnostic(child_process_import_diagnostic(call.span));
+                    return;
+                }
+                self.check_call(call, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DetectChildProcess {
+    fn check_call<'a>(&self, call: &CallExpression<'a>, ctx: &LintContext<'a>) {
+        let Some(method) = child_process_method_name(&call.callee, ctx) else { return };
+        let Some(command) = call.arguments.first() else { return };
+        if is_static_string_argument(command, ctx) {
+            return;
+        }
+
+        match method {
+            "exec" | "execSync" => {
+                ctx.diagnostic(non_literal_shell_command_diagnostic(command.span(), method));
+            }
+            "spawn" | "spawnSync" | "execFile" | "execFileSync" | "fork"
+                if self.sensitivity >= Sensitivity::Medium =>
+            {
+                ctx.diagnostic(non_literal_file_diagnostic(command.span(), method));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Get the name of the `child_process` function `callee` refers to, if it does.
+fn child_process_method_name<'a, 'c>(
+    callee: &'c Expression<'a>,
+    ctx: &'c LintContext<'a>,
+) -> Option<&'c str> {
+    match callee.without_parentheses() {
+        // `exec(...)` where `exec` is imported from `child_process`
+        Expression::Identifier(ident) => match resolve_module_import(ident, ctx)? {
+            ModuleImport { module: CHILD_PROCESS, imported } => imported,
+            ModuleImport { .. } => None,
+        },
+        // `childProcess.exec(...)`
+        expr => {
+            let member = expr.as_member_expression()?;
+            let Expression::Identifier(object) = member.object().without_parentheses() else {
+                return None;
+            };
+            match resolve_module_import(object, ctx)? {
+                ModuleImport { module: CHILD_PROCESS, imported: None } => {
+                    member.static_property_name()
+                }
+                ModuleImport { .. } => None,
+            }
+        }
+    }
+}
+
+/// `require('child_process')`
+fn is_child_process_require(call: &CallExpression) -> bool {
+    call.is_require_call()
+        && matches!(
+            call.arguments.first(),
+            Some(Argument::StringLiteral(source)) if is_child_process(source.value.as_str())
+        )
+}
+
+fn is_child_process(module: &str) -> bool {
+    module.strip_prefix("node:").unwrap_or(module) == CHILD_PROCESS
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import { exec } from 'child_process'; exec('ls')", None),
+        ("import { exec } from 'child_process'; exec(`ls -la`)", None),
+        ("import { exec } from 'child_process'; exec('ls ' + '-la')", None),
+        ("import { exec } from 'child_process'; const FLAGS = '-la'; exec(`ls ${FLAGS}`)", None),
+        ("import { execFile } from 'child_process'; execFile('git', ['log', branch])", None),
+        ("import { spawn } from 'child_process'; spawn('git', args)", None),
+        ("import cp from 'child_process'; cp.exec('ls')", None),
+        ("const cp = require('child_process'); cp.execSync('ls')", None),
+        ("const { exec } = require('child_process'); exec('ls')", None),
+        ("import { exec } from './exec'; exec(command)", None),
+        ("const { exec } = require('shelljs'); exec(command)", None),
+        ("function f(exec) { exec(command) }", None),
+        ("exec(command)", None),
+        ("db.exec(command)", None),
+        ("const cp = require('child_process');", None),
+        ("import { exec } from 'child_process';", None),
+        (
+            "import { spawn } from 'child_process'; spawn(command)",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("import { exec } from 'child_process'; exec(command)", None),
+        ("import { exec } from 'node:child_process'; exec(`git log ${branch}`)", None),
+        ("import { execSync } from 'child_process'; execSync('convert ' + file)", None),
+        ("import { exec as run } from 'child_process'; run(command)", None),
+        ("import cp from 'child_process'; cp.exec(command)", None),
+        ("import * as cp from 'child_process'; cp.exec(command)", None),
+        ("const cp = require('child_process'); cp.execSync(command)", None),
+        ("const { exec } = require('child_process'); exec(command)", None),
+        ("import { spawn } from 'child_process'; spawn(command)", None),
+        ("import { execFile } from 'child_process'; execFile(file, args)", None),
+        ("import { fork } from 'child_process'; fork(modulePath)", None),
+        (
+            "import { exec } from 'child_process'; exec(command)",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        ("import { exec } from 'child_process';", Some(json!([{ "sensitivity": "high" }]))),
+        ("const cp = require('node:child_process');", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    Tester::new(DetectChildProcess::NAME, DetectChildProcess::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
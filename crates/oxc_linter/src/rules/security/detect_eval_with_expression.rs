@@ -0,0 +1,189 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{Sensitivity, is_static_string_argument},
+};
+
+fn eval_with_expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`eval` called with a non-literal argument")
+        .with_help("If the argument contains user input, an attacker can execute arbitrary code. Parse the data instead, e.g. with `JSON.parse`")
+        .with_label(span)
+}
+
+fn function_with_expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`Function` constructor called with a non-literal argument")
+        .with_help("If the arguments contain user input, an attacker can execute arbitrary code")
+        .with_label(span)
+}
+
+/// Objects which refer to the global object, and so have an `eval` property.
+const GLOBAL_OBJECTS: [&str; 4] = ["globalThis", "window", "self", "global"];
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectEvalWithExpression {
+    /// How eagerly to report. One of `"low"`, `"medium"` or `"high"`.
+    sensitivity: Sensitivity,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects `eval` called with an argument which is not a string literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `eval` executes its argument as code, with the privileges of the caller. If any part of
+    /// the argument can be influenced by a user, they can run arbitrary code.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// eval(input);
+    /// eval("var x = " + input);
+    /// globalThis.eval(`${input}`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval("var x = 1");
+    /// const data = JSON.parse(input);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### sensitivity
+    ///
+    /// `{ type: "low" | "medium" | "high", default: "medium" }`
+    ///
+    /// * `"low"`: only report direct calls to `eval(...)`.
+    /// * `"medium"`: also report calls through the global object (`globalThis.eval(...)`,
+    ///   `window.eval(...)`) and indirect calls (`(0, eval)(...)`).
+    /// * `"high"`: also report `Function(...)` and `new Function(...)` with non-literal arguments.
+    DetectEvalWithExpression,
+    security,
+    restriction,
+    config = DetectEvalWithExpression,
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn from_configuration(value: Value) -> Self {
+        Self { sensitivity: Sensitivity::from_configuration(&value) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::CallExpression(call) => {
+                if self.is_eval(&call.callee, ctx) {
+                    if let Some(argument) =
+                        call.arguments.first().filter(|arg| !is_static_string_argument(arg, ctx))
+                    {
+                        ctx.diagnostic(eval_with_expression_diagnostic(argument.span()));
+                    }
+                } else if self.is_function_constructor(&call.callee, ctx)
+                    && has_non_literal_argument(&call.arguments, ctx)
+                {
+                    ctx.diagnostic(function_with_expression_diagnostic(call.span));
+                }
+            }
+            AstKind::NewExpression(new_expr) => {
+                if self.is_function_constructor(&new_expr.callee, ctx)
+                    && has_non_literal_argument(&new_expr.arguments, ctx)
+                {
+                    ctx.diagnostic(function_with_expression_diagnostic(new_expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DetectEvalWithExpression {
+    fn is_eval(&self, callee: &Expression, ctx: &LintContext) -> bool {
+        if callee.is_global_reference_name("eval", ctx.scoping()) {
+            return true;
+        }
+        if self.sensitivity == Sensitivity::Low {
+            return false;
+        }
+
+        match callee.without_parentheses() {
+            // `(0, eval)(...)`
+            Expression::SequenceExpression(sequence) => sequence
+                .expressions
+                .last()
+                .is_some_and(|last| last.is_global_reference_name("eval", ctx.scoping())),
+            // `globalThis.eval(...)`, `window["eval"](...)`
+            expr => expr.as_member_expression().is_some_and(|member| {
+                member.static_property_name() == Some("eval")
+                    && GLOBAL_OBJECTS.iter().any(|global| {
+                        member.object().is_global_reference_name(global, ctx.scoping())
+                    })
+            }),
+        }
+    }
+
+    fn is_function_constructor(&self, callee: &Expression, ctx: &LintContext) -> bool {
+        self.sensitivity == Sensitivity::High
+            && callee.is_global_reference_name("Function", ctx.scoping())
+    }
+}
+
+fn has_non_literal_argument<'a>(arguments: &[Argument<'a>], ctx: &LintContext<'a>) -> bool {
+    arguments.iter().any(|arg| !is_static_string_argument(arg, ctx))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("eval('var x = 1')", None),
+        ("eval(`var x = 1`)", None),
+        ("eval('var x = ' + '1')", None),
+        ("eval()", None),
+        ("const code = 'var x = 1'; eval(code)", None),
+        ("const x = 1; eval(`var y = ${x}`)", None),
+        ("const Code = Object.freeze({ X: 'var x = 1' }); eval(Code.X)", None),
+        ("enum Code { X = 'var x = 1' } eval(Code.X)", None),
+        ("const Code = { X: 'var x = 1' } as const; eval(Code['X'])", None),
+        ("function f(eval) { eval(input) }", None),
+        ("obj.eval(input)", None),
+        ("globalThis.eval(input)", Some(json!([{ "sensitivity": "low" }]))),
+        ("(0, eval)(input)", Some(json!([{ "sensitivity": "low" }]))),
+        ("new Function(input)", None),
+        ("new Function('a', 'return a')", Some(json!([{ "sensitivity": "high" }]))),
+        ("Function()", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    let fail = vec![
+        ("eval(input)", None),
+        ("eval('var x = ' + input)", None),
+        ("eval(`var x = ${input}`)", None),
+        ("eval(...args)", None),
+        ("eval(input)", Some(json!([{ "sensitivity": "low" }]))),
+        ("globalThis.eval(input)", None),
+        ("window['eval'](input)", None),
+        ("(0, eval)(input)", None),
+        ("new Function(input)", Some(json!([{ "sensitivity": "high" }]))),
+        ("Function('a', body)", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, DetectEvalWithExpression::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
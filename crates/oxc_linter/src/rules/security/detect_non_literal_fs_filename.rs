@@ -0,0 +1,295 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{ModuleImport, Sensitivity, is_static_string, resolve_module_import},
+};
+
+fn non_literal_fs_filename_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{method}` called with a non-literal path"))
+        .with_help("If the path contains user input, an attacker can read or write arbitrary files. Validate the path, e.g. check it's inside an expected directory after resolving it")
+        .with_label(span)
+}
+
+/// Modules which export the `fs` API.
+const FS_MODULES: [&str; 4] = ["fs", "fs/promises", "fs-extra", "graceful-fs"];
+
+/// Names commonly given to the `fs` module. Only checked with `"high"` sensitivity,
+/// for bindings which can't be resolved to an import.
+const FS_NAMES: [&str; 4] = ["fs", "fsPromises", "fse", "fsExtra"];
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectNonLiteralFsFilename {
+    /// How eagerly to report. One of `"low"`, `"medium"` or `"high"`.
+    sensitivity: Sensitivity,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects `fs` methods called with a path which is not a string literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a path passed to `fs` contains user input, an attacker can use `../` segments or
+    /// absolute paths to read, overwrite or delete files outside the intended directory.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// fs.readFileSync(req.query.file);
+    /// fs.writeFile(`${dir}/${name}`, data, cb);
+    ///
+    /// const { unlink } = require("fs/promises");
+    /// await unlink(path.join(uploads, name));
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// fs.readFileSync("config.json");
+    /// fs.readFileSync(path.join(__dirname, "config.json"));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### sensitivity
+    ///
+    /// `{ type: "low" | "medium" | "high", default: "medium" }`
+    ///
+    /// * `"low"`: only report paths which are entirely dynamic. Paths which start with a
+    ///   literal or `__dirname` (e.g. `` `./data/${name}` ``, `path.join(__dirname, name)`)
+    ///   are allowed.
+    /// * `"medium"`: report any path which is not fully known statically.
+    /// * `"high"`: also check method calls on objects conventionally named `fs` (e.g. `fs`,
+    ///   `fsPromises`, `fse`) which can't be resolved to an import, e.g. when passed in as a
+    ///   parameter.
+    DetectNonLiteralFsFilename,
+    security,
+    restriction,
+    config = DetectNonLiteralFsFilename,
+);
+
+impl Rule for DetectNonLiteralFsFilename {
+    fn from_configuration(value: Value) -> Self {
+        Self { sensitivity: Sensitivity::from_configuration(&value) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(method) = self.fs_method_name(&call.callee, ctx) else { return };
+        let Some(path_arguments) = path_argument_count(method) else { return };
+
+        for argument in call.arguments.iter().take(path_arguments) {
+            if !self.is_safe_path_argument(argument, ctx) {
+                ctx.diagnostic(non_literal_fs_filename_diagnostic(argument.span(), method));
+                return;
+            }
+        }
+    }
+}
+
+impl DetectNonLiteralFsFilename {
+    /// Get the name of the `fs` method `callee` refers to, if it does.
+    fn fs_method_name<'a, 'c>(
+        &self,
+        callee: &'c Expression<'a>,
+        ctx: &'c LintContext<'a>,
+    ) -> Option<&'c str> {
+        match callee.without_parentheses() {
+            // `readFile(...)` where `readFile` is imported from `fs`
+            Expression::Identifier(ident) => match resolve_module_import(ident, ctx)? {
+                ModuleImport { module, imported: Some(imported) }
+                    if FS_MODULES.contains(&module) =>
+                {
+                    Some(imported)
+                }
+                _ => None,
+            },
+            expr => {
+                let member = expr.as_member_expression()?;
+                let method = member.static_property_name()?;
+                self.is_fs_object(member.object(), ctx).then_some(method)
+            }
+        }
+    }
+
+    /// `fs`, `fs.promises`
+    fn is_fs_object<'a>(&self, object: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match object.without_parentheses() {
+            Expression::Identifier(ident) => match resolve_module_import(ident, ctx) {
+                Some(ModuleImport { module, imported }) => {
+                    FS_MODULES.contains(&module) && matches!(imported, None | Some("promises"))
+                }
+                None => {
+                    self.sensitivity == Sensitivity::High && FS_NAMES.contains(&ident.name.as_str())
+                }
+            },
+            expr => expr.as_member_expression().is_some_and(|member| {
+                member.static_property_name() == Some("promises")
+                    && self.is_fs_object(member.object(), ctx)
+            }),
+        }
+    }
+
+    fn is_safe_path_argument<'a>(&self, argument: &Argument<'a>, ctx: &LintContext<'a>) -> bool {
+        argument.as_expression().is_some_and(|expr| {
+            if self.sensitivity == Sensitivity::Low {
+                has_static_prefix(expr, ctx)
+            } else {
+                is_static_path(expr, ctx)
+            }
+        })
+    }
+}
+
+/// Number of leading arguments of `fs` method `method` which are paths,
+/// or `None` if `method` doesn't take paths.
+fn path_argument_count(method: &str) -> Option<usize> {
+    let method = method.strip_suffix("Sync").unwrap_or(method);
+    match method {
+        "access" | "appendFile" | "chmod" | "chown" | "createReadStream" | "createWriteStream"
+        | "exists" | "lchmod" | "lchown" | "lstat" | "lutimes" | "mkdir" | "mkdtemp" | "open"
+        | "opendir" | "readdir" | "readFile" | "readlink" | "realpath" | "rm" | "rmdir"
+        | "stat" | "statfs" | "truncate" | "unlink" | "unwatchFile" | "utimes" | "watch"
+        | "watchFile" | "writeFile" => Some(1),
+        "copyFile" | "cp" | "link" | "rename" | "symlink" => Some(2),
+        _ => None,
+    }
+}
+
+/// A path which is fully known statically, e.g. `'a.txt'`, `__dirname`,
+/// `path.join(__dirname, 'a.txt')`.
+fn is_static_path<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => matches!(ident.name.as_str(), "__dirname" | "__filename"),
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            is_static_path(&binary.left, ctx) && is_static_path(&binary.right, ctx)
+        }
+        Expression::CallExpression(call) => {
+            is_path_call(&call.callee)
+                && call
+                    .arguments
+                    .iter()
+                    .all(|arg| arg.as_expression().is_some_and(|expr| is_static_path(expr, ctx)))
+        }
+        expr => is_static_string(expr, ctx),
+    }
+}
+
+/// A path which starts with a static part, so can't point to an arbitrary location
+/// without `..` segments, e.g. `` `./data/${name}` ``, `path.join(__dirname, name)`.
+fn has_static_prefix<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.without_parentheses() {
+        Expression::TemplateLiteral(template) => {
+            template.quasis.first().is_some_and(|quasi| !quasi.value.raw.is_empty())
+                || template.expressions.first().is_some_and(|expr| has_static_prefix(expr, ctx))
+        }
+        Expression::BinaryExpression(binary) => has_static_prefix(&binary.left, ctx),
+        Expression::CallExpression(call) => {
+            is_path_call(&call.callee)
+                && call
+                    .arguments
+                    .first()
+                    .and_then(Argument::as_expression)
+                    .is_some_and(|expr| has_static_prefix(expr, ctx))
+        }
+        expr => is_static_path(expr, ctx),
+    }
+}
+
+/// `path.join(...)`, `path.resolve(...)`
+fn is_path_call(callee: &Expression) -> bool {
+    callee.as_member_expression().is_some_and(|member| {
+        member.object().is_specific_id("path")
+            && matches!(member.static_property_name(), Some("join" | "resolve"))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import fs from 'fs'; fs.readFileSync('a.txt')", None),
+        ("import fs from 'fs'; fs.readFileSync(`a.txt`)", None),
+        ("import fs from 'fs'; fs.readFileSync('dir/' + 'a.txt')", None),
+        ("import fs from 'fs'; const DIR = 'dir/'; fs.readFileSync(DIR + 'a.txt')", None),
+        ("import fs from 'fs'; fs.readFileSync(__dirname)", None),
+        ("import fs from 'fs'; fs.readFileSync(__dirname + '/a.txt')", None),
+        ("import fs from 'fs'; fs.readFileSync(path.join(__dirname, 'a.txt'))", None),
+        ("import fs from 'fs'; fs.readFile(path.resolve('a', 'b'), cb)", None),
+        ("import fs from 'fs'; fs.writeFileSync('a.txt', data)", None),
+        ("import fs from 'fs'; fs.rename('a.txt', 'b.txt', cb)", None),
+        ("import fs from 'fs'; fs.closeSync(fd)", None),
+        ("import fs from 'fs'; fs.fstat(fd, cb)", None),
+        ("import { readFile } from 'fs'; readFile('a.txt', cb)", None),
+        ("import * as path from 'path'; path.join(name)", None),
+        ("const fs = require('fs'); fs.readFileSync('a.txt')", None),
+        ("const fs = require('graceful-fs'); fs.readFileSync('a.txt')", None),
+        ("const db = require('db'); db.readFile(name)", None),
+        ("import { readFile } from './utils'; readFile(name)", None),
+        ("fs.readFileSync(name)", None),
+        ("function f(fs) { fs.readFileSync(name) }", None),
+        ("const readFile = (name) => name; readFile(name)", None),
+        (
+            "import fs from 'fs'; fs.readFileSync(`./data/${name}`)",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        (
+            "import fs from 'fs'; fs.readFileSync(path.join(__dirname, name))",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        (
+            "import fs from 'fs'; fs.readFileSync(__dirname + '/' + name)",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        ("function f(fs) { fs.readFileSync('a.txt') }", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    let fail = vec![
+        ("import fs from 'fs'; fs.readFileSync(name)", None),
+        ("import fs from 'node:fs'; fs.readFileSync(name)", None),
+        ("import * as fs from 'fs'; fs.readFileSync(req.query.file)", None),
+        ("import fs from 'fs'; fs.readFileSync(`./data/${name}`)", None),
+        ("import fs from 'fs'; fs.readFileSync(path.join(__dirname, name))", None),
+        ("import fs from 'fs'; fs.readFileSync(__dirname + '/' + name)", None),
+        ("import fs from 'fs'; fs.writeFile(name, data, cb)", None),
+        ("import fs from 'fs'; fs.rename('a.txt', name, cb)", None),
+        ("import fs from 'fs'; fs.promises.readFile(name)", None),
+        ("import fs from 'fs-extra'; fs.readFile(name)", None),
+        ("import fsPromises from 'fs/promises'; fsPromises.unlink(name)", None),
+        ("import { promises as fsp } from 'fs'; fsp.unlink(name)", None),
+        ("import { readFile } from 'fs'; readFile(name, cb)", None),
+        ("import { readFile as read } from 'node:fs/promises'; read(name)", None),
+        ("const fs = require('fs'); fs.createReadStream(name)", None),
+        ("const { readFileSync } = require('fs'); readFileSync(name)", None),
+        ("const { readFileSync: read } = require('fs'); read(name)", None),
+        ("import fs from 'fs'; fs.readFileSync(name)", Some(json!([{ "sensitivity": "low" }]))),
+        (
+            "import fs from 'fs'; fs.readFileSync(`${dir}/${name}`)",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        ("function f(fs) { fs.readFileSync(name) }", Some(json!([{ "sensitivity": "high" }]))),
+        ("fsPromises.readFile(name)", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    Tester::new(DetectNonLiteralFsFilename::NAME, DetectNonLiteralFsFilename::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
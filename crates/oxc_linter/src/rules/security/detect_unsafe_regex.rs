@@ -0,0 +1,314 @@
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::ast::{Disjunction, Quantifier, Term};
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{Sensitivity, is_static_string_argument, run_on_regex_node},
+};
+
+fn nested_quantifiers_diagnostic(outer: Span, inner: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unsafe regular expression: nested quantifiers can cause catastrophic backtracking")
+        .with_help("Rewrite the pattern so that a repeated part cannot itself be repeated, or validate the input length before matching")
+        .with_labels([outer.label("This quantifier repeats..."), inner.label("...a term which is itself repeated")])
+}
+
+fn too_many_quantifiers_diagnostic(span: Span, count: usize) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Unsafe regular expression: {count} quantifiers exceed the limit of {MAX_QUANTIFIERS}"
+    ))
+    .with_help("Complex patterns are hard to audit for catastrophic backtracking. Split the pattern, or parse the input in code")
+    .with_label(span)
+}
+
+fn non_literal_regexp_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`RegExp` constructed from a non-literal value")
+        .with_help("If the pattern comes from user input, an attacker can supply one which causes catastrophic backtracking")
+        .with_label(span)
+}
+
+/// Number of quantifiers above which a pattern is considered too complex to be safe.
+/// Same limit as `safe-regex`, which `eslint-plugin-security` uses.
+const MAX_QUANTIFIERS: usize = 25;
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectUnsafeRegex {
+    /// How eagerly to report. One of `"low"`, `"medium"` or `"high"`.
+    sensitivity: Sensitivity,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects regular expressions which are vulnerable to catastrophic backtracking
+    /// (ReDoS), such as patterns with nested quantifiers.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript regular expression engines backtrack. A pattern like `/(a+)+$/` takes
+    /// exponential time to reject an input like `"aaaaaaaaaaaaaaaaaaaaaaaaaaaa!"`. If such a
+    /// pattern is matched against user input, an attacker can block the event loop with a
+    /// short string.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /(a+)+$/.test(input);
+    /// /^(\w+\s?)*$/.test(input);
+    /// new RegExp("(x+x+)+y");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /a+$/.test(input);
+    /// /^[\w\s]*$/.test(input);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### sensitivity
+    ///
+    /// `{ type: "low" | "medium" | "high", default: "medium" }`
+    ///
+    /// * `"low"`: only report an unbounded quantifier (`*`, `+`, `{n,}`) nested inside
+    ///   another unbounded quantifier.
+    /// * `"medium"`: also report nested quantifiers with a bounded maximum greater than 1
+    ///   (e.g. `(a{1,5}){1,5}`), and patterns with more than 25 quantifiers.
+    /// * `"high"`: also report `RegExp` constructed from a non-literal pattern, which can't be
+    ///   checked statically.
+    ///
+    /// When the inner quantifier is the only content of a non-capturing group, e.g. `(?:a+)+`,
+    /// the rule suggests collapsing it into a single quantifier (`a+`), which matches exactly
+    /// the same strings.
+    DetectUnsafeRegex,
+    security,
+    suspicious,
+    conditional_suggestion,
+    config = DetectUnsafeRegex,
+);
+
+impl Rule for DetectUnsafeRegex {
+    fn from_configuration(value: Value) -> Self {
+        Self { sensitivity: Sensitivity::from_configuration(&value) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if self.sensitivity == Sensitivity::High {
+            check_non_literal_regexp(node, ctx);
+        }
+
+        let is_literal = matches!(node.kind(), AstKind::RegExpLiteral(_));
+        run_on_regex_node(node, ctx, |pattern, span| {
+            let mut analysis = Analysis::new(self.sensitivity);
+            analysis.visit_disjunction(&pattern.body, None, None);
+
+            if let Some(nested) = analysis.nested {
+                let diagnostic = nested_quantifiers_diagnostic(nested.outer, nested.inner);
+                match nested.collapsed.filter(|_| is_literal) {
+                    Some((body, quantifier)) => {
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            let collapsed = format!("{}{quantifier}", ctx.source_range(body));
+                            fixer
+                                .replace(nested.outer, collapsed)
+                                .with_message("Collapse the nested quantifiers into one")
+                        });
+                    }
+                    None => ctx.diagnostic(diagnostic),
+                }
+            } else if self.sensitivity >= Sensitivity::Medium
+                && analysis.quantifiers > MAX_QUANTIFIERS
+            {
+                ctx.diagnostic(too_many_quantifiers_diagnostic(span, analysis.quantifiers));
+            }
+        });
+    }
+}
+
+/// Report `new RegExp(pattern)` / `RegExp(pattern)` where `pattern` is not known statically.
+fn check_non_literal_regexp<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
+    let (callee, arguments) = match node.kind() {
+        AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments),
+        AstKind::CallExpression(expr) => (&expr.callee, &expr.arguments),
+        _ => return,
+    };
+    if !callee.is_global_reference_name("RegExp", ctx.scoping()) {
+        return;
+    }
+    let Some(pattern) = arguments.first() else { return };
+    if is_static_string_argument(pattern, ctx) || matches!(pattern, Argument::RegExpLiteral(_)) {
+        return;
+    }
+    ctx.diagnostic(non_literal_regexp_diagnostic(pattern.span()));
+}
+
+/// A quantifier nested inside another quantifier.
+struct Nested {
+    outer: Span,
+    inner: Span,
+    /// Body and quantifier to replace `outer` with, which match the same strings without nesting.
+    collapsed: Option<(Span, char)>,
+}
+
+struct Analysis {
+    sensitivity: Sensitivity,
+    quantifiers: usize,
+    nested: Option<Nested>,
+}
+
+impl Analysis {
+    fn new(sensitivity: Sensitivity) -> Self {
+        Self { sensitivity, quantifiers: 0, nested: None }
+    }
+
+    /// `repeating` is the closest enclosing quantifier which can repeat its body more than once.
+    /// `unbounded` is the closest enclosing quantifier with no upper bound.
+    fn visit_disjunction(
+        &mut self,
+        disjunction: &Disjunction,
+        repeating: Option<&Quantifier>,
+        unbounded: Option<&Quantifier>,
+    ) {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                self.visit_term(term, repeating, unbounded);
+            }
+        }
+    }
+
+    fn visit_term(
+        &mut self,
+        term: &Term,
+        repeating: Option<&Quantifier>,
+        unbounded: Option<&Quantifier>,
+    ) {
+        match term {
+            Term::Quantifier(quantifier) => {
+                self.quantifiers += 1;
+
+                let is_unbounded = quantifier.max.is_none();
+                let is_repeating = quantifier.max.is_none_or(|max| max > 1);
+                if self.nested.is_none() {
+                    let outer = if self.sensitivity == Sensitivity::Low {
+                        unbounded.filter(|_| is_unbounded)
+                    } else {
+                        repeating.filter(|_| is_repeating)
+                    };
+                    if let Some(outer) = outer {
+                        self.nested = Some(Nested {
+                            outer: outer.span,
+                            inner: quantifier.span,
+                            collapsed: collapse(outer, quantifier),
+                        });
+                    }
+                }
+
+                self.visit_term(
+                    &quantifier.body,
+                    if is_repeating { Some(quantifier) } else { repeating },
+                    if is_unbounded { Some(quantifier) } else { unbounded },
+                );
+            }
+            Term::CapturingGroup(group) => {
+                self.visit_disjunction(&group.body, repeating, unbounded);
+            }
+            Term::IgnoreGroup(group) => self.visit_disjunction(&group.body, repeating, unbounded),
+            Term::LookAroundAssertion(assertion) => {
+                self.visit_disjunction(&assertion.body, repeating, unbounded);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `(?:a+)+` -> `a+`, `(?:a+)*` -> `a*`, `(?:a*)+` -> `a*`, `(?:a*)*` -> `a*`.
+///
+/// Only applies when `inner` is the sole content of a non-capturing group without modifiers
+/// which `outer` repeats, and both quantifiers are greedy `*` or `+`.
+fn collapse(outer: &Quantifier, inner: &Quantifier) -> Option<(Span, char)> {
+    let Term::IgnoreGroup(group) = &outer.body else { return None };
+    if group.modifiers.is_some() {
+        return None;
+    }
+    let [alternative] = group.body.body.as_slice() else { return None };
+    let [Term::Quantifier(only)] = alternative.body.as_slice() else { return None };
+    if only.span != inner.span {
+        return None;
+    }
+
+    let is_star_or_plus = |q: &Quantifier| q.greedy && q.max.is_none() && q.min <= 1;
+    if !is_star_or_plus(outer) || !is_star_or_plus(inner) {
+        return None;
+    }
+
+    let quantifier = if outer.min == 0 || inner.min == 0 { '*' } else { '+' };
+    Some((inner.body.span(), quantifier))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"/a+$/", None),
+        (r"/^[\w\s]*$/", None),
+        (r"/(a+)?b/", None),
+        (r"/(ab){2}/", None),
+        (r"/(a|b)+c/", None),
+        (r"new RegExp('a+b')", None),
+        (r"new RegExp(input)", None),
+        (r"/(a{1,5}){1,5}/", Some(json!([{ "sensitivity": "low" }]))),
+        (r"/(a+){1,5}/", Some(json!([{ "sensitivity": "low" }]))),
+        (
+            r"/a?b?c?d?e?f?g?h?i?j?k?l?m?n?o?p?q?r?s?t?u?v?w?x?y?z?/",
+            Some(json!([{ "sensitivity": "low" }])),
+        ),
+        (r"new RegExp('a+' + 'b')", Some(json!([{ "sensitivity": "high" }]))),
+        (r"new RegExp(/a+/)", Some(json!([{ "sensitivity": "high" }]))),
+        (r"function f(RegExp) { new RegExp(input) }", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    let fail = vec![
+        (r"/(a+)+$/", None),
+        (r"/^(\w+\s?)*$/", None),
+        (r"/(?:a+)+$/", None),
+        (r"/(?:a*)+$/", None),
+        (r"/(?<=(a+)+)b/", None),
+        (r"/(a{1,5}){1,5}/", None),
+        (r"/(a+){1,5}/", None),
+        (r"new RegExp('(x+x+)+y')", None),
+        (r"RegExp('(a*)*b', 'u')", None),
+        (r"/(a+)+$/", Some(json!([{ "sensitivity": "low" }]))),
+        (r"/a?b?c?d?e?f?g?h?i?j?k?l?m?n?o?p?q?r?s?t?u?v?w?x?y?z?/", None),
+        (r"new RegExp(input)", Some(json!([{ "sensitivity": "high" }]))),
+        (r"RegExp(`${prefix}+`)", Some(json!([{ "sensitivity": "high" }]))),
+    ];
+
+    let fix = vec![
+        (r"/(?:a+)+$/", r"/a+$/"),
+        (r"/(?:a+)*$/", r"/a*$/"),
+        (r"/(?:a*)+$/", r"/a*$/"),
+        (r"/(?:[a-z]+)*x/", r"/[a-z]*x/"),
+        (r"/^(?:\d+)+$/", r"/^\d+$/"),
+        // Not applicable: capturing group, lazy quantifier, extra content in group
+        (r"/(a+)+$/", r"/(a+)+$/"),
+        (r"/(?:a+?)+$/", r"/(?:a+?)+$/"),
+        (r"/(?:ba+)+$/", r"/(?:ba+)+$/"),
+        // Not applicable: pattern in a string, where escapes would differ
+        (r"new RegExp('(?:a+)+')", r"new RegExp('(?:a+)+')"),
+    ];
+
+    Tester::new(DetectUnsafeRegex::NAME, DetectUnsafeRegex::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
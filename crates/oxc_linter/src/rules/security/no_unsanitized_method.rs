@@ -0,0 +1,166 @@
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{NoUnsanitizedConfig, get_node_name, is_safe_html},
+};
+
+fn no_unsanitized_method_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsafe call to `{method}`"))
+        .with_help("If the argument contains user input, an attacker can inject scripts. Escape or sanitize the argument, or insert text nodes instead")
+        .with_label(span)
+}
+
+/// Objects which refer to a document, and so have `write` and `writeln` methods.
+const DOCUMENTS: [&str; 3] = ["document", "ownerDocument", "contentDocument"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsanitizedMethod(Box<NoUnsanitizedConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects calls of DOM methods which parse their argument as HTML, e.g. `document.write`
+    /// and `insertAdjacentHTML`, with an argument which may contain markup from user input.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If any part of the HTML can be influenced by a user, they can inject scripts into the page
+    /// (cross-site scripting, XSS).
+    ///
+    /// Values known statically, values built only from those (including `const` variables), and
+    /// values escaped by the functions and template tags of the `escape` option are allowed.
+    ///
+    /// Checked methods:
+    /// * `document.write(...)` and `document.writeln(...)`
+    /// * `insertAdjacentHTML(position, html)`
+    /// * `createContextualFragment(html)`
+    /// * `setHTMLUnsafe(html)`
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// document.write(input);
+    /// el.insertAdjacentHTML("beforeend", `<li>${item}</li>`);
+    /// range.createContextualFragment(html);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// document.write("<p>Loading</p>");
+    /// const row = "<tr></tr>";
+    /// el.insertAdjacentHTML("beforeend", row);
+    /// el.insertAdjacentHTML("beforeend", escapeHTML`<li>${item}</li>`);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### escape
+    ///
+    /// `{ type: { taggedTemplates: string[], methods: string[] } }`
+    ///
+    /// Template tags and functions whose results are safe to use as HTML, by name, e.g.
+    /// `{ "escape": { "methods": ["DOMPurify.sanitize"] } }`.
+    ///
+    /// Default: `{ "taggedTemplates": ["Sanitizer.escapeHTML", "escapeHTML"], "methods": ["Sanitizer.unwrapSafeHTML", "unwrapSafeHTML"] }`
+    NoUnsanitizedMethod,
+    security,
+    restriction,
+    config = NoUnsanitizedConfig,
+);
+
+impl Rule for NoUnsanitizedMethod {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(NoUnsanitizedConfig::from_configuration(&value)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.get_inner_expression().as_member_expression() else {
+            return;
+        };
+        let Some(method) = member.static_property_name() else {
+            return;
+        };
+        let html_arguments = match method {
+            "write" | "writeln" => {
+                let object = get_node_name(member.object());
+                if !object.rsplit('.').next().is_some_and(|name| DOCUMENTS.contains(&name)) {
+                    return;
+                }
+                &call.arguments[..]
+            }
+            "insertAdjacentHTML" => call.arguments.get(1..).unwrap_or_default(),
+            "createContextualFragment" | "setHTMLUnsafe" => {
+                call.arguments.get(..1).unwrap_or_default()
+            }
+            _ => return,
+        };
+        for argument in html_arguments {
+            if !self.is_safe_argument(argument, ctx) {
+                ctx.diagnostic(no_unsanitized_method_diagnostic(argument.span(), method));
+            }
+        }
+    }
+}
+
+impl NoUnsanitizedMethod {
+    fn is_safe_argument<'a>(&self, argument: &Argument<'a>, ctx: &LintContext<'a>) -> bool {
+        argument.as_expression().is_some_and(|expr| is_safe_html(expr, &self.0.escape, ctx))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("document.write('<p>Loading</p>')", None),
+        ("document.writeln('<p>', '</p>')", None),
+        ("document.write()", None),
+        ("const row = '<tr></tr>'; el.insertAdjacentHTML('beforeend', row)", None),
+        ("el.insertAdjacentHTML(position, '<hr>')", None),
+        ("el.insertAdjacentHTML('beforeend', escapeHTML`<li>${item}</li>`)", None),
+        ("el.insertAdjacentHTML('beforeend', Sanitizer.unwrapSafeHTML(html))", None),
+        ("range.createContextualFragment('<b></b>')", None),
+        ("el.setHTMLUnsafe(`<b>${0}</b>`)", None),
+        ("stream.write(input)", None),
+        ("res.write(`<p>${input}</p>`)", None),
+        ("el.insertAdjacentText('beforeend', input)", None),
+        ("insertAdjacentHTML('beforeend', input)", None),
+        (
+            "el.insertAdjacentHTML('beforeend', DOMPurify.sanitize(input))",
+            Some(json!([{ "escape": { "methods": ["DOMPurify.sanitize"] } }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("document.write(input)", None),
+        ("document.writeln('<p>' + input + '</p>')", None),
+        ("document.write('<p>', input, '</p>')", None),
+        ("window.document.write(input)", None),
+        ("el.ownerDocument.write(input)", None),
+        ("iframe.contentDocument.write(input)", None),
+        ("document['write'](input)", None),
+        ("document.write(...parts)", None),
+        ("el.insertAdjacentHTML('beforeend', `<li>${item}</li>`)", None),
+        ("el?.insertAdjacentHTML('beforeend', html)", None),
+        ("range.createContextualFragment(html)", None),
+        ("el.setHTMLUnsafe(html)", None),
+        ("let row = '<tr></tr>'; row += input; el.insertAdjacentHTML('beforeend', row)", None),
+        ("el.insertAdjacentHTML('beforeend', DOMPurify.sanitize(input))", None),
+    ];
+
+    Tester::new(NoUnsanitizedMethod::NAME, NoUnsanitizedMethod::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,151 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{NoUnsanitizedConfig, is_safe_html},
+};
+
+fn no_unsanitized_property_diagnostic(span: Span, property: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsafe assignment to `{property}`"))
+        .with_help("If the value contains user input, an attacker can inject scripts. Escape or sanitize the value, or set `textContent` to insert text")
+        .with_label(span)
+}
+
+/// Properties which parse the assigned string as HTML.
+const HTML_PROPERTIES: [&str; 2] = ["innerHTML", "outerHTML"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsanitizedProperty(Box<NoUnsanitizedConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects values assigned to `innerHTML` and `outerHTML` which may contain markup from
+    /// user input.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The assigned string is parsed as HTML. If any part of it can be influenced by a user, they
+    /// can inject scripts into the page (cross-site scripting, XSS).
+    ///
+    /// Values known statically, values built only from those (including `const` variables), and
+    /// values escaped by the functions and template tags of the `escape` option are allowed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// el.innerHTML = input;
+    /// el.innerHTML = `<b>${name}</b>`;
+    /// el.outerHTML += "<p>" + comment + "</p>";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// el.innerHTML = "<b>Loading</b>";
+    /// const icon = "<svg></svg>";
+    /// el.innerHTML = `<i>${icon}</i>`;
+    /// el.innerHTML = escapeHTML`<b>${name}</b>`;
+    /// el.textContent = input;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### escape
+    ///
+    /// `{ type: { taggedTemplates: string[], methods: string[] } }`
+    ///
+    /// Template tags and functions whose results are safe to use as HTML, by name, e.g.
+    /// `{ "escape": { "methods": ["DOMPurify.sanitize"] } }`.
+    ///
+    /// Default: `{ "taggedTemplates": ["Sanitizer.escapeHTML", "escapeHTML"], "methods": ["Sanitizer.unwrapSafeHTML", "unwrapSafeHTML"] }`
+    NoUnsanitizedProperty,
+    security,
+    restriction,
+    config = NoUnsanitizedConfig,
+);
+
+impl Rule for NoUnsanitizedProperty {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(NoUnsanitizedConfig::from_configuration(&value)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(assignment) = node.kind() else {
+            return;
+        };
+        let Some(property) = assignment
+            .left
+            .as_member_expression()
+            .and_then(|member| member.static_property_name())
+            .filter(|name| HTML_PROPERTIES.contains(name))
+        else {
+            return;
+        };
+        if !is_safe_html(&assignment.right, &self.0.escape, ctx) {
+            ctx.diagnostic(no_unsanitized_property_diagnostic(assignment.right.span(), property));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("el.innerHTML = '<b>Loading</b>'", None),
+        ("el.innerHTML = ''", None),
+        ("el.innerHTML = null", None),
+        ("el.innerHTML = typeof x", None),
+        ("el.innerHTML = a - b", None),
+        ("const icon = '<svg></svg>'; el.innerHTML = `<i>${icon}</i>`", None),
+        (
+            "const icon = '<svg></svg>'; const html = '<i>' + icon + '</i>'; el.innerHTML = html",
+            None,
+        ),
+        ("let html = '<br>'; el.innerHTML = html", None),
+        ("el.innerHTML = loading ? '<i>Loading</i>' : ''", None),
+        ("const Icons = Object.freeze({ A: '<svg></svg>' }); el.innerHTML = Icons.A", None),
+        ("el.innerHTML = escapeHTML`<b>${name}</b>`", None),
+        ("el.innerHTML = Sanitizer.escapeHTML`<b>${name}</b>`", None),
+        ("el.innerHTML = unwrapSafeHTML(html)", None),
+        ("const html = escapeHTML`<b>${name}</b>`; el.outerHTML = `<p>${html}</p>`", None),
+        (
+            "el.innerHTML = DOMPurify.sanitize(input)",
+            Some(json!([{ "escape": { "methods": ["DOMPurify.sanitize"] } }])),
+        ),
+        ("el.textContent = input", None),
+        ("el.innerText = input", None),
+        ("innerHTML = input", None),
+    ];
+
+    let fail = vec![
+        ("el.innerHTML = input", None),
+        ("el.outerHTML = input", None),
+        ("el['innerHTML'] = input", None),
+        ("el.innerHTML += input", None),
+        ("el.innerHTML = `<b>${name}</b>`", None),
+        ("el.innerHTML = '<p>' + comment + '</p>'", None),
+        ("el.innerHTML = loading ? '<i>Loading</i>' : content", None),
+        ("el.innerHTML = data.html", None),
+        ("el.innerHTML = render()", None),
+        ("let html = '<br>'; html = input; el.innerHTML = html", None),
+        ("function f(html) { el.innerHTML = html }", None),
+        ("const html = `<b>${name}</b>`; el.innerHTML = html", None),
+        ("el.innerHTML = DOMPurify.sanitize(input)", None),
+        (
+            "el.innerHTML = escapeHTML`<b>${name}</b>`",
+            Some(json!([{ "escape": { "taggedTemplates": ["html"] } }])),
+        ),
+    ];
+
+    Tester::new(NoUnsanitizedProperty::NAME, NoUnsanitizedProperty::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
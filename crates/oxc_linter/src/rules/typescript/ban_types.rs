@@ -64,7 +64,12 @@ declare_oxc_lint!(
     BanTypes,
     typescript,
     pedantic,
-    pending
+    pending,
+    deprecated = [
+        "typescript/no-empty-object-type",
+        "typescript/no-unsafe-function-type",
+        "typescript/no-wrapper-object-types",
+    ]
 );
 
 impl Rule for BanTypes {
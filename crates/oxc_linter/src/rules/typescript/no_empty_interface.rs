@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// ```
     NoEmptyInterface,
     typescript,
-    style
+    style,
+    deprecated = "typescript/no-empty-object-type"
 );
 
 impl Rule for NoEmptyInterface {
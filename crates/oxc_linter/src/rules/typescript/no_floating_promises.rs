@@ -0,0 +1,199 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_floating_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promises must be awaited or have their rejection handled.")
+        .with_help("Add `await`, a `.catch()` handler, a `.then()` rejection handler, or mark the promise as ignored with the `void` operator.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromises {
+    ignore_void: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires promises which are created in expression statements to be handled.
+    ///
+    /// This rule needs type information, it only reports problems when `oxlint` runs with the
+    /// `--type-aware` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither awaited nor has a rejection handler is "floating": its errors
+    /// are not caught and are reported as unhandled rejections, and the code after it doesn't
+    /// wait for it to settle.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// async function save() {}
+    ///
+    /// save();
+    /// save().then(() => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// async function save() {}
+    ///
+    /// await save();
+    /// save().catch(console.error);
+    /// save().then(() => {}, console.error);
+    /// void save();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `ignoreVoid` (default `true`): allow marking a promise as ignored with the `void` operator.
+    NoFloatingPromises,
+    typescript,
+    suspicious
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: Value) -> Self {
+        let ignore_void = value
+            .get(0)
+            .and_then(|config| config.get("ignoreVoid"))
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        Self { ignore_void }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        let mut expr = stmt.expression.get_inner_expression();
+
+        if let Expression::UnaryExpression(unary) = expr {
+            if unary.operator == UnaryOperator::Void {
+                if self.ignore_void {
+                    return;
+                }
+                expr = unary.argument.get_inner_expression();
+            }
+        }
+
+        // Only ask for the types of expressions which can evaluate to a promise.
+        if !matches!(
+            expr,
+            Expression::CallExpression(_)
+                | Expression::NewExpression(_)
+                | Expression::ChainExpression(_)
+                | Expression::Identifier(_)
+                | Expression::StaticMemberExpression(_)
+                | Expression::ComputedMemberExpression(_)
+                | Expression::ConditionalExpression(_)
+                | Expression::LogicalExpression(_)
+        ) || handles_rejection(expr)
+        {
+            return;
+        }
+
+        if ctx.type_of(expr.span()).is_some_and(|info| info.is_promise_like()) {
+            ctx.diagnostic(no_floating_promises_diagnostic(stmt.span));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.has_type_checker()
+    }
+}
+
+/// `promise.catch(handler)` or `promise.then(onFulfilled, onRejected)`.
+fn handles_rejection(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr else { return false };
+    let Some(member) = call.callee.get_member_expr() else { return false };
+    let is_handler = |argument: Option<&Argument>| {
+        argument.is_some_and(|argument| {
+            !matches!(argument, Argument::NullLiteral(_))
+                && !argument.as_expression().is_some_and(Expression::is_undefined)
+        })
+    };
+    match member.static_property_name() {
+        Some("catch") => is_handler(call.arguments.first()),
+        Some("then") => is_handler(call.arguments.get(1)),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_span::Span;
+    use serde_json::json;
+
+    use crate::{
+        tester::Tester,
+        type_checker::{TypeFlags, TypeInfo, TypeQuery},
+    };
+
+    /// Identifiers starting with `promise` and calls of functions starting with `async` are
+    /// promises in the test cases.
+    fn type_check(source_text: &str, span: Span, query: TypeQuery) -> Option<TypeInfo> {
+        if query != TypeQuery::TypeOf {
+            return None;
+        }
+        let text = span.source_text(source_text);
+        let text = text.strip_prefix("new ").unwrap_or(text);
+        let is_promise = text.starts_with("promise")
+            || text.starts_with("async")
+            || text.starts_with("Promise")
+            || text.contains("? promise");
+        Some(if is_promise {
+            TypeInfo::new("Promise<void>", TypeFlags::PROMISE_LIKE)
+        } else {
+            TypeInfo::new("void", TypeFlags::empty())
+        })
+    }
+
+    let pass = vec![
+        ("await asyncFn();", None),
+        ("asyncFn().catch(() => {});", None),
+        ("asyncFn().then(() => {}, () => {});", None),
+        ("void asyncFn();", None),
+        ("const p = asyncFn();", None),
+        ("promise = asyncFn();", None),
+        ("syncFn();", None),
+        ("(syncFn());", None),
+        ("value;", None),
+    ];
+
+    let fail = vec![
+        ("asyncFn();", None),
+        ("(asyncFn());", None),
+        ("promise;", None),
+        ("promise.then(() => {});", None),
+        ("asyncFn().then(() => {}, undefined);", None),
+        ("asyncFn().catch(null);", None),
+        ("new Promise(() => {});", None),
+        ("Promise.resolve();", None),
+        ("cond ? promise : value;", None),
+        ("void asyncFn();", Some(json!([{ "ignoreVoid": false }]))),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .with_type_checker(type_check)
+        .test_and_snapshot();
+}
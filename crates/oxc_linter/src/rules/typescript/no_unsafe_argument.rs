@@ -0,0 +1,147 @@
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn unsafe_argument_diagnostic(span: Span, parameter_type: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Unsafe argument of type `any` assigned to a parameter of type `{parameter_type}`."
+    ))
+    .with_label(span)
+}
+
+fn unsafe_spread_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unsafe spread of an `any` type as arguments.").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeArgument;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling a function with a value of type `any` as an argument, when the parameter
+    /// is not `any` or `unknown` itself.
+    ///
+    /// This rule needs type information, it only reports problems when `oxlint` runs with the
+    /// `--type-aware` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `any` disables type checking. Passing it to a typed parameter lets a value of the wrong
+    /// type into code which relies on the declared type, without any error from TypeScript.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// declare function greet(name: string): void;
+    ///
+    /// const name: any = JSON.parse(input);
+    /// greet(name);
+    /// greet(...([] as any));
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// declare function greet(name: string): void;
+    ///
+    /// const name: unknown = JSON.parse(input);
+    /// greet(String(name));
+    /// ```
+    NoUnsafeArgument,
+    typescript,
+    pedantic
+);
+
+impl Rule for NoUnsafeArgument {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let arguments = match node.kind() {
+            AstKind::CallExpression(call) => &call.arguments,
+            AstKind::NewExpression(new) => &new.arguments,
+            _ => return,
+        };
+
+        for argument in arguments {
+            let span = argument.span();
+            // Ask for the expected type only when the argument is `any`, which is rare.
+            if !ctx.type_of(span).is_some_and(|info| info.is_any()) {
+                continue;
+            }
+            if let Argument::SpreadElement(spread) = argument {
+                ctx.diagnostic(unsafe_spread_diagnostic(spread.span));
+                continue;
+            }
+            let Some(parameter_type) = ctx.contextual_type_of(span) else { continue };
+            if !parameter_type.is_any() && !parameter_type.is_unknown() {
+                ctx.diagnostic(unsafe_argument_diagnostic(span, &parameter_type.text));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.has_type_checker()
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_span::Span;
+
+    use crate::{
+        tester::Tester,
+        type_checker::{TypeFlags, TypeInfo, TypeQuery},
+    };
+
+    /// Arguments starting with `any` or spreading `any` are `any` in the test cases, and the
+    /// parameters of `takesString`, `takesAny` and `takesUnknown` have the named type.
+    fn type_check(source_text: &str, span: Span, query: TypeQuery) -> Option<TypeInfo> {
+        match query {
+            TypeQuery::TypeOf => {
+                let text = span.source_text(source_text);
+                let text = text.strip_prefix("...").unwrap_or(text);
+                Some(if text.starts_with("any") {
+                    TypeInfo::new("any", TypeFlags::ANY)
+                } else {
+                    TypeInfo::new("string", TypeFlags::empty())
+                })
+            }
+            TypeQuery::ContextualTypeOf => {
+                let callee = source_text[..span.start as usize].trim_end_matches('(');
+                let callee = callee.rsplit([' ', ',']).next()?;
+                match callee.strip_prefix("takes")? {
+                    "String" => Some(TypeInfo::new("string", TypeFlags::empty())),
+                    "Any" => Some(TypeInfo::new("any", TypeFlags::ANY)),
+                    "Unknown" => Some(TypeInfo::new("unknown", TypeFlags::UNKNOWN)),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    let pass = vec![
+        "takesString(value);",
+        "takesAny(anyValue);",
+        "takesUnknown(anyValue);",
+        "untyped(anyValue);",
+        "new takesString(value);",
+        "takesString(...values);",
+    ];
+
+    let fail = vec![
+        "takesString(anyValue);",
+        "new takesString(anyValue);",
+        "takesString(...anyValues);",
+        "takesString(anyValue.member);",
+    ];
+
+    Tester::new(NoUnsafeArgument::NAME, NoUnsafeArgument::PLUGIN, pass, fail)
+        .with_type_checker(type_check)
+        .test_and_snapshot();
+}
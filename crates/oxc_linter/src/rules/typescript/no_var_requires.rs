@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// ```
     NoVarRequires,
     typescript,
-    restriction
+    restriction,
+    deprecated = "typescript/no-require-imports"
 );
 
 impl Rule for NoVarRequires {
@@ -56,7 +56,8 @@ declare_oxc_lint!(
     PreferTsExpectError,
     typescript,
     pedantic,
-    fix
+    fix,
+    deprecated = "typescript/ban-ts-comment"
 );
 
 impl Rule for PreferTsExpectError {
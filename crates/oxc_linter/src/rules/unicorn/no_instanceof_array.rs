@@ -40,7 +40,8 @@ declare_oxc_lint!(
     NoInstanceofArray,
     unicorn,
     pedantic,
-    fix
+    fix,
+    deprecated = "unicorn/no-instanceof-builtins"
 );
 
 impl Rule for NoInstanceofArray {
@@ -1,32 +1,19 @@
 use itertools::Itertools;
 use oxc_ast::{
     AstKind,
-    ast::{Expression, MemberExpression, VariableDeclarationKind},
+    ast::{Expression, VariableDeclarationKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::Span;
 
-use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
-
-const ARRAY_METHODS_RETURNS_ARRAY: [&str; 15] = [
-    "concat",
-    "copyWithin",
-    "fill",
-    "filter",
-    "flat",
-    "flatMap",
-    "map",
-    "reverse",
-    "slice",
-    "sort",
-    "splice",
-    "toReversed",
-    "toSorted",
-    "toSpliced",
-    "with",
-];
+use crate::{
+    AstNode,
+    ast_util::{is_kind_of_array_expr, is_method_call},
+    context::LintContext,
+    rule::Rule,
+};
 
 fn prefer_set_has_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("should be a `Set`, and use `.has()` to check existence or non-existence.")
@@ -69,34 +56,6 @@ declare_oxc_lint!(
     dangerous_fix
 );
 
-fn is_array_of_or_from(callee: &MemberExpression) -> bool {
-    callee.is_specific_member_access("Array", "of")
-        || callee.is_specific_member_access("Array", "from")
-}
-
-fn is_kind_of_array_expr(expr: &Expression) -> bool {
-    match expr {
-        Expression::NewExpression(new_expr) => {
-            new_expr.callee.get_identifier_reference().is_some_and(|ident| ident.name == "Array")
-        }
-        Expression::CallExpression(call_expr) => {
-            let Some(callee) = call_expr.callee.get_member_expr() else {
-                return call_expr.callee_name().is_some_and(|name| name == "Array");
-            };
-
-            if callee.is_computed() || callee.optional() {
-                return false;
-            }
-
-            let Some(name) = callee.static_property_name() else { return false };
-
-            is_array_of_or_from(callee) || ARRAY_METHODS_RETURNS_ARRAY.contains(&name)
-        }
-        Expression::ArrayExpression(_) => true,
-        _ => false,
-    }
-}
-
 fn is_multiple_calls(node: &AstNode, ctx: &LintContext, root_scope_id: ScopeId) -> bool {
     let mut was_in_root_scope = node.scope_id() == root_scope_id;
     let mut is_multiple = false;
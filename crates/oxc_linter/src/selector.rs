@@ -0,0 +1,1100 @@
+//! A subset of [esquery](https://github.com/estools/esquery) selectors, matched against the oxc AST.
+//!
+//! Selectors are written in terms of [ESTree](https://github.com/estree/estree) node types and
+//! properties, as in ESLint configurations. The oxc AST differs from ESTree in a few places, so node
+//! types are mapped to their ESTree names (e.g. [`AstKind::StaticMemberExpression`] matches
+//! `MemberExpression`), and nodes without an ESTree counterpart (e.g. [`AstKind::Argument`]) are
+//! skipped.
+//!
+//! Supported syntax:
+//! - node types (`CallExpression`) and the wildcard (`*`)
+//! - attributes: `[attr]`, `[attr.nested=value]`, with operators `=`, `!=`, `<`, `<=`, `>` and `>=`,
+//!   and values which are strings, numbers, regular expressions (`/^foo/i`) or `type(string)`
+//! - fields (`.callee`), i.e. the node is the value of that property of its parent
+//! - descendant (`A B`) and child (`A > B`) combinators
+//! - selector lists (`A, B`)
+//! - `:not(...)`, `:matches(...)`, `:is(...)`, and the classes `:statement`, `:expression`,
+//!   `:declaration`, `:function` and `:pattern`
+
+use std::{borrow::Cow, fmt};
+
+use cow_utils::CowUtils;
+use lazy_regex::Regex;
+
+use oxc_allocator::GetAddress;
+use oxc_ast::{
+    AstKind, AstType,
+    ast::{
+        Argument, ArrayExpressionElement, AssignmentTarget, BindingPattern, BindingPatternKind,
+        ChainElement, Expression, FunctionType, MethodDefinitionKind, ObjectPropertyKind,
+        PropertyKey, PropertyKind, RegExp, SimpleAssignmentTarget,
+    },
+};
+use oxc_semantic::AstNodes;
+
+use crate::AstNode;
+
+/// A parsed selector list, e.g. `CallExpression[callee.name="eval"], WithStatement`.
+///
+/// Rules parse selectors once in `from_configuration`, and test each node in `run` with
+/// [`Selector::matches`].
+#[derive(Debug, Clone)]
+pub struct Selector {
+    alternatives: Vec<Complex>,
+}
+
+/// Error returned when a selector can not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    message: String,
+    offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+impl Selector {
+    /// Parse a selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the selector is malformed, or uses syntax which is not supported.
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        let mut parser = Parser { source, pos: 0 };
+        let alternatives = parser.parse_selector_list()?;
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(parser.error("Unexpected character"));
+        }
+        Ok(Self { alternatives })
+    }
+
+    /// Returns `true` if `node` matches any of the alternatives of this selector.
+    pub fn matches(&self, node: &AstNode, nodes: &AstNodes) -> bool {
+        if !is_estree_node(node, nodes) {
+            return false;
+        }
+        self.alternatives.iter().any(|complex| complex.matches(node, nodes))
+    }
+}
+
+/// A sequence of compound selectors joined by combinators, e.g. `A > B C`.
+#[derive(Debug, Clone)]
+struct Complex {
+    /// The rightmost compound selector, matched against the node itself.
+    subject: Compound,
+    /// Compound selectors to the left of the subject, from right to left, each with the
+    /// combinator to its right.
+    ancestors: Vec<(Combinator, Compound)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Combinator {
+    Child,
+    Descendant,
+}
+
+/// Simple selectors which must all match the same node, e.g. `CallExpression[optional=true]`.
+type Compound = Vec<Simple>;
+
+#[derive(Debug, Clone)]
+enum Simple {
+    Wildcard,
+    Type(String),
+    /// The node is the value of the named property of its parent, e.g. `.callee`.
+    Field(String),
+    Attribute {
+        path: Vec<String>,
+        test: Option<(Operator, AttributeValue)>,
+    },
+    Not(Vec<Complex>),
+    Matches(Vec<Complex>),
+    Class(Class),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    Literal(String),
+    Regex(Regex),
+    Type(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Class {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> SelectorError {
+        SelectorError { message: message.into(), offset: self.pos }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) { Ok(()) } else { Err(self.error(format!("Expected '{c}'"))) }
+    }
+
+    /// Returns `true` if any whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    fn parse_identifier(&mut self) -> Result<&str, SelectorError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-'))
+        {
+            self.bump();
+        }
+        if self.pos == start {
+            return Err(self.error("Expected an identifier"));
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    fn parse_selector_list(&mut self) -> Result<Vec<Complex>, SelectorError> {
+        let mut list = vec![self.parse_complex()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(list);
+            }
+            list.push(self.parse_complex()?);
+        }
+    }
+
+    fn parse_complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.parse_compound()?];
+        let mut combinators = vec![];
+        loop {
+            let had_whitespace = self.skip_whitespace();
+            match self.peek() {
+                Some('>') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    combinators.push(Combinator::Child);
+                }
+                Some('~' | '+') => {
+                    return Err(self.error("Sibling combinators are not supported"));
+                }
+                Some(',' | ')') | None => break,
+                Some(_) if had_whitespace => combinators.push(Combinator::Descendant),
+                Some(_) => return Err(self.error("Unexpected character")),
+            }
+            compounds.push(self.parse_compound()?);
+        }
+
+        let subject = compounds.pop().unwrap_or_default();
+        let ancestors = combinators.into_iter().rev().zip(compounds.into_iter().rev()).collect();
+        Ok(Complex { subject, ancestors })
+    }
+
+    fn parse_compound(&mut self) -> Result<Compound, SelectorError> {
+        let mut compound = vec![];
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.bump();
+                    compound.push(Simple::Wildcard);
+                }
+                Some('.') => {
+                    self.bump();
+                    compound.push(Simple::Field(self.parse_identifier()?.to_string()));
+                    if self.peek() == Some('.') {
+                        return Err(self.error("Nested field selectors are not supported"));
+                    }
+                }
+                Some('[') => compound.push(self.parse_attribute()?),
+                Some(':') => compound.push(self.parse_pseudo()?),
+                Some(c) if c.is_ascii_alphabetic() => {
+                    compound.push(Simple::Type(self.parse_identifier()?.to_string()));
+                }
+                _ => break,
+            }
+        }
+        if compound.is_empty() {
+            return Err(self.error("Expected a selector"));
+        }
+        Ok(compound)
+    }
+
+    fn parse_attribute(&mut self) -> Result<Simple, SelectorError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let mut path = vec![self.parse_identifier()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?.to_string());
+        }
+        self.skip_whitespace();
+
+        let operator = if self.eat('=') {
+            Some(Operator::Eq)
+        } else if self.eat('!') {
+            self.expect('=')?;
+            Some(Operator::NotEq)
+        } else if self.eat('<') {
+            Some(if self.eat('=') { Operator::LtEq } else { Operator::Lt })
+        } else if self.eat('>') {
+            Some(if self.eat('=') { Operator::GtEq } else { Operator::Gt })
+        } else {
+            None
+        };
+
+        let test = match operator {
+            Some(operator) => {
+                self.skip_whitespace();
+                let value = self.parse_attribute_value()?;
+                self.skip_whitespace();
+                Some((operator, value))
+            }
+            None => None,
+        };
+        self.expect(']')?;
+        Ok(Simple::Attribute { path, test })
+    }
+
+    fn parse_attribute_value(&mut self) -> Result<AttributeValue, SelectorError> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                let mut value = String::new();
+                loop {
+                    match self.bump() {
+                        Some('\\') => match self.bump() {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some(c) => value.push(c),
+                            None => return Err(self.error("Unterminated string")),
+                        },
+                        Some(c) if c == quote => break,
+                        Some(c) => value.push(c),
+                        None => return Err(self.error("Unterminated string")),
+                    }
+                }
+                Ok(AttributeValue::Literal(value))
+            }
+            Some('/') => {
+                self.bump();
+                let start = self.pos;
+                loop {
+                    match self.bump() {
+                        Some('\\') => {
+                            self.bump();
+                        }
+                        Some('/') => break,
+                        Some(_) => {}
+                        None => return Err(self.error("Unterminated regular expression")),
+                    }
+                }
+                let pattern = &self.source[start..self.pos - 1];
+                let mut inline_flags = String::new();
+                while let Some(flag @ ('i' | 'm' | 's' | 'u')) = self.peek() {
+                    self.bump();
+                    if flag != 'u' {
+                        inline_flags.push(flag);
+                    }
+                }
+                let pattern = if inline_flags.is_empty() {
+                    Cow::Borrowed(pattern)
+                } else {
+                    Cow::Owned(format!("(?{inline_flags}){pattern}"))
+                };
+                Regex::new(&pattern)
+                    .map(AttributeValue::Regex)
+                    .map_err(|_| self.error("Invalid regular expression"))
+            }
+            _ => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| !c.is_whitespace() && !matches!(c, ']' | '(')) {
+                    self.bump();
+                }
+                let word = &self.source[start..self.pos];
+                if word == "type" && self.eat('(') {
+                    self.skip_whitespace();
+                    let ty = self.parse_identifier()?.to_string();
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    return Ok(AttributeValue::Type(ty));
+                }
+                if word.is_empty() {
+                    return Err(self.error("Expected an attribute value"));
+                }
+                Ok(AttributeValue::Literal(word.to_string()))
+            }
+        }
+    }
+
+    fn parse_pseudo(&mut self) -> Result<Simple, SelectorError> {
+        self.expect(':')?;
+        let start = self.pos;
+        let name = self.parse_identifier()?.cow_to_ascii_lowercase().into_owned();
+        let class = match name.as_str() {
+            "not" | "matches" | "is" => {
+                self.expect('(')?;
+                let list = self.parse_selector_list()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                return Ok(if name == "not" { Simple::Not(list) } else { Simple::Matches(list) });
+            }
+            "statement" => Class::Statement,
+            "expression" => Class::Expression,
+            "declaration" => Class::Declaration,
+            "function" => Class::Function,
+            "pattern" => Class::Pattern,
+            _ => {
+                self.pos = start;
+                return Err(self.error(format!("Unsupported pseudo-class ':{name}'")));
+            }
+        };
+        Ok(Simple::Class(class))
+    }
+}
+
+impl Complex {
+    fn matches(&self, node: &AstNode, nodes: &AstNodes) -> bool {
+        matches_compound(&self.subject, node, nodes) && self.matches_ancestors(0, node, nodes)
+    }
+
+    fn matches_ancestors(&self, index: usize, node: &AstNode, nodes: &AstNodes) -> bool {
+        let Some((combinator, compound)) = self.ancestors.get(index) else {
+            return true;
+        };
+        let mut ancestors =
+            nodes.ancestors(node.id()).filter(|ancestor| is_estree_node(ancestor, nodes));
+        match combinator {
+            Combinator::Child => ancestors.next().is_some_and(|parent| {
+                matches_compound(compound, parent, nodes)
+                    && self.matches_ancestors(index + 1, parent, nodes)
+            }),
+            Combinator::Descendant => ancestors.any(|ancestor| {
+                matches_compound(compound, ancestor, nodes)
+                    && self.matches_ancestors(index + 1, ancestor, nodes)
+            }),
+        }
+    }
+}
+
+fn matches_compound(compound: &Compound, node: &AstNode, nodes: &AstNodes) -> bool {
+    compound.iter().all(|simple| matches_simple(simple, node, nodes))
+}
+
+fn matches_simple(simple: &Simple, node: &AstNode, nodes: &AstNodes) -> bool {
+    match simple {
+        Simple::Wildcard => true,
+        Simple::Field(name) => {
+            let Some(parent) =
+                nodes.ancestors(node.id()).find(|ancestor| is_estree_node(ancestor, nodes))
+            else {
+                return false;
+            };
+            let address = node.kind().address();
+            let is_node =
+                |value: &Value| matches!(value, Value::Node(kind) if kind.address() == address);
+            match attribute(parent.kind(), name) {
+                Some(Value::List(items)) => items.iter().any(is_node),
+                Some(value) => is_node(&value),
+                None => false,
+            }
+        }
+        Simple::Type(name) => {
+            estree_type(node.kind()).is_some_and(|ty| ty.eq_ignore_ascii_case(name))
+        }
+        Simple::Attribute { path, test } => {
+            let value =
+                path.iter().try_fold(Value::Node(node.kind()), |value, name| value.get(name));
+            test_attribute(value.as_ref(), test.as_ref())
+        }
+        Simple::Not(list) => !list.iter().any(|complex| complex.matches(node, nodes)),
+        Simple::Matches(list) => list.iter().any(|complex| complex.matches(node, nodes)),
+        Simple::Class(class) => {
+            let Some(ty) = estree_type(node.kind()) else { return false };
+            match class {
+                Class::Statement => {
+                    ty.ends_with("Statement")
+                        || ty.ends_with("Declaration") && !ty.starts_with("TS")
+                }
+                Class::Expression => {
+                    ty.ends_with("Expression") || ty == "Identifier" || ty == "Literal"
+                }
+                Class::Declaration => ty.ends_with("Declaration"),
+                Class::Function => matches!(
+                    ty.as_ref(),
+                    "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+                ),
+                Class::Pattern => {
+                    ty.ends_with("Pattern")
+                        || ty.ends_with("Expression")
+                        || ty == "Identifier"
+                        || ty == "Literal"
+                        || ty == "RestElement"
+                }
+            }
+        }
+    }
+}
+
+fn test_attribute(value: Option<&Value>, test: Option<&(Operator, AttributeValue)>) -> bool {
+    let Some((operator, expected)) = test else {
+        return value.is_some_and(|value| !matches!(value, Value::Null));
+    };
+    let is_equal = match expected {
+        AttributeValue::Regex(regex) => {
+            matches!(value, Some(Value::Str(value)) if regex.is_match(value))
+        }
+        AttributeValue::Type(ty) => value.map_or("undefined", Value::type_of) == ty,
+        AttributeValue::Literal(literal) => match operator {
+            Operator::Eq | Operator::NotEq => {
+                value.map_or(Cow::Borrowed("undefined"), Value::to_js_string) == literal.as_str()
+            }
+            Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                let (Some(Value::Number(value)), Ok(literal)) = (value, literal.parse::<f64>())
+                else {
+                    return false;
+                };
+                return match operator {
+                    Operator::Lt => *value < literal,
+                    Operator::LtEq => *value <= literal,
+                    Operator::Gt => *value > literal,
+                    _ => *value >= literal,
+                };
+            }
+        },
+    };
+    match operator {
+        Operator::Eq => is_equal,
+        Operator::NotEq => !is_equal,
+        _ => false,
+    }
+}
+
+/// The value of an attribute of an ESTree node.
+#[derive(Debug)]
+enum Value<'a> {
+    Node(AstKind<'a>),
+    RegExp(&'a RegExp<'a>),
+    List(Vec<Value<'a>>),
+    Str(Cow<'a, str>),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl<'a> Value<'a> {
+    fn get(self, name: &str) -> Option<Self> {
+        match self {
+            Self::Node(kind) if name == "type" => estree_type(kind).map(Self::Str),
+            Self::Node(kind) => attribute(kind, name),
+            Self::RegExp(regex) => match name {
+                "pattern" => Some(Self::Str(Cow::Borrowed(regex.pattern.text.as_str()))),
+                "flags" => Some(Self::Str(Cow::Owned(regex.flags.to_string()))),
+                _ => None,
+            },
+            Self::List(items) if name == "length" =>
+            {
+                #[expect(clippy::cast_precision_loss)]
+                Some(Self::Number(items.len() as f64))
+            }
+            Self::List(items) => items.into_iter().nth(name.parse().ok()?),
+            _ => None,
+        }
+    }
+
+    fn type_of(&self) -> &'static str {
+        match self {
+            Self::Node(_) | Self::RegExp(_) | Self::List(_) | Self::Null => "object",
+            Self::Str(_) => "string",
+            Self::Number(_) => "number",
+            Self::Bool(_) => "boolean",
+        }
+    }
+
+    fn to_js_string(&self) -> Cow<'a, str> {
+        match self {
+            Self::Node(_) => Cow::Borrowed("[object Object]"),
+            Self::RegExp(regex) => Cow::Owned(format!("/{}/{}", regex.pattern.text, regex.flags)),
+            Self::List(items) => {
+                Cow::Owned(items.iter().map(Value::to_js_string).collect::<Vec<_>>().join(","))
+            }
+            Self::Str(value) => value.clone(),
+            Self::Number(value) => Cow::Owned(number_to_js_string(*value)),
+            Self::Bool(value) => Cow::Borrowed(if *value { "true" } else { "false" }),
+            Self::Null => Cow::Borrowed("null"),
+        }
+    }
+}
+
+fn number_to_js_string(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        // `Display` for `f64` prints integers without a fractional part, as JS does.
+        value.to_string()
+    }
+}
+
+/// Returns `true` if `node` has an ESTree counterpart.
+///
+/// In ESTree, the body of an expression arrow function (`() => a`) is the expression itself, so
+/// the [`AstKind::FunctionBody`] and [`AstKind::ExpressionStatement`] wrapping it are skipped.
+fn is_estree_node(node: &AstNode, nodes: &AstNodes) -> bool {
+    let is_expression_arrow =
+        |kind| matches!(kind, AstKind::ArrowFunctionExpression(arrow) if arrow.expression);
+    match node.kind() {
+        AstKind::FunctionBody(_) => !is_expression_arrow(nodes.parent_kind(node.id())),
+        AstKind::ExpressionStatement(_) => {
+            let parent = nodes.parent_node(node.id());
+            !(matches!(parent.kind(), AstKind::FunctionBody(_))
+                && is_expression_arrow(nodes.parent_kind(parent.id())))
+        }
+        kind => estree_type(kind).is_some(),
+    }
+}
+
+/// The ESTree type of a node, or `None` if the node has no ESTree counterpart.
+fn estree_type(kind: AstKind) -> Option<Cow<'static, str>> {
+    let ty = match kind {
+        AstKind::Argument(_)
+        | AstKind::ParenthesizedExpression(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::FormalParameter(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::Elision(_) => return None,
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::StaticMemberExpression(_)
+        | AstKind::ComputedMemberExpression(_)
+        | AstKind::PrivateFieldExpression(_) => "MemberExpression",
+        AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::ObjectProperty(_)
+        | AstKind::BindingProperty(_)
+        | AstKind::AssignmentTargetPropertyIdentifier(_)
+        | AstKind::AssignmentTargetPropertyProperty(_) => "Property",
+        AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::AssignmentTargetRest(_) | AstKind::BindingRestElement(_) => "RestElement",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::Function(function) => match function.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => {
+            if class.is_declaration() {
+                "ClassDeclaration"
+            } else {
+                "ClassExpression"
+            }
+        }
+        _ => return Some(Cow::Owned(ast_type_name(kind.ty()))),
+    };
+    Some(Cow::Borrowed(ty))
+}
+
+fn ast_type_name(ty: AstType) -> String {
+    format!("{ty:?}")
+}
+
+fn expression<'a>(expr: &'a Expression<'a>) -> Value<'a> {
+    Value::Node(AstKind::from_expression(expr.without_parentheses()))
+}
+
+fn optional_expression<'a>(expr: Option<&'a Expression<'a>>) -> Value<'a> {
+    expr.map_or(Value::Null, expression)
+}
+
+fn argument<'a>(argument: &'a Argument<'a>) -> Value<'a> {
+    match argument {
+        Argument::SpreadElement(spread) => Value::Node(AstKind::SpreadElement(spread)),
+        _ => expression(argument.to_expression()),
+    }
+}
+
+fn property_key<'a>(key: &'a PropertyKey<'a>) -> Value<'a> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Value::Node(AstKind::IdentifierName(ident)),
+        PropertyKey::PrivateIdentifier(ident) => Value::Node(AstKind::PrivateIdentifier(ident)),
+        _ => expression(key.to_expression()),
+    }
+}
+
+fn binding_pattern<'a>(pattern: &'a BindingPattern<'a>) -> Value<'a> {
+    Value::Node(match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => AstKind::BindingIdentifier(ident),
+        BindingPatternKind::ObjectPattern(pattern) => AstKind::ObjectPattern(pattern),
+        BindingPatternKind::ArrayPattern(pattern) => AstKind::ArrayPattern(pattern),
+        BindingPatternKind::AssignmentPattern(pattern) => AstKind::AssignmentPattern(pattern),
+    })
+}
+
+fn simple_assignment_target<'a>(target: &'a SimpleAssignmentTarget<'a>) -> Value<'a> {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+            Value::Node(AstKind::IdentifierReference(ident))
+        }
+        SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+            Value::Node(AstKind::ComputedMemberExpression(member))
+        }
+        SimpleAssignmentTarget::StaticMemberExpression(member) => {
+            Value::Node(AstKind::StaticMemberExpression(member))
+        }
+        SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+            Value::Node(AstKind::PrivateFieldExpression(member))
+        }
+        SimpleAssignmentTarget::TSAsExpression(expr) => Value::Node(AstKind::TSAsExpression(expr)),
+        SimpleAssignmentTarget::TSSatisfiesExpression(expr) => {
+            Value::Node(AstKind::TSSatisfiesExpression(expr))
+        }
+        SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+            Value::Node(AstKind::TSNonNullExpression(expr))
+        }
+        SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+            Value::Node(AstKind::TSTypeAssertion(expr))
+        }
+    }
+}
+
+fn assignment_target<'a>(target: &'a AssignmentTarget<'a>) -> Value<'a> {
+    match target {
+        AssignmentTarget::ArrayAssignmentTarget(pattern) => {
+            Value::Node(AstKind::ArrayAssignmentTarget(pattern))
+        }
+        AssignmentTarget::ObjectAssignmentTarget(pattern) => {
+            Value::Node(AstKind::ObjectAssignmentTarget(pattern))
+        }
+        _ => simple_assignment_target(target.to_simple_assignment_target()),
+    }
+}
+
+fn chain_element<'a>(element: &'a ChainElement<'a>) -> Value<'a> {
+    Value::Node(match element {
+        ChainElement::CallExpression(call) => AstKind::CallExpression(call),
+        ChainElement::TSNonNullExpression(expr) => AstKind::TSNonNullExpression(expr),
+        ChainElement::ComputedMemberExpression(member) => AstKind::ComputedMemberExpression(member),
+        ChainElement::StaticMemberExpression(member) => AstKind::StaticMemberExpression(member),
+        ChainElement::PrivateFieldExpression(member) => AstKind::PrivateFieldExpression(member),
+    })
+}
+
+fn str(value: &str) -> Value<'_> {
+    Value::Str(Cow::Borrowed(value))
+}
+
+/// The value of the ESTree property `name` of the node `kind`.
+fn attribute<'a>(kind: AstKind<'a>, name: &str) -> Option<Value<'a>> {
+    let value = match (kind, name) {
+        // Identifiers
+        (AstKind::IdentifierName(ident), "name") => str(ident.name.as_str()),
+        (AstKind::IdentifierReference(ident), "name") => str(ident.name.as_str()),
+        (AstKind::BindingIdentifier(ident), "name") => str(ident.name.as_str()),
+        (AstKind::LabelIdentifier(ident), "name") => str(ident.name.as_str()),
+        (AstKind::PrivateIdentifier(ident), "name") => str(ident.name.as_str()),
+        (AstKind::JSXIdentifier(ident), "name") => str(ident.name.as_str()),
+
+        // Literals
+        (AstKind::StringLiteral(lit), "value") => str(lit.value.as_str()),
+        (AstKind::NumericLiteral(lit), "value") => Value::Number(lit.value),
+        (AstKind::BooleanLiteral(lit), "value") => Value::Bool(lit.value),
+        (AstKind::NullLiteral(_), "value") => Value::Null,
+        (AstKind::BigIntLiteral(lit), "value" | "bigint") => str(lit.value.as_str()),
+        (AstKind::RegExpLiteral(lit), "value" | "regex") => Value::RegExp(&lit.regex),
+        (AstKind::StringLiteral(lit), "raw") => str(lit.raw?.as_str()),
+        (AstKind::NumericLiteral(lit), "raw") => str(lit.raw?.as_str()),
+        (AstKind::BigIntLiteral(lit), "raw") => str(lit.raw?.as_str()),
+        (AstKind::RegExpLiteral(lit), "raw") => str(lit.raw?.as_str()),
+        (AstKind::BooleanLiteral(lit), "raw") => str(if lit.value { "true" } else { "false" }),
+        (AstKind::NullLiteral(_), "raw") => str("null"),
+
+        // Member expressions
+        (AstKind::StaticMemberExpression(member), _) => match name {
+            "object" => expression(&member.object),
+            "property" => Value::Node(AstKind::IdentifierName(&member.property)),
+            "computed" => Value::Bool(false),
+            "optional" => Value::Bool(member.optional),
+            _ => return None,
+        },
+        (AstKind::ComputedMemberExpression(member), _) => match name {
+            "object" => expression(&member.object),
+            "property" => expression(&member.expression),
+            "computed" => Value::Bool(true),
+            "optional" => Value::Bool(member.optional),
+            _ => return None,
+        },
+        (AstKind::PrivateFieldExpression(member), _) => match name {
+            "object" => expression(&member.object),
+            "property" => Value::Node(AstKind::PrivateIdentifier(&member.field)),
+            "computed" => Value::Bool(false),
+            "optional" => Value::Bool(member.optional),
+            _ => return None,
+        },
+
+        // Calls
+        (AstKind::CallExpression(call), _) => match name {
+            "callee" => expression(&call.callee),
+            "arguments" => Value::List(call.arguments.iter().map(argument).collect()),
+            "optional" => Value::Bool(call.optional),
+            _ => return None,
+        },
+        (AstKind::NewExpression(new), _) => match name {
+            "callee" => expression(&new.callee),
+            "arguments" => Value::List(new.arguments.iter().map(argument).collect()),
+            _ => return None,
+        },
+        (AstKind::ImportExpression(import), "source") => expression(&import.source),
+        (AstKind::ChainExpression(chain), "expression") => chain_element(&chain.expression),
+        (AstKind::TaggedTemplateExpression(tagged), "tag") => expression(&tagged.tag),
+
+        // Operators
+        (AstKind::UnaryExpression(unary), _) => match name {
+            "operator" => str(unary.operator.as_str()),
+            "argument" => expression(&unary.argument),
+            "prefix" => Value::Bool(true),
+            _ => return None,
+        },
+        (AstKind::UpdateExpression(update), _) => match name {
+            "operator" => str(update.operator.as_str()),
+            "argument" => simple_assignment_target(&update.argument),
+            "prefix" => Value::Bool(update.prefix),
+            _ => return None,
+        },
+        (AstKind::BinaryExpression(binary), _) => match name {
+            "operator" => str(binary.operator.as_str()),
+            "left" => expression(&binary.left),
+            "right" => expression(&binary.right),
+            _ => return None,
+        },
+        (AstKind::PrivateInExpression(binary), _) => match name {
+            "operator" => str("in"),
+            "left" => Value::Node(AstKind::PrivateIdentifier(&binary.left)),
+            "right" => expression(&binary.right),
+            _ => return None,
+        },
+        (AstKind::LogicalExpression(logical), _) => match name {
+            "operator" => str(logical.operator.as_str()),
+            "left" => expression(&logical.left),
+            "right" => expression(&logical.right),
+            _ => return None,
+        },
+        (AstKind::AssignmentExpression(assignment), _) => match name {
+            "operator" => str(assignment.operator.as_str()),
+            "left" => assignment_target(&assignment.left),
+            "right" => expression(&assignment.right),
+            _ => return None,
+        },
+        (AstKind::ConditionalExpression(conditional), _) => match name {
+            "test" => expression(&conditional.test),
+            "consequent" => expression(&conditional.consequent),
+            "alternate" => expression(&conditional.alternate),
+            _ => return None,
+        },
+        (AstKind::SequenceExpression(sequence), "expressions") => {
+            Value::List(sequence.expressions.iter().map(expression).collect())
+        }
+        (AstKind::AwaitExpression(expr), "argument") => expression(&expr.argument),
+        (AstKind::YieldExpression(expr), _) => match name {
+            "argument" => optional_expression(expr.argument.as_ref()),
+            "delegate" => Value::Bool(expr.delegate),
+            _ => return None,
+        },
+        (AstKind::SpreadElement(spread), "argument") => expression(&spread.argument),
+
+        // Arrays and objects
+        (AstKind::ArrayExpression(array), "elements") => Value::List(
+            array
+                .elements
+                .iter()
+                .map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(spread) => {
+                        Value::Node(AstKind::SpreadElement(spread))
+                    }
+                    ArrayExpressionElement::Elision(_) => Value::Null,
+                    _ => expression(element.to_expression()),
+                })
+                .collect(),
+        ),
+        (AstKind::ObjectExpression(object), "properties") => Value::List(
+            object
+                .properties
+                .iter()
+                .map(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        Value::Node(AstKind::ObjectProperty(property))
+                    }
+                    ObjectPropertyKind::SpreadProperty(spread) => {
+                        Value::Node(AstKind::SpreadElement(spread))
+                    }
+                })
+                .collect(),
+        ),
+        (AstKind::ObjectProperty(property), _) => match name {
+            "key" => property_key(&property.key),
+            "value" => expression(&property.value),
+            "kind" => str(match property.kind {
+                PropertyKind::Init => "init",
+                PropertyKind::Get => "get",
+                PropertyKind::Set => "set",
+            }),
+            "computed" => Value::Bool(property.computed),
+            "method" => Value::Bool(property.method),
+            "shorthand" => Value::Bool(property.shorthand),
+            _ => return None,
+        },
+
+        // Statements
+        (AstKind::ExpressionStatement(stmt), "expression") => expression(&stmt.expression),
+        (AstKind::IfStatement(stmt), "test") => expression(&stmt.test),
+        (AstKind::WhileStatement(stmt), "test") => expression(&stmt.test),
+        (AstKind::DoWhileStatement(stmt), "test") => expression(&stmt.test),
+        (AstKind::ForStatement(stmt), "test") => optional_expression(stmt.test.as_ref()),
+        (AstKind::ReturnStatement(stmt), "argument") => optional_expression(stmt.argument.as_ref()),
+        (AstKind::ThrowStatement(stmt), "argument") => expression(&stmt.argument),
+        (AstKind::SwitchStatement(stmt), "discriminant") => expression(&stmt.discriminant),
+        (AstKind::WithStatement(stmt), "object") => expression(&stmt.object),
+        (AstKind::BreakStatement(stmt), "label") => stmt
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+        (AstKind::ContinueStatement(stmt), "label") => stmt
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+        (AstKind::LabeledStatement(stmt), "label") => {
+            Value::Node(AstKind::LabelIdentifier(&stmt.label))
+        }
+
+        // Declarations
+        (AstKind::VariableDeclaration(decl), _) => match name {
+            "kind" => str(decl.kind.as_str()),
+            "declarations" => Value::List(
+                decl.declarations
+                    .iter()
+                    .map(|d| Value::Node(AstKind::VariableDeclarator(d)))
+                    .collect(),
+            ),
+            "declare" => Value::Bool(decl.declare),
+            _ => return None,
+        },
+        (AstKind::VariableDeclarator(decl), _) => match name {
+            "id" => binding_pattern(&decl.id),
+            "init" => optional_expression(decl.init.as_ref()),
+            _ => return None,
+        },
+        (AstKind::Function(function), _) => match name {
+            "id" => function
+                .id
+                .as_ref()
+                .map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id))),
+            "params" => Value::List(
+                function
+                    .params
+                    .items
+                    .iter()
+                    .map(|param| binding_pattern(&param.pattern))
+                    .chain(
+                        function
+                            .params
+                            .rest
+                            .iter()
+                            .map(|rest| Value::Node(AstKind::BindingRestElement(rest))),
+                    )
+                    .collect(),
+            ),
+            "async" => Value::Bool(function.r#async),
+            "generator" => Value::Bool(function.generator),
+            _ => return None,
+        },
+        (AstKind::ArrowFunctionExpression(arrow), _) => match name {
+            "params" => Value::List(
+                arrow
+                    .params
+                    .items
+                    .iter()
+                    .map(|param| binding_pattern(&param.pattern))
+                    .chain(
+                        arrow
+                            .params
+                            .rest
+                            .iter()
+                            .map(|rest| Value::Node(AstKind::BindingRestElement(rest))),
+                    )
+                    .collect(),
+            ),
+            "async" => Value::Bool(arrow.r#async),
+            "generator" => Value::Bool(false),
+            "expression" => Value::Bool(arrow.expression),
+            _ => return None,
+        },
+        (AstKind::Class(class), "id") => {
+            class.id.as_ref().map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id)))
+        }
+        (AstKind::Class(class), "superClass") => optional_expression(class.super_class.as_ref()),
+        (AstKind::MethodDefinition(method), _) => match name {
+            "key" => property_key(&method.key),
+            "kind" => str(match method.kind {
+                MethodDefinitionKind::Constructor => "constructor",
+                MethodDefinitionKind::Method => "method",
+                MethodDefinitionKind::Get => "get",
+                MethodDefinitionKind::Set => "set",
+            }),
+            "static" => Value::Bool(method.r#static),
+            "computed" => Value::Bool(method.computed),
+            _ => return None,
+        },
+        (AstKind::PropertyDefinition(property), _) => match name {
+            "key" => property_key(&property.key),
+            "value" => optional_expression(property.value.as_ref()),
+            "static" => Value::Bool(property.r#static),
+            "computed" => Value::Bool(property.computed),
+            _ => return None,
+        },
+
+        // Modules
+        (AstKind::ImportDeclaration(decl), "source") => {
+            Value::Node(AstKind::StringLiteral(&decl.source))
+        }
+        (AstKind::ExportAllDeclaration(decl), "source") => {
+            Value::Node(AstKind::StringLiteral(&decl.source))
+        }
+        (AstKind::ExportNamedDeclaration(decl), "source") => decl
+            .source
+            .as_ref()
+            .map_or(Value::Null, |source| Value::Node(AstKind::StringLiteral(source))),
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::Selector;
+
+    /// Source text of every node in `source` matched by `selector`.
+    fn matches(selector: &str, source: &str) -> Vec<String> {
+        let selector = Selector::parse(selector).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let nodes = semantic.nodes();
+        nodes
+            .iter()
+            .filter(|node| selector.matches(node, nodes))
+            .map(|node| node.kind().span().source_text(source).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn types_and_attributes() {
+        assert_eq!(matches("WithStatement", "with (a) {}"), ["with (a) {}"]);
+        assert_eq!(matches("Identifier[name='foo']", "foo(bar, foo.foo)"), ["foo", "foo", "foo"]);
+        assert_eq!(matches("MemberExpression", "a.b; a[b]; (a).b"), ["a.b", "a[b]", "(a).b"]);
+        assert_eq!(
+            matches("CallExpression[callee.name=/^set/]", "setA(); a(); setB()"),
+            ["setA()", "setB()"]
+        );
+        assert_eq!(matches("CallExpression[arguments.length>1]", "a(1); b(1, 2)"), ["b(1, 2)"]);
+        assert_eq!(matches("Literal[value=1]", "1; '1'; 2"), ["1", "'1'"]);
+        assert_eq!(matches("Literal[value=type(string)]", "1; 'a'"), ["'a'"]);
+        assert_eq!(matches("Literal[regex.flags=/u/]", "/a/; /b/u"), ["/b/u"]);
+        assert_eq!(matches("VariableDeclaration[kind!='const']", "var a; const b = 1"), ["var a;"]);
+        assert_eq!(matches("CallExpression > .callee", "a.b(c)"), ["a.b"]);
+        assert_eq!(matches("Property > Literal.key", "({ a: 'b', 'c': 1 })"), ["'c'"]);
+        assert_eq!(matches("CallExpression[optional=true]", "a?.(); b()"), ["a?.()"]);
+        assert_eq!(
+            matches("FunctionDeclaration[params.0.name='a']", "function f(a) {}"),
+            ["function f(a) {}"]
+        );
+    }
+
+    #[test]
+    fn combinators_and_pseudo_classes() {
+        let source = "function f() { a(b()); } c(d())";
+        assert_eq!(matches("FunctionDeclaration CallExpression", source), ["a(b())", "b()"]);
+        assert_eq!(matches("ExpressionStatement > CallExpression", source), ["a(b())", "c(d())"]);
+        assert_eq!(matches("CallExpression > CallExpression", source), ["b()", "d()"]);
+        assert_eq!(matches("CallExpression:not(FunctionDeclaration *)", source), ["c(d())", "d()"]);
+        assert_eq!(
+            matches(":matches(ArrowFunctionExpression, Literal)", "() => 1"),
+            ["() => 1", "1"]
+        );
+        assert_eq!(
+            matches(":function", "function f() {} (() => {})"),
+            ["function f() {}", "() => {}"]
+        );
+        assert_eq!(
+            matches("DebuggerStatement, WithStatement", "debugger; with (a) {}"),
+            ["debugger;", "with (a) {}"]
+        );
+    }
+
+    #[test]
+    fn invalid_selectors() {
+        for selector in
+            ["", "A >", "[name", "A ~ B", "A + B", ":has(A)", "A[b=/[/]", "A:first-child", ".a.b"]
+        {
+            assert!(Selector::parse(selector).is_err(), "{selector}");
+        }
+    }
+}
@@ -6,9 +6,10 @@ use std::{
 
 use oxc_diagnostics::DiagnosticSender;
 
-use crate::Linter;
+use crate::{FileSystem, Linter, OsFileSystem};
 
 mod runtime;
+mod scheduler;
 use runtime::Runtime;
 pub use runtime::RuntimeFileSystem;
 
@@ -22,15 +23,42 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Build the whole module graph before linting, see [`Self::with_module_graph`]
+    module_graph: bool,
+
+    /// File system for reading source files and resolving imported modules
+    file_system: Arc<dyn FileSystem>,
+
+    /// Resident set size in bytes above which files are processed one at a time
+    max_memory: Option<u64>,
+
+    /// Size in bytes above which files are processed one at a time
+    large_file_size: Option<u64>,
+
+    /// Maximum number of times files are linted and fixed, see [`Self::with_max_fix_passes`]
+    max_fix_passes: usize,
 }
 
+/// Default maximum number of fix passes, the same as ESLint.
+pub const DEFAULT_MAX_FIX_PASSES: usize = 10;
+
 impl LintServiceOptions {
     #[must_use]
     pub fn new<T>(cwd: T) -> Self
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            tsconfig: None,
+            cross_module: false,
+            module_graph: false,
+            file_system: Arc::new(OsFileSystem),
+            max_memory: None,
+            large_file_size: None,
+            max_fix_passes: DEFAULT_MAX_FIX_PASSES,
+        }
     }
 
     #[inline]
@@ -42,7 +70,7 @@ impl LintServiceOptions {
         let tsconfig = tsconfig.into();
         // Should this be canonicalized?
         let tsconfig = if tsconfig.is_relative() { self.cwd.join(tsconfig) } else { tsconfig };
-        debug_assert!(tsconfig.is_file());
+        debug_assert!(self.file_system.is_file(&tsconfig));
 
         self.tsconfig = Some(tsconfig);
         self
@@ -55,6 +83,58 @@ impl LintServiceOptions {
         self
     }
 
+    /// Build the module graph of all paths to lint before linting them, so rules such as
+    /// `import/no-unused-modules` know which exports are imported by the other modules.
+    ///
+    /// This keeps all modules in memory until the graph is complete, so it is only done when such a
+    /// rule is enabled. It requires [`Self::with_cross_module`], and should only be used when the
+    /// paths to lint are the whole project, not for linting single files.
+    #[inline]
+    #[must_use]
+    pub fn with_module_graph(mut self, module_graph: bool) -> Self {
+        self.module_graph = module_graph;
+        self
+    }
+
+    /// Read source files and resolve imported modules with `file_system` instead of the file
+    /// system of the operating system. Call this before [`Self::with_tsconfig`].
+    #[inline]
+    #[must_use]
+    pub fn with_file_system(mut self, file_system: Arc<dyn FileSystem>) -> Self {
+        self.file_system = file_system;
+        self
+    }
+
+    /// Process only one file at a time while the resident set size of the process exceeds
+    /// `max_memory` bytes, instead of one file per thread. Only supported on Linux.
+    #[inline]
+    #[must_use]
+    pub fn with_max_memory(mut self, max_memory: Option<u64>) -> Self {
+        self.max_memory = max_memory;
+        self
+    }
+
+    /// Process only one file larger than `large_file_size` bytes at a time, such as generated
+    /// files, while smaller files continue to be processed in parallel.
+    #[inline]
+    #[must_use]
+    pub fn with_large_file_size(mut self, large_file_size: Option<u64>) -> Self {
+        self.large_file_size = large_file_size;
+        self
+    }
+
+    /// When fixing, lint files again after their fixes are applied, up to `max_fix_passes` times
+    /// in total, so fixes which conflicted with other fixes and fixes of code produced by other
+    /// fixes are applied too. Diagnostics are reported for the last pass of each file.
+    ///
+    /// Files are only fixed once when the module graph is built, see [`Self::with_module_graph`].
+    #[inline]
+    #[must_use]
+    pub fn with_max_fix_passes(mut self, max_fix_passes: usize) -> Self {
+        self.max_fix_passes = max_fix_passes.max(1);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -101,6 +181,12 @@ impl LintService {
         self.runtime.run_source(allocator)
     }
 
+    /// Statistics of the pool of allocators which files are parsed into.
+    #[cfg(feature = "language_server")]
+    pub fn allocator_pool_stats(&self) -> oxc_allocator::AllocatorPoolStats {
+        self.runtime.allocator_pool.stats()
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_test_source<'a>(
@@ -1,11 +1,10 @@
 use std::{
     borrow::Cow,
     ffi::OsStr,
-    fs,
     mem::take,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, mpsc},
+    sync::{Arc, Mutex, mpsc},
 };
 
 use indexmap::IndexSet;
@@ -18,33 +17,44 @@ use smallvec::SmallVec;
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
+use oxc_resolver::ResolverGeneric;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
 use crate::{
-    Fixer, Linter, Message,
+    FileSystem, Fixer, Linter, Message,
+    file_system::{ResolverFileSystem, SourceFileSystem},
     fixer::PossibleFixes,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    module_graph::link_imported_names,
     module_record::ModuleRecord,
-    utils::read_to_arena_str,
 };
 
 #[cfg(feature = "language_server")]
 use crate::fixer::MessageWithPosition;
 
-use super::LintServiceOptions;
+use super::{
+    LintServiceOptions,
+    scheduler::{Scheduler, SchedulerPermit},
+};
 
 pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: Linter,
-    resolver: Option<Resolver>,
+    resolver: Option<ResolverGeneric<ResolverFileSystem>>,
+    /// Build the whole module graph before linting, for rules such as `import/no-unused-modules`
+    module_graph: bool,
+    /// Maximum number of times files are linted and fixed
+    max_fix_passes: usize,
 
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
-    allocator_pool: AllocatorPool,
+    pub(super) allocator_pool: AllocatorPool,
+
+    /// Limits the number of files processed at the same time
+    scheduler: Scheduler,
 }
 
 /// Output of `Runtime::process_path`
@@ -68,9 +78,9 @@ struct ProcessedModule<'alloc_pool> {
     ///
     /// This value is required for linter to run on the module.  There are two cases where `content` is `None`:
     /// - Import plugin is enabled and the module is a dependency, which is processed only to construct the module graph, not for linting.
-    /// - Couldn't get the source text of the module to lint, e.g. the file doesn't exist or the source isn't valid utf-8.
+    /// - Couldn't get the source text of the module to lint, e.g. the file doesn't exist or the source isn't valid in its encoding.
     ///
-    /// Note that `content` is `Some` even if parsing is unsuccessful as long as the source to lint is valid utf-8.
+    /// Note that `content` is `Some` even if parsing is unsuccessful as long as the source to lint could be read.
     /// It is designed this way to cover the case where some but not all the sections fail to parse.
     content: Option<ModuleContent<'alloc_pool>>,
 }
@@ -137,39 +147,28 @@ impl<'alloc_pool> ModuleToLint<'alloc_pool> {
 }
 
 /// A simple trait for the `Runtime` to load and save file from a filesystem
-/// The `Runtime` uses OsFileSystem as a default
+/// The `Runtime` defaults to its `FileSystem`
 /// The Tester and `oxc_language_server` would like to provide the content from memory
 pub trait RuntimeFileSystem {
-    /// reads the content of a file path
+    /// reads the content of a file path, transcoding it to UTF-8 if necessary
     ///
     /// # Errors
-    /// When no valid path is provided or the content is not valid UTF-8 Stream
+    /// When no valid path is provided or the content is not valid in its encoding
     fn read_to_arena_str<'a>(
         &'a self,
         path: &Path,
         allocator: &'a Allocator,
     ) -> Result<&'a str, std::io::Error>;
 
-    /// write a file to the file system
+    /// write a file to the file system, in the encoding the file was read in
     ///
     /// # Errors
     /// When the program does not have write permission for the file system
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error>;
-}
 
-struct OsFileSystem;
-
-impl RuntimeFileSystem for OsFileSystem {
-    fn read_to_arena_str<'a>(
-        &self,
-        path: &Path,
-        allocator: &'a Allocator,
-    ) -> Result<&'a str, std::io::Error> {
-        read_to_arena_str(path, allocator)
-    }
-
-    fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
-        fs::write(path, content)
+    /// size of a file in bytes, used to schedule large files
+    fn file_size(&self, _path: &Path) -> Option<u64> {
+        None
     }
 }
 
@@ -180,15 +179,24 @@ impl Runtime {
         options: LintServiceOptions,
     ) -> Self {
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            Self::get_resolver(
+                options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))),
+                &options.file_system,
+            )
         });
+        let module_graph =
+            options.module_graph && resolver.is_some() && linter.config.needs_module_graph();
         Self {
             allocator_pool,
             cwd: options.cwd,
             paths: IndexSet::with_capacity_and_hasher(0, FxBuildHasher),
             linter,
             resolver,
-            file_system: Box::new(OsFileSystem),
+            module_graph,
+            // Fixed files can't be linted again without the other modules of the graph
+            max_fix_passes: if module_graph { 1 } else { options.max_fix_passes },
+            file_system: Box::new(SourceFileSystem(options.file_system)),
+            scheduler: Scheduler::new(options.max_memory, options.large_file_size),
         }
     }
 
@@ -205,10 +213,13 @@ impl Runtime {
         self
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
+    fn get_resolver(
+        tsconfig_path: Option<PathBuf>,
+        file_system: &Arc<dyn FileSystem>,
+    ) -> ResolverGeneric<ResolverFileSystem> {
         use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
         let tsconfig = tsconfig_path.and_then(|path| {
-            path.is_file().then_some(TsconfigOptions {
+            file_system.is_file(&path).then_some(TsconfigOptions {
                 config_file: path,
                 references: TsconfigReferences::Auto,
             })
@@ -220,14 +231,15 @@ impl Runtime {
                 (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
             ]
         });
-        Resolver::new(ResolveOptions {
+        let options = ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
             main_fields: vec!["module".into(), "main".into()],
             condition_names: vec!["module".into(), "import".into()],
             extension_alias,
             tsconfig,
             ..ResolveOptions::default()
-        })
+        };
+        ResolverGeneric::new_with_file_system(ResolverFileSystem(Arc::clone(file_system)), options)
     }
 
     fn get_source_type_and_text<'a>(
@@ -274,6 +286,8 @@ impl Runtime {
     ) {
         if self.resolver.is_none() {
             self.paths.par_iter().for_each(|path| {
+                // Sources and semantics are kept until the module is linted.
+                let _permit = self.acquire_permit(path);
                 let output = self.process_path(path, check_syntax_errors, tx_error);
                 let Some(entry) =
                     ModuleToLint::from_processed_module(output.path, output.processed_module)
@@ -317,7 +331,10 @@ impl Runtime {
         // We build the module graph from one group, run lint on them, drop sources and semantics but keep the module
         // graph, and then move on to the next group.
         // This size is empirical based on AFFiNE@97cc814a.
-        let group_size = rayon::current_num_threads() * 4;
+        // Rules which need the importers of every module can only run when the whole module graph is built,
+        // so all modules are processed in one group for them.
+        let group_size =
+            if self.module_graph { self.paths.len() } else { rayon::current_num_threads() * 4 };
 
         // Stores modules that belongs to `self.paths` in current group.
         // They are passed to `on_module_to_lint` at the end of each group.
@@ -372,9 +389,10 @@ impl Runtime {
                     let path = Arc::clone(path);
                     let tx_process_output = tx_process_output.clone();
                     scope.spawn(move |_| {
-                        tx_process_output
-                            .send(me.process_path(&path, check_syntax_errors, tx_error))
-                            .unwrap();
+                        let permit = me.acquire_permit(&path);
+                        let output = me.process_path(&path, check_syntax_errors, tx_error);
+                        drop(permit);
+                        tx_process_output.send(output).unwrap();
                     });
                 }
             }
@@ -406,13 +424,11 @@ impl Runtime {
                                 let tx_resolve_output = tx_process_output.clone();
                                 let dep_path = Arc::clone(dep_path);
                                 move |_| {
-                                    tx_resolve_output
-                                        .send(me.process_path(
-                                            &dep_path,
-                                            check_syntax_errors,
-                                            tx_error,
-                                        ))
-                                        .unwrap();
+                                    let permit = me.acquire_permit(&dep_path);
+                                    let output =
+                                        me.process_path(&dep_path, check_syntax_errors, tx_error);
+                                    drop(permit);
+                                    tx_resolve_output.send(output).unwrap();
                                 }
                             });
                             pending_module_count += 1;
@@ -482,6 +498,9 @@ impl Runtime {
                     }
                 }
             });
+            if me.module_graph {
+                link_imported_names(modules_by_path.values().flatten());
+            }
             #[expect(clippy::iter_with_drain)]
             for entry in modules_to_lint.drain(..) {
                 let on_entry = on_module_to_lint.clone();
@@ -492,10 +511,28 @@ impl Runtime {
         }
     }
 
+    pub(super) fn run(&mut self, tx_error: &DiagnosticSender) {
+        let mut pass = 1;
+        loop {
+            let relint_fixed = self.linter.fixes_enabled() && pass < self.max_fix_passes;
+            let fixed_paths = self.run_pass(tx_error, relint_fixed);
+            if fixed_paths.is_empty() {
+                break;
+            }
+            // Lint the fixed files again, with the fixes which were skipped because they
+            // conflicted with other fixes, and fixes of the fixed code
+            self.paths = fixed_paths.into_iter().collect();
+            pass += 1;
+        }
+    }
+
+    /// Lint and fix all paths once. With `relint_fixed`, diagnostics of files which are fixed
+    /// are not reported, and the paths of these files are returned to lint them again.
     // clippy: the source field is checked and assumed to be less than 4GB, and
     // we assume that the fix offset will not exceed 2GB in either direction
     #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-    pub(super) fn run(&mut self, tx_error: &DiagnosticSender) {
+    fn run_pass(&mut self, tx_error: &DiagnosticSender, relint_fixed: bool) -> Vec<Arc<OsStr>> {
+        let fixed_paths = Mutex::new(vec![]);
         rayon::scope(|scope| {
             self.resolve_modules(scope, true, tx_error, |me, mut module_to_lint| {
                 module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
@@ -509,6 +546,7 @@ impl Runtime {
                     let mut fix_offset: i32 = 0;
 
                     let path = Path::new(&module_to_lint.path);
+                    let mut diagnostics = vec![];
 
                     assert_eq!(
                         module_to_lint.section_module_records.len(),
@@ -540,7 +578,7 @@ impl Runtime {
                         }
 
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.fixes_enabled() {
                             let fix_result = Fixer::new(source_text, messages).fix();
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
@@ -558,25 +596,52 @@ impl Runtime {
                             messages = fix_result.messages;
                         }
 
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                &me.cwd,
-                                path,
-                                dep.source_text,
-                                errors,
-                            );
-                            tx_error.send((path.to_path_buf(), diagnostics)).unwrap();
+                        if me.linter.fix_preview_enabled() {
+                            for message in &mut messages {
+                                message.add_fix_preview(source_text);
+                            }
+                        }
+
+                        // fixes are reported relative to the file, like the labels
+                        if section.source.start != 0 {
+                            for message in &mut messages {
+                                message.move_fix_offset(section.source.start);
+                            }
                         }
+
+                        diagnostics.extend(messages.into_iter().map(Into::into));
                     }
                     // If the new source text is owned, that means it was modified,
                     // so we write the new source text to the file.
                     if let Cow::Owned(new_source_text) = &new_source_text {
-                        me.file_system.write_file(path, new_source_text).unwrap();
+                        match me.file_system.write_file(path, new_source_text) {
+                            Ok(()) if relint_fixed => {
+                                fixed_paths.lock().unwrap().push(Arc::clone(&module_to_lint.path));
+                                return;
+                            }
+                            Ok(()) => {}
+                            Err(e) => diagnostics.push(OxcDiagnostic::error(format!(
+                                "Failed to write fixes to file {} with error \"{e}\"",
+                                path.display()
+                            ))),
+                        }
+                    }
+                    if !diagnostics.is_empty() {
+                        let diagnostics = DiagnosticService::wrap_diagnostics(
+                            &me.cwd,
+                            path,
+                            dep.source_text,
+                            diagnostics,
+                        );
+                        tx_error.send((path.to_path_buf(), diagnostics)).unwrap();
                     }
                 });
             });
         });
+        let mut fixed_paths = fixed_paths.into_inner().unwrap();
+        // Lint files in a deterministic order
+        fixed_paths.sort_unstable();
+        fixed_paths
     }
 
     // clippy: the source field is checked and assumed to be less than 4GB, and
@@ -592,11 +657,12 @@ impl Runtime {
     ) -> Vec<MessageWithPosition<'a>> {
         use oxc_allocator::CloneIn;
         use oxc_data_structures::rope::Rope;
-        use std::sync::Mutex;
+
+        use oxc_span::GetSpan;
 
         use crate::{
             FixWithPosition,
-            fixer::{Fix, PossibleFixesWithPosition},
+            fixer::{Fix, PossibleFixesWithPosition, disable_at_end_of_line, disable_for_line},
             service::offset_to_position::{SpanPositionMessage, offset_to_position},
         };
 
@@ -629,7 +695,8 @@ impl Runtime {
                             .into_iter()
                             .zip(section_contents.drain(..))
                         {
-                            let mut section_messages = match record_result {
+                            let (mut section_messages, mut disable_line_fixes) = match record_result
+                            {
                                 Err(diagnostics) => {
                                     messages
                                         .lock()
@@ -637,12 +704,27 @@ impl Runtime {
                                         .extend(diagnostics.into_iter().map(Into::into));
                                     continue;
                                 }
-                                Ok(module_record) => me.linter.run(
-                                    Path::new(&module.path),
-                                    Rc::new(section.semantic.unwrap()),
-                                    Arc::clone(&module_record),
-                                    allocator_guard,
-                                ),
+                                Ok(module_record) => {
+                                    let semantic = Rc::new(section.semantic.unwrap());
+                                    let section_messages = me.linter.run(
+                                        Path::new(&module.path),
+                                        Rc::clone(&semantic),
+                                        Arc::clone(&module_record),
+                                        allocator_guard,
+                                    );
+                                    let disable_line_fixes = section_messages
+                                        .iter()
+                                        .map(|message| {
+                                            let rule = message.error.code.number.as_deref();
+                                            let offset = message.span().start;
+                                            (
+                                                disable_for_line(&semantic, offset, rule),
+                                                disable_at_end_of_line(&semantic, offset, rule),
+                                            )
+                                        })
+                                        .collect::<Vec<_>>();
+                                    (section_messages, disable_line_fixes)
+                                }
                             };
                             // adjust offset for multiple source text in a single file
                             if section.source.start != 0 {
@@ -651,7 +733,13 @@ impl Runtime {
                                         .move_offset(section.source.start)
                                         .move_fix_offset(section.source.start);
                                 }
+                                for (next_line, same_line) in &mut disable_line_fixes {
+                                    for fix in next_line.iter_mut().chain(same_line.as_mut()) {
+                                        fix.span = fix.span.move_right(section.source.start);
+                                    }
+                                }
                             }
+                            let mut disable_line_fixes = disable_line_fixes.into_iter();
 
                             messages.lock().unwrap().extend(section_messages.iter().map(
                                 |message| {
@@ -682,6 +770,8 @@ impl Runtime {
                                             .collect::<Vec<_>>()
                                     });
 
+                                    let (next_line_fixes, same_line_fix) =
+                                        disable_line_fixes.next().unwrap_or_default();
                                     MessageWithPosition {
                                         message: message.error.message.clone(),
                                         severity: message.error.severity,
@@ -715,6 +805,15 @@ impl Runtime {
                                                 )
                                             }
                                         },
+                                        disable_line_fixes: next_line_fixes
+                                            .iter()
+                                            .map(|fix| {
+                                                fix_to_fix_with_position(fix, rope, source_text)
+                                            })
+                                            .collect(),
+                                        disable_same_line_fix: same_line_fix.map(|fix| {
+                                            fix_to_fix_with_position(&fix, rope, source_text)
+                                        }),
                                     }
                                 },
                             ));
@@ -747,7 +846,6 @@ impl Runtime {
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
         use oxc_allocator::CloneIn;
-        use std::sync::Mutex;
 
         let messages = Mutex::new(Vec::<Message<'a>>::new());
         rayon::scope(|scope| {
@@ -790,6 +888,14 @@ impl Runtime {
         messages.into_inner().unwrap()
     }
 
+    /// Wait until the scheduler allows processing `path`.
+    fn acquire_permit(&self, path: &OsStr) -> Option<SchedulerPermit<'_>> {
+        if !self.scheduler.is_enabled() {
+            return None;
+        }
+        self.scheduler.acquire(self.file_system.file_size(Path::new(path)))
+    }
+
     fn process_path(
         &self,
         path: &Arc<OsStr>,
@@ -0,0 +1,158 @@
+use std::sync::{Condvar, Mutex, PoisonError};
+
+/// Limits the number of files which are processed at the same time, so that linting fits in the
+/// memory of small machines such as CI runners.
+///
+/// - When the resident set size of the process exceeds `max_memory`, new files wait until all
+///   files in flight are done, so only one file is processed at a time until memory is released.
+/// - Files larger than `large_file_size` are processed in their own lane: only one of them is in
+///   flight at a time, while smaller files continue to be processed next to it.
+///
+/// At least one file is always allowed, so that linting makes progress.
+#[derive(Debug, Default)]
+pub(super) struct Scheduler {
+    /// Resident set size in bytes
+    max_memory: Option<u64>,
+    /// File size in bytes
+    large_file_size: Option<u64>,
+    state: Mutex<SchedulerState>,
+    released: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    in_flight: usize,
+    large_in_flight: bool,
+}
+
+/// Allows a file to be processed until it is dropped.
+pub(super) struct SchedulerPermit<'a> {
+    scheduler: &'a Scheduler,
+    large: bool,
+}
+
+impl Scheduler {
+    pub(super) fn new(max_memory: Option<u64>, large_file_size: Option<u64>) -> Self {
+        Self { max_memory, large_file_size, ..Self::default() }
+    }
+
+    pub(super) fn is_enabled(&self) -> bool {
+        self.max_memory.is_some() || self.large_file_size.is_some()
+    }
+
+    /// Wait until a file of `file_size` bytes can be processed. Returns `None` without waiting
+    /// when no limit is set.
+    pub(super) fn acquire(&self, file_size: Option<u64>) -> Option<SchedulerPermit<'_>> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let large = self
+            .large_file_size
+            .is_some_and(|large_file_size| file_size.is_some_and(|size| size > large_file_size));
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            let lane_is_free = !large || !state.large_in_flight;
+            let memory_is_free = state.in_flight == 0 || !self.is_over_memory();
+            if lane_is_free && memory_is_free {
+                break;
+            }
+            state = self.released.wait(state).unwrap_or_else(PoisonError::into_inner);
+        }
+        state.in_flight += 1;
+        state.large_in_flight |= large;
+        Some(SchedulerPermit { scheduler: self, large })
+    }
+
+    fn is_over_memory(&self) -> bool {
+        self.max_memory.is_some_and(|max_memory| {
+            resident_set_size().is_some_and(|resident_set_size| resident_set_size > max_memory)
+        })
+    }
+}
+
+impl Drop for SchedulerPermit<'_> {
+    fn drop(&mut self) {
+        let mut state = self.scheduler.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.in_flight -= 1;
+        if self.large {
+            state.large_in_flight = false;
+        }
+        drop(state);
+        self.scheduler.released.notify_all();
+    }
+}
+
+/// Resident set size of the current process in bytes, `None` when it is unknown.
+#[cfg(target_os = "linux")]
+fn resident_set_size() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes = line.trim_start_matches("VmRSS:").trim().trim_end_matches("kB").trim();
+    kilobytes.parse::<u64>().ok().map(|kilobytes| kilobytes * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_set_size() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use super::Scheduler;
+
+    #[test]
+    fn disabled() {
+        assert!(Scheduler::default().acquire(Some(u64::MAX)).is_none());
+    }
+
+    #[test]
+    fn large_files_are_processed_one_at_a_time() {
+        let scheduler = Scheduler::new(None, Some(100));
+        let large_in_flight = AtomicUsize::new(0);
+        let max_large_in_flight = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for i in 0..8 {
+                let (scheduler, large_in_flight, max_large_in_flight) =
+                    (&scheduler, &large_in_flight, &max_large_in_flight);
+                scope.spawn(move || {
+                    let large = i % 2 == 0;
+                    let _permit = scheduler.acquire(Some(if large { 1000 } else { 10 }));
+                    if large {
+                        let count = large_in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_large_in_flight.fetch_max(count, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(5));
+                        large_in_flight.fetch_sub(1, Ordering::SeqCst);
+                    }
+                });
+            }
+        });
+        assert_eq!(max_large_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn over_memory() {
+        // Any process uses more than 1 byte, so files are processed one at a time.
+        let scheduler = Scheduler::new(Some(1), None);
+        let started = AtomicUsize::new(0);
+        let permit = scheduler.acquire(None);
+        assert!(permit.is_some());
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                let _permit = scheduler.acquire(None);
+                started.fetch_add(1, Ordering::SeqCst);
+            });
+            thread::sleep(Duration::from_millis(20));
+            assert_eq!(started.load(Ordering::SeqCst), 0);
+            drop(permit);
+        });
+        assert_eq!(started.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,7 +2,7 @@ use std::{borrow::Cow, fmt::Write};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{RuleCategory, RuleFixMeta, rules::RULES};
+use crate::{RuleCategory, RuleDeprecation, RuleFixMeta, rules::RULES};
 
 pub struct RuleTable {
     pub sections: Vec<RuleTableSection>,
@@ -28,6 +28,18 @@ pub struct RuleTableRow {
 
     pub turned_on_by_default: bool,
     pub autofix: RuleFixMeta,
+    pub deprecated: Option<RuleDeprecation>,
+}
+
+impl RuleTableRow {
+    /// The rule name, marked when the rule is deprecated.
+    fn display_name(&self) -> Cow<'static, str> {
+        if self.deprecated.is_some() {
+            Cow::Owned(format!("{} (deprecated)", self.name))
+        } else {
+            Cow::Borrowed(self.name)
+        }
+    }
 }
 
 impl Default for RuleTable {
@@ -65,6 +77,7 @@ impl RuleTable {
                     category: rule.category(),
                     turned_on_by_default: default_rules.contains(name),
                     autofix: rule.fix(),
+                    deprecated: rule.deprecated(),
                 }
             })
             .collect::<Vec<_>>();
@@ -93,7 +106,7 @@ impl RuleTable {
         .into_iter()
         .filter_map(|category| {
             let rows = rows_by_category.remove(&category)?;
-            let rule_column_width = rows.iter().map(|r| r.name.len()).max()?;
+            let rule_column_width = rows.iter().map(|r| r.display_name().len()).max()?;
             let plugin_column_width = rows.iter().map(|r| r.plugin.len()).max()?;
             Some(RuleTableSection { rows, category, rule_column_width, plugin_column_width })
         })
@@ -134,14 +147,18 @@ impl RuleTableSection {
         writeln!(s, "| {x:-<rule_width$} | {x:-<plugin_width$} | {x:-<7} | {x:-<8} |").unwrap();
 
         for row in rows {
-            let rule_name = row.name;
+            let rule_name = row.display_name();
             let plugin_name = &row.plugin;
             let (default, default_width) =
                 if row.turned_on_by_default { ("✅", DEFAULT - 1) } else { ("", DEFAULT) };
             let rendered_name = if let Some(prefix) = link_prefix {
-                Cow::Owned(format!("[{rule_name}]({prefix}/{plugin_name}/{rule_name}.html)"))
+                let suffix = &rule_name[row.name.len()..];
+                Cow::Owned(format!(
+                    "[{}]({prefix}/{plugin_name}/{}.html){suffix}",
+                    row.name, row.name
+                ))
             } else {
-                Cow::Borrowed(rule_name)
+                rule_name
             };
             let (fix_emoji, fix_emoji_width) = row.autofix.emoji().map_or(("", FIX), |emoji| {
                 let len = emoji.len();
@@ -200,4 +217,17 @@ mod test {
             assert!(html.contains(PREFIX_WITH_SLASH));
         }
     }
+
+    #[test]
+    fn test_table_deprecated() {
+        let section =
+            table().sections.iter().find(|section| section.category == RuleCategory::Restriction);
+        let section = section.unwrap();
+        assert!(section.render_markdown_table(None).contains("| no-var-requires (deprecated) "));
+        assert!(
+            section.render_markdown_table(Some("/rules")).contains(
+                "| [no-var-requires](/rules/typescript/no-var-requires.html) (deprecated) "
+            )
+        );
+    }
 }
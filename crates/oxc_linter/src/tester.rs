@@ -13,6 +13,7 @@ use serde_json::{Value, json};
 
 use oxc_allocator::{Allocator, AllocatorPool};
 use oxc_diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource};
+use oxc_span::Span;
 
 use crate::{
     AllowWarnDeny, BuiltinLintPlugins, ConfigStore, ConfigStoreBuilder, LintPlugins, LintService,
@@ -22,6 +23,7 @@ use crate::{
     options::LintOptions,
     rules::RULES,
     service::RuntimeFileSystem,
+    type_checker::{TypeChecker, TypeInfo, TypeQuery},
     utils::read_to_arena_str,
 };
 
@@ -214,6 +216,21 @@ impl RuntimeFileSystem for TesterFileSystem {
     }
 }
 
+/// Answers the type queries of type-aware rules from the source text of a test case.
+type TesterTypeCheck = fn(source_text: &str, span: Span, query: TypeQuery) -> Option<TypeInfo>;
+
+#[derive(Debug)]
+struct TesterTypeChecker {
+    source_text: String,
+    check: TesterTypeCheck,
+}
+
+impl TypeChecker for TesterTypeChecker {
+    fn query(&self, _path: &Path, span: Span, query: TypeQuery) -> Option<TypeInfo> {
+        (self.check)(&self.source_text, span, query)
+    }
+}
+
 pub struct Tester {
     rule_name: &'static str,
     plugin_name: &'static str,
@@ -235,6 +252,7 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    type_check: Option<TesterTypeCheck>,
 }
 
 impl Tester {
@@ -262,6 +280,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            type_check: None,
         }
     }
 
@@ -305,6 +324,12 @@ impl Tester {
         self
     }
 
+    /// Lint in type-aware mode, with the types of the test cases given by `check`.
+    pub fn with_type_checker(mut self, check: TesterTypeCheck) -> Self {
+        self.type_check = Some(check);
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.plugins.builtin.set(BuiltinLintPlugins::IMPORT, yes);
         self
@@ -504,7 +529,7 @@ impl Tester {
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
         let mut external_plugin_store = ExternalPluginStore::default();
-        let linter = Linter::new(
+        let mut linter = Linter::new(
             self.lint_options,
             ConfigStore::new(
                 eslint_config
@@ -529,6 +554,10 @@ impl Tester {
             None,
         )
         .with_fix(fix_kind.into());
+        if let Some(check) = self.type_check {
+            let source_text = source_text.to_string();
+            linter = linter.with_type_checker(Arc::new(TesterTypeChecker { source_text, check }));
+        }
 
         let path_to_lint = if self.plugins.has_import() {
             assert!(path.is_none(), "import plugin does not support path");
@@ -543,7 +572,9 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![Arc::<OsStr>::from(path_to_lint.as_os_str())];
-        let options = LintServiceOptions::new(cwd).with_cross_module(self.plugins.has_import());
+        let options = LintServiceOptions::new(cwd)
+            .with_cross_module(self.plugins.has_import())
+            .with_module_graph(self.plugins.has_import());
         let mut lint_service = LintService::new(linter, AllocatorPool::default(), options);
         lint_service
             .with_file_system(Box::new(TesterFileSystem::new(
@@ -0,0 +1,138 @@
+//! Per-rule performance profiling, e.g. for `oxlint --timing`.
+//!
+//! The time spent in each rule is always measured when a [`Linter`](crate::Linter) has
+//! [`RuleTimings`]. Heap allocations are only counted when the program uses a
+//! [`CountingAllocator`] as its global allocator, otherwise they are reported as `0`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+    ops::AddAssign,
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::rules::RuleEnum;
+
+/// Time and heap allocations spent in a rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub duration: Duration,
+    pub allocations: u64,
+}
+
+impl AddAssign for RuleTiming {
+    fn add_assign(&mut self, other: Self) {
+        self.duration += other.duration;
+        self.allocations += other.allocations;
+    }
+}
+
+/// The [`RuleTiming`]s of all rules, summed up over all linted files.
+#[derive(Debug)]
+pub struct RuleTimings {
+    rules: Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>,
+}
+
+impl Default for RuleTimings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleTimings {
+    /// Also starts counting allocations, if a [`CountingAllocator`] is the global allocator.
+    pub fn new() -> Self {
+        COUNT_ALLOCATIONS.store(true, Ordering::Relaxed);
+        Self { rules: Mutex::new(FxHashMap::default()) }
+    }
+
+    /// Add the timings of the rules which ran on one file.
+    pub(crate) fn record(&self, timings: &[(&RuleEnum, RuleTiming)]) {
+        let mut rules = self.rules.lock().unwrap_or_else(PoisonError::into_inner);
+        for (rule, timing) in timings {
+            *rules.entry((rule.plugin_name(), rule.name())).or_default() += *timing;
+        }
+    }
+
+    /// `(plugin name, rule name, timing)` of each rule which ran, the slowest rule first.
+    pub fn sorted(&self) -> Vec<(&'static str, &'static str, RuleTiming)> {
+        let rules = self.rules.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut sorted = rules
+            .iter()
+            .map(|(&(plugin_name, rule_name), &timing)| (plugin_name, rule_name, timing))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable_by(|a, b| {
+            b.2.duration.cmp(&a.2.duration).then(a.0.cmp(b.0)).then(a.1.cmp(b.1))
+        });
+        sorted
+    }
+}
+
+static COUNT_ALLOCATIONS: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The number of heap allocations made by the current thread so far, see [`CountingAllocator`].
+pub fn allocations() -> u64 {
+    ALLOCATIONS.try_with(Cell::get).unwrap_or_default()
+}
+
+/// A global allocator which counts the heap allocations of each thread for [`RuleTimings`], and
+/// leaves the allocation itself to `A`.
+///
+/// Allocations are only counted after a [`RuleTimings`] was created, so the overhead is a single
+/// atomic load otherwise.
+pub struct CountingAllocator<A>(A);
+
+impl<A> CountingAllocator<A> {
+    pub const fn new(allocator: A) -> Self {
+        Self(allocator)
+    }
+
+    #[inline]
+    fn count() {
+        if COUNT_ALLOCATIONS.load(Ordering::Relaxed) {
+            // Fails when the thread local is already destroyed, the allocation isn't counted then.
+            let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        }
+    }
+}
+
+// SAFETY: All methods forward to `A`, which upholds the contract of `GlobalAlloc`.
+// Counting doesn't allocate, because the thread local is `const` initialized and has no destructor.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { self.0.alloc(layout) }
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { self.0.alloc_zeroed(layout) }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { self.0.dealloc(ptr, layout) }
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { self.0.realloc(ptr, layout, new_size) }
+    }
+}
@@ -0,0 +1,262 @@
+//! Type information for type-aware rules, e.g. `typescript/no-floating-promises`.
+//!
+//! Oxlint does not check types itself. In the type-aware mode, types are queried from a
+//! [`TypeChecker`], usually a [`SidecarTypeChecker`] which asks a separate process running the
+//! TypeScript compiler. Rules query types lazily through
+//! [`LintContext::type_of`](crate::LintContext::type_of), so only the expressions rules are
+//! interested in are type checked.
+
+use std::{
+    ffi::OsStr,
+    fmt::Debug,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::{Mutex, PoisonError},
+};
+
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use oxc_span::{CompactStr, Span};
+
+bitflags! {
+    /// Properties of a type which rules check for.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TypeFlags: u8 {
+        /// `any`, including the implicit `any` of untyped values.
+        const ANY = 1 << 0;
+        /// `unknown`
+        const UNKNOWN = 1 << 1;
+        /// A promise, or any other object with a `then` method.
+        const PROMISE_LIKE = 1 << 2;
+    }
+}
+
+/// The type of an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The type as printed by the type checker, e.g. `Promise<string>`.
+    pub text: CompactStr,
+    pub flags: TypeFlags,
+}
+
+impl TypeInfo {
+    pub fn new(text: &str, flags: TypeFlags) -> Self {
+        Self { text: CompactStr::new(text), flags }
+    }
+
+    pub fn is_any(&self) -> bool {
+        self.flags.contains(TypeFlags::ANY)
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.flags.contains(TypeFlags::UNKNOWN)
+    }
+
+    pub fn is_promise_like(&self) -> bool {
+        self.flags.contains(TypeFlags::PROMISE_LIKE)
+    }
+}
+
+/// What to ask the [`TypeChecker`] about an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeQuery {
+    /// The type of the expression.
+    TypeOf,
+    /// The type the expression is expected to have from its position, e.g. the type of the
+    /// parameter an argument is passed to.
+    ContextualTypeOf,
+}
+
+/// Provides the types of expressions in the linted files.
+pub trait TypeChecker: Debug + Send + Sync {
+    /// Answer `query` for the expression at `span` of the file at `path`. Returns `None` if the
+    /// type is not known, e.g. for files which are not part of the TypeScript project.
+    fn query(&self, path: &Path, span: Span, query: TypeQuery) -> Option<TypeInfo>;
+}
+
+/// A [`TypeChecker`] which asks a sidecar process, e.g. a wrapper around `tsserver` or `tsgo`.
+///
+/// The process is spawned in the working directory of the linter, and reads requests from its
+/// standard input and writes responses to its standard output, one JSON object per line:
+///
+/// ```json
+/// {"id":1,"method":"typeOf","file":"/project/src/a.ts","start":10,"end":24}
+/// {"id":1,"type":{"text":"Promise<void>","flags":["promiseLike"]}}
+/// ```
+///
+/// `method` is `typeOf` or `contextualTypeOf`, `start` and `end` are UTF-8 byte offsets in the
+/// file as it is on disk. `type` is `null` when the type is not known, and the supported `flags`
+/// are `any`, `unknown` and `promiseLike`. Requests are sent one at a time.
+#[derive(Debug)]
+pub struct SidecarTypeChecker {
+    process: Mutex<SidecarProcess>,
+}
+
+#[derive(Debug)]
+struct SidecarProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    id: u64,
+    method: TypeQuery,
+    file: &'a Path,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    id: u64,
+    #[serde(rename = "type")]
+    r#type: Option<ResponseType>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseType {
+    text: String,
+    #[serde(default)]
+    flags: Vec<String>,
+}
+
+impl SidecarTypeChecker {
+    /// Spawn `command` with `args` in `cwd`.
+    ///
+    /// # Errors
+    /// When the process can't be spawned
+    pub fn spawn<I, S>(command: &OsStr, args: I, cwd: &Path) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut child = Command::new(command)
+            .args(args)
+            .current_dir(cwd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(io::Error::other("failed to open the pipes of the type checker"));
+        };
+        let process = SidecarProcess { child, stdin, stdout: BufReader::new(stdout), next_id: 0 };
+        Ok(Self { process: Mutex::new(process) })
+    }
+}
+
+impl SidecarProcess {
+    fn request(&mut self, path: &Path, span: Span, query: TypeQuery) -> io::Result<Response> {
+        self.next_id += 1;
+        let request = Request {
+            id: self.next_id,
+            method: query,
+            file: path,
+            start: span.start,
+            end: span.end,
+        };
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        self.stdin.write_all(line.as_bytes())?;
+        self.stdin.flush()?;
+
+        loop {
+            line.clear();
+            if self.stdout.read_line(&mut line)? == 0 {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+            let response = serde_json::from_str::<Response>(&line)?;
+            // Skip late responses to earlier requests which failed, e.g. because of an invalid response.
+            if response.id == self.next_id {
+                return Ok(response);
+            }
+        }
+    }
+}
+
+impl TypeChecker for SidecarTypeChecker {
+    fn query(&self, path: &Path, span: Span, query: TypeQuery) -> Option<TypeInfo> {
+        let mut process = self.process.lock().unwrap_or_else(PoisonError::into_inner);
+        let response = process.request(path, span, query).ok()?;
+        response.r#type.map(ResponseType::into_type_info)
+    }
+}
+
+impl Drop for SidecarTypeChecker {
+    fn drop(&mut self) {
+        let process = self.process.get_mut().unwrap_or_else(PoisonError::into_inner);
+        let _ = process.child.kill();
+        let _ = process.child.wait();
+    }
+}
+
+impl ResponseType {
+    fn into_type_info(self) -> TypeInfo {
+        let flags = self.flags.iter().fold(TypeFlags::empty(), |flags, flag| {
+            flags
+                | match flag.as_str() {
+                    "any" => TypeFlags::ANY,
+                    "unknown" => TypeFlags::UNKNOWN,
+                    "promiseLike" => TypeFlags::PROMISE_LIKE,
+                    _ => TypeFlags::empty(),
+                }
+        });
+        TypeInfo { text: CompactStr::from(self.text), flags }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_span::Span;
+
+    use super::{Request, Response, TypeFlags, TypeInfo, TypeQuery};
+
+    #[test]
+    fn protocol() {
+        let request = Request {
+            id: 1,
+            method: TypeQuery::ContextualTypeOf,
+            file: Path::new("/project/a.ts"),
+            start: 10,
+            end: 24,
+        };
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"id":1,"method":"contextualTypeOf","file":"/project/a.ts","start":10,"end":24}"#
+        );
+
+        let response: Response = serde_json::from_str(
+            r#"{"id":1,"type":{"text":"Promise<any>","flags":["promiseLike","other"]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.r#type.unwrap().into_type_info(),
+            TypeInfo::new("Promise<any>", TypeFlags::PROMISE_LIKE)
+        );
+
+        let response: Response = serde_json::from_str(r#"{"id":2,"type":null}"#).unwrap();
+        assert_eq!(response.id, 2);
+        assert!(response.r#type.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sidecar() {
+        use super::{SidecarTypeChecker, TypeChecker};
+
+        let script =
+            r#"while read -r line; do echo '{"id":1,"type":{"text":"any","flags":["any"]}}'; done"#;
+        let checker = SidecarTypeChecker::spawn("sh".as_ref(), ["-c", script], Path::new("."))
+            .expect("failed to spawn sh");
+        let info = checker.query(Path::new("a.ts"), Span::new(0, 1), TypeQuery::TypeOf).unwrap();
+        assert!(info.is_any());
+    }
+}
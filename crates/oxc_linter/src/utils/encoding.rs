@@ -0,0 +1,214 @@
+use std::{borrow::Cow, fs, io, path::Path};
+
+use oxc_allocator::Allocator;
+
+/// Encoding of a source file.
+///
+/// Files which are not valid UTF-8 are transcoded when they are read, so they can be linted.
+/// Fixes are encoded back to UTF-16, but never written to Latin-1 files, see
+/// [`write_in_source_encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    /// ISO-8859-1. Used for all files which are not valid UTF-8 or UTF-16.
+    Latin1,
+}
+
+impl SourceEncoding {
+    /// Detect encoding of `bytes`.
+    ///
+    /// UTF-16 is detected by its byte order mark, or if the text starts with Latin-1 characters
+    /// which are all followed or preceded by a zero byte.
+    pub fn detect(bytes: &[u8]) -> Self {
+        Self::detect_utf16(bytes).unwrap_or_else(|| {
+            if simdutf8::basic::from_utf8(bytes).is_ok() { Self::Utf8 } else { Self::Latin1 }
+        })
+    }
+
+    fn detect_utf16(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0xFF, 0xFE, ..] => Some(Self::Utf16Le),
+            [0xFE, 0xFF, ..] => Some(Self::Utf16Be),
+            [a, 0, ..] if *a != 0 && looks_like_utf16(bytes, 1) => Some(Self::Utf16Le),
+            [0, b, ..] if *b != 0 && looks_like_utf16(bytes, 0) => Some(Self::Utf16Be),
+            _ => None,
+        }
+    }
+
+    /// Decode `bytes` in the encoding detected by [`SourceEncoding::detect`].
+    ///
+    /// Only validates UTF-8 once, so it's faster than calling [`SourceEncoding::detect`]
+    /// followed by [`SourceEncoding::decode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` starts like UTF-16, but is not valid UTF-16.
+    pub fn detect_and_decode<'alloc>(
+        bytes: &'alloc [u8],
+        allocator: &'alloc Allocator,
+    ) -> io::Result<&'alloc str> {
+        if let Some(encoding) = Self::detect_utf16(bytes) {
+            return encoding.decode(bytes, allocator);
+        }
+        match simdutf8::basic::from_utf8(bytes) {
+            Ok(text) => Ok(text),
+            Err(_) => Self::Latin1.decode(bytes, allocator),
+        }
+    }
+
+    /// Decode `bytes` into a `&str` in `allocator`.
+    ///
+    /// A byte order mark is kept in the decoded text, as it is for UTF-8 files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not valid UTF-8 or UTF-16 respectively.
+    pub fn decode<'alloc>(
+        self,
+        bytes: &'alloc [u8],
+        allocator: &'alloc Allocator,
+    ) -> io::Result<&'alloc str> {
+        let decoded = match self {
+            Self::Utf8 => {
+                return simdutf8::basic::from_utf8(bytes).map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+                });
+            }
+            Self::Utf16Le | Self::Utf16Be => {
+                let invalid = || {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream did not contain valid UTF-16",
+                    )
+                };
+                if bytes.len() % 2 != 0 {
+                    return Err(invalid());
+                }
+                let units = bytes.chunks_exact(2).map(|pair| {
+                    let pair = [pair[0], pair[1]];
+                    if self == Self::Utf16Le {
+                        u16::from_le_bytes(pair)
+                    } else {
+                        u16::from_be_bytes(pair)
+                    }
+                });
+                char::decode_utf16(units).collect::<Result<String, _>>().map_err(|_| invalid())?
+            }
+            Self::Latin1 => bytes.iter().map(|&byte| char::from(byte)).collect::<String>(),
+        };
+        Ok(allocator.alloc_str(&decoded))
+    }
+
+    /// Encode `text` in this encoding.
+    ///
+    /// Returns [`None`] if `text` contains characters which can't be represented in Latin-1.
+    pub fn encode(self, text: &str) -> Option<Cow<'_, [u8]>> {
+        match self {
+            Self::Utf8 => Some(Cow::Borrowed(text.as_bytes())),
+            Self::Utf16Le => {
+                Some(Cow::Owned(text.encode_utf16().flat_map(u16::to_le_bytes).collect()))
+            }
+            Self::Utf16Be => {
+                Some(Cow::Owned(text.encode_utf16().flat_map(u16::to_be_bytes).collect()))
+            }
+            Self::Latin1 => text
+                .chars()
+                .map(|c| u8::try_from(u32::from(c)).ok())
+                .collect::<Option<Vec<_>>>()
+                .map(Cow::Owned),
+        }
+    }
+}
+
+/// Check that the first characters of `bytes` are Latin-1 characters encoded as UTF-16,
+/// with the zero byte of each code unit at `zero_index` (0 for big endian, 1 for little endian).
+fn looks_like_utf16(bytes: &[u8], zero_index: usize) -> bool {
+    bytes.len() % 2 == 0 && bytes.chunks_exact(2).take(64).all(|pair| pair[zero_index] == 0)
+}
+
+/// Write `content` to the file at `path`, in the encoding of the existing file.
+///
+/// Files detected as Latin-1 are not written: any file which is not valid UTF-8 is detected as
+/// Latin-1, so it may as well be a binary file or be in another encoding.
+///
+/// # Errors
+///
+/// Returns an error if the existing file is detected as Latin-1, or if the file can't be written.
+pub fn write_in_source_encoding(path: &Path, content: &str) -> io::Result<()> {
+    let encoding =
+        fs::read(path).map_or(SourceEncoding::Utf8, |bytes| SourceEncoding::detect(&bytes));
+    let bytes = match encoding {
+        SourceEncoding::Latin1 => None,
+        encoding => encoding.encode(content),
+    };
+    let Some(bytes) = bytes else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "fixes are only written to files which are valid UTF-8 or UTF-16",
+        ));
+    };
+    fs::write(path, bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::{SourceEncoding, write_in_source_encoding};
+
+    #[test]
+    fn detect() {
+        assert_eq!(SourceEncoding::detect(b"let a = 1;"), SourceEncoding::Utf8);
+        assert_eq!(SourceEncoding::detect("let a = 'é';".as_bytes()), SourceEncoding::Utf8);
+        assert_eq!(SourceEncoding::detect(b"\xEF\xBB\xBFlet a;"), SourceEncoding::Utf8);
+        assert_eq!(SourceEncoding::detect(b"\xFF\xFEl\0e\0t\0"), SourceEncoding::Utf16Le);
+        assert_eq!(SourceEncoding::detect(b"\xFE\xFF\0l\0e\0t"), SourceEncoding::Utf16Be);
+        assert_eq!(SourceEncoding::detect(b"l\0e\0t\0"), SourceEncoding::Utf16Le);
+        assert_eq!(SourceEncoding::detect(b"\0l\0e\0t"), SourceEncoding::Utf16Be);
+        assert_eq!(SourceEncoding::detect(b"let a = '\xE9';"), SourceEncoding::Latin1);
+        assert_eq!(SourceEncoding::detect(b""), SourceEncoding::Utf8);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let allocator = Allocator::default();
+        let cases: [(&[u8], &str); 4] = [
+            (b"\xFF\xFEa\0=\0\xE9\0", "\u{FEFF}a=\u{e9}"),
+            (b"\xFE\xFF\0a\0=\0\xE9", "\u{FEFF}a=\u{e9}"),
+            (b"a\0=\0\xE9\0", "a=\u{e9}"),
+            (b"a='\xE9'", "a='\u{e9}'"),
+        ];
+        for (bytes, text) in cases {
+            let encoding = SourceEncoding::detect(bytes);
+            assert_eq!(encoding.decode(bytes, &allocator).unwrap(), text);
+            assert_eq!(SourceEncoding::detect_and_decode(bytes, &allocator).unwrap(), text);
+            assert_eq!(&*encoding.encode(text).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let allocator = Allocator::default();
+        // Odd number of bytes
+        assert!(SourceEncoding::Utf16Le.decode(b"\xFF\xFEa", &allocator).is_err());
+        // Unpaired surrogate
+        assert!(SourceEncoding::Utf16Le.decode(b"\xFF\xFE\x00\xD8", &allocator).is_err());
+        // Characters which can't be encoded in Latin-1
+        assert!(SourceEncoding::Latin1.encode("a = '€'").is_none());
+    }
+
+    #[test]
+    fn fixes_are_not_written_to_latin1_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.js");
+        std::fs::write(&path, b"a = '\xE9';").unwrap();
+        assert!(write_in_source_encoding(&path, "a = '\u{e9}'").is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"a = '\xE9';");
+
+        std::fs::write(&path, b"\xFF\xFEa\0").unwrap();
+        write_in_source_encoding(&path, "\u{FEFF}b").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"\xFF\xFEb\0");
+    }
+}
@@ -69,6 +69,11 @@ pub fn parse_jest_fn_call<'a>(
         };
 
         if matches!(kind, JestFnKind::Expect | JestFnKind::ExpectTypeOf) {
+            let (head, members) = if matches!(kind, JestFnKind::Expect) {
+                unwrap_expect_variant(head, members)
+            } else {
+                (head, members)
+            };
             let options = ExpectFnCallOptions {
                 call_expr,
                 members,
@@ -125,6 +130,37 @@ pub fn parse_jest_fn_call<'a>(
     None
 }
 
+/// Vitest's `expect.soft(a).toBe(b)` and `expect.poll(() => a).toBe(b)` work like `expect(a).toBe(b)`,
+/// so treat `expect.soft(...)` as the head of the chain.
+fn unwrap_expect_variant<'a>(
+    head: KnownMemberExpressionProperty<'a>,
+    members: Vec<KnownMemberExpressionProperty<'a>>,
+) -> (KnownMemberExpressionProperty<'a>, Vec<KnownMemberExpressionProperty<'a>>) {
+    let [variant, next, ..] = members.as_slice() else {
+        return (head, members);
+    };
+    if !(variant.is_name_equal("soft") || variant.is_name_equal("poll"))
+        || !matches!(variant.grandparent_kind, Some(KnownMemberExpressionParentKind::Call))
+    {
+        return (head, members);
+    }
+    // `expect.soft(a)` is the object of the member expression of the next link
+    let Some(call_expr @ Expression::CallExpression(_)) =
+        next.parent.and_then(Expression::as_member_expression).map(MemberExpression::object)
+    else {
+        return (head, members);
+    };
+
+    let head = KnownMemberExpressionProperty {
+        element: head.element,
+        parent: Some(call_expr),
+        parent_kind: Some(KnownMemberExpressionParentKind::Call),
+        grandparent_kind: Some(KnownMemberExpressionParentKind::Member),
+        span: Span::new(head.span.start, variant.span.end),
+    };
+    (head, members.into_iter().skip(1).collect())
+}
+
 fn parse_jest_expect_fn_call<'a>(
     options: ExpectFnCallOptions<'a, '_>,
     is_type_of: bool,
@@ -355,7 +391,6 @@ pub struct ParsedGeneralJestFnCall<'a> {
     pub kind: JestFnKind,
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: Cow<'a, str>,
-    #[expect(unused)]
     pub local: Cow<'a, str>,
 }
 
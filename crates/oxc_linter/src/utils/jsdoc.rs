@@ -9,6 +9,9 @@ use oxc_span::Span;
 
 use crate::{AstNode, config::JSDocPluginSettings};
 
+mod type_expression;
+pub use type_expression::*;
+
 pub const CUSTOM_SKIP_TAG_NAMES: [&str; 6] =
     ["abstract", "class", "constructor", "interface", "type", "virtual"];
 
@@ -0,0 +1,568 @@
+use oxc_span::Span;
+
+/// A parsed JSDoc type expression, e.g. the `Array<Foo>|null` in `@param {Array<Foo>|null} foo`.
+///
+/// Both the Closure Compiler flavor (`?Foo`, `Foo=`, `function(Foo): Bar`, `Object.<K, V>`)
+/// and the TypeScript flavor (`Foo | null`, `(a: Foo) => Bar`, `typeof foo`, `import('x').Foo`)
+/// are supported, as both are commonly found in the wild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocTypeExpression<'a> {
+    /// `*`
+    Any,
+    /// `?` on its own
+    Unknown,
+    /// `Foo`, `Foo.Bar`, `Foo#bar`, `module:foo/bar`
+    Name(JSDocTypeName<'a>),
+    /// `'foo'`, `1`, `-1`
+    Literal(&'a str),
+    /// `Array<Foo>`, `Array.<Foo>`, `Object<K, V>`
+    Generic { base: Box<Self>, arguments: Vec<Self> },
+    /// `Foo[]`
+    Array(Box<Self>),
+    /// `Foo | Bar`
+    Union(Vec<Self>),
+    /// `?Foo`, `!Foo`, `Foo=`, `...Foo`, `Foo?`, `Foo!`
+    Modified { modifier: JSDocTypeModifier, inner: Box<Self> },
+    /// `function(this: Foo, Bar): Baz`, `(bar: Bar) => Baz`
+    Function { params: Vec<Self>, returns: Option<Box<Self>> },
+    /// `{ foo: Foo, bar }`
+    Record(Vec<JSDocRecordField<'a>>),
+    /// `[Foo, Bar]`
+    Tuple(Vec<Self>),
+    /// `typeof foo`
+    TypeOf(JSDocTypeName<'a>),
+    /// `keyof Foo`
+    KeyOf(Box<Self>),
+    /// `import('./foo').Foo`
+    Import { module: &'a str, member: Option<&'a str> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JSDocTypeModifier {
+    /// `?Foo` or `Foo?`
+    Nullable,
+    /// `!Foo` or `Foo!`
+    NonNullable,
+    /// `Foo=`
+    Optional,
+    /// `...Foo`
+    Variadic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JSDocTypeName<'a> {
+    pub name: &'a str,
+    pub span: Span,
+}
+
+impl<'a> JSDocTypeName<'a> {
+    /// The first segment of a name path, e.g. `Foo` for `Foo.Bar#baz`.
+    pub fn root(&self) -> &'a str {
+        self.name.split(['.', '#', '~']).next().unwrap_or(self.name)
+    }
+
+    /// Whether this is a namepath with a prefix like `module:`, which does not refer to a
+    /// declaration in scope.
+    pub fn is_prefixed(&self) -> bool {
+        self.name.contains(':')
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordField<'a> {
+    pub key: &'a str,
+    pub value: Option<JSDocTypeExpression<'a>>,
+}
+
+impl<'a> JSDocTypeExpression<'a> {
+    /// Parse the `{...}` type part of a tag, given its span including the braces.
+    ///
+    /// Returns `None` if the type is not a valid type expression.
+    pub fn parse_type_part(type_span: Span, source_text: &'a str) -> Option<Self> {
+        let start = type_span.start + 1;
+        let end = type_span.end.checked_sub(1)?;
+        Self::parse(source_text.get(start as usize..end as usize)?, start)
+    }
+
+    /// Parse a type expression. `span_start` is the offset of `source` in the file, used to
+    /// compute the spans of names.
+    pub fn parse(source: &'a str, span_start: u32) -> Option<Self> {
+        let mut parser = Parser { source, pos: 0, span_start };
+        let ty = parser.parse_type()?;
+        parser.skip_trivia();
+        parser.at_end().then_some(ty)
+    }
+
+    /// Visit every name referenced as a type or a value (`typeof foo`) in this expression.
+    pub fn walk_names<F: FnMut(&JSDocTypeName<'a>)>(&self, f: &mut F) {
+        match self {
+            Self::Name(name) | Self::TypeOf(name) => f(name),
+            Self::Generic { base, arguments } => {
+                base.walk_names(f);
+                arguments.iter().for_each(|argument| argument.walk_names(f));
+            }
+            Self::Array(inner) | Self::Modified { inner, .. } | Self::KeyOf(inner) => {
+                inner.walk_names(f);
+            }
+            Self::Union(types) | Self::Tuple(types) => types.iter().for_each(|ty| ty.walk_names(f)),
+            Self::Function { params, returns } => {
+                params.iter().for_each(|param| param.walk_names(f));
+                if let Some(returns) = returns {
+                    returns.walk_names(f);
+                }
+            }
+            Self::Record(fields) => {
+                fields
+                    .iter()
+                    .filter_map(|field| field.value.as_ref())
+                    .for_each(|v| v.walk_names(f));
+            }
+            Self::Any | Self::Unknown | Self::Literal(_) | Self::Import { .. } => {}
+        }
+    }
+
+    /// Whether this type only ever describes the absence of a value, e.g. `void`, `undefined`
+    /// or `Promise<void>`.
+    pub fn is_void_like(&self) -> bool {
+        match self {
+            Self::Name(name) => matches!(name.name, "void" | "undefined" | "never"),
+            Self::Union(types) => types.iter().all(Self::is_void_like),
+            Self::Generic { base, arguments } => {
+                matches!(&**base, Self::Name(name) if name.name == "Promise")
+                    && arguments.iter().all(Self::is_void_like)
+            }
+            _ => false,
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    span_start: u32,
+}
+
+impl<'a> Parser<'a> {
+    fn at_end(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    /// Skip whitespace, including the leading `*` of continuation lines in a multiline type.
+    fn skip_trivia(&mut self) {
+        let mut at_line_start = false;
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                at_line_start = true;
+            } else if c == '*' && at_line_start {
+                at_line_start = false;
+            } else if !c.is_whitespace() {
+                return;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_trivia();
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Option<()> {
+        self.eat(s).then_some(())
+    }
+
+    /// Eat a keyword like `typeof`, which must not be followed by an identifier character.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_trivia();
+        let rest = self.rest();
+        if rest.starts_with(keyword)
+            && !rest[keyword.len()..].chars().next().is_some_and(is_identifier_part)
+        {
+            self.pos += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Option<&'a str> {
+        self.skip_trivia();
+        let start = self.pos;
+        if !self.peek().is_some_and(is_identifier_start) {
+            return None;
+        }
+        while self.peek().is_some_and(is_identifier_part) {
+            self.pos += self.peek().map_or(1, char::len_utf8);
+        }
+        Some(&self.source[start..self.pos])
+    }
+
+    /// `Foo`, `Foo.Bar`, `Foo#bar`, `Foo~bar`, `module:foo/bar.baz`
+    fn parse_name(&mut self) -> Option<JSDocTypeName<'a>> {
+        self.skip_trivia();
+        let start = self.pos;
+        let first = self.parse_identifier()?;
+        if matches!(first, "module" | "external" | "event") && self.rest().starts_with(':') {
+            // Prefixed namepath, the rest may contain path characters
+            self.pos += 1;
+            while self.peek().is_some_and(|c| is_identifier_part(c) || "/.-@#~".contains(c)) {
+                self.pos += self.peek().map_or(1, char::len_utf8);
+            }
+        } else {
+            loop {
+                let rest = self.rest();
+                // Don't eat `.` of `Array.<Foo>`
+                if rest.starts_with(['.', '#', '~'])
+                    && rest[1..].chars().next().is_some_and(is_identifier_start)
+                {
+                    self.pos += 1;
+                    self.parse_identifier()?;
+                } else {
+                    break;
+                }
+            }
+        }
+        let end = self.pos;
+        Some(JSDocTypeName { name: &self.source[start..end], span: self.span(start, end) })
+    }
+
+    fn span(&self, start: usize, end: usize) -> Span {
+        let offset = |pos: usize| self.span_start + u32::try_from(pos).unwrap_or_default();
+        Span::new(offset(start), offset(end))
+    }
+
+    fn parse_type(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        // Leading `|` is allowed in TypeScript
+        self.eat("|");
+        let first = self.parse_prefixed()?;
+        let mut types = vec![first];
+        while self.eat("|") {
+            types.push(self.parse_prefixed()?);
+        }
+        Some(if types.len() == 1 { types.remove(0) } else { JSDocTypeExpression::Union(types) })
+    }
+
+    fn parse_prefixed(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        let modifier = if self.eat("...") {
+            JSDocTypeModifier::Variadic
+        } else if self.eat("!") {
+            JSDocTypeModifier::NonNullable
+        } else if self.eat("?") {
+            // `?` on its own is the unknown type
+            self.skip_trivia();
+            if self.at_end() || self.rest().starts_with([',', '|', '>', ')', ']', '}', '=']) {
+                return Some(JSDocTypeExpression::Unknown);
+            }
+            JSDocTypeModifier::Nullable
+        } else {
+            return self.parse_postfix();
+        };
+        let inner = self.parse_prefixed()?;
+        Some(JSDocTypeExpression::Modified { modifier, inner: Box::new(inner) })
+    }
+
+    fn parse_postfix(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        let mut ty = self.parse_primary()?;
+        loop {
+            if self.eat("[]") {
+                ty = JSDocTypeExpression::Array(Box::new(ty));
+            } else if self.eat(".<") || self.eat("<") {
+                let arguments = self.parse_list(">")?;
+                ty = JSDocTypeExpression::Generic { base: Box::new(ty), arguments };
+            } else if self.eat("=") {
+                // `=>` is handled by the caller
+                if self.rest().starts_with('>') {
+                    self.pos -= 1;
+                    break;
+                }
+                ty = modified(JSDocTypeModifier::Optional, ty);
+            } else if self.eat("!") {
+                ty = modified(JSDocTypeModifier::NonNullable, ty);
+            } else if self.rest_after_trivia().starts_with('?')
+                && !self.rest_after_trivia().starts_with("?:")
+            {
+                self.eat("?");
+                ty = modified(JSDocTypeModifier::Nullable, ty);
+            } else {
+                break;
+            }
+        }
+        Some(ty)
+    }
+
+    fn rest_after_trivia(&mut self) -> &'a str {
+        self.skip_trivia();
+        self.rest()
+    }
+
+    fn parse_primary(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        self.skip_trivia();
+        let c = self.peek()?;
+        match c {
+            '*' => {
+                self.pos += 1;
+                Some(JSDocTypeExpression::Any)
+            }
+            '(' => {
+                let checkpoint = self.pos;
+                if let Some(function) = self.parse_arrow_function() {
+                    return Some(function);
+                }
+                self.pos = checkpoint + 1;
+                let ty = self.parse_type()?;
+                self.expect(")")?;
+                Some(ty)
+            }
+            '{' => {
+                self.pos += 1;
+                self.parse_record()
+            }
+            '[' => {
+                self.pos += 1;
+                Some(JSDocTypeExpression::Tuple(self.parse_list("]")?))
+            }
+            '\'' | '"' | '`' => {
+                let start = self.pos;
+                self.pos += 1;
+                let end = self.rest().find(c)?;
+                self.pos += end + 1;
+                Some(JSDocTypeExpression::Literal(&self.source[start..self.pos]))
+            }
+            '-' | '0'..='9' => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '.') {
+                    self.pos += 1;
+                }
+                Some(JSDocTypeExpression::Literal(&self.source[start..self.pos]))
+            }
+            _ => {
+                if self.eat_keyword("function") {
+                    return self.parse_closure_function();
+                }
+                if self.eat_keyword("typeof") {
+                    return self.parse_name().map(JSDocTypeExpression::TypeOf);
+                }
+                if self.eat_keyword("keyof") {
+                    let inner = self.parse_prefixed()?;
+                    return Some(JSDocTypeExpression::KeyOf(Box::new(inner)));
+                }
+                if self.eat_keyword("import") {
+                    return self.parse_import();
+                }
+                self.parse_name().map(JSDocTypeExpression::Name)
+            }
+        }
+    }
+
+    /// `Foo, Bar>` after the opening delimiter
+    fn parse_list(&mut self, close: &str) -> Option<Vec<JSDocTypeExpression<'a>>> {
+        let mut types = vec![];
+        while !self.eat(close) {
+            types.push(self.parse_type()?);
+            if !self.eat(",") {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Some(types)
+    }
+
+    /// `{ foo: Foo, 'bar'?: Bar; baz }` after the `{`
+    fn parse_record(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        let mut fields = vec![];
+        while !self.eat("}") {
+            self.skip_trivia();
+            let key = match self.peek()? {
+                quote @ ('\'' | '"') => {
+                    let start = self.pos;
+                    self.pos += 1;
+                    let end = self.rest().find(quote)?;
+                    self.pos += end + 1;
+                    &self.source[start..self.pos]
+                }
+                _ => self.parse_identifier()?,
+            };
+            self.eat("?");
+            let value = if self.eat(":") { Some(self.parse_type()?) } else { None };
+            fields.push(JSDocRecordField { key, value });
+            if !self.eat(",") && !self.eat(";") {
+                self.expect("}")?;
+                break;
+            }
+        }
+        Some(JSDocTypeExpression::Record(fields))
+    }
+
+    /// `(foo: Foo, ...bar: Bar[]) => Baz`
+    fn parse_arrow_function(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        self.expect("(")?;
+        let mut params = vec![];
+        while !self.eat(")") {
+            let variadic = self.eat("...");
+            self.parse_identifier()?;
+            self.eat("?");
+            self.expect(":")?;
+            let ty = self.parse_type()?;
+            params.push(if variadic { modified(JSDocTypeModifier::Variadic, ty) } else { ty });
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        self.expect("=>")?;
+        let returns = self.parse_type()?;
+        Some(JSDocTypeExpression::Function { params, returns: Some(Box::new(returns)) })
+    }
+
+    /// `(this: Foo, new: Foo, Bar): Baz` after the `function` keyword
+    fn parse_closure_function(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        let keyword_end = self.pos;
+        let mut params = vec![];
+        if self.eat("(") {
+            while !self.eat(")") {
+                if (self.eat_keyword("this") || self.eat_keyword("new")) && !self.eat(":") {
+                    return None;
+                }
+                params.push(self.parse_type()?);
+                if !self.eat(",") {
+                    self.expect(")")?;
+                    break;
+                }
+            }
+        } else {
+            // Bare `function` is a type name
+            return Some(JSDocTypeExpression::Name(JSDocTypeName {
+                name: "function",
+                span: self.span(keyword_end - "function".len(), keyword_end),
+            }));
+        }
+        let returns = if self.eat(":") { Some(Box::new(self.parse_prefixed()?)) } else { None };
+        Some(JSDocTypeExpression::Function { params, returns })
+    }
+
+    /// `('./foo').Foo` after the `import` keyword
+    fn parse_import(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        self.expect("(")?;
+        let JSDocTypeExpression::Literal(module) = self.parse_primary()? else {
+            return None;
+        };
+        self.expect(")")?;
+        let member = if self.eat(".") { Some(self.parse_name()?.name) } else { None };
+        Some(JSDocTypeExpression::Import { module: &module[1..module.len() - 1], member })
+    }
+}
+
+fn modified(modifier: JSDocTypeModifier, inner: JSDocTypeExpression) -> JSDocTypeExpression {
+    JSDocTypeExpression::Modified { modifier, inner: Box::new(inner) }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c == '$' || c == '_' || c.is_alphabetic()
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c == '$' || c == '_' || c.is_alphanumeric()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JSDocTypeExpression, JSDocTypeModifier};
+
+    fn names(source: &str) -> Vec<&str> {
+        let ty = JSDocTypeExpression::parse(source, 0)
+            .unwrap_or_else(|| panic!("failed to parse `{source}`"));
+        let mut names = vec![];
+        ty.walk_names(&mut |name| names.push(name.name));
+        names
+    }
+
+    #[test]
+    fn parses_names() {
+        let cases: &[(&str, &[&str])] = &[
+            ("Foo", &["Foo"]),
+            ("Foo.Bar#baz", &["Foo.Bar#baz"]),
+            ("module:foo/bar", &["module:foo/bar"]),
+            ("Array<Foo>", &["Array", "Foo"]),
+            ("Array.<Foo>", &["Array", "Foo"]),
+            ("Object.<string, Foo>", &["Object", "string", "Foo"]),
+            ("Foo[]", &["Foo"]),
+            ("Foo | Bar | null", &["Foo", "Bar", "null"]),
+            ("?Foo", &["Foo"]),
+            ("!Foo=", &["Foo"]),
+            ("...Foo", &["Foo"]),
+            ("function(this: Foo, Bar): Baz", &["Foo", "Bar", "Baz"]),
+            ("function", &["function"]),
+            ("(foo: Foo, ...bar: Bar[]) => Baz", &["Foo", "Bar", "Baz"]),
+            ("(Foo | Bar)[]", &["Foo", "Bar"]),
+            ("{ foo: Foo, 'bar'?: Bar; baz }", &["Foo", "Bar"]),
+            ("[Foo, Bar]", &["Foo", "Bar"]),
+            ("typeof foo", &["foo"]),
+            ("keyof Foo", &["Foo"]),
+            ("import('./foo').Foo", &[]),
+            ("'foo' | 1 | -1", &[]),
+            ("*", &[]),
+            ("?", &[]),
+            ("Foo\n   * | Bar", &["Foo", "Bar"]),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(&names(source), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        for source in ["", "Array<Foo", "Foo Bar", "{ foo: }", "function(", "@link Foo", "Foo|"] {
+            assert!(JSDocTypeExpression::parse(source, 0).is_none(), "{source}");
+        }
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        let Some(JSDocTypeExpression::Modified { modifier, .. }) =
+            JSDocTypeExpression::parse("Foo=", 0)
+        else {
+            panic!();
+        };
+        assert_eq!(modifier, JSDocTypeModifier::Optional);
+
+        let Some(JSDocTypeExpression::Modified { modifier, .. }) =
+            JSDocTypeExpression::parse("?Foo", 0)
+        else {
+            panic!();
+        };
+        assert_eq!(modifier, JSDocTypeModifier::Nullable);
+    }
+
+    #[test]
+    fn spans() {
+        let ty = JSDocTypeExpression::parse("Array<Foo>", 10).unwrap();
+        let mut spans = vec![];
+        ty.walk_names(&mut |name| spans.push((name.span.start, name.span.end)));
+        assert_eq!(spans, vec![(10, 15), (16, 19)]);
+    }
+
+    #[test]
+    fn void_like() {
+        for source in ["void", "undefined", "Promise<void>", "void | undefined", "never"] {
+            assert!(JSDocTypeExpression::parse(source, 0).unwrap().is_void_like(), "{source}");
+        }
+        for source in ["string", "Promise<string>", "string | undefined"] {
+            assert!(!JSDocTypeExpression::parse(source, 0).unwrap().is_void_like(), "{source}");
+        }
+    }
+}
@@ -11,6 +11,7 @@ use oxc_allocator::Allocator;
 
 mod comment;
 mod config;
+mod encoding;
 mod express;
 mod jest;
 mod jsdoc;
@@ -19,17 +20,18 @@ mod promise;
 mod react;
 mod react_perf;
 mod regex;
+mod security;
 mod unicorn;
 mod url;
 mod vitest;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
-    react_perf::*, regex::*, unicorn::*, url::*, vitest::*,
+    comment::*, config::*, encoding::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*,
+    react::*, react_perf::*, regex::*, security::*, unicorn::*, url::*, vitest::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
-const VITEST_COMPATIBLE_JEST_RULES: [&str; 34] = [
+const VITEST_COMPATIBLE_JEST_RULES: [&str; 40] = [
     "consistent-test-it",
     "expect-expect",
     "max-expects",
@@ -49,6 +51,7 @@ const VITEST_COMPATIBLE_JEST_RULES: [&str; 34] = [
     "no-standalone-expect",
     "no-test-prefixes",
     "no-test-return-statement",
+    "prefer-called-with",
     "prefer-comparison-matcher",
     "prefer-each",
     "prefer-equality-matcher",
@@ -57,13 +60,18 @@ const VITEST_COMPATIBLE_JEST_RULES: [&str; 34] = [
     "prefer-hooks-on-top",
     "prefer-lowercase-title",
     "prefer-mock-promise-shorthand",
+    "prefer-spy-on",
     "prefer-strict-equal",
+    "prefer-to-be",
+    "prefer-to-contain",
     "prefer-to-have-length",
     "prefer-todo",
+    "require-hook",
     "require-to-throw-message",
     "require-top-level-describe",
     "valid-describe-callback",
     "valid-expect",
+    "valid-title",
 ];
 
 // List of Eslint rules that have Typescript equivalents.
@@ -141,13 +149,16 @@ pub fn read_to_string(path: &Path) -> io::Result<String> {
     Ok(unsafe { String::from_utf8_unchecked(bytes) })
 }
 
-/// Read the contents of a UTF-8 encoded file directly into arena allocator.
+/// Read the contents of a file directly into arena allocator.
 /// Avoids intermediate allocations if file size is known in advance.
 ///
 /// This function opens the file at `path`, reads its entire contents into memory
 /// allocated from the given [`Allocator`], validates that the bytes are valid UTF-8,
 /// and returns a borrowed `&str` pointing to the allocator-backed data.
 ///
+/// Files in other encodings (UTF-16 or Latin-1, see [`SourceEncoding::detect`]) are transcoded
+/// to UTF-8, which requires a second allocation.
+///
 /// This is useful for performance-critical workflows where zero-copy string handling is desired,
 /// such as parsing large source files in memory-constrained or throughput-sensitive environments.
 ///
@@ -161,7 +172,7 @@ pub fn read_to_string(path: &Path) -> io::Result<String> {
 /// Returns [`io::Error`] if any of:
 ///
 /// - The file cannot be read.
-/// - The file's contents are not valid UTF-8.
+/// - The file's contents are not valid in the detected encoding.
 /// - The file is larger than `isize::MAX` bytes.
 pub fn read_to_arena_str<'alloc>(
     path: &Path,
@@ -175,10 +186,8 @@ pub fn read_to_arena_str<'alloc>(
         read_to_arena_bytes_unknown_size(file, allocator)
     }?;
 
-    // Convert to `&str`, checking contents is valid UTF-8
-    simdutf8::basic::from_utf8(bytes).map_err(|_| {
-        io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
-    })
+    // Convert to `&str`, checking contents is valid UTF-8, or transcoding it if it's not UTF-8
+    SourceEncoding::detect_and_decode(bytes, allocator)
 }
 
 /// Read contents of file directly into arena.
@@ -1,4 +1,6 @@
-use oxc_ast::ast::CallExpression;
+use oxc_ast::{AstKind, ast::CallExpression};
+
+use crate::{AstNode, LintContext};
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
 pub const PROMISE_STATIC_METHODS: [&str; 7] =
@@ -20,3 +22,18 @@ pub fn is_promise(call_expr: &CallExpression) -> Option<String> {
 
     None
 }
+
+/// Get the promise method call which `node` is a callback function of, and the name of the
+/// method, e.g. `promise.then` and `"then"` for the arrow function of `promise.then(() => {})`.
+pub fn get_promise_callback_call<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<(&'b CallExpression<'a>, String)> {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        || !matches!(ctx.nodes().parent_kind(node.id()), AstKind::Argument(_))
+    {
+        return None;
+    }
+    let call_expr = ctx.nodes().ancestors(node.id()).nth(1)?.kind().as_call_expression()?;
+    is_promise(call_expr).map(|prop_name| (call_expr, prop_name))
+}
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPatternKind, Expression, IdentifierReference},
+};
+use oxc_ecmascript::constant_evaluation::ConstantValue;
+use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{LintContext, module_record::ImportImportName, utils::get_node_name};
+
+/// How eagerly a `security` rule reports potential problems.
+///
+/// * `low` only reports patterns which are almost certainly dangerous.
+/// * `medium` (default) also reports patterns which are dangerous when fed untrusted input.
+/// * `high` also reports patterns which merely deserve a security review, at the cost of more
+///   false positives.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Sensitivity {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Sensitivity {
+    /// Read `sensitivity` from the first element of a rule's configuration.
+    pub fn from_configuration(value: &Value) -> Self {
+        value
+            .get(0)
+            .and_then(|config| config.get("sensitivity"))
+            .and_then(Value::as_str)
+            .map(Self::from)
+            .unwrap_or_default()
+    }
+}
+
+impl From<&str> for Sensitivity {
+    fn from(raw: &str) -> Self {
+        match raw {
+            "low" => Self::Low,
+            "high" => Self::High,
+            _ => Self::Medium,
+        }
+    }
+}
+
+/// Whether `expr` evaluates to a string which is fully known statically.
+///
+/// e.g. `'a'`, `` `a` ``, `'a' + 'b'`, or `PREFIX + 'b'` with `const PREFIX = 'a'`. See
+/// [`LintContext::constant_value`].
+pub fn is_static_string<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    matches!(ctx.constant_value(expr), Some(ConstantValue::String(_)))
+}
+
+/// Same as [`is_static_string`], for call arguments.
+pub fn is_static_string_argument<'a>(arg: &Argument<'a>, ctx: &LintContext<'a>) -> bool {
+    arg.as_expression().is_some_and(|expr| is_static_string(expr, ctx))
+}
+
+/// A local binding which refers to a module, or to an export of a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleImport<'s> {
+    /// Module specifier, with any `node:` prefix removed.
+    pub module: &'s str,
+    /// Name of the imported export.
+    /// `None` for namespace and default imports, and for the whole result of `require(...)`.
+    pub imported: Option<&'s str>,
+}
+
+/// Resolve where the binding `ident` refers to was imported from.
+///
+/// Handles:
+/// * `import fs from 'fs'`, `import * as fs from 'fs'`, `import { readFile } from 'fs'`
+/// * `const fs = require('fs')`, `const { readFile } = require('fs')`
+pub fn resolve_module_import<'a, 'c>(
+    ident: &IdentifierReference<'a>,
+    ctx: &'c LintContext<'a>,
+) -> Option<ModuleImport<'c>> {
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+
+    match declaration.kind() {
+        AstKind::ImportSpecifier(_)
+        | AstKind::ImportDefaultSpecifier(_)
+        | AstKind::ImportNamespaceSpecifier(_) => {
+            let entry = ctx
+                .module_record()
+                .import_entries
+                .iter()
+                .find(|entry| entry.local_name.name() == ident.name.as_str())?;
+            let imported = match &entry.import_name {
+                ImportImportName::Name(name) => Some(name.name()),
+                ImportImportName::NamespaceObject | ImportImportName::Default(_) => None,
+            };
+            Some(ModuleImport { module: strip_node_prefix(entry.module_request.name()), imported })
+        }
+        AstKind::VariableDeclarator(declarator) => {
+            let Some(Expression::CallExpression(call)) =
+                declarator.init.as_ref().map(Expression::without_parentheses)
+            else {
+                return None;
+            };
+            if !call.is_require_call() {
+                return None;
+            }
+            let Some(Argument::StringLiteral(source)) = call.arguments.first() else {
+                return None;
+            };
+            let module = strip_node_prefix(source.value.as_str());
+
+            match &declarator.id.kind {
+                BindingPatternKind::BindingIdentifier(_) => {
+                    Some(ModuleImport { module, imported: None })
+                }
+                BindingPatternKind::ObjectPattern(pattern) => {
+                    let property = pattern.properties.iter().find(|property| {
+                        property
+                            .value
+                            .get_binding_identifier()
+                            .is_some_and(|id| id.symbol_id() == symbol_id)
+                    })?;
+                    // Numeric and regex keys are not names of exports we're interested in
+                    let Some(Cow::Borrowed(imported)) = property.key.static_name() else {
+                        return None;
+                    };
+                    Some(ModuleImport { module, imported: Some(imported) })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn strip_node_prefix(module: &str) -> &str {
+    module.strip_prefix("node:").unwrap_or(module)
+}
+
+/// Configuration of the `no-unsanitized-*` rules.
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnsanitizedConfig {
+    /// Template tags and functions which escape or sanitize HTML.
+    pub escape: HtmlEscape,
+}
+
+impl NoUnsanitizedConfig {
+    /// Read the configuration from the first element of a rule's configuration.
+    pub fn from_configuration(value: &Value) -> Self {
+        value
+            .get(0)
+            .and_then(|config| serde_json::from_value(config.clone()).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Template tags and functions whose results are safe to use as HTML.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HtmlEscape {
+    /// Names of template tags which escape the interpolated values, e.g. `Sanitizer.escapeHTML`.
+    pub tagged_templates: Vec<String>,
+    /// Names of functions which escape or sanitize their argument, e.g. `DOMPurify.sanitize`.
+    pub methods: Vec<String>,
+}
+
+impl Default for HtmlEscape {
+    fn default() -> Self {
+        Self {
+            tagged_templates: vec!["Sanitizer.escapeHTML".to_string(), "escapeHTML".to_string()],
+            methods: vec!["Sanitizer.unwrapSafeHTML".to_string(), "unwrapSafeHTML".to_string()],
+        }
+    }
+}
+
+/// How many references to other variables are followed by [`is_safe_html`].
+const MAX_HTML_DEPTH: u8 = 16;
+
+/// Whether `expr` is safe to use as HTML, i.e. it can't contain markup from user input.
+///
+/// Safe values are values known statically (see [`LintContext::constant_value`]), values which
+/// can't be strings (e.g. `a - b`, `typeof a`), results of the escaping functions and template
+/// tags of `escape`, and strings built from safe values only, e.g.
+/// `` `<b>${escapeHTML(name)}</b>` ``.
+///
+/// Variables which are never reassigned are followed to their initial value, so
+/// `const html = `<b>${escapeHTML(name)}</b>`; el.innerHTML = html;` is safe too.
+pub fn is_safe_html<'a>(expr: &Expression<'a>, escape: &HtmlEscape, ctx: &LintContext<'a>) -> bool {
+    is_safe_html_impl(expr, escape, ctx, MAX_HTML_DEPTH)
+}
+
+fn is_safe_html_impl<'a>(
+    expr: &Expression<'a>,
+    escape: &HtmlEscape,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> bool {
+    if ctx.constant_value(expr).is_some() {
+        return true;
+    }
+    let is_safe = |expr: &Expression<'a>| is_safe_html_impl(expr, escape, ctx, depth);
+    match expr.get_inner_expression() {
+        Expression::TemplateLiteral(template) => template.expressions.iter().all(is_safe),
+        Expression::BinaryExpression(binary) => {
+            binary.operator != BinaryOperator::Addition
+                || (is_safe(&binary.left) && is_safe(&binary.right))
+        }
+        Expression::LogicalExpression(logical) => is_safe(&logical.left) && is_safe(&logical.right),
+        Expression::ConditionalExpression(conditional) => {
+            is_safe(&conditional.consequent) && is_safe(&conditional.alternate)
+        }
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.last().is_some_and(is_safe)
+        }
+        Expression::UnaryExpression(_) | Expression::UpdateExpression(_) => true,
+        Expression::CallExpression(call) => {
+            let name = get_node_name(&call.callee);
+            escape.methods.iter().any(|method| *method == name)
+        }
+        Expression::TaggedTemplateExpression(tagged) => {
+            let name = get_node_name(&tagged.tag);
+            escape.tagged_templates.iter().any(|tag| *tag == name)
+        }
+        Expression::Identifier(ident) => {
+            let Some(depth) = depth.checked_sub(1) else {
+                return false;
+            };
+            let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            else {
+                return false;
+            };
+            if ctx.scoping().symbol_is_mutated(symbol_id) {
+                return false;
+            }
+            let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+            let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+                return false;
+            };
+            matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+                && declarator
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| is_safe_html_impl(init, escape, ctx, depth))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::AllocatorPool;
+use oxc_diagnostics::Error;
+
+use crate::{
+    ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, FileSystem, FixKind,
+    LINTABLE_EXTENSIONS, LintOptions, LintService, LintServiceOptions, Linter, MemoryFileSystem,
+    Oxlintrc,
+};
+
+/// Lints files which only exist in memory, such as the output of a code generator before it is
+/// written to disk, with configurations which only exist in memory.
+///
+/// The files are linted together like the files of a project on disk: cross-module rules see the
+/// imports between them, the `overrides` of each configuration match paths relative to the
+/// directory of that configuration, and nested configurations apply to the files in their
+/// directory, as if they were `.oxlintrc.json` files in those directories. `extends` in the
+/// configurations is resolved among the virtual files.
+///
+/// ```
+/// use oxc_linter::{Oxlintrc, VirtualFiles};
+///
+/// let config = Oxlintrc::from_string(r#"{ "rules": { "no-debugger": "error" } }"#).unwrap();
+/// let results = VirtualFiles::new("/project", config)
+///     .with_file("src/index.js", "debugger;")
+///     .lint()
+///     .unwrap();
+/// assert_eq!(results[0].diagnostics.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct VirtualFiles {
+    cwd: PathBuf,
+    config: Oxlintrc,
+    nested_configs: Vec<(PathBuf, Oxlintrc)>,
+    files: Vec<(PathBuf, String)>,
+    fix: FixKind,
+}
+
+/// The result of linting one of the [`VirtualFiles`].
+#[derive(Debug)]
+pub struct VirtualFileResult {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Diagnostics reported in the file, with its source code attached
+    pub diagnostics: Vec<Error>,
+    /// Content of the file after applying fixes, `None` when nothing was fixed
+    pub fixed_source_text: Option<String>,
+}
+
+impl VirtualFiles {
+    /// Lint files relative to `cwd` with `config`, as if it was the configuration file in `cwd`.
+    pub fn new(cwd: impl Into<PathBuf>, config: Oxlintrc) -> Self {
+        Self { cwd: cwd.into(), config, nested_configs: vec![], files: vec![], fix: FixKind::None }
+    }
+
+    /// Add a file to lint. Relative paths are relative to the working directory.
+    ///
+    /// Files without a lintable extension, e.g. JSON files, are not linted, but can be imported
+    /// by the other files or extended by the configurations.
+    #[must_use]
+    pub fn with_file(mut self, path: impl AsRef<Path>, source_text: impl Into<String>) -> Self {
+        self.files.push((self.cwd.join(path), source_text.into()));
+        self
+    }
+
+    /// Add files to lint, see [`Self::with_file`].
+    #[must_use]
+    pub fn with_files<P, S>(mut self, files: impl IntoIterator<Item = (P, S)>) -> Self
+    where
+        P: AsRef<Path>,
+        S: Into<String>,
+    {
+        for (path, source_text) in files {
+            self = self.with_file(path, source_text);
+        }
+        self
+    }
+
+    /// Use `config` for the files in `directory` and its subdirectories, as if it was a nested
+    /// configuration file in `directory`. Relative paths are relative to the working directory.
+    #[must_use]
+    pub fn with_nested_config(mut self, directory: impl AsRef<Path>, config: Oxlintrc) -> Self {
+        self.nested_configs.push((self.cwd.join(directory), config));
+        self
+    }
+
+    /// Set the kind of fixes to apply, see [`VirtualFileResult::fixed_source_text`].
+    #[must_use]
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.fix = kind;
+        self
+    }
+
+    /// Lint the files, and return the results of the lintable files in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigBuilderError`] if one of the configurations is not valid.
+    pub fn lint(self) -> Result<Vec<VirtualFileResult>, ConfigBuilderError> {
+        let Self { cwd, config, nested_configs, files, fix } = self;
+
+        let file_system = Arc::new(
+            files
+                .iter()
+                .fold(MemoryFileSystem::default(), |fs, (path, text)| fs.with_file(path, text)),
+        );
+
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let base_config =
+            Self::build_config(&cwd, config, &*file_system, &mut external_plugin_store)?;
+        let mut configs = FxHashMap::default();
+        for (directory, config) in nested_configs {
+            let config =
+                Self::build_config(&directory, config, &*file_system, &mut external_plugin_store)?;
+            configs.insert(directory, config);
+        }
+
+        let use_cross_module = base_config.plugins().has_import()
+            || configs.values().any(|config| config.plugins().has_import());
+        let options = LintServiceOptions::new(cwd)
+            .with_cross_module(use_cross_module)
+            .with_module_graph(true)
+            .with_file_system(Arc::clone(&file_system) as Arc<dyn FileSystem>);
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(base_config, configs, external_plugin_store),
+            None,
+        )
+        .with_fix(fix);
+
+        let paths = files
+            .iter()
+            .filter(|(path, _)| {
+                path.extension()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|ext| LINTABLE_EXTENSIONS.contains(&ext))
+            })
+            .map(|(path, _)| Arc::from(path.as_os_str()))
+            .collect::<Vec<_>>();
+
+        let (tx_error, rx_error) = mpsc::channel();
+        let allocator_pool = AllocatorPool::new(rayon::current_num_threads());
+        LintService::new(linter, allocator_pool, options).with_paths(paths.clone()).run(&tx_error);
+        drop(tx_error);
+
+        let mut diagnostics = FxHashMap::<PathBuf, Vec<Error>>::default();
+        for (path, errors) in rx_error {
+            diagnostics.entry(path).or_default().extend(errors);
+        }
+
+        let results = files
+            .into_iter()
+            .filter(|(path, _)| paths.iter().any(|linted| **linted == *path.as_os_str()))
+            .map(|(path, source_text)| {
+                let fixed_source_text =
+                    file_system.file(&path).filter(|fixed| *fixed != source_text);
+                let diagnostics = diagnostics.remove(&path).unwrap_or_default();
+                VirtualFileResult { path, diagnostics, fixed_source_text }
+            })
+            .collect();
+        Ok(results)
+    }
+
+    /// Build `config` as if it was the configuration file in `directory`, so its overrides
+    /// match paths relative to `directory`.
+    fn build_config(
+        directory: &Path,
+        mut config: Oxlintrc,
+        file_system: &dyn FileSystem,
+        external_plugin_store: &mut ExternalPluginStore,
+    ) -> Result<crate::Config, ConfigBuilderError> {
+        if config.path.as_os_str().is_empty() {
+            config.path = directory.join(".oxlintrc.json");
+        }
+        let builder = ConfigStoreBuilder::from_oxlintrc_with_file_system(
+            false,
+            config,
+            file_system,
+            None,
+            external_plugin_store,
+        )?;
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{FixKind, Oxlintrc};
+
+    use super::VirtualFiles;
+
+    fn codes(results: &[super::VirtualFileResult], index: usize) -> Vec<String> {
+        results[index]
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code().map(|code| code.to_string()).unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn lint_virtual_files() {
+        let config = Oxlintrc::from_string(
+            r#"{
+                "extends": ["./configs/base.json"],
+                "rules": { "no-debugger": "error" },
+                "overrides": [{ "files": ["generated/**"], "rules": { "no-debugger": "off" } }]
+            }"#,
+        )
+        .unwrap();
+        let nested_config =
+            Oxlintrc::from_string(r#"{ "rules": { "no-empty": "error", "no-debugger": "off" } }"#)
+                .unwrap();
+
+        let results = VirtualFiles::new("/project", config)
+            .with_files([
+                ("src/index.js", "debugger; console.log(1);"),
+                ("generated/index.js", "debugger;"),
+                ("packages/a/index.js", "debugger; if (a) {}"),
+                ("configs/base.json", r#"{ "rules": { "no-console": "warn" } }"#),
+            ])
+            .with_nested_config("packages/a", nested_config)
+            .lint()
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].path.to_str(), Some("/project/src/index.js"));
+        let mut src_codes = codes(&results, 0);
+        src_codes.sort();
+        assert_eq!(src_codes, ["eslint(no-console)", "eslint(no-debugger)"]);
+        assert!(codes(&results, 1).is_empty());
+        assert_eq!(codes(&results, 2), ["eslint(no-empty)"]);
+        assert!(results.iter().all(|result| result.fixed_source_text.is_none()));
+    }
+
+    #[test]
+    fn fix_virtual_files() {
+        let config = Oxlintrc::from_string(r#"{ "rules": { "no-debugger": "error" } }"#).unwrap();
+        let results = VirtualFiles::new("/project", config)
+            .with_file("a.js", "debugger;\nexport const a = 1;\n")
+            .with_file("b.js", "export const b = 1;\n")
+            .with_fix(FixKind::SafeFix)
+            .lint()
+            .unwrap();
+
+        assert_eq!(results[0].fixed_source_text.as_deref(), Some("\nexport const a = 1;\n"));
+        assert!(results[0].diagnostics.is_empty());
+        assert_eq!(results[1].fixed_source_text, None);
+    }
+
+    #[test]
+    fn invalid_config() {
+        let config = Oxlintrc::from_string(r#"{ "extends": ["./missing.json"] }"#).unwrap();
+        assert!(VirtualFiles::new("/project", config).with_file("a.js", "").lint().is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! Custom rules compiled to WebAssembly.
+//!
+//! A WASM plugin is loaded by path from the `plugins` of a config, e.g. `"plugins": ["./my-rules.wasm"]`,
+//! and runs in an interpreter, so neither Node.js nor a native toolchain is needed to use it.
+//!
+//! # ABI
+//!
+//! Version 1 of the ABI. Strings are UTF-8 JSON, passed in the memory of the plugin.
+//! Functions returning a string return its pointer in the high 32 bits and its length in the low 32 bits.
+//!
+//! The module must not have imports, and must export:
+//!
+//! * `memory`.
+//! * `oxlint_abi_version() -> i32`: returns `1`.
+//! * `oxlint_plugin() -> i64`: returns the metadata of the plugin,
+//!   `{ "name": "my-plugin", "rules": ["my-rule", ...] }`.
+//! * `oxlint_alloc(len: i32) -> i32`: returns a pointer to `len` bytes, which the input of
+//!   `oxlint_lint` is written to. Called once before each call of `oxlint_lint`, so buffers of
+//!   previous calls can be reused.
+//! * `oxlint_lint(ptr: i32, len: i32) -> i64`: lints a file and returns its diagnostics.
+//!
+//! The input of `oxlint_lint` is
+//! `{ "filename": "...", "sourceText": "...", "rules": [0, ...], "program": { "type": "Program", ... } }`.
+//! `rules` are the indices of the enabled rules in the `rules` of the metadata, and `program` is the
+//! [ESTree](https://github.com/estree/estree) AST of the file, as produced by `oxc-parser`, with
+//! UTF-8 byte offsets in `start` and `end`.
+//!
+//! The output is an array of diagnostics, in the same shape as diagnostics of JS plugins:
+//! `[{ "ruleIndex": 0, "message": "...", "loc": { "start": 0, "end": 9 }, "fixes": [], "suggestions": [] }]`.
+//! `ruleIndex` is the index of the rule in the `rules` of the input. `fixes` are edits
+//! `{ "start": 0, "end": 9, "text": "" }` applied together, and `suggestions` are
+//! `{ "message": "...", "fixes": [...] }`. Both are optional.
+//!
+//! # Limits
+//!
+//! A plugin can't hang or exhaust the memory of oxlint: each call may execute at most
+//! [`FUEL_PER_CALL`] instructions, plus [`FUEL_PER_INPUT_BYTE`] for each byte of the input of
+//! `oxlint_lint`, and the memory of an instance may grow to at most [`MEMORY_LIMIT`] bytes.
+//! A plugin which exceeds them fails like a plugin which traps, and the file is reported as not
+//! linted by it.
+
+use std::{fmt, path::Path, sync::Mutex};
+
+use serde::Deserialize;
+use wasmi::{
+    Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+    TrapCode, TypedFunc, WasmParams, WasmResults,
+};
+
+use crate::LintFileResult;
+
+const ABI_VERSION: u32 = 1;
+
+/// Instructions each call of a plugin may execute, besides [`FUEL_PER_INPUT_BYTE`].
+pub const FUEL_PER_CALL: u64 = 1_000_000_000;
+
+/// Instructions `oxlint_lint` may execute for each byte of its input, to parse it.
+pub const FUEL_PER_INPUT_BYTE: u64 = 1_000;
+
+/// Maximum size of the memory of an instance, in bytes.
+pub const MEMORY_LIMIT: usize = 1 << 30;
+
+/// A plugin of rules compiled to WebAssembly. See the [module docs](self) for the ABI.
+pub struct WasmPlugin {
+    name: String,
+    rule_names: Vec<String>,
+    engine: Engine,
+    module: Module,
+    /// Instances which are not in use. Files are linted in parallel, each with its own instance.
+    instances: Mutex<Vec<WasmInstance>>,
+}
+
+#[derive(Deserialize)]
+struct PluginMetadata {
+    name: String,
+    rules: Vec<String>,
+}
+
+struct WasmInstance {
+    store: Store<StoreLimits>,
+    instance: Instance,
+    memory: Memory,
+    alloc: TypedFunc<u32, u32>,
+    lint: TypedFunc<(u32, u32), u64>,
+}
+
+impl WasmPlugin {
+    /// Load the plugin at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, is not a valid module, does not implement the
+    /// ABI, or returns invalid metadata.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|err| err.to_string())?;
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &bytes).map_err(|err| err.to_string())?;
+
+        let mut instance = WasmInstance::new(&engine, &module)?;
+        let abi_version = instance.call::<(), u32>("oxlint_abi_version", ())?;
+        if abi_version != ABI_VERSION {
+            return Err(format!(
+                "Unsupported ABI version {abi_version}, expected version {ABI_VERSION}"
+            ));
+        }
+        let metadata = instance.call::<(), u64>("oxlint_plugin", ())?;
+        let metadata: PluginMetadata = serde_json::from_slice(instance.read(metadata)?)
+            .map_err(|err| format!("Invalid plugin metadata: {err}"))?;
+
+        Ok(Self {
+            name: metadata.name,
+            rule_names: metadata.rules,
+            engine,
+            module,
+            instances: Mutex::new(vec![instance]),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rule_names(&self) -> &[String] {
+        &self.rule_names
+    }
+
+    /// Lint a file. `input` is the JSON input of `oxlint_lint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plugin traps or returns invalid diagnostics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the instances is poisoned.
+    pub fn lint(&self, input: &str) -> Result<Vec<LintFileResult>, String> {
+        let instance = self.instances.lock().unwrap().pop();
+        let mut instance = match instance {
+            Some(instance) => instance,
+            None => WasmInstance::new(&self.engine, &self.module)?,
+        };
+        let diagnostics = instance.lint(input)?;
+        // Instances which trapped may be in an invalid state, so are only reused after success
+        self.instances.lock().unwrap().push(instance);
+        Ok(diagnostics)
+    }
+}
+
+impl WasmInstance {
+    fn new(engine: &Engine, module: &Module) -> Result<Self, String> {
+        let limits = StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build();
+        let mut store = Store::new(engine, limits);
+        store.limiter(|limits| limits);
+        set_fuel(&mut store, FUEL_PER_CALL)?;
+        let instance = Linker::new(engine)
+            .instantiate_and_start(&mut store, module)
+            .map_err(|err| err.to_string())?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| "Missing export `memory`".to_string())?;
+        let alloc = get_typed_func(&store, instance, "oxlint_alloc")?;
+        let lint = get_typed_func(&store, instance, "oxlint_lint")?;
+        Ok(Self { store, instance, memory, alloc, lint })
+    }
+
+    fn call<Params: WasmParams, Results: WasmResults>(
+        &mut self,
+        name: &str,
+        params: Params,
+    ) -> Result<Results, String> {
+        let func = get_typed_func(&self.store, self.instance, name)?;
+        set_fuel(&mut self.store, FUEL_PER_CALL)?;
+        func.call(&mut self.store, params).map_err(|err| call_error(name, &err))
+    }
+
+    fn lint(&mut self, input: &str) -> Result<Vec<LintFileResult>, String> {
+        let len = u32::try_from(input.len()).map_err(|_| "File is too large".to_string())?;
+        set_fuel(&mut self.store, FUEL_PER_CALL)?;
+        let ptr = self
+            .alloc
+            .call(&mut self.store, len)
+            .map_err(|err| call_error("oxlint_alloc", &err))?;
+        self.memory
+            .write(&mut self.store, ptr as usize, input.as_bytes())
+            .map_err(|err| format!("`oxlint_alloc` returned an invalid pointer: {err}"))?;
+        let fuel = FUEL_PER_CALL.saturating_add(u64::from(len) * FUEL_PER_INPUT_BYTE);
+        set_fuel(&mut self.store, fuel)?;
+        let output = self
+            .lint
+            .call(&mut self.store, (ptr, len))
+            .map_err(|err| call_error("oxlint_lint", &err))?;
+        serde_json::from_slice(self.read(output)?)
+            .map_err(|err| format!("Invalid diagnostics: {err}"))
+    }
+
+    /// Read the string at the pointer and length returned by a function.
+    #[expect(clippy::cast_possible_truncation)]
+    fn read(&self, ptr_len: u64) -> Result<&[u8], String> {
+        let ptr = (ptr_len >> 32) as usize;
+        let len = ptr_len as u32 as usize;
+        self.memory
+            .data(&self.store)
+            .get(ptr..ptr + len)
+            .ok_or_else(|| "Returned string is out of bounds".to_string())
+    }
+}
+
+fn set_fuel(store: &mut Store<StoreLimits>, fuel: u64) -> Result<(), String> {
+    store.set_fuel(fuel).map_err(|err| err.to_string())
+}
+
+fn call_error(name: &str, err: &wasmi::Error) -> String {
+    if err.as_trap_code() == Some(TrapCode::OutOfFuel) {
+        format!("`{name}` exceeded its limit of executed instructions")
+    } else {
+        format!("`{name}` failed: {err}")
+    }
+}
+
+fn get_typed_func<Params: WasmParams, Results: WasmResults>(
+    store: &Store<StoreLimits>,
+    instance: Instance,
+    name: &str,
+) -> Result<TypedFunc<Params, Results>, String> {
+    instance.get_typed_func(store, name).map_err(|err| format!("Export `{name}`: {err}"))
+}
+
+impl fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("name", &self.name)
+            .field("rule_names", &self.rule_names)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::WasmPlugin;
+
+    #[test]
+    fn fuel_limit() {
+        let plugin = WasmPlugin::load(Path::new("fixtures/wasm_plugin/loop.wasm")).unwrap();
+        assert_eq!(plugin.rule_names(), ["loop"]);
+        let err = plugin.lint("{}").unwrap_err();
+        assert_eq!(err, "`oxlint_lint` exceeded its limit of executed instructions");
+    }
+}
@@ -1,4 +1,4 @@
-use oxc_linter::{RuleCategory, RuleMeta};
+use oxc_linter::{RuleCategory, RuleDeprecation, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -23,6 +23,16 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    eslint,
+    style,
+    deprecated = ["eslint/test-rule", "eslint/test-rule2"]
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -38,4 +48,14 @@ fn test_declare_oxc_lint() {
 
     // plugin name is passed to const
     assert_eq!(TestRule::PLUGIN, "eslint");
+
+    // Deprecation with the rules replacing it
+    assert_eq!(TestRule::DEPRECATED, None);
+    let deprecated = TestRule3::DEPRECATED.unwrap();
+    assert_eq!(deprecated.replaced_by, ["eslint/test-rule", "eslint/test-rule2"]);
+    assert_eq!(
+        deprecated.to_string(),
+        "deprecated, use `eslint/test-rule` or `eslint/test-rule2` instead"
+    );
+    assert_eq!(RuleDeprecation { replaced_by: &[] }.to_string(), "deprecated");
 }
@@ -78,7 +78,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
 
         use crate::{
             context::{ContextHost, LintContext},
-            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta},
+            rule::{Rule, RuleCategory, RuleDeprecation, RuleFixMeta, RuleMeta},
             utils::PossibleJestNode,
             AstNode
         };
@@ -116,6 +116,13 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// Whether this [`Rule`] is deprecated, and which rules replace it.
+            pub fn deprecated(&self) -> Option<RuleDeprecation> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::DEPRECATED),*
+                }
+            }
+
             #[cfg(feature = "ruledocs")]
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
@@ -123,7 +130,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
-            #[cfg(feature = "ruledocs")]
+            /// JSON Schema of the options of this [`Rule`], if it has any.
             pub fn schema(&self, generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::config_schema(generator).or_else(||#struct_names::schema(generator))),*
@@ -144,6 +151,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn validate_json(&self, value: &serde_json::Value) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
@@ -20,6 +20,8 @@ pub struct LintRuleMeta {
     /// This is the name of a struct/enum/whatever implementing
     /// schemars::JsonSchema
     config: Option<Ident>,
+    /// Rules replacing this rule, if it is deprecated
+    deprecated: Option<Vec<LitStr>>,
 }
 
 impl Parse for LintRuleMeta {
@@ -60,6 +62,7 @@ impl Parse for LintRuleMeta {
         // Do not provide a default value here so that it can be set there instead.
         let mut fix: Option<Ident> = None;
         let mut config: Option<Ident> = None;
+        let mut deprecated: Option<Vec<LitStr>> = None;
 
         // remaining options are `key = value` pairs, with the exception of
         // fix kinds. Those can be short-handed to just the fix kind
@@ -85,6 +88,24 @@ impl Parse for LintRuleMeta {
                     input.parse::<Token!(=)>()?;
                     config.replace(input.parse()?);
                 }
+                // `deprecated`, `deprecated = "plugin/replacement"` or
+                // `deprecated = ["plugin/replacement", ...]`
+                "deprecated" => {
+                    let mut replaced_by = vec![];
+                    if input.peek(Token!(=)) {
+                        input.parse::<Token!(=)>()?;
+                        if input.peek(syn::token::Bracket) {
+                            let content;
+                            syn::bracketed!(content in input);
+                            replaced_by.extend(
+                                content.parse_terminated(<LitStr as Parse>::parse, Token!(,))?,
+                            );
+                        } else {
+                            replaced_by.push(input.parse()?);
+                        }
+                    }
+                    deprecated.replace(replaced_by);
+                }
                 _ => {
                     if input.peek(Token!(=)) || fix.is_some() {
                         panic!("invalid key: {key}");
@@ -108,6 +129,7 @@ impl Parse for LintRuleMeta {
             documentation,
             used_in_test: false,
             config,
+            deprecated,
         })
     }
 }
@@ -126,6 +148,7 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         documentation,
         used_in_test,
         config,
+        deprecated,
     } = metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
@@ -148,10 +171,19 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let deprecated = deprecated.map(|replaced_by| {
+        quote! {
+            const DEPRECATED: Option<RuleDeprecation> =
+                Some(RuleDeprecation { replaced_by: &[#(#replaced_by),*] });
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
-        Some(quote! { use crate::{rule::{RuleCategory, RuleMeta, RuleFixMeta}, fixer::FixKind}; })
+        Some(quote! {
+            use crate::{rule::{RuleCategory, RuleDeprecation, RuleMeta, RuleFixMeta}, fixer::FixKind};
+        })
     };
 
     #[cfg(not(feature = "ruledocs"))]
@@ -164,24 +196,13 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
-    #[cfg(not(feature = "ruledocs"))]
-    let config_schema: Option<proc_macro2::TokenStream> = {
-        let _ = config;
-        None
-    };
-    #[cfg(feature = "ruledocs")]
-    let config_schema = match config {
-        Some(config) => Some(quote! {
+    let config_schema = config.map(|config| {
+        quote! {
             fn config_schema(generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
                 Some(generator.subschema_for::<#config>())
             }
-        }),
-        None => Some(quote! {
-            fn config_schema(_generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
-                None
-            }
-        }),
-    };
+        }
+    });
 
     let output = quote! {
         #import_statement
@@ -195,6 +216,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #deprecated
+
             #docs
 
             #config_schema
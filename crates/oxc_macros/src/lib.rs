@@ -47,6 +47,13 @@ mod declare_oxc_lint;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// ## Deprecation
+///
+/// Rules which should no longer be used are marked with `deprecated`, naming the
+/// rules replacing them if there are any: `deprecated = "plugin/rule-name"` or
+/// `deprecated = ["plugin/rule-name", "plugin/other-rule-name"]`.
+///
 /// # Example
 ///
 /// ```
@@ -0,0 +1,58 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{AstKind, ast::Program};
+use oxc_semantic::{AstNodes, Scoping, SymbolId};
+use oxc_span::GetSpan;
+
+/// Text of the comment which keeps the statement after it, e.g.
+/// `/* oxc-keep */ function onLoad() {}`.
+pub const KEEP_ANNOTATION: &str = "oxc-keep";
+
+/// Start offsets of the statements annotated with `/* oxc-keep */`.
+///
+/// Annotated statements are not removed by the compressor, even if they are unused,
+/// and the names they declare are not mangled.
+pub fn collect_keep_annotations(program: &Program) -> FxHashSet<u32> {
+    program
+        .comments
+        .iter()
+        .filter(|comment| {
+            comment.content_span().source_text(program.source_text).trim() == KEEP_ANNOTATION
+        })
+        .map(|comment| comment.attached_to)
+        .collect()
+}
+
+/// Symbols declared by statements annotated with `/* oxc-keep */`.
+pub fn collect_kept_symbols(
+    program: &Program,
+    scoping: &Scoping,
+    ast_nodes: &AstNodes,
+) -> FxHashSet<SymbolId> {
+    let annotations = collect_keep_annotations(program);
+    if annotations.is_empty() {
+        return FxHashSet::default();
+    }
+    scoping
+        .symbol_ids()
+        .filter(|symbol_id| {
+            let node_id = scoping.symbol_declaration(*symbol_id);
+            // Walk up from the declaration to the statement which declares it,
+            // e.g. `x` in `export const { x } = obj`.
+            std::iter::once(ast_nodes.get_node(node_id))
+                .chain(ast_nodes.ancestors(node_id))
+                .take_while(|node| {
+                    matches!(
+                        node.kind(),
+                        AstKind::VariableDeclarator(_)
+                            | AstKind::VariableDeclaration(_)
+                            | AstKind::Function(_)
+                            | AstKind::Class(_)
+                            | AstKind::ExportNamedDeclaration(_)
+                            | AstKind::ExportDefaultDeclaration(_)
+                    )
+                })
+                .any(|node| annotations.contains(&node.kind().span().start))
+        })
+        .collect()
+}
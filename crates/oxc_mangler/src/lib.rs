@@ -2,6 +2,7 @@ use std::iter::{self, repeat_with};
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
+use keep_annotation::collect_kept_symbols;
 use keep_names::collect_name_symbols;
 use rustc_hash::FxHashSet;
 
@@ -14,9 +15,13 @@ use oxc_semantic::{AstNodes, Scoping, Semantic, SemanticBuilder, SymbolId};
 use oxc_span::Atom;
 
 pub(crate) mod base54;
+mod keep_annotation;
 mod keep_names;
+mod name_frequencies;
 
+pub use keep_annotation::{KEEP_ANNOTATION, collect_keep_annotations};
 pub use keep_names::MangleOptionsKeepNames;
+pub use name_frequencies::NameFrequencies;
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct MangleOptions {
@@ -181,6 +186,8 @@ pub struct Mangler<'t> {
     /// It can be cleared after mangling is done, to free up memory for subsequent
     /// files or other operations.
     temp_allocator: TempAllocator<'t>,
+    /// Identifier frequencies across multiple files, used instead of the frequencies in this file.
+    name_frequencies: Option<&'t NameFrequencies>,
 }
 
 impl Default for Mangler<'_> {
@@ -188,6 +195,7 @@ impl Default for Mangler<'_> {
         Self {
             options: MangleOptions::default(),
             temp_allocator: TempAllocator::Owned(Allocator::default()),
+            name_frequencies: None,
         }
     }
 }
@@ -246,6 +254,7 @@ impl<'t> Mangler<'t> {
         Self {
             options: MangleOptions::default(),
             temp_allocator: TempAllocator::Borrowed(temp_allocator),
+            name_frequencies: None,
         }
     }
 
@@ -255,6 +264,17 @@ impl<'t> Mangler<'t> {
         self
     }
 
+    /// Rank slots by how often the original names of their symbols are used across all files
+    /// in `name_frequencies`, instead of how often the symbols are referenced in this file.
+    ///
+    /// See [`NameFrequencies`] for how this reduces the gzip size of bundles which are mangled
+    /// file by file.
+    #[must_use]
+    pub fn with_name_frequencies(mut self, name_frequencies: &'t NameFrequencies) -> Self {
+        self.name_frequencies = Some(name_frequencies);
+        self
+    }
+
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
@@ -296,8 +316,13 @@ impl<'t> Mangler<'t> {
         } else {
             Default::default()
         };
-        let (keep_name_names, keep_name_symbols) =
+        let (mut keep_name_names, mut keep_name_symbols) =
             Mangler::collect_keep_name_symbols(self.options.keep_names, scoping, ast_nodes);
+        // Names declared by statements annotated with `/* oxc-keep */` are not mangled.
+        for symbol_id in collect_kept_symbols(program, scoping, ast_nodes) {
+            keep_name_names.insert(scoping.symbol_name(symbol_id));
+            keep_name_symbols.insert(symbol_id);
+        }
 
         let temp_allocator = self.temp_allocator.as_ref();
 
@@ -498,9 +523,14 @@ impl<'t> Mangler<'t> {
             if keep_name_symbols.contains(&symbol_id) {
                 continue;
             }
+            let local_frequency = scoping.get_resolved_reference_ids(symbol_id).len();
+            // Names missing from the global statistics still count with their local frequency.
+            let frequency = self.name_frequencies.map_or(local_frequency, |name_frequencies| {
+                name_frequencies.get(scoping.symbol_name(symbol_id)).max(local_frequency)
+            });
             let index = slot;
             frequencies[index].slot = slot;
-            frequencies[index].frequency += scoping.get_resolved_reference_ids(symbol_id).len();
+            frequencies[index].frequency += frequency;
             frequencies[index].symbol_ids.push(symbol_id);
         }
         frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
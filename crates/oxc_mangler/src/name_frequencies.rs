@@ -0,0 +1,100 @@
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::Program;
+use oxc_semantic::{Scoping, SemanticBuilder};
+use oxc_span::CompactStr;
+
+/// How often each identifier is used across multiple files, e.g. all modules of a bundle.
+///
+/// The mangler assigns the shortest names to the most frequent slots of a single file.
+/// When files are mangled separately but shipped together, passing the frequencies of the
+/// whole bundle to [`Mangler::with_name_frequencies`](crate::Mangler::with_name_frequencies)
+/// ranks slots by how common their original names are across all files instead.
+/// Names which are common across files then get the same short identifiers in every file,
+/// which makes the bundle more compressible by gzip.
+///
+/// ## Example
+///
+/// ```rust
+/// use oxc_allocator::Allocator;
+/// use oxc_mangler::{Mangler, NameFrequencies};
+/// use oxc_parser::Parser;
+/// use oxc_span::SourceType;
+///
+/// let allocator = Allocator::default();
+/// let programs = ["function f(options) { return options }", "function g(options, i) { i }"]
+///     .map(|source| Parser::new(&allocator, source, SourceType::mjs()).parse().program);
+///
+/// let mut frequencies = NameFrequencies::new();
+/// for program in &programs {
+///     frequencies.add_program(program);
+/// }
+/// for program in &programs {
+///     let scoping = Mangler::new().with_name_frequencies(&frequencies).build(program);
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct NameFrequencies {
+    counts: FxHashMap<CompactStr, usize>,
+}
+
+impl NameFrequencies {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count the declarations and references of the symbols of `program`.
+    pub fn add_program(&mut self, program: &Program<'_>) {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        self.add_scoping(semantic.scoping());
+    }
+
+    /// Count the declarations and references of the symbols of `scoping`,
+    /// when the semantic analysis of a file is already available.
+    pub fn add_scoping(&mut self, scoping: &Scoping) {
+        for symbol_id in scoping.symbol_ids() {
+            let count = 1 + scoping.get_resolved_reference_ids(symbol_id).len();
+            self.add(scoping.symbol_name(symbol_id), count);
+        }
+    }
+
+    /// Add `count` uses of `name`, e.g. from statistics collected by another tool.
+    pub fn add(&mut self, name: &str, count: usize) {
+        if let Some(total) = self.counts.get_mut(name) {
+            *total += count;
+        } else {
+            self.counts.insert(CompactStr::new(name), count);
+        }
+    }
+
+    /// Add the counts of `other`, to combine frequencies collected in parallel.
+    pub fn merge(&mut self, other: &Self) {
+        for (name, count) in &other.counts {
+            self.add(name, *count);
+        }
+    }
+
+    /// How often `name` is used across all files.
+    pub fn get(&self, name: &str) -> usize {
+        self.counts.get(name).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NameFrequencies;
+
+    #[test]
+    fn merge() {
+        let mut a = NameFrequencies::new();
+        a.add("options", 2);
+        a.add("i", 1);
+        let mut b = NameFrequencies::new();
+        b.add("options", 3);
+        a.merge(&b);
+        assert_eq!(a.get("options"), 5);
+        assert_eq!(a.get("i"), 1);
+        assert_eq!(a.get("missing"), 0);
+    }
+}
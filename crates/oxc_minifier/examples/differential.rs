@@ -0,0 +1,82 @@
+#![expect(clippy::print_stdout)]
+//! # Minifier Differential Testing Example
+//!
+//! Runs programs before and after minification in the embedded Boa engine, or in Node.js with
+//! `--node`, and reports any difference in behavior, reduced to a minimal reproduction.
+//!
+//! ## Usage
+//!
+//! Test generated programs:
+//! ```bash
+//! cargo run -p oxc_minifier --features differential --example differential -- --seed 1 --iterations 100
+//! ```
+//!
+//! Test your own scripts:
+//! ```bash
+//! cargo run -p oxc_minifier --features differential --example differential -- a.js b.js
+//! ```
+//!
+//! Run programs in Node.js:
+//! ```bash
+//! cargo run -p oxc_minifier --features differential --example differential -- --node a.js
+//! ```
+
+use std::path::PathBuf;
+
+use pico_args::Arguments;
+
+use oxc_minifier::{
+    CompressOptions, MinifierOptions,
+    differential::{BoaEngine, DifferentialTester, Divergence, Engine, NodeEngine},
+};
+
+fn main() -> std::io::Result<()> {
+    let mut args = Arguments::from_env();
+
+    let node = args.contains("--node");
+    let seed = args.opt_value_from_str("--seed").unwrap().unwrap_or(0);
+    let iterations = args.opt_value_from_str("--iterations").unwrap().unwrap_or(100);
+    let paths = args.finish().into_iter().map(PathBuf::from).collect::<Vec<_>>();
+
+    let divergence = if node {
+        test(NodeEngine::default(), seed, iterations, &paths)?
+    } else {
+        test(BoaEngine::default(), seed, iterations, &paths)?
+    };
+
+    match divergence {
+        Some(divergence) => {
+            println!("{divergence}");
+            std::process::exit(1);
+        }
+        None => println!("No divergence found."),
+    }
+
+    Ok(())
+}
+
+fn test<E: Engine>(
+    engine: E,
+    seed: u64,
+    iterations: usize,
+    paths: &[PathBuf],
+) -> std::io::Result<Option<Divergence>> {
+    let options = MinifierOptions {
+        mangle: Some(oxc_minifier::MangleOptions::default()),
+        compress: Some(CompressOptions::smallest()),
+    };
+    let tester = DifferentialTester::new(engine, options);
+
+    if paths.is_empty() {
+        return Ok(tester.fuzz(seed, iterations));
+    }
+    for path in paths {
+        let source_text = std::fs::read_to_string(path)?;
+        let divergence = tester.check_and_reduce(&source_text);
+        if divergence.is_some() {
+            println!("{}", path.display());
+            return Ok(divergence);
+        }
+    }
+    Ok(None)
+}
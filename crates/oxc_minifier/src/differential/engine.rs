@@ -0,0 +1,227 @@
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use boa_engine::{Context, JsNativeErrorKind, JsResult, JsString, JsValue, Source, js_string};
+
+/// Result of running a program in an [`Engine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Execution {
+    /// The program ran to completion, or threw.
+    /// Contains everything observable about the run: logged values and the name of any uncaught error.
+    Completed(String),
+    /// The program did not finish in time, or ran into a limit of the engine.
+    TimedOut,
+    /// The engine itself could not be run.
+    Failed(String),
+}
+
+/// A JavaScript engine to run original and minified programs with.
+pub trait Engine {
+    /// Run `source_text` as a script, and capture its observable behavior.
+    fn run(&self, source_text: &str) -> Execution;
+}
+
+/// Harness which returns functions to run a program, and to read its observable behavior as JSON.
+/// Same as [`NODE_HARNESS`], without Node.js APIs.
+const BOA_HARNESS: &str = r"
+(() => {
+  const output = [];
+  const format = (value, depth) => {
+    switch (typeof value) {
+      case 'string': return depth === 0 ? value : JSON.stringify(value);
+      case 'function': return '[Function]';
+      case 'bigint': return value + 'n';
+      case 'symbol': return value.toString();
+      case 'object':
+        if (value === null) return 'null';
+        if (value instanceof Error) return value.name;
+        if (depth > 2) return Array.isArray(value) ? '[Array]' : '[Object]';
+        if (Array.isArray(value)) return '[ ' + value.map((item) => format(item, depth + 1)).join(', ') + ' ]';
+        return '{ ' + Object.keys(value).map((key) => key + ': ' + format(value[key], depth + 1)).join(', ') + ' }';
+      default: return Object.is(value, -0) ? '-0' : String(value);
+    }
+  };
+  const log = (...args) => { output.push(args.map((arg) => format(arg, 0)).join(' ')); };
+  globalThis.console = { log, info: log, warn: log, error: log, debug: log };
+  let seed = 1;
+  Math.random = () => (seed = (seed * 16807) % 2147483647) / 2147483647;
+  Date.now = () => 0;
+  const fail = (error) => { output.push('Uncaught ' + (error instanceof Error ? error.name : typeof error)); };
+  return {
+    run: (source) => { try { (0, eval)(source); } catch (error) { fail(error); } },
+    output: () => JSON.stringify(output),
+  };
+})()
+";
+
+/// [`Engine`] which runs programs in [Boa](https://boajs.dev), a JavaScript engine written in Rust
+/// which is embedded in the process, so nothing needs to be installed to run programs.
+///
+/// Each program runs in a new context. Instead of a timeout, which would make results depend on
+/// the speed of the machine, loops and recursion are limited.
+#[derive(Debug, Clone)]
+pub struct BoaEngine {
+    loop_iteration_limit: u64,
+    recursion_limit: usize,
+}
+
+impl Default for BoaEngine {
+    fn default() -> Self {
+        Self { loop_iteration_limit: 1_000_000, recursion_limit: 400 }
+    }
+}
+
+impl BoaEngine {
+    /// Maximum number of iterations of a loop. Default: 1,000,000.
+    #[must_use]
+    pub fn with_loop_iteration_limit(mut self, limit: u64) -> Self {
+        self.loop_iteration_limit = limit;
+        self
+    }
+
+    /// Maximum depth of function calls. Default: 400.
+    #[must_use]
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
+    fn run_in(context: &mut Context, source_text: &str) -> JsResult<String> {
+        let harness = context.eval(Source::from_bytes(BOA_HARNESS))?;
+        let harness = harness.as_object().expect("harness returns an object");
+        let call = |name: JsString, args: &[JsValue], context: &mut Context| -> JsResult<JsValue> {
+            let function = harness.get(name, context)?;
+            let function = function.as_callable().expect("harness returns functions");
+            function.call(&JsValue::undefined(), args, context)
+        };
+        call(js_string!("run"), &[JsString::from(source_text).into()], context)?;
+        context.run_jobs();
+        let output = call(js_string!("output"), &[], context)?;
+        Ok(output.to_string(context)?.to_std_string_escaped())
+    }
+}
+
+impl Engine for BoaEngine {
+    fn run(&self, source_text: &str) -> Execution {
+        let mut context = Context::default();
+        context.runtime_limits_mut().set_loop_iteration_limit(self.loop_iteration_limit);
+        context.runtime_limits_mut().set_recursion_limit(self.recursion_limit);
+        match Self::run_in(&mut context, source_text) {
+            Ok(output) => Execution::Completed(output),
+            // Limits can't be caught by the program, so they end up here
+            Err(err)
+                if err
+                    .as_native()
+                    .is_some_and(|err| matches!(err.kind, JsNativeErrorKind::RuntimeLimit)) =>
+            {
+                Execution::TimedOut
+            }
+            Err(err) => Execution::Failed(err.to_string()),
+        }
+    }
+}
+
+/// Harness which evaluates the script read from stdin, and prints its observable behavior as JSON.
+///
+/// Sources of non-determinism (`Math.random`, `Date.now`) are replaced with deterministic versions,
+/// and only the names of errors are recorded, as messages may contain (mangled) identifier names.
+const NODE_HARNESS: &str = r"
+const { inspect } = require('util');
+const output = [];
+const format = (value) => typeof value === 'string' ? value : typeof value === 'function' ? '[Function]' : inspect(value);
+const log = (...args) => { output.push(args.map(format).join(' ')); };
+console.log = console.info = console.warn = console.error = console.debug = log;
+let seed = 1;
+Math.random = () => (seed = (seed * 16807) % 2147483647) / 2147483647;
+Date.now = () => 0;
+const fail = (error) => { output.push('Uncaught ' + (error instanceof Error ? error.name : typeof error)); };
+process.on('uncaughtException', fail);
+process.on('exit', () => { process.stdout.write(JSON.stringify(output)); });
+const source = require('fs').readFileSync(0, 'utf8');
+try { (0, eval)(source); } catch (error) { fail(error); }
+";
+
+/// [`Engine`] which runs programs in a `node` child process, to check programs in the engine they
+/// are deployed to. Slower than [`BoaEngine`], and requires Node.js to be installed.
+#[derive(Debug, Clone)]
+pub struct NodeEngine {
+    program: PathBuf,
+    timeout: Duration,
+}
+
+impl Default for NodeEngine {
+    fn default() -> Self {
+        Self { program: PathBuf::from("node"), timeout: Duration::from_secs(5) }
+    }
+}
+
+impl NodeEngine {
+    /// Use the `node` executable at `program`, instead of the one in `PATH`.
+    #[must_use]
+    pub fn with_program(mut self, program: impl Into<PathBuf>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Maximum time a program may run for. Default: 5 seconds.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Engine for NodeEngine {
+    fn run(&self, source_text: &str) -> Execution {
+        let child = Command::new(&self.program)
+            .arg("-e")
+            .arg(NODE_HARNESS)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(err) => return Execution::Failed(err.to_string()),
+        };
+
+        let mut stdin = child.stdin.take().unwrap();
+        if let Err(err) = stdin.write_all(source_text.as_bytes()) {
+            let _ = child.kill();
+            return Execution::Failed(err.to_string());
+        }
+        drop(stdin);
+
+        // Read output on another thread, so the child can't block on a full pipe
+        let mut stdout = child.stdout.take().unwrap();
+        let reader = thread::spawn(move || {
+            let mut output = String::new();
+            stdout.read_to_string(&mut output).map(|_| output)
+        });
+
+        let start = Instant::now();
+        loop {
+            match child.try_wait() {
+                Ok(Some(_)) => break,
+                Ok(None) if start.elapsed() > self.timeout => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Execution::TimedOut;
+                }
+                Ok(None) => thread::sleep(Duration::from_millis(1)),
+                Err(err) => return Execution::Failed(err.to_string()),
+            }
+        }
+
+        match reader.join() {
+            Ok(Ok(output)) => Execution::Completed(output),
+            Ok(Err(err)) => Execution::Failed(err.to_string()),
+            Err(_) => Execution::Failed("Failed to read output".to_string()),
+        }
+    }
+}
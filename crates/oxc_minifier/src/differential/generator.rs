@@ -0,0 +1,263 @@
+use std::{fmt::Write, iter};
+
+const MAX_DEPTH: usize = 3;
+
+/// Generates random, deterministic and terminating JavaScript programs, which log their state.
+///
+/// Programs only use language features whose behavior does not depend on the environment,
+/// so any difference in output between a program and its minified version is a minifier bug.
+pub struct ProgramGenerator {
+    rng: Rng,
+    /// Variables in scope, and whether they can be assigned to.
+    variables: Vec<(String, bool)>,
+    /// Functions declared so far, and their number of parameters.
+    functions: Vec<(String, usize)>,
+    next_id: usize,
+}
+
+impl ProgramGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Rng::new(seed), variables: vec![], functions: vec![], next_id: 0 }
+    }
+
+    /// Generate a new program.
+    pub fn generate(&mut self) -> String {
+        self.variables.clear();
+        self.functions.clear();
+        self.next_id = 0;
+
+        let mut out = String::new();
+        for _ in 0..3 + self.rng.below(12) {
+            self.statement(&mut out, 0, true);
+        }
+        for (name, _) in &self.variables {
+            writeln!(out, "console.log({name});").unwrap();
+        }
+        out
+    }
+
+    fn id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{prefix}{}", self.next_id)
+    }
+
+    fn statement(&mut self, out: &mut String, depth: usize, top_level: bool) {
+        let indent = "  ".repeat(depth);
+        let kind = if depth >= MAX_DEPTH { self.rng.below(3) } else { self.rng.below(10) };
+        match kind {
+            0 => {
+                let keyword = *self.rng.choose(&["var", "let", "const"]);
+                let init = self.expression(0);
+                let name = self.id("v");
+                writeln!(out, "{indent}{keyword} {name} = {init};").unwrap();
+                self.variables.push((name, keyword != "const"));
+            }
+            1 => {
+                let mutable =
+                    self.variables.iter().filter(|(_, mutable)| *mutable).collect::<Vec<_>>();
+                if mutable.is_empty() {
+                    writeln!(out, "{indent}console.log({});", self.expression(0)).unwrap();
+                } else {
+                    let name = self.rng.choose(&mutable).0.clone();
+                    let operator =
+                        *self.rng.choose(&["=", "+=", "-=", "*=", "|=", "&&=", "||=", "??="]);
+                    let value = self.expression(0);
+                    writeln!(out, "{indent}{name} {operator} {value};").unwrap();
+                }
+            }
+            2 => writeln!(out, "{indent}console.log({});", self.expression(0)).unwrap(),
+            3 => {
+                writeln!(out, "{indent}if ({}) {{", self.expression(0)).unwrap();
+                self.block(out, depth + 1);
+                if self.rng.chance(50) {
+                    writeln!(out, "{indent}}} else {{").unwrap();
+                    self.block(out, depth + 1);
+                }
+                writeln!(out, "{indent}}}").unwrap();
+            }
+            4 => {
+                let counter = self.id("i");
+                let count = self.rng.below(5);
+                writeln!(
+                    out,
+                    "{indent}for (let {counter} = 0; {counter} < {count}; {counter}++) {{"
+                )
+                .unwrap();
+                let scope = self.variables.len();
+                self.variables.push((counter, false));
+                self.block(out, depth + 1);
+                self.variables.truncate(scope);
+                writeln!(out, "{indent}}}").unwrap();
+            }
+            5 => {
+                writeln!(out, "{indent}switch ({}) {{", self.expression(0)).unwrap();
+                for _ in 0..=self.rng.below(3) {
+                    writeln!(out, "{indent}  case {}:", self.literal()).unwrap();
+                    self.block(out, depth + 2);
+                    if self.rng.chance(70) {
+                        writeln!(out, "{indent}    break;").unwrap();
+                    }
+                }
+                writeln!(out, "{indent}  default:").unwrap();
+                self.block(out, depth + 2);
+                writeln!(out, "{indent}}}").unwrap();
+            }
+            6 => {
+                writeln!(out, "{indent}try {{").unwrap();
+                self.block(out, depth + 1);
+                if self.rng.chance(50) {
+                    writeln!(out, "{indent}  throw {};", self.expression(0)).unwrap();
+                }
+                writeln!(out, "{indent}}} catch (e) {{").unwrap();
+                writeln!(out, "{indent}  console.log(typeof e);").unwrap();
+                writeln!(out, "{indent}}}").unwrap();
+            }
+            7 if top_level => self.function(out),
+            _ => {
+                let expression = self.expression(0);
+                writeln!(out, "{indent}{expression};").unwrap();
+            }
+        }
+    }
+
+    fn block(&mut self, out: &mut String, depth: usize) {
+        let scope = self.variables.len();
+        for _ in 0..=self.rng.below(3) {
+            self.statement(out, depth, false);
+        }
+        self.variables.truncate(scope);
+    }
+
+    /// Functions can only call previously declared functions, so they can't recurse.
+    fn function(&mut self, out: &mut String) {
+        let name = self.id("f");
+        let count = self.rng.below(3);
+        let params = iter::repeat_with(|| self.id("p")).take(count).collect::<Vec<_>>();
+        writeln!(out, "function {name}({}) {{", params.join(", ")).unwrap();
+        let scope = self.variables.len();
+        self.variables.extend(params.iter().map(|param| (param.clone(), true)));
+        for _ in 0..self.rng.below(4) {
+            self.statement(out, 1, false);
+        }
+        writeln!(out, "  return {};", self.expression(0)).unwrap();
+        self.variables.truncate(scope);
+        writeln!(out, "}}").unwrap();
+        self.functions.push((name, params.len()));
+    }
+
+    fn expression(&mut self, depth: usize) -> String {
+        let kind = if depth >= MAX_DEPTH { self.rng.below(2) } else { self.rng.below(11) };
+        match kind {
+            1 if !self.variables.is_empty() => self.rng.choose(&self.variables).0.clone(),
+            2 => {
+                let operator = *self.rng.choose(&[
+                    "+", "-", "*", "/", "%", "**", "==", "===", "!=", "!==", "<", "<=", ">", ">=",
+                    "&&", "||", "??", "&", "|", "^", "<<", ">>", ">>>",
+                ]);
+                let left = self.expression(depth + 1);
+                let right = self.expression(depth + 1);
+                format!("({left} {operator} {right})")
+            }
+            3 => {
+                let operator = *self.rng.choose(&["!", "-", "+", "~", "typeof ", "void "]);
+                format!("({operator}{})", self.expression(depth + 1))
+            }
+            4 => {
+                let test = self.expression(depth + 1);
+                let consequent = self.expression(depth + 1);
+                let alternate = self.expression(depth + 1);
+                format!("({test} ? {consequent} : {alternate})")
+            }
+            5 if !self.functions.is_empty() => {
+                let (name, params) = self.rng.choose(&self.functions).clone();
+                let args = iter::repeat_with(|| self.expression(depth + 1))
+                    .take(params)
+                    .collect::<Vec<_>>();
+                format!("{name}({})", args.join(", "))
+            }
+            6 => {
+                let count = self.rng.below(4);
+                let elements = iter::repeat_with(|| self.expression(depth + 1))
+                    .take(count)
+                    .collect::<Vec<_>>();
+                let elements = elements.join(", ");
+                match self.rng.below(3) {
+                    0 => format!("[{elements}].length"),
+                    1 => format!("[{elements}].join({})", self.literal()),
+                    _ => format!("[{elements}][{}]", self.rng.below(4)),
+                }
+            }
+            7 => {
+                let x = self.expression(depth + 1);
+                let y = self.expression(depth + 1);
+                let key = *self.rng.choose(&["x", "y", "z"]);
+                format!("({{ x: {x}, y: {y} }}).{key}")
+            }
+            8 => {
+                let a = self.expression(depth + 1);
+                let b = self.expression(depth + 1);
+                format!("`${{{a}}}-${{{b}}}`")
+            }
+            9 => {
+                let value = self.expression(depth + 1);
+                match self.rng.below(3) {
+                    0 => format!("String({value}).length"),
+                    1 => format!("String({value}).charAt(0)"),
+                    _ => format!("Number({value})"),
+                }
+            }
+            10 => {
+                let a = self.expression(depth + 1);
+                let b = self.expression(depth + 1);
+                format!("({a}, {b})")
+            }
+            _ => self.literal(),
+        }
+    }
+
+    fn literal(&mut self) -> String {
+        match self.rng.below(6) {
+            0 => self.rng.below(10).to_string(),
+            1 => (*self
+                .rng
+                .choose(&["(-1)", "0.5", "1e3", "NaN", "Infinity", "(-0)", "255", "0x10"]))
+            .to_string(),
+            2 => (*self.rng.choose(&["''", "'a'", "'0'", "'abc'", "' '", "'1'"])).to_string(),
+            3 => (*self.rng.choose(&["true", "false"])).to_string(),
+            4 => (*self.rng.choose(&["null", "undefined"])).to_string(),
+            _ => self.rng.below(100).to_string(),
+        }
+    }
+}
+
+/// xorshift64* pseudo-random number generator.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        #[expect(clippy::cast_possible_truncation)]
+        let n = (self.next() % n as u64) as usize;
+        n
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    fn choose<'t, T>(&mut self, items: &'t [T]) -> &'t T {
+        &items[self.below(items.len())]
+    }
+}
@@ -0,0 +1,199 @@
+//! Differential testing of the minifier.
+//!
+//! Runs a program and its minified version in a JavaScript [`Engine`], and reports a
+//! [`Divergence`] if they behave differently, reduced to a minimal reproduction.
+//!
+//! Programs run in [`BoaEngine`], which is embedded, or in Node.js with [`NodeEngine`].
+//!
+//! Programs can be generated by [`ProgramGenerator`] ([`DifferentialTester::fuzz`]),
+//! or come from your own codebase ([`DifferentialTester::check_and_reduce`]), which is useful
+//! to validate aggressive compression options before enabling them.
+//!
+//! Only enabled with the `differential` feature.
+
+mod engine;
+mod generator;
+mod reduce;
+
+use std::fmt;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+pub use self::{
+    engine::{BoaEngine, Engine, Execution, NodeEngine},
+    generator::ProgramGenerator,
+    reduce::reduce,
+};
+use crate::{Minifier, MinifierOptions};
+
+/// A program which behaves differently after minification.
+#[derive(Debug, Clone)]
+pub struct Divergence {
+    pub source_text: String,
+    pub minified: String,
+    pub expected: Execution,
+    pub actual: Execution,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Minified program behaves differently from the original.")?;
+        writeln!(f, "\nOriginal:\n{}", self.source_text)?;
+        writeln!(f, "\nMinified:\n{}", self.minified)?;
+        writeln!(f, "\nExpected: {:?}", self.expected)?;
+        write!(f, "Actual:   {:?}", self.actual)
+    }
+}
+
+pub struct DifferentialTester<E: Engine> {
+    engine: E,
+    options: MinifierOptions,
+    source_type: SourceType,
+}
+
+impl<E: Engine> DifferentialTester<E> {
+    pub fn new(engine: E, options: MinifierOptions) -> Self {
+        Self { engine, options, source_type: SourceType::cjs() }
+    }
+
+    /// Source type of programs. Programs are run as scripts, so this should not be a module.
+    #[must_use]
+    pub fn with_source_type(mut self, source_type: SourceType) -> Self {
+        self.source_type = source_type;
+        self
+    }
+
+    /// Minify `source_text`. Returns `None` if it fails to parse.
+    pub fn minify(&self, source_text: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, self.source_type).parse();
+        if ret.panicked || !ret.errors.is_empty() {
+            return None;
+        }
+        let mut program = ret.program;
+        let ret = Minifier::new(self.options.clone()).build(&allocator, &mut program);
+        let code = Codegen::new()
+            .with_options(CodegenOptions::minify())
+            .with_scoping(ret.scoping)
+            .build(&program)
+            .code;
+        Some(code)
+    }
+
+    /// Run `source_text` before and after minification, and compare the results.
+    ///
+    /// Returns `None` if they behave the same, or the result is inconclusive
+    /// (e.g. the program does not parse, or times out).
+    pub fn check(&self, source_text: &str) -> Option<Divergence> {
+        let minified = self.minify(source_text)?;
+        let expected = self.engine.run(source_text);
+        if !matches!(expected, Execution::Completed(_)) {
+            return None;
+        }
+        let actual = self.engine.run(&minified);
+        if !matches!(actual, Execution::Completed(_)) || actual == expected {
+            return None;
+        }
+        Some(Divergence { source_text: source_text.to_string(), minified, expected, actual })
+    }
+
+    /// Same as [`DifferentialTester::check`], but reduces a divergent program to a minimal
+    /// reproduction.
+    pub fn check_and_reduce(&self, source_text: &str) -> Option<Divergence> {
+        let divergence = self.check(source_text)?;
+        let reduced =
+            reduce(source_text, self.source_type, |candidate| self.check(candidate).is_some());
+        self.check(&reduced).or(Some(divergence))
+    }
+
+    /// Check `iterations` programs generated from `seed`, and return the first divergence found.
+    pub fn fuzz(&self, seed: u64, iterations: usize) -> Option<Divergence> {
+        let mut generator = ProgramGenerator::new(seed);
+        (0..iterations).find_map(|_| self.check_and_reduce(&generator.generate()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{BoaEngine, DifferentialTester, Engine, Execution, ProgramGenerator, reduce};
+    use crate::MinifierOptions;
+
+    /// Fake engine which "outputs" whether the program contains a marker.
+    struct ContainsEngine;
+
+    impl Engine for ContainsEngine {
+        fn run(&self, source_text: &str) -> Execution {
+            Execution::Completed(source_text.contains("marker").to_string())
+        }
+    }
+
+    #[test]
+    fn boa_engine() {
+        let run = |source_text: &str| BoaEngine::default().run(source_text);
+        assert_eq!(
+            run("console.log(1 + 1, 'a', [1, 'b'], { c: -0 }, () => {});"),
+            Execution::Completed(r#"["2 a [ 1, \"b\" ] { c: -0 } [Function]"]"#.to_string())
+        );
+        assert_eq!(
+            run(
+                "console.log(Math.random() === Math.random(), Date.now()); null.a; console.log(1);"
+            ),
+            Execution::Completed(r#"["false 0","Uncaught TypeError"]"#.to_string())
+        );
+        assert_eq!(run("throw 1;"), Execution::Completed(r#"["Uncaught number"]"#.to_string()));
+        assert_eq!(
+            run("Promise.resolve().then(() => console.log(1)); console.log(2);"),
+            Execution::Completed(r#"["2","1"]"#.to_string())
+        );
+        assert_eq!(run("try { while (true) {} } catch {}"), Execution::TimedOut);
+    }
+
+    #[test]
+    fn generator_is_deterministic() {
+        let first = ProgramGenerator::new(1).generate();
+        assert_eq!(first, ProgramGenerator::new(1).generate());
+        assert_ne!(first, ProgramGenerator::new(2).generate());
+    }
+
+    #[test]
+    fn generated_programs_parse() {
+        let mut generator = ProgramGenerator::new(0);
+        for _ in 0..100 {
+            let program = generator.generate();
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &program, SourceType::cjs()).parse();
+            assert!(ret.errors.is_empty(), "{program}");
+        }
+    }
+
+    #[test]
+    fn reduces_to_interesting_statement() {
+        let source = "let a = 1;\nif (a) {\n  foo();\n  marker();\n}\nlet b = 2;\nbar(a, b);\n";
+        let reduced = reduce(source, SourceType::cjs(), |candidate| candidate.contains("marker"));
+        assert!(reduced.contains("marker();"));
+        assert!(
+            ["let", "foo", "bar"].iter().all(|removed| !reduced.contains(removed)),
+            "{reduced}"
+        );
+    }
+
+    #[test]
+    fn check() {
+        let tester = DifferentialTester::new(ContainsEngine, MinifierOptions::default());
+        assert!(tester.check("console.log(1)").is_none());
+        // Dead code is removed by minification, so "output" differs
+        let divergence =
+            tester.check_and_reduce("if (false) {\n  marker();\n}\nconsole.log(1);\n").unwrap();
+        assert!(divergence.source_text.contains("marker"));
+        assert!(!divergence.source_text.contains("console"));
+        assert_eq!(divergence.expected, Execution::Completed("true".to_string()));
+        assert_eq!(divergence.actual, Execution::Completed("false".to_string()));
+    }
+}
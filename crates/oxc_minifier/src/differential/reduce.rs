@@ -0,0 +1,81 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Statement;
+use oxc_ast_visit::{Visit, walk};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Reduce `source_text` to a smaller program for which `is_interesting` still returns `true`.
+///
+/// Removes chunks of top-level statements first, then individual nested statements,
+/// until no statement can be removed.
+pub fn reduce(
+    source_text: &str,
+    source_type: SourceType,
+    mut is_interesting: impl FnMut(&str) -> bool,
+) -> String {
+    let mut current = source_text.to_string();
+
+    // Remove chunks of top-level statements, halving the chunk size down to single statements
+    'top_level: loop {
+        let spans = statement_spans(&current, source_type, true);
+        let mut chunk = spans.len().div_ceil(2);
+        while chunk > 0 {
+            for start in (0..spans.len()).step_by(chunk) {
+                let end = (start + chunk).min(spans.len()) - 1;
+                let candidate = remove(&current, Span::new(spans[start].start, spans[end].end));
+                if is_interesting(&candidate) {
+                    current = candidate;
+                    continue 'top_level;
+                }
+            }
+            chunk /= 2;
+        }
+        break;
+    }
+
+    // Remove nested statements one by one, last first
+    'nested: loop {
+        for span in statement_spans(&current, source_type, false).into_iter().rev() {
+            let candidate = remove(&current, span);
+            if is_interesting(&candidate) {
+                current = candidate;
+                continue 'nested;
+            }
+        }
+        break;
+    }
+
+    current
+}
+
+fn remove(source_text: &str, span: Span) -> String {
+    let mut result = source_text[..span.start as usize].to_string();
+    result.push_str(&source_text[span.end as usize..]);
+    result
+}
+
+fn statement_spans(source_text: &str, source_type: SourceType, top_level: bool) -> Vec<Span> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return vec![];
+    }
+    if top_level {
+        return ret.program.body.iter().map(GetSpan::span).collect();
+    }
+    let mut collector = StatementCollector::default();
+    collector.visit_program(&ret.program);
+    collector.spans
+}
+
+#[derive(Default)]
+struct StatementCollector {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for StatementCollector {
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        self.spans.push(it.span());
+        walk::walk_statement(self, it);
+    }
+}
@@ -4,6 +4,8 @@
 
 mod compressor;
 mod ctx;
+#[cfg(feature = "differential")]
+pub mod differential;
 mod keep_var;
 mod options;
 mod peephole;
@@ -1,5 +1,7 @@
 use oxc_syntax::es_target::ESTarget;
 
+pub use oxc_syntax::identifier::PropertyNameTarget;
+
 pub use oxc_ecmascript::side_effects::PropertyReadSideEffects;
 
 #[derive(Debug, Clone)]
@@ -14,6 +16,16 @@ pub struct CompressOptions {
     /// Default `ESTarget::ESNext`
     pub target: ESTarget,
 
+    /// Oldest EcmaScript edition which has to parse property names written without quotes.
+    ///
+    /// `foo['bar']` -> `foo.bar` and `{ 'bar': 1 }` -> `{ bar: 1 }` are only done when `bar` is safe
+    /// for this edition, e.g. `foo['default']` is kept for [`PropertyNameTarget::ES3`].
+    /// Pass the same value to `CodegenOptions::property_name_target` so that codegen quotes the
+    /// remaining property names, e.g. `foo.default` -> `foo["default"]`.
+    ///
+    /// Default [`PropertyNameTarget::ES2015`]
+    pub property_name_target: PropertyNameTarget,
+
     /// Remove `debugger;` statements.
     ///
     /// Default `true`
@@ -57,6 +69,7 @@ impl CompressOptions {
     pub fn smallest() -> Self {
         Self {
             target: ESTarget::ESNext,
+            property_name_target: PropertyNameTarget::ES2015,
             keep_names: CompressOptionsKeepNames::all_false(),
             drop_debugger: true,
             drop_console: false,
@@ -70,6 +83,7 @@ impl CompressOptions {
     pub fn safest() -> Self {
         Self {
             target: ESTarget::ESNext,
+            property_name_target: PropertyNameTarget::ES2015,
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
@@ -83,6 +97,7 @@ impl CompressOptions {
     pub fn dce() -> Self {
         Self {
             target: ESTarget::ESNext,
+            property_name_target: PropertyNameTarget::ES2015,
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
@@ -1,6 +1,5 @@
 use oxc_allocator::TakeIn;
 use oxc_ast::ast::*;
-use oxc_syntax::identifier::is_identifier_name;
 
 use crate::ctx::Ctx;
 
@@ -17,7 +16,7 @@ impl<'a> LatePeepholeOptimizations {
     pub fn convert_to_dotted_properties(expr: &mut MemberExpression<'a>, ctx: &mut Ctx<'a, '_>) {
         let MemberExpression::ComputedMemberExpression(e) = expr else { return };
         let Expression::StringLiteral(s) = &e.expression else { return };
-        if is_identifier_name(&s.value) {
+        if ctx.options().property_name_target.is_unquoted_property_name(&s.value) {
             let property = ctx.ast.identifier_name(s.span, s.value);
             *expr =
                 MemberExpression::StaticMemberExpression(ctx.ast.alloc_static_member_expression(
@@ -40,7 +39,10 @@ impl<'a> LatePeepholeOptimizations {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions, PropertyNameTarget,
+        tester::{default_options, test, test_options, test_same, test_same_options},
+    };
 
     #[test]
     fn test_computed_to_member_expression() {
@@ -271,4 +273,26 @@ mod test {
         test("x['-2147483648']", "x[-2147483648]");
         test_same("x['-2147483649']");
     }
+
+    #[test]
+    fn test_property_name_target() {
+        let es3 =
+            CompressOptions { property_name_target: PropertyNameTarget::ES3, ..default_options() };
+        test_options("a['p']", "a.p", &es3);
+        test_options("a['let']", "a.let", &es3);
+        test_options("a['\\u00d1StuffAfter']", "a.\u{00d1}StuffAfter", &es3);
+        test_same_options("a['default']", &es3);
+        test_same_options("a?.['int']", &es3);
+        test_same_options("a['\\u1d17A']", &es3);
+        test_same_options("v = ({ 'class': 0, 'ᴗA': 0 })", &es3);
+        test_options("v = ({ ['class']: 0, 'b': 0 })", "v = ({ 'class': 0, b: 0 })", &es3);
+        // codegen quotes the property names which are not valid for ES3
+        test_options("a.default", "a['default']", &es3);
+
+        let es5 =
+            CompressOptions { property_name_target: PropertyNameTarget::ES5, ..default_options() };
+        test_options("a['default']", "a.default", &es5);
+        test_same_options("a['\\u1d17A']", &es5);
+        test_same_options("v = ({ 'ᴗA': 0 })", &es5);
+    }
 }
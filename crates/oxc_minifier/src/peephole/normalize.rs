@@ -1,6 +1,10 @@
 use oxc_allocator::{TakeIn, Vec};
 use oxc_ast::ast::*;
-use oxc_ecmascript::constant_evaluation::{DetermineValueType, ValueType};
+use oxc_ecmascript::{
+    BoundNames,
+    constant_evaluation::{DetermineValueType, ValueType},
+};
+use oxc_mangler::collect_keep_annotations;
 use oxc_semantic::IsGlobalReference;
 use oxc_span::GetSpan;
 use oxc_syntax::scope::ScopeFlags;
@@ -31,6 +35,7 @@ pub struct NormalizeOptions {
 /// * convert `undefined` to `void 0`
 /// * apply `pure` to side-effect free global constructors (e.g. `new WeakMap()`)
 /// * remove unnecessary 'use strict' directive
+/// * collect statements annotated with `/* oxc-keep */` and the symbols they declare
 ///
 /// Also
 ///
@@ -52,6 +57,10 @@ impl<'a> Normalize {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
+    fn enter_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        ctx.state.kept_statements = collect_keep_annotations(node);
+    }
+
     fn exit_program(&mut self, node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
         if node.source_type.is_module() {
             node.directives.drain_filter(|d| d.directive.as_str() == "use strict");
@@ -63,9 +72,16 @@ impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
             !(matches!(stmt, Statement::EmptyStatement(_))
                 || Self::drop_debugger(stmt, ctx)
                 || Self::drop_console(stmt, ctx))
+                || ctx.state.kept_statements.contains(&stmt.span().start)
         });
     }
 
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if ctx.state.kept_statements.contains(&stmt.span().start) {
+            Self::collect_kept_symbols(stmt, ctx);
+        }
+    }
+
     fn exit_variable_declaration(
         &mut self,
         decl: &mut VariableDeclaration<'a>,
@@ -127,6 +143,38 @@ impl<'a> Normalize {
         Self { options }
     }
 
+    /// Collect the symbols declared by a statement annotated with `/* oxc-keep */`,
+    /// so they are not removed when unused.
+    fn collect_kept_symbols(stmt: &Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let kept_symbols = &mut ctx.state.kept_symbols;
+        let mut keep = |ident: &BindingIdentifier<'a>| {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                kept_symbols.insert(symbol_id);
+            }
+        };
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(decl) = &decl.declaration {
+                    decl.bound_names(&mut keep);
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    func.bound_names(&mut keep);
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    class.bound_names(&mut keep);
+                }
+                _ => {}
+            },
+            _ => {
+                if let Some(decl) = stmt.as_declaration() {
+                    decl.bound_names(&mut keep);
+                }
+            }
+        }
+    }
+
     /// Drop `drop_debugger` statement.
     ///
     /// Enabled by `compress.drop_debugger`
@@ -268,6 +268,9 @@ impl<'a> PeepholeOptimizations {
 
     fn try_fold_expression_stmt(&self, stmt: &mut Statement<'a>, ctx: &mut Ctx<'a, '_>) {
         let Statement::ExpressionStatement(expr_stmt) = stmt else { return };
+        if ctx.state.kept_statements.contains(&expr_stmt.span.start) {
+            return;
+        }
         // We need to check if it is in arrow function with `expression: true`.
         // This is the only scenario where we can't remove it even if `ExpressionStatement`.
         if let Ancestor::ArrowFunctionExpressionBody(body) = ctx.ancestry.ancestor(1) {
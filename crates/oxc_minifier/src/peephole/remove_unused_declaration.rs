@@ -21,7 +21,8 @@ impl<'a> PeepholeOptimizations {
                 return false;
             }
             if let Some(symbol_id) = ident.symbol_id.get() {
-                return ctx.scoping().symbol_is_unused(symbol_id);
+                return ctx.scoping().symbol_is_unused(symbol_id)
+                    && !ctx.state.kept_symbols.contains(&symbol_id);
             }
         }
         false
@@ -39,7 +40,8 @@ impl<'a> PeepholeOptimizations {
         }
         let id = f.id.as_ref()?;
         let symbol_id = id.symbol_id.get()?;
-        if !ctx.scoping().symbol_is_unused(symbol_id) {
+        if !ctx.scoping().symbol_is_unused(symbol_id) || ctx.state.kept_symbols.contains(&symbol_id)
+        {
             return None;
         }
         Some(ctx.ast.statement_empty(f.span))
@@ -57,7 +59,8 @@ impl<'a> PeepholeOptimizations {
         }
         let id = c.id.as_ref()?;
         let symbol_id = id.symbol_id.get()?;
-        if !ctx.scoping().symbol_is_unused(symbol_id) {
+        if !ctx.scoping().symbol_is_unused(symbol_id) || ctx.state.kept_symbols.contains(&symbol_id)
+        {
             return None;
         }
         Self::remove_unused_class(c, ctx).map(|exprs| {
@@ -155,6 +158,27 @@ mod test {
         test_same_options("class C extends (() => {}) {}", &options);
     }
 
+    #[test]
+    fn keep_annotation() {
+        let options = CompressOptions::smallest();
+        test_same_options("/* oxc-keep */ var x = 1", &options);
+        test_options(
+            "/* oxc-keep */ var x = 1, y = 2; var z",
+            "/* oxc-keep */ var x = 1, y = 2",
+            &options,
+        );
+        test_same_options("/* oxc-keep */ function foo() {}", &options);
+        test_same_options("/* oxc-keep */ class C {}", &options);
+        test_same_options("/*oxc-keep*/ 'use foo'", &options);
+        test_same_options("export function foo() { /* oxc-keep */ let x }", &options);
+        test_options(
+            "/* oxc-keep */ function foo() { let x }",
+            "/* oxc-keep */ function foo() {}",
+            &options,
+        );
+        test_options("/* keep */ var x = 1", "", &options);
+    }
+
     #[test]
     fn keep_in_script_mode() {
         let options = CompressOptions::smallest();
@@ -8,7 +8,6 @@ use oxc_span::GetSpan;
 use oxc_span::SPAN;
 use oxc_syntax::{
     es_target::ESTarget,
-    identifier::is_identifier_name,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
 };
@@ -885,7 +884,7 @@ impl<'a> PeepholeOptimizations {
         }
         let PropertyKey::StringLiteral(s) = key else { return };
         let value = s.value.as_str();
-        if is_identifier_name(value) {
+        if ctx.options().property_name_target.is_unquoted_property_name(value) {
             *computed = false;
             *key = PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(s.span, s.value));
             ctx.state.changed = true;
@@ -15,6 +15,12 @@ pub struct MinifierState<'a> {
 
     pub symbol_values: SymbolValues<'a>,
 
+    /// Start offsets of statements annotated with `/* oxc-keep */`
+    pub kept_statements: FxHashSet<u32>,
+
+    /// Symbols declared by statements annotated with `/* oxc-keep */`
+    pub kept_symbols: FxHashSet<SymbolId>,
+
     pub changed: bool,
 }
 
@@ -25,6 +31,8 @@ impl MinifierState<'_> {
             options,
             empty_functions: FxHashSet::default(),
             symbol_values: SymbolValues::default(),
+            kept_statements: FxHashSet::default(),
+            kept_symbols: FxHashSet::default(),
             changed: false,
         }
     }
@@ -3,7 +3,7 @@ use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
-use crate::{CompressOptions, CompressOptionsUnused, Compressor};
+use crate::{CompressOptions, CompressOptionsUnused, Compressor, PropertyNameTarget};
 
 pub fn default_options() -> CompressOptions {
     CompressOptions { unused: CompressOptionsUnused::Keep, ..CompressOptions::smallest() }
@@ -63,13 +63,16 @@ fn run(source_text: &str, source_type: SourceType, options: Option<CompressOptio
     assert!(!ret.panicked, "{source_text}");
     assert!(ret.errors.is_empty(), "{source_text}");
     let mut program = ret.program;
+    let mut property_name_target = PropertyNameTarget::default();
     if let Some(options) = options {
+        property_name_target = options.property_name_target;
         Compressor::new(&allocator).build(&mut program, options);
     }
     Codegen::new()
         .with_options(CodegenOptions {
             single_quote: true,
             minify: false,
+            property_name_target,
             ..CodegenOptions::default()
         })
         .build(&program)
@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler, NameFrequencies};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -23,6 +23,47 @@ fn direct_eval() {
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 }
 
+#[test]
+fn keep_annotation() {
+    let source_text = "function foo() { /* oxc-keep */ function onLoad() {} /* oxc-keep */ let [ keep ] = x; let mangle }";
+    let mangled = mangle(source_text, MangleOptions::default());
+    assert_eq!(
+        mangled,
+        "function foo() {\n\t/* oxc-keep */ function onLoad() {}\n\t/* oxc-keep */ let [keep] = x;\n\tlet e;\n}\n"
+    );
+}
+
+#[test]
+fn name_frequencies() {
+    // More parameters than single character names, so that the least frequent one gets a longer name.
+    let params = (0..54).map(|i| format!("p{i}")).collect::<Vec<_>>();
+    let first = format!(
+        "function _({}, options) {{ {}; options }}",
+        params.join(", "),
+        params.iter().map(|p| format!("{p}, {p}")).collect::<Vec<_>>().join(", ")
+    );
+    let second = "function _(options) { options; options; options; options }";
+
+    let allocator = Allocator::default();
+    let programs = [first.as_str(), second]
+        .map(|source| Parser::new(&allocator, source, SourceType::mjs()).parse().program);
+    let mut frequencies = NameFrequencies::new();
+    for program in &programs {
+        frequencies.add_program(program);
+    }
+
+    let last_param_name = |code: &str| {
+        let params = code.split_once('(').unwrap().1.split_once(')').unwrap().0;
+        params.rsplit(", ").next().unwrap().to_string()
+    };
+    // `options` is the least frequent name in the first file alone.
+    assert_eq!(last_param_name(&mangle(&first, MangleOptions::default())).len(), 2);
+    // `options` is the most frequent name across both files.
+    let symbol_table = Mangler::new().with_name_frequencies(&frequencies).build(&programs[0]);
+    let code = Codegen::new().with_scoping(Some(symbol_table)).build(&programs[0]).code;
+    assert_eq!(last_param_name(&code).len(), 1);
+}
+
 #[test]
 fn mangler() {
     let cases = [
@@ -17,7 +17,9 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_len: usize,
     fatal_error: Option<FatalError>,
+    pipe_topic_used: Option<bool>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -101,6 +103,7 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.record_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
@@ -108,10 +111,22 @@ impl<'a> ParserImpl<'a> {
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.record_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the current token before moving to the next one, see [`ParseOptions::preserve_trivia`].
+    /// The token is final when the parser leaves it, after any re-lexing.
+    ///
+    /// [`ParseOptions::preserve_trivia`]: crate::ParseOptions::preserve_trivia
+    #[inline]
+    fn record_token(&mut self) {
+        if self.options.preserve_trivia {
+            self.tokens.push(self.token);
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     #[must_use = "Use `bump` instead of `eat` if you are ignoring the return value"]
@@ -229,6 +244,19 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    pub(crate) fn re_lex_pipe_gt(&mut self) -> Kind {
+        if self.fatal_error.is_some() {
+            return Kind::Eof;
+        }
+        let kind = self.cur_kind();
+        if kind == Kind::Pipe {
+            self.token = self.lexer.re_lex_pipe_gt();
+            self.token.kind()
+        } else {
+            kind
+        }
+    }
+
     pub(crate) fn re_lex_l_angle(&mut self) -> Kind {
         if self.fatal_error.is_some() {
             return Kind::Eof;
@@ -261,19 +289,30 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
+            pipe_topic_used: self.state.pipe_topic_used,
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            tokens_len,
+            fatal_error,
+            pipe_topic_used,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.tokens.truncate(tokens_len);
         self.fatal_error = fatal_error;
+        self.state.pipe_topic_used = pipe_topic_used;
     }
 
     pub(crate) fn try_parse<T>(
@@ -27,6 +27,18 @@ pub fn overlong_source() -> OxcDiagnostic {
     OxcDiagnostic::error("Source length exceeds 4 GiB limit")
 }
 
+#[cold]
+pub fn source_too_long(max: usize) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Source length exceeds the limit of {max} bytes"))
+}
+
+#[cold]
+pub fn nesting_too_deep(max: u32, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Nesting exceeds the maximum depth of {max}"))
+        .with_label(span)
+        .with_help("Reduce the nesting of the code, or increase `max_nesting_depth`")
+}
+
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
@@ -43,6 +55,23 @@ pub fn expect_token(x0: &str, x1: &str, span: Span) -> OxcDiagnostic {
         .with_label(span.label(format!("`{x0}` expected")))
 }
 
+#[cold]
+pub fn json_syntax(syntax: &str, dialect: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{syntax} are not allowed in {dialect}")).with_label(span)
+}
+
+#[cold]
+pub fn invalid_json_number(dialect: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid number in {dialect}")).with_label(span)
+}
+
+#[cold]
+pub fn invalid_json_string(dialect: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid string in {dialect}"))
+        .with_label(span)
+        .with_help("Control characters must be escaped, and only `\\\"`, `\\\\`, `\\/`, `\\b`, `\\f`, `\\n`, `\\r`, `\\t` and `\\uXXXX` escapes are allowed")
+}
+
 #[cold]
 pub fn invalid_escape_sequence(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid escape sequence").with_label(span)
@@ -264,6 +293,33 @@ pub fn decorator_optional(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn invalid_decorator_expression(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid decorator expression.")
+        .with_help("Only member accesses and a call of them are allowed, wrap other expressions in parentheses: `@(expression)`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn pipe_topic_unused(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Pipe body must use the topic reference `%`.")
+        .with_help("Refer to the value of the left-hand side with `%`, e.g. `value |> f(%)`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn pipe_topic_unbound(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Topic reference `%` is only allowed in the body of a pipe expression.")
+        .with_label(span)
+}
+
+#[cold]
+pub fn pipe_body_unparenthesized(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Pipe body cannot be an unparenthesized {kind} expression."))
+        .with_help("Wrap the body in parentheses")
+        .with_label(span)
+}
+
 #[cold]
 pub fn for_loop_async_of(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The left-hand side of a `for...of` statement may not be `async`")
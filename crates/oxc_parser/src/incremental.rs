@@ -0,0 +1,459 @@
+//! Incremental parsing: reparse the top-level statements changed by text edits, and reuse the
+//! others from the previous parse.
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::ast::{Comment, Program, RegExpLiteral};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::DynamicImport;
+
+use crate::{
+    MAX_LEN, ParserImpl, ParserReturn, StatementContext, Tokens, lexer::Kind,
+    module_record::ModuleRecordBuilder,
+};
+
+/// A change of the source text: `span` of the previous source text is replaced by `new_text`.
+///
+/// See [`Parser::parse_incremental`](crate::Parser::parse_incremental).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit<'e> {
+    /// Span of the replaced text, in the previous source text.
+    pub span: Span,
+    /// The inserted text.
+    pub new_text: &'e str,
+}
+
+impl<'e> TextEdit<'e> {
+    /// Create a new [`TextEdit`] which replaces `span` with `new_text`.
+    pub fn new(span: Span, new_text: &'e str) -> Self {
+        Self { span, new_text }
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Parse the source text, reusing the top-level statements of `old` which are not changed by
+    /// `edits`.
+    ///
+    /// Returns `None` if the statements can't be reused safely, and the source text must be parsed
+    /// from scratch, e.g. when the edits don't produce the source text, or there are syntax errors.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn parse_incremental(
+        mut self,
+        old: ParserReturn<'a>,
+        edits: &[TextEdit<'_>],
+    ) -> Option<ParserReturn<'a>> {
+        let ParserReturn {
+            program: old_program,
+            module_record: old_module_record,
+            errors: old_errors,
+            irregular_whitespaces: old_irregular_whitespaces,
+            panicked,
+            ..
+        } = old;
+        if panicked
+            || !old_errors.is_empty()
+            || old_program.source_type != self.source_type
+            // The previous parse changed the source type to either script or module
+            || self.source_type.is_unambiguous()
+            // Skipped function bodies and tokens are not tracked across edits
+            || self.options.lazy_function_bodies
+            || self.options.preserve_trivia
+            || self.source_text.len() > MAX_LEN
+            || !produces(old_program.source_text, edits, self.source_text)
+        {
+            return None;
+        }
+        let (first_edit, last_edit) = (edits.first()?, edits.last()?);
+        let old_len = old_program.source_text.len() as u32;
+        let new_len = self.source_text.len() as u32;
+        let Program { comments: old_comments, hashbang, directives, body: old_body, .. } =
+            old_program;
+
+        // The first statement which may be changed by the edits is reparsed, and so is the
+        // statement before it, because it may continue into the edited text, e.g. `a = b` + `(c)`.
+        // Edits of the first statement, directives or hashbang are not reused.
+        let changed = old_body.partition_point(|stmt| stmt.span().end < first_edit.span.start);
+        let first = changed.checked_sub(1)?;
+        let start = match first.checked_sub(1) {
+            Some(index) => old_body[index].span().end,
+            None => directives
+                .last()
+                .map(|directive| directive.span.end)
+                .or_else(|| hashbang.as_ref().map(|hashbang| hashbang.span.end))
+                .unwrap_or(0),
+        };
+        // Statements after the edits are reused if reparsing the edited text stops right before one
+        let suffix = old_body.partition_point(|stmt| stmt.span().start < last_edit.span.end);
+        if start != 0 {
+            self.lexer.seek(start);
+        }
+        self.bump_any();
+        let mut statements = self.ast.vec();
+        let mut reused = suffix;
+        let shift = |offset: u32| new_len - (old_len - offset);
+        loop {
+            if self.has_fatal_error() {
+                return None;
+            }
+            if self.at(Kind::Eof) {
+                reused = old_body.len();
+                break;
+            }
+            let position = self.cur_token().start();
+            while reused < old_body.len() && shift(old_body[reused].span().start) < position {
+                reused += 1;
+            }
+            if reused < old_body.len() && shift(old_body[reused].span().start) == position {
+                break;
+            }
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            statements.push(stmt);
+        }
+        if !self.errors.is_empty()
+            || !self.lexer.errors.is_empty()
+            || !self.state.cover_initialized_name.is_empty()
+        {
+            return None;
+        }
+
+        let (old_end, new_end) = match old_body.get(reused) {
+            Some(stmt) => (stmt.span().start, shift(stmt.span().start)),
+            None => (old_len, new_len),
+        };
+        let shift = Shift { from: old_end, to: new_end };
+
+        let allocator = self.ast.allocator;
+        let mut shifter = SpanShifter { shift, allocator };
+        let mut body =
+            self.ast.vec_with_capacity(first + statements.len() + old_body.len() - reused);
+        let mut old_body = old_body.into_iter();
+        body.extend(old_body.by_ref().take(first));
+        body.extend(statements);
+        body.extend(old_body.skip(reused - first).map(|mut stmt| {
+            shifter.visit_statement(&mut stmt);
+            stmt
+        }));
+
+        let region_comments = &self.lexer.trivia_builder.comments;
+        let comments = self.ast.vec_from_iter(
+            old_comments
+                .iter()
+                .filter(|comment| comment.span.start < start)
+                .copied()
+                .chain(
+                    region_comments.iter().filter(|comment| comment.span.start < new_end).copied(),
+                )
+                .chain(
+                    old_comments
+                        .iter()
+                        .filter(|comment| comment.span.start >= old_end)
+                        .map(|comment| shift.comment(*comment)),
+                ),
+        );
+        let irregular_whitespaces = old_irregular_whitespaces
+            .iter()
+            .filter(|span| span.start < start)
+            .copied()
+            .chain(
+                self.lexer
+                    .trivia_builder
+                    .irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start < new_end)
+                    .copied(),
+            )
+            .chain(
+                old_irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start >= old_end)
+                    .map(|span| shift.span(*span)),
+            )
+            .collect();
+
+        // Module declarations are visited again, in source order. Dynamic imports and
+        // `import.meta` can be nested anywhere, so they are reused like the statements.
+        let mut module_record_builder = ModuleRecordBuilder::new(allocator);
+        for stmt in &body {
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                module_record_builder.visit_module_declaration(module_decl);
+            }
+        }
+        let (mut module_record, module_record_errors) = module_record_builder.build();
+        if !self.source_type.is_typescript() && !module_record_errors.is_empty() {
+            return None;
+        }
+        let (region_module_record, _) = self.module_record_builder.build();
+        module_record.dynamic_imports = ArenaVec::from_iter_in(
+            old_module_record
+                .dynamic_imports
+                .iter()
+                .filter(|import| import.span.start < start)
+                .copied()
+                .chain(region_module_record.dynamic_imports.iter().copied())
+                .chain(
+                    old_module_record
+                        .dynamic_imports
+                        .iter()
+                        .filter(|import| import.span.start >= old_end)
+                        .map(|import| DynamicImport {
+                            span: shift.span(import.span),
+                            module_request: shift.span(import.module_request),
+                        }),
+                ),
+            allocator,
+        );
+        module_record.import_metas = ArenaVec::from_iter_in(
+            old_module_record
+                .import_metas
+                .iter()
+                .filter(|span| span.start < start)
+                .copied()
+                .chain(region_module_record.import_metas.iter().copied())
+                .chain(
+                    old_module_record
+                        .import_metas
+                        .iter()
+                        .filter(|span| span.start >= old_end)
+                        .map(|span| shift.span(*span)),
+                ),
+            allocator,
+        );
+        module_record.has_module_syntax |= !module_record.import_metas.is_empty();
+
+        let program = self.ast.program(
+            Span::new(0, new_len),
+            self.source_type,
+            self.source_text,
+            comments,
+            hashbang,
+            directives,
+            body,
+        );
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors: vec![],
+            irregular_whitespaces,
+            panicked: false,
+            is_flow_language: false,
+            lazy_function_bodies: vec![],
+            tokens: Tokens::new(self.source_text, vec![]),
+        })
+    }
+}
+
+/// Whether applying `edits` to `old_source_text` produces `new_source_text`.
+///
+/// The edits must be sorted, and must not overlap.
+fn produces(old_source_text: &str, edits: &[TextEdit<'_>], new_source_text: &str) -> bool {
+    let mut old_end = 0;
+    let mut rest = new_source_text;
+    for edit in edits {
+        let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+        if start < old_end || old_source_text.get(start..end).is_none() {
+            return false;
+        }
+        let Some(after) = rest
+            .strip_prefix(&old_source_text[old_end..start])
+            .and_then(|after| after.strip_prefix(edit.new_text))
+        else {
+            return false;
+        };
+        rest = after;
+        old_end = end;
+    }
+    old_source_text[old_end..] == *rest
+}
+
+/// Moves offsets after the edits, so that `from` in the previous source text becomes `to`.
+#[derive(Clone, Copy)]
+struct Shift {
+    from: u32,
+    to: u32,
+}
+
+impl Shift {
+    fn offset(self, offset: u32) -> u32 {
+        offset - self.from + self.to
+    }
+
+    fn span(self, span: Span) -> Span {
+        Span::new(self.offset(span.start), self.offset(span.end))
+    }
+
+    fn comment(self, mut comment: Comment) -> Comment {
+        comment.span = self.span(comment.span);
+        // Trailing comments are not attached to a token
+        if comment.is_leading() {
+            comment.attached_to = self.offset(comment.attached_to);
+        }
+        comment
+    }
+}
+
+/// Moves all spans in a reused statement.
+struct SpanShifter<'a> {
+    shift: Shift,
+    #[cfg_attr(not(feature = "regular_expression"), expect(dead_code))]
+    allocator: &'a Allocator,
+}
+
+impl<'a> VisitMut<'a> for SpanShifter<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.shift.span(*span);
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &mut RegExpLiteral<'a>) {
+        walk_mut::walk_reg_exp_literal(self, it);
+        // The parsed pattern has spans too, parse it again at the new offset
+        #[cfg(feature = "regular_expression")]
+        if let (Some(_), Some(raw)) = (&it.regex.pattern.pattern, it.raw) {
+            use oxc_allocator::Box as ArenaBox;
+            use oxc_regular_expression::{LiteralParser, Options};
+
+            let pattern = it.regex.pattern.text.as_str();
+            let pattern_span_offset = it.span.start + 1;
+            #[expect(clippy::cast_possible_truncation)]
+            let flags_span_offset = pattern_span_offset + pattern.len() as u32 + 1;
+            let flags = &raw.as_str()[pattern.len() + 2..];
+            it.regex.pattern.pattern = LiteralParser::new(
+                self.allocator,
+                pattern,
+                Some(flags),
+                Options { pattern_span_offset, flags_span_offset },
+            )
+            .parse()
+            .ok()
+            .map(|pattern| ArenaBox::new_in(pattern, self.allocator));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, GetAddress};
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_span::{SourceType, Span};
+
+    use crate::{ParseOptions, Parser, TextEdit};
+
+    /// Parse `source_text`, then parse it after `edits` incrementally, and check that the result
+    /// is the same as parsing the new source text from scratch.
+    /// Returns the number of reused statements.
+    fn check(source_type: SourceType, source_text: &str, edits: &[(u32, u32, &str)]) -> usize {
+        let edits = edits
+            .iter()
+            .map(|&(start, end, new_text)| TextEdit::new(Span::new(start, end), new_text))
+            .collect::<Vec<_>>();
+        let mut new_source_text = String::new();
+        let mut end = 0;
+        for edit in &edits {
+            new_source_text.push_str(&source_text[end..edit.span.start as usize]);
+            new_source_text.push_str(edit.new_text);
+            end = edit.span.end as usize;
+        }
+        new_source_text.push_str(&source_text[end..]);
+
+        let allocator = Allocator::default();
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+        let old = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+        assert!(old.errors.is_empty());
+        let old_addresses = old.program.body.iter().map(GetAddress::address).collect::<Vec<_>>();
+
+        let ret = Parser::new(&allocator, &new_source_text, source_type)
+            .with_options(options)
+            .parse_incremental(old, &edits);
+        let expected =
+            Parser::new(&allocator, &new_source_text, source_type).with_options(options).parse();
+        assert_eq!(
+            ret.program.to_estree_ts_json(true),
+            expected.program.to_estree_ts_json(true),
+            "{new_source_text}"
+        );
+        assert_eq!(ret.program.comments.as_slice(), expected.program.comments.as_slice());
+        assert_eq!(ret.irregular_whitespaces, expected.irregular_whitespaces);
+        assert_eq!(format!("{:?}", ret.module_record), format!("{:?}", expected.module_record));
+        assert_eq!(ret.errors.len(), expected.errors.len());
+        assert_eq!(ret.panicked, expected.panicked);
+
+        ret.program.body.iter().filter(|stmt| old_addresses.contains(&stmt.address())).count()
+    }
+
+    #[test]
+    fn reuse_statements() {
+        let source_text = "// a\nlet a = 1; // a\n/* b */ let b = 2;\nlet c = 3;\n// d\nfunction d() { return 4; }\n";
+        assert_eq!(check(SourceType::mjs(), source_text, &[(48, 49, "30")]), 2);
+        assert_eq!(check(SourceType::mjs(), source_text, &[(40, 51, "")]), 2);
+        assert_eq!(check(SourceType::mjs(), source_text, &[(33, 34, "x"), (48, 49, "y")]), 1);
+        // Insert statements at the end of the file
+        let len = u32::try_from(source_text.len()).unwrap();
+        assert_eq!(check(SourceType::mjs(), source_text, &[(len, len, "let e = 5;\n")]), 3);
+    }
+
+    #[test]
+    fn reparse_continued_statements() {
+        // `a = b` continues into the edited text
+        let source_text = "x;\na = b\nfoo()\nbar()\n";
+        assert_eq!(check(SourceType::cjs(), source_text, &[(9, 14, "(c)")]), 2);
+        // The edited text continues into the next statement
+        assert_eq!(check(SourceType::cjs(), source_text, &[(9, 14, "foo +")]), 1);
+        // The edited text swallows the rest of the file
+        let source_text = "x;\ny;\nz;\n`${w}`;\n";
+        assert_eq!(check(SourceType::cjs(), source_text, &[(6, 6, "/*")]), 0);
+        assert_eq!(check(SourceType::cjs(), source_text, &[(6, 6, "`")]), 0);
+    }
+
+    #[test]
+    fn module_record() {
+        let source_text = "import a from 'a';\nlet b = import('b');\nlet c;\nexport { c };\nlet d = import.meta;\n";
+        assert_eq!(check(SourceType::mjs(), source_text, &[(44, 45, "c, e")]), 3);
+        assert_eq!(check(SourceType::mjs(), source_text, &[(44, 45, "c = import('c')")]), 3);
+        assert_eq!(check(SourceType::mjs(), source_text, &[(47, 60, "export { c as e };")]), 3);
+        // Duplicate export
+        assert_eq!(check(SourceType::mjs(), source_text, &[(40, 40, "export ")]), 0);
+    }
+
+    #[test]
+    fn shift_regular_expressions() {
+        let source_text = "let a = 1;\nlet b = 2;\nlet c = /c+/u;\n";
+        assert_eq!(check(SourceType::mjs(), source_text, &[(19, 20, "42")]), 1);
+
+        let allocator = Allocator::default();
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+        let old =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        let ret =
+            Parser::new(&allocator, "let a = 1;\nlet b = 42;\nlet c = /c+/u;\n", SourceType::mjs())
+                .with_options(options)
+                .parse_incremental(old, &[TextEdit::new(Span::new(19, 20), "42")]);
+        let Statement::VariableDeclaration(decl) = &ret.program.body[2] else { unreachable!() };
+        let Some(Expression::RegExpLiteral(regex)) = &decl.declarations[0].init else {
+            unreachable!()
+        };
+        assert_eq!(regex.regex.pattern.pattern.as_ref().unwrap().span, Span::new(32, 34));
+    }
+
+    #[test]
+    fn parse_from_scratch() {
+        let source_text = "'use strict';\nlet a = 1;\nlet b = 2;\n";
+        // Edits of the first statement or the directives
+        assert_eq!(check(SourceType::cjs(), source_text, &[(22, 23, "2")]), 0);
+        assert_eq!(check(SourceType::cjs(), source_text, &[(1, 4, "use")]), 0);
+        // Syntax errors
+        assert_eq!(check(SourceType::cjs(), source_text, &[(33, 34, "")]), 0);
+        // No edits
+        assert_eq!(check(SourceType::cjs(), source_text, &[]), 0);
+    }
+
+    #[test]
+    fn edits_which_do_not_produce_source_text() {
+        let allocator = Allocator::default();
+        let source_text = "let a = 1;\nlet b = 2;\nlet c = 3;\n";
+        let old = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let new_source_text = "let a = 1;\nlet b = 2;\nlet c = 4;\n";
+        let ret = Parser::new(&allocator, new_source_text, SourceType::mjs())
+            .parse_incremental(old, &[TextEdit::new(Span::new(19, 20), "4")]);
+        let expected = Parser::new(&allocator, new_source_text, SourceType::mjs()).parse();
+        assert_eq!(ret.program.to_estree_ts_json(true), expected.program.to_estree_ts_json(true));
+    }
+}
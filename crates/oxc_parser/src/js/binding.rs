@@ -26,6 +26,10 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_binding_pattern_kind(&mut self) -> BindingPatternKind<'a> {
+        self.nested(Self::parse_binding_pattern_kind_impl)
+    }
+
+    fn parse_binding_pattern_kind_impl(&mut self) -> BindingPatternKind<'a> {
         match self.cur_kind() {
             Kind::LCurly => self.parse_object_binding_pattern(),
             Kind::LBrack => self.parse_array_binding_pattern(),
@@ -1,3 +1,5 @@
+use std::mem;
+
 use cow_utils::CowUtils;
 use oxc_allocator::{Box, TakeIn, Vec};
 use oxc_ast::ast::*;
@@ -181,6 +183,13 @@ impl<'a> ParserImpl<'a> {
             Kind::NoSubstitutionTemplate | Kind::TemplateHead => {
                 self.parse_template_literal_expression(false)
             }
+            Kind::Percent
+                if self.options.experimental_pipeline
+                    && (self.state.pipe_topic_used.is_some()
+                        || !self.options.allow_v8_intrinsics) =>
+            {
+                self.parse_topic_reference()
+            }
             Kind::Percent if self.options.allow_v8_intrinsics => {
                 self.parse_v8_intrinsic_expression()
             }
@@ -843,6 +852,10 @@ impl<'a> ParserImpl<'a> {
 
     /// [NewExpression](https://tc39.es/ecma262/#sec-new-operator)
     fn parse_new_expression(&mut self) -> Expression<'a> {
+        self.nested(Self::parse_new_expression_impl)
+    }
+
+    fn parse_new_expression_impl(&mut self) -> Expression<'a> {
         let span = self.start_span();
         let identifier = self.parse_keyword_identifier(Kind::New);
 
@@ -1036,6 +1049,10 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_simple_unary_expression(&mut self, lhs_span: u32) -> Expression<'a> {
+        self.nested(|p| p.parse_simple_unary_expression_impl(lhs_span))
+    }
+
+    fn parse_simple_unary_expression_impl(&mut self, lhs_span: u32) -> Expression<'a> {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => self.parse_unary_expression(),
             Kind::LAngle => {
@@ -1107,7 +1124,11 @@ impl<'a> ParserImpl<'a> {
         loop {
             // re-lex for `>=` `>>` `>>>`
             // This is need for jsx `<div>=</div>` case
-            let kind = self.re_lex_right_angle();
+            let mut kind = self.re_lex_right_angle();
+            // `|>` is only a token with `experimental_pipeline`, so `a |> b` is `a | > b` otherwise
+            if kind == Kind::Pipe && self.options.experimental_pipeline {
+                kind = self.re_lex_pipe_gt();
+            }
 
             let Some(left_precedence) = kind_to_precedence(kind) else { break };
 
@@ -1149,9 +1170,17 @@ impl<'a> ParserImpl<'a> {
                 continue;
             }
 
+            if kind == Kind::PipeGt {
+                self.bump_any();
+                let body = self.parse_pipe_body();
+                let span = self.end_span(lhs_span);
+                lhs = self.ast.expression_pipeline(span, lhs, body);
+                continue;
+            }
+
             self.bump_any(); // bump operator
             let rhs_parenthesized = self.at(Kind::LParen);
-            let rhs = self.parse_binary_expression_or_higher(left_precedence);
+            let rhs = self.nested(|p| p.parse_binary_expression_or_higher(left_precedence));
 
             lhs = if kind.is_logical_operator() {
                 let span = self.end_span(lhs_span);
@@ -1196,6 +1225,44 @@ impl<'a> ParserImpl<'a> {
         lhs
     }
 
+    /// Body of a Hack-style pipe expression `value |> body`, with `%` referring to `value`.
+    fn parse_pipe_body(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        let outer_topic_used = self.state.pipe_topic_used.replace(false);
+        let body = self.parse_assignment_expression_or_higher();
+        let topic_used = mem::replace(&mut self.state.pipe_topic_used, outer_topic_used);
+        // A parenthesized body is a `ParenthesizedExpression`, or starts after `(` without
+        // `preserve_parens`
+        if body.span().start == span {
+            let kind = match body {
+                Expression::ArrowFunctionExpression(_) => Some("arrow function"),
+                Expression::AssignmentExpression(_) => Some("assignment"),
+                Expression::ConditionalExpression(_) => Some("conditional"),
+                Expression::YieldExpression(_) => Some("yield"),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                self.error(diagnostics::pipe_body_unparenthesized(kind, body.span()));
+            }
+        }
+        if topic_used == Some(false) {
+            self.error(diagnostics::pipe_topic_unused(body.span()));
+        }
+        body
+    }
+
+    /// `%` in the body of a pipe expression
+    fn parse_topic_reference(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any();
+        let span = self.end_span(span);
+        match &mut self.state.pipe_topic_used {
+            Some(used) => *used = true,
+            None => self.error(diagnostics::pipe_topic_unbound(span)),
+        }
+        self.ast.expression_topic_reference(span)
+    }
+
     /// Section 13.14 Conditional Expression
     /// `ConditionalExpression`[In, Yield, Await] :
     ///     `ShortCircuitExpression`[?In, ?Yield, ?Await]
@@ -1230,6 +1297,13 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_assignment_expression_or_higher_impl(
         &mut self,
         allow_return_type_in_arrow_function: bool,
+    ) -> Expression<'a> {
+        self.nested(|p| p.parse_assignment_expression(allow_return_type_in_arrow_function))
+    }
+
+    fn parse_assignment_expression(
+        &mut self,
+        allow_return_type_in_arrow_function: bool,
     ) -> Expression<'a> {
         let has_no_side_effects_comment =
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
@@ -1429,14 +1503,48 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_decorator(&mut self) -> Decorator<'a> {
         let span = self.start_span();
         self.bump_any(); // bump @
+        let expr_span = self.start_span();
         let expr = self.context(
             Context::Decorator,
             Context::empty(),
             Self::parse_lhs_expression_or_higher,
         );
+        if self.options.stage3_decorators {
+            self.check_decorator_expression(expr_span, &expr);
+        }
         self.ast.decorator(self.end_span(span), expr)
     }
 
+    /// Only `@a.b.#c`, `@a.b.#c(args)` and `@(expression)` are valid decorators in the
+    /// decorators proposal. TypeScript's legacy decorators allow any left-hand-side expression.
+    fn check_decorator_expression(&mut self, start: u32, expr: &Expression<'a>) {
+        let valid = match expr {
+            Expression::ParenthesizedExpression(_) => true,
+            // Without `preserve_parens`, the expression inside `@(expression)` starts after `(`
+            _ if expr.span().start != start => true,
+            // Optional chaining is reported in `parse_lhs_expression_or_higher`
+            Expression::ChainExpression(_) => true,
+            Expression::CallExpression(call) => Self::is_decorator_member_expression(&call.callee),
+            _ => Self::is_decorator_member_expression(expr),
+        };
+        if !valid {
+            self.error(diagnostics::invalid_decorator_expression(expr.span()));
+        }
+    }
+
+    fn is_decorator_member_expression(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(_) => true,
+            Expression::StaticMemberExpression(member) => {
+                Self::is_decorator_member_expression(&member.object)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                Self::is_decorator_member_expression(&member.object)
+            }
+            _ => false,
+        }
+    }
+
     fn is_update_expression(&self) -> bool {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => false,
@@ -29,6 +29,15 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_function_body(&mut self) -> Box<'a, FunctionBody<'a>> {
+        if self.options.lazy_function_bodies {
+            if let Some(body) = self.skip_function_body() {
+                return body;
+            }
+        }
+        self.parse_function_body_eagerly()
+    }
+
+    pub(crate) fn parse_function_body_eagerly(&mut self) -> Box<'a, FunctionBody<'a>> {
         let span = self.start_span();
         self.expect(Kind::LCurly);
 
@@ -40,6 +49,66 @@ impl<'a> ParserImpl<'a> {
         self.ast.alloc_function_body(self.end_span(span), directives, statements)
     }
 
+    /// Skip the tokens of a function body, from `{` to the matching `}`, for
+    /// [`ParseOptions::lazy_function_bodies`], and return an empty body with its span.
+    ///
+    /// Regular expressions are told apart from divisions by the previous token, as the body is not
+    /// parsed. Returns `None` and rewinds to `{` when the end of the body can't be found reliably:
+    /// if there are lexer errors, JSX, which can't be lexed without parsing, or a `/` whose previous
+    /// token doesn't tell (see [`is_expression_start`]). The body is then parsed as usual.
+    ///
+    /// [`ParseOptions::lazy_function_bodies`]: crate::ParseOptions::lazy_function_bodies
+    fn skip_function_body(&mut self) -> Option<Box<'a, FunctionBody<'a>>> {
+        let checkpoint = self.checkpoint();
+        let lexer_errors_len = self.lexer.errors.len();
+        let span = self.start_span();
+        // Open braces, `true` for the `${` of a template literal
+        let mut braces = vec![];
+        let mut prev_kind = Kind::Undetermined;
+        loop {
+            match self.cur_kind() {
+                Kind::LCurly => braces.push(false),
+                Kind::TemplateHead => braces.push(true),
+                Kind::RCurly => {
+                    if braces.last() == Some(&true) {
+                        self.re_lex_template_substitution_tail();
+                        if self.at(Kind::TemplateTail) {
+                            braces.pop();
+                        }
+                    } else {
+                        braces.pop();
+                    }
+                }
+                Kind::Slash | Kind::SlashEq => match is_expression_start(prev_kind) {
+                    Some(true) => {
+                        self.read_regex();
+                    }
+                    Some(false) => {}
+                    None => break,
+                },
+                Kind::LAngle
+                    if self.source_type.is_jsx()
+                        && is_expression_start(prev_kind) != Some(false) =>
+                {
+                    break;
+                }
+                _ => {}
+            }
+            prev_kind = self.cur_kind();
+            self.bump_any();
+            if self.has_fatal_error() || self.lexer.errors.len() > lexer_errors_len {
+                break;
+            }
+            if braces.is_empty() {
+                let span = self.end_span(span);
+                self.state.lazy_function_bodies.insert(span.start, span);
+                return Some(self.ast.alloc_function_body(span, self.ast.vec(), self.ast.vec()));
+            }
+        }
+        self.rewind(checkpoint);
+        None
+    }
+
     pub(crate) fn parse_formal_parameters(
         &mut self,
         func_kind: FunctionKind,
@@ -87,7 +156,7 @@ impl<'a> ParserImpl<'a> {
             );
         }
         let pattern = self.parse_binding_pattern_with_initializer();
-        if func_kind != FunctionKind::ClassMethod || !self.is_ts {
+        if func_kind != FunctionKind::ClassMethod || !self.is_ts || self.options.stage3_decorators {
             for decorator in &decorators {
                 self.error(diagnostics::decorators_are_not_valid_here(decorator.span));
             }
@@ -358,3 +427,37 @@ impl<'a> ParserImpl<'a> {
         }
     }
 }
+
+/// Whether a token after `prev_kind` starts an expression, rather than continuing one,
+/// e.g. `/` after `return` starts a regular expression, but after `]` it's a division.
+///
+/// Returns `None` when it depends on what the previous token closed: `/` after `)` is a regular
+/// expression in `if (a) /re/`, but a division in `f(a) / 2`, and after `}` it's a regular
+/// expression following a block, but a division following an object literal.
+fn is_expression_start(prev_kind: Kind) -> Option<bool> {
+    Some(match prev_kind {
+        Kind::RParen | Kind::RCurly => return None,
+        Kind::RBrack
+        | Kind::Plus2
+        | Kind::Minus2
+        | Kind::Str
+        | Kind::RegExp
+        | Kind::NoSubstitutionTemplate
+        | Kind::TemplateTail
+        | Kind::PrivateIdentifier => false,
+        Kind::Await
+        | Kind::Case
+        | Kind::Delete
+        | Kind::Do
+        | Kind::Else
+        | Kind::In
+        | Kind::Instanceof
+        | Kind::New
+        | Kind::Return
+        | Kind::Throw
+        | Kind::Typeof
+        | Kind::Void
+        | Kind::Yield => true,
+        kind => !kind.is_number() && !kind.is_identifier_name(),
+    })
+}
@@ -24,6 +24,8 @@ pub fn kind_to_precedence(kind: Kind) -> Option<Precedence> {
         Kind::Star | Kind::Slash | Kind::Percent => Some(Precedence::Multiply),
         Kind::Star2 => Some(Precedence::Exponentiation),
         Kind::As | Kind::Satisfies => Some(Precedence::Compare),
+        // Lower than any binary operator, so `|>` is only parsed at the top level, like `=>`
+        Kind::PipeGt => Some(Precedence::Conditional),
         _ => None,
     }
 }
@@ -81,6 +81,10 @@ impl<'a> ParserImpl<'a> {
         &mut self,
         stmt_ctx: StatementContext,
     ) -> Statement<'a> {
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(&mut self, stmt_ctx: StatementContext) -> Statement<'a> {
         let has_no_side_effects_comment =
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
 
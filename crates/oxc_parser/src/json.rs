@@ -0,0 +1,363 @@
+//! Parser for JSON, JSONC and JSON5, which produces JavaScript expressions.
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{ParserImpl, diagnostics, lexer::Kind};
+
+/// Options for [`Parser::parse_json`].
+///
+/// [`Parser::parse_json`]: crate::Parser::parse_json
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Allow comments and trailing commas (JSONC), as in `tsconfig.json` and VS Code settings.
+    ///
+    /// Default: `false`
+    pub jsonc: bool,
+
+    /// Allow the syntax of [JSON5](https://spec.json5.org), which includes JSONC, unquoted keys,
+    /// single-quoted strings, hexadecimal numbers, `Infinity`, `NaN`, a `+` sign, and leading or
+    /// trailing decimal points in numbers.
+    ///
+    /// Default: `false`
+    pub json5: bool,
+}
+
+impl JsonOptions {
+    fn dialect(self) -> &'static str {
+        if self.json5 {
+            "JSON5"
+        } else if self.jsonc {
+            "JSONC"
+        } else {
+            "JSON"
+        }
+    }
+
+    fn allows_comments_and_trailing_commas(self) -> bool {
+        self.jsonc || self.json5
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    pub(crate) fn parse_json(
+        mut self,
+        options: JsonOptions,
+    ) -> Result<Expression<'a>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let value = self.parse_json_value(options);
+        if !self.at(Kind::Eof) && !self.has_fatal_error() {
+            self.set_unexpected();
+        }
+        if !options.allows_comments_and_trailing_commas() {
+            if let Some(comment) = self.lexer.trivia_builder.comments.first() {
+                let error = diagnostics::json_syntax("Comments", options.dialect(), comment.span);
+                self.error(error);
+            }
+        }
+        self.finish_isolated(value)
+    }
+
+    fn parse_json_value(&mut self, options: JsonOptions) -> Expression<'a> {
+        match self.cur_kind() {
+            Kind::LCurly => self.nested(|p| p.parse_json_object(options)),
+            Kind::LBrack => self.nested(|p| p.parse_json_array(options)),
+            Kind::Str => {
+                self.check_json_string(options);
+                let lit = self.parse_literal_string();
+                Expression::StringLiteral(self.alloc(lit))
+            }
+            Kind::True | Kind::False | Kind::Null => self.parse_literal_expression(),
+            Kind::Minus | Kind::Plus => self.parse_json_signed_number(options),
+            kind if kind.is_number() => self.parse_json_number(options),
+            Kind::Ident if options.json5 && matches!(self.cur_src(), "Infinity" | "NaN") => {
+                let span = self.cur_token().span();
+                let name = self.cur_src();
+                self.bump_any();
+                self.ast.expression_identifier(span, name)
+            }
+            _ => self.unexpected(),
+        }
+    }
+
+    fn parse_json_object(&mut self, options: JsonOptions) -> Expression<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly);
+        let (properties, trailing_comma) =
+            self.parse_delimited_list(Kind::RCurly, Kind::Comma, |p| {
+                p.parse_json_property(options)
+            });
+        self.check_json_trailing_comma(trailing_comma, options);
+        self.expect(Kind::RCurly);
+        self.ast.expression_object(self.end_span(span), properties)
+    }
+
+    fn parse_json_property(&mut self, options: JsonOptions) -> ObjectPropertyKind<'a> {
+        let span = self.start_span();
+        let key = match self.cur_kind() {
+            Kind::Str => {
+                self.check_json_string(options);
+                let lit = self.parse_literal_string();
+                PropertyKey::StringLiteral(self.alloc(lit))
+            }
+            kind if kind.is_identifier_name() => {
+                if !options.json5 {
+                    let error = diagnostics::json_syntax(
+                        "Unquoted keys",
+                        options.dialect(),
+                        self.cur_token().span(),
+                    );
+                    self.error(error);
+                }
+                let ident = self.parse_identifier_name();
+                PropertyKey::StaticIdentifier(self.alloc(ident))
+            }
+            _ => return self.unexpected(),
+        };
+        self.expect(Kind::Colon);
+        let value = self.parse_json_value(options);
+        self.ast.object_property_kind_object_property(
+            self.end_span(span),
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn parse_json_array(&mut self, options: JsonOptions) -> Expression<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LBrack);
+        let (elements, trailing_comma) =
+            self.parse_delimited_list(Kind::RBrack, Kind::Comma, |p| {
+                ArrayExpressionElement::from(p.parse_json_value(options))
+            });
+        self.check_json_trailing_comma(trailing_comma, options);
+        self.expect(Kind::RBrack);
+        self.ast.expression_array(self.end_span(span), elements)
+    }
+
+    /// `-1` in all dialects, and `+1`, `-Infinity` or `+NaN` in JSON5.
+    fn parse_json_signed_number(&mut self, options: JsonOptions) -> Expression<'a> {
+        let span = self.start_span();
+        let operator = if self.at(Kind::Minus) {
+            UnaryOperator::UnaryNegation
+        } else {
+            if !options.json5 {
+                return self.unexpected();
+            }
+            UnaryOperator::UnaryPlus
+        };
+        self.bump_any();
+        // The sign is part of the number, so it can't be followed by whitespace or comments
+        if self.cur_token().start() != self.prev_token_end {
+            return self.unexpected();
+        }
+        let argument = match self.cur_kind() {
+            kind if kind.is_number() => self.parse_json_number(options),
+            Kind::Ident if options.json5 && matches!(self.cur_src(), "Infinity" | "NaN") => {
+                self.parse_json_value(options)
+            }
+            _ => return self.unexpected(),
+        };
+        self.ast.expression_unary(self.end_span(span), operator, argument)
+    }
+
+    fn parse_json_number(&mut self, options: JsonOptions) -> Expression<'a> {
+        let span = self.cur_token().span();
+        let valid = if options.json5 {
+            is_json5_number(self.cur_src())
+        } else {
+            is_json_number(self.cur_src())
+        };
+        if !valid {
+            self.error(diagnostics::invalid_json_number(options.dialect(), span));
+            // Don't parse bigints or numbers with separators as numbers
+            self.bump_any();
+            return self.ast.expression_numeric_literal(span, 0.0, None, NumberBase::Decimal);
+        }
+        let lit = self.parse_literal_number();
+        Expression::NumericLiteral(self.alloc(lit))
+    }
+
+    /// Check that the current string token is a valid string in JSON or JSONC: double-quoted,
+    /// without control characters, and with only the escape sequences of JSON.
+    /// Strings in JSON5 are strings in JavaScript, apart from template literals.
+    fn check_json_string(&mut self, options: JsonOptions) {
+        if options.json5 {
+            return;
+        }
+        let span = self.cur_token().span();
+        let raw = self.cur_src();
+        if raw.starts_with('\'') {
+            let error = diagnostics::json_syntax("Single-quoted strings", options.dialect(), span);
+            self.error(error);
+            return;
+        }
+        let mut chars = raw.get(1..raw.len() - 1).unwrap_or_default().chars();
+        while let Some(ch) = chars.next() {
+            let valid = match ch {
+                '\\' => match chars.next() {
+                    Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => true,
+                    Some('u') => chars.by_ref().take(4).all(|ch| ch.is_ascii_hexdigit()),
+                    _ => false,
+                },
+                ch => ch > '\u{1f}',
+            };
+            if !valid {
+                self.error(diagnostics::invalid_json_string(options.dialect(), span));
+                return;
+            }
+        }
+    }
+
+    fn check_json_trailing_comma(&mut self, trailing_comma: Option<u32>, options: JsonOptions) {
+        if let Some(comma) = trailing_comma {
+            if !options.allows_comments_and_trailing_commas() {
+                let span = Span::sized(comma, 1);
+                self.error(diagnostics::json_syntax("Trailing commas", options.dialect(), span));
+            }
+        }
+    }
+}
+
+/// `0`, `-0.5` or `1e10`, without the sign.
+/// <https://www.json.org/json-en.html>
+fn is_json_number(s: &str) -> bool {
+    let s = s.as_bytes();
+    let mut i = match s {
+        [b'0', ..] => 1,
+        [b'1'..=b'9', ..] => digits(s, 0),
+        _ => return false,
+    };
+    if s.get(i) == Some(&b'.') {
+        let end = digits(s, i + 1);
+        if end == i + 1 {
+            return false;
+        }
+        i = end;
+    }
+    exponent(s, i) == Some(s.len())
+}
+
+/// Numbers of JSON, `0x1F`, `.5` and `5.`, without the sign.
+/// <https://spec.json5.org/#numbers>
+fn is_json5_number(s: &str) -> bool {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    }
+    let s = s.as_bytes();
+    let mut i = match s {
+        [b'0', ..] => 1,
+        [b'1'..=b'9', ..] => digits(s, 0),
+        [b'.', b'0'..=b'9', ..] => 0,
+        _ => return false,
+    };
+    if s.get(i) == Some(&b'.') {
+        i = digits(s, i + 1);
+    }
+    exponent(s, i) == Some(s.len())
+}
+
+/// End of the ASCII digits starting at `i`.
+fn digits(s: &[u8], i: usize) -> usize {
+    i + s[i.min(s.len())..].iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// End of the optional exponent starting at `i`, or `None` if it has no digits.
+fn exponent(s: &[u8], i: usize) -> Option<usize> {
+    if !matches!(s.get(i), Some(b'e' | b'E')) {
+        return Some(i);
+    }
+    let start = if matches!(s.get(i + 1), Some(b'+' | b'-')) { i + 2 } else { i + 1 };
+    let end = digits(s, start);
+    (end > start).then_some(end)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Expression;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::JsonOptions;
+    use crate::Parser;
+
+    const JSONC: JsonOptions = JsonOptions { jsonc: true, json5: false };
+    const JSON5: JsonOptions = JsonOptions { jsonc: false, json5: true };
+
+    fn is_valid(source_text: &str, options: JsonOptions) -> bool {
+        let allocator = Allocator::default();
+        Parser::new(&allocator, source_text, SourceType::default()).parse_json(options).is_ok()
+    }
+
+    #[test]
+    fn json() {
+        let allocator = Allocator::default();
+        let source_text = r#" { "a": [1, -2.5e3, true, null, "\u00e9\n"], "b": {} } "#;
+        let value = Parser::new(&allocator, source_text, SourceType::default())
+            .parse_json(JsonOptions::default())
+            .unwrap();
+        let Expression::ObjectExpression(object) = &value else { panic!("{value:?}") };
+        assert_eq!(object.span.source_text(source_text), &source_text[1..source_text.len() - 1]);
+        assert_eq!(object.properties.len(), 2);
+        let a = object.properties[0].as_property().unwrap();
+        assert_eq!(a.key.static_name().as_deref(), Some("a"));
+        let Expression::ArrayExpression(array) = &a.value else { panic!("{:?}", a.value) };
+        assert_eq!(array.elements[1].span().source_text(source_text), "-2.5e3");
+        let Some(Expression::StringLiteral(string)) = array.elements[4].as_expression() else {
+            panic!("{:?}", array.elements[4])
+        };
+        assert_eq!(string.value, "é\n");
+
+        for valid in ["0", "-0", "1.5", "1E+2", "\"\"", "[]", "{\"a\":{\"b\":[[]]}}", " \n\t\r[1]"]
+        {
+            assert!(is_valid(valid, JsonOptions::default()), "{valid}");
+        }
+        for invalid in [
+            "",
+            "[1,]",
+            "{\"a\":1,}",
+            "// a\n1",
+            "{a:1}",
+            "'a'",
+            "\"\\x41\"",
+            "\"\t\"",
+            "01",
+            ".5",
+            "5.",
+            "0x1",
+            "1_000",
+            "1n",
+            "+1",
+            "- 1",
+            "Infinity",
+            "undefined",
+            "[1] [2]",
+            "`a`",
+        ] {
+            assert!(!is_valid(invalid, JsonOptions::default()), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn jsonc() {
+        assert!(is_valid("// a\n{ /* b */ \"a\": [1, 2,], }", JSONC));
+        assert!(!is_valid("{a: 1}", JSONC));
+        assert!(!is_valid("[1,,]", JSONC));
+    }
+
+    #[test]
+    fn json5() {
+        let source_text = "// a\n{ unquoted: 'single', hex: 0xFF, half: .5, five: 5., pos: +1, inf: -Infinity, nan: NaN, 'a': [1,], }";
+        assert!(is_valid(source_text, JSON5));
+        for invalid in ["0b1", "0o7", "1_000", "1n", "undefined", "`a`", "+ 1", "{a: 1,,}"] {
+            assert!(!is_valid(invalid, JSON5), "{invalid}");
+        }
+    }
+}
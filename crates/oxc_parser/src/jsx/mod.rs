@@ -239,6 +239,10 @@ impl<'a> ParserImpl<'a> {
     ///   `JSXFragment`
     ///   { `JSXChildExpression_opt` }
     fn parse_jsx_child(&mut self) -> Option<JSXChild<'a>> {
+        self.nested(Self::parse_jsx_child_impl)
+    }
+
+    fn parse_jsx_child_impl(&mut self) -> Option<JSXChild<'a>> {
         match self.cur_kind() {
             Kind::LAngle => {
                 let span = self.start_span();
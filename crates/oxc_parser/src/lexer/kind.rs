@@ -1,5 +1,7 @@
 //! ECMAScript Token Kinds
 
+#![cfg_attr(not(feature = "benchmarking"), expect(missing_docs))] // fixme
+
 use std::fmt::{self, Display};
 
 /// Lexer token kind
@@ -137,6 +139,7 @@ pub enum Kind {
     Pipe2,
     Pipe2Eq,
     PipeEq,
+    PipeGt, // |>
     Plus,
     Plus2,
     PlusEq,
@@ -630,6 +633,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            PipeGt => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
@@ -154,6 +154,16 @@ impl<'a> Lexer<'a> {
         self.token = checkpoint.token;
     }
 
+    /// Move to `offset` in the source text, to lex from there as if a token ended just before it.
+    ///
+    /// # Panics
+    /// Panics if `offset` is out of bounds, or not on a UTF-8 character boundary.
+    pub fn seek(&mut self, offset: u32) {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.trivia_builder.handle_seek();
+    }
+
     pub fn peek_token(&mut self) -> Token {
         let checkpoint = self.checkpoint();
         let token = self.next_token();
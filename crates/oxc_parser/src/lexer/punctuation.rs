@@ -64,6 +64,13 @@ impl Lexer<'_> {
         self.finish_next(kind)
     }
 
+    /// Re-tokenize the current `|` token for `|>` of [`crate::ParseOptions::experimental_pipeline`]
+    pub(crate) fn re_lex_pipe_gt(&mut self) -> Token {
+        self.token.set_start(self.offset() - 1);
+        let kind = if self.next_ascii_byte_eq(b'>') { Kind::PipeGt } else { Kind::Pipe };
+        self.finish_next(kind)
+    }
+
     fn read_right_angle(&mut self) -> Kind {
         if self.next_ascii_byte_eq(b'>') {
             if self.next_ascii_byte_eq(b'>') {
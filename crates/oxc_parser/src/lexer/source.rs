@@ -149,6 +149,18 @@ impl<'a> Source<'a> {
         self.ptr == self.end
     }
 
+    /// Move current position to `offset` bytes from start of source.
+    ///
+    /// # Panics
+    /// Panics if `offset` is out of bounds, or not on a UTF-8 character boundary.
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        let offset = offset as usize;
+        assert!(self.whole().is_char_boundary(offset));
+        // SAFETY: `is_char_boundary` returns `false` if `offset` is after the end of source,
+        // so `offset` is in bounds, and on a UTF-8 character boundary
+        self.ptr = unsafe { self.start.add(offset) };
+    }
+
     /// Get current position.
     ///
     /// The `SourcePosition` returned is guaranteed to be within bounds of `&str` that `Source`
@@ -40,6 +40,7 @@ const _: () = {
     assert!(is_valid_shift(HAS_SEPARATOR_SHIFT));
 };
 
+/// A token of the source text: its kind, span and flags.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Token(u128);
@@ -86,11 +87,13 @@ impl Token {
 
 // Getters and setters
 impl Token {
+    /// Span of the token.
     #[inline]
     pub fn span(&self) -> Span {
         Span::new(self.start(), self.end())
     }
 
+    /// Start offset of the token.
     #[inline]
     pub fn start(&self) -> u32 {
         ((self.0 >> START_SHIFT) & START_MASK) as u32
@@ -102,6 +105,7 @@ impl Token {
         self.0 |= u128::from(start) << START_SHIFT;
     }
 
+    /// End offset of the token.
     #[inline]
     pub fn end(&self) -> u32 {
         ((self.0 >> END_SHIFT) & END_MASK) as u32
@@ -115,6 +119,7 @@ impl Token {
         self.0 |= u128::from(end) << END_SHIFT;
     }
 
+    /// Kind of the token.
     #[inline]
     pub fn kind(&self) -> Kind {
         // SAFETY: `Kind` is `#[repr(u8)]`. Only `Token::default` and `Token::set_kind` set these bits,
@@ -129,6 +134,7 @@ impl Token {
         self.0 |= u128::from(kind as u8) << KIND_SHIFT;
     }
 
+    /// Whether there is a line break before the token.
     #[inline]
     pub fn is_on_new_line(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -143,6 +149,7 @@ impl Token {
         self.0 |= u128::from(value) << IS_ON_NEW_LINE_SHIFT;
     }
 
+    /// Whether the token contains escape sequences, e.g. `\u0061` or `\n`.
     #[inline]
     pub fn escaped(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -157,6 +164,7 @@ impl Token {
         self.0 |= u128::from(escaped) << ESCAPED_SHIFT;
     }
 
+    /// Whether the string or template token contains lone surrogates.
     #[inline]
     pub fn lone_surrogates(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
@@ -171,6 +179,7 @@ impl Token {
         self.0 |= u128::from(value) << LONE_SURROGATES_SHIFT;
     }
 
+    /// Whether the numeric literal token contains `_` separators.
     #[inline]
     pub fn has_separator(&self) -> bool {
         // Use a pointer read rather than arithmetic as it produces less instructions.
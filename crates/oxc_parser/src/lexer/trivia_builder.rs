@@ -91,6 +91,13 @@ impl TriviaBuilder {
         self.saw_newline = false;
     }
 
+    /// Continue after a token which is not `=` or `(`, e.g. when the lexer moves to the start of a
+    /// statement.
+    pub fn handle_seek(&mut self) {
+        self.saw_newline = false;
+        self.previous_kind = Kind::Undetermined;
+    }
+
     /// Determines if the current line comment should be treated as a trailing comment.
     ///
     /// A line comment should be treated as trailing when both of the following conditions are met:
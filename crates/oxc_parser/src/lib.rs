@@ -69,9 +69,12 @@
 mod context;
 mod cursor;
 mod error_handler;
+mod incremental;
+mod json;
 mod modifiers;
 mod module_record;
 mod state;
+mod tokens;
 
 mod js;
 mod jsx;
@@ -86,19 +89,26 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
+use oxc_allocator::{Allocator, Box as ArenaBox, Dummy, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
-    ast::{Expression, Program},
+    ast::{Expression, FunctionBody, Program, Statement},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{ModuleKind, SourceType, Span};
 use oxc_syntax::module_record::ModuleRecord;
 
+pub use crate::{
+    incremental::TextEdit,
+    json::JsonOptions,
+    lexer::{Kind, Token},
+    tokens::Tokens,
+};
+
 use crate::{
     context::{Context, StatementContext},
     error_handler::FatalError,
-    lexer::{Lexer, Token},
+    lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
@@ -180,6 +190,16 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// Spans of the function bodies which were not parsed, in source order.
+    /// Empty unless [`ParseOptions::lazy_function_bodies`] is enabled.
+    ///
+    /// Parse them with [`Parser::parse_lazy_function_body`].
+    pub lazy_function_bodies: Vec<Span>,
+
+    /// All tokens of the source text, with the trivia between them.
+    /// Empty unless [`ParseOptions::preserve_trivia`] is enabled.
+    pub tokens: Tokens<'a>,
 }
 
 /// Parse options
@@ -221,6 +241,82 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Maximum nesting depth of statements, expressions, types, binding patterns and JSX elements.
+    ///
+    /// Deeper nesting is a fatal error, instead of a stack overflow. Use it to parse untrusted code,
+    /// with a limit which fits in the stack of the thread. A level of nesting in the source, e.g.
+    /// `[` or `{`, is counted as one or more levels, depending on the grammar it is nested in.
+    ///
+    /// Default: `None` (no limit)
+    pub max_nesting_depth: Option<u32>,
+
+    /// Maximum length of the source text, in bytes.
+    ///
+    /// Longer source text is a fatal error, and is not parsed.
+    ///
+    /// Default: `None` (the limit of 4 GiB)
+    pub max_source_length: Option<usize>,
+
+    /// Skip the bodies of functions, methods and arrow functions, instead of parsing them.
+    ///
+    /// Skipped bodies are [`FunctionBody`] nodes without statements, and their spans are in
+    /// [`ParserReturn::lazy_function_bodies`], to be parsed on demand with
+    /// [`Parser::parse_lazy_function_body`]. This is much faster for tools which only need the
+    /// structure of a module, e.g. scanning imports and exports, or emitting declarations.
+    ///
+    /// Syntax errors in skipped bodies are not reported, and the dynamic imports and `import.meta`
+    /// in them are not in the [`ModuleRecord`]. Bodies which contain JSX are parsed as usual.
+    ///
+    /// Default: `false`
+    pub lazy_function_bodies: bool,
+
+    /// Record all tokens of the source text in [`ParserReturn::tokens`], for a lossless view of
+    /// the source text alongside the AST.
+    ///
+    /// Every AST node can then be split into its tokens, and the whitespace and comments around
+    /// them, e.g. for formatters and codemods which must print untouched code exactly as written.
+    ///
+    /// Default: `false`
+    pub preserve_trivia: bool,
+
+    /// Parse decorators as specified by the [decorators proposal], instead of accepting both
+    /// TC39 and legacy TypeScript decorators.
+    ///
+    /// The expression of a decorator must be a member access like `@a.b.#c`, a call of one like
+    /// `@a.b(c)`, or be wrapped in parentheses like `@(a[b])`. Parameter decorators are an error
+    /// in TypeScript too, and `accessor` must be on the same line as the name of the field.
+    ///
+    /// The AST is the same in both modes: decorators are in the `decorators` of [`Class`],
+    /// [`MethodDefinition`], [`PropertyDefinition`] and [`AccessorProperty`], in source order,
+    /// and `accessor` fields are [`AccessorProperty`] nodes. Decorator metadata has no syntax of
+    /// its own, it's added by the transformer when lowering decorated classes.
+    ///
+    /// Default: `false`
+    ///
+    /// [decorators proposal]: https://github.com/tc39/proposal-decorators
+    /// [`Class`]: oxc_ast::ast::Class
+    /// [`MethodDefinition`]: oxc_ast::ast::MethodDefinition
+    /// [`PropertyDefinition`]: oxc_ast::ast::PropertyDefinition
+    /// [`AccessorProperty`]: oxc_ast::ast::AccessorProperty
+    pub stage3_decorators: bool,
+
+    /// Parse the `|>` operator of the [Hack-style pipeline proposal], e.g. `value |> f(%)`.
+    ///
+    /// A pipe expression is a [`PipelineExpression`], and `%` in its body is a [`TopicReference`]
+    /// to its head. `|>` has the same precedence as `=>`, so `a |> f(%) |> g(%)` nests to the
+    /// right. The pipe body must use `%`, and can't be an unparenthesized arrow function,
+    /// assignment, conditional or `yield`. Without this option, `|>` is lexed as `|` and `>`.
+    ///
+    /// If [`ParseOptions::allow_v8_intrinsics`] is enabled too, `%` outside of pipe bodies is
+    /// parsed as a V8 intrinsic.
+    ///
+    /// Default: `false`
+    ///
+    /// [Hack-style pipeline proposal]: https://github.com/tc39/proposal-pipeline-operator
+    /// [`PipelineExpression`]: oxc_ast::ast::PipelineExpression
+    /// [`TopicReference`]: oxc_ast::ast::TopicReference
+    pub experimental_pipeline: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,10 +327,37 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            max_nesting_depth: None,
+            max_source_length: None,
+            lazy_function_bodies: false,
+            preserve_trivia: false,
+            stage3_decorators: false,
+            experimental_pipeline: false,
         }
     }
 }
 
+/// Grammar parameters of code which is parsed in isolation, but belongs inside a function,
+/// e.g. the code of a template macro or a REPL input.
+///
+/// You may provide the context to the [`Parser`] using [`Parser::with_context`].
+///
+/// Strict mode is not part of the context: the parser accepts the code of both modes,
+/// and errors specific to strict mode are reported by semantic analysis.
+/// Module code is selected with the [`SourceType`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParseContext {
+    /// Parse `await` as an [`AwaitExpression`], as inside an async function.
+    ///
+    /// [`AwaitExpression`]: oxc_ast::ast::AwaitExpression
+    pub r#await: bool,
+
+    /// Parse `yield` as a [`YieldExpression`], as inside a generator function.
+    ///
+    /// [`YieldExpression`]: oxc_ast::ast::YieldExpression
+    pub r#yield: bool,
+}
+
 /// Recursive Descent Parser for ECMAScript and TypeScript
 ///
 /// See [`Parser::parse`] for entry function.
@@ -243,6 +366,7 @@ pub struct Parser<'a> {
     source_text: &'a str,
     source_type: SourceType,
     options: ParseOptions,
+    context: Option<ParseContext>,
 }
 
 impl<'a> Parser<'a> {
@@ -254,7 +378,7 @@ impl<'a> Parser<'a> {
     /// - `source_type`: Source type (e.g. JavaScript, TypeScript, JSX, ESM Module, Script)
     pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
         let options = ParseOptions::default();
-        Self { allocator, source_text, source_type, options }
+        Self { allocator, source_text, source_type, options, context: None }
     }
 
     /// Set parse options
@@ -263,6 +387,14 @@ impl<'a> Parser<'a> {
         self.options = options;
         self
     }
+
+    /// Set the grammar parameters of the code, instead of those of the top level of a
+    /// script or module.
+    #[must_use]
+    pub fn with_context(mut self, context: ParseContext) -> Self {
+        self.context = Some(context);
+        self
+    }
 }
 
 mod parser_parse {
@@ -318,6 +450,56 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse the source text after `edits` of the source text of a previous parse, reusing
+        /// the top-level statements of `old` which are not changed by the edits.
+        ///
+        /// This is for editors and language servers, where a small region of a large file
+        /// changes between parses. The reused statements are moved into the new [`Program`],
+        /// and the spans after the edits are moved by the change of length, so the result is the
+        /// same as [`Parser::parse`] of the new source text.
+        ///
+        /// `edits` replace spans of the previous source text, in order, without overlapping.
+        /// `old` must be parsed from the same allocator, with the same source type and options.
+        ///
+        /// The source text is parsed from scratch when the statements can't be reused: if `old`
+        /// has errors, the edits don't produce the new source text, the edits change the first
+        /// statement, the directives or the hashbang, the source type is unambiguous, or the new
+        /// source text has errors.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TextEdit};
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::mjs();
+        /// let old = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 3;", source_type).parse();
+        /// let edits = [TextEdit::new(Span::new(19, 20), "42")];
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 42;\nlet c = 3;", source_type)
+        ///     .parse_incremental(old, &edits);
+        /// assert_eq!(ret.program.body.len(), 3);
+        /// ```
+        pub fn parse_incremental(
+            self,
+            old: ParserReturn<'a>,
+            edits: &[TextEdit<'_>],
+        ) -> ParserReturn<'a> {
+            let ret = {
+                let unique = UniquePromise::new();
+                let parser = ParserImpl::new(
+                    self.allocator,
+                    self.source_text,
+                    self.source_type,
+                    self.options,
+                    unique,
+                );
+                parser.parse_incremental(old, edits)
+            };
+            ret.unwrap_or_else(|| self.parse())
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -346,7 +528,169 @@ mod parser_parse {
                 self.options,
                 unique,
             );
-            parser.parse_expression()
+            parser.with_context(self.context).parse_expression()
+        }
+
+        /// Parse JSON into an [`Expression`] of objects, arrays and literals, with spans.
+        ///
+        /// With [`JsonOptions::jsonc`] or [`JsonOptions::json5`], comments and the syntax of
+        /// those dialects are allowed. The source type and options of the parser are ignored,
+        /// apart from [`ParseOptions::max_nesting_depth`].
+        ///
+        /// Negative numbers are [`UnaryExpression`]s, and `Infinity` and `NaN` in JSON5 are
+        /// [`IdentifierReference`]s.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::Expression;
+        /// use oxc_parser::{JsonOptions, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let options = JsonOptions { jsonc: true, ..JsonOptions::default() };
+        /// let value = Parser::new(&allocator, "{ \"a\": 1, /* b */ }", SourceType::default())
+        ///     .parse_json(options)
+        ///     .unwrap();
+        /// assert!(matches!(value, Expression::ObjectExpression(_)));
+        /// ```
+        ///
+        /// # Errors
+        /// If the source text is not valid JSON, or JSON of the dialect of `options`.
+        ///
+        /// [`UnaryExpression`]: oxc_ast::ast::UnaryExpression
+        /// [`IdentifierReference`]: oxc_ast::ast::IdentifierReference
+        pub fn parse_json(
+            self,
+            options: JsonOptions,
+        ) -> Result<Expression<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_json(options)
+        }
+
+        /// Parse a list of [`Statement`]s, e.g. the body of a block. Directives such as
+        /// `"use strict"` are parsed as expression statements.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{ParseContext, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let statements = Parser::new(&allocator, "const x = await f(); x", SourceType::mjs())
+        ///     .with_context(ParseContext { r#await: true, ..ParseContext::default() })
+        ///     .parse_statements()
+        ///     .unwrap();
+        /// assert_eq!(statements.len(), 2);
+        /// ```
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors.
+        pub fn parse_statements(self) -> Result<ArenaVec<'a, Statement<'a>>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.with_context(self.context).parse_statements()
+        }
+
+        /// Parse the body of a function, without its braces.
+        ///
+        /// `return` statements are allowed, and a directive prologue is parsed into
+        /// [`FunctionBody::directives`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{ParseContext, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let body = Parser::new(&allocator, "'use strict'; yield 1; return 2", SourceType::cjs())
+        ///     .with_context(ParseContext { r#yield: true, ..ParseContext::default() })
+        ///     .parse_function_body()
+        ///     .unwrap();
+        /// assert_eq!(body.directives.len(), 1);
+        /// assert_eq!(body.statements.len(), 2);
+        /// ```
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors.
+        pub fn parse_function_body(
+            self,
+        ) -> Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.with_context(self.context).parse_function_body_only()
+        }
+
+        /// Parse a function body which was skipped by [`ParseOptions::lazy_function_bodies`],
+        /// from its span in [`ParserReturn::lazy_function_bodies`].
+        ///
+        /// The source text must be the whole source text of the skipped body's parse. Spans of
+        /// the returned body are offsets in it, like in the rest of the AST. Functions nested in
+        /// the body are parsed too. Use [`Parser::with_context`] to parse the body of an `async`
+        /// function or a generator.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{ParseOptions, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_text = "export function f() { return 1; }";
+        /// let options = ParseOptions { lazy_function_bodies: true, ..ParseOptions::default() };
+        /// let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        ///     .with_options(options)
+        ///     .parse();
+        /// assert_eq!(ret.lazy_function_bodies.len(), 1);
+        ///
+        /// let body = Parser::new(&allocator, source_text, SourceType::mjs())
+        ///     .parse_lazy_function_body(ret.lazy_function_bodies[0])
+        ///     .unwrap();
+        /// assert_eq!(body.statements.len(), 1);
+        /// ```
+        ///
+        /// # Errors
+        /// If the body has syntax errors.
+        pub fn parse_lazy_function_body(
+            self,
+            span: Span,
+        ) -> Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            // The body of a function which is neither `async` nor a generator by default
+            let context = self.context.unwrap_or_default();
+            parser.with_context(Some(context)).parse_lazy_function_body(span)
         }
     }
 }
@@ -391,6 +735,12 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Current nesting depth, see [`ParseOptions::max_nesting_depth`]
+    depth: u32,
+
+    /// Consumed tokens, see [`ParseOptions::preserve_trivia`]
+    tokens: Vec<Token>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -406,7 +756,7 @@ impl<'a> ParserImpl<'a> {
         options: ParseOptions,
         unique: UniquePromise,
     ) -> Self {
-        Self {
+        let mut parser = Self {
             options,
             lexer: Lexer::new(allocator, source_text, source_type, unique),
             source_type,
@@ -420,7 +770,15 @@ impl<'a> ParserImpl<'a> {
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
             is_ts: source_type.is_typescript(),
+            depth: 0,
+            tokens: vec![],
+        };
+        if let Some(max_source_length) = options.max_source_length {
+            if source_text.len() > max_source_length {
+                parser.set_fatal_error(diagnostics::source_too_long(max_source_length));
+            }
         }
+        parser
     }
 
     /// Main entry point
@@ -486,6 +844,13 @@ impl<'a> ParserImpl<'a> {
             };
         }
 
+        let mut lazy_function_bodies = vec![];
+        if !panicked {
+            lazy_function_bodies.extend(self.state.lazy_function_bodies.into_values());
+            lazy_function_bodies.sort_unstable_by_key(|span| span.start);
+        }
+        let tokens = Tokens::new(self.source_text, if panicked { vec![] } else { self.tokens });
+
         ParserReturn {
             program,
             module_record,
@@ -493,13 +858,75 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            lazy_function_bodies,
+            tokens,
         }
     }
 
+    /// Replace the `[Await]` and `[Yield]` parameters of the default context.
+    fn with_context(mut self, context: Option<ParseContext>) -> Self {
+        if let Some(context) = context {
+            self.ctx = self.ctx.and_await(context.r#await).and_yield(context.r#yield);
+        }
+        self
+    }
+
     pub fn parse_expression(mut self) -> Result<Expression<'a>, Vec<OxcDiagnostic>> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
         let expr = self.parse_expr();
+        self.finish_isolated(expr)
+    }
+
+    pub fn parse_statements(mut self) -> Result<ArenaVec<'a, Statement<'a>>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let mut statements = self.ast.vec();
+        while !self.at(Kind::Eof) && !self.has_fatal_error() {
+            statements.push(self.parse_statement_list_item(StatementContext::StatementList));
+        }
+        self.finish_isolated(statements)
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn parse_function_body_only(
+        mut self,
+    ) -> Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let (directives, statements) = self.context(Context::Return, Context::empty(), |p| {
+            p.parse_directives_and_statements(/* is_top_level */ false)
+        });
+        // `parse_directives_and_statements` stops at an unmatched `}`
+        if !self.has_fatal_error() {
+            self.expect(Kind::Eof);
+        }
+        let span = Span::new(0, self.source_text.len() as u32);
+        let body = self.ast.alloc_function_body(span, directives, statements);
+        self.finish_isolated(body)
+    }
+
+    pub fn parse_lazy_function_body(
+        mut self,
+        span: Span,
+    ) -> Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+        if span.end as usize > self.source_text.len()
+            || !self.source_text.is_char_boundary(span.start as usize)
+        {
+            return Err(vec![diagnostics::unexpected_token(span)]);
+        }
+        self.lexer.seek(span.start);
+        self.bump_any();
+        self.options.lazy_function_bodies = false;
+        let body = self.parse_function_body_eagerly();
+        if self.fatal_error.is_none() && body.span != span {
+            self.error(diagnostics::unexpected_token(body.span));
+        }
+        self.finish_isolated(body)
+    }
+
+    /// Return `value`, or the errors found while parsing it.
+    fn finish_isolated<T>(mut self, value: T) -> Result<T, Vec<OxcDiagnostic>> {
         if let Some(FatalError { error, .. }) = self.fatal_error.take() {
             return Err(vec![error]);
         }
@@ -508,7 +935,7 @@ impl<'a> ParserImpl<'a> {
         if !errors.is_empty() {
             return Err(errors);
         }
-        Ok(expr)
+        Ok(value)
     }
 
     #[expect(clippy::cast_possible_truncation)]
@@ -578,6 +1005,26 @@ impl<'a> ParserImpl<'a> {
         None
     }
 
+    /// Parse a nested node with `parse`, or return a dummy node with a fatal error if
+    /// [`ParseOptions::max_nesting_depth`] is exceeded.
+    #[inline]
+    fn nested<T: Dummy<'a>>(&mut self, parse: impl FnOnce(&mut Self) -> T) -> T {
+        if self.options.max_nesting_depth.is_some_and(|max| self.depth >= max) {
+            return self.nesting_too_deep();
+        }
+        self.depth += 1;
+        let node = parse(self);
+        self.depth -= 1;
+        node
+    }
+
+    #[cold]
+    fn nesting_too_deep<T: Dummy<'a>>(&mut self) -> T {
+        let max = self.options.max_nesting_depth.unwrap_or_default();
+        let error = diagnostics::nesting_too_deep(max, self.cur_token().span());
+        self.fatal_error(error)
+    }
+
     #[inline]
     fn alloc<T>(&self, value: T) -> ArenaBox<'a, T> {
         self.ast.alloc(value)
@@ -588,7 +1035,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{ClassElement, CommentKind, Expression, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -613,6 +1060,51 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn parse_with_context() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::cjs();
+        let async_generator = ParseContext { r#await: true, r#yield: true };
+
+        let expr = Parser::new(&allocator, "await x", source_type)
+            .with_context(async_generator)
+            .parse_expression()
+            .unwrap();
+        assert!(matches!(expr, Expression::AwaitExpression(_)));
+        // `await` is an identifier in scripts
+        let expr = Parser::new(&allocator, "await", source_type).parse_expression().unwrap();
+        assert!(matches!(expr, Expression::Identifier(_)));
+
+        let statements =
+            Parser::new(&allocator, "'use strict'; yield 1; const y = 2;", source_type)
+                .with_context(async_generator)
+                .parse_statements()
+                .unwrap();
+        assert_eq!(statements.len(), 3);
+        assert!(
+            matches!(&statements[1], Statement::ExpressionStatement(stmt) if matches!(stmt.expression, Expression::YieldExpression(_)))
+        );
+        // `return` is only allowed in function bodies
+        assert!(Parser::new(&allocator, "return 1", source_type).parse_statements().is_err());
+        assert!(Parser::new(&allocator, "}", source_type).parse_statements().is_err());
+
+        let body = Parser::new(&allocator, "'use strict'; return await x", source_type)
+            .with_context(async_generator)
+            .parse_function_body()
+            .unwrap();
+        assert_eq!(body.directives.len(), 1);
+        assert_eq!(body.statements.len(), 1);
+        assert_eq!(body.span, Span::new(0, 28));
+        assert!(Parser::new(&allocator, "return 1 }", source_type).parse_function_body().is_err());
+        // `await` is not allowed in the body of a non-async function of a module
+        assert!(
+            Parser::new(&allocator, "await x", SourceType::mjs())
+                .with_context(ParseContext::default())
+                .parse_function_body()
+                .is_err()
+        );
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
@@ -767,6 +1259,229 @@ mod test {
         }
     }
 
+    #[test]
+    fn lazy_function_bodies() {
+        let allocator = Allocator::default();
+        let options = ParseOptions { lazy_function_bodies: true, ..ParseOptions::default() };
+        let source_text = "import a from 'a';
+function f(x) { return /}/.test(`${ { x }.x }`) ? x / 2 : {}; }
+export const g = async () => { await a; };
+class C { m() { if (a) { return '}'; } } }";
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 4);
+        assert_eq!(ret.module_record.requested_modules.len(), 1);
+        let bodies = ret
+            .lazy_function_bodies
+            .iter()
+            .map(|span| span.source_text(source_text))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bodies,
+            [
+                "{ return /}/.test(`${ { x }.x }`) ? x / 2 : {}; }",
+                "{ await a; }",
+                "{ if (a) { return '}'; } }"
+            ]
+        );
+
+        let parse_body = |span, context| {
+            let parser = Parser::new(&allocator, source_text, SourceType::mjs());
+            parser.with_context(context).parse_lazy_function_body(span).unwrap()
+        };
+        let body = parse_body(ret.lazy_function_bodies[0], ParseContext::default());
+        assert_eq!(body.span, ret.lazy_function_bodies[0]);
+        assert_eq!(body.statements.len(), 1);
+        let context = ParseContext { r#await: true, ..ParseContext::default() };
+        let body = parse_body(ret.lazy_function_bodies[1], context);
+        assert!(matches!(body.statements[0], Statement::ExpressionStatement(_)));
+        let error = Parser::new(&allocator, source_text, SourceType::mjs())
+            .parse_lazy_function_body(Span::new(0, 6))
+            .unwrap_err();
+        assert_eq!(error.len(), 1);
+
+        // JSX can't be skipped without parsing it
+        let ret =
+            Parser::new(&allocator, "function f() { return <p>'{x}</p>; }", SourceType::jsx())
+                .with_options(options)
+                .parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.lazy_function_bodies.is_empty());
+
+        // `/` after `)` or `}` may start a regular expression, so these bodies are parsed eagerly
+        for source_text in [
+            "function f(a) { if (a) /}/.test(a); }",
+            "function f() { {} /}/; }",
+            "function f(a) { return f(a) / 2; }",
+            "function f() { return {} / 2; }",
+        ] {
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+                .with_options(options)
+                .parse();
+            assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+            assert!(ret.lazy_function_bodies.is_empty(), "{source_text}");
+            let Statement::FunctionDeclaration(func) = &ret.program.body[0] else { unreachable!() };
+            assert!(!func.body.as_ref().unwrap().statements.is_empty(), "{source_text}");
+        }
+    }
+
+    #[test]
+    fn max_source_length() {
+        let allocator = Allocator::default();
+        let options = ParseOptions { max_source_length: Some(8), ..ParseOptions::default() };
+
+        let ret = Parser::new(&allocator, "let x = 1;", SourceType::default())
+            .with_options(options)
+            .parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Source length exceeds the limit of 8 bytes");
+        assert!(
+            Parser::new(&allocator, "let x = 1;", SourceType::default())
+                .with_options(options)
+                .parse_expression()
+                .is_err()
+        );
+
+        let ret =
+            Parser::new(&allocator, "let x;", SourceType::default()).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn stage3_decorators() {
+        let allocator = Allocator::default();
+        let options = ParseOptions { stage3_decorators: true, ..ParseOptions::default() };
+        let parse = |source: &str, source_type: SourceType| {
+            Parser::new(&allocator, source, source_type).with_options(options).parse()
+        };
+
+        for source in [
+            "@a @a.b.c @a.b(c) @(a[b]) @(a.b()) class A { @a.#b m() {} @a accessor x = 1; }",
+            "export @a class A { static accessor #x; @a accessor [b]; }",
+            "@a export default class {}",
+            "(@a class {})",
+        ] {
+            let ret = parse(source, SourceType::mjs());
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        for source in ["@a().b class A {}", "@a()() class A {}", "@this.a class A {}"] {
+            let ret = parse(source, SourceType::mjs());
+            assert_eq!(ret.errors.len(), 1, "{source}: {:?}", ret.errors);
+            assert_eq!(ret.errors[0].to_string(), "Invalid decorator expression.");
+            // Legacy decorators allow any left-hand-side expression
+            let ret = Parser::new(&allocator, source, SourceType::ts()).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let source = "class A { m(@a b) {} }";
+        let ret = parse(source, SourceType::ts());
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Decorators are not valid here.");
+        assert!(Parser::new(&allocator, source, SourceType::ts()).parse().errors.is_empty());
+
+        // `accessor` followed by a line break is a field named `accessor`
+        let ret = parse("class A { accessor\n x }", SourceType::mjs());
+        assert!(ret.errors.is_empty());
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(class.body.body.len(), 2);
+        assert!(
+            class
+                .body
+                .body
+                .iter()
+                .all(|element| matches!(element, ClassElement::PropertyDefinition(_)))
+        );
+    }
+
+    #[test]
+    fn experimental_pipeline() {
+        let allocator = Allocator::default();
+        let options = ParseOptions { experimental_pipeline: true, ..ParseOptions::default() };
+        let parse = |source: &str| {
+            Parser::new(&allocator, source, SourceType::mjs()).with_options(options).parse()
+        };
+
+        for source in [
+            "a |> f(%) |> g(%, 1);",
+            "x = a |> (% ? b : c);",
+            "a |> (() => %) |> %();",
+            "a |> % + 1 |> [%, % |> f(%)];",
+            "a ?? b |> % | c |> f(%);",
+            "async function* f() { a |> await % |> (yield %); }",
+        ] {
+            let ret = parse(source);
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        // `|>` nests to the right
+        let ret = parse("a |> f(%) |> g(%)");
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::PipelineExpression(pipe) = &stmt.expression else { unreachable!() };
+        assert!(matches!(pipe.head, Expression::Identifier(_)));
+        assert!(matches!(pipe.body, Expression::PipelineExpression(_)));
+
+        for (source, error) in [
+            ("a |> f(x)", "Pipe body must use the topic reference `%`."),
+            ("a |> % ? b : c", "Pipe body cannot be an unparenthesized conditional expression."),
+            ("a |> x => %", "Pipe body cannot be an unparenthesized arrow function expression."),
+            ("f(%)", "Topic reference `%` is only allowed in the body of a pipe expression."),
+        ] {
+            let ret = parse(source);
+            assert_eq!(ret.errors.len(), 1, "{source}: {:?}", ret.errors);
+            assert_eq!(ret.errors[0].to_string(), error);
+        }
+
+        assert!(!Parser::new(&allocator, "a |> f(%)", SourceType::mjs()).parse().errors.is_empty());
+
+        // `%` outside of pipe bodies is a V8 intrinsic
+        let options = ParseOptions { allow_v8_intrinsics: true, ..options };
+        let ret = Parser::new(&allocator, "%DebugPrint(a |> f(%))", SourceType::mjs())
+            .with_options(options)
+            .parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        const DEPTH: usize = 100_000;
+        let allocator = Allocator::default();
+        let options = ParseOptions { max_nesting_depth: Some(100), ..ParseOptions::default() };
+
+        // Each of these overflows the stack without a limit
+        let sources = [
+            ("[".repeat(DEPTH), SourceType::default()),
+            ("{".repeat(DEPTH), SourceType::default()),
+            ("(".repeat(DEPTH), SourceType::default()),
+            ("if (x) ".repeat(DEPTH), SourceType::default()),
+            ("!".repeat(DEPTH) + "x", SourceType::default()),
+            ("new ".repeat(DEPTH) + "X", SourceType::default()),
+            ("x => ".repeat(DEPTH) + "x", SourceType::default()),
+            ("x ? x : ".repeat(DEPTH) + "x", SourceType::default()),
+            ("2 ** ".repeat(DEPTH) + "2", SourceType::default()),
+            ("let ".to_string() + &"[".repeat(DEPTH), SourceType::default()),
+            ("<a>".repeat(DEPTH), SourceType::jsx()),
+            ("type A = ".to_string() + &"Array<".repeat(DEPTH), SourceType::ts()),
+        ];
+        for (source, source_type) in sources {
+            let ret = Parser::new(&allocator, &source, source_type).with_options(options).parse();
+            assert!(ret.program.is_empty());
+            assert!(ret.panicked);
+            assert_eq!(ret.errors.len(), 1);
+            assert_eq!(ret.errors[0].to_string(), "Nesting exceeds the maximum depth of 100");
+        }
+
+        let source = "[".repeat(20) + &"]".repeat(20);
+        let ret =
+            Parser::new(&allocator, &source, SourceType::default()).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
     // Source with length MAX_LEN + 1 fails to parse.
     // Skip this test on 32-bit systems as impossible to allocate a string longer than `isize::MAX`.
     // Also skip running under Miri since it takes so long.
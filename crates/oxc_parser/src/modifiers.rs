@@ -318,6 +318,11 @@ impl<'a> ParserImpl<'a> {
                 self.bump_any();
                 self.at(Kind::Enum)
             }
+            // `accessor [no LineTerminator here] ClassElementName` in the decorators proposal
+            Kind::Accessor if self.options.stage3_decorators => {
+                self.bump_any();
+                self.can_follow_modifier() && !self.cur_token().is_on_new_line()
+            }
             Kind::Accessor | Kind::Static | Kind::Get | Kind::Set => {
                 // These modifiers can cross line.
                 self.bump_any();
@@ -15,6 +15,14 @@ pub struct ParserState<'a> {
     /// Keyed by start span of `ArrayExpression`.
     /// Valued by position of the trailing_comma.
     pub trailing_commas: FxHashMap<u32, Span>,
+
+    /// Spans of function bodies skipped by [`crate::ParseOptions::lazy_function_bodies`].
+    /// Keyed by `FunctionBody`'s span.start.
+    pub lazy_function_bodies: FxHashMap<u32, Span>,
+
+    /// Whether the topic reference `%` is used in the body of the pipe expression being parsed.
+    /// `None` outside of pipe bodies, see [`crate::ParseOptions::experimental_pipeline`].
+    pub pipe_topic_used: Option<bool>,
 }
 
 impl ParserState<'_> {
@@ -23,6 +31,8 @@ impl ParserState<'_> {
             not_parenthesized_arrow: FxHashSet::default(),
             cover_initialized_name: FxHashMap::default(),
             trailing_commas: FxHashMap::default(),
+            lazy_function_bodies: FxHashMap::default(),
+            pipe_topic_used: None,
         }
     }
 }
@@ -0,0 +1,212 @@
+//! Tokens of the source text, recorded with [`ParseOptions::preserve_trivia`].
+//!
+//! [`ParseOptions::preserve_trivia`]: crate::ParseOptions::preserve_trivia
+
+use std::ops::Deref;
+
+use oxc_span::Span;
+
+use crate::lexer::Token;
+
+/// All tokens of the source text, in source order, as the parser read them.
+///
+/// Together with the trivia between them, i.e. whitespace and comments, the tokens cover the
+/// whole source text. So the source text of any AST node can be split into its tokens and trivia,
+/// e.g. to print untouched nodes exactly as they were written.
+///
+/// The trivia between two tokens is divided like in Roslyn and SwiftSyntax: the trailing trivia
+/// of a token continues until the end of its line, and the rest is the leading trivia of the next
+/// token.
+///
+/// Tokens which are lexed differently depending on the grammar have the kind the parser chose,
+/// e.g. [`Kind::RegExp`] rather than [`Kind::Slash`], or [`Kind::ShiftRight`] rather than two
+/// [`Kind::RAngle`]s in an expression.
+///
+/// [`Kind::RegExp`]: crate::lexer::Kind::RegExp
+/// [`Kind::Slash`]: crate::lexer::Kind::Slash
+/// [`Kind::RAngle`]: crate::lexer::Kind::RAngle
+/// [`Kind::ShiftRight`]: crate::lexer::Kind::ShiftRight
+#[derive(Debug, Default, Clone)]
+pub struct Tokens<'a> {
+    source_text: &'a str,
+    tokens: Vec<Token>,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(source_text: &'a str, tokens: Vec<Token>) -> Self {
+        Self { source_text, tokens }
+    }
+
+    /// Source text of the tokens.
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    /// Tokens inside `span`, e.g. the span of an AST node.
+    pub fn in_span(&self, span: Span) -> &[Token] {
+        let start = self.tokens.partition_point(|token| token.start() < span.start);
+        let end = self.tokens.partition_point(|token| token.end() <= span.end);
+        self.tokens.get(start..end).unwrap_or_default()
+    }
+
+    /// Span of the leading trivia of the token or node at `span`: the whitespace and comments
+    /// after the trailing trivia of the previous token.
+    ///
+    /// The trivia at the end of the file is the leading trivia of `Span::empty(source_text.len())`.
+    pub fn leading_trivia(&self, span: Span) -> Span {
+        let index = self.tokens.partition_point(|token| token.start() < span.start);
+        let start = match index.checked_sub(1) {
+            Some(prev) => self.trailing_trivia(self.tokens[prev].span()).end,
+            None => 0,
+        };
+        Span::new(start.min(span.start), span.start)
+    }
+
+    /// Span of the trailing trivia of the token or node at `span`: the whitespace and comments
+    /// after it, until the end of its line, including the line break.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn trailing_trivia(&self, span: Span) -> Span {
+        let index = self.tokens.partition_point(|token| token.start() < span.end);
+        let end = self.tokens.get(index).map_or(self.source_text.len() as u32, Token::start);
+        let trivia = &self.source_text[span.end as usize..end as usize];
+        Span::new(span.end, span.end + trailing_trivia_len(trivia) as u32)
+    }
+}
+
+impl Deref for Tokens<'_> {
+    type Target = [Token];
+
+    fn deref(&self) -> &Self::Target {
+        &self.tokens
+    }
+}
+
+/// Length of the start of `trivia` which is on the same line as the token before it,
+/// including the line break.
+fn trailing_trivia_len(trivia: &str) -> usize {
+    let mut chars = trivia.char_indices().peekable();
+    let mut in_line_comment = false;
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\n' | '\u{2028}' | '\u{2029}' => return index + ch.len_utf8(),
+            '\r' => {
+                let len = if chars.peek().is_some_and(|(_, ch)| *ch == '\n') { 2 } else { 1 };
+                return index + len;
+            }
+            '/' if trivia[index..].starts_with("//") => in_line_comment = true,
+            // A multi-line comment continues the line, even if it contains line breaks
+            '/' if !in_line_comment && trivia[index..].starts_with("/*") => {
+                let Some(end) = trivia[index + 2..].find("*/") else { return trivia.len() };
+                let comment_end = index + 2 + end + 2;
+                while chars.peek().is_some_and(|(index, _)| *index < comment_end) {
+                    chars.next();
+                }
+            }
+            _ => {}
+        }
+    }
+    trivia.len()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use crate::{ParseOptions, Parser, lexer::Kind};
+
+    fn kinds(source_text: &str, source_type: SourceType) -> Vec<Kind> {
+        let allocator = Allocator::default();
+        let options = ParseOptions { preserve_trivia: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        ret.tokens.iter().map(|token| token.kind()).collect()
+    }
+
+    #[test]
+    fn re_lexed_tokens() {
+        assert_eq!(kinds("a / /b/g", SourceType::mjs()), [Kind::Ident, Kind::Slash, Kind::RegExp]);
+        assert_eq!(
+            kinds("`a${b}c${d}`", SourceType::mjs()),
+            [
+                Kind::TemplateHead,
+                Kind::Ident,
+                Kind::TemplateMiddle,
+                Kind::Ident,
+                Kind::TemplateTail
+            ]
+        );
+        assert_eq!(
+            kinds("let a: A<B<C>> = b >> c", SourceType::ts()),
+            [
+                Kind::Let,
+                Kind::Ident,
+                Kind::Colon,
+                Kind::Ident,
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::RAngle,
+                Kind::RAngle,
+                Kind::Eq,
+                Kind::Ident,
+                Kind::ShiftRight,
+                Kind::Ident,
+            ]
+        );
+        assert_eq!(
+            kinds("<a>b{c}</a>", SourceType::jsx()),
+            [
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::RAngle,
+                Kind::JSXText,
+                Kind::LCurly,
+                Kind::Ident,
+                Kind::RCurly,
+                Kind::LAngle,
+                Kind::Slash,
+                Kind::Ident,
+                Kind::RAngle,
+            ]
+        );
+        // Tokens of attempts to parse arrow functions are not recorded twice
+        assert_eq!(kinds("(a, b) => a; (a, b);", SourceType::mjs()).len(), 14);
+    }
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)]
+    fn trivia() {
+        let allocator = Allocator::default();
+        let source_text =
+            "#!/usr/bin/env node\n// a\nlet a = 1; /* b\n */ // c\n\n  /** d */ let d;\n// e\n";
+        let options = ParseOptions { preserve_trivia: true, ..ParseOptions::default() };
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        let tokens = &ret.tokens;
+        assert_eq!(tokens.first().map(|token| token.kind()), Some(Kind::HashbangComment));
+        assert_eq!(tokens.last().map(|token| token.kind()), Some(Kind::Semicolon));
+
+        let [first, second] = [ret.program.body[0].span(), ret.program.body[1].span()];
+        let text = |span: Span| span.source_text(source_text);
+        assert_eq!(tokens.in_span(first).len(), 5);
+        assert_eq!(text(tokens.leading_trivia(first)), "// a\n");
+        assert_eq!(text(tokens.trailing_trivia(first)), " /* b\n */ // c\n");
+        assert_eq!(text(tokens.leading_trivia(second)), "\n  /** d */ ");
+        assert_eq!(text(tokens.trailing_trivia(second)), "\n");
+        let end = Span::empty(source_text.len() as u32);
+        assert_eq!(text(tokens.leading_trivia(end)), "// e\n");
+
+        // Concatenating leading trivia, tokens and trailing trivia produces the source text
+        let mut printed = String::new();
+        for token in tokens.iter() {
+            let span = token.span();
+            printed.push_str(text(tokens.leading_trivia(span)));
+            printed.push_str(text(span));
+            printed.push_str(text(tokens.trailing_trivia(span)));
+        }
+        printed.push_str(text(tokens.leading_trivia(end)));
+        assert_eq!(printed, source_text);
+    }
+}
@@ -13,6 +13,10 @@ use super::{super::js::FunctionKind, statement::CallOrConstructorSignature};
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> TSType<'a> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> TSType<'a> {
         if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
@@ -0,0 +1,50 @@
+#![expect(clippy::print_stdout)]
+//! # Regular Expression Downleveling Example
+//!
+//! This example demonstrates rewriting regular expressions which use newer features
+//! into equivalents supported by older targets.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo run -p oxc_regular_expression --example downlevel
+//! ```
+
+use oxc_allocator::Allocator;
+use oxc_regular_expression::{DownlevelOptions, LiteralParser, Options, downlevel};
+
+/// Downlevel various regular expressions and print the results
+fn main() {
+    let allocator = Allocator::default();
+    let options = DownlevelOptions {
+        dot_all_flag: true,
+        named_capture_groups: true,
+        look_behind_assertions: true,
+        set_notation: true,
+    };
+
+    for (pattern_text, flags_text) in [
+        (r"a.b", "s"),
+        (r"(?<year>\d{4})-(?<month>\d{2})-\k<month>", ""),
+        (r"[\w--\d]+", "v"),
+        (r"[[a-z]&&[^aeiou]]", "gv"),
+        (r"(?<=\$)\d+", ""),
+        (r"[\p{L}--[a-z]]", "v"),
+    ] {
+        println!("/{pattern_text}/{flags_text}");
+
+        let mut pattern =
+            LiteralParser::new(&allocator, pattern_text, Some(flags_text), Options::default())
+                .parse()
+                .unwrap();
+        match downlevel(&allocator, &mut pattern, flags_text, options) {
+            Ok(ret) => {
+                println!("  => /{pattern}/{}", ret.flags);
+                for (name, index) in ret.group_names {
+                    println!("     group `{name}` is ${index}");
+                }
+            }
+            Err(error) => println!("  => {error}"),
+        }
+    }
+}
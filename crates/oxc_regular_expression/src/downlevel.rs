@@ -0,0 +1,515 @@
+//! Rewrite a parsed [`Pattern`] to only use features supported by older targets.
+//!
+//! Only rewrites which keep the matching behavior are performed:
+//!
+//! - `.` in `dotAll` mode becomes `[\s\S]`, and the `s` flag is removed
+//! - `(?<name>x)` becomes `(x)`, and `\k<name>` becomes an indexed backreference
+//! - Classes of the `v` flag are converted to classes of the `u` flag, and the `v` flag is replaced with `u`
+//!
+//! Features which have no equivalent are reported as [`DownlevelError`],
+//! in which case the pattern must be left to the runtime (e.g. `new RegExp(...)` with a polyfill).
+
+use std::fmt;
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_span::{Atom, Span};
+
+use crate::ast::*;
+
+/// Features which [`downlevel`] rewrites.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DownlevelOptions {
+    /// Rewrite `.` in `dotAll` mode to `[\s\S]`, and remove the `s` flag.
+    pub dot_all_flag: bool,
+    /// Rewrite `(?<name>x)` to `(x)`, and `\k<name>` to `\1`.
+    ///
+    /// The removed names are returned in [`DownlevelReturn::group_names`].
+    pub named_capture_groups: bool,
+    /// Lookbehind assertions `(?<=x)` and `(?<!x)` have no equivalent,
+    /// so any of them is reported as [`DownlevelError::LookBehindAssertion`].
+    pub look_behind_assertions: bool,
+    /// Rewrite classes of the `v` flag to classes of the `u` flag, and replace the `v` flag with `u`.
+    pub set_notation: bool,
+}
+
+/// Reason why a pattern cannot be downleveled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownlevelError {
+    /// `(?<=x)` or `(?<!x)`.
+    LookBehindAssertion,
+    /// `\k<name>` where `name` is used by more than one capturing group.
+    DuplicateNamedReference,
+    /// A class of the `v` flag which cannot be expressed with the `u` flag.
+    /// e.g. set operations on `\p{...}`, strings in `\q{...}`, or set operations with the `i` flag.
+    SetNotation,
+}
+
+impl fmt::Display for DownlevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::LookBehindAssertion => "Lookbehind assertions cannot be downleveled",
+            Self::DuplicateNamedReference => {
+                "Backreferences to duplicate named groups cannot be downleveled"
+            }
+            Self::SetNotation => "This class cannot be expressed without the `v` flag",
+        };
+        f.write_str(message)
+    }
+}
+
+#[derive(Debug)]
+pub struct DownlevelReturn<'a> {
+    /// Flags after rewriting.
+    pub flags: String,
+    /// Names of the capturing groups which were removed, with their 1-based index.
+    /// A name appears more than once if it is used by several groups.
+    pub group_names: std::vec::Vec<(Atom<'a>, u32)>,
+}
+
+/// Rewrite `pattern` in place according to `options`.
+///
+/// `pattern` is left in an unspecified state when an error is returned.
+///
+/// ```ignore
+/// let mut pattern = LiteralParser::new(&allocator, r"(?<year>\d+).", Some("s"), options).parse()?;
+/// let ret = downlevel(&allocator, &mut pattern, "s", DownlevelOptions { dot_all_flag: true, ..Default::default() })?;
+/// assert_eq!(pattern.to_string(), r"(?<year>\d+)[\s\S]");
+/// assert_eq!(ret.flags, "");
+/// ```
+pub fn downlevel<'a>(
+    allocator: &'a Allocator,
+    pattern: &mut Pattern<'a>,
+    flags: &str,
+    options: DownlevelOptions,
+) -> Result<DownlevelReturn<'a>, DownlevelError> {
+    let mut group_names = std::vec::Vec::new();
+    if options.named_capture_groups {
+        collect_group_names(&pattern.body, &mut 0, &mut group_names);
+    }
+
+    let mut downleveler = Downleveler {
+        allocator,
+        options,
+        ignore_case: flags.contains('i'),
+        unicode_sets: flags.contains('v'),
+        group_names: &group_names,
+    };
+    downleveler.visit_disjunction(&mut pattern.body, flags.contains('s'))?;
+
+    let flags = flags
+        .chars()
+        .filter(|&c| !(options.dot_all_flag && c == 's'))
+        .map(|c| if options.set_notation && c == 'v' { 'u' } else { c })
+        .collect();
+
+    Ok(DownlevelReturn { flags, group_names })
+}
+
+fn collect_group_names<'a>(
+    disjunction: &Disjunction<'a>,
+    count: &mut u32,
+    names: &mut std::vec::Vec<(Atom<'a>, u32)>,
+) {
+    for term in disjunction.body.iter().flat_map(|alternative| &alternative.body) {
+        let mut term = term;
+        while let Term::Quantifier(quantifier) = term {
+            term = &quantifier.body;
+        }
+        match term {
+            Term::CapturingGroup(group) => {
+                *count += 1;
+                if let Some(name) = group.name {
+                    names.push((name, *count));
+                }
+                collect_group_names(&group.body, count, names);
+            }
+            Term::IgnoreGroup(group) => collect_group_names(&group.body, count, names),
+            Term::LookAroundAssertion(assertion) => {
+                collect_group_names(&assertion.body, count, names);
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Downleveler<'a, 'n> {
+    allocator: &'a Allocator,
+    options: DownlevelOptions,
+    ignore_case: bool,
+    unicode_sets: bool,
+    group_names: &'n [(Atom<'a>, u32)],
+}
+
+impl<'a> Downleveler<'a, '_> {
+    fn visit_disjunction(
+        &mut self,
+        disjunction: &mut Disjunction<'a>,
+        dot_all: bool,
+    ) -> Result<(), DownlevelError> {
+        for alternative in &mut disjunction.body {
+            for term in &mut alternative.body {
+                self.visit_term(term, dot_all)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_term(&mut self, term: &mut Term<'a>, dot_all: bool) -> Result<(), DownlevelError> {
+        match term {
+            Term::LookAroundAssertion(assertion) => {
+                if self.options.look_behind_assertions
+                    && matches!(
+                        assertion.kind,
+                        LookAroundAssertionKind::Lookbehind
+                            | LookAroundAssertionKind::NegativeLookbehind
+                    )
+                {
+                    return Err(DownlevelError::LookBehindAssertion);
+                }
+                self.visit_disjunction(&mut assertion.body, dot_all)?;
+            }
+            Term::Quantifier(quantifier) => self.visit_term(&mut quantifier.body, dot_all)?,
+            Term::Dot(dot) if self.options.dot_all_flag && dot_all => {
+                *term = Term::CharacterClass(Box::new_in(
+                    any_character(dot.span, self.allocator),
+                    self.allocator,
+                ));
+            }
+            Term::UnicodePropertyEscape(escape)
+                if self.options.set_notation && self.unicode_sets && escape.strings =>
+            {
+                return Err(DownlevelError::SetNotation);
+            }
+            Term::CharacterClass(class) if self.options.set_notation && self.unicode_sets => {
+                self.visit_character_class(class)?;
+            }
+            Term::CapturingGroup(group) => {
+                if self.options.named_capture_groups {
+                    group.name = None;
+                }
+                self.visit_disjunction(&mut group.body, dot_all)?;
+            }
+            Term::IgnoreGroup(group) => {
+                let mut dot_all = dot_all;
+                if let Some(modifiers) = &group.modifiers {
+                    if modifiers.enabling.contains(Modifier::S) {
+                        dot_all = true;
+                    }
+                    if modifiers.disabling.contains(Modifier::S) {
+                        dot_all = false;
+                    }
+                }
+                self.visit_disjunction(&mut group.body, dot_all)?;
+            }
+            Term::NamedReference(reference) if self.options.named_capture_groups => {
+                let mut indexes = self
+                    .group_names
+                    .iter()
+                    .filter(|(name, _)| *name == reference.name)
+                    .map(|(_, index)| *index);
+                let (Some(index), None) = (indexes.next(), indexes.next()) else {
+                    return Err(DownlevelError::DuplicateNamedReference);
+                };
+                *term = Term::IndexedReference(Box::new_in(
+                    IndexedReference { span: reference.span, index },
+                    self.allocator,
+                ));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Convert a class of the `v` flag to a class of the `u` flag.
+    fn visit_character_class(&self, class: &mut CharacterClass<'a>) -> Result<(), DownlevelError> {
+        let is_plain_union = class.kind == CharacterClassContentsKind::Union
+            && class.body.iter().all(|content| {
+                !matches!(
+                    content,
+                    CharacterClassContents::NestedCharacterClass(_)
+                        | CharacterClassContents::ClassStringDisjunction(_)
+                )
+            });
+
+        if is_plain_union {
+            // Same syntax in both modes, except for the punctuators which only `v` allows to escape
+            for content in &mut class.body {
+                match content {
+                    CharacterClassContents::Character(character) => {
+                        unescape_set_punctuator(character);
+                    }
+                    CharacterClassContents::CharacterClassRange(range) => {
+                        unescape_set_punctuator(&mut range.min);
+                        unescape_set_punctuator(&mut range.max);
+                    }
+                    CharacterClassContents::UnicodePropertyEscape(escape) if escape.strings => {
+                        return Err(DownlevelError::SetNotation);
+                    }
+                    _ => {}
+                }
+            }
+            return Ok(());
+        }
+
+        // Case folding of complemented sets differs between `u` and `v`
+        if self.ignore_case {
+            return Err(DownlevelError::SetNotation);
+        }
+
+        let set = class_body_set(class)?;
+        let mut body = Vec::with_capacity_in(set.len(), self.allocator);
+        for (min, max) in set {
+            let min = character(min, class.span);
+            body.push(if min.value == max {
+                CharacterClassContents::Character(Box::new_in(min, self.allocator))
+            } else {
+                CharacterClassContents::CharacterClassRange(Box::new_in(
+                    CharacterClassRange { span: class.span, min, max: character(max, class.span) },
+                    self.allocator,
+                ))
+            });
+        }
+        class.kind = CharacterClassContentsKind::Union;
+        class.strings = false;
+        class.body = body;
+        Ok(())
+    }
+}
+
+/// `[\s\S]`
+fn any_character(span: Span, allocator: &Allocator) -> CharacterClass<'_> {
+    let escape = |kind| {
+        CharacterClassContents::CharacterClassEscape(Box::new_in(
+            CharacterClassEscape { span, kind },
+            allocator,
+        ))
+    };
+    CharacterClass {
+        span,
+        negative: false,
+        strings: false,
+        kind: CharacterClassContentsKind::Union,
+        body: Vec::from_array_in(
+            [escape(CharacterClassEscapeKind::S), escape(CharacterClassEscapeKind::NegativeS)],
+            allocator,
+        ),
+    }
+}
+
+/// `\&` is valid with the `v` flag, but must be written as `&` with the `u` flag.
+fn unescape_set_punctuator(character: &mut Character) {
+    if matches!(character.kind, CharacterKind::Identifier | CharacterKind::SingleEscape)
+        && char::from_u32(character.value).is_some_and(|c| "&!#%,:;<=>@`~".contains(c))
+    {
+        character.kind = CharacterKind::Symbol;
+    }
+}
+
+fn character(value: u32, span: Span) -> Character {
+    let kind =
+        if char::from_u32(value).is_some_and(|c| c.is_ascii_graphic() && !r"/-[\]^".contains(c)) {
+            CharacterKind::Symbol
+        } else {
+            CharacterKind::UnicodeEscape
+        };
+    Character { span, value, kind }
+}
+
+// --- Sets of code points, as sorted and non-overlapping inclusive ranges
+
+type CodePointSet = std::vec::Vec<(u32, u32)>;
+
+const MAX_CODE_POINT: u32 = 0x10_FFFF;
+
+/// Code points matched by `class`, ignoring its own negation.
+fn class_body_set(class: &CharacterClass) -> Result<CodePointSet, DownlevelError> {
+    let mut sets = class.body.iter().map(content_set);
+    let Some(first) = sets.next() else { return Ok(vec![]) };
+    let mut set = first?;
+    for other in sets {
+        let other = other?;
+        set = match class.kind {
+            CharacterClassContentsKind::Union => union(set, &other),
+            CharacterClassContentsKind::Intersection => intersection(&set, &other),
+            CharacterClassContentsKind::Subtraction => intersection(&set, &complement(&other)),
+        };
+    }
+    Ok(set)
+}
+
+fn content_set(content: &CharacterClassContents) -> Result<CodePointSet, DownlevelError> {
+    Ok(match content {
+        CharacterClassContents::Character(character) => vec![(character.value, character.value)],
+        CharacterClassContents::CharacterClassRange(range) => {
+            vec![(range.min.value, range.max.value)]
+        }
+        CharacterClassContents::CharacterClassEscape(escape) => escape_set(escape.kind),
+        CharacterClassContents::NestedCharacterClass(class) => {
+            let set = class_body_set(class)?;
+            if class.negative { complement(&set) } else { set }
+        }
+        CharacterClassContents::ClassStringDisjunction(disjunction) => {
+            let mut set = vec![];
+            for string in &disjunction.body {
+                let [character] = string.body.as_slice() else {
+                    return Err(DownlevelError::SetNotation);
+                };
+                set = union(set, &[(character.value, character.value)]);
+            }
+            set
+        }
+        CharacterClassContents::UnicodePropertyEscape(_) => {
+            return Err(DownlevelError::SetNotation);
+        }
+    })
+}
+
+fn escape_set(kind: CharacterClassEscapeKind) -> CodePointSet {
+    const DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+    const WORD: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+    // WhiteSpace and LineTerminator
+    const SPACE: &[(u32, u32)] = &[
+        (0x09, 0x0D),
+        (0x20, 0x20),
+        (0xA0, 0xA0),
+        (0x1680, 0x1680),
+        (0x2000, 0x200A),
+        (0x2028, 0x2029),
+        (0x202F, 0x202F),
+        (0x205F, 0x205F),
+        (0x3000, 0x3000),
+        (0xFEFF, 0xFEFF),
+    ];
+    match kind {
+        CharacterClassEscapeKind::D => DIGIT.to_vec(),
+        CharacterClassEscapeKind::NegativeD => complement(DIGIT),
+        CharacterClassEscapeKind::W => WORD.to_vec(),
+        CharacterClassEscapeKind::NegativeW => complement(WORD),
+        CharacterClassEscapeKind::S => SPACE.to_vec(),
+        CharacterClassEscapeKind::NegativeS => complement(SPACE),
+    }
+}
+
+fn union(mut set: CodePointSet, other: &[(u32, u32)]) -> CodePointSet {
+    set.extend_from_slice(other);
+    set.sort_unstable();
+    let mut merged: CodePointSet = std::vec::Vec::with_capacity(set.len());
+    for (min, max) in set {
+        match merged.last_mut() {
+            Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+            _ => merged.push((min, max)),
+        }
+    }
+    merged
+}
+
+fn complement(set: &[(u32, u32)]) -> CodePointSet {
+    let mut result = vec![];
+    let mut next = 0;
+    for &(min, max) in set {
+        if min > next {
+            result.push((next, min - 1));
+        }
+        next = max + 1;
+    }
+    if next <= MAX_CODE_POINT {
+        result.push((next, MAX_CODE_POINT));
+    }
+    result
+}
+
+fn intersection(set: &[(u32, u32)], other: &[(u32, u32)]) -> CodePointSet {
+    complement(&union(complement(set), &complement(other)))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::*;
+    use crate::{LiteralParser, Options};
+
+    fn run(source: &str, options: DownlevelOptions) -> Result<(String, String), DownlevelError> {
+        let allocator = Allocator::default();
+        let (body, flags) = source[1..].rsplit_once('/').unwrap();
+        let mut pattern = LiteralParser::new(&allocator, body, Some(flags), Options::default())
+            .parse()
+            .unwrap_or_else(|error| panic!("{source}: {error:?}"));
+        let ret = downlevel(&allocator, &mut pattern, flags, options)?;
+        Ok((pattern.to_string(), ret.flags))
+    }
+
+    #[test]
+    fn dot_all() {
+        let options = DownlevelOptions { dot_all_flag: true, ..DownlevelOptions::default() };
+        for (source, expected, flags) in [
+            ("/a.b/s", r"a[\s\S]b", ""),
+            ("/a.b/gsi", r"a[\s\S]b", "gi"),
+            ("/a.b/", "a.b", ""),
+            ("/(?s:.)./", r"(?s:[\s\S]).", ""),
+            ("/(?-s:.)./s", r"(?-s:.)[\s\S]", ""),
+            ("/[.]+/s", "[.]+", ""),
+        ] {
+            assert_eq!(run(source, options), Ok((expected.to_string(), flags.to_string())));
+        }
+    }
+
+    #[test]
+    fn named_capture_groups() {
+        let options =
+            DownlevelOptions { named_capture_groups: true, ..DownlevelOptions::default() };
+        for (source, expected) in [
+            (r"/(?<year>\d{4})-(?<month>\d{2})/", r"(\d{4})-(\d{2})"),
+            (r"/(a)(?<b>b)\k<b>/", r"(a)(b)\2"),
+            (r"/\k<a>((?<a>x))+/", r"\2((x))+"),
+            (r"/(?<a>x)|(?<a>y)/", "(x)|(y)"),
+        ] {
+            assert_eq!(run(source, options), Ok((expected.to_string(), String::new())));
+        }
+
+        let allocator = Allocator::default();
+        let mut pattern =
+            LiteralParser::new(&allocator, "(x)(?<a>y)|(?<a>z)", None, Options::default())
+                .parse()
+                .unwrap();
+        let ret = downlevel(&allocator, &mut pattern, "", options).unwrap();
+        assert_eq!(ret.group_names, vec![(Atom::from("a"), 2), (Atom::from("a"), 3)]);
+
+        assert_eq!(
+            run(r"/(?<a>x)|(?<a>y)\k<a>/", options),
+            Err(DownlevelError::DuplicateNamedReference)
+        );
+    }
+
+    #[test]
+    fn look_behind_assertions() {
+        let options =
+            DownlevelOptions { look_behind_assertions: true, ..DownlevelOptions::default() };
+        assert_eq!(run("/(?=a)b/", options), Ok(("(?=a)b".to_string(), String::new())));
+        assert_eq!(run("/(?<=a)b/", options), Err(DownlevelError::LookBehindAssertion));
+        assert_eq!(run("/(?:(?<!a))+b/", options), Err(DownlevelError::LookBehindAssertion));
+    }
+
+    #[test]
+    fn set_notation() {
+        let options = DownlevelOptions { set_notation: true, ..DownlevelOptions::default() };
+        for (source, expected, flags) in [
+            ("/[abc]/v", "[abc]", "u"),
+            (r"/[\&\-]\p{L}/gv", r"[&\-]\p{L}", "gu"),
+            ("/[[a-z]--[aeiou]]/v", r"[b-df-hj-np-tv-z]", "u"),
+            (r"/[\w&&[a-f]]/v", "[a-f]", "u"),
+            (r"/[^[a-z]--x]/v", "[^a-wy-z]", "u"),
+            (r"/[[[a-c][^\d]]--\q{b|c}]/v", r"[\u0000-\u002F:-ad-\u{10FFFF}]", "u"),
+            (r"/[\q{a|b}x]/v", "[a-bx]", "u"),
+            ("/[a-c]/i", "[a-c]", "i"),
+        ] {
+            assert_eq!(run(source, options), Ok((expected.to_string(), flags.to_string())));
+        }
+
+        for source in
+            [r"/[\p{L}--a]/v", r"/[\q{abc}x]/v", r"/\p{RGI_Emoji}/v", "/[[a-z]--[aeiou]]/iv"]
+        {
+            assert_eq!(run(source, options), Err(DownlevelError::SetNotation), "{source}");
+        }
+    }
+}
@@ -4,6 +4,8 @@ use unicode_id_start::{is_id_continue_unicode, is_id_start_unicode};
 
 use oxc_data_structures::assert_unchecked;
 
+use crate::keyword::is_es3_reserved_word;
+
 pub const EOF: char = '\0';
 
 // 11.1 Unicode Format-Control Characters
@@ -281,6 +283,37 @@ pub fn is_identifier_name(name: &str) -> bool {
     chars.all(is_identifier_part)
 }
 
+/// Oldest ECMAScript edition which has to accept property names written without quotes,
+/// e.g. `a.b` and `{ b: 1 }` instead of `a["b"]` and `{ "b": 1 }`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNameTarget {
+    /// Reserved words can't be property names, and identifiers only contain ASCII and Latin-1 letters.
+    ES3,
+    /// Identifiers only contain ASCII and Latin-1 letters, because engines use older versions of Unicode.
+    ES5,
+    /// Any identifier name.
+    #[default]
+    ES2015,
+}
+
+impl PropertyNameTarget {
+    /// Determine if `name` can be written without quotes as a property name in this edition.
+    pub fn is_unquoted_property_name(self, name: &str) -> bool {
+        if !is_identifier_name(name) {
+            return false;
+        }
+        match self {
+            Self::ES2015 => true,
+            Self::ES5 => is_latin1(name),
+            Self::ES3 => is_latin1(name) && !is_es3_reserved_word(name),
+        }
+    }
+}
+
+fn is_latin1(name: &str) -> bool {
+    name.chars().all(|c| u32::from(c) <= 0xFF)
+}
+
 #[test]
 fn is_identifier_name_true() {
     let cases = [
@@ -367,3 +400,22 @@ fn is_identifier_name_false() {
         assert!(!is_identifier_name(str));
     }
 }
+
+#[test]
+fn is_unquoted_property_name() {
+    use PropertyNameTarget::{ES3, ES5, ES2015};
+
+    for target in [ES3, ES5, ES2015] {
+        assert!(target.is_unquoted_property_name("foo"));
+        assert!(target.is_unquoted_property_name("\u{d1}oño"));
+        assert!(!target.is_unquoted_property_name("a-b"));
+        assert!(!target.is_unquoted_property_name(""));
+    }
+    assert!(ES2015.is_unquoted_property_name("\u{1d17}A"));
+    assert!(!ES5.is_unquoted_property_name("\u{1d17}A"));
+    assert!(ES5.is_unquoted_property_name("default"));
+    assert!(ES5.is_unquoted_property_name("int"));
+    assert!(!ES3.is_unquoted_property_name("default"));
+    assert!(!ES3.is_unquoted_property_name("int"));
+    assert!(ES3.is_unquoted_property_name("let"));
+}
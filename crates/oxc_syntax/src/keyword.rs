@@ -93,3 +93,79 @@ pub const RESERVED_KEYWORDS: Set<&'static str> = phf_set! {
     "with",
     "yield",
 };
+
+/// Checks if the given string is a reserved word in ES3.
+///
+/// ES3 does not allow reserved words as property names, e.g. `a.default` or `{ class: 1 }`.
+#[inline]
+pub fn is_es3_reserved_word(s: &str) -> bool {
+    ES3_RESERVED_WORDS.contains(s)
+}
+
+/// Reserved words of ES3, which includes more future reserved words than later editions.
+///
+/// Reference: <https://www-archive.mozilla.org/js/language/E262-3.pdf> (7.5.1 Reserved Words)
+pub const ES3_RESERVED_WORDS: Set<&'static str> = phf_set! {
+    // keywords
+    "break",
+    "case",
+    "catch",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "in",
+    "instanceof",
+    "new",
+    "return",
+    "switch",
+    "this",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    // future reserved words
+    "abstract",
+    "boolean",
+    "byte",
+    "char",
+    "class",
+    "const",
+    "debugger",
+    "double",
+    "enum",
+    "export",
+    "extends",
+    "final",
+    "float",
+    "goto",
+    "implements",
+    "import",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "short",
+    "static",
+    "super",
+    "synchronized",
+    "throws",
+    "transient",
+    "volatile",
+    // literals
+    "null",
+    "true",
+    "false",
+};
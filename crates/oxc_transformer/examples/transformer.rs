@@ -17,11 +17,16 @@
 //! - `--babel-options <path>`: Path to Babel options file
 //! - `--targets <targets>`: Browser/environment targets
 //! - `--target <target>`: Single target environment
+//! - `--helpers <mode>`: Helper loader mode, `runtime`, `external` (default) or `inline`
+//!
+//! Transformer warnings are printed before the transformed code. The example exits with status 1
+//! if the transformer reports an error.
 
 use std::path::Path;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
+use oxc_diagnostics::Severity;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -39,6 +44,7 @@ fn main() {
         args.opt_value_from_str("--babel-options").unwrap_or(None);
     let targets: Option<String> = args.opt_value_from_str("--targets").unwrap_or(None);
     let target: Option<String> = args.opt_value_from_str("--target").unwrap_or(None);
+    let helpers: Option<String> = args.opt_value_from_str("--helpers").unwrap_or(None);
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -92,13 +98,19 @@ fn main() {
         TransformOptions::enable_all()
     };
 
-    transform_options.helper_loader.mode = HelperLoaderMode::External;
+    transform_options.helper_loader.mode = match helpers.as_deref() {
+        None | Some("external") => HelperLoaderMode::External,
+        Some("runtime") => HelperLoaderMode::Runtime,
+        Some("inline") => HelperLoaderMode::Inline,
+        Some(mode) => panic!("Unknown helper loader mode `{mode}`."),
+    };
 
     let ret = Transformer::new(&allocator, path, &transform_options)
         .build_with_scoping(scoping, &mut program);
 
+    let has_error = ret.errors.iter().any(|error| error.severity == Severity::Error);
     if !ret.errors.is_empty() {
-        println!("Transformer Errors:");
+        println!("Transformer Diagnostics:");
         for error in ret.errors {
             let error = error.with_source_code(source_text.clone());
             println!("{error:?}");
@@ -108,4 +120,8 @@ fn main() {
     let printed = Codegen::new().build(&program).code;
     println!("Transformed:\n");
     println!("{printed}");
+
+    if has_error {
+        std::process::exit(1);
+    }
 }
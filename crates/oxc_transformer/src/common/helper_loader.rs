@@ -48,6 +48,7 @@
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
 //! > Note: This mode is not currently implemented.
+//! > It falls back to Runtime mode, and a warning is reported.
 //!
 //! Inline helper functions are inserted directly into the top of program.
 //!
@@ -75,6 +76,7 @@ use oxc_ast::{
     NONE,
     ast::{Argument, CallExpression, Expression},
 };
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::{ReferenceFlags, SymbolFlags};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_traverse::BoundIdentifier;
@@ -87,6 +89,7 @@ pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
     /// Note: This mode is not currently implemented.
+    /// It falls back to [`HelperLoaderMode::Runtime`], and a warning is reported.
     ///
     /// Example output:
     /// ```js
@@ -133,6 +136,11 @@ impl Default for HelperLoaderOptions {
     }
 }
 
+pub fn inline_helpers_not_supported() -> OxcDiagnostic {
+    OxcDiagnostic::warn("Inline helpers are not supported yet, runtime helpers are used instead.")
+        .with_help("Set the helper loader mode to `runtime` or `external`.")
+}
+
 fn default_as_module_name() -> Cow<'static, str> {
     Cow::Borrowed("@oxc-project/runtime")
 }
@@ -168,6 +176,7 @@ pub enum Helper {
     DecorateParam,
     DecorateMetadata,
     UsingCtx,
+    WrapRegExp,
 }
 
 impl Helper {
@@ -201,6 +210,7 @@ impl Helper {
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
+            Self::WrapRegExp => "wrapRegExp",
         }
     }
 
@@ -222,7 +232,11 @@ impl HelperLoaderStore<'_> {
     pub fn new(options: &HelperLoaderOptions) -> Self {
         Self {
             module_name: options.module_name.clone(),
-            mode: options.mode,
+            // Inline helpers are not supported yet, `TransformCtx::new` reports a warning.
+            mode: match options.mode {
+                HelperLoaderMode::Inline => HelperLoaderMode::Runtime,
+                mode => mode,
+            },
             loaded_helpers: RefCell::new(FxHashMap::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
@@ -271,7 +285,7 @@ impl<'a> TransformCtx<'a> {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
             HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
+                unreachable!("Inline mode falls back to runtime mode");
             }
         }
     }
@@ -10,8 +10,10 @@ use oxc_span::SourceType;
 use crate::{
     CompilerAssumptions, Module, TransformOptions,
     common::{
-        helper_loader::HelperLoaderStore, module_imports::ModuleImportsStore,
-        statement_injector::StatementInjectorStore, top_level_statements::TopLevelStatementsStore,
+        helper_loader::{HelperLoaderMode, HelperLoaderStore, inline_helpers_not_supported},
+        module_imports::ModuleImportsStore,
+        statement_injector::StatementInjectorStore,
+        top_level_statements::TopLevelStatementsStore,
         var_declarations::VarDeclarationsStore,
     },
     state::TransformState,
@@ -59,8 +61,13 @@ impl TransformCtx<'_> {
             .file_stem() // omit file extension
             .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string());
 
+        let mut errors = vec![];
+        if matches!(options.helper_loader.mode, HelperLoaderMode::Inline) {
+            errors.push(inline_helpers_not_supported());
+        }
+
         Self {
-            errors: RefCell::new(vec![]),
+            errors: RefCell::new(errors),
             filename,
             source_path: source_path.to_path_buf(),
             source_type: SourceType::default(),
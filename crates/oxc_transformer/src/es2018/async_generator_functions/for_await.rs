@@ -3,7 +3,7 @@
 use oxc_allocator::{TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
 use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, GetSpanMut, SPAN};
 use oxc_traverse::{Ancestor, BoundIdentifier};
 
 use crate::{common::helper_loader::Helper, context::TraverseCtx};
@@ -25,6 +25,7 @@ impl<'a> AsyncGeneratorFunctions<'a, '_> {
     }
 
     pub(crate) fn transform_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let span = stmt.span();
         let (for_of, label) = match stmt {
             Statement::LabeledStatement(labeled) => {
                 let LabeledStatement { label, body, .. } = labeled.as_mut();
@@ -58,6 +59,11 @@ impl<'a> AsyncGeneratorFunctions<'a, '_> {
         // TODO: Once we have a method to replace the current statement, we can simplify this logic.
         let mut statements = self.transform_for_of_statement(for_of, parent_scope_id, ctx);
         let mut new_stmt = statements.pop().unwrap();
+        // The first emitted statement takes the span of the `for await` statement,
+        // so that comments attached to it are printed before the lowered code.
+        if let Some(first) = statements.first_mut() {
+            *first.span_mut() = span;
+        }
 
         // If it's a labeled statement, we need to wrap the ForStatement with a labeled statement.
         if let Some(label) = label {
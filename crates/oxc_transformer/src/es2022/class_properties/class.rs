@@ -4,7 +4,7 @@
 use indexmap::map::Entry;
 use oxc_allocator::{Address, GetAddress, TakeIn};
 use oxc_ast::{NONE, ast::*};
-use oxc_span::SPAN;
+use oxc_span::{SPAN, Span};
 use oxc_syntax::{
     node::NodeId,
     reference::ReferenceFlags,
@@ -852,9 +852,17 @@ impl<'a> ClassProperties<'a, '_> {
     }
 
     /// Insert an expression after the class.
-    pub(super) fn insert_expr_after_class(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+    ///
+    /// `span` is the span of the class element the expression was created from. It is used for the
+    /// statement, so comments attached to the class element are printed before the statement.
+    pub(super) fn insert_expr_after_class(
+        &mut self,
+        span: Span,
+        expr: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
         if self.current_class().is_declaration {
-            self.insert_after_stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            self.insert_after_stmts.push(ctx.ast.statement_expression(span, expr));
         } else {
             self.insert_after_exprs.push(expr);
         }
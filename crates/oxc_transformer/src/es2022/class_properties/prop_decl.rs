@@ -2,7 +2,7 @@
 //! Transform of class property declarations (instance or static properties).
 
 use oxc_ast::{NONE, ast::*};
-use oxc_span::SPAN;
+use oxc_span::{GetSpanMut, SPAN, Span};
 use oxc_syntax::reference::ReferenceFlags;
 
 use crate::{
@@ -27,7 +27,7 @@ impl<'a> ClassProperties<'a, '_> {
         // Get value
         let value = prop.value.take();
 
-        let init_expr = if let PropertyKey::PrivateIdentifier(ident) = &mut prop.key {
+        let mut init_expr = if let PropertyKey::PrivateIdentifier(ident) = &mut prop.key {
             let value = value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
             self.create_private_instance_init_assignment(ident, value, ctx)
         } else {
@@ -49,6 +49,8 @@ impl<'a> ClassProperties<'a, '_> {
             let this = ctx.ast.expression_this(SPAN);
             self.create_init_assignment(prop, value, this, false, ctx)
         };
+        // Span of the property, to keep comments attached to it
+        *init_expr.span_mut() = prop.span;
         instance_inits.push(init_expr);
     }
 
@@ -84,7 +86,6 @@ impl<'a> ClassProperties<'a, '_> {
             Argument::from(prop.binding.create_read_expression(ctx)),
             Argument::from(value),
         ]);
-        // TODO: Should this have span of original `PropertyDefinition`?
         self.ctx.helper_call_expr(Helper::ClassPrivateFieldInitSpec, SPAN, arguments, ctx)
     }
 }
@@ -108,7 +109,7 @@ impl<'a> ClassProperties<'a, '_> {
 
         if let PropertyKey::PrivateIdentifier(ident) = &mut prop.key {
             let value = value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
-            self.insert_private_static_init_assignment(ident, value, ctx);
+            self.insert_private_static_init_assignment(prop.span, ident, value, ctx);
         } else {
             let value = match value {
                 Some(value) => value,
@@ -137,7 +138,7 @@ impl<'a> ClassProperties<'a, '_> {
 
             let assignee = class_binding.create_read_expression(ctx);
             let init_expr = self.create_init_assignment(prop, value, assignee, true, ctx);
-            self.insert_expr_after_class(init_expr, ctx);
+            self.insert_expr_after_class(prop.span, init_expr, ctx);
         }
     }
 
@@ -151,14 +152,15 @@ impl<'a> ClassProperties<'a, '_> {
     /// `Object.defineProperty(Class, _prop, {writable: true, value: value});`
     fn insert_private_static_init_assignment(
         &mut self,
+        span: Span,
         ident: &PrivateIdentifier<'a>,
         value: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.private_fields_as_properties {
-            self.insert_private_static_init_assignment_loose(ident, value, ctx);
+            self.insert_private_static_init_assignment_loose(span, ident, value, ctx);
         } else {
-            self.insert_private_static_init_assignment_not_loose(ident, value, ctx);
+            self.insert_private_static_init_assignment_not_loose(span, ident, value, ctx);
         }
     }
 
@@ -166,6 +168,7 @@ impl<'a> ClassProperties<'a, '_> {
     /// `Object.defineProperty(Class, _prop, {writable: true, value: value});`
     fn insert_private_static_init_assignment_loose(
         &mut self,
+        span: Span,
         ident: &PrivateIdentifier<'a>,
         value: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
@@ -183,7 +186,7 @@ impl<'a> ClassProperties<'a, '_> {
 
         let assignee = class_binding.create_read_expression(ctx);
         let assignment = self.create_private_init_assignment_loose(ident, value, assignee, ctx);
-        self.insert_expr_after_class(assignment, ctx);
+        self.insert_expr_after_class(span, assignment, ctx);
     }
 
     /// Insert after class:
@@ -192,6 +195,7 @@ impl<'a> ClassProperties<'a, '_> {
     /// * Class expression: `_prop = {_: value}`
     fn insert_private_static_init_assignment_not_loose(
         &mut self,
+        span: Span,
         ident: &PrivateIdentifier<'a>,
         value: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
@@ -215,7 +219,8 @@ impl<'a> ClassProperties<'a, '_> {
 
         if class_details.is_declaration {
             // `var _prop = {_: value};`
-            let var_decl = create_variable_declaration(prop_binding, obj, ctx);
+            let mut var_decl = create_variable_declaration(prop_binding, obj, ctx);
+            *var_decl.span_mut() = span;
             self.insert_after_stmts.push(var_decl);
         } else {
             // `_prop = {_: value}`
@@ -287,7 +292,6 @@ impl<'a> ClassProperties<'a, '_> {
             }
         };
 
-        // TODO: Should this have span of the original `PropertyDefinition`?
         ctx.ast.expression_assignment(
             SPAN,
             AssignmentOperator::Assign,
@@ -328,7 +332,6 @@ impl<'a> ClassProperties<'a, '_> {
             Argument::from(key),
             Argument::from(value),
         ]);
-        // TODO: Should this have span of the original `PropertyDefinition`?
         self.ctx.helper_call_expr(Helper::DefineProperty, SPAN, arguments, ctx)
     }
 
@@ -384,7 +387,6 @@ impl<'a> ClassProperties<'a, '_> {
             Argument::from(prop_binding.create_read_expression(ctx)),
             Argument::from(prop_def),
         ]);
-        // TODO: Should this have span of original `PropertyDefinition`?
         ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
     }
 }
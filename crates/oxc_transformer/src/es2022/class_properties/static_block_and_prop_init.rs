@@ -49,7 +49,7 @@ impl<'a> ClassProperties<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let replacement = self.convert_static_block_to_expression(block, ctx);
-        self.insert_expr_after_class(replacement, ctx);
+        self.insert_expr_after_class(block.span, replacement, ctx);
     }
 
     fn convert_static_block_to_expression(
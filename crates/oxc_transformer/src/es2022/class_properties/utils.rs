@@ -4,7 +4,7 @@
 use std::path::Path;
 
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 use oxc_traverse::BoundIdentifier;
 
 use crate::context::TraverseCtx;
@@ -27,6 +27,9 @@ pub(super) fn create_variable_declaration<'a>(
 }
 
 /// Convert an iterator of `Expression`s into an iterator of `Statement::ExpressionStatement`s.
+///
+/// Statements have the span of their expression, so comments attached to the start of the original
+/// class element (e.g. `/* istanbul ignore next */`) are printed before the statement.
 pub(super) fn exprs_into_stmts<'a, E>(
     exprs: E,
     ctx: &TraverseCtx<'a>,
@@ -34,7 +37,7 @@ pub(super) fn exprs_into_stmts<'a, E>(
 where
     E: IntoIterator<Item = Expression<'a>>,
 {
-    exprs.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr))
+    exprs.into_iter().map(|expr| ctx.ast.statement_expression(expr.span(), expr))
 }
 
 /// Create `IdentifierName` for `_`.
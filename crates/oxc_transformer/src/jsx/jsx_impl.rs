@@ -341,7 +341,8 @@ impl<'a> Pragma<'a> {
         ctx: &TransformCtx<'a>,
     ) -> Self {
         if let Some(pragma) = pragma {
-            if pragma.is_empty() {
+            // `""`, `foo.`, `.foo` and `foo..bar` would produce empty identifiers
+            if pragma.split('.').any(str::is_empty) {
                 ctx.error(diagnostics::invalid_pragma());
             } else {
                 return Self::parse_impl(pragma, ast);
@@ -1368,4 +1369,21 @@ mod test {
         assert_eq!(&meta_prop.property.name, "meta");
         assert_eq!(member.property.name, "prop");
     }
+
+    #[test]
+    fn invalid_pragma() {
+        setup!(traverse_ctx, transform_ctx);
+
+        for pragma in ["", "foo.", ".foo", "foo..bar"] {
+            let pragma =
+                Pragma::parse(Some(pragma), "createElement", traverse_ctx.ast, &transform_ctx);
+            let expr = pragma.create_expression(traverse_ctx);
+
+            let Expression::StaticMemberExpression(member) = &expr else { panic!() };
+            let Expression::Identifier(object) = &member.object else { panic!() };
+            assert_eq!(object.name, "React");
+            assert_eq!(member.property.name, "createElement");
+        }
+        assert_eq!(transform_ctx.take_errors().len(), 4);
+    }
 }
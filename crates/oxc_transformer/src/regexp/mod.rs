@@ -1,8 +1,11 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
+//! When an unsupported feature is detected, the pattern is first rewritten with
+//! [`oxc_regular_expression::downlevel`] into an equivalent one, e.g. `/a.b/s` to `/a[\s\S]b/`,
+//! or `/(?<a>b)/` to `babelHelpers.wrapRegExp(/(b)/, { a: 1 })`.
+//! If that is not possible, the RegExp literal is converted into a `new RegExp()` constructor call
+//! to avoid syntax errors.
 //!
 //! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
 //!
@@ -44,16 +47,22 @@
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
+use oxc_allocator::{CloneIn, TakeIn};
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
+use oxc_regular_expression::{
+    DownlevelOptions,
+    ast::{
+        CharacterClass, CharacterClassContents, Disjunction, LookAroundAssertionKind, Pattern, Term,
+    },
+    downlevel,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
@@ -69,6 +78,7 @@ pub struct RegExp<'a, 'ctx> {
     look_behind_assertions: bool,
     named_capture_groups: bool,
     unicode_property_escapes: bool,
+    downlevel: DownlevelOptions,
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
@@ -102,6 +112,13 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
         let some_unsupported_patterns =
             look_behind_assertions || named_capture_groups || unicode_property_escapes;
 
+        let downlevel = DownlevelOptions {
+            dot_all_flag: options.dot_all_flag,
+            named_capture_groups,
+            look_behind_assertions,
+            set_notation: options.set_notation,
+        };
+
         Self {
             ctx,
             unsupported_flags,
@@ -109,6 +126,7 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
             look_behind_assertions,
             named_capture_groups,
             unicode_property_escapes,
+            downlevel,
         }
     }
 }
@@ -124,7 +142,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, rewrite it with [`downlevel`] if possible,
+    /// otherwise transform to `new RegExp(...)`.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -134,48 +153,140 @@ impl<'a> RegExp<'a, '_> {
         let pattern_text = regexp.regex.pattern.text;
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
+
+        let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
+            Ok(pattern.as_ref().clone_in(ctx.ast.allocator))
+        } else {
+            #[expect(clippy::cast_possible_truncation)]
+            let pattern_len = pattern_text.len() as u32;
+            let literal_span = regexp.span;
+            let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
+            let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
+            let flags_text =
+                Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
+            // Try to parse pattern
+            try_parse_pattern(
+                pattern_text.as_str(),
+                pattern_span_start,
+                flags_text,
+                flags_span_start,
+                ctx,
+            )
+        };
+
+        let pattern = match pattern {
+            Ok(pattern) => pattern,
+            // The RegExp needs transforming anyway, so leave the error to the runtime
+            Err(_) if has_unsupported_flags => {
+                *expr = Self::create_regexp_constructor(regexp.span, pattern_text, flags, ctx);
+                return;
+            }
+            Err(error) => {
+                self.ctx.error(error);
                 return;
             }
+        };
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
-            } else {
-                #[expect(clippy::cast_possible_truncation)]
-                let pattern_len = pattern_text.len() as u32;
-                let literal_span = regexp.span;
-                let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
-                let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
-                let flags_text =
-                    Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
-                // Try to parse pattern
-                match try_parse_pattern(
-                    pattern_text.as_str(),
-                    pattern_span_start,
-                    flags_text,
-                    flags_span_start,
-                    ctx,
-                ) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
-                    Err(error) => {
-                        self.ctx.error(error);
-                        return;
-                    }
-                }
-            };
+        if !has_unsupported_flags && !self.has_unsupported_regular_expression_pattern(&pattern) {
+            return;
+        }
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
-                return;
+        if let Some(new_expr) = self.try_downlevel(regexp, pattern, ctx) {
+            *expr = new_expr;
+            return;
+        }
+
+        *expr = Self::create_regexp_constructor(regexp.span, pattern_text, flags, ctx);
+    }
+
+    /// Rewrite the RegExp to only use supported features.
+    ///
+    /// Returns `None` if any unsupported feature remains.
+    fn try_downlevel(
+        &self,
+        regexp: &mut RegExpLiteral<'a>,
+        mut pattern: Pattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let flags_text = regexp.regex.flags.to_inline_string();
+        let ret =
+            downlevel(ctx.ast.allocator, &mut pattern, flags_text.as_str(), self.downlevel).ok()?;
+
+        let flags = ret
+            .flags
+            .chars()
+            .filter_map(|c| RegExpFlags::try_from(c).ok())
+            .fold(RegExpFlags::empty(), |flags, flag| flags | flag);
+        if flags.intersects(self.unsupported_flags)
+            || self.has_unsupported_regular_expression_pattern(&pattern)
+        {
+            return None;
+        }
+
+        let span = regexp.span;
+        regexp.regex.pattern.text = ctx.ast.atom(&pattern.to_string());
+        regexp.regex.pattern.pattern = Some(ctx.ast.alloc(pattern));
+        regexp.regex.flags = flags;
+        regexp.raw = None;
+        let regexp = Expression::RegExpLiteral(ctx.ast.alloc(regexp.take_in(ctx.ast)));
+
+        if ret.group_names.is_empty() {
+            return Some(regexp);
+        }
+
+        // `/(?<year>\d+)/` -> `babelHelpers.wrapRegExp(/(\d+)/, { year: 1 })`
+        let mut groups: Vec<(Atom<'a>, Vec<u32>)> = vec![];
+        for (name, index) in ret.group_names {
+            match groups.iter_mut().find(|(group_name, _)| *group_name == name) {
+                Some((_, indexes)) => indexes.push(index),
+                None => groups.push((name, vec![index])),
             }
         }
+        let number = |index: u32| {
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(index), None, NumberBase::Decimal)
+        };
+        let properties = ctx.ast.vec_from_iter(groups.into_iter().map(|(name, indexes)| {
+            let value = if let [index] = indexes.as_slice() {
+                number(*index)
+            } else {
+                ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec_from_iter(
+                        indexes
+                            .into_iter()
+                            .map(|index| ArrayExpressionElement::from(number(index))),
+                    ),
+                )
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_static_identifier(SPAN, name),
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(regexp),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        Some(self.ctx.helper_call_expr(Helper::WrapRegExp, span, arguments, ctx))
+    }
 
+    /// `new RegExp(pattern, flags)`
+    fn create_regexp_constructor(
+        span: Span,
+        pattern_text: Atom<'a>,
+        flags: RegExpFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
         let callee = {
             let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "RegExp");
             ctx.create_ident_expr(SPAN, Atom::from("RegExp"), symbol_id, ReferenceFlags::read())
@@ -190,14 +301,18 @@ impl<'a> RegExp<'a, '_> {
             )),
         ]);
 
-        *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
+        ctx.ast.expression_new(span, callee, NONE, arguments)
     }
 
     /// Check if the regular expression contains any unsupported syntax.
     ///
     /// Based on parsed regular expression pattern.
     fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
+        self.disjunction_contains_unsupported(&pattern.body)
+    }
+
+    fn disjunction_contains_unsupported(&self, disjunction: &Disjunction) -> bool {
+        disjunction.body.iter().any(|alternative| {
             alternative.body.iter().any(|term| self.term_contains_unsupported(term))
         })
     }
@@ -206,19 +321,26 @@ impl<'a> RegExp<'a, '_> {
         // Loop because `Term::Quantifier` contains a nested `Term`
         loop {
             match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
+                Term::CapturingGroup(group) => {
+                    return (self.named_capture_groups && group.name.is_some())
+                        || self.disjunction_contains_unsupported(&group.body);
+                }
+                Term::IgnoreGroup(group) => {
+                    return self.disjunction_contains_unsupported(&group.body);
+                }
                 Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
                 Term::CharacterClass(character_class) => {
                     return self.unicode_property_escapes
                         && character_class_has_unicode_property_escape(character_class);
                 }
                 Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
+                    return (self.look_behind_assertions
                         && matches!(
                             assertion.kind,
                             LookAroundAssertionKind::Lookbehind
                                 | LookAroundAssertionKind::NegativeLookbehind
-                        );
+                        ))
+                        || self.disjunction_contains_unsupported(&assertion.body);
                 }
                 Term::Quantifier(quantifier) => term = &quantifier.body,
                 _ => return false,
@@ -81,7 +81,7 @@ impl<'a> TypeScriptModule<'a, '_> {
         let right = export_assignment.expression.take_in(ctx.ast);
         let assignment_expr =
             ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, left, right);
-        ctx.ast.statement_expression(SPAN, assignment_expr)
+        ctx.ast.statement_expression(export_assignment.span, assignment_expr)
     }
 
     /// Transform TSImportEqualsDeclaration to a VariableDeclaration.
@@ -169,7 +169,7 @@ impl<'a> TypeScriptModule<'a, '_> {
         let decls =
             ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, binding, Some(init), false));
 
-        Some(ctx.ast.declaration_variable(SPAN, kind, decls, false))
+        Some(ctx.ast.declaration_variable(decl_span, kind, decls, false))
     }
 
     #[expect(clippy::only_used_in_recursion)]
@@ -1,7 +1,7 @@
 use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
 use oxc_ecmascript::BoundNames;
-use oxc_span::SPAN;
+use oxc_span::{GetSpanMut, SPAN};
 use oxc_syntax::{
     operator::{AssignmentOperator, LogicalOperator},
     scope::{ScopeFlags, ScopeId},
@@ -202,10 +202,13 @@ impl<'a> TypeScriptNamespace<'a, '_> {
                     // NB: `ExportNamedDeclaration` with no declaration (e.g. `export {x}`) is not
                     // legal syntax in TS namespaces
                     let export_decl = export_decl.unbox();
-                    if let Some(decl) = export_decl.declaration {
+                    if let Some(mut decl) = export_decl.declaration {
                         if decl.declare() {
                             continue;
                         }
+                        // Comments before `export` are attached to it, keep them by starting
+                        // the declaration at `export`
+                        decl.span_mut().start = export_decl.span.start;
                         match decl {
                             Declaration::TSImportEqualsDeclaration(ref import_equals) => {
                                 let binding =
@@ -0,0 +1,38 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::test_with_source_type;
+
+/// Comments attached to nodes that are moved or replaced by a lowering pass
+/// must still be present in the output.
+#[test]
+fn comments_survive_lowering() {
+    let cases = [
+        ("class A { /* static prop */ static a = 1; }", "/* static prop */"),
+        ("class A { /* instance prop */ a = 1; }", "/* instance prop */"),
+        ("class A { /* static block */ static { foo(); } }", "/* static block */"),
+        ("class A { /* private static */ static #a = 1; }", "/* private static */"),
+        ("namespace N { /* export const */ export const a = 1; }", "/* export const */"),
+        ("namespace N { /* export function */ export function f() {} }", "/* export function */"),
+        ("/* export assignment */ export = foo;", "/* export assignment */"),
+        ("/* import equals */ import a = require('a'); a;", "/* import equals */"),
+        ("namespace N { /* import alias */ import b = M.b; b; }", "/* import alias */"),
+        ("async function f() { /* for await */ for await (const x of y) {} }", "/* for await */"),
+        ("import(/* webpackChunkName: 'a' */ 'a');", "/* webpackChunkName: 'a' */"),
+        ("/* istanbul ignore next */ async function f() {}", "/* istanbul ignore next */"),
+        ("/* c8 ignore next */ const f = async () => {};", "/* c8 ignore next */"),
+        ("/* v8 ignore next */ ({ ...x });", "/* v8 ignore next */"),
+    ];
+
+    let options = TransformOptions::from_target("es2015").unwrap();
+    for (source, comment) in cases {
+        let source_type = if source.contains("export =") {
+            SourceType::cjs().with_typescript(true)
+        } else {
+            SourceType::ts()
+        };
+        let code = test_with_source_type(source, source_type, &options)
+            .unwrap_or_else(|errors| panic!("{source}: {errors:?}"));
+        assert!(code.contains(comment), "`{comment}` is lost in:\n{source}\n---\n{code}");
+    }
+}
@@ -0,0 +1,29 @@
+use oxc_diagnostics::Severity;
+use oxc_span::SourceType;
+use oxc_transformer::{DecoratorOptions, TransformOptions};
+
+use crate::test_with_source_type;
+
+#[test]
+fn invalid_pragma_comment() {
+    let source = "/** @jsxRuntime classic */\n/** @jsx h..div */\n<div />";
+    let errors =
+        test_with_source_type(source, SourceType::jsx(), &TransformOptions::default()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].severity, Severity::Warning);
+    assert_eq!(
+        errors[0].to_string(),
+        "pragma and pragmaFrag must be of the form `foo` or `foo.bar`."
+    );
+}
+
+#[test]
+fn decorator_metadata_negative_bigint() {
+    let options = TransformOptions {
+        decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+        ..TransformOptions::default()
+    };
+    let source = "class C { @dec m(x: -1n, y: -1) {} }";
+    let code = test_with_source_type(source, SourceType::ts(), &options).unwrap();
+    assert!(code.contains("[BigInt, Number]"), "{code}");
+}
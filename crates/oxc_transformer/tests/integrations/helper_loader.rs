@@ -0,0 +1,18 @@
+use oxc_diagnostics::Severity;
+use oxc_transformer::{HelperLoaderMode, HelperLoaderOptions, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn inline_helpers_fall_back_to_runtime() {
+    let mut options = TransformOptions::from_target("es2015").unwrap();
+    options.helper_loader =
+        HelperLoaderOptions { mode: HelperLoaderMode::Inline, ..HelperLoaderOptions::default() };
+    let errors = test("({ ...x })", &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].severity, Severity::Warning);
+    assert_eq!(
+        errors[0].to_string(),
+        "Inline helpers are not supported yet, runtime helpers are used instead."
+    );
+}
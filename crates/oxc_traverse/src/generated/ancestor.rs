@@ -192,130 +192,132 @@ pub(crate) enum AncestorType {
     ExportSpecifierExported = 167,
     V8IntrinsicExpressionName = 168,
     V8IntrinsicExpressionArguments = 169,
-    JSXElementOpeningElement = 170,
-    JSXElementChildren = 171,
-    JSXElementClosingElement = 172,
-    JSXOpeningElementName = 173,
-    JSXOpeningElementTypeArguments = 174,
-    JSXOpeningElementAttributes = 175,
-    JSXClosingElementName = 176,
-    JSXFragmentOpeningFragment = 177,
-    JSXFragmentChildren = 178,
-    JSXFragmentClosingFragment = 179,
-    JSXNamespacedNameNamespace = 180,
-    JSXNamespacedNameName = 181,
-    JSXMemberExpressionObject = 182,
-    JSXMemberExpressionProperty = 183,
-    JSXExpressionContainerExpression = 184,
-    JSXAttributeName = 185,
-    JSXAttributeValue = 186,
-    JSXSpreadAttributeArgument = 187,
-    JSXSpreadChildExpression = 188,
-    TSThisParameterTypeAnnotation = 189,
-    TSEnumDeclarationId = 190,
-    TSEnumDeclarationBody = 191,
-    TSEnumBodyMembers = 192,
-    TSEnumMemberId = 193,
-    TSEnumMemberInitializer = 194,
-    TSTypeAnnotationTypeAnnotation = 195,
-    TSLiteralTypeLiteral = 196,
-    TSConditionalTypeCheckType = 197,
-    TSConditionalTypeExtendsType = 198,
-    TSConditionalTypeTrueType = 199,
-    TSConditionalTypeFalseType = 200,
-    TSUnionTypeTypes = 201,
-    TSIntersectionTypeTypes = 202,
-    TSParenthesizedTypeTypeAnnotation = 203,
-    TSTypeOperatorTypeAnnotation = 204,
-    TSArrayTypeElementType = 205,
-    TSIndexedAccessTypeObjectType = 206,
-    TSIndexedAccessTypeIndexType = 207,
-    TSTupleTypeElementTypes = 208,
-    TSNamedTupleMemberLabel = 209,
-    TSNamedTupleMemberElementType = 210,
-    TSOptionalTypeTypeAnnotation = 211,
-    TSRestTypeTypeAnnotation = 212,
-    TSTypeReferenceTypeName = 213,
-    TSTypeReferenceTypeArguments = 214,
-    TSQualifiedNameLeft = 215,
-    TSQualifiedNameRight = 216,
-    TSTypeParameterInstantiationParams = 217,
-    TSTypeParameterName = 218,
-    TSTypeParameterConstraint = 219,
-    TSTypeParameterDefault = 220,
-    TSTypeParameterDeclarationParams = 221,
-    TSTypeAliasDeclarationId = 222,
-    TSTypeAliasDeclarationTypeParameters = 223,
-    TSTypeAliasDeclarationTypeAnnotation = 224,
-    TSClassImplementsExpression = 225,
-    TSClassImplementsTypeArguments = 226,
-    TSInterfaceDeclarationId = 227,
-    TSInterfaceDeclarationTypeParameters = 228,
-    TSInterfaceDeclarationExtends = 229,
-    TSInterfaceDeclarationBody = 230,
-    TSInterfaceBodyBody = 231,
-    TSPropertySignatureKey = 232,
-    TSPropertySignatureTypeAnnotation = 233,
-    TSIndexSignatureParameters = 234,
-    TSIndexSignatureTypeAnnotation = 235,
-    TSCallSignatureDeclarationTypeParameters = 236,
-    TSCallSignatureDeclarationThisParam = 237,
-    TSCallSignatureDeclarationParams = 238,
-    TSCallSignatureDeclarationReturnType = 239,
-    TSMethodSignatureKey = 240,
-    TSMethodSignatureTypeParameters = 241,
-    TSMethodSignatureThisParam = 242,
-    TSMethodSignatureParams = 243,
-    TSMethodSignatureReturnType = 244,
-    TSConstructSignatureDeclarationTypeParameters = 245,
-    TSConstructSignatureDeclarationParams = 246,
-    TSConstructSignatureDeclarationReturnType = 247,
-    TSIndexSignatureNameTypeAnnotation = 248,
-    TSInterfaceHeritageExpression = 249,
-    TSInterfaceHeritageTypeArguments = 250,
-    TSTypePredicateParameterName = 251,
-    TSTypePredicateTypeAnnotation = 252,
-    TSModuleDeclarationId = 253,
-    TSModuleDeclarationBody = 254,
-    TSModuleBlockDirectives = 255,
-    TSModuleBlockBody = 256,
-    TSTypeLiteralMembers = 257,
-    TSInferTypeTypeParameter = 258,
-    TSTypeQueryExprName = 259,
-    TSTypeQueryTypeArguments = 260,
-    TSImportTypeArgument = 261,
-    TSImportTypeOptions = 262,
-    TSImportTypeQualifier = 263,
-    TSImportTypeTypeArguments = 264,
-    TSFunctionTypeTypeParameters = 265,
-    TSFunctionTypeThisParam = 266,
-    TSFunctionTypeParams = 267,
-    TSFunctionTypeReturnType = 268,
-    TSConstructorTypeTypeParameters = 269,
-    TSConstructorTypeParams = 270,
-    TSConstructorTypeReturnType = 271,
-    TSMappedTypeTypeParameter = 272,
-    TSMappedTypeNameType = 273,
-    TSMappedTypeTypeAnnotation = 274,
-    TSTemplateLiteralTypeQuasis = 275,
-    TSTemplateLiteralTypeTypes = 276,
-    TSAsExpressionExpression = 277,
-    TSAsExpressionTypeAnnotation = 278,
-    TSSatisfiesExpressionExpression = 279,
-    TSSatisfiesExpressionTypeAnnotation = 280,
-    TSTypeAssertionTypeAnnotation = 281,
-    TSTypeAssertionExpression = 282,
-    TSImportEqualsDeclarationId = 283,
-    TSImportEqualsDeclarationModuleReference = 284,
-    TSExternalModuleReferenceExpression = 285,
-    TSNonNullExpressionExpression = 286,
-    DecoratorExpression = 287,
-    TSExportAssignmentExpression = 288,
-    TSNamespaceExportDeclarationId = 289,
-    TSInstantiationExpressionExpression = 290,
-    TSInstantiationExpressionTypeArguments = 291,
-    JSDocNullableTypeTypeAnnotation = 292,
-    JSDocNonNullableTypeTypeAnnotation = 293,
+    PipelineExpressionHead = 170,
+    PipelineExpressionBody = 171,
+    JSXElementOpeningElement = 172,
+    JSXElementChildren = 173,
+    JSXElementClosingElement = 174,
+    JSXOpeningElementName = 175,
+    JSXOpeningElementTypeArguments = 176,
+    JSXOpeningElementAttributes = 177,
+    JSXClosingElementName = 178,
+    JSXFragmentOpeningFragment = 179,
+    JSXFragmentChildren = 180,
+    JSXFragmentClosingFragment = 181,
+    JSXNamespacedNameNamespace = 182,
+    JSXNamespacedNameName = 183,
+    JSXMemberExpressionObject = 184,
+    JSXMemberExpressionProperty = 185,
+    JSXExpressionContainerExpression = 186,
+    JSXAttributeName = 187,
+    JSXAttributeValue = 188,
+    JSXSpreadAttributeArgument = 189,
+    JSXSpreadChildExpression = 190,
+    TSThisParameterTypeAnnotation = 191,
+    TSEnumDeclarationId = 192,
+    TSEnumDeclarationBody = 193,
+    TSEnumBodyMembers = 194,
+    TSEnumMemberId = 195,
+    TSEnumMemberInitializer = 196,
+    TSTypeAnnotationTypeAnnotation = 197,
+    TSLiteralTypeLiteral = 198,
+    TSConditionalTypeCheckType = 199,
+    TSConditionalTypeExtendsType = 200,
+    TSConditionalTypeTrueType = 201,
+    TSConditionalTypeFalseType = 202,
+    TSUnionTypeTypes = 203,
+    TSIntersectionTypeTypes = 204,
+    TSParenthesizedTypeTypeAnnotation = 205,
+    TSTypeOperatorTypeAnnotation = 206,
+    TSArrayTypeElementType = 207,
+    TSIndexedAccessTypeObjectType = 208,
+    TSIndexedAccessTypeIndexType = 209,
+    TSTupleTypeElementTypes = 210,
+    TSNamedTupleMemberLabel = 211,
+    TSNamedTupleMemberElementType = 212,
+    TSOptionalTypeTypeAnnotation = 213,
+    TSRestTypeTypeAnnotation = 214,
+    TSTypeReferenceTypeName = 215,
+    TSTypeReferenceTypeArguments = 216,
+    TSQualifiedNameLeft = 217,
+    TSQualifiedNameRight = 218,
+    TSTypeParameterInstantiationParams = 219,
+    TSTypeParameterName = 220,
+    TSTypeParameterConstraint = 221,
+    TSTypeParameterDefault = 222,
+    TSTypeParameterDeclarationParams = 223,
+    TSTypeAliasDeclarationId = 224,
+    TSTypeAliasDeclarationTypeParameters = 225,
+    TSTypeAliasDeclarationTypeAnnotation = 226,
+    TSClassImplementsExpression = 227,
+    TSClassImplementsTypeArguments = 228,
+    TSInterfaceDeclarationId = 229,
+    TSInterfaceDeclarationTypeParameters = 230,
+    TSInterfaceDeclarationExtends = 231,
+    TSInterfaceDeclarationBody = 232,
+    TSInterfaceBodyBody = 233,
+    TSPropertySignatureKey = 234,
+    TSPropertySignatureTypeAnnotation = 235,
+    TSIndexSignatureParameters = 236,
+    TSIndexSignatureTypeAnnotation = 237,
+    TSCallSignatureDeclarationTypeParameters = 238,
+    TSCallSignatureDeclarationThisParam = 239,
+    TSCallSignatureDeclarationParams = 240,
+    TSCallSignatureDeclarationReturnType = 241,
+    TSMethodSignatureKey = 242,
+    TSMethodSignatureTypeParameters = 243,
+    TSMethodSignatureThisParam = 244,
+    TSMethodSignatureParams = 245,
+    TSMethodSignatureReturnType = 246,
+    TSConstructSignatureDeclarationTypeParameters = 247,
+    TSConstructSignatureDeclarationParams = 248,
+    TSConstructSignatureDeclarationReturnType = 249,
+    TSIndexSignatureNameTypeAnnotation = 250,
+    TSInterfaceHeritageExpression = 251,
+    TSInterfaceHeritageTypeArguments = 252,
+    TSTypePredicateParameterName = 253,
+    TSTypePredicateTypeAnnotation = 254,
+    TSModuleDeclarationId = 255,
+    TSModuleDeclarationBody = 256,
+    TSModuleBlockDirectives = 257,
+    TSModuleBlockBody = 258,
+    TSTypeLiteralMembers = 259,
+    TSInferTypeTypeParameter = 260,
+    TSTypeQueryExprName = 261,
+    TSTypeQueryTypeArguments = 262,
+    TSImportTypeArgument = 263,
+    TSImportTypeOptions = 264,
+    TSImportTypeQualifier = 265,
+    TSImportTypeTypeArguments = 266,
+    TSFunctionTypeTypeParameters = 267,
+    TSFunctionTypeThisParam = 268,
+    TSFunctionTypeParams = 269,
+    TSFunctionTypeReturnType = 270,
+    TSConstructorTypeTypeParameters = 271,
+    TSConstructorTypeParams = 272,
+    TSConstructorTypeReturnType = 273,
+    TSMappedTypeTypeParameter = 274,
+    TSMappedTypeNameType = 275,
+    TSMappedTypeTypeAnnotation = 276,
+    TSTemplateLiteralTypeQuasis = 277,
+    TSTemplateLiteralTypeTypes = 278,
+    TSAsExpressionExpression = 279,
+    TSAsExpressionTypeAnnotation = 280,
+    TSSatisfiesExpressionExpression = 281,
+    TSSatisfiesExpressionTypeAnnotation = 282,
+    TSTypeAssertionTypeAnnotation = 283,
+    TSTypeAssertionExpression = 284,
+    TSImportEqualsDeclarationId = 285,
+    TSImportEqualsDeclarationModuleReference = 286,
+    TSExternalModuleReferenceExpression = 287,
+    TSNonNullExpressionExpression = 288,
+    DecoratorExpression = 289,
+    TSExportAssignmentExpression = 290,
+    TSNamespaceExportDeclarationId = 291,
+    TSInstantiationExpressionExpression = 292,
+    TSInstantiationExpressionTypeArguments = 293,
+    JSDocNullableTypeTypeAnnotation = 294,
+    JSDocNonNullableTypeTypeAnnotation = 295,
 }
 
 /// Ancestor type used in AST traversal.
@@ -640,6 +642,10 @@ pub enum Ancestor<'a, 't> {
         AncestorType::V8IntrinsicExpressionName as u16,
     V8IntrinsicExpressionArguments(V8IntrinsicExpressionWithoutArguments<'a, 't>) =
         AncestorType::V8IntrinsicExpressionArguments as u16,
+    PipelineExpressionHead(PipelineExpressionWithoutHead<'a, 't>) =
+        AncestorType::PipelineExpressionHead as u16,
+    PipelineExpressionBody(PipelineExpressionWithoutBody<'a, 't>) =
+        AncestorType::PipelineExpressionBody as u16,
     JSXElementOpeningElement(JSXElementWithoutOpeningElement<'a, 't>) =
         AncestorType::JSXElementOpeningElement as u16,
     JSXElementChildren(JSXElementWithoutChildren<'a, 't>) = AncestorType::JSXElementChildren as u16,
@@ -1424,6 +1430,11 @@ impl<'a, 't> Ancestor<'a, 't> {
         matches!(self, Self::V8IntrinsicExpressionName(_) | Self::V8IntrinsicExpressionArguments(_))
     }
 
+    #[inline]
+    pub fn is_pipeline_expression(self) -> bool {
+        matches!(self, Self::PipelineExpressionHead(_) | Self::PipelineExpressionBody(_))
+    }
+
     #[inline]
     pub fn is_jsx_element(self) -> bool {
         matches!(
@@ -1954,6 +1965,8 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::AccessorPropertyValue(_)
                 | Self::ImportExpressionSource(_)
                 | Self::ImportExpressionOptions(_)
+                | Self::PipelineExpressionHead(_)
+                | Self::PipelineExpressionBody(_)
                 | Self::JSXSpreadAttributeArgument(_)
                 | Self::JSXSpreadChildExpression(_)
                 | Self::TSEnumMemberInitializer(_)
@@ -2366,6 +2379,8 @@ impl<'a, 't> GetAddress for Ancestor<'a, 't> {
             Self::ExportSpecifierExported(a) => a.address(),
             Self::V8IntrinsicExpressionName(a) => a.address(),
             Self::V8IntrinsicExpressionArguments(a) => a.address(),
+            Self::PipelineExpressionHead(a) => a.address(),
+            Self::PipelineExpressionBody(a) => a.address(),
             Self::JSXElementOpeningElement(a) => a.address(),
             Self::JSXElementChildren(a) => a.address(),
             Self::JSXElementClosingElement(a) => a.address(),
@@ -10460,6 +10475,66 @@ impl<'a, 't> GetAddress for V8IntrinsicExpressionWithoutArguments<'a, 't> {
     }
 }
 
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_SPAN: usize = offset_of!(PipelineExpression, span);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_HEAD: usize = offset_of!(PipelineExpression, head);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_BODY: usize = offset_of!(PipelineExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutHead<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn body(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_BODY) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        Address::from_ptr(self.0)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutBody<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn head(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_HEAD) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        Address::from_ptr(self.0)
+    }
+}
+
 pub(crate) const OFFSET_JSX_ELEMENT_SPAN: usize = offset_of!(JSXElement, span);
 pub(crate) const OFFSET_JSX_ELEMENT_OPENING_ELEMENT: usize =
     offset_of!(JSXElement, opening_element);
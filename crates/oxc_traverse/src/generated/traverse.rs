@@ -1588,6 +1588,36 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
     #[inline]
     fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a, State>) {}
     #[inline]
@@ -197,6 +197,12 @@ unsafe fn walk_expression<'a, State, Tr: Traverse<'a, State>>(
         Expression::V8IntrinsicExpression(node) => {
             walk_v8_intrinsic_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::TopicReference(node) => {
+            walk_topic_reference(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::PipelineExpression(node) => {
+            walk_pipeline_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -320,6 +326,8 @@ unsafe fn walk_array_expression_element<'a, State, Tr: Traverse<'a, State>>(
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
         | ArrayExpressionElement::V8IntrinsicExpression(_)
+        | ArrayExpressionElement::TopicReference(_)
+        | ArrayExpressionElement::PipelineExpression(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -450,6 +458,8 @@ unsafe fn walk_property_key<'a, State, Tr: Traverse<'a, State>>(
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
         | PropertyKey::V8IntrinsicExpression(_)
+        | PropertyKey::TopicReference(_)
+        | PropertyKey::PipelineExpression(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -773,6 +783,8 @@ unsafe fn walk_argument<'a, State, Tr: Traverse<'a, State>>(
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
         | Argument::V8IntrinsicExpression(_)
+        | Argument::TopicReference(_)
+        | Argument::PipelineExpression(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -1722,6 +1734,8 @@ unsafe fn walk_for_statement_init<'a, State, Tr: Traverse<'a, State>>(
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
         | ForStatementInit::V8IntrinsicExpression(_)
+        | ForStatementInit::TopicReference(_)
+        | ForStatementInit::PipelineExpression(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -3119,6 +3133,8 @@ unsafe fn walk_export_default_declaration_kind<'a, State, Tr: Traverse<'a, State
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
         | ExportDefaultDeclarationKind::V8IntrinsicExpression(_)
+        | ExportDefaultDeclarationKind::TopicReference(_)
+        | ExportDefaultDeclarationKind::PipelineExpression(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3172,6 +3188,39 @@ unsafe fn walk_v8_intrinsic_expression<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_v8_intrinsic_expression(&mut *node, ctx);
 }
 
+unsafe fn walk_topic_reference<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut TopicReference,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_topic_reference(&mut *node, ctx);
+    traverser.exit_topic_reference(&mut *node, ctx);
+}
+
+unsafe fn walk_pipeline_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut PipelineExpression<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_pipeline_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::PipelineExpressionHead(
+        ancestor::PipelineExpressionWithoutHead(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_HEAD) as *mut Expression,
+        ctx,
+    );
+    ctx.retag_stack(AncestorType::PipelineExpressionBody);
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_BODY) as *mut Expression,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_pipeline_expression(&mut *node, ctx);
+}
+
 unsafe fn walk_jsx_element<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     node: *mut JSXElement<'a>,
@@ -3467,6 +3516,8 @@ unsafe fn walk_jsx_expression<'a, State, Tr: Traverse<'a, State>>(
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
         | JSXExpression::V8IntrinsicExpression(_)
+        | JSXExpression::TopicReference(_)
+        | JSXExpression::PipelineExpression(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
@@ -31,12 +31,14 @@ const commandPrefix = 'oxc';
 const enum OxcCommands {
   RestartServer = `${commandPrefix}.restartServer`,
   ApplyAllFixesFile = `${commandPrefix}.applyAllFixesFile`,
+  ApplyAllFixesWorkspace = `${commandPrefix}.applyAllFixesWorkspace`,
   ShowOutputChannel = `${commandPrefix}.showOutputChannel`,
   ToggleEnable = `${commandPrefix}.toggleEnable`,
 }
 
 const enum LspCommands {
   FixAll = 'oxc.fixAll',
+  FixAllWorkspace = 'oxc.fixAllWorkspace',
 }
 
 let client: LanguageClient | undefined;
@@ -126,10 +128,28 @@ export async function activate(context: ExtensionContext) {
     },
   );
 
+  const applyAllFixesWorkspace = commands.registerCommand(
+    OxcCommands.ApplyAllFixesWorkspace,
+    async () => {
+      if (!client) {
+        window.showErrorMessage('oxc client not found');
+        return;
+      }
+
+      const params = {
+        command: LspCommands.FixAllWorkspace,
+        arguments: [],
+      };
+
+      await client.sendRequest(ExecuteCommandRequest.type, params);
+    },
+  );
+
   const outputChannel = window.createOutputChannel(outputChannelName, { log: true });
 
   context.subscriptions.push(
     applyAllFixesFile,
+    applyAllFixesWorkspace,
     restartCommand,
     showOutputCommand,
     toggleEnable,
@@ -210,6 +230,24 @@ export async function activate(context: ExtensionContext) {
     clientOptions,
   );
 
+  // Experimental: let the server reveal the output channel when it logged an error.
+  client.registerFeature({
+    fillClientCapabilities(capabilities) {
+      capabilities.experimental = { ...capabilities.experimental, showOutputChannelOnError: true };
+    },
+    initialize() {},
+    getState() {
+      return { kind: 'static' };
+    },
+    clear() {},
+  });
+
+  const onShowOutputChannelDispose = client.onNotification('oxc/showOutputChannelOnError', () => {
+    outputChannel.show(true);
+  });
+
+  context.subscriptions.push(onShowOutputChannelDispose);
+
   const onNotificationDispose = client.onNotification(ShowMessageNotification.type, (params) => {
     switch (params.type) {
       case MessageType.Debug:
@@ -36,7 +36,9 @@ suite('commands', () => {
       'oxc.showOutputChannel',
       'oxc.toggleEnable',
       'oxc.applyAllFixesFile',
+      'oxc.applyAllFixesWorkspace',
       'oxc.fixAll',
+      'oxc.fixAllWorkspace',
     ], oxcCommands);
   });
 
@@ -7,6 +7,13 @@ export interface CodegenOptions {
    * @default true
    */
   removeWhitespace?: boolean
+  /**
+   * Keep a line break after each statement and the indentation of blocks when removing
+   * whitespace, for minified code which can still be diffed and debugged.
+   *
+   * @default false
+   */
+  keepLineBreaks?: boolean
 }
 
 export interface CompressOptions {
@@ -23,6 +30,15 @@ export interface CompressOptions {
    * @default 'esnext'
    */
   target?: 'esnext' | 'es2015' | 'es2016' | 'es2017' | 'es2018' | 'es2019' | 'es2020' | 'es2021' | 'es2022' | 'es2023' | 'es2024'
+  /**
+   * Oldest EcmaScript edition which has to parse property names written without quotes.
+   *
+   * `a['default']` is kept as is for `es3`, and `a.default` is printed as `a["default"]`.
+   * Non-ASCII property names are quoted for `es3` and `es5`.
+   *
+   * @default 'es2015'
+   */
+  propertyNameTarget?: 'es3' | 'es5' | 'es2015'
   /**
    * Pass true to discard calls to `console.*`.
    *
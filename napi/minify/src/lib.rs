@@ -69,6 +69,8 @@ pub fn minify(
     let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
     let mut program = parser_ret.program;
 
+    let property_name_target =
+        minifier_options.compress.as_ref().map(|compress| compress.property_name_target);
     let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
 
     let mut codegen_options = match &options.codegen {
@@ -78,6 +80,11 @@ pub fn minify(
         Some(Either::B(o)) => CodegenOptions::from(o),
     };
 
+    // Quote the property names which the compressor kept quoted.
+    if let Some(property_name_target) = property_name_target {
+        codegen_options.property_name_target = property_name_target;
+    }
+
     if options.sourcemap == Some(true) {
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
     }
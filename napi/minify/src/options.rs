@@ -3,7 +3,7 @@ use std::str::FromStr;
 use napi::Either;
 use napi_derive::napi;
 
-use oxc_minifier::TreeShakeOptions;
+use oxc_minifier::{PropertyNameTarget, TreeShakeOptions};
 use oxc_syntax::es_target::ESTarget;
 
 #[napi(object)]
@@ -23,6 +23,15 @@ pub struct CompressOptions {
     )]
     pub target: Option<String>,
 
+    /// Oldest EcmaScript edition which has to parse property names written without quotes.
+    ///
+    /// `a['default']` is kept as is for `es3`, and `a.default` is printed as `a["default"]`.
+    /// Non-ASCII property names are quoted for `es3` and `es5`.
+    ///
+    /// @default 'es2015'
+    #[napi(ts_type = "'es3' | 'es5' | 'es2015'")]
+    pub property_name_target: Option<String>,
+
     /// Pass true to discard calls to `console.*`.
     ///
     /// @default false
@@ -54,6 +63,13 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 .map(|s| ESTarget::from_str(s))
                 .transpose()?
                 .unwrap_or(default.target),
+            property_name_target: match o.property_name_target.as_deref() {
+                Some("es3") => PropertyNameTarget::ES3,
+                Some("es5") => PropertyNameTarget::ES5,
+                Some("es2015") => PropertyNameTarget::ES2015,
+                Some(s) => return Err(format!("Invalid property name target \"{s}\".")),
+                None => default.property_name_target,
+            },
             drop_console: o.drop_console.unwrap_or(default.drop_console),
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
             // TODO
@@ -148,18 +164,27 @@ pub struct CodegenOptions {
     ///
     /// @default true
     pub remove_whitespace: Option<bool>,
+
+    /// Keep a line break after each statement and the indentation of blocks when removing
+    /// whitespace, for minified code which can still be diffed and debugged.
+    ///
+    /// @default false
+    pub keep_line_breaks: Option<bool>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
-        Self { remove_whitespace: Some(true) }
+        Self { remove_whitespace: Some(true), keep_line_breaks: Some(false) }
     }
 }
 
 impl From<&CodegenOptions> for oxc_codegen::CodegenOptions {
     fn from(o: &CodegenOptions) -> Self {
         if o.remove_whitespace.is_some_and(|b| b) {
-            oxc_codegen::CodegenOptions::minify()
+            oxc_codegen::CodegenOptions {
+                keep_line_breaks: o.keep_line_breaks.is_some_and(|b| b),
+                ..oxc_codegen::CodegenOptions::minify()
+            }
         } else {
             // Need to remove all comments.
             oxc_codegen::CodegenOptions { minify: false, ..oxc_codegen::CodegenOptions::minify() }
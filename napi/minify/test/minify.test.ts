@@ -34,6 +34,13 @@ describe('simple', () => {
     );
   });
 
+  it('can keep line breaks', () => {
+    const ret = minify('test.js', code, { compress: false, mangle: false, codegen: { keepLineBreaks: true } });
+    expect(ret.code).toBe(
+      'function foo(){\n\tvar bar;\n\tbar(undefined);\n}\nfoo();\n',
+    );
+  });
+
   it('defaults to esnext', () => {
     const code = 'try { foo } catch (e) {}';
     const ret = minify('test.js', code);
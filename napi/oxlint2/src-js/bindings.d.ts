@@ -6,4 +6,25 @@ export type JsLintFileCb =
 export type JsLoadPluginCb =
   ((arg: string) => Promise<string>)
 
-export declare function lint(loadPlugin: JsLoadPluginCb, lintFile: JsLintFileCb): Promise<boolean>
+export type JsReportCb =
+  ((arg0: string, arg1: string) => string | undefined | null)
+
+/** Deregister all JS worker threads, once they are stopped at the end of a run. */
+export declare function clearLintWorkers(): void
+
+/**
+ * Deregister the JS worker thread with ID `id`, after it failed.
+ *
+ * Files which would be sent to this worker are linted on the main thread instead.
+ */
+export declare function deregisterLintWorker(id: number): void
+
+export declare function lint(loadPlugin: JsLoadPluginCb, lintFile: JsLintFileCb, reporters?: Record<string, JsReportCb> | undefined | null): Promise<boolean>
+
+/**
+ * Register the `lintFile` callback of the JS worker thread with ID `id`.
+ *
+ * Once any workers are registered, Rust sends files to lint to workers instead of to the
+ * `lintFile` passed to `lint`.
+ */
+export declare function registerLintWorker(id: number, lintFile: JsLintFileCb): void
@@ -393,5 +393,8 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { lint } = nativeBinding
+const { clearLintWorkers, deregisterLintWorker, lint, registerLintWorker } = nativeBinding
+export { clearLintWorkers }
+export { deregisterLintWorker }
 export { lint }
+export { registerLintWorker }
@@ -0,0 +1,125 @@
+// Fixes and suggestions reported by rules.
+//
+// Rules report a fix with the `fix` property of the diagnostic passed to `context.report`,
+// and suggestions with the `suggest` property, as in ESLint:
+//
+// ```
+// context.report({
+//   message: 'Unexpected `debugger` statement',
+//   node,
+//   fix: fixer => fixer.remove(node),
+//   suggest: [
+//     { desc: 'Replace with a comment', fix: fixer => fixer.replaceText(node, '/* debugger */') },
+//   ],
+// });
+// ```
+//
+// A fix function returns a single fix, an iterable of fixes which are applied together, or `null`.
+// Fixes are sent to Rust as the edits `{ start, end, text }`.
+
+// Range of source text, as `[start, end]`.
+export type Range = [number, number];
+
+// Node or token, which fixes are applied to.
+export interface Ranged {
+  start: number;
+  end: number;
+  [key: string]: unknown;
+}
+
+export interface Fix {
+  range: Range;
+  text: string;
+}
+
+export type FixFn = (fixer: Fixer) => Fix | Iterable<Fix> | null | undefined;
+
+export interface Suggestion {
+  desc: string;
+  fix: FixFn;
+}
+
+// Edit sent to Rust.
+export interface FixReport {
+  start: number;
+  end: number;
+  text: string;
+}
+
+// Suggestion sent to Rust.
+export interface SuggestionReport {
+  message: string;
+  fixes: FixReport[];
+}
+
+export type Fixer = typeof FIXER;
+
+const FIXER = Object.freeze({
+  insertTextBefore(nodeOrToken: Ranged, text: string): Fix {
+    return { range: [nodeOrToken.start, nodeOrToken.start], text };
+  },
+  insertTextBeforeRange(range: Range, text: string): Fix {
+    return { range: [range[0], range[0]], text };
+  },
+  insertTextAfter(nodeOrToken: Ranged, text: string): Fix {
+    return { range: [nodeOrToken.end, nodeOrToken.end], text };
+  },
+  insertTextAfterRange(range: Range, text: string): Fix {
+    return { range: [range[1], range[1]], text };
+  },
+  remove(nodeOrToken: Ranged): Fix {
+    return { range: [nodeOrToken.start, nodeOrToken.end], text: '' };
+  },
+  removeRange(range: Range): Fix {
+    return { range: [range[0], range[1]], text: '' };
+  },
+  replaceText(nodeOrToken: Ranged, text: string): Fix {
+    return { range: [nodeOrToken.start, nodeOrToken.end], text };
+  },
+  replaceTextRange(range: Range, text: string): Fix {
+    return { range: [range[0], range[1]], text };
+  },
+});
+
+/**
+ * Call a fix function, and convert the fixes it returns to edits to send to Rust.
+ *
+ * @param fix - Fix function
+ * @returns Edits, or `null` if the fix function returned no fixes
+ * @throws {TypeError} If the fix function returns something which is not a fix
+ */
+export function getFixes(fix: FixFn): FixReport[] | null {
+  const result = fix(FIXER);
+  if (result === null || result === undefined) return null;
+
+  const fixes = isFix(result) ? [result] : Array.from(result);
+  if (fixes.length === 0) return null;
+
+  return fixes.map((fix) => {
+    if (!isFix(fix)) throw new TypeError('Fix objects must have a `range` and a `text` property');
+    return { start: fix.range[0], end: fix.range[1], text: fix.text };
+  });
+}
+
+/**
+ * Convert suggestions to send to Rust.
+ *
+ * @param suggestions - Suggestions
+ * @returns Suggestions which have fixes
+ */
+export function getSuggestions(suggestions: Suggestion[]): SuggestionReport[] {
+  const reports = [];
+  for (const { desc, fix } of suggestions) {
+    if (typeof desc !== 'string') throw new TypeError('Suggestions must have a `desc` property');
+    const fixes = getFixes(fix);
+    if (fixes !== null) reports.push({ message: desc, fixes });
+  }
+  return reports;
+}
+
+function isFix(value: unknown): value is Fix {
+  if (value === null || typeof value !== 'object') return false;
+  const { range, text } = value as Fix;
+  return Array.isArray(range) && typeof range[0] === 'number' && typeof range[1] === 'number' &&
+    typeof text === 'string';
+}
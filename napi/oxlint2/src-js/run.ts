@@ -0,0 +1,46 @@
+import { lint } from './bindings.js';
+import { lintFile, loadPlugin, resetPlugins } from './plugins.js';
+import { getErrorMessage } from './utils.js';
+import { loadPluginInWorkers, stopWorkers } from './workers.js';
+
+// --------------------
+// Plugin loading
+// --------------------
+
+/**
+ * Load a plugin on main thread and in all worker threads.
+ *
+ * @param {string} path - Absolute path of plugin file
+ * @returns {string} - JSON result
+ */
+async function loadPluginInAllThreads(path: string): Promise<string> {
+  const result = await loadPlugin(path);
+  if (!result.startsWith('{"Success"')) return result;
+  try {
+    return await loadPluginInWorkers(path, result);
+  } catch (err) {
+    return JSON.stringify({ Failure: getErrorMessage(err) });
+  }
+}
+
+// --------------------
+// Run linter
+// --------------------
+
+/**
+ * Run the linter with the arguments of the process.
+ *
+ * Workers and plugins only live for one run, so the linter can run more than once in a process.
+ *
+ * @returns {boolean} - `true` if linting succeeded
+ */
+export async function run(): Promise<boolean> {
+  try {
+    // Call Rust, passing `loadPlugin` and `lintFile` as callbacks.
+    // Rust runs rules in worker threads when there are any, and on main thread with `lintFile` otherwise.
+    return await lint(loadPluginInAllThreads, lintFile);
+  } finally {
+    await stopWorkers();
+    resetPlugins();
+  }
+}
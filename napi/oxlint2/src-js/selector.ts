@@ -0,0 +1,125 @@
+// Parsing of selectors in visitor object keys.
+//
+// As well as plain node type names, visitor keys may be a node type followed by attribute conditions,
+// in the syntax of [esquery](https://github.com/estools/esquery) (which ESLint uses), e.g.:
+//
+// ```
+// {
+//   'CallExpression[callee.name="require"]'(node) {},
+//   'IdentifierReference[name=/^_/]:exit'(node) {},
+//   'CallExpression[arguments.length>=2]'(node) {},
+// }
+// ```
+//
+// Attributes support `[path]` (property is present and not `null`) and `[path op value]`,
+// where `op` is one of `=`, `!=`, `<`, `<=`, `>`, `>=`, and `value` is a quoted string,
+// a regular expression (`/^foo/i`), a number, or a bare word (`true`, `null`).
+//
+// Combinators (`A > B`, `A B`) are not supported, because nodes passed to visitors don't have links
+// to their parents. The built-in `no-restricted-syntax` rule implements the full selector syntax in Rust.
+
+import type { Node } from './types.ts';
+
+type Filter = (node: Node) => boolean;
+
+export interface ParsedSelector {
+  // Node type name
+  type: string;
+  // Function which returns `true` if node matches the attribute conditions.
+  // `null` if selector has no attribute conditions.
+  filter: Filter | null;
+}
+
+const TYPE_REGEX = /^[A-Za-z]+$/;
+
+const ATTRIBUTE_REGEX =
+  /\[\s*([\w$]+(?:\.[\w$]+)*)\s*(?:(!=|<=|>=|=|<|>)\s*("(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|\/(?:[^/\\]|\\.)+\/[imsu]*|[^\]\s]+)\s*)?\]/y;
+
+/**
+ * Parse a visitor key into node type name, and filter function for its attribute conditions.
+ *
+ * @param selector - Visitor key, without `:exit` postfix
+ * @returns Parsed selector
+ * @throws {Error} If selector is malformed, or uses unsupported syntax
+ */
+export function parseSelector(selector: string): ParsedSelector {
+  const bracketIndex = selector.indexOf('[');
+  if (bracketIndex === -1) return { type: selector, filter: null };
+
+  const type = selector.slice(0, bracketIndex);
+  if (!TYPE_REGEX.test(type)) throw new Error(`Unsupported selector '${selector}' in visitor object`);
+
+  const filters: Filter[] = [];
+  ATTRIBUTE_REGEX.lastIndex = bracketIndex;
+  while (ATTRIBUTE_REGEX.lastIndex < selector.length) {
+    const match = ATTRIBUTE_REGEX.exec(selector);
+    if (match === null) throw new Error(`Unsupported selector '${selector}' in visitor object`);
+    const [, path, operator, value] = match;
+    filters.push(createAttributeFilter(path.split('.'), operator, value));
+  }
+
+  const filter: Filter = filters.length === 1
+    ? filters[0]
+    : (node) => filters.every((filter) => filter(node));
+  return { type, filter };
+}
+
+function createAttributeFilter(
+  path: string[],
+  operator: string | undefined,
+  value: string | undefined,
+): Filter {
+  const get = (node: Node): unknown => {
+    let current: any = node;
+    for (const key of path) {
+      if (current === null || current === undefined) return undefined;
+      current = current[key];
+    }
+    return current;
+  };
+
+  if (operator === undefined) return (node) => get(node) != null;
+
+  assertIsString(value);
+  const first = value[0];
+  if (first === '/') {
+    const end = value.lastIndexOf('/');
+    const regex = new RegExp(value.slice(1, end), value.slice(end + 1));
+    if (operator !== '=' && operator !== '!=') return () => false;
+    const isNot = operator === '!=';
+    return (node) => {
+      const actual = get(node);
+      return (typeof actual === 'string' && regex.test(actual)) !== isNot;
+    };
+  }
+
+  const expected = first === '"' || first === "'" ? unescape(value.slice(1, -1)) : value;
+  switch (operator) {
+    case '=':
+      return (node) => `${get(node)}` === expected;
+    case '!=':
+      return (node) => `${get(node)}` !== expected;
+    default: {
+      const expectedNum = Number(expected);
+      const compare = operator === '<'
+        ? (actual: number) => actual < expectedNum
+        : operator === '<='
+        ? (actual: number) => actual <= expectedNum
+        : operator === '>'
+        ? (actual: number) => actual > expectedNum
+        : (actual: number) => actual >= expectedNum;
+      return (node) => {
+        const actual = get(node);
+        return typeof actual === 'number' && compare(actual);
+      };
+    }
+  }
+}
+
+function unescape(str: string): string {
+  return str.replace(/\\(.)/g, (_, c) => c === 'n' ? '\n' : c === 't' ? '\t' : c);
+}
+
+function assertIsString(value: unknown): asserts value is string {
+  if (typeof value !== 'string') throw new Error('Expected attribute value');
+}
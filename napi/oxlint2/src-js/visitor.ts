@@ -6,6 +6,9 @@
 // * Name of an AST type. or
 // * Name of an AST type postfixed with `:exit`.
 //
+// Name of an AST type may be followed by attribute conditions e.g. `CallExpression[callee.name="foo"]`.
+// The visit function is then only called for nodes which match the conditions. See `selector.ts`.
+//
 // Each property value must be a function that handles that AST node.
 //
 // e.g.:
@@ -75,6 +78,7 @@
 // TODO(camc314): we need to generate `.d.ts` file for this module.
 // @ts-expect-error
 import { LEAF_NODE_TYPES_COUNT, NODE_TYPE_IDS_MAP, NODE_TYPES_COUNT } from '../dist/parser/generated/lazy/types.cjs';
+import { parseSelector } from './selector.js';
 import { assertIs } from './utils.js';
 
 import type { CompiledVisitorEntry, EnterExit, Node, VisitFn, Visitor } from './types.ts';
@@ -211,7 +215,7 @@ export function addVisitorToCompiled(visitor: Visitor): void {
 
   // Populate visitors array from provided object
   for (let name of keys) {
-    const visitFn = visitor[name];
+    let visitFn = visitor[name];
     if (typeof visitFn !== 'function') {
       throw new TypeError(`'${name}' property of visitor object is not a function`);
     }
@@ -219,6 +223,15 @@ export function addVisitorToCompiled(visitor: Visitor): void {
     const isExit = name.endsWith(':exit');
     if (isExit) name = name.slice(0, -5);
 
+    const { type, filter } = parseSelector(name);
+    if (filter !== null) {
+      const visitMatching = visitFn;
+      visitFn = (node) => {
+        if (filter(node)) visitMatching(node);
+      };
+      name = type;
+    }
+
     const typeId = NODE_TYPE_IDS_MAP.get(name);
     if (typeId === void 0) throw new Error(`Unknown node type '${name}' in visitor object`);
 
@@ -0,0 +1,18 @@
+// Entry point of worker threads, started by `workers.ts`.
+//
+// Registers this thread's `lintFile` with Rust, which then sends files to lint to this thread directly.
+// Plugins are loaded on request of the main thread, in the same order as on the main thread,
+// so rule IDs are the same in all threads.
+
+import { parentPort, workerData } from 'node:worker_threads';
+import { registerLintWorker } from './bindings.js';
+import { lintFile, loadPlugin } from './plugins.js';
+
+import type { LoadPluginRequest, LoadPluginResponse, WorkerData } from './workers.ts';
+
+registerLintWorker((workerData as WorkerData).workerId, lintFile);
+
+parentPort!.on('message', async ({ id, path }: LoadPluginRequest) => {
+  const result = await loadPlugin(path);
+  parentPort!.postMessage({ id, result } satisfies LoadPluginResponse);
+});
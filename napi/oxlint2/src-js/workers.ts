@@ -0,0 +1,118 @@
+// Pool of worker threads which run JS plugin rules.
+//
+// Without workers, all JS rules run on the main thread, so linting of files with JS plugins is
+// serialized, no matter how many threads Rust uses. Each worker registers its own `lintFile` with Rust.
+// Rust sends each AST buffer to the same worker every time, so a buffer is only transferred
+// to JS once, and is only owned by one JS thread.
+//
+// Number of workers is `OXLINT_JS_WORKERS` env var, defaulting to number of CPU cores.
+// `OXLINT_JS_WORKERS=0` runs all rules on the main thread.
+
+import { availableParallelism } from 'node:os';
+import { Worker } from 'node:worker_threads';
+import { clearLintWorkers, deregisterLintWorker } from './bindings.js';
+
+export interface LoadPluginRequest {
+  id: number;
+  path: string;
+}
+
+export interface LoadPluginResponse {
+  id: number;
+  result: string;
+}
+
+export interface WorkerData {
+  // ID the worker registers its `lintFile` with
+  workerId: number;
+}
+
+interface PluginWorker {
+  worker: Worker;
+  // Callbacks for pending `loadPlugin` requests, keyed by request ID
+  pending: Map<number, (result: string) => void>;
+}
+
+// Workers. `null` until first plugin is loaded, so no workers are started if no JS plugins are used.
+let workers: PluginWorker[] | null = null;
+
+let nextRequestId = 0;
+
+/**
+ * Get number of workers to start.
+ * @returns Number of workers
+ */
+function getWorkerCount(): number {
+  const count = process.env.OXLINT_JS_WORKERS;
+  if (count === undefined || count === '') return availableParallelism();
+  const parsed = Number(count);
+  if (!Number.isInteger(parsed) || parsed < 0) {
+    throw new Error(`\`OXLINT_JS_WORKERS\` must be a non-negative integer, received \`${count}\``);
+  }
+  return parsed;
+}
+
+function startWorker(workerId: number): PluginWorker {
+  const worker = new Worker(new URL('./worker.js', import.meta.url), {
+    workerData: { workerId } satisfies WorkerData,
+  });
+  const pending = new Map<number, (result: string) => void>();
+  worker.on('message', ({ id, result }: LoadPluginResponse) => {
+    const resolve = pending.get(id)!;
+    pending.delete(id);
+    resolve(result);
+  });
+  worker.on('error', (err) => {
+    // Stop sending files to this worker. Rust lints them on main thread instead.
+    deregisterLintWorker(workerId);
+    // Fail all pending requests, so loading plugins doesn't hang
+    const result = JSON.stringify({ Failure: `Worker thread failed: ${err.message}` });
+    for (const resolve of pending.values()) {
+      resolve(result);
+    }
+    pending.clear();
+  });
+  return { worker, pending };
+}
+
+/**
+ * Load a plugin in all workers, starting the workers if not started already.
+ *
+ * Must be called after the plugin has been loaded successfully on main thread.
+ * Plugins must be loaded in the same order in all threads, so all threads have the same rule IDs.
+ *
+ * @param path - Absolute path of plugin file
+ * @param mainResult - Result of loading the plugin on main thread, serialized to JSON
+ * @returns Result of loading the plugin, serialized to JSON
+ */
+export async function loadPluginInWorkers(path: string, mainResult: string): Promise<string> {
+  if (workers === null) {
+    workers = Array.from({ length: getWorkerCount() }, (_, workerId) => startWorker(workerId));
+  }
+
+  const results = await Promise.all(workers.map(({ worker, pending }) =>
+    new Promise<string>((resolve) => {
+      const id = nextRequestId++;
+      pending.set(id, resolve);
+      worker.postMessage({ id, path } satisfies LoadPluginRequest);
+    })
+  ));
+
+  for (const result of results) {
+    if (result !== mainResult) {
+      const failure = JSON.parse(result).Failure;
+      return JSON.stringify({ Failure: failure ?? 'Plugin loaded differently in worker thread' });
+    }
+  }
+  return mainResult;
+}
+
+/**
+ * Stop all workers, and deregister them with Rust, so the next run of the linter starts new workers.
+ */
+export async function stopWorkers(): Promise<void> {
+  if (workers === null) return;
+  await Promise.all(workers.map(({ worker }) => worker.terminate()));
+  workers = null;
+  clearLintWorkers();
+}
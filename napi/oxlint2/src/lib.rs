@@ -1,6 +1,13 @@
+// Allocations are only counted with `--timing`.
+#[cfg(not(any(target_arch = "arm", target_os = "freebsd", target_family = "wasm")))]
+#[global_allocator]
+static ALLOC: oxlint::CountingAllocator<mimalloc_safe::MiMalloc> =
+    oxlint::CountingAllocator::new(mimalloc_safe::MiMalloc);
+
 use std::{
+    path::Path,
     process::{ExitCode, Termination},
-    sync::{Arc, atomic::Ordering, mpsc::channel},
+    sync::{Arc, Mutex, atomic::Ordering, mpsc::channel},
 };
 
 use napi::{
@@ -9,11 +16,16 @@ use napi::{
     threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
 };
 use napi_derive::napi;
+use rustc_hash::FxHashMap;
 
 use oxc_allocator::{Allocator, free_fixed_size_allocator};
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticResult, Info, ProjectRoot, fingerprint},
+};
 use oxlint::{
     ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, LintFileResult,
-    PluginLoadResult, lint as oxlint_lint,
+    PluginLoadResult, Reporter, lint as oxlint_lint, register_reporter,
 };
 
 mod generated {
@@ -54,6 +66,57 @@ pub type JsLintFileCb = ThreadsafeFunction<
     false,
 >;
 
+#[napi]
+pub type JsReportCb = ThreadsafeFunction<
+    // Arguments
+    FnArgs<(
+        String, // Event: `start`, `file`, `diagnostic` or `end`
+        String, // Data of the event, serialized to JSON
+    )>,
+    // Return value
+    Option<String>, // Output to write to stdout
+    // Arguments (repeated)
+    FnArgs<(String, String)>,
+    // Error status
+    Status,
+    // CalleeHandled
+    false,
+>;
+
+/// `lintFile` callbacks of the JS worker threads of the current run, indexed by worker ID.
+/// `None` for workers which failed, and for IDs of workers which haven't registered yet.
+static LINT_WORKERS: Mutex<Vec<Option<Arc<JsLintFileCb>>>> = Mutex::new(Vec::new());
+
+/// Register the `lintFile` callback of the JS worker thread with ID `id`.
+///
+/// Once any workers are registered, Rust sends files to lint to workers instead of to the
+/// `lintFile` passed to `lint`.
+#[napi]
+pub fn register_lint_worker(id: u32, lint_file: JsLintFileCb) {
+    let mut workers = LINT_WORKERS.lock().unwrap();
+    let index = id as usize;
+    if workers.len() <= index {
+        workers.resize(index + 1, None);
+    }
+    workers[index] = Some(Arc::new(lint_file));
+}
+
+/// Deregister the JS worker thread with ID `id`, after it failed.
+///
+/// Files which would be sent to this worker are linted on the main thread instead.
+#[napi]
+pub fn deregister_lint_worker(id: u32) {
+    if let Some(worker) = LINT_WORKERS.lock().unwrap().get_mut(id as usize) {
+        *worker = None;
+    }
+}
+
+/// Deregister all JS worker threads, once they are stopped at the end of a run.
+#[napi]
+pub fn clear_lint_workers() {
+    LINT_WORKERS.lock().unwrap().clear();
+}
+
 fn wrap_load_plugin(cb: JsLoadPluginCb) -> ExternalLinterLoadPluginCb {
     let cb = Arc::new(cb);
     Arc::new(move |plugin_name| {
@@ -71,8 +134,6 @@ fn wrap_load_plugin(cb: JsLoadPluginCb) -> ExternalLinterLoadPluginCb {
 fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
     let cb = Arc::new(cb);
     Arc::new(move |file_path: String, rule_ids: Vec<u32>, allocator: &Allocator| {
-        let cb = Arc::clone(&cb);
-
         let (tx, rx) = channel();
 
         // Each buffer is sent over to JS only once.
@@ -140,6 +201,20 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
             Some(buffer)
         };
 
+        // Each buffer is always sent to the same worker, so it is only transferred once, and only ever
+        // owned by one JS thread. Different buffers are in use on different Rust threads at the same
+        // time, so files are linted by several workers in parallel.
+        // Workers register while plugins are loaded, which completes before any file is linted.
+        let worker = {
+            let workers = LINT_WORKERS.lock().unwrap();
+            if workers.is_empty() {
+                None
+            } else {
+                workers[buffer_id as usize % workers.len()].clone()
+            }
+        };
+        let cb = worker.as_ref().unwrap_or(&cb);
+
         // Send data to JS
         let status = cb.call_with_return_value(
             FnArgs::from((file_path, buffer_id, buffer, rule_ids)),
@@ -169,13 +244,89 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
     })
 }
 
+/// A [`Reporter`] which passes each event to a JS callback, and writes the returned output.
+struct JsReporter {
+    cb: Arc<JsReportCb>,
+    project: ProjectRoot,
+}
+
+impl JsReporter {
+    fn report(&self, event: &str, data: &serde_json::Value) -> Option<String> {
+        let (tx, rx) = channel();
+        let status = self.cb.call_with_return_value(
+            FnArgs::from((event.to_string(), data.to_string())),
+            ThreadsafeFunctionCallMode::NonBlocking,
+            move |result, _env| {
+                let _ = tx.send(result.as_ref().ok().cloned().flatten());
+                result.map(|_| ())
+            },
+        );
+        if status != Status::Ok {
+            return None;
+        }
+        rx.recv().ok().flatten()
+    }
+}
+
+impl Reporter for JsReporter {
+    fn on_project_root(&mut self, project: &ProjectRoot) {
+        self.project = project.clone();
+    }
+
+    fn on_start(&mut self) -> Option<String> {
+        self.report("start", &serde_json::Value::Null)
+    }
+
+    fn on_file(&mut self, path: &Path) -> Option<String> {
+        self.report("file", &serde_json::json!(path.to_string_lossy()))
+    }
+
+    fn on_diagnostic(&mut self, diagnostic: Error) -> Option<String> {
+        let Info { start, end, filename, message, severity, rule_id } = Info::new(&diagnostic);
+        let data = serde_json::json!({
+            "filename": filename,
+            "message": message,
+            "severity": if severity == Severity::Error { "error" } else { "warning" },
+            "ruleId": rule_id,
+            "start": { "line": start.line, "column": start.column },
+            "end": { "line": end.line, "column": end.column },
+            "fingerprint": fingerprint(&diagnostic, &self.project),
+        });
+        self.report("diagnostic", &data)
+    }
+
+    fn on_end(&mut self, result: &DiagnosticResult) -> Option<String> {
+        let data = serde_json::json!({
+            "errorsCount": result.errors_count(),
+            "warningsCount": result.warnings_count(),
+        });
+        self.report("end", &data)
+    }
+}
+
 #[expect(clippy::allow_attributes)]
 #[allow(clippy::trailing_empty_array, clippy::unused_async)] // https://github.com/napi-rs/napi-rs/issues/2758
+#[allow(clippy::implicit_hasher)] // `#[napi]` functions can't be generic
 #[napi]
-pub async fn lint(load_plugin: JsLoadPluginCb, lint_file: JsLintFileCb) -> bool {
+pub async fn lint(
+    load_plugin: JsLoadPluginCb,
+    lint_file: JsLintFileCb,
+    reporters: Option<FxHashMap<String, JsReportCb>>,
+) -> bool {
     let rust_load_plugin = wrap_load_plugin(load_plugin);
     let rust_lint_file = wrap_lint_file(lint_file);
 
+    // Custom output formats, selected with `--format <name>`
+    for (name, cb) in reporters.unwrap_or_default() {
+        let cb = Arc::new(cb);
+        register_reporter(
+            &name,
+            Arc::new(move || {
+                Box::new(JsReporter { cb: Arc::clone(&cb), project: ProjectRoot::default() })
+            }),
+        );
+    }
+
     oxlint_lint(Some(ExternalLinter::new(rust_load_plugin, rust_lint_file))).report()
         == ExitCode::SUCCESS
 }
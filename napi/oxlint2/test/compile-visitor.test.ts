@@ -379,6 +379,40 @@ describe('compile visitor', () => {
     });
   });
 
+  describe('registers visitors with attribute selectors', () => {
+    it('only calls visitor for matching nodes', () => {
+      const enter = vi.fn(() => {});
+      addVisitorToCompiled({ 'EmptyStatement[foo.bar="qux"][len>=2]': enter });
+      expect(finalizeCompiledVisitor()).toBe(true);
+
+      const visit = compiledVisitor[EMPTY_STMT_TYPE_ID] as VisitFn;
+      const matching = { foo: { bar: 'qux' }, len: 2 };
+      visit(matching);
+      visit({ foo: { bar: 'qux' }, len: 1 });
+      visit({ foo: null, len: 3 });
+      expect(enter).toHaveBeenCalledTimes(1);
+      expect(enter).toHaveBeenCalledWith(matching);
+    });
+
+    it('supports regular expressions and exit visitors', () => {
+      const exit = vi.fn(() => {});
+      addVisitorToCompiled({ 'Program[sourceType=/^mod/]:exit': exit });
+      expect(finalizeCompiledVisitor()).toBe(true);
+
+      const { exit: visit } = compiledVisitor[PROGRAM_TYPE_ID] as EnterExit;
+      visit!({ sourceType: 'module' });
+      visit!({ sourceType: 'script' });
+      expect(exit).toHaveBeenCalledTimes(1);
+    });
+
+    it('throws if selector is unsupported', () => {
+      expect(() => addVisitorToCompiled({ 'Program > EmptyStatement[foo]'() {} }))
+        .toThrow(new Error("Unsupported selector 'Program > EmptyStatement[foo]' in visitor object"));
+      expect(() => addVisitorToCompiled({ 'EmptyStatement[foo'() {} }))
+        .toThrow(new Error("Unsupported selector 'EmptyStatement[foo' in visitor object"));
+    });
+  });
+
   describe('`finalizeCompiledVisitor` returns false if all visitors empty', () => {
     it('no visitors', () => {
       expect(finalizeCompiledVisitor()).toBe(false);
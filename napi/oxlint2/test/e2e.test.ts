@@ -7,17 +7,18 @@ import { execa } from 'execa';
 const PACKAGE_ROOT_PATH = path.dirname(import.meta.dirname);
 const ENTRY_POINT_PATH = path.join(PACKAGE_ROOT_PATH, 'dist/index.js');
 
-async function runOxlint(cwd: string, args: string[] = []) {
+async function runOxlint(cwd: string, args: string[] = [], env: Record<string, string> = {}) {
   return await execa('node', [ENTRY_POINT_PATH, ...args], {
     cwd: path.join(PACKAGE_ROOT_PATH, cwd),
+    env,
     reject: false,
   });
 }
 
 function normalizeOutput(output: string): string {
   return output
-    .replace(/Finished in \d+(\.\d+)?(s|ms|us|ns)/, 'Finished in Xms')
-    .replace(/using \d+ threads./, 'using X threads.');
+    .replace(/Finished in \d+(\.\d+)?(s|ms|us|ns)/g, 'Finished in Xms')
+    .replace(/using \d+ threads./g, 'using X threads.');
 }
 
 describe('oxlint2 CLI', () => {
@@ -57,6 +58,48 @@ describe('oxlint2 CLI', () => {
     expect(normalizeOutput(stdout)).toMatchSnapshot();
   });
 
+  it('should report the same diagnostics with and without worker threads', async () => {
+    const mainThread = await runOxlint(
+      'test/fixtures/basic_custom_plugin_many_files',
+      [],
+      { OXLINT_JS_WORKERS: '0' },
+    );
+    const workers = await runOxlint(
+      'test/fixtures/basic_custom_plugin_many_files',
+      [],
+      { OXLINT_JS_WORKERS: '2' },
+    );
+
+    expect(workers.exitCode).toBe(mainThread.exitCode);
+    expect(normalizeOutput(workers.stdout)).toBe(normalizeOutput(mainThread.stdout));
+  });
+
+  it('should lint twice in the same process', async () => {
+    const cwd = 'test/fixtures/basic_custom_plugin_many_files';
+    const env = { OXLINT_JS_WORKERS: '2' };
+    const once = await runOxlint(cwd, [], env);
+    const twice = await execa('node', [path.join(PACKAGE_ROOT_PATH, 'test/scripts/lint-twice.js')], {
+      cwd: path.join(PACKAGE_ROOT_PATH, cwd),
+      env,
+      reject: false,
+    });
+
+    expect(twice.exitCode).toBe(once.exitCode);
+    const output = normalizeOutput(once.stdout);
+    expect(normalizeOutput(twice.stdout)).toBe(`${output}\n${output}`);
+  });
+
+  it('should report an error if `OXLINT_JS_WORKERS` is invalid', async () => {
+    const { stdout, exitCode } = await runOxlint(
+      'test/fixtures/basic_custom_plugin',
+      [],
+      { OXLINT_JS_WORKERS: 'many' },
+    );
+
+    expect(exitCode).toBe(1);
+    expect(stdout).toContain('`OXLINT_JS_WORKERS` must be a non-negative integer');
+  });
+
   it('should load a custom plugin when configured in overrides', async () => {
     const { stdout, exitCode } = await runOxlint(
       'test/fixtures/custom_plugin_via_overrides',
@@ -110,4 +153,14 @@ describe('oxlint2 CLI', () => {
     expect(exitCode).toBe(1);
     expect(normalizeOutput(stdout)).toMatchSnapshot();
   });
+
+  it('should report fixes from plugins', async () => {
+    const { stdout, exitCode } = await runOxlint(
+      'test/fixtures/fixes',
+      ['--show-fixes'],
+    );
+
+    expect(exitCode).toBe(1);
+    expect(normalizeOutput(stdout)).toMatchSnapshot();
+  });
 });
@@ -0,0 +1,47 @@
+export default {
+  meta: {
+    name: "fixes-plugin",
+  },
+  rules: {
+    "no-debugger": {
+      meta: {
+        fixable: "code",
+      },
+      create(context) {
+        return {
+          DebuggerStatement(debuggerStatement) {
+            context.report({
+              message: "Unexpected Debugger Statement",
+              node: debuggerStatement,
+              fix: fixer => fixer.remove(debuggerStatement),
+            });
+          },
+        };
+      },
+    },
+    "no-foo": {
+      meta: {
+        fixable: "code",
+        hasSuggestions: true,
+      },
+      create(context) {
+        return {
+          IdentifierReference(ident) {
+            if (ident.name !== "foo") return;
+            context.report({
+              message: "Unexpected Identifier Reference named foo",
+              node: ident,
+              fix: fixer => [
+                fixer.insertTextBefore(ident, "b"),
+                fixer.replaceTextRange([ident.start + 1, ident.end], "ar"),
+              ],
+              suggest: [
+                { desc: "Rename to `qux`", fix: fixer => fixer.replaceText(ident, "qux") },
+              ],
+            });
+          },
+        };
+      },
+    },
+  },
+};
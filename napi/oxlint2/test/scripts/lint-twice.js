@@ -0,0 +1,7 @@
+// Runs the linter twice in the same process, to check that nothing of the first run leaks into the second.
+
+import { run } from '../../dist/run.js';
+
+for (let i = 0; i < 2; i++) {
+  if (!(await run())) process.exitCode = 1;
+}
@@ -1025,6 +1025,24 @@ function deserializeV8IntrinsicExpression(pos) {
   };
 }
 
+function deserializeTopicReference(pos) {
+  return {
+    type: 'TopicReference',
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
+function deserializePipelineExpression(pos) {
+  return {
+    type: 'PipelineExpression',
+    head: deserializeExpression(pos + 8),
+    body: deserializeExpression(pos + 24),
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   const value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -2260,6 +2278,10 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2353,6 +2375,10 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2461,6 +2487,10 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2584,6 +2614,10 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2908,6 +2942,10 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3207,6 +3245,10 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3353,6 +3395,10 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4258,6 +4304,14 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   const arr = [],
     pos32 = pos >> 2;
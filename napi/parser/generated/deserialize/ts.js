@@ -1157,6 +1157,24 @@ function deserializeV8IntrinsicExpression(pos) {
   };
 }
 
+function deserializeTopicReference(pos) {
+  return {
+    type: 'TopicReference',
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
+function deserializePipelineExpression(pos) {
+  return {
+    type: 'PipelineExpression',
+    head: deserializeExpression(pos + 8),
+    body: deserializeExpression(pos + 24),
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   const value = deserializeBool(pos + 8),
     start = deserializeU32(pos),
@@ -2391,6 +2409,10 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2484,6 +2506,10 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2592,6 +2618,10 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2715,6 +2745,10 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3039,6 +3073,10 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3338,6 +3376,10 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3484,6 +3526,10 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxTopicReference(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4389,6 +4435,14 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(uint32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   const arr = [],
     pos32 = pos >> 2;
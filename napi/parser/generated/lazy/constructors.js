@@ -153,6 +153,10 @@ function constructExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -526,6 +530,10 @@ function constructArrayExpressionElement(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -798,6 +806,10 @@ function constructPropertyKey(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -1527,6 +1539,10 @@ function constructArgument(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -3355,6 +3371,10 @@ function constructForStatementInit(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6188,6 +6208,10 @@ function constructExportDefaultDeclarationKind(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6272,6 +6296,98 @@ class V8IntrinsicExpression {
 
 const DebugV8IntrinsicExpression = class V8IntrinsicExpression {};
 
+class TopicReference {
+  type = 'TopicReference';
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: 'TopicReference',
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugTopicReference.prototype);
+  }
+}
+
+const DebugTopicReference = class TopicReference {};
+
+class PipelineExpression {
+  type = 'PipelineExpression';
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  get head() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 8, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 24, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: 'PipelineExpression',
+      start: this.start,
+      end: this.end,
+      head: this.head,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugPipelineExpression.prototype);
+  }
+}
+
+const DebugPipelineExpression = class PipelineExpression {};
+
 class BooleanLiteral {
   type = 'BooleanLiteral';
   #internal;
@@ -7244,6 +7360,10 @@ function constructJSXExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxTopicReference(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -12720,6 +12840,14 @@ function constructBoxV8IntrinsicExpression(pos, ast) {
   return new V8IntrinsicExpression(ast.buffer.uint32[pos >> 2], ast);
 }
 
+function constructBoxTopicReference(pos, ast) {
+  return new TopicReference(ast.buffer.uint32[pos >> 2], ast);
+}
+
+function constructBoxPipelineExpression(pos, ast) {
+  return new PipelineExpression(ast.buffer.uint32[pos >> 2], ast);
+}
+
 function constructVecArrayExpressionElement(pos, ast) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
@@ -13996,6 +14124,8 @@ module.exports = {
   ExportAllDeclaration,
   ExportSpecifier,
   V8IntrinsicExpression,
+  TopicReference,
+  PipelineExpression,
   BooleanLiteral,
   NullLiteral,
   NumericLiteral,
@@ -91,6 +91,8 @@ const {
   ExportAllDeclaration,
   ExportSpecifier,
   V8IntrinsicExpression,
+  TopicReference,
+  PipelineExpression,
   BooleanLiteral,
   NullLiteral,
   NumericLiteral,
@@ -187,7 +189,7 @@ const {
 module.exports = walkProgram;
 
 function walkProgram(pos, ast, visitors) {
-  const enterExit = visitors[38];
+  const enterExit = visitors[39];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -323,6 +325,12 @@ function walkExpression(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -363,7 +371,7 @@ function walkThisExpression(pos, ast, visitors) {
 }
 
 function walkArrayExpression(pos, ast, visitors) {
-  const enterExit = visitors[39];
+  const enterExit = visitors[40];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -498,6 +506,12 @@ function walkArrayExpressionElement(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -524,7 +538,7 @@ function walkElision(pos, ast, visitors) {
 }
 
 function walkObjectExpression(pos, ast, visitors) {
-  const enterExit = visitors[40];
+  const enterExit = visitors[41];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -551,7 +565,7 @@ function walkObjectPropertyKind(pos, ast, visitors) {
 }
 
 function walkObjectProperty(pos, ast, visitors) {
-  const enterExit = visitors[41];
+  const enterExit = visitors[42];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -687,6 +701,12 @@ function walkPropertyKey(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -708,7 +728,7 @@ function walkPropertyKey(pos, ast, visitors) {
 }
 
 function walkTemplateLiteral(pos, ast, visitors) {
-  const enterExit = visitors[42];
+  const enterExit = visitors[43];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -723,7 +743,7 @@ function walkTemplateLiteral(pos, ast, visitors) {
 }
 
 function walkTaggedTemplateExpression(pos, ast, visitors) {
-  const enterExit = visitors[43];
+  const enterExit = visitors[44];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -744,7 +764,7 @@ function walkTemplateElement(pos, ast, visitors) {
 }
 
 function walkComputedMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[44];
+  const enterExit = visitors[45];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -759,7 +779,7 @@ function walkComputedMemberExpression(pos, ast, visitors) {
 }
 
 function walkStaticMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[45];
+  const enterExit = visitors[46];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -774,7 +794,7 @@ function walkStaticMemberExpression(pos, ast, visitors) {
 }
 
 function walkPrivateFieldExpression(pos, ast, visitors) {
-  const enterExit = visitors[46];
+  const enterExit = visitors[47];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -789,7 +809,7 @@ function walkPrivateFieldExpression(pos, ast, visitors) {
 }
 
 function walkCallExpression(pos, ast, visitors) {
-  const enterExit = visitors[47];
+  const enterExit = visitors[48];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -805,7 +825,7 @@ function walkCallExpression(pos, ast, visitors) {
 }
 
 function walkNewExpression(pos, ast, visitors) {
-  const enterExit = visitors[48];
+  const enterExit = visitors[49];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -821,7 +841,7 @@ function walkNewExpression(pos, ast, visitors) {
 }
 
 function walkMetaProperty(pos, ast, visitors) {
-  const enterExit = visitors[49];
+  const enterExit = visitors[50];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -836,7 +856,7 @@ function walkMetaProperty(pos, ast, visitors) {
 }
 
 function walkSpreadElement(pos, ast, visitors) {
-  const enterExit = visitors[50];
+  const enterExit = visitors[51];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -971,6 +991,12 @@ function walkArgument(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -989,7 +1015,7 @@ function walkArgument(pos, ast, visitors) {
 }
 
 function walkUpdateExpression(pos, ast, visitors) {
-  const enterExit = visitors[51];
+  const enterExit = visitors[52];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1003,7 +1029,7 @@ function walkUpdateExpression(pos, ast, visitors) {
 }
 
 function walkUnaryExpression(pos, ast, visitors) {
-  const enterExit = visitors[52];
+  const enterExit = visitors[53];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1017,7 +1043,7 @@ function walkUnaryExpression(pos, ast, visitors) {
 }
 
 function walkBinaryExpression(pos, ast, visitors) {
-  const enterExit = visitors[53];
+  const enterExit = visitors[54];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1032,7 +1058,7 @@ function walkBinaryExpression(pos, ast, visitors) {
 }
 
 function walkPrivateInExpression(pos, ast, visitors) {
-  const enterExit = visitors[54];
+  const enterExit = visitors[55];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1047,7 +1073,7 @@ function walkPrivateInExpression(pos, ast, visitors) {
 }
 
 function walkLogicalExpression(pos, ast, visitors) {
-  const enterExit = visitors[55];
+  const enterExit = visitors[56];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1062,7 +1088,7 @@ function walkLogicalExpression(pos, ast, visitors) {
 }
 
 function walkConditionalExpression(pos, ast, visitors) {
-  const enterExit = visitors[56];
+  const enterExit = visitors[57];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1078,7 +1104,7 @@ function walkConditionalExpression(pos, ast, visitors) {
 }
 
 function walkAssignmentExpression(pos, ast, visitors) {
-  const enterExit = visitors[57];
+  const enterExit = visitors[58];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1161,7 +1187,7 @@ function walkSimpleAssignmentTarget(pos, ast, visitors) {
 }
 
 function walkArrayAssignmentTarget(pos, ast, visitors) {
-  const enterExit = visitors[58];
+  const enterExit = visitors[59];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1175,7 +1201,7 @@ function walkArrayAssignmentTarget(pos, ast, visitors) {
 }
 
 function walkObjectAssignmentTarget(pos, ast, visitors) {
-  const enterExit = visitors[59];
+  const enterExit = visitors[60];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1229,7 +1255,7 @@ function walkAssignmentTargetMaybeDefault(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetWithDefault(pos, ast, visitors) {
-  const enterExit = visitors[60];
+  const enterExit = visitors[61];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1257,7 +1283,7 @@ function walkAssignmentTargetProperty(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetPropertyIdentifier(pos, ast, visitors) {
-  const enterExit = visitors[61];
+  const enterExit = visitors[62];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1272,7 +1298,7 @@ function walkAssignmentTargetPropertyIdentifier(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetPropertyProperty(pos, ast, visitors) {
-  const enterExit = visitors[62];
+  const enterExit = visitors[63];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1287,7 +1313,7 @@ function walkAssignmentTargetPropertyProperty(pos, ast, visitors) {
 }
 
 function walkSequenceExpression(pos, ast, visitors) {
-  const enterExit = visitors[63];
+  const enterExit = visitors[64];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1306,7 +1332,7 @@ function walkSuper(pos, ast, visitors) {
 }
 
 function walkAwaitExpression(pos, ast, visitors) {
-  const enterExit = visitors[64];
+  const enterExit = visitors[65];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1320,7 +1346,7 @@ function walkAwaitExpression(pos, ast, visitors) {
 }
 
 function walkChainExpression(pos, ast, visitors) {
-  const enterExit = visitors[65];
+  const enterExit = visitors[66];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1356,7 +1382,7 @@ function walkChainElement(pos, ast, visitors) {
 }
 
 function walkParenthesizedExpression(pos, ast, visitors) {
-  const enterExit = visitors[66];
+  const enterExit = visitors[67];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1478,7 +1504,7 @@ function walkHashbang(pos, ast, visitors) {
 }
 
 function walkBlockStatement(pos, ast, visitors) {
-  const enterExit = visitors[67];
+  const enterExit = visitors[68];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1523,7 +1549,7 @@ function walkDeclaration(pos, ast, visitors) {
 }
 
 function walkVariableDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[68];
+  const enterExit = visitors[69];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1537,7 +1563,7 @@ function walkVariableDeclaration(pos, ast, visitors) {
 }
 
 function walkVariableDeclarator(pos, ast, visitors) {
-  const enterExit = visitors[69];
+  const enterExit = visitors[70];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1557,7 +1583,7 @@ function walkEmptyStatement(pos, ast, visitors) {
 }
 
 function walkExpressionStatement(pos, ast, visitors) {
-  const enterExit = visitors[70];
+  const enterExit = visitors[71];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1571,7 +1597,7 @@ function walkExpressionStatement(pos, ast, visitors) {
 }
 
 function walkIfStatement(pos, ast, visitors) {
-  const enterExit = visitors[71];
+  const enterExit = visitors[72];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1587,7 +1613,7 @@ function walkIfStatement(pos, ast, visitors) {
 }
 
 function walkDoWhileStatement(pos, ast, visitors) {
-  const enterExit = visitors[72];
+  const enterExit = visitors[73];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1602,7 +1628,7 @@ function walkDoWhileStatement(pos, ast, visitors) {
 }
 
 function walkWhileStatement(pos, ast, visitors) {
-  const enterExit = visitors[73];
+  const enterExit = visitors[74];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1617,7 +1643,7 @@ function walkWhileStatement(pos, ast, visitors) {
 }
 
 function walkForStatement(pos, ast, visitors) {
-  const enterExit = visitors[74];
+  const enterExit = visitors[75];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1755,6 +1781,12 @@ function walkForStatementInit(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -1773,7 +1805,7 @@ function walkForStatementInit(pos, ast, visitors) {
 }
 
 function walkForInStatement(pos, ast, visitors) {
-  const enterExit = visitors[75];
+  const enterExit = visitors[76];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1829,7 +1861,7 @@ function walkForStatementLeft(pos, ast, visitors) {
 }
 
 function walkForOfStatement(pos, ast, visitors) {
-  const enterExit = visitors[76];
+  const enterExit = visitors[77];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1845,7 +1877,7 @@ function walkForOfStatement(pos, ast, visitors) {
 }
 
 function walkContinueStatement(pos, ast, visitors) {
-  const enterExit = visitors[77];
+  const enterExit = visitors[78];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1859,7 +1891,7 @@ function walkContinueStatement(pos, ast, visitors) {
 }
 
 function walkBreakStatement(pos, ast, visitors) {
-  const enterExit = visitors[78];
+  const enterExit = visitors[79];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1873,7 +1905,7 @@ function walkBreakStatement(pos, ast, visitors) {
 }
 
 function walkReturnStatement(pos, ast, visitors) {
-  const enterExit = visitors[79];
+  const enterExit = visitors[80];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1887,7 +1919,7 @@ function walkReturnStatement(pos, ast, visitors) {
 }
 
 function walkWithStatement(pos, ast, visitors) {
-  const enterExit = visitors[80];
+  const enterExit = visitors[81];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1902,7 +1934,7 @@ function walkWithStatement(pos, ast, visitors) {
 }
 
 function walkSwitchStatement(pos, ast, visitors) {
-  const enterExit = visitors[81];
+  const enterExit = visitors[82];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1917,7 +1949,7 @@ function walkSwitchStatement(pos, ast, visitors) {
 }
 
 function walkSwitchCase(pos, ast, visitors) {
-  const enterExit = visitors[82];
+  const enterExit = visitors[83];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1932,7 +1964,7 @@ function walkSwitchCase(pos, ast, visitors) {
 }
 
 function walkLabeledStatement(pos, ast, visitors) {
-  const enterExit = visitors[83];
+  const enterExit = visitors[84];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1947,7 +1979,7 @@ function walkLabeledStatement(pos, ast, visitors) {
 }
 
 function walkThrowStatement(pos, ast, visitors) {
-  const enterExit = visitors[84];
+  const enterExit = visitors[85];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1961,7 +1993,7 @@ function walkThrowStatement(pos, ast, visitors) {
 }
 
 function walkTryStatement(pos, ast, visitors) {
-  const enterExit = visitors[85];
+  const enterExit = visitors[86];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1977,7 +2009,7 @@ function walkTryStatement(pos, ast, visitors) {
 }
 
 function walkCatchClause(pos, ast, visitors) {
-  const enterExit = visitors[86];
+  const enterExit = visitors[87];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2025,7 +2057,7 @@ function walkBindingPatternKind(pos, ast, visitors) {
 }
 
 function walkAssignmentPattern(pos, ast, visitors) {
-  const enterExit = visitors[87];
+  const enterExit = visitors[88];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2040,7 +2072,7 @@ function walkAssignmentPattern(pos, ast, visitors) {
 }
 
 function walkObjectPattern(pos, ast, visitors) {
-  const enterExit = visitors[88];
+  const enterExit = visitors[89];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2054,7 +2086,7 @@ function walkObjectPattern(pos, ast, visitors) {
 }
 
 function walkBindingProperty(pos, ast, visitors) {
-  const enterExit = visitors[89];
+  const enterExit = visitors[90];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2069,7 +2101,7 @@ function walkBindingProperty(pos, ast, visitors) {
 }
 
 function walkArrayPattern(pos, ast, visitors) {
-  const enterExit = visitors[90];
+  const enterExit = visitors[91];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2083,7 +2115,7 @@ function walkArrayPattern(pos, ast, visitors) {
 }
 
 function walkFunction(pos, ast, visitors) {
-  const enterExit = visitors[91];
+  const enterExit = visitors[92];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2101,7 +2133,7 @@ function walkFunction(pos, ast, visitors) {
 }
 
 function walkFormalParameters(pos, ast, visitors) {
-  const enterExit = visitors[92];
+  const enterExit = visitors[93];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2120,7 +2152,7 @@ function walkFormalParameter(pos, ast, visitors) {
 }
 
 function walkFunctionBody(pos, ast, visitors) {
-  const enterExit = visitors[93];
+  const enterExit = visitors[94];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2134,7 +2166,7 @@ function walkFunctionBody(pos, ast, visitors) {
 }
 
 function walkArrowFunctionExpression(pos, ast, visitors) {
-  const enterExit = visitors[94];
+  const enterExit = visitors[95];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2151,7 +2183,7 @@ function walkArrowFunctionExpression(pos, ast, visitors) {
 }
 
 function walkYieldExpression(pos, ast, visitors) {
-  const enterExit = visitors[95];
+  const enterExit = visitors[96];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2165,7 +2197,7 @@ function walkYieldExpression(pos, ast, visitors) {
 }
 
 function walkClass(pos, ast, visitors) {
-  const enterExit = visitors[96];
+  const enterExit = visitors[97];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2185,7 +2217,7 @@ function walkClass(pos, ast, visitors) {
 }
 
 function walkClassBody(pos, ast, visitors) {
-  const enterExit = visitors[97];
+  const enterExit = visitors[98];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2221,7 +2253,7 @@ function walkClassElement(pos, ast, visitors) {
 }
 
 function walkMethodDefinition(pos, ast, visitors) {
-  const enterExit = visitors[98];
+  const enterExit = visitors[99];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2237,7 +2269,7 @@ function walkMethodDefinition(pos, ast, visitors) {
 }
 
 function walkPropertyDefinition(pos, ast, visitors) {
-  const enterExit = visitors[99];
+  const enterExit = visitors[100];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2259,7 +2291,7 @@ function walkPrivateIdentifier(pos, ast, visitors) {
 }
 
 function walkStaticBlock(pos, ast, visitors) {
-  const enterExit = visitors[100];
+  const enterExit = visitors[101];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2273,7 +2305,7 @@ function walkStaticBlock(pos, ast, visitors) {
 }
 
 function walkAccessorProperty(pos, ast, visitors) {
-  const enterExit = visitors[101];
+  const enterExit = visitors[102];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2290,7 +2322,7 @@ function walkAccessorProperty(pos, ast, visitors) {
 }
 
 function walkImportExpression(pos, ast, visitors) {
-  const enterExit = visitors[102];
+  const enterExit = visitors[103];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2305,7 +2337,7 @@ function walkImportExpression(pos, ast, visitors) {
 }
 
 function walkImportDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[103];
+  const enterExit = visitors[104];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2337,7 +2369,7 @@ function walkImportDeclarationSpecifier(pos, ast, visitors) {
 }
 
 function walkImportSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[104];
+  const enterExit = visitors[105];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2352,7 +2384,7 @@ function walkImportSpecifier(pos, ast, visitors) {
 }
 
 function walkImportDefaultSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[105];
+  const enterExit = visitors[106];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2366,7 +2398,7 @@ function walkImportDefaultSpecifier(pos, ast, visitors) {
 }
 
 function walkImportNamespaceSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[106];
+  const enterExit = visitors[107];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2384,7 +2416,7 @@ function walkWithClause(pos, ast, visitors) {
 }
 
 function walkImportAttribute(pos, ast, visitors) {
-  const enterExit = visitors[107];
+  const enterExit = visitors[108];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2412,7 +2444,7 @@ function walkImportAttributeKey(pos, ast, visitors) {
 }
 
 function walkExportNamedDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[108];
+  const enterExit = visitors[109];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2429,7 +2461,7 @@ function walkExportNamedDeclaration(pos, ast, visitors) {
 }
 
 function walkExportDefaultDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[109];
+  const enterExit = visitors[110];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2443,7 +2475,7 @@ function walkExportDefaultDeclaration(pos, ast, visitors) {
 }
 
 function walkExportAllDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[110];
+  const enterExit = visitors[111];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2459,7 +2491,7 @@ function walkExportAllDeclaration(pos, ast, visitors) {
 }
 
 function walkExportSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[111];
+  const enterExit = visitors[112];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2595,6 +2627,12 @@ function walkExportDefaultDeclarationKind(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -2635,7 +2673,7 @@ function walkModuleExportName(pos, ast, visitors) {
 }
 
 function walkV8IntrinsicExpression(pos, ast, visitors) {
-  const enterExit = visitors[112];
+  const enterExit = visitors[113];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2649,38 +2687,58 @@ function walkV8IntrinsicExpression(pos, ast, visitors) {
   if (exit !== null) exit(node);
 }
 
-function walkBooleanLiteral(pos, ast, visitors) {
+function walkTopicReference(pos, ast, visitors) {
   const visit = visitors[12];
+  if (visit !== null) visit(new TopicReference(pos, ast));
+}
+
+function walkPipelineExpression(pos, ast, visitors) {
+  const enterExit = visitors[114];
+  let node, enter, exit = null;
+  if (enterExit !== null) {
+    ({ enter, exit } = enterExit);
+    node = new PipelineExpression(pos, ast);
+    if (enter !== null) enter(node);
+  }
+
+  walkExpression(pos + 8, ast, visitors);
+  walkExpression(pos + 24, ast, visitors);
+
+  if (exit !== null) exit(node);
+}
+
+function walkBooleanLiteral(pos, ast, visitors) {
+  const visit = visitors[13];
   if (visit !== null) visit(new BooleanLiteral(pos, ast));
 }
 
 function walkNullLiteral(pos, ast, visitors) {
-  const visit = visitors[13];
+  const visit = visitors[14];
   if (visit !== null) visit(new NullLiteral(pos, ast));
 }
 
 function walkNumericLiteral(pos, ast, visitors) {
-  const visit = visitors[14];
+  const visit = visitors[15];
   if (visit !== null) visit(new NumericLiteral(pos, ast));
 }
 
 function walkStringLiteral(pos, ast, visitors) {
-  const visit = visitors[15];
+  const visit = visitors[16];
   if (visit !== null) visit(new StringLiteral(pos, ast));
 }
 
 function walkBigIntLiteral(pos, ast, visitors) {
-  const visit = visitors[16];
+  const visit = visitors[17];
   if (visit !== null) visit(new BigIntLiteral(pos, ast));
 }
 
 function walkRegExpLiteral(pos, ast, visitors) {
-  const visit = visitors[17];
+  const visit = visitors[18];
   if (visit !== null) visit(new RegExpLiteral(pos, ast));
 }
 
 function walkJSXElement(pos, ast, visitors) {
-  const enterExit = visitors[113];
+  const enterExit = visitors[115];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2696,7 +2754,7 @@ function walkJSXElement(pos, ast, visitors) {
 }
 
 function walkJSXOpeningElement(pos, ast, visitors) {
-  const enterExit = visitors[114];
+  const enterExit = visitors[116];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2712,7 +2770,7 @@ function walkJSXOpeningElement(pos, ast, visitors) {
 }
 
 function walkJSXClosingElement(pos, ast, visitors) {
-  const enterExit = visitors[115];
+  const enterExit = visitors[117];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2726,7 +2784,7 @@ function walkJSXClosingElement(pos, ast, visitors) {
 }
 
 function walkJSXFragment(pos, ast, visitors) {
-  const enterExit = visitors[116];
+  const enterExit = visitors[118];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2742,12 +2800,12 @@ function walkJSXFragment(pos, ast, visitors) {
 }
 
 function walkJSXOpeningFragment(pos, ast, visitors) {
-  const visit = visitors[18];
+  const visit = visitors[19];
   if (visit !== null) visit(new JSXOpeningFragment(pos, ast));
 }
 
 function walkJSXClosingFragment(pos, ast, visitors) {
-  const visit = visitors[19];
+  const visit = visitors[20];
   if (visit !== null) visit(new JSXClosingFragment(pos, ast));
 }
 
@@ -2774,7 +2832,7 @@ function walkJSXElementName(pos, ast, visitors) {
 }
 
 function walkJSXNamespacedName(pos, ast, visitors) {
-  const enterExit = visitors[117];
+  const enterExit = visitors[119];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2789,7 +2847,7 @@ function walkJSXNamespacedName(pos, ast, visitors) {
 }
 
 function walkJSXMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[118];
+  const enterExit = visitors[120];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2820,7 +2878,7 @@ function walkJSXMemberExpressionObject(pos, ast, visitors) {
 }
 
 function walkJSXExpressionContainer(pos, ast, visitors) {
-  const enterExit = visitors[119];
+  const enterExit = visitors[121];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2955,6 +3013,12 @@ function walkJSXExpression(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxTopicReference(pos + 8, ast, visitors);
+      return;
+    case 41:
+      walkBoxPipelineExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -2973,7 +3037,7 @@ function walkJSXExpression(pos, ast, visitors) {
 }
 
 function walkJSXEmptyExpression(pos, ast, visitors) {
-  const visit = visitors[20];
+  const visit = visitors[21];
   if (visit !== null) visit(new JSXEmptyExpression(pos, ast));
 }
 
@@ -2991,7 +3055,7 @@ function walkJSXAttributeItem(pos, ast, visitors) {
 }
 
 function walkJSXAttribute(pos, ast, visitors) {
-  const enterExit = visitors[120];
+  const enterExit = visitors[122];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3006,7 +3070,7 @@ function walkJSXAttribute(pos, ast, visitors) {
 }
 
 function walkJSXSpreadAttribute(pos, ast, visitors) {
-  const enterExit = visitors[121];
+  const enterExit = visitors[123];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3052,7 +3116,7 @@ function walkJSXAttributeValue(pos, ast, visitors) {
 }
 
 function walkJSXIdentifier(pos, ast, visitors) {
-  const visit = visitors[21];
+  const visit = visitors[22];
   if (visit !== null) visit(new JSXIdentifier(pos, ast));
 }
 
@@ -3079,7 +3143,7 @@ function walkJSXChild(pos, ast, visitors) {
 }
 
 function walkJSXSpreadChild(pos, ast, visitors) {
-  const enterExit = visitors[122];
+  const enterExit = visitors[124];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3093,12 +3157,12 @@ function walkJSXSpreadChild(pos, ast, visitors) {
 }
 
 function walkJSXText(pos, ast, visitors) {
-  const visit = visitors[22];
+  const visit = visitors[23];
   if (visit !== null) visit(new JSXText(pos, ast));
 }
 
 function walkTSEnumDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[123];
+  const enterExit = visitors[125];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3113,7 +3177,7 @@ function walkTSEnumDeclaration(pos, ast, visitors) {
 }
 
 function walkTSEnumBody(pos, ast, visitors) {
-  const enterExit = visitors[124];
+  const enterExit = visitors[126];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3127,7 +3191,7 @@ function walkTSEnumBody(pos, ast, visitors) {
 }
 
 function walkTSEnumMember(pos, ast, visitors) {
-  const enterExit = visitors[125];
+  const enterExit = visitors[127];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3161,7 +3225,7 @@ function walkTSEnumMemberName(pos, ast, visitors) {
 }
 
 function walkTSTypeAnnotation(pos, ast, visitors) {
-  const enterExit = visitors[126];
+  const enterExit = visitors[128];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3175,7 +3239,7 @@ function walkTSTypeAnnotation(pos, ast, visitors) {
 }
 
 function walkTSLiteralType(pos, ast, visitors) {
-  const enterExit = visitors[127];
+  const enterExit = visitors[129];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3332,7 +3396,7 @@ function walkTSType(pos, ast, visitors) {
 }
 
 function walkTSConditionalType(pos, ast, visitors) {
-  const enterExit = visitors[128];
+  const enterExit = visitors[130];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3349,7 +3413,7 @@ function walkTSConditionalType(pos, ast, visitors) {
 }
 
 function walkTSUnionType(pos, ast, visitors) {
-  const enterExit = visitors[129];
+  const enterExit = visitors[131];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3363,7 +3427,7 @@ function walkTSUnionType(pos, ast, visitors) {
 }
 
 function walkTSIntersectionType(pos, ast, visitors) {
-  const enterExit = visitors[130];
+  const enterExit = visitors[132];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3377,7 +3441,7 @@ function walkTSIntersectionType(pos, ast, visitors) {
 }
 
 function walkTSParenthesizedType(pos, ast, visitors) {
-  const enterExit = visitors[131];
+  const enterExit = visitors[133];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3391,7 +3455,7 @@ function walkTSParenthesizedType(pos, ast, visitors) {
 }
 
 function walkTSTypeOperator(pos, ast, visitors) {
-  const enterExit = visitors[132];
+  const enterExit = visitors[134];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3405,7 +3469,7 @@ function walkTSTypeOperator(pos, ast, visitors) {
 }
 
 function walkTSArrayType(pos, ast, visitors) {
-  const enterExit = visitors[133];
+  const enterExit = visitors[135];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3419,7 +3483,7 @@ function walkTSArrayType(pos, ast, visitors) {
 }
 
 function walkTSIndexedAccessType(pos, ast, visitors) {
-  const enterExit = visitors[134];
+  const enterExit = visitors[136];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3434,7 +3498,7 @@ function walkTSIndexedAccessType(pos, ast, visitors) {
 }
 
 function walkTSTupleType(pos, ast, visitors) {
-  const enterExit = visitors[135];
+  const enterExit = visitors[137];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3448,7 +3512,7 @@ function walkTSTupleType(pos, ast, visitors) {
 }
 
 function walkTSNamedTupleMember(pos, ast, visitors) {
-  const enterExit = visitors[136];
+  const enterExit = visitors[138];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3463,7 +3527,7 @@ function walkTSNamedTupleMember(pos, ast, visitors) {
 }
 
 function walkTSOptionalType(pos, ast, visitors) {
-  const enterExit = visitors[137];
+  const enterExit = visitors[139];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3477,7 +3541,7 @@ function walkTSOptionalType(pos, ast, visitors) {
 }
 
 function walkTSRestType(pos, ast, visitors) {
-  const enterExit = visitors[138];
+  const enterExit = visitors[140];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3615,77 +3679,77 @@ function walkTSTupleElement(pos, ast, visitors) {
 }
 
 function walkTSAnyKeyword(pos, ast, visitors) {
-  const visit = visitors[23];
+  const visit = visitors[24];
   if (visit !== null) visit(new TSAnyKeyword(pos, ast));
 }
 
 function walkTSStringKeyword(pos, ast, visitors) {
-  const visit = visitors[24];
+  const visit = visitors[25];
   if (visit !== null) visit(new TSStringKeyword(pos, ast));
 }
 
 function walkTSBooleanKeyword(pos, ast, visitors) {
-  const visit = visitors[25];
+  const visit = visitors[26];
   if (visit !== null) visit(new TSBooleanKeyword(pos, ast));
 }
 
 function walkTSNumberKeyword(pos, ast, visitors) {
-  const visit = visitors[26];
+  const visit = visitors[27];
   if (visit !== null) visit(new TSNumberKeyword(pos, ast));
 }
 
 function walkTSNeverKeyword(pos, ast, visitors) {
-  const visit = visitors[27];
+  const visit = visitors[28];
   if (visit !== null) visit(new TSNeverKeyword(pos, ast));
 }
 
 function walkTSIntrinsicKeyword(pos, ast, visitors) {
-  const visit = visitors[28];
+  const visit = visitors[29];
   if (visit !== null) visit(new TSIntrinsicKeyword(pos, ast));
 }
 
 function walkTSUnknownKeyword(pos, ast, visitors) {
-  const visit = visitors[29];
+  const visit = visitors[30];
   if (visit !== null) visit(new TSUnknownKeyword(pos, ast));
 }
 
 function walkTSNullKeyword(pos, ast, visitors) {
-  const visit = visitors[30];
+  const visit = visitors[31];
   if (visit !== null) visit(new TSNullKeyword(pos, ast));
 }
 
 function walkTSUndefinedKeyword(pos, ast, visitors) {
-  const visit = visitors[31];
+  const visit = visitors[32];
   if (visit !== null) visit(new TSUndefinedKeyword(pos, ast));
 }
 
 function walkTSVoidKeyword(pos, ast, visitors) {
-  const visit = visitors[32];
+  const visit = visitors[33];
   if (visit !== null) visit(new TSVoidKeyword(pos, ast));
 }
 
 function walkTSSymbolKeyword(pos, ast, visitors) {
-  const visit = visitors[33];
+  const visit = visitors[34];
   if (visit !== null) visit(new TSSymbolKeyword(pos, ast));
 }
 
 function walkTSThisType(pos, ast, visitors) {
-  const visit = visitors[34];
+  const visit = visitors[35];
   if (visit !== null) visit(new TSThisType(pos, ast));
 }
 
 function walkTSObjectKeyword(pos, ast, visitors) {
-  const visit = visitors[35];
+  const visit = visitors[36];
   if (visit !== null) visit(new TSObjectKeyword(pos, ast));
 }
 
 function walkTSBigIntKeyword(pos, ast, visitors) {
-  const visit = visitors[36];
+  const visit = visitors[37];
   if (visit !== null) visit(new TSBigIntKeyword(pos, ast));
 }
 
 function walkTSTypeReference(pos, ast, visitors) {
-  const enterExit = visitors[139];
+  const enterExit = visitors[141];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3716,7 +3780,7 @@ function walkTSTypeName(pos, ast, visitors) {
 }
 
 function walkTSQualifiedName(pos, ast, visitors) {
-  const enterExit = visitors[140];
+  const enterExit = visitors[142];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3731,7 +3795,7 @@ function walkTSQualifiedName(pos, ast, visitors) {
 }
 
 function walkTSTypeParameterInstantiation(pos, ast, visitors) {
-  const enterExit = visitors[141];
+  const enterExit = visitors[143];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3745,7 +3809,7 @@ function walkTSTypeParameterInstantiation(pos, ast, visitors) {
 }
 
 function walkTSTypeParameter(pos, ast, visitors) {
-  const enterExit = visitors[142];
+  const enterExit = visitors[144];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3761,7 +3825,7 @@ function walkTSTypeParameter(pos, ast, visitors) {
 }
 
 function walkTSTypeParameterDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[143];
+  const enterExit = visitors[145];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3775,7 +3839,7 @@ function walkTSTypeParameterDeclaration(pos, ast, visitors) {
 }
 
 function walkTSTypeAliasDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[144];
+  const enterExit = visitors[146];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3791,7 +3855,7 @@ function walkTSTypeAliasDeclaration(pos, ast, visitors) {
 }
 
 function walkTSClassImplements(pos, ast, visitors) {
-  const enterExit = visitors[145];
+  const enterExit = visitors[147];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3806,7 +3870,7 @@ function walkTSClassImplements(pos, ast, visitors) {
 }
 
 function walkTSInterfaceDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[146];
+  const enterExit = visitors[148];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3823,7 +3887,7 @@ function walkTSInterfaceDeclaration(pos, ast, visitors) {
 }
 
 function walkTSInterfaceBody(pos, ast, visitors) {
-  const enterExit = visitors[147];
+  const enterExit = visitors[149];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3837,7 +3901,7 @@ function walkTSInterfaceBody(pos, ast, visitors) {
 }
 
 function walkTSPropertySignature(pos, ast, visitors) {
-  const enterExit = visitors[148];
+  const enterExit = visitors[150];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3874,7 +3938,7 @@ function walkTSSignature(pos, ast, visitors) {
 }
 
 function walkTSIndexSignature(pos, ast, visitors) {
-  const enterExit = visitors[149];
+  const enterExit = visitors[151];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3889,7 +3953,7 @@ function walkTSIndexSignature(pos, ast, visitors) {
 }
 
 function walkTSCallSignatureDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[150];
+  const enterExit = visitors[152];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3905,7 +3969,7 @@ function walkTSCallSignatureDeclaration(pos, ast, visitors) {
 }
 
 function walkTSMethodSignature(pos, ast, visitors) {
-  const enterExit = visitors[151];
+  const enterExit = visitors[153];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3922,7 +3986,7 @@ function walkTSMethodSignature(pos, ast, visitors) {
 }
 
 function walkTSConstructSignatureDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[152];
+  const enterExit = visitors[154];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3938,7 +4002,7 @@ function walkTSConstructSignatureDeclaration(pos, ast, visitors) {
 }
 
 function walkTSIndexSignatureName(pos, ast, visitors) {
-  const enterExit = visitors[153];
+  const enterExit = visitors[155];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3952,7 +4016,7 @@ function walkTSIndexSignatureName(pos, ast, visitors) {
 }
 
 function walkTSInterfaceHeritage(pos, ast, visitors) {
-  const enterExit = visitors[154];
+  const enterExit = visitors[156];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3967,7 +4031,7 @@ function walkTSInterfaceHeritage(pos, ast, visitors) {
 }
 
 function walkTSTypePredicate(pos, ast, visitors) {
-  const enterExit = visitors[155];
+  const enterExit = visitors[157];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3995,7 +4059,7 @@ function walkTSTypePredicateName(pos, ast, visitors) {
 }
 
 function walkTSModuleDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[156];
+  const enterExit = visitors[158];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4036,7 +4100,7 @@ function walkTSModuleDeclarationBody(pos, ast, visitors) {
 }
 
 function walkTSModuleBlock(pos, ast, visitors) {
-  const enterExit = visitors[157];
+  const enterExit = visitors[159];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4050,7 +4114,7 @@ function walkTSModuleBlock(pos, ast, visitors) {
 }
 
 function walkTSTypeLiteral(pos, ast, visitors) {
-  const enterExit = visitors[158];
+  const enterExit = visitors[160];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4064,7 +4128,7 @@ function walkTSTypeLiteral(pos, ast, visitors) {
 }
 
 function walkTSInferType(pos, ast, visitors) {
-  const enterExit = visitors[159];
+  const enterExit = visitors[161];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4078,7 +4142,7 @@ function walkTSInferType(pos, ast, visitors) {
 }
 
 function walkTSTypeQuery(pos, ast, visitors) {
-  const enterExit = visitors[160];
+  const enterExit = visitors[162];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4112,7 +4176,7 @@ function walkTSTypeQueryExprName(pos, ast, visitors) {
 }
 
 function walkTSImportType(pos, ast, visitors) {
-  const enterExit = visitors[161];
+  const enterExit = visitors[163];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4129,7 +4193,7 @@ function walkTSImportType(pos, ast, visitors) {
 }
 
 function walkTSFunctionType(pos, ast, visitors) {
-  const enterExit = visitors[162];
+  const enterExit = visitors[164];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4145,7 +4209,7 @@ function walkTSFunctionType(pos, ast, visitors) {
 }
 
 function walkTSConstructorType(pos, ast, visitors) {
-  const enterExit = visitors[163];
+  const enterExit = visitors[165];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4161,7 +4225,7 @@ function walkTSConstructorType(pos, ast, visitors) {
 }
 
 function walkTSMappedType(pos, ast, visitors) {
-  const enterExit = visitors[164];
+  const enterExit = visitors[166];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4176,7 +4240,7 @@ function walkTSMappedType(pos, ast, visitors) {
 }
 
 function walkTSTemplateLiteralType(pos, ast, visitors) {
-  const enterExit = visitors[165];
+  const enterExit = visitors[167];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4191,7 +4255,7 @@ function walkTSTemplateLiteralType(pos, ast, visitors) {
 }
 
 function walkTSAsExpression(pos, ast, visitors) {
-  const enterExit = visitors[166];
+  const enterExit = visitors[168];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4206,7 +4270,7 @@ function walkTSAsExpression(pos, ast, visitors) {
 }
 
 function walkTSSatisfiesExpression(pos, ast, visitors) {
-  const enterExit = visitors[167];
+  const enterExit = visitors[169];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4221,7 +4285,7 @@ function walkTSSatisfiesExpression(pos, ast, visitors) {
 }
 
 function walkTSTypeAssertion(pos, ast, visitors) {
-  const enterExit = visitors[168];
+  const enterExit = visitors[170];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4236,7 +4300,7 @@ function walkTSTypeAssertion(pos, ast, visitors) {
 }
 
 function walkTSImportEqualsDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[169];
+  const enterExit = visitors[171];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4270,7 +4334,7 @@ function walkTSModuleReference(pos, ast, visitors) {
 }
 
 function walkTSExternalModuleReference(pos, ast, visitors) {
-  const enterExit = visitors[170];
+  const enterExit = visitors[172];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4284,7 +4348,7 @@ function walkTSExternalModuleReference(pos, ast, visitors) {
 }
 
 function walkTSNonNullExpression(pos, ast, visitors) {
-  const enterExit = visitors[171];
+  const enterExit = visitors[173];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4298,7 +4362,7 @@ function walkTSNonNullExpression(pos, ast, visitors) {
 }
 
 function walkDecorator(pos, ast, visitors) {
-  const enterExit = visitors[172];
+  const enterExit = visitors[174];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4312,7 +4376,7 @@ function walkDecorator(pos, ast, visitors) {
 }
 
 function walkTSExportAssignment(pos, ast, visitors) {
-  const enterExit = visitors[173];
+  const enterExit = visitors[175];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4326,7 +4390,7 @@ function walkTSExportAssignment(pos, ast, visitors) {
 }
 
 function walkTSNamespaceExportDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[174];
+  const enterExit = visitors[176];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4340,7 +4404,7 @@ function walkTSNamespaceExportDeclaration(pos, ast, visitors) {
 }
 
 function walkTSInstantiationExpression(pos, ast, visitors) {
-  const enterExit = visitors[175];
+  const enterExit = visitors[177];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4355,7 +4419,7 @@ function walkTSInstantiationExpression(pos, ast, visitors) {
 }
 
 function walkJSDocNullableType(pos, ast, visitors) {
-  const enterExit = visitors[176];
+  const enterExit = visitors[178];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4369,7 +4433,7 @@ function walkJSDocNullableType(pos, ast, visitors) {
 }
 
 function walkJSDocNonNullableType(pos, ast, visitors) {
-  const enterExit = visitors[177];
+  const enterExit = visitors[179];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4383,7 +4447,7 @@ function walkJSDocNonNullableType(pos, ast, visitors) {
 }
 
 function walkJSDocUnknownType(pos, ast, visitors) {
-  const visit = visitors[37];
+  const visit = visitors[38];
   if (visit !== null) visit(new JSDocUnknownType(pos, ast));
 }
 
@@ -4564,6 +4628,14 @@ function walkBoxV8IntrinsicExpression(pos, ast, visitors) {
   return walkV8IntrinsicExpression(ast.buffer.uint32[pos >> 2], ast, visitors);
 }
 
+function walkBoxTopicReference(pos, ast, visitors) {
+  return walkTopicReference(ast.buffer.uint32[pos >> 2], ast, visitors);
+}
+
+function walkBoxPipelineExpression(pos, ast, visitors) {
+  return walkPipelineExpression(ast.buffer.uint32[pos >> 2], ast, visitors);
+}
+
 function walkVecArrayExpressionElement(pos, ast, visitors) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
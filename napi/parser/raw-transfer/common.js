@@ -44,7 +44,13 @@ if (!rawTransferSupported()) {
  */
 function parseSyncRawImpl(filename, sourceText, options, convert) {
   const { buffer, sourceByteLen } = prepareRaw(sourceText);
-  parseSyncRawBinding(filename, buffer, sourceByteLen, options);
+  try {
+    parseSyncRawBinding(filename, buffer, sourceByteLen, options);
+  } catch (err) {
+    // Source text is too long. Buffer can still be reused.
+    returnBufferToCache(buffer);
+    throw err;
+  }
   return convert(buffer, sourceText, sourceByteLen);
 }
 
@@ -124,18 +130,27 @@ async function parseAsyncRawImpl(filename, sourceText, options, convert) {
 
   // Parse
   const { buffer, sourceByteLen } = prepareRaw(sourceText);
-  await parseAsyncRawBinding(filename, buffer, sourceByteLen, options);
-  const data = convert(buffer, sourceText, sourceByteLen);
-
-  // Free the CPU core
-  if (queue.length > 0) {
-    // Some further tasks waiting in queue. Run the next one.
-    // Do not increment `availableCores` (see above).
-    const resolve = queue.shift();
-    resolve();
-  } else {
-    // No tasks waiting in queue. This CPU is now free.
-    availableCores++;
+  let data;
+  try {
+    try {
+      await parseAsyncRawBinding(filename, buffer, sourceByteLen, options);
+    } catch (err) {
+      // Source text is too long. Buffer can still be reused.
+      returnBufferToCache(buffer);
+      throw err;
+    }
+    data = convert(buffer, sourceText, sourceByteLen);
+  } finally {
+    // Free the CPU core
+    if (queue.length > 0) {
+      // Some further tasks waiting in queue. Run the next one.
+      // Do not increment `availableCores` (see above).
+      const resolve = queue.shift();
+      resolve();
+    } else {
+      // No tasks waiting in queue. This CPU is now free.
+      availableCores++;
+    }
   }
 
   return data;
@@ -14,6 +14,7 @@ use oxc::{
     allocator::{Allocator, FromIn, Vec as ArenaVec},
     ast_visit::utf8_to_utf16::Utf8ToUtf16,
     semantic::SemanticBuilder,
+    span::SourceType,
 };
 use oxc_napi::get_source_type;
 
@@ -72,22 +73,23 @@ pub fn get_buffer_offset(buffer: Uint8Array) -> u32 {
 /// If source text is originally a JS string on JS side, and converted to a buffer with
 /// `Buffer.from(str)` or `new TextEncoder().encode(str)`, this guarantees it's valid UTF-8.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if source text is too long, or AST takes more memory than is available in the buffer.
+/// Returns an error if source text is too long, or AST takes more memory than is available
+/// in the buffer.
 #[napi(skip_typescript)]
 pub unsafe fn parse_sync_raw(
     filename: String,
     mut buffer: Uint8Array,
     source_len: u32,
     options: Option<ParserOptions>,
-) {
+) -> napi::Result<()> {
     // SAFETY: This function is called synchronously, so buffer cannot be mutated outside this function
     // during the time this `&mut [u8]` exists
     let buffer = unsafe { buffer.as_mut() };
 
     // SAFETY: `parse_raw_impl` has same safety requirements as this function
-    unsafe { parse_raw_impl(&filename, buffer, source_len, options) };
+    unsafe { parse_raw_impl(&filename, buffer, source_len, options) }
 }
 
 /// Parse AST into provided `Uint8Array` buffer, asynchronously.
@@ -115,9 +117,8 @@ pub unsafe fn parse_sync_raw(
 /// If source text is originally a JS string on JS side, and converted to a buffer with
 /// `Buffer.from(str)` or `new TextEncoder().encode(str)`, this guarantees it's valid UTF-8.
 ///
-/// # Panics
-///
-/// Panics if source text is too long, or AST takes more memory than is available in the buffer.
+/// The returned promise rejects if source text is too long, or AST takes more memory than is
+/// available in the buffer.
 #[napi(skip_typescript)]
 pub fn parse_async_raw(
     filename: String,
@@ -146,8 +147,7 @@ impl Task for ResolveTask {
         // Therefore, this is a valid exclusive `&mut [u8]`.
         let buffer = unsafe { self.buffer.as_mut() };
         // SAFETY: Caller of `parse_async` guarantees to uphold invariants of `parse_raw_impl`
-        unsafe { parse_raw_impl(&self.filename, buffer, self.source_len, self.options.take()) };
-        Ok(())
+        unsafe { parse_raw_impl(&self.filename, buffer, self.source_len, self.options.take()) }
     }
 
     fn resolve(&mut self, _: napi::Env, _result: ()) -> napi::Result<()> {
@@ -155,6 +155,11 @@ impl Task for ResolveTask {
     }
 }
 
+/// Only sources at least this many times smaller than the space left for the AST are parsed,
+/// as an AST is rarely more than this many times larger than its source.
+/// Running out of space in the buffer part-way through parsing would abort the process.
+const AST_SIZE_RATIO: usize = 8;
+
 /// Parse AST into buffer.
 ///
 /// # SAFETY
@@ -166,13 +171,18 @@ impl Task for ResolveTask {
 ///
 /// If source text is originally a JS string on JS side, and converted to a buffer with
 /// `Buffer.from(str)` or `new TextEncoder().encode(str)`, this guarantees it's valid UTF-8.
+///
+/// # Errors
+///
+/// Returns an error if source text is too long, or AST takes more memory than is available
+/// in the buffer.
 #[allow(clippy::items_after_statements, clippy::allow_attributes)]
 unsafe fn parse_raw_impl(
     filename: &str,
     buffer: &mut [u8],
     source_len: u32,
     options: Option<ParserOptions>,
-) {
+) -> napi::Result<()> {
     // Check buffer has expected size and alignment
     assert_eq!(buffer.len(), BUFFER_SIZE);
     let buffer_ptr = ptr::from_mut(buffer).cast::<u8>();
@@ -190,7 +200,19 @@ unsafe fn parse_raw_impl(
     let source_len = source_len as usize;
     let data_offset = source_len.next_multiple_of(BUMP_ALIGN);
     let data_size = BUFFER_SIZE.saturating_sub(data_offset + RAW_METADATA_SIZE);
-    assert!(data_size >= Allocator::RAW_MIN_SIZE, "Source text is too long");
+    if data_size < Allocator::RAW_MIN_SIZE {
+        return Err(too_long());
+    }
+
+    // SAFETY: We checked above that `source_len` does not exceed length of buffer
+    let source_text = unsafe { buffer.get_unchecked(..source_len) };
+    // SAFETY: Caller guarantees source occupies this region of the buffer and is valid UTF-8
+    let source_text = unsafe { str::from_utf8_unchecked(source_text) };
+
+    let options = options.unwrap_or_default();
+    let source_type =
+        get_source_type(filename, options.lang.as_deref(), options.source_type.as_deref());
+    let ast_type = get_ast_type(source_type, &options);
 
     // Create `Allocator`.
     // Wrap in `ManuallyDrop` so the allocation doesn't get freed at end of function, or if panic.
@@ -206,68 +228,23 @@ unsafe fn parse_raw_impl(
         unsafe { Allocator::from_raw_parts(NonNull::new_unchecked(data_ptr), data_size) };
     let allocator = ManuallyDrop::new(allocator);
 
+    // Reserve space for `RawTransferData` up front, so that if buffer is too small to hold it,
+    // we fail before parsing, rather than after all the work has been done
+    let data_scope = allocator.alloc_scope(size_of::<RawTransferData>()).ok_or_else(too_long)?;
+
+    // Likewise fail before parsing if the space left after the reservation is unlikely to hold
+    // the AST
+    if source_len.saturating_mul(AST_SIZE_RATIO) > allocator.free_capacity() {
+        return Err(too_long());
+    }
+
     // Parse source.
     // Enclose parsing logic in a scope to make 100% sure no references to within `Allocator`
     // exist after this.
-    let options = options.unwrap_or_default();
-    let source_type =
-        get_source_type(filename, options.lang.as_deref(), options.source_type.as_deref());
-    let ast_type = get_ast_type(source_type, &options);
-
     let data_ptr = {
-        // SAFETY: We checked above that `source_len` does not exceed length of buffer
-        let source_text = unsafe { buffer.get_unchecked(..source_len) };
-        // SAFETY: Caller guarantees source occupies this region of the buffer and is valid UTF-8
-        let source_text = unsafe { str::from_utf8_unchecked(source_text) };
-
-        let ret = parse(&allocator, source_type, source_text, &options);
-        let mut program = ret.program;
-        let mut comments = mem::replace(&mut program.comments, ArenaVec::new_in(&allocator));
-        let mut module_record = ret.module_record;
-
-        // Convert errors.
-        // Run `SemanticBuilder` if requested.
-        //
-        // Note: Avoid calling `Error::from_diagnostics_in` unless there are some errors,
-        // because it's fairly expensive (it copies whole of source text into a `String`).
-        let mut errors = if options.show_semantic_errors == Some(true) {
-            let semantic_ret = SemanticBuilder::new().with_check_syntax_error(true).build(&program);
-
-            if !ret.errors.is_empty() || !semantic_ret.errors.is_empty() {
-                Error::from_diagnostics_in(
-                    ret.errors.into_iter().chain(semantic_ret.errors),
-                    source_text,
-                    filename,
-                    &allocator,
-                )
-            } else {
-                ArenaVec::new_in(&allocator)
-            }
-        } else if !ret.errors.is_empty() {
-            Error::from_diagnostics_in(ret.errors, source_text, filename, &allocator)
-        } else {
-            ArenaVec::new_in(&allocator)
-        };
-
-        // Convert spans to UTF-16
-        let span_converter = Utf8ToUtf16::new(source_text);
-        span_converter.convert_program(&mut program);
-        span_converter.convert_comments(&mut comments);
-        span_converter.convert_module_record(&mut module_record);
-        if let Some(mut converter) = span_converter.converter() {
-            for error in &mut errors {
-                for label in &mut error.labels {
-                    converter.convert_span(&mut label.span);
-                }
-            }
-        }
-
-        // Convert module record
-        let module = EcmaScriptModule::from_in(module_record, &allocator);
-
+        let data = build_data(&allocator, filename, source_text, source_type, &options);
         // Write `RawTransferData` to arena, and return pointer to it
-        let data = RawTransferData { program, comments, module, errors };
-        let data = allocator.alloc(data);
+        let data = data_scope.alloc(data);
         ptr::from_ref(data).cast::<u8>()
     };
 
@@ -282,6 +259,69 @@ unsafe fn parse_raw_impl(
     unsafe {
         buffer_ptr.add(RAW_METADATA_OFFSET).cast::<RawTransferMetadata>().write(metadata);
     }
+
+    Ok(())
+}
+
+fn too_long() -> napi::Error {
+    napi::Error::from_reason("Source text is too long to parse with raw transfer")
+}
+
+/// Parse source into `allocator`, and convert the AST, comments, module record and errors
+/// to the form they are transferred in.
+fn build_data<'a>(
+    allocator: &'a Allocator,
+    filename: &str,
+    source_text: &'a str,
+    source_type: SourceType,
+    options: &ParserOptions,
+) -> RawTransferData<'a> {
+    let ret = parse(allocator, source_type, source_text, options);
+    let mut program = ret.program;
+    let mut comments = mem::replace(&mut program.comments, ArenaVec::new_in(allocator));
+    let mut module_record = ret.module_record;
+
+    // Convert errors.
+    // Run `SemanticBuilder` if requested.
+    //
+    // Note: Avoid calling `Error::from_diagnostics_in` unless there are some errors,
+    // because it's fairly expensive (it copies whole of source text into a `String`).
+    let mut errors = if options.show_semantic_errors == Some(true) {
+        let semantic_ret = SemanticBuilder::new().with_check_syntax_error(true).build(&program);
+
+        if !ret.errors.is_empty() || !semantic_ret.errors.is_empty() {
+            Error::from_diagnostics_in(
+                ret.errors.into_iter().chain(semantic_ret.errors),
+                source_text,
+                filename,
+                allocator,
+            )
+        } else {
+            ArenaVec::new_in(allocator)
+        }
+    } else if !ret.errors.is_empty() {
+        Error::from_diagnostics_in(ret.errors, source_text, filename, allocator)
+    } else {
+        ArenaVec::new_in(allocator)
+    };
+
+    // Convert spans to UTF-16
+    let span_converter = Utf8ToUtf16::new(source_text);
+    span_converter.convert_program(&mut program);
+    span_converter.convert_comments(&mut comments);
+    span_converter.convert_module_record(&mut module_record);
+    if let Some(mut converter) = span_converter.converter() {
+        for error in &mut errors {
+            for label in &mut error.labels {
+                converter.convert_span(&mut label.span);
+            }
+        }
+    }
+
+    // Convert module record
+    let module = EcmaScriptModule::from_in(module_record, allocator);
+
+    RawTransferData { program, comments, module, errors }
 }
 
 /// Returns `true` if raw transfer is supported on this platform.
@@ -451,6 +451,21 @@ export interface TransformOptions {
    * options.
    */
   cwd?: string
+  /**
+   * Use the options commonly needed by a tool.
+   *
+   * Options which are set explicitly take precedence over the options of the preset,
+   * and `define` entries are merged with the entries of the preset.
+   *
+   * * `vite`: targets the default browsers of `vite build`, uses the automatic JSX runtime,
+   * imports helpers from `@oxc-project/runtime`, generates source maps and defines
+   * `process.env.NODE_ENV` as `"production"`.
+   * * `next`: as `vite`, but targets the default browsers of Next.js.
+   * * `library`: targets `es2020`, uses the automatic JSX runtime, imports helpers from
+   * `@oxc-project/runtime`, and generates source maps and `.d.ts` declarations.
+   * `process.env.NODE_ENV` is left for the consumers of the library to define.
+   */
+  preset?: 'vite' | 'next' | 'library'
   /**
    * Enable source map generation.
    *
@@ -541,7 +556,8 @@ export interface TransformResult {
    *
    * Oxc's parser recovers from common syntax errors, meaning that
    * transformed code may still be available even if there are errors in this
-   * list.
+   * list. Transformation diagnostics with a `Warning` severity are reported
+   * alongside the transformed code.
    */
   errors: Array<OxcError>
 }
@@ -76,7 +76,8 @@ pub struct TransformResult {
     ///
     /// Oxc's parser recovers from common syntax errors, meaning that
     /// transformed code may still be available even if there are errors in this
-    /// list.
+    /// list. Transformation diagnostics with a `Warning` severity are reported
+    /// alongside the transformed code.
     pub errors: Vec<OxcError>,
 }
 
@@ -98,6 +99,21 @@ pub struct TransformOptions {
     /// options.
     pub cwd: Option<String>,
 
+    /// Use the options commonly needed by a tool.
+    ///
+    /// Options which are set explicitly take precedence over the options of the preset,
+    /// and `define` entries are merged with the entries of the preset.
+    ///
+    /// * `vite`: targets the default browsers of `vite build`, uses the automatic JSX runtime,
+    ///   imports helpers from `@oxc-project/runtime`, generates source maps and defines
+    ///   `process.env.NODE_ENV` as `"production"`.
+    /// * `next`: as `vite`, but targets the default browsers of Next.js.
+    /// * `library`: targets `es2020`, uses the automatic JSX runtime, imports helpers from
+    ///   `@oxc-project/runtime`, and generates source maps and `.d.ts` declarations.
+    ///   `process.env.NODE_ENV` is left for the consumers of the library to define.
+    #[napi(ts_type = "'vite' | 'next' | 'library'")]
+    pub preset: Option<String>,
+
     /// Enable source map generation.
     ///
     /// When `true`, the `sourceMap` field of transform result objects will be populated.
@@ -149,6 +165,55 @@ pub struct TransformOptions {
     pub plugins: Option<PluginsOptions>,
 }
 
+/// Default browsers of `vite build`.
+/// <https://vite.dev/config/build-options.html#build-target>
+const VITE_TARGETS: &[&str] = &["es2020", "edge88", "firefox78", "chrome87", "safari14"];
+
+/// Default browsers of Next.js.
+/// <https://nextjs.org/docs/architecture/supported-browsers>
+const NEXT_TARGETS: &[&str] = &["chrome64", "edge79", "firefox67", "opera51", "safari12"];
+
+const LIBRARY_TARGETS: &[&str] = &["es2020"];
+
+impl TransformOptions {
+    /// Set the options which are not set explicitly to the options of the `preset`.
+    fn apply_preset(&mut self) -> Result<(), String> {
+        let Some(preset) = self.preset.take() else {
+            return Ok(());
+        };
+        let (targets, define_node_env, declaration) = match preset.as_str() {
+            "vite" => (VITE_TARGETS, true, false),
+            "next" => (NEXT_TARGETS, true, false),
+            "library" => (LIBRARY_TARGETS, false, true),
+            _ => return Err(format!("Invalid preset option: `{preset}`.")),
+        };
+
+        self.target
+            .get_or_insert_with(|| Either::B(targets.iter().map(ToString::to_string).collect()));
+        self.jsx.get_or_insert_with(|| {
+            Either::B(JsxOptions {
+                runtime: Some("automatic".to_string()),
+                ..JsxOptions::default()
+            })
+        });
+        self.helpers.get_or_insert(Helpers { mode: Some(HelperMode::Runtime) });
+        self.sourcemap.get_or_insert(true);
+        if define_node_env {
+            self.define
+                .get_or_insert_with(FxHashMap::default)
+                .entry("process.env.NODE_ENV".to_string())
+                .or_insert_with(|| "\"production\"".to_string());
+        }
+        if declaration {
+            self.typescript
+                .get_or_insert_with(TypeScriptOptions::default)
+                .declaration
+                .get_or_insert_with(IsolatedDeclarationsOptions::default);
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
@@ -507,6 +572,7 @@ impl From<StyledComponentsOptions> for oxc::transformer::StyledComponentsOptions
 ///
 /// @see {@link https://babeljs.io/docs/babel-plugin-transform-react-jsx#options}
 #[napi(object)]
+#[derive(Default)]
 pub struct JsxOptions {
     /// Decides which runtime to use.
     ///
@@ -738,6 +804,10 @@ impl Compiler {
     fn new(options: Option<TransformOptions>) -> Result<Self, Vec<OxcDiagnostic>> {
         let mut options = options;
 
+        if let Some(options) = &mut options {
+            options.apply_preset().map_err(|err| vec![OxcDiagnostic::error(err)])?;
+        }
+
         let isolated_declaration_options = options
             .as_ref()
             .and_then(|o| o.typescript.as_ref())
@@ -1,7 +1,7 @@
 import { Worker } from 'node:worker_threads';
 import { describe, expect, it, test } from 'vitest';
 
-import { HelperMode, transform } from '../index';
+import { HelperMode, Severity, transform } from '../index';
 
 describe('simple', () => {
   const code = 'export class A<T> {}';
@@ -188,6 +188,14 @@ describe('jsx', () => {
     });
     expect(ret.code).toEqual('const foo = <div />;\n');
   });
+
+  it('reports warnings alongside the transformed code', () => {
+    const ret = transform('test.jsx', '<a:b />');
+    expect(ret.errors.length).toBe(1);
+    expect(ret.errors[0].severity).toBe(Severity.Warning);
+    expect(ret.errors[0].labels[0]).toMatchObject({ start: 1, end: 4 });
+    expect(ret.code).toContain('_jsx("a:b", {})');
+  });
 });
 
 describe('react refresh plugin', () => {
@@ -255,6 +263,44 @@ describe('define plugin', () => {
   });
 });
 
+describe('preset', () => {
+  const code = 'if (process.env.NODE_ENV === "production") { a ??= b; }';
+
+  it('vite', () => {
+    const ret = transform('test.js', code, { preset: 'vite' });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.code).toEqual('a ?? (a = b);\n');
+    expect(ret.map).toBeDefined();
+  });
+
+  it('overrides options of the preset', () => {
+    const ret = transform('test.js', code, {
+      preset: 'vite',
+      target: 'esnext',
+      sourcemap: false,
+      define: { 'process.env.NODE_ENV': '"development"' },
+    });
+    expect(ret.code).toEqual('');
+    expect(ret.map).toBeUndefined();
+  });
+
+  it('library', () => {
+    const ret = transform('test.ts', 'export const a: number = process.env.NODE_ENV ? 1 : 2;', {
+      preset: 'library',
+    });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.code).toEqual('export const a = process.env.NODE_ENV ? 1 : 2;\n');
+    expect(ret.declaration).toEqual('export declare const a: number;\n');
+    expect(ret.declarationMap).toBeDefined();
+  });
+
+  it('reports invalid presets', () => {
+    // @ts-expect-error
+    const ret = transform('test.js', code, { preset: 'webpack' });
+    expect(ret.errors[0].message).toEqual('Invalid preset option: `webpack`.');
+  });
+});
+
 describe('inject plugin', () => {
   const code = 'let _ = Object.assign';
 
@@ -49,6 +49,8 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | TopicReference
+  | PipelineExpression
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -823,6 +825,16 @@ export interface V8IntrinsicExpression extends Span {
   arguments: Array<Argument>;
 }
 
+export interface TopicReference extends Span {
+  type: 'TopicReference';
+}
+
+export interface PipelineExpression extends Span {
+  type: 'PipelineExpression';
+  head: Expression;
+  body: Expression;
+}
+
 export interface BooleanLiteral extends Span {
   type: 'Literal';
   value: boolean;
@@ -1609,6 +1621,8 @@ export type Node =
   | ExportAllDeclaration
   | ExportSpecifier
   | V8IntrinsicExpression
+  | TopicReference
+  | PipelineExpression
   | BooleanLiteral
   | NullLiteral
   | NumericLiteral
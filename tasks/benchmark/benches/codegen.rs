@@ -5,6 +5,7 @@ use oxc_benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
 use oxc_tasks_common::TestFiles;
 use oxc_transformer::{TransformOptions, Transformer};
 
@@ -42,5 +43,47 @@ fn bench_codegen(criterion: &mut Criterion) {
     }
 }
 
-criterion_group!(codegen, bench_codegen);
+/// Printing string literals, which dominates printing of large bundles (e.g. embedded templates,
+/// translations and source maps). Printed with and without `minify`, as minifying picks quotes.
+fn bench_codegen_strings(criterion: &mut Criterion) {
+    let source_text = strings_source_text();
+    let allocator = Allocator::default();
+    let parser_ret = Parser::new(&allocator, &source_text, SourceType::mjs()).parse();
+    assert!(parser_ret.errors.is_empty());
+    let program = parser_ret.program;
+
+    let mut group = criterion.benchmark_group("codegen_strings");
+    for minify in [false, true] {
+        let id = BenchmarkId::from_parameter(if minify { "minify" } else { "default" });
+        group.bench_function(id, |b| {
+            b.iter_with_large_drop(|| {
+                Codegen::new()
+                    .with_options(CodegenOptions { minify, ..CodegenOptions::default() })
+                    .build(&program)
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Source text with string literals of various lengths and contents.
+fn strings_source_text() -> String {
+    let strings = [
+        "id",
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit",
+        "It's a \"quoted\" string with a \\ backslash",
+        "line 1\nline 2\nline 3",
+        "<div class=\"container\"><span>${text}</span></div>",
+        "Größenänderung der Benutzeroberfläche",
+        "日本語のテキストを含む文字列",
+    ];
+    let mut source_text = String::new();
+    for i in 0..2000 {
+        let s = strings[i % strings.len()].repeat(1 + i % 5);
+        source_text.push_str(&format!("export const s{i} = \"{s}\";\n"));
+    }
+    source_text
+}
+
+criterion_group!(codegen, bench_codegen, bench_codegen_strings);
 criterion_main!(codegen);
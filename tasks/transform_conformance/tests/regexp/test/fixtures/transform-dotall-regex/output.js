@@ -1 +1,2 @@
-a1 = new RegExp("a.b", "s");
+a1 = /a[\s\S]b/;
+a2 = /(?-s:.)[\s\S]/;
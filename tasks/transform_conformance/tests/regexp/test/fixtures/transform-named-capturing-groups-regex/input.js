@@ -1,2 +1,5 @@
 c1 = /(?<a>b)/
 c2 = /((?<a>b)){2}/
+c3 = /(?<y>\d+)-\k<y>/g
+c4 = /(?<a>x)|(?<a>y)/
+c5 = /(?<a>x)|(?<a>y)\k<a>/
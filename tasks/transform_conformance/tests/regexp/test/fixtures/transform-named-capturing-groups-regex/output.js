@@ -1,2 +1,5 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+c3 = babelHelpers.wrapRegExp(/(\d+)-\1/g, { y: 1 });
+c4 = babelHelpers.wrapRegExp(/(x)|(y)/, { a: [1, 2] });
+c5 = new RegExp("(?<a>x)|(?<a>y)\\k<a>", "");
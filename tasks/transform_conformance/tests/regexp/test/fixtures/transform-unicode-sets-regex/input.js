@@ -1 +1,3 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[[a-z]--[aeiou]]/v
+g3 = /[\w&&\d]+/gv
@@ -1 +1,3 @@
 g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g2 = /[b-df-hj-np-tv-z]/u;
+g3 = /[0-9]+/gu;
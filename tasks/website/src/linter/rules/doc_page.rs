@@ -39,6 +39,7 @@ impl Context {
             turned_on_by_default,
             autofix,
             category,
+            deprecated,
         } = rule;
         let resolved =
             schema.as_ref().map(|schema| self.schemas.dereference(schema).unwrap_or(schema));
@@ -66,6 +67,14 @@ const source = `{}`;
 
         // rule metadata
         self.page.div(r#"class="rule-meta""#, |p| {
+            if let Some(deprecated) = deprecated {
+                p.Alert(r#"class="deprecated" type="warning""#, |p| {
+                    p.writeln(format!(
+                        r#"<span class="emoji">⚠️</span> This rule is {deprecated}."#
+                    ))
+                })?;
+            }
+
             if *turned_on_by_default {
                 p.Alert(r#"class="default-on" type="success""#, |p| {
                     p.writeln(r#"<span class="emoji">✅</span> This rule is turned on by default."#)